@@ -0,0 +1,413 @@
+//! Automata over the characters of the text, built from patterns
+//!
+//! A pattern is translated into an NFA whose edges are labeled with sets of characters.
+//! `[^]` and `[$]` are assertions which depend on the characters around the position,
+//! so a set of states is kept together with the context of its position (`Position`).
+//! The characters are those of the prepared (normalized and folded) text,
+//! and every token reads single characters even if the grapheme option is set.
+
+use crate::ex::{complement_ranges, intersect_ranges, union_ranges, CharList, MatchOptions, PatternChar};
+use crate::fold;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+
+/// sorted set of character ranges
+pub type Ranges = Vec<(u32, u32)>;
+
+/// every character
+const ANY: &[(u32, u32)] = &[(0, 0xD7FF), (0xE000, 0x10FFFF)];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Assertion {
+    /// `[^]`: the start of the text (or of a line)
+    Start,
+    /// `[$]`: the end of the text (or of a line)
+    End,
+    /// the end of the text (after `[*+]`)
+    TextEnd,
+    /// no digit before the position (the start of `[<min-max>]`)
+    NumberStart,
+    /// no digit after the position (the end of `[<min-max>]`)
+    NumberEnd,
+}
+
+#[derive(Clone, Debug)]
+enum Edge {
+    Chars(Ranges, usize),
+    Epsilon(usize),
+    Assert(Assertion, usize),
+}
+
+/// NFA of a pattern
+#[derive(Clone, Debug)]
+pub struct Nfa {
+    edges: Vec<Vec<Edge>>,
+    start: usize,
+    accept: usize,
+    multiline: bool,
+    /// states from which the accepting state can be reached
+    live: Vec<bool>,
+}
+
+/// Set of NFA states (before following the epsilon edges) at a position of the text
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    states: Vec<usize>,
+    /// the position is the start of the text
+    at_start: bool,
+    /// the previous character is '\n'
+    after_newline: bool,
+    /// the previous character is an ASCII digit
+    after_digit: bool,
+}
+
+impl Position {
+    /// check if no string leads from the position to a match
+    pub fn is_dead(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+/// characters of the text which the list accepts (with the folded forms of its characters)
+fn text_ranges(charlist: &CharList, options: &MatchOptions) -> Ranges {
+    let mut ranges = charlist.ranges().iter().map(|r| (r.start as u32, r.end as u32)).collect::<Ranges>();
+    if fold::is_enabled(options) {
+        ranges.extend(fold::folded_forms(|c| charlist.contains(c), options).into_iter().map(|c| (c as u32, c as u32)));
+        ranges = union_ranges(ranges);
+    }
+    ranges
+}
+
+/// check if the sorted ranges contain the character
+pub fn ranges_contain(ranges: &[(u32, u32)], c: u32) -> bool {
+    let i = ranges.partition_point(|&(_, end)| end < c);
+    i < ranges.len() && ranges[i].0 <= c
+}
+
+impl Nfa {
+    /// create the NFA which accepts the (prepared) texts the pattern matches
+    pub fn new(pattern: &[PatternChar], options: &MatchOptions) -> Self {
+        let mut nfa = Nfa { edges: vec![vec![]], start: 0, accept: 0, multiline: options.multiline, live: vec![] };
+        nfa.accept = nfa.build(pattern, 0, options);
+        if fold::is_enabled(options) {
+            // a folded text has no characters which are changed by folding
+            let text_chars = complement_ranges(&fold::changed_ranges(options));
+            for edges in &mut nfa.edges {
+                for edge in edges.iter_mut() {
+                    if let Edge::Chars(ranges, _) = edge {
+                        *ranges = intersect_ranges(ranges, &text_chars);
+                    }
+                }
+                edges.retain(|edge| !matches!(edge, Edge::Chars(ranges, _) if ranges.is_empty()));
+            }
+        }
+        nfa.live = nfa.live_states();
+        nfa
+    }
+    fn add_state(&mut self) -> usize {
+        self.edges.push(vec![]);
+        self.edges.len() - 1
+    }
+    /// add the states of the pattern after the state `from` and return the last state
+    fn build(&mut self, pattern: &[PatternChar], from: usize, options: &MatchOptions) -> usize {
+        pattern.iter().enumerate().fold(from, |from, (i, token)| self.build_token(token, pattern.get(i + 1), from, options))
+    }
+    /// add the states of the token (`next` is the token after it, which delimits `[^=...]`)
+    fn build_token(&mut self, token: &PatternChar, next: Option<&PatternChar>, from: usize, options: &MatchOptions) -> usize {
+        let to = self.add_state();
+        // `from` -> `to` once or more (`zero` adds an empty path) with the characters
+        let repeat = |nfa: &mut Nfa, ranges: Ranges, zero: bool| {
+            nfa.edges[from].push(Edge::Chars(ranges.clone(), to));
+            nfa.edges[to].push(Edge::Chars(ranges, to));
+            if zero {
+                nfa.edges[from].push(Edge::Epsilon(to));
+            }
+        };
+        match token {
+            PatternChar::Char(c) => self.edges[from].push(Edge::Chars(vec![(*c as u32, *c as u32)], to)),
+            PatternChar::Number => self.edges[from].push(Edge::Chars(vec![('0' as u32, '9' as u32)], to)),
+            PatternChar::Question => self.edges[from].push(Edge::Chars(ANY.to_vec(), to)),
+            PatternChar::Wildcard | PatternChar::LazyWildcard => repeat(self, ANY.to_vec(), true),
+            PatternChar::PossessiveWildcard => {
+                // any characters until the end of the text
+                let rest = self.add_state();
+                self.edges[from].push(Edge::Epsilon(rest));
+                self.edges[rest].push(Edge::Chars(ANY.to_vec(), rest));
+                self.edges[rest].push(Edge::Assert(Assertion::TextEnd, to));
+            }
+            PatternChar::CharList(charlist) => self.edges[from].push(Edge::Chars(text_ranges(charlist, options), to)),
+            PatternChar::NotCharList(charlist) => {
+                let ranges = complement_ranges(&text_ranges(charlist, options));
+                self.edges[from].push(Edge::Chars(ranges, to));
+            }
+            PatternChar::CharListRepeat(charlist) => repeat(self, text_ranges(charlist, options), false),
+            PatternChar::CharListRepeatZero(charlist) => repeat(self, text_ranges(charlist, options), true),
+            PatternChar::CharListOptional(charlist) => {
+                self.edges[from].push(Edge::Chars(text_ranges(charlist, options), to));
+                self.edges[from].push(Edge::Epsilon(to));
+            }
+            PatternChar::NotCharListRepeat(charlist) => {
+                repeat(self, complement_ranges(&text_ranges(charlist, options)), false);
+            }
+            PatternChar::Selector(selector) => {
+                for sub_pattern in selector {
+                    let end = self.build(sub_pattern, from, options);
+                    self.edges[end].push(Edge::Epsilon(to));
+                }
+            }
+            PatternChar::NotSelector(selector) => self.build_not_selector(selector, next, from, to, options),
+            PatternChar::NumberRange(range) => {
+                // the number takes the whole run of digits
+                let first = self.add_state();
+                self.edges[from].push(Edge::Assert(Assertion::NumberStart, first));
+                let last = self.add_state();
+                self.edges[last].push(Edge::Assert(Assertion::NumberEnd, to));
+                if range.leading_zeros && range.width.is_none() {
+                    self.edges[first].push(Edge::Chars(vec![('0' as u32, '0' as u32)], first));
+                }
+                for sequence in range.digit_sequences() {
+                    let mut state = first;
+                    for (low, high) in sequence {
+                        let next = self.add_state();
+                        self.edges[state].push(Edge::Chars(vec![(low as u32, high as u32)], next));
+                        state = next;
+                    }
+                    self.edges[state].push(Edge::Epsilon(last));
+                }
+            }
+            PatternChar::Start => self.edges[from].push(Edge::Assert(Assertion::Start, to)),
+            PatternChar::End => self.edges[from].push(Edge::Assert(Assertion::End, to)),
+        }
+        to
+    }
+    /// add the states of `[^=...]`: the complement of the determinized alternatives (one or more characters),
+    /// where no character after the first one can start the next token
+    fn build_not_selector(
+        &mut self, selector: &[Vec<PatternChar>], next: Option<&PatternChar>, from: usize, to: usize, options: &MatchOptions,
+    ) {
+        let inside = match next {
+            Some(next) => complement_ranges(&first_chars(std::slice::from_ref(next), options).0),
+            None => ANY.to_vec(),
+        };
+        let sub = Nfa::new(&[PatternChar::Selector(selector.to_vec())], options);
+        let alphabet = alphabet(&[&sub]);
+        let initial = Position { states: vec![sub.start], at_start: false, after_newline: false, after_digit: false };
+        // the initial position is `from`, which never accepts the empty string
+        let mut nodes: HashMap<Position, usize> = HashMap::new();
+        let mut queue = VecDeque::from([(initial, from)]);
+        while let Some((position, node)) = queue.pop_front() {
+            let mut targets: HashMap<usize, Ranges> = HashMap::new();
+            for &block in &alphabet {
+                let next = sub.step(&position, representative(block));
+                let target = *nodes.entry(next.clone()).or_insert_with(|| {
+                    let target = self.add_state();
+                    if !sub.is_accepting(&next) {
+                        self.edges[target].push(Edge::Epsilon(to));
+                    }
+                    queue.push_back((next, target));
+                    target
+                });
+                targets.entry(target).or_default().push(block);
+            }
+            let mut targets = targets.into_iter().collect::<Vec<_>>();
+            targets.sort();
+            for (target, ranges) in targets {
+                let mut ranges = union_ranges(ranges);
+                if node != from {
+                    ranges = intersect_ranges(&ranges, &inside);
+                }
+                if !ranges.is_empty() {
+                    self.edges[node].push(Edge::Chars(ranges, target));
+                }
+            }
+        }
+    }
+    /// find the states from which the accepting state can be reached
+    fn live_states(&self) -> Vec<bool> {
+        let mut reverse = vec![vec![]; self.edges.len()];
+        for (state, edges) in self.edges.iter().enumerate() {
+            for edge in edges {
+                let (Edge::Chars(_, target) | Edge::Epsilon(target) | Edge::Assert(_, target)) = edge;
+                reverse[*target].push(state);
+            }
+        }
+        let mut live = vec![false; self.edges.len()];
+        let mut stack = vec![self.accept];
+        live[self.accept] = true;
+        while let Some(state) = stack.pop() {
+            for &prev in &reverse[state] {
+                if !live[prev] {
+                    live[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+        live
+    }
+    /// get the position at the start of the text
+    pub fn initial(&self) -> Position {
+        let states = if self.live[self.start] { vec![self.start] } else { vec![] };
+        Position { states, at_start: true, after_newline: false, after_digit: false }
+    }
+    /// follow the epsilon edges and the assertions which hold before `next` (None at the end of the text)
+    fn closure(&self, position: &Position, next: Option<char>) -> Vec<usize> {
+        let mut seen = HashSet::new();
+        let mut stack = position.states.clone();
+        let mut result = vec![];
+        while let Some(state) = stack.pop() {
+            if !seen.insert(state) {
+                continue;
+            }
+            result.push(state);
+            for edge in &self.edges[state] {
+                match edge {
+                    Edge::Epsilon(target) => stack.push(*target),
+                    Edge::Assert(assertion, target) => {
+                        let holds = match assertion {
+                            Assertion::Start => position.at_start || (self.multiline && position.after_newline),
+                            Assertion::End => next.is_none() || (self.multiline && next == Some('\n')),
+                            Assertion::TextEnd => next.is_none(),
+                            Assertion::NumberStart => !position.after_digit,
+                            Assertion::NumberEnd => !next.is_some_and(|c| c.is_ascii_digit()),
+                        };
+                        if holds {
+                            stack.push(*target);
+                        }
+                    }
+                    Edge::Chars(..) => {}
+                }
+            }
+        }
+        result
+    }
+    /// read the character at the position
+    pub fn step(&self, position: &Position, c: char) -> Position {
+        let mut states = vec![];
+        for state in self.closure(position, Some(c)) {
+            for edge in &self.edges[state] {
+                if let Edge::Chars(ranges, target) = edge {
+                    if self.live[*target] && ranges_contain(ranges, c as u32) {
+                        states.push(*target);
+                    }
+                }
+            }
+        }
+        states.sort();
+        states.dedup();
+        Position { states, at_start: false, after_newline: c == '\n', after_digit: c.is_ascii_digit() }
+    }
+    /// check if the text ending at the position is accepted
+    pub fn is_accepting(&self, position: &Position) -> bool {
+        self.closure(position, None).contains(&self.accept)
+    }
+    /// check if some string (including the empty string) leads from the position to a match
+    pub fn can_accept(&self, position: &Position, alphabet: &[(u32, u32)]) -> bool {
+        let mut seen = HashSet::from([position.clone()]);
+        let mut queue = VecDeque::from([position.clone()]);
+        while let Some(position) = queue.pop_front() {
+            if self.is_accepting(&position) {
+                return true;
+            }
+            for &block in alphabet {
+                let next = self.step(&position, representative(block));
+                if !next.is_dead() && seen.insert(next.clone()) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+    /// collect the bounds of the character sets on the edges
+    fn add_bounds(&self, bounds: &mut Vec<u32>) {
+        for edges in &self.edges {
+            for edge in edges {
+                if let Edge::Chars(ranges, _) = edge {
+                    for &(start, end) in ranges {
+                        bounds.push(start);
+                        bounds.push(end + 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// get the characters which can start a match of the pattern, and whether it matches the empty string
+/// (the assertions are assumed to hold)
+pub fn first_chars(pattern: &[PatternChar], options: &MatchOptions) -> (Ranges, bool) {
+    let nfa = Nfa::new(pattern, options);
+    let mut seen = HashSet::new();
+    let mut stack = vec![nfa.start];
+    let mut ranges = vec![];
+    while let Some(state) = stack.pop() {
+        if !seen.insert(state) {
+            continue;
+        }
+        for edge in &nfa.edges[state] {
+            match edge {
+                Edge::Chars(chars, target) if nfa.live[*target] => ranges.extend_from_slice(chars),
+                Edge::Chars(..) => {}
+                Edge::Epsilon(target) | Edge::Assert(_, target) => stack.push(*target),
+            }
+        }
+    }
+    (union_ranges(ranges), seen.contains(&nfa.accept))
+}
+
+/// split the characters into blocks which every NFA reads in the same way
+/// (the first character of a block represents it)
+pub fn alphabet(nfas: &[&Nfa]) -> Vec<(u32, u32)> {
+    // '\n' and the digits are blocks by themselves because of the assertions
+    let mut bounds = vec![0, '\n' as u32, '\n' as u32 + 1, '0' as u32, '9' as u32 + 1];
+    for nfa in nfas {
+        nfa.add_bounds(&mut bounds);
+    }
+    bounds.push(0x110000);
+    bounds.sort();
+    bounds.dedup();
+    // the complement of the complement is the block without surrogates
+    bounds.windows(2).flat_map(|pair| complement_ranges(&complement_ranges(&[(pair[0], pair[1] - 1)]))).collect()
+}
+
+/// get a character of the block, preferring a printable ASCII character
+pub fn representative((start, end): (u32, u32)) -> char {
+    let printable = start.max(0x21);
+    let c = if printable <= end.min(0x7E) { printable } else { start };
+    char::from_u32(c).unwrap_or('\0')
+}
+
+/// A path of the search in `find_text`
+struct Step {
+    positions: Vec<Position>,
+    prev: Option<(Rc<Step>, char)>,
+}
+
+/// find the shortest text for which `accept` holds on the acceptance of the NFAs.
+/// texts are searched only while the first NFA can still accept them
+pub fn find_text(nfas: &[&Nfa], accept: impl Fn(&[bool]) -> bool) -> Option<String> {
+    let alphabet = alphabet(nfas);
+    let initial = nfas.iter().map(|nfa| nfa.initial()).collect::<Vec<Position>>();
+    let mut seen = HashSet::from([initial.clone()]);
+    let mut queue = VecDeque::from([Rc::new(Step { positions: initial, prev: None })]);
+    while let Some(step) = queue.pop_front() {
+        let accepts = nfas.iter().zip(&step.positions).map(|(nfa, p)| nfa.is_accepting(p)).collect::<Vec<bool>>();
+        if accept(&accepts) {
+            let mut chars = vec![];
+            let mut step = &step;
+            while let Some((prev, c)) = &step.prev {
+                chars.push(*c);
+                step = prev;
+            }
+            return Some(chars.into_iter().rev().collect());
+        }
+        for &block in &alphabet {
+            let c = representative(block);
+            let positions = nfas.iter().zip(&step.positions).map(|(nfa, p)| nfa.step(p, c)).collect::<Vec<Position>>();
+            if positions[0].is_dead() || !seen.insert(positions.clone()) {
+                continue;
+            }
+            queue.push_back(Rc::new(Step { positions, prev: Some((step.clone(), c)) }));
+        }
+    }
+    None
+}
@@ -1,12 +1,77 @@
-///
-/// # VBLike Pattern Matching
-///
+//!
+//! # VBLike Pattern Matching
+//!
+
+use crate::automaton::{self, Nfa};
+use crate::expand;
+use crate::fold;
+use crate::grapheme;
+use crate::normalize;
+use crate::sample::{self, Pick, Rng};
+use crate::registry::{self, PatternRegistry};
+use crate::unicode_tables;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Error of parsing a pattern (`position` is the index of the character where the problem starts)
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternError {
+    /// `\x` is not followed by two hex digits, or `\u` by four hex digits or `{hex}`
+    InvalidEscape { position: usize },
+    /// the escaped value is not a character (a surrogate or over U+10FFFF)
+    InvalidCodePoint { position: usize, value: u32 },
+    /// the pattern ends with '\'
+    TrailingEscape { position: usize },
+    /// the name of `\p{..}` is not a known general category or script
+    UnknownProperty { position: usize, name: String },
+    /// the name of `[:name:]` is not a known character class
+    UnknownClass { position: usize, name: String },
+    /// `[<...>]` is not `[<min-max>]` or `[<min-max:z>]` with `min <= max`
+    InvalidNumberRange { position: usize },
+    /// `[@name]` refers to a name which is not defined
+    UnknownReference { position: usize, name: String },
+    /// `[@name]` refers to a definition which refers back to `name`
+    CyclicReference { position: usize, name: String },
+    /// the name cannot be written as `[@name]` (use ASCII letters, digits, '_' and '-')
+    InvalidName { name: String },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::InvalidEscape { position } => write!(f, "invalid escape at {}", position),
+            PatternError::InvalidCodePoint { position, value } => {
+                write!(f, "invalid code point U+{:X} at {}", value, position)
+            }
+            PatternError::TrailingEscape { position } => write!(f, "pattern ends with '\\' at {}", position),
+            PatternError::UnknownProperty { position, name } => {
+                write!(f, "unknown property '{}' at {}", name, position)
+            }
+            PatternError::UnknownClass { position, name } => {
+                write!(f, "unknown character class '{}' at {}", name, position)
+            }
+            PatternError::InvalidNumberRange { position } => write!(f, "invalid number range at {}", position),
+            PatternError::UnknownReference { position, name } => {
+                write!(f, "unknown reference '{}' at {}", name, position)
+            }
+            PatternError::CyclicReference { position, name } => {
+                write!(f, "cyclic reference '{}' at {}", name, position)
+            }
+            PatternError::InvalidName { name } => write!(f, "invalid name '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
 
 /// String Cursor
 #[derive(Clone, Debug)]
 pub struct StrCursor {
     pub index: usize,
     pub chars: Vec<char>,
+    /// the first error found while reading
+    pub error: Option<PatternError>,
 }
 impl StrCursor {
     /// create a new string cursor
@@ -14,6 +79,7 @@ impl StrCursor {
         StrCursor {
             index: 0,
             chars: s.chars().collect(),
+            error: None,
         }
     }
     /// check if there is next character
@@ -27,7 +93,12 @@ impl StrCursor {
         }
         '\0'
     }
+    /// peek the character at `offset` from the current position
+    pub fn peek_at(&self, offset: usize) -> char {
+        self.chars.get(self.index + offset).copied().unwrap_or('\0')
+    }
     /// get next character
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> char {
         if !self.has_next() {
             return '\0';
@@ -36,35 +107,67 @@ impl StrCursor {
         self.index += 1;
         c
     }
-    /// get next character and skip escape character '\'
+    /// get next character and skip escape character '\'.
+    /// an invalid escape is recorded in `error` and read as U+FFFD ('\' at the end is read as itself)
     pub fn next_char_esc(&mut self) -> char {
+        match self.try_next_char_esc() {
+            Ok(c) => c,
+            Err(error) => {
+                let c = match error {
+                    PatternError::TrailingEscape { .. } => '\\',
+                    _ => char::REPLACEMENT_CHARACTER,
+                };
+                self.error.get_or_insert(error);
+                c
+            }
+        }
+    }
+    /// get next character and skip escape character '\'
+    ///
+    /// `\t`, `\n`, `\r`, `\0`, `\xHH`, `\uHHHH` and `\u{H..H}` are characters,
+    /// and '\' followed by any other character is the character itself.
+    pub fn try_next_char_esc(&mut self) -> Result<char, PatternError> {
+        let position = self.index;
         let c = self.next();
-        if c == '\\' {
-            let c2 = self.next();
-            match c2 {
-                't' => '\t',
-                'n' => '\n',
-                'r' => '\r',
-                '0' => '\0',
-                'x' | 'u' => {
-                    let mut hex = String::new();
-                    while self.has_next() {
-                        let c3 = self.peek();
-                        if c3.is_ascii_hexdigit() {
-                            hex.push(c3);
-                            self.next();
-                            continue;
-                        }
-                        break;
-                    }
-                    let n = u32::from_str_radix(&hex, 16).unwrap_or(0);
-                    std::char::from_u32(n).unwrap_or('\0')
+        if c != '\\' {
+            return Ok(c);
+        }
+        if !self.has_next() {
+            return Err(PatternError::TrailingEscape { position });
+        }
+        let c2 = self.next();
+        let hex = match c2 {
+            't' => return Ok('\t'),
+            'n' => return Ok('\n'),
+            'r' => return Ok('\r'),
+            '0' => return Ok('\0'),
+            'x' => self.read_hex(2, 2),
+            'u' if self.peek() == '{' => {
+                self.next(); // skip '{'
+                let hex = self.read_hex(1, 6);
+                if self.peek() == '}' {
+                    self.next(); // skip '}'
+                    hex
+                } else {
+                    None
                 }
-                _ => c2,
             }
-        } else {
-            c
+            'u' => self.read_hex(4, 4),
+            _ => return Ok(c2),
+        };
+        let value = hex.ok_or(PatternError::InvalidEscape { position })?;
+        char::from_u32(value).ok_or(PatternError::InvalidCodePoint { position, value })
+    }
+    /// read `min` to `max` hex digits
+    fn read_hex(&mut self, min: usize, max: usize) -> Option<u32> {
+        let mut hex = String::new();
+        while hex.len() < max && self.peek().is_ascii_hexdigit() {
+            hex.push(self.next());
+        }
+        if hex.len() < min {
+            return None;
         }
+        u32::from_str_radix(&hex, 16).ok()
     }
 }
 
@@ -85,6 +188,317 @@ impl CharRange {
     }
 }
 
+/// Named character class such as `[:alpha:]` (Unicode-aware)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CharClass {
+    Alpha, // [:alpha:] Alphabetic
+    Digit, // [:digit:] General_Category=Nd
+    Space, // [:space:] White_Space
+    Upper, // [:upper:] Uppercase
+    Lower, // [:lower:] Lowercase
+    Alnum, // [:alnum:] [:alpha:] or [:digit:]
+    Punct, // [:punct:] General_Category=P
+    Hiragana, // [:hiragana:] Script=Hiragana and kana marks such as 'ー'
+    Katakana, // [:katakana:] Script=Katakana and kana marks such as 'ー'
+    Kanji, // [:kanji:] Script=Han
+    Fullwidth, // [:fullwidth:] East_Asian_Width=F
+    HalfwidthKatakana, // [:halfwidth_katakana:] U+FF65..U+FF9F
+    Jis1, // [:jis1:] JIS X 0208 level 1 kanji
+    Jis2, // [:jis2:] JIS X 0208 level 2 kanji
+}
+impl CharClass {
+    /// get the class from its name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "alpha" => Some(CharClass::Alpha),
+            "digit" => Some(CharClass::Digit),
+            "space" => Some(CharClass::Space),
+            "upper" => Some(CharClass::Upper),
+            "lower" => Some(CharClass::Lower),
+            "alnum" => Some(CharClass::Alnum),
+            "punct" => Some(CharClass::Punct),
+            "hiragana" => Some(CharClass::Hiragana),
+            "katakana" => Some(CharClass::Katakana),
+            "kanji" => Some(CharClass::Kanji),
+            "fullwidth" => Some(CharClass::Fullwidth),
+            "halfwidth_katakana" => Some(CharClass::HalfwidthKatakana),
+            "jis1" => Some(CharClass::Jis1),
+            "jis2" => Some(CharClass::Jis2),
+            _ => None,
+        }
+    }
+    /// get the name of the class
+    pub fn name(&self) -> &'static str {
+        match self {
+            CharClass::Alpha => "alpha",
+            CharClass::Digit => "digit",
+            CharClass::Space => "space",
+            CharClass::Upper => "upper",
+            CharClass::Lower => "lower",
+            CharClass::Alnum => "alnum",
+            CharClass::Punct => "punct",
+            CharClass::Hiragana => "hiragana",
+            CharClass::Katakana => "katakana",
+            CharClass::Kanji => "kanji",
+            CharClass::Fullwidth => "fullwidth",
+            CharClass::HalfwidthKatakana => "halfwidth_katakana",
+            CharClass::Jis1 => "jis1",
+            CharClass::Jis2 => "jis2",
+        }
+    }
+    /// check if the character is in the class
+    pub fn contains(&self, ch: char) -> bool {
+        match self {
+            CharClass::Alnum => CharClass::Alpha.contains(ch) || CharClass::Digit.contains(ch),
+            _ => table_contains(self.table(), ch),
+        }
+    }
+    /// get the sorted table of ranges of the class (except `Alnum`, which is a union of two)
+    fn table(&self) -> &'static [(char, char)] {
+        match self {
+            CharClass::Alpha => unicode_tables::ALPHABETIC,
+            CharClass::Digit => unicode_tables::DECIMAL_NUMBER,
+            CharClass::Space => unicode_tables::WHITE_SPACE,
+            CharClass::Upper => unicode_tables::UPPERCASE,
+            CharClass::Lower => unicode_tables::LOWERCASE,
+            CharClass::Alnum => &[],
+            CharClass::Punct => unicode_tables::PUNCTUATION,
+            CharClass::Hiragana => unicode_tables::HIRAGANA,
+            CharClass::Katakana => unicode_tables::KATAKANA,
+            CharClass::Kanji => unicode_tables::KANJI,
+            CharClass::Fullwidth => unicode_tables::FULLWIDTH,
+            CharClass::HalfwidthKatakana => unicode_tables::HALFWIDTH_KATAKANA,
+            CharClass::Jis1 => unicode_tables::JIS_LEVEL1,
+            CharClass::Jis2 => unicode_tables::JIS_LEVEL2,
+        }
+    }
+    /// get the ranges of the class
+    fn ranges(&self) -> Vec<(u32, u32)> {
+        match self {
+            CharClass::Alnum => union_ranges([CharClass::Alpha.ranges(), CharClass::Digit.ranges()].concat()),
+            _ => self.table().iter().map(|&(start, end)| (start as u32, end as u32)).collect(),
+        }
+    }
+}
+
+/// Unicode property such as `\p{L}` (general category) or `\p{Han}` (script)
+#[derive(Clone, Copy, Debug)]
+pub struct UnicodeProperty {
+    name: &'static str,
+    table: &'static [(char, char)],
+}
+impl UnicodeProperty {
+    /// find the property by its name, such as `L`, `Letter`, `Han`, `Hani`, `gc=Nd` or `Script=Latin`
+    /// (case, spaces, '_' and '-' are ignored)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let loose = |s: &str| {
+            s.chars().filter(|c| !" _-".contains(*c)).collect::<String>().to_ascii_lowercase()
+        };
+        let (kind, value) = match name.split_once('=') {
+            Some((kind, value)) => (Some(loose(kind)), loose(value)),
+            None => (None, loose(name)),
+        };
+        let lists: &[&[unicode_tables::PropertyEntry]] = match kind.as_deref() {
+            None => &[unicode_tables::GENERAL_CATEGORIES, unicode_tables::SCRIPTS],
+            Some("gc" | "generalcategory") => &[unicode_tables::GENERAL_CATEGORIES],
+            Some("sc" | "script") => &[unicode_tables::SCRIPTS],
+            Some(_) => return None,
+        };
+        lists.iter().flat_map(|list| list.iter())
+            .find(|(_, aliases, _)| aliases.iter().any(|alias| loose(alias) == value))
+            .map(|&(name, _, table)| UnicodeProperty { name, table })
+    }
+    /// get the name of the property
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// check if the character has the property
+    pub fn contains(&self, ch: char) -> bool {
+        table_contains(self.table, ch)
+    }
+}
+
+/// check if the character is in the sorted table of ranges
+fn table_contains(table: &[(char, char)], ch: char) -> bool {
+    table.binary_search_by(|&(start, end)| {
+        if end < ch {
+            std::cmp::Ordering::Less
+        } else if start > ch {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }).is_ok()
+}
+
+/// Item of a character list
+#[derive(Clone, Debug)]
+pub enum CharItem {
+    Range(CharRange),
+    Class(CharClass),
+    /// `\p{..}`, or `\P{..}` and `\p{^..}` if negated
+    Property { property: UnicodeProperty, negated: bool },
+}
+
+/// Set operation in a character list
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SetOperation {
+    Difference, // a-z--aeiou
+    Intersection, // \p{Han}&&[:jis1:]
+}
+
+/// Character list such as `a-z0-9[:space:]\p{Han}` or `a-z--aeiou`
+///
+/// The items and the operations (applied from left to right) are kept for printing,
+/// and the characters are kept as a sorted set of ranges.
+#[derive(Clone, Debug, Default)]
+pub struct CharList {
+    pub items: Vec<CharItem>,
+    pub operations: Vec<(SetOperation, CharList)>,
+    ranges: Vec<CharRange>,
+}
+impl CharList {
+    /// create a new character list
+    pub fn new(items: Vec<CharItem>) -> Self {
+        CharList::with_operations(items, vec![])
+    }
+    /// create a new character list with set operations
+    pub fn with_operations(items: Vec<CharItem>, operations: Vec<(SetOperation, CharList)>) -> Self {
+        let mut ranges = vec![];
+        for item in &items {
+            match item {
+                CharItem::Range(range) => ranges.push((range.start as u32, range.end as u32)),
+                CharItem::Class(class) => ranges.extend(class.ranges()),
+                CharItem::Property { property, negated } => {
+                    let table: Vec<(u32, u32)> = property.table.iter().map(|&(start, end)| (start as u32, end as u32)).collect();
+                    ranges.extend(if *negated { complement_ranges(&table) } else { table });
+                }
+            }
+        }
+        let mut ranges = union_ranges(ranges);
+        for (operation, operand) in &operations {
+            let operand = operand.ranges.iter().map(|r| (r.start as u32, r.end as u32)).collect::<Vec<_>>();
+            ranges = match operation {
+                SetOperation::Difference => intersect_ranges(&ranges, &complement_ranges(&operand)),
+                SetOperation::Intersection => intersect_ranges(&ranges, &operand),
+            };
+        }
+        let ranges = ranges.into_iter()
+            .filter_map(|(start, end)| Some(CharRange::new(char::from_u32(start)?, char::from_u32(end)?)))
+            .collect();
+        CharList { items, operations, ranges }
+    }
+    /// check if the character is in the list
+    pub fn contains(&self, ch: char) -> bool {
+        self.ranges.binary_search_by(|range| {
+            if range.end < ch {
+                std::cmp::Ordering::Less
+            } else if range.start > ch {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        }).is_ok()
+    }
+    /// get the characters of the list as sorted ranges
+    pub fn ranges(&self) -> &[CharRange] {
+        &self.ranges
+    }
+}
+
+/// sort and merge the ranges (an empty range such as `z-a` is dropped)
+pub(crate) fn union_ranges(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.retain(|&(start, end)| start <= end);
+    ranges.sort();
+    let mut result: Vec<(u32, u32)> = vec![];
+    for (start, end) in ranges {
+        match result.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => result.push((start, end)),
+        }
+    }
+    result
+}
+
+/// intersect two sorted sets of ranges
+pub(crate) fn intersect_ranges(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start <= end {
+            result.push((start, end));
+        }
+        if a[i].1 < b[j].1 { i += 1; } else { j += 1; }
+    }
+    result
+}
+
+/// get the complement of a sorted set of ranges (surrogates are excluded)
+pub(crate) fn complement_ranges(ranges: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut result = vec![];
+    let mut next = 0;
+    for &(start, end) in ranges {
+        if start > next {
+            result.push((next, start - 1));
+        }
+        next = end + 1;
+    }
+    if next <= 0x10FFFF {
+        result.push((next, 0x10FFFF));
+    }
+    intersect_ranges(&result, &[(0, 0xD7FF), (0xE000, 0x10FFFF)])
+}
+
+/// Range of integer values such as `[<0-255>]`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberRange {
+    pub min: u64,
+    pub max: u64,
+    /// the number of digits if the lower bound is written with leading zeros, such as `[<00-59>]`
+    pub width: Option<usize>,
+    /// allow leading zeros (`[<0-255:z>]`)
+    pub leading_zeros: bool,
+}
+impl NumberRange {
+    /// check if the digits are a number in the range
+    pub fn contains_digits(&self, digits: &[char]) -> bool {
+        if digits.is_empty() || !digits.iter().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+        let valid_width = match self.width {
+            Some(width) => digits.len() == width,
+            None => self.leading_zeros || digits.len() == 1 || digits[0] != '0',
+        };
+        let mut value: u64 = 0;
+        for c in digits {
+            let Some(v) = value.checked_mul(10).and_then(|v| v.checked_add(*c as u64 - '0' as u64)) else {
+                return false; // too large
+            };
+            value = v;
+        }
+        valid_width && self.min <= value && value <= self.max
+    }
+    /// list the sequences of digit ranges which match the numbers (longer numbers first).
+    /// the leading zeros allowed by `:z` are not included
+    pub(crate) fn digit_sequences(&self) -> Vec<Vec<(char, char)>> {
+        if let Some(width) = self.width {
+            return digit_sequences(&format!("{:0width$}", self.min), &format!("{:0width$}", self.max));
+        }
+        // split the range by the number of digits
+        let mut sequences = vec![];
+        let digits = |n: u64| n.to_string().len() as u32;
+        for len in (digits(self.min)..=digits(self.max)).rev() {
+            let low = if len == 1 { 0 } else { 10u64.pow(len - 1) };
+            let high = 10u64.checked_pow(len).map_or(u64::MAX, |n| n - 1);
+            let (low, high) = (low.max(self.min), high.min(self.max));
+            sequences.extend(digit_sequences(&low.to_string(), &high.to_string()));
+        }
+        sequences
+    }
+}
+
 /// Pattern Character
 #[derive(Debug,Clone)]
 pub enum PatternChar {
@@ -92,144 +506,1266 @@ pub enum PatternChar {
     Number, // '#'
     Question, // '?'
     Wildcard, // '*'
-    CharList(Vec<CharRange>), // [charlist]
-    NotCharList(Vec<CharRange>), // [!charlist]
-    CharListRepeat(Vec<CharRange>), // [*charlist]
-    NotCharListRepeat(Vec<CharRange>), // [-charlist]
-    Selector(Vec<String>), // [=str1|str2|str3]
+    LazyWildcard, // [*?]
+    PossessiveWildcard, // [*+]
+    CharList(CharList), // [charlist]
+    NotCharList(CharList), // [!charlist]
+    CharListRepeat(CharList), // [+charlist]
+    CharListRepeatZero(CharList), // [*charlist]
+    CharListOptional(CharList), // [?charlist]
+    NotCharListRepeat(CharList), // [-charlist]
+    Selector(Vec<Vec<PatternChar>>), // [=pat1|pat2|pat3]
+    NotSelector(Vec<Vec<PatternChar>>), // [^=pat1|pat2|pat3]
+    NumberRange(NumberRange), // [<min-max>]
+    Start, // [^]
+    End, // [$]
 }
 
 impl PatternChar {
-    /// read character list
-    fn read_charlist(pattern_cur: &mut StrCursor) -> Vec<CharRange> {
-        let mut charlist = vec![];
-        let mut start_char;
-        let mut end_char;
-        loop {
+    /// read character list until ']', with set operations such as `a-z--aeiou`
+    fn read_charlist(pattern_cur: &mut StrCursor) -> CharList {
+        let items = PatternChar::read_charlist_items(pattern_cur);
+        let mut operations = vec![];
+        while !items.is_empty() {
+            let operation = match (pattern_cur.peek(), pattern_cur.peek_at(1)) {
+                ('-', '-') => SetOperation::Difference,
+                ('&', '&') => SetOperation::Intersection,
+                _ => break,
+            };
+            pattern_cur.index += 2; // skip the operator
+            let operand = if pattern_cur.peek() == '[' && pattern_cur.peek_at(1) != ':' {
+                pattern_cur.next(); // skip '['
+                PatternChar::read_charlist(pattern_cur) // nested list such as `[a-z--[aeiou]]`
+            } else {
+                CharList::new(PatternChar::read_charlist_items(pattern_cur))
+            };
+            operations.push((operation, operand));
+        }
+        pattern_cur.next(); // skip ']'
+        CharList::with_operations(items, operations)
+    }
+    /// read the items of a character list until ']' or a set operator
+    fn read_charlist_items(pattern_cur: &mut StrCursor) -> Vec<CharItem> {
+        let mut items = vec![];
+        let is_operator = |cur: &StrCursor| matches!((cur.peek(), cur.peek_at(1)), ('-', '-') | ('&', '&'));
+        while pattern_cur.has_next() {
+            if pattern_cur.peek() == ']' { break; } // '\]' is a literal
+            if !items.is_empty() && is_operator(pattern_cur) { break; }
+            if let Some(class) = PatternChar::read_class(pattern_cur) {
+                items.push(CharItem::Class(class));
+                continue;
+            }
+            if let Some(item) = PatternChar::read_property(pattern_cur) {
+                items.push(item);
+                continue;
+            }
             let c = pattern_cur.next_char_esc();
-            if c == ']' { break; }
-            // next char is '-' ?
-            if pattern_cur.peek() == '-' {
-                start_char = c;
+            // next char is '-' (but not an operator) ?
+            if pattern_cur.peek() == '-' && !is_operator(pattern_cur) {
                 pattern_cur.next(); // skip '-'
-                end_char = pattern_cur.next_char_esc();
-                if end_char == ']' {
-                    charlist.push(CharRange::new(start_char, start_char));
-                    charlist.push(CharRange::new(end_char, end_char));
+                if pattern_cur.peek() == ']' || !pattern_cur.has_next() { // '-' before ']' is a literal
+                    items.push(CharItem::Range(CharRange::new(c, c)));
+                    items.push(CharItem::Range(CharRange::new('-', '-')));
                     break;
                 }
-                charlist.push(CharRange::new(start_char, end_char));
+                let end = pattern_cur.next_char_esc();
+                items.push(CharItem::Range(CharRange::new(c, end)));
                 continue;
             }
-            charlist.push(CharRange::new(c, c));
+            items.push(CharItem::Range(CharRange::new(c, c)));
+        }
+        items
+    }
+    /// read a named class such as `[:alpha:]` in a character list
+    fn read_class(pattern_cur: &mut StrCursor) -> Option<CharClass> {
+        if pattern_cur.peek() != '[' || pattern_cur.peek_at(1) != ':' {
+            return None;
+        }
+        let mut name = String::new();
+        let mut offset = 2;
+        loop {
+            let c = pattern_cur.peek_at(offset);
+            if c == ':' && pattern_cur.peek_at(offset + 1) == ']' { break; }
+            if !c.is_ascii_alphanumeric() && c != '_' { return None; }
+            name.push(c);
+            offset += 1;
+        }
+        let Some(class) = CharClass::from_name(&name) else {
+            let error = PatternError::UnknownClass { position: pattern_cur.index, name };
+            pattern_cur.error.get_or_insert(error);
+            return None;
+        };
+        pattern_cur.index += offset + 2; // skip "[:name:]"
+        Some(class)
+    }
+    /// read a Unicode property such as `\p{L}`, `\P{L}` or `\p{^L}` in a character list
+    fn read_property(pattern_cur: &mut StrCursor) -> Option<CharItem> {
+        let upper = match (pattern_cur.peek(), pattern_cur.peek_at(1), pattern_cur.peek_at(2)) {
+            ('\\', 'p', '{') => false,
+            ('\\', 'P', '{') => true,
+            _ => return None,
+        };
+        let mut name = String::new();
+        let mut offset = 3;
+        loop {
+            let c = pattern_cur.peek_at(offset);
+            if c == '}' { break; }
+            if c == '\0' || c == ']' { return None; }
+            name.push(c);
+            offset += 1;
+        }
+        let (name, caret) = match name.strip_prefix('^') {
+            Some(name) => (name, true),
+            None => (name.as_str(), false),
+        };
+        let Some(property) = UnicodeProperty::from_name(name) else {
+            let error = PatternError::UnknownProperty { position: pattern_cur.index, name: name.to_string() };
+            pattern_cur.error.get_or_insert(error);
+            return None;
+        };
+        pattern_cur.index += offset + 1; // skip "\p{name}"
+        Some(CharItem::Property { property, negated: upper != caret })
+    }
+    /// read a number range such as `<0-255>]`, `<00-59>]` or `<0-255:z>]`
+    fn read_number_range(pattern_cur: &mut StrCursor) -> Option<NumberRange> {
+        let position = pattern_cur.index - 1;
+        let mut src = String::new();
+        let mut offset = 1;
+        loop {
+            let c = pattern_cur.peek_at(offset);
+            if c == '>' && pattern_cur.peek_at(offset + 1) == ']' { break; }
+            if c == '\0' || c == ']' {
+                pattern_cur.error.get_or_insert(PatternError::InvalidNumberRange { position });
+                return None;
+            }
+            src.push(c);
+            offset += 1;
+        }
+        let (bounds, leading_zeros) = match src.strip_suffix(":z") {
+            Some(bounds) => (bounds, true),
+            None => (src.as_str(), false),
+        };
+        let range = bounds.split_once('-').and_then(|(min_str, max_str)| {
+            let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+            if !is_digits(min_str) || !is_digits(max_str) {
+                return None;
+            }
+            let (min, max) = (min_str.parse::<u64>().ok()?, max_str.parse::<u64>().ok()?);
+            let width = if min_str.len() > 1 && min_str.starts_with('0') { Some(min_str.len()) } else { None };
+            if min > max || width.is_some_and(|width| max_str.len() > width) {
+                return None;
+            }
+            Some(NumberRange { min, max, width, leading_zeros })
+        });
+        match range {
+            Some(range) => {
+                pattern_cur.index += offset + 2; // skip "<min-max>]"
+                Some(range)
+            }
+            None => {
+                pattern_cur.error.get_or_insert(PatternError::InvalidNumberRange { position });
+                None
+            }
         }
-        charlist
     }
 }
 
+/// Options of matching
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MatchOptions {
+    /// fold full-width ASCII to half-width, and half-width katakana to full-width
+    pub fold_width: bool,
+    /// fold hiragana to katakana
+    pub fold_kana: bool,
+    /// normalize the pattern and the text before matching
+    pub normalization: Normalization,
+    /// match `?`, `#`, char lists and their repeat forms with extended grapheme clusters,
+    /// and let every match start and end at cluster boundaries
+    pub grapheme: bool,
+    /// let `[^]` and `[$]` also match at the start and the end of each line (before and after '\n')
+    pub multiline: bool,
+    /// choose the end of a match in `extract_match`, `find_match` and `find_iter` (`is_match` is not affected)
+    pub match_kind: MatchKind,
+}
+
+/// Unicode normalization form applied before matching
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Normalization {
+    #[default]
+    None,
+    /// canonical composition (`か` + U+3099 is `が`)
+    Nfc,
+    /// compatibility composition (also `ｶﾞ` is `ガ` and `①` is `1`)
+    Nfkc,
+}
+
+/// How to choose the end of a match starting at a position
+///
+/// The start is always the leftmost position where the pattern matches.
+/// With `LeftmostFirst`, every token tries its spans in this order and the first span
+/// that lets the rest of the pattern match is used:
+///
+/// - `*` and `[^=...]`: shortest first, but longest first if the token is the last one
+/// - `[*?]`: shortest first, also as the last token
+/// - `[*+]`: only the rest of the text (with any match kind)
+/// - `[+str]`, `[*str]`, `[-str]`: longest first
+/// - `[?str]`: one character first, then none
+/// - `[=aaa|bbb]`: the alternatives from left to right, each in its own order
+/// - `[<min-max>]`: a single span (the whole run of digits)
+/// - characters, `?`, `#`, `[str]`, `[!str]` and anchors: a single span
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MatchKind {
+    /// the shortest match among all the spans the tokens can take
+    Shortest,
+    /// the first match in the order of the tokens (`abc*g` stops at the first `g`)
+    #[default]
+    LeftmostFirst,
+    /// the longest match among all the spans the tokens can take (`abc*g` extends to the last `g`)
+    LeftmostLongest,
+}
+
+/// Status of a text which may be the start of a match (see `Pattern::prefix_status`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrefixStatus {
+    /// the text matches (more characters may still keep it matching)
+    Complete,
+    /// the text does not match yet, but some characters appended to it make a match
+    Viable,
+    /// no characters appended to the text make a match
+    Dead,
+}
+
 /// Pattern structure
 #[derive(Clone, Debug)]
 pub struct Pattern {
     pub pattern: Vec<PatternChar>,
+    options: MatchOptions,
 }
 #[allow(dead_code)]
 impl Pattern {
     pub fn new(pattern_str: &str) -> Self {
+        Pattern::new_with_options(pattern_str, MatchOptions::default())
+    }
+    /// create a pattern which matches with the options
+    pub fn new_with_options(pattern_str: &str, options: MatchOptions) -> Self {
         let mut pattern_cur = StrCursor::new(pattern_str);
+        Pattern::read(&mut pattern_cur, options, PatternRegistry::builtin())
+    }
+    /// create a pattern, or return the first error in it (see `PatternError`),
+    /// such as an invalid escape, an unknown class or property, an invalid `[<min-max>]` or an unknown reference.
+    /// `new` reads the invalid parts leniently instead
+    pub fn try_new(pattern_str: &str) -> Result<Self, PatternError> {
+        Pattern::try_new_with_options(pattern_str, MatchOptions::default())
+    }
+    /// create a pattern which matches with the options, or return the first error in it (see `try_new`)
+    pub fn try_new_with_options(pattern_str: &str, options: MatchOptions) -> Result<Self, PatternError> {
+        let mut pattern_cur = StrCursor::new(pattern_str);
+        let pattern = Pattern::read(&mut pattern_cur, options, PatternRegistry::builtin());
+        match pattern_cur.error {
+            Some(error) => Err(error),
+            None => Ok(pattern),
+        }
+    }
+    /// read a pattern whose `[@name]` refers to the registry
+    pub(crate) fn read(pattern_cur: &mut StrCursor, options: MatchOptions, registry: &PatternRegistry) -> Self {
+        let mut refs = References { registry, stack: vec![] };
+        let pattern = Pattern::read_pattern(pattern_cur, false, &mut refs);
+        let pattern = Pattern::prepare_pattern(pattern, &options);
+        Pattern {
+            pattern,
+            options,
+        }
+    }
+    /// read the definition of the name to find errors
+    pub(crate) fn read_definition(pattern_cur: &mut StrCursor, name: &str, registry: &PatternRegistry) {
+        let mut refs = References { registry, stack: vec![name.to_string()] };
+        Pattern::read_pattern(pattern_cur, false, &mut refs);
+    }
+    /// get the options of matching
+    pub fn options(&self) -> &MatchOptions {
+        &self.options
+    }
+    /// normalize and fold the literals of the pattern in the same way as the text
+    fn prepare_pattern(pattern: Vec<PatternChar>, options: &MatchOptions) -> Vec<PatternChar> {
+        if !fold::is_enabled(options) && options.normalization == Normalization::None {
+            return pattern;
+        }
+        let prepare_literal = |literal: &[char]| {
+            let normalized = normalize::normalize(literal, options.normalization);
+            fold::fold_chars(&normalized, options).0
+        };
+        let mut result = vec![];
+        let mut literal = vec![];
+        for token in pattern {
+            if let PatternChar::Char(c) = token {
+                literal.push(c);
+                continue;
+            }
+            result.extend(prepare_literal(&literal).into_iter().map(PatternChar::Char));
+            literal.clear();
+            result.push(match token {
+                PatternChar::Selector(selector) => PatternChar::Selector(
+                    selector.into_iter().map(|sub_pattern| Pattern::prepare_pattern(sub_pattern, options)).collect(),
+                ),
+                PatternChar::NotSelector(selector) => PatternChar::NotSelector(
+                    selector.into_iter().map(|sub_pattern| Pattern::prepare_pattern(sub_pattern, options)).collect(),
+                ),
+                token => token,
+            });
+        }
+        result.extend(prepare_literal(&literal).into_iter().map(PatternChar::Char));
+        result
+    }
+    /// prepare the text for matching with the options
+    fn prepare(&self, text: &str) -> PreparedText {
+        let original = text.chars().collect::<Vec<char>>();
+        let (normalized, normalized_origin) = normalize::normalize_chars(&original, self.options.normalization);
+        let (chars, origin) = if fold::is_enabled(&self.options) {
+            let (folded, folded_origin) = fold::fold_chars(&normalized, &self.options);
+            (folded, folded_origin.into_iter().map(|i| normalized_origin[i]).collect())
+        } else {
+            (normalized, normalized_origin)
+        };
+        // a position inside the expansion of an original character ends after the character
+        let mut end_origin = origin.clone();
+        for i in (1..chars.len()).rev() {
+            if origin[i] == origin[i - 1] {
+                end_origin[i] = end_origin[i + 1];
+            }
+        }
+        PreparedText { original, chars, origin, end_origin }
+    }
+    /// read pattern characters (in a selector, stop before '|' or ']')
+    fn read_pattern(pattern_cur: &mut StrCursor, in_selector: bool, refs: &mut References) -> Vec<PatternChar> {
         let mut pattern = vec![];
         while pattern_cur.has_next() {
+            if in_selector && (pattern_cur.peek() == '|' || pattern_cur.peek() == ']') {
+                break;
+            }
             let c = pattern_cur.next();
             match c {
                 '#' => pattern.push(PatternChar::Number),
                 '?' => pattern.push(PatternChar::Question),
                 '*' => pattern.push(PatternChar::Wildcard),
                 '\\' => { // escape
-                    let c = pattern_cur.next();
-                    pattern.push(PatternChar::Char(c));
+                    pattern_cur.index -= 1; // read the escape sequence from '\'
+                    pattern.push(PatternChar::Char(pattern_cur.next_char_esc()));
                 },
                 '[' => {
                     let c = pattern_cur.peek();
                     match c {
                         '!' => {
                             pattern_cur.next(); // skip '!'
-                            let charlist = PatternChar::read_charlist(&mut pattern_cur);
+                            let charlist = PatternChar::read_charlist(pattern_cur);
                             pattern.push(PatternChar::NotCharList(charlist));
                         },
                         '+' => {
-                            pattern_cur.next(); // skip '*'
-                            let charlist = PatternChar::read_charlist(&mut pattern_cur);
+                            pattern_cur.next(); // skip '+'
+                            let charlist = PatternChar::read_charlist(pattern_cur);
                             pattern.push(PatternChar::CharListRepeat(charlist));
                         },
+                        '*' if matches!(pattern_cur.peek_at(1), '?' | '+') && pattern_cur.peek_at(2) == ']' => {
+                            pattern_cur.next(); // skip '*'
+                            let kind = pattern_cur.next();
+                            pattern_cur.next(); // skip ']'
+                            pattern.push(if kind == '?' { PatternChar::LazyWildcard } else { PatternChar::PossessiveWildcard });
+                        },
+                        '*' => {
+                            pattern_cur.next(); // skip '*'
+                            let charlist = PatternChar::read_charlist(pattern_cur);
+                            pattern.push(PatternChar::CharListRepeatZero(charlist));
+                        },
+                        '?' => {
+                            pattern_cur.next(); // skip '?'
+                            let charlist = PatternChar::read_charlist(pattern_cur);
+                            pattern.push(PatternChar::CharListOptional(charlist));
+                        },
                         '-' => {
                             pattern_cur.next(); // skip '-'
-                            let charlist = PatternChar::read_charlist(&mut pattern_cur);
+                            let charlist = PatternChar::read_charlist(pattern_cur);
                             pattern.push(PatternChar::NotCharListRepeat(charlist));
                         },
                         '=' => {
                             pattern_cur.next(); // skip '='
-                            let mut selector = vec![];
-                            while pattern_cur.has_next() {
-                                let mut str = String::new();
-                                let mut c = '\0';
-                                while pattern_cur.has_next() {
-                                    c = pattern_cur.next();
-                                    if c == '|' || c == ']' { break; }
-                                    if c == '\\' { c = pattern_cur.next(); }
-                                    str.push(c);
-                                }
-                                selector.push(str);
-                                if c == ']' { break; }
-                            }
+                            let selector = Pattern::read_selector(pattern_cur, refs);
                             pattern.push(PatternChar::Selector(selector));
                         }
+                        '^' | '$' if pattern_cur.peek_at(1) == ']' => {
+                            pattern_cur.next(); // skip '^' or '$'
+                            pattern_cur.next(); // skip ']'
+                            pattern.push(if c == '^' { PatternChar::Start } else { PatternChar::End });
+                        }
+                        '^' if pattern_cur.peek_at(1) == '=' => {
+                            pattern_cur.next(); // skip '^'
+                            pattern_cur.next(); // skip '='
+                            let selector = Pattern::read_selector(pattern_cur, refs);
+                            pattern.push(PatternChar::NotSelector(selector));
+                        }
+                        '@' => match Pattern::read_reference(pattern_cur, refs) {
+                            Some(sub_pattern) => pattern.extend(sub_pattern),
+                            None => { // read as a character list
+                                let charlist = PatternChar::read_charlist(pattern_cur);
+                                pattern.push(PatternChar::CharList(charlist));
+                            }
+                        },
+                        '<' => match PatternChar::read_number_range(pattern_cur) {
+                            Some(range) => pattern.push(PatternChar::NumberRange(range)),
+                            None => { // read as a character list
+                                let charlist = PatternChar::read_charlist(pattern_cur);
+                                pattern.push(PatternChar::CharList(charlist));
+                            }
+                        },
                         _ => {
-                            let charlist = PatternChar::read_charlist(&mut pattern_cur);
+                            let charlist = PatternChar::read_charlist(pattern_cur);
                             pattern.push(PatternChar::CharList(charlist));
                         }
                     }
                 }
-                _ => pattern.push(PatternChar::Char(c)),
+                _ => pattern.push(PatternChar::Char(c)),
+            }
+        }
+        pattern
+    }
+    /// read the alternatives of a selector
+    fn read_selector(pattern_cur: &mut StrCursor, refs: &mut References) -> Vec<Vec<PatternChar>> {
+        let mut selector = vec![];
+        loop {
+            selector.push(Pattern::read_pattern(pattern_cur, true, refs));
+            if pattern_cur.next() != '|' { break; } // ']' or end of pattern
+        }
+        selector
+    }
+    /// read a reference such as `@zip]` and expand it into the defined pattern
+    fn read_reference(pattern_cur: &mut StrCursor, refs: &mut References) -> Option<Vec<PatternChar>> {
+        let position = pattern_cur.index - 1;
+        let mut name = String::new();
+        let mut offset = 1;
+        loop {
+            let c = pattern_cur.peek_at(offset);
+            if c == ']' { break; }
+            if c == '\0' { return None; }
+            name.push(c);
+            offset += 1;
+        }
+        if !registry::is_valid_name(&name) {
+            return None; // such as `[@.]`
+        }
+        let error = if refs.stack.contains(&name) {
+            PatternError::CyclicReference { position, name }
+        } else if let Some(definition) = refs.registry.get(&name) {
+            let mut definition_cur = StrCursor::new(definition);
+            refs.stack.push(name);
+            let sub_pattern = Pattern::read_pattern(&mut definition_cur, false, refs);
+            refs.stack.pop();
+            match definition_cur.error {
+                None => {
+                    pattern_cur.index += offset + 1; // skip "@name]"
+                    return Some(sub_pattern);
+                }
+                // report the error in the definition at this reference
+                Some(PatternError::UnknownReference { name, .. }) => PatternError::UnknownReference { position, name },
+                Some(PatternError::CyclicReference { name, .. }) => PatternError::CyclicReference { position, name },
+                Some(error) => error,
+            }
+        } else {
+            PatternError::UnknownReference { position, name }
+        };
+        pattern_cur.error.get_or_insert(error);
+        None
+    }
+    /// translate the pattern into a regular expression which matches the same whole strings.
+    /// returns None if the pattern uses `[^=...]`, or `[<min-max>]` next to a token which may match a digit,
+    /// which have no regular expression equivalent.
+    pub fn to_regex(&self) -> Option<String> {
+        Some(format!("^(?s:{})$", regex_seq(&self.pattern, self.options.multiline, false, false)?))
+    }
+    /// check if the pattern matches the text
+    pub fn is_match(&self, text: &str) -> bool {
+        let text = self.prepare(text);
+        let mut matcher = Matcher::new(&text.chars, &self.options);
+        matcher.match_seq(&self.pattern, 0, &mut |end| end == text.chars.len())
+    }
+    /// check if the pattern matches the text list
+    pub fn is_match_all(&self, str_list: &[String]) -> Vec<bool> {
+        let mut result = vec![];
+        for text in str_list {
+            result.push(self.is_match(text));
+        }
+        result
+    }
+    /// filter the text list by the pattern
+    pub fn filter(&self, str_list: &[String]) -> Vec<String> {
+        let mut result = vec![];
+        for text in str_list {
+            if self.is_match(text) {
+                result.push(text.clone());
+            }
+        }
+        result
+    }
+    /// extract matched text from the beginning of string
+    pub fn extract_match(&self, text: &str) -> Option<String> {
+        let text = self.prepare(text);
+        let end = Matcher::new(&text.chars, &self.options).best_end(&self.pattern, 0)?;
+        Some(text.to_result(0, end).matched)
+    }
+    /// find a matching substring from the entire string.
+    pub fn find_match(&self, text: &str) -> Option<MatchedResult> {
+        let text = self.prepare(text);
+        let (start, end) = find_range(&self.pattern, &text.chars, &self.options, 0)?;
+        Some(text.to_result(start, end))
+    }
+    /// iterate over the successive non-overlapping matches from the beginning of the string.
+    pub fn find_iter(&self, text: &str) -> Matches<'_> {
+        self.find_iter_from(text, 0)
+    }
+    /// iterate over the matches starting at the character `from` or later,
+    /// the characters before it are only seen by `[^]` and `[<min-max>]`
+    pub(crate) fn find_iter_from(&self, text: &str, from: usize) -> Matches<'_> {
+        let text = self.prepare(text);
+        let pos = text.origin.iter().position(|&o| o >= from).unwrap_or(text.chars.len());
+        Matches {
+            pattern: self,
+            reversed: None,
+            boundaries: text_boundaries(&text.chars, &self.options, false),
+            text,
+            pos,
+            last_end: None,
+            last_result: None,
+            memo: Memo::default(),
+        }
+    }
+    /// split the string by the matches of the pattern
+    pub fn split<'t>(&self, text: &'t str) -> Split<'_, 't> {
+        Split::new(self, text, None, false)
+    }
+    /// split the string by the matches of the pattern into at most `n` pieces (the last one is the rest)
+    pub fn splitn<'t>(&self, text: &'t str, n: usize) -> Split<'_, 't> {
+        Split::new(self, text, Some(n), false)
+    }
+    /// split the string after the matches of the pattern, keeping them at the end of the pieces
+    pub fn split_inclusive<'t>(&self, text: &'t str) -> Split<'_, 't> {
+        Split::new(self, text, None, true)
+    }
+    /// find the rightmost matching substring (the match which ends last) from the entire string.
+    /// the pattern is reversed and matched from the end, so the match kind chooses the start
+    pub fn rfind_match(&self, text: &str) -> Option<MatchedResult> {
+        self.rfind_iter(text).next()
+    }
+    /// iterate over the successive non-overlapping matches from the end of the string.
+    pub fn rfind_iter(&self, text: &str) -> Matches<'_> {
+        let mut text = self.prepare(text);
+        text.chars.reverse();
+        Matches {
+            pattern: self,
+            reversed: Some(reverse_pattern(&self.pattern)),
+            boundaries: text_boundaries(&text.chars, &self.options, true),
+            text,
+            pos: 0,
+            last_end: None,
+            last_result: None,
+            memo: Memo::default(),
+        }
+    }
+    /// find the longest matching substring which ends at the end of the string.
+    pub fn match_suffix(&self, text: &str) -> Option<MatchedResult> {
+        let text = self.prepare(text);
+        let len = text.chars.len();
+        let mut matcher = Matcher::new(&text.chars, &self.options);
+        let start = (0..=len).find(|&start| {
+            matcher.is_boundary(start) && matcher.match_seq(&self.pattern, start, &mut |end| end == len)
+        })?;
+        Some(text.to_result(start, len))
+    }
+    /// check if the text matches, or can become a match by appending characters (for validating input as it is typed).
+    /// the grapheme option is not taken into account
+    pub fn prefix_status(&self, text: &str) -> PrefixStatus {
+        let nfa = Nfa::new(&self.pattern, &self.options);
+        let position = self.read_prefix(&nfa, text);
+        if nfa.is_accepting(&position) {
+            PrefixStatus::Complete
+        } else if nfa.can_accept(&position, &automaton::alphabet(&[&nfa])) {
+            PrefixStatus::Viable
+        } else {
+            PrefixStatus::Dead
+        }
+    }
+    /// get the characters which can follow the text on the way to a match
+    /// (empty if the text is dead, `None` if any character can follow).
+    /// the characters are normalized and folded with the options
+    pub fn next_chars(&self, text: &str) -> Option<Vec<CharRange>> {
+        let nfa = Nfa::new(&self.pattern, &self.options);
+        let position = self.read_prefix(&nfa, text);
+        let alphabet = automaton::alphabet(&[&nfa]);
+        let mut ranges = vec![];
+        if !position.is_dead() {
+            for &block in &alphabet {
+                let next = nfa.step(&position, automaton::representative(block));
+                if !next.is_dead() && nfa.can_accept(&next, &alphabet) {
+                    ranges.push(block);
+                }
+            }
+        }
+        let ranges = union_ranges(ranges);
+        if complement_ranges(&ranges).is_empty() {
+            return None;
+        }
+        Some(ranges.into_iter()
+            .filter_map(|(start, end)| Some(CharRange::new(char::from_u32(start)?, char::from_u32(end)?)))
+            .collect())
+    }
+    /// fill the variable positions (`#`, `?`, `[...]` and `[!...]`) of an input mask such as `(###)###-####`
+    /// with the raw characters and insert the literals between them.
+    /// returns None if a character is not accepted, the number of characters differs,
+    /// or the pattern has other tokens such as `*` and `[=...]`
+    pub fn format_input(&self, raw: &str) -> Option<String> {
+        if !self.is_mask() {
+            return None;
+        }
+        let mut raw = raw.chars();
+        let mut result = String::new();
+        for token in &self.pattern {
+            match token {
+                PatternChar::Char(c) => result.push(*c),
+                PatternChar::Start | PatternChar::End => {}
+                _ => {
+                    let c = raw.next().filter(|&c| self.accepts_char(token, c))?;
+                    result.push(c);
+                }
+            }
+        }
+        match raw.next() {
+            Some(_) => None,
+            None => Some(result),
+        }
+    }
+    /// remove the literals of an input mask from the formatted text (the inverse of `format_input`).
+    /// returns None if the text does not fit the mask
+    pub fn strip_literals(&self, text: &str) -> Option<String> {
+        if !self.is_mask() {
+            return None;
+        }
+        let mut chars = text.chars();
+        let mut raw = String::new();
+        for token in &self.pattern {
+            match token {
+                PatternChar::Char(c) => {
+                    chars.next().filter(|&ch| fold::fold_char(ch, &self.options) == *c)?;
+                }
+                PatternChar::Start | PatternChar::End => {}
+                _ => {
+                    let c = chars.next().filter(|&c| self.accepts_char(token, c))?;
+                    raw.push(c);
+                }
+            }
+        }
+        match chars.next() {
+            Some(_) => None,
+            None => Some(raw),
+        }
+    }
+    /// generate a string which the pattern matches at random (the same seed gives the same string).
+    /// `*` and the repeated character lists take at most 3 characters.
+    /// returns None if no matching string is found
+    pub fn sample(&self, seed: u64) -> Option<String> {
+        let mut pick = Pick::Random(Rng::new(seed));
+        (0..sample::MAX_TRIES)
+            .filter_map(|_| sample::generate(&self.pattern, &mut pick))
+            .find(|text| self.is_match(text))
+    }
+    /// generate up to `n` different strings which the pattern matches, always in the same order:
+    /// the shortest one, one for each alternative of the selectors, the upper bounds of the ranges,
+    /// the longest repetitions, and then random ones
+    pub fn examples(&self, n: usize) -> Vec<String> {
+        let alternatives = sample::max_alternatives(&self.pattern).max(2);
+        let variants = (0..alternatives).map(|index| Pick::Variant { index, long: false })
+            .chain((0..2).map(|index| Pick::Variant { index, long: true }))
+            .chain((0..sample::MAX_TRIES).map(|seed| Pick::Random(Rng::new(seed))));
+        let mut examples: Vec<String> = vec![];
+        for mut pick in variants {
+            if examples.len() >= n {
+                break;
+            }
+            if let Some(text) = sample::generate(&self.pattern, &mut pick) {
+                if self.is_match(&text) && !examples.contains(&text) {
+                    examples.push(text);
+                }
+            }
+        }
+        examples
+    }
+    /// list every string which the pattern matches, or None if there are infinitely many
+    /// (the pattern has `*`, `?`, `[!...]`, `[+...]`, `[*...]`, `[-...]` or `[^=...]`).
+    /// the strings are listed lazily in the order of the tokens, such as `a1`, `a2`, `b1`, `b2` for `[=a|b][1-2]`,
+    /// and each string only once (the listed strings are kept to skip the duplicates such as `[=a|a]`)
+    pub fn expand(&self) -> Option<impl Iterator<Item = String> + '_> {
+        if !expand::is_finite(&self.pattern) {
+            return None;
+        }
+        let strings = expand::strings(std::rc::Rc::new(self.pattern.clone()), 0);
+        let mut seen = HashSet::new();
+        // anchors in the middle of the pattern may reject a string
+        Some(strings.filter(move |text| self.is_match(text) && seen.insert(text.clone())))
+    }
+    /// list every string which the pattern matches, or None if there are infinitely many or more than `limit`
+    pub fn expand_with_limit(&self, limit: usize) -> Option<Vec<String>> {
+        if self.expand_count()? > limit as u128 {
+            return None;
+        }
+        Some(self.expand()?.collect())
+    }
+    /// count the strings `expand` lists without listing them, or None if there are infinitely many.
+    /// this is an upper bound because alternatives such as `[=a|a]` are counted separately
+    pub fn expand_count(&self) -> Option<u128> {
+        if !expand::is_finite(&self.pattern) {
+            return None;
+        }
+        Some(expand::count(&self.pattern))
+    }
+    /// check if the pattern matches no string, such as `a[^]b`
+    pub fn is_empty(&self) -> bool {
+        let nfa = Nfa::new(&self.pattern, &self.options);
+        automaton::find_text(&[&nfa], |accepts| accepts[0]).is_none()
+    }
+    /// check if every string this pattern matches is also matched by the other pattern.
+    ///
+    /// The patterns are compared as sets of prepared (normalized and folded) texts,
+    /// so both patterns must be created with the same `normalization`, `fold_width`, `fold_kana`
+    /// and `multiline` options; otherwise the result is meaningless.
+    /// The grapheme option is not taken into account.
+    /// The same applies to `overlaps` and `is_equivalent`.
+    pub fn is_subset_of(&self, other: &Pattern) -> bool {
+        let (nfa, other_nfa) = (Nfa::new(&self.pattern, &self.options), Nfa::new(&other.pattern, &other.options));
+        automaton::find_text(&[&nfa, &other_nfa], |accepts| accepts[0] && !accepts[1]).is_none()
+    }
+    /// find the shortest string which both patterns match, or None if they do not overlap.
+    /// the string is a prepared text, and both patterns must have the same options (see `is_subset_of`)
+    pub fn overlaps(&self, other: &Pattern) -> Option<String> {
+        let (nfa, other_nfa) = (Nfa::new(&self.pattern, &self.options), Nfa::new(&other.pattern, &other.options));
+        automaton::find_text(&[&nfa, &other_nfa], |accepts| accepts[0] && accepts[1])
+    }
+    /// check if both patterns match the same strings (both patterns must have the same options, see `is_subset_of`)
+    pub fn is_equivalent(&self, other: &Pattern) -> bool {
+        self.is_subset_of(other) && other.is_subset_of(self)
+    }
+    /// check if the pattern consists of literals and tokens of a single character
+    fn is_mask(&self) -> bool {
+        self.pattern.iter().all(|token| matches!(token,
+            PatternChar::Char(_) | PatternChar::Number | PatternChar::Question |
+            PatternChar::CharList(_) | PatternChar::NotCharList(_) | PatternChar::Start | PatternChar::End
+        ))
+    }
+    /// check if the token of a single character accepts the character
+    fn accepts_char(&self, token: &PatternChar, c: char) -> bool {
+        let chars = [fold::fold_char(c, &self.options)];
+        Matcher::new(&chars, &self.options).match_seq(std::slice::from_ref(token), 0, &mut |end| end == 1)
+    }
+    /// read the prepared text with the NFA of the pattern
+    fn read_prefix(&self, nfa: &Nfa, text: &str) -> automaton::Position {
+        let mut position = nfa.initial();
+        for &c in &self.prepare(text).chars {
+            if position.is_dead() {
+                break;
+            }
+            position = nfa.step(&position, c);
+        }
+        position
+    }
+}
+
+/// Iterator over the successive non-overlapping matches of a pattern
+/// (see `Pattern::find_iter` and `Pattern::rfind_iter`)
+pub struct Matches<'p> {
+    pattern: &'p Pattern,
+    /// the reversed pattern to match from the end (`text.chars` is also reversed)
+    reversed: Option<Vec<PatternChar>>,
+    text: PreparedText,
+    /// position in the prepared (or reversed) text to search from
+    pos: usize,
+    /// end of the last match
+    last_end: Option<usize>,
+    /// range of the last match in the original text
+    last_result: Option<(usize, usize)>,
+    /// grapheme cluster boundaries of `text.chars`
+    boundaries: Vec<bool>,
+    /// what the matcher remembers between the searches
+    memo: Memo,
+}
+impl Iterator for Matches<'_> {
+    type Item = MatchedResult;
+    fn next(&mut self) -> Option<MatchedResult> {
+        let chars = &self.text.chars;
+        let options = &self.pattern.options;
+        while self.pos <= chars.len() {
+            // the matcher is set up once for the text, and its memo is kept for the next search
+            let mut matcher = Matcher {
+                text: chars,
+                options,
+                memo: std::mem::take(&mut self.memo),
+                boundaries: Cow::Borrowed(&self.boundaries),
+                reversed: self.reversed.is_some(),
+            };
+            let range = search(&mut matcher, self.reversed.as_deref().unwrap_or(&self.pattern.pattern), self.pos);
+            self.memo = matcher.memo;
+            let Some((start, end)) = range else {
+                self.pos = chars.len() + 1;
+                return None;
+            };
+            self.pos = if start == end { end + 1 } else { end };
+            // skip an empty match right after the last match
+            if start == end && self.last_end == Some(start) {
+                continue;
+            }
+            self.last_end = Some(end);
+            let result = match self.reversed {
+                Some(_) => self.text.to_result(chars.len() - end, chars.len() - start),
+                None => self.text.to_result(start, end),
+            };
+            // matches inside the expansion of one original character (by normalization) give the same range
+            if let Some((last_start, last_end)) = self.last_result {
+                let same = (result.start, result.end) == (last_start, last_end);
+                if same || (result.start < last_end && last_start < result.end) {
+                    continue;
+                }
+            }
+            self.last_result = Some((result.start, result.end));
+            return Some(result);
+        }
+        None
+    }
+}
+
+/// Iterator over the pieces of a string split by the matches of a pattern
+/// (see `Pattern::split`, `Pattern::splitn` and `Pattern::split_inclusive`)
+pub struct Split<'p, 't> {
+    text: &'t str,
+    matches: Matches<'p>,
+    /// byte offset of each character, followed by the length of the text
+    offsets: Vec<usize>,
+    /// index of the character where the next piece starts (None after the last piece)
+    start: Option<usize>,
+    /// number of the pieces which can still be returned (None for no limit)
+    limit: Option<usize>,
+    /// keep the matches at the end of the pieces
+    inclusive: bool,
+}
+impl<'p, 't> Split<'p, 't> {
+    fn new(pattern: &'p Pattern, text: &'t str, limit: Option<usize>, inclusive: bool) -> Self {
+        Split {
+            text,
+            matches: pattern.find_iter(text),
+            offsets: text.char_indices().map(|(i, _)| i).chain(std::iter::once(text.len())).collect(),
+            start: Some(0),
+            limit,
+            inclusive,
+        }
+    }
+}
+impl<'t> Iterator for Split<'_, 't> {
+    type Item = &'t str;
+    fn next(&mut self) -> Option<&'t str> {
+        let start = self.start?;
+        if let Some(limit) = self.limit.as_mut() {
+            if *limit <= 1 {
+                self.start = None;
+                return if *limit == 1 { Some(&self.text[self.offsets[start]..]) } else { None };
+            }
+            *limit -= 1;
+        }
+        match self.matches.next() {
+            Some(m) => {
+                let end = if self.inclusive { m.end } else { m.start };
+                self.start = Some(m.end);
+                Some(&self.text[self.offsets[start]..self.offsets[end]])
+            }
+            None => {
+                self.start = None;
+                let rest = &self.text[self.offsets[start]..];
+                if self.inclusive && rest.is_empty() { None } else { Some(rest) }
+            }
+        }
+    }
+}
+
+/// `[@name]` references being expanded while reading a pattern
+struct References<'a> {
+    registry: &'a PatternRegistry,
+    /// names of the definitions being expanded
+    stack: Vec<String>,
+}
+
+/// Text prepared for matching with the options
+struct PreparedText {
+    original: Vec<char>,
+    chars: Vec<char>,
+    /// index in the original text of each character, followed by the length of the original text
+    origin: Vec<usize>,
+    /// index in the original text where a match ending at each position ends
+    /// (after the original character if the position is inside its expansion)
+    end_origin: Vec<usize>,
+}
+impl PreparedText {
+    /// convert a range of the prepared text into the result in the original text
+    fn to_result(&self, start: usize, end: usize) -> MatchedResult {
+        let (start, end) = if start == end {
+            (self.origin[start], self.origin[start])
+        } else {
+            (self.origin[start], self.end_origin[end])
+        };
+        MatchedResult::new(start, end, self.original[start..end].iter().collect())
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.pattern {
+            write!(f, "{}", token)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PatternChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternChar::Char(c) => {
+                if let Some(esc) = control_escape(*c) {
+                    return write!(f, "{}", esc);
+                }
+                if "*?#[]|\\".contains(*c) {
+                    write!(f, "\\")?;
+                }
+                write!(f, "{}", c)
+            }
+            PatternChar::Number => write!(f, "#"),
+            PatternChar::Question => write!(f, "?"),
+            PatternChar::Wildcard => write!(f, "*"),
+            PatternChar::LazyWildcard => write!(f, "[*?]"),
+            PatternChar::PossessiveWildcard => write!(f, "[*+]"),
+            PatternChar::CharList(charlist) => fmt_charlist(f, "", charlist),
+            PatternChar::NotCharList(charlist) => fmt_charlist(f, "!", charlist),
+            PatternChar::CharListRepeat(charlist) => fmt_charlist(f, "+", charlist),
+            PatternChar::CharListRepeatZero(charlist) => fmt_charlist(f, "*", charlist),
+            PatternChar::CharListOptional(charlist) => fmt_charlist(f, "?", charlist),
+            PatternChar::NotCharListRepeat(charlist) => fmt_charlist(f, "-", charlist),
+            PatternChar::Selector(selector) => fmt_selector(f, "=", selector),
+            PatternChar::NotSelector(selector) => fmt_selector(f, "^=", selector),
+            PatternChar::NumberRange(range) => {
+                let width = range.width.unwrap_or(0);
+                write!(f, "[<{:0width$}-{}{}>]", range.min, range.max, if range.leading_zeros { ":z" } else { "" })
+            }
+            PatternChar::Start => write!(f, "[^]"),
+            PatternChar::End => write!(f, "[$]"),
+        }
+    }
+}
+
+fn fmt_charlist(f: &mut fmt::Formatter<'_>, prefix: &str, charlist: &CharList) -> fmt::Result {
+    write!(f, "[{}", prefix)?;
+    // a leading mark would be read as the kind of the list (or `[*?]` and `[*+]`)
+    let marks = match prefix {
+        "" => "!+*?-=^$@<",
+        "*" => "?+",
+        _ => "",
+    };
+    fmt_charlist_items(f, marks, charlist)?;
+    write!(f, "]")
+}
+
+fn fmt_charlist_items(f: &mut fmt::Formatter<'_>, marks: &str, charlist: &CharList) -> fmt::Result {
+    for (i, item) in charlist.items.iter().enumerate() {
+        match item {
+            CharItem::Range(range) => {
+                if i == 0 && marks.contains(range.start) {
+                    write!(f, "\\")?;
+                }
+                fmt_charlist_char(f, range.start)?;
+                if range.start != range.end {
+                    write!(f, "-")?;
+                    fmt_charlist_char(f, range.end)?;
+                }
+            }
+            CharItem::Class(class) => write!(f, "[:{}:]", class.name())?,
+            CharItem::Property { property, negated } => {
+                write!(f, "\\{}{{{}}}", if *negated { 'P' } else { 'p' }, property.name())?
+            }
+        }
+    }
+    for (operation, operand) in &charlist.operations {
+        write!(f, "{}", if *operation == SetOperation::Difference { "--" } else { "&&" })?;
+        if operand.operations.is_empty() {
+            fmt_charlist_items(f, "", operand)?;
+        } else {
+            fmt_charlist(f, "", operand)?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_charlist_char(f: &mut fmt::Formatter<'_>, c: char) -> fmt::Result {
+    match c {
+        '[' | ']' | '\\' | '-' | '&' => write!(f, "\\{}", c),
+        _ => match control_escape(c) {
+            Some(esc) => write!(f, "{}", esc),
+            None => write!(f, "{}", c),
+        },
+    }
+}
+
+/// get the escape sequence of a control character
+fn control_escape(c: char) -> Option<&'static str> {
+    match c {
+        '\t' => Some("\\t"),
+        '\n' => Some("\\n"),
+        '\r' => Some("\\r"),
+        '\0' => Some("\\0"),
+        _ => None,
+    }
+}
+
+fn fmt_selector(f: &mut fmt::Formatter<'_>, prefix: &str, selector: &[Vec<PatternChar>]) -> fmt::Result {
+    write!(f, "[{}", prefix)?;
+    for (i, sub_pattern) in selector.iter().enumerate() {
+        if i > 0 {
+            write!(f, "|")?;
+        }
+        for token in sub_pattern {
+            write!(f, "{}", token)?;
+        }
+    }
+    write!(f, "]")
+}
+
+/// translate the pattern characters into a regular expression
+/// (`digit_before` and `digit_after` tell if the text around the sequence may have a digit next to it)
+fn regex_seq(pattern: &[PatternChar], multiline: bool, digit_before: bool, digit_after: bool) -> Option<String> {
+    let mut re = String::new();
+    for (i, token) in pattern.iter().enumerate() {
+        let before = || may_have_digit(pattern[..i].iter().rev(), true, digit_before);
+        let after = || may_have_digit(pattern[i + 1..].iter(), false, digit_after);
+        match token {
+            PatternChar::Char(c) => push_regex_char(&mut re, *c, "\\.+*?()|[]{}^$#&-~"),
+            PatternChar::Number => re.push_str("[0-9]"),
+            PatternChar::Question => re.push('.'),
+            PatternChar::Wildcard => {
+                // '*' is lazy unless it is the last token
+                re.push_str(if i + 1 == pattern.len() { ".*" } else { ".*?" });
+            }
+            PatternChar::LazyWildcard => re.push_str(".*?"),
+            // it takes the rest of the text, so the following tokens must match at the end
+            PatternChar::PossessiveWildcard => re.push_str(".*$"),
+            PatternChar::CharList(charlist) => push_regex_class(&mut re, charlist, false),
+            PatternChar::NotCharList(charlist) => push_regex_class(&mut re, charlist, true),
+            PatternChar::CharListRepeat(charlist) => {
+                push_regex_class(&mut re, charlist, false);
+                re.push('+');
+            }
+            PatternChar::CharListRepeatZero(charlist) => {
+                push_regex_class(&mut re, charlist, false);
+                re.push('*');
+            }
+            PatternChar::CharListOptional(charlist) => {
+                push_regex_class(&mut re, charlist, false);
+                re.push('?');
+            }
+            PatternChar::NotCharListRepeat(charlist) => {
+                push_regex_class(&mut re, charlist, true);
+                re.push('+');
+            }
+            PatternChar::Selector(selector) => {
+                let mut alternatives = vec![];
+                for sub_pattern in selector {
+                    alternatives.push(regex_seq(sub_pattern, multiline, before(), after())?);
+                }
+                re.push_str(&format!("(?:{})", alternatives.join("|")));
+            }
+            PatternChar::NotSelector(_) => return None,
+            PatternChar::NumberRange(range) => {
+                // a regular expression cannot check that the number is not a part of a longer run of digits
+                if before() || after() {
+                    return None;
+                }
+                re.push_str(&regex_number_range(range));
+            }
+            PatternChar::Start => re.push_str(if multiline { "(?m:^)" } else { "^" }),
+            PatternChar::End => re.push_str(if multiline { "(?m:$)" } else { "$" }),
+        }
+    }
+    Some(re)
+}
+
+/// check if the tokens may put a digit next to the token before them
+/// (`at_end` if they are in reverse order before the token, `default` if they may match nothing)
+fn may_have_digit<'a>(mut tokens: impl Iterator<Item = &'a PatternChar>, at_end: bool, default: bool) -> bool {
+    let any_digit = |charlist: &CharList| ('0'..='9').any(|c| charlist.contains(c));
+    tokens.find_map(|token| {
+        let (digit, may_be_empty) = match token {
+            PatternChar::Char(c) => (c.is_ascii_digit(), false),
+            PatternChar::CharList(charlist) => (any_digit(charlist), false),
+            PatternChar::CharListRepeat(charlist) => (any_digit(charlist), false),
+            PatternChar::CharListRepeatZero(charlist) | PatternChar::CharListOptional(charlist) => {
+                (any_digit(charlist), true)
+            }
+            PatternChar::NotCharList(charlist) | PatternChar::NotCharListRepeat(charlist) => {
+                (!('0'..='9').all(|c| charlist.contains(c)), false)
+            }
+            PatternChar::Wildcard | PatternChar::LazyWildcard | PatternChar::PossessiveWildcard => (true, true),
+            PatternChar::Start | PatternChar::End => (false, true),
+            PatternChar::Selector(selector) => {
+                let digit = selector.iter().any(|sub_pattern| if at_end {
+                    may_have_digit(sub_pattern.iter().rev(), at_end, false)
+                } else {
+                    may_have_digit(sub_pattern.iter(), at_end, false)
+                });
+                (digit, selector.iter().any(|sub_pattern| may_be_empty(sub_pattern)))
+            }
+            _ => (true, false),
+        };
+        if digit { Some(true) } else if may_be_empty { None } else { Some(false) }
+    }).unwrap_or(default)
+}
+
+/// check if the sub pattern may match nothing
+fn may_be_empty(pattern: &[PatternChar]) -> bool {
+    pattern.iter().all(|token| matches!(token,
+        PatternChar::Wildcard | PatternChar::LazyWildcard | PatternChar::PossessiveWildcard |
+        PatternChar::CharListRepeatZero(_) | PatternChar::CharListOptional(_) | PatternChar::Start | PatternChar::End
+    ) || matches!(token, PatternChar::Selector(selector) if selector.iter().any(|sub| may_be_empty(sub))))
+}
+
+/// translate the number range into a regular expression of the digits
+fn regex_number_range(range: &NumberRange) -> String {
+    let alternatives = range.digit_sequences().iter().map(|sequence| regex_digits(sequence)).collect::<Vec<String>>();
+    let zeros = if range.leading_zeros { "0*" } else { "" };
+    format!("{}(?:{})", zeros, alternatives.join("|"))
+}
+
+/// translate a sequence of digit ranges (literal digits, a range and any digits) into a regular expression
+fn regex_digits(sequence: &[(char, char)]) -> String {
+    let mut re = String::new();
+    let mut i = 0;
+    while i < sequence.len() && sequence[i].0 == sequence[i].1 {
+        re.push(sequence[i].0);
+        i += 1;
+    }
+    if i < sequence.len() && sequence[i] != ('0', '9') {
+        re.push_str(&format!("[{}-{}]", sequence[i].0, sequence[i].1));
+        i += 1;
+    }
+    match sequence.len() - i {
+        0 => {}
+        1 => re.push_str("[0-9]"),
+        n => re.push_str(&format!("[0-9]{{{}}}", n)),
+    }
+    re
+}
+
+/// list the sequences of digit ranges which match the digits from `low` to `high` of the same length
+fn digit_sequences(low: &str, high: &str) -> Vec<Vec<(char, char)>> {
+    let (Some(l), Some(h)) = (low.chars().next(), high.chars().next()) else {
+        return vec![vec![]];
+    };
+    let (low_rest, high_rest) = (&low[1..], &high[1..]);
+    let prepend = |digit: char, sequences: Vec<Vec<(char, char)>>| {
+        sequences.into_iter().map(|sequence| [vec![(digit, digit)], sequence].concat()).collect::<Vec<_>>()
+    };
+    if l == h {
+        return prepend(l, digit_sequences(low_rest, high_rest));
+    }
+    let mut result = vec![];
+    // from `low` to the end of its leading digit
+    let mut first = l;
+    if !low_rest.chars().all(|c| c == '0') {
+        let nines = "9".repeat(low_rest.len());
+        result.extend(prepend(l, digit_sequences(low_rest, &nines)));
+        first = (l as u8 + 1) as char;
+    }
+    // the leading digits between, followed by any digits
+    let high_full = high_rest.chars().all(|c| c == '9');
+    let last = if high_full { h } else { (h as u8 - 1) as char };
+    if first <= last {
+        result.push([vec![(first, last)], vec![('0', '9'); low_rest.len()]].concat());
+    }
+    // from the start of the leading digit of `high`
+    if !high_full {
+        let zeros = "0".repeat(high_rest.len());
+        result.extend(prepend(h, digit_sequences(&zeros, high_rest)));
+    }
+    result
+}
+
+fn push_regex_char(re: &mut String, c: char, meta: &str) {
+    if c.is_control() {
+        re.push_str(&format!("\\x{{{:X}}}", c as u32));
+        return;
+    }
+    if meta.contains(c) {
+        re.push('\\');
+    }
+    re.push(c);
+}
+
+fn push_regex_class(re: &mut String, charlist: &CharList, negated: bool) {
+    if charlist.ranges().is_empty() {
+        // a class without characters
+        re.push_str(if negated { "[\\x{0}-\\x{10FFFF}]" } else { "[^\\x{0}-\\x{10FFFF}]" });
+        return;
+    }
+    re.push('[');
+    if negated {
+        re.push('^');
+    }
+    push_regex_items(re, charlist);
+    re.push(']');
+}
+
+fn push_regex_items(re: &mut String, charlist: &CharList) {
+    for item in &charlist.items {
+        match item {
+            CharItem::Range(range) if range.start > range.end => {} // an empty range
+            CharItem::Range(range) => {
+                push_regex_char(re, range.start, "\\[]^-&~");
+                if range.start != range.end {
+                    re.push('-');
+                    push_regex_char(re, range.end, "\\[]^-&~");
+                }
+            }
+            CharItem::Class(class) => match class {
+                CharClass::Alpha => re.push_str("\\p{Alphabetic}"),
+                CharClass::Digit => re.push_str("\\p{Nd}"),
+                CharClass::Space => re.push_str("\\p{White_Space}"),
+                CharClass::Upper => re.push_str("\\p{Uppercase}"),
+                CharClass::Lower => re.push_str("\\p{Lowercase}"),
+                CharClass::Alnum => re.push_str("\\p{Alphabetic}\\p{Nd}"),
+                CharClass::Punct => re.push_str("\\p{P}"),
+                CharClass::Hiragana => re.push_str("\\p{Hiragana}\\x{3099}-\\x{309C}\\x{30FC}"),
+                CharClass::Katakana => re.push_str("\\p{Katakana}\\x{3099}-\\x{309C}\\x{30FC}\\x{FF70}\\x{FF9E}\\x{FF9F}"),
+                CharClass::Kanji => re.push_str("\\p{Han}"),
+                CharClass::Fullwidth => re.push_str("\\x{3000}\\x{FF01}-\\x{FF60}\\x{FFE0}-\\x{FFE6}"),
+                CharClass::HalfwidthKatakana => re.push_str("\\x{FF65}-\\x{FF9F}"),
+                CharClass::Jis1 | CharClass::Jis2 => {
+                    // no property in regular expressions, so list the ranges
+                    for &(start, end) in class.table() {
+                        re.push_str(&format!("\\x{{{:X}}}-\\x{{{:X}}}", start as u32, end as u32));
+                    }
+                }
+            },
+            CharItem::Property { property, negated } => {
+                re.push_str(&format!("\\{}{{{}}}", if *negated { 'P' } else { 'p' }, property.name()));
             }
         }
-        Pattern {
-            pattern,
-        }
-    }
-    /// check if the pattern matches the text
-    pub fn is_match(&self, text: &str) -> bool {
-        let text_vec = text.chars().collect::<Vec<char>>();
-        is_match_slice(&self.pattern[..], &text_vec[..])
-    }
-    /// check if the pattern matches the text list
-    pub fn is_match_all(&self, str_list: &[String]) -> Vec<bool> {
-        let mut result = vec![];
-        for text in str_list {
-            let text_vec = text.chars().collect::<Vec<char>>();
-            result.push(is_match_slice(&self.pattern[..], &text_vec[..]));
-        }
-        result
     }
-    /// filter the text list by the pattern
-    pub fn filter(&self, str_list: &[String]) -> Vec<String> {
-        let mut result = vec![];
-        for text in str_list {
-            let text_vec = text.chars().collect::<Vec<char>>();
-            let b = is_match_slice(&self.pattern[..], &text_vec[..]);
-            if b {
-                result.push(text.clone());
+    for (operation, operand) in &charlist.operations {
+        if operand.ranges().is_empty() {
+            if *operation == SetOperation::Difference {
+                continue; // removes nothing
             }
+            re.push_str("&&[^\\x{0}-\\x{10FFFF}]");
+            continue;
         }
-        result
-    }
-    /// extract matched text from the beginning of string
-    pub fn extract_match(&self, text: &str) -> Option<String> {
-        let text_vec = text.chars().collect::<Vec<char>>();
-        extract_match_slice(&self.pattern[..], &text_vec[..])
-    }
-    /// find a matching substring from the entire string.
-    pub fn find_match(&self, text: &str) -> Option<MatchedResult> {
-        let text_vec = text.chars().collect::<Vec<char>>();
-        find_match_slice(&self.pattern[..], &text_vec[..])
+        re.push_str(if *operation == SetOperation::Difference { "--[" } else { "&&[" });
+        push_regex_items(re, operand);
+        re.push(']');
     }
 }
 
@@ -242,114 +1778,301 @@ pub fn is_match(pattern: &str, text: &str) -> bool {
 
 /// check if the pattern matches the text
 pub fn is_match_slice(pattern: &[PatternChar], text: &[char]) -> bool {
-    let mut i = 0;
-    let mut j = 0;
-    while i < pattern.len() && j < text.len() {
-        let pattern_char = &pattern[i];
-        match pattern_char {
-            PatternChar::Char(ch) => {
-                if text[j] == *ch {
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return false;
-            }
-            PatternChar::Number => {
-                if text[j].is_ascii_digit() {
-                    i += 1;
-                    j += 1;
-                    continue;
+    let options = MatchOptions::default();
+    let mut matcher = Matcher::new(text, &options);
+    matcher.match_seq(pattern, 0, &mut |end| end == text.len())
+}
+
+/// Backtracking matcher
+///
+/// Every token yields its candidate end positions in order of preference,
+/// and the rest of the pattern is tried from each of them in turn.
+/// Positions where the rest of the pattern failed are remembered,
+/// so a matcher must only be used with one root pattern and one `accept` callback.
+struct Matcher<'a> {
+    text: &'a [char],
+    options: &'a MatchOptions,
+    memo: Memo,
+    /// grapheme cluster boundaries of the text (empty if the grapheme mode is off)
+    boundaries: Cow<'a, [bool]>,
+    /// the text and the pattern are reversed to match from the end
+    reversed: bool,
+}
+/// What a matcher remembers about its root pattern, which `Matches` keeps between the searches
+#[derive(Default)]
+struct Memo {
+    failed: HashSet<(usize, usize)>,
+    /// end positions of the selectors and `[^=...]`, kept across the backtracking (with the same keys as `failed`)
+    selector_ends: HashMap<(usize, usize), Vec<usize>>,
+}
+/// get the grapheme cluster boundaries of the (reversed) text, or none if the grapheme mode is off
+fn text_boundaries(text: &[char], options: &MatchOptions, reversed: bool) -> Vec<bool> {
+    if !options.grapheme {
+        return vec![];
+    }
+    if !reversed {
+        return grapheme::boundaries(text);
+    }
+    // the clusters of the text in the original order
+    let original = text.iter().rev().copied().collect::<Vec<char>>();
+    let mut boundaries = grapheme::boundaries(&original);
+    boundaries.reverse();
+    boundaries
+}
+impl<'a> Matcher<'a> {
+    fn new(text: &'a [char], options: &'a MatchOptions) -> Self {
+        Matcher {
+            text,
+            options,
+            memo: Memo::default(),
+            boundaries: Cow::Owned(text_boundaries(text, options, false)),
+            reversed: false,
+        }
+    }
+    /// create a matcher for a sub pattern over the text until `end`
+    fn sub_matcher(&self, end: usize) -> Matcher<'_> {
+        let boundaries = if self.boundaries.is_empty() { &[][..] } else { &self.boundaries[..=end] };
+        Matcher {
+            text: &self.text[..end],
+            options: self.options,
+            memo: Memo::default(),
+            boundaries: Cow::Borrowed(boundaries),
+            reversed: self.reversed,
+        }
+    }
+    /// check if a match can start or end at `pos`
+    fn is_boundary(&self, pos: usize) -> bool {
+        self.boundaries.is_empty() || self.boundaries[pos]
+    }
+    /// get the end of the character (or the grapheme cluster) at `pos`
+    fn unit_end(&self, pos: usize) -> usize {
+        let mut end = pos + 1;
+        while end < self.text.len() && !self.is_boundary(end) {
+            end += 1;
+        }
+        end
+    }
+    /// get the character which classifies the unit at `pos` (the first character of a cluster)
+    fn unit_char(&self, pos: usize) -> Option<char> {
+        if pos >= self.text.len() {
+            return None;
+        }
+        Some(if self.reversed { self.text[self.unit_end(pos) - 1] } else { self.text[pos] })
+    }
+    /// match the pattern at `pos` and call `accept` with each end position until it returns true
+    fn match_seq(&mut self, pattern: &[PatternChar], pos: usize, accept: &mut dyn FnMut(usize) -> bool) -> bool {
+        self.match_rest(None, pattern, pos, accept)
+    }
+    /// match the rest of the pattern after the token `prev`
+    fn match_rest(
+        &mut self, prev: Option<&PatternChar>, pattern: &[PatternChar], pos: usize, accept: &mut dyn FnMut(usize) -> bool,
+    ) -> bool {
+        let Some((token, rest)) = pattern.split_first() else {
+            return self.is_boundary(pos) && accept(pos);
+        };
+        let key = (pattern.len(), pos);
+        if self.memo.failed.contains(&key) {
+            return false;
+        }
+        // the token after this one in the original order
+        let next = if self.reversed { prev } else { rest.first() };
+        let ends = match token {
+            PatternChar::Selector(_) | PatternChar::NotSelector(_) => match self.memo.selector_ends.get(&key) {
+                Some(ends) => ends.clone(),
+                None => {
+                    let ends = self.token_ends(token, pos, rest.is_empty(), next);
+                    self.memo.selector_ends.insert(key, ends.clone());
+                    ends
                 }
-                return false;
+            },
+            _ => self.token_ends(token, pos, rest.is_empty(), next),
+        };
+        for end in ends {
+            if self.match_rest(Some(token), rest, end, accept) {
+                return true;
             }
-            PatternChar::Question => {
-                i += 1;
-                j += 1;
-                continue;
+        }
+        self.memo.failed.insert(key);
+        false
+    }
+    /// find the preferred end position of the pattern at `pos`
+    fn first_end(&mut self, pattern: &[PatternChar], pos: usize) -> Option<usize> {
+        let mut result = None;
+        self.match_seq(pattern, pos, &mut |end| {
+            result = Some(end);
+            true
+        });
+        result
+    }
+    /// find the end position of the pattern at `pos` chosen by the match kind
+    fn best_end(&mut self, pattern: &[PatternChar], pos: usize) -> Option<usize> {
+        let kind = self.options.match_kind;
+        if kind == MatchKind::LeftmostFirst {
+            return self.first_end(pattern, pos);
+        }
+        // the ends found from another start would not be accepted again
+        self.memo.failed.clear();
+        let mut best: Option<usize> = None;
+        self.match_seq(pattern, pos, &mut |end| {
+            best = Some(match best {
+                Some(best) if kind == MatchKind::Shortest => best.min(end),
+                Some(best) => best.max(end),
+                None => end,
+            });
+            false
+        });
+        best
+    }
+    /// list every end position of a sub pattern at `pos` in order of preference
+    fn all_ends(&self, pattern: &[PatternChar], pos: usize) -> Vec<usize> {
+        let mut ends = vec![];
+        let mut seen = vec![false; self.text.len() + 1];
+        let mut sub_matcher = self.sub_matcher(self.text.len());
+        sub_matcher.match_seq(pattern, pos, &mut |end| {
+            if !seen[end] {
+                seen[end] = true;
+                ends.push(end);
             }
+            false
+        });
+        ends
+    }
+    /// check if the (folded) character is in the list
+    fn in_charlist(&self, charlist: &CharList, ch: char) -> bool {
+        if !fold::is_enabled(self.options) {
+            return charlist.contains(ch);
+        }
+        fold::unfold_char(ch, self.options).into_iter().any(|c| charlist.contains(c))
+    }
+    /// list the end positions of the run of characters (or clusters by the first character)
+    /// which satisfy `f` from `pos`, starting with `pos` itself
+    fn run_ends(&self, pos: usize, f: impl Fn(char) -> bool) -> Vec<usize> {
+        let mut ends = vec![pos];
+        let mut end = pos;
+        while self.unit_char(end).is_some_and(&f) {
+            end = self.unit_end(end);
+            ends.push(end);
+        }
+        ends
+    }
+    /// list the end positions of the token at `pos` in order of preference
+    /// (`next` is the token after it in the original order)
+    fn token_ends(&self, token: &PatternChar, pos: usize, is_last: bool, next: Option<&PatternChar>) -> Vec<usize> {
+        let text = self.text;
+        let ch = self.unit_char(pos);
+        if !matches!(token, PatternChar::Char(_)) && !self.is_boundary(pos) {
+            return vec![];
+        }
+        let single = |ok: bool| if ok { vec![self.unit_end(pos)] } else { vec![] };
+        match token {
+            PatternChar::Char(c) => if text.get(pos) == Some(c) { vec![pos + 1] } else { vec![] },
+            PatternChar::Number => single(ch.is_some_and(|c| c.is_ascii_digit())),
+            PatternChar::Question => single(ch.is_some()),
             PatternChar::Wildcard => {
-                i += 1; // skip '*'
-                if pattern.len() == i { // match until the end of the string
-                    return true;
-                }
-                let sub_pattern = &pattern[i..];
-                for j2 in j..text.len() {
-                    if is_match_slice(sub_pattern, &text[j2..]) {
-                        return true;
-                    }
-                }
-                return false;
-            }
-            PatternChar::CharList(charlist) => {
-                let ch = text[j];
-                if charlist_contains(charlist, ch) {
-                    i += 1;
-                    j += 1;
-                    continue;
+                let ends = (pos..=text.len()).filter(|&end| self.is_boundary(end));
+                if is_last { // match until the end of the string
+                    ends.rev().collect()
+                } else {
+                    ends.collect()
                 }
-                return false;
             }
-            PatternChar::NotCharList(charlist) => {
-                let ch = text[j];
-                if !charlist_contains(charlist, ch) {
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return false;
+            PatternChar::LazyWildcard => (pos..=text.len()).filter(|&end| self.is_boundary(end)).collect(),
+            PatternChar::PossessiveWildcard if self.reversed => {
+                // the span reaches the end of the original text
+                if pos == 0 { (pos..=text.len()).filter(|&end| self.is_boundary(end)).collect() } else { vec![] }
             }
+            PatternChar::PossessiveWildcard => vec![text.len()], // never gives back
+            PatternChar::CharList(charlist) => single(ch.is_some_and(|c| self.in_charlist(charlist, c))),
+            PatternChar::NotCharList(charlist) => single(ch.is_some_and(|c| !self.in_charlist(charlist, c))),
             PatternChar::CharListRepeat(charlist) => {
-                if !charlist_contains(charlist, text[j]) { return false; }
-                i += 1;
-                j += 1;
-                while j < text.len() {
-                    if !charlist_contains(charlist, text[j]) {
-                        break;
-                    }
-                    j += 1;
+                let ends = self.run_ends(pos, |c| self.in_charlist(charlist, c));
+                ends[1..].iter().rev().copied().collect()
+            }
+            PatternChar::CharListRepeatZero(charlist) => {
+                let ends = self.run_ends(pos, |c| self.in_charlist(charlist, c));
+                ends.into_iter().rev().collect()
+            }
+            PatternChar::CharListOptional(charlist) => {
+                if ch.is_some_and(|c| self.in_charlist(charlist, c)) {
+                    vec![self.unit_end(pos), pos]
+                } else {
+                    vec![pos]
                 }
-                continue;
             }
             PatternChar::NotCharListRepeat(charlist) => {
-                if charlist_contains(charlist, text[j]) { return false; }
-                i += 1;
-                j += 1;
-                while j < text.len() {
-                    if charlist_contains(charlist, text[j]) {
-                        break;
+                let ends = self.run_ends(pos, |c| !self.in_charlist(charlist, c));
+                ends[1..].iter().rev().copied().collect()
+            }
+            PatternChar::Selector(selector) => {
+                let mut ends = vec![];
+                let mut seen = vec![false; text.len() + 1];
+                for sub_pattern in selector {
+                    for end in self.all_ends(sub_pattern, pos) {
+                        if !seen[end] {
+                            seen[end] = true;
+                            ends.push(end);
+                        }
                     }
-                    j += 1;
                 }
-                continue;
+                ends
             }
-            PatternChar::Selector(selector) => {
-                let mut matched = false;
-                i += 1;
-                for substr in selector {
-                    let substr_chars = substr.chars().collect::<Vec<char>>();
-                    let subtext = &text[j..];
-                    if subtext.starts_with(substr_chars.as_slice()){
-                        j += substr_chars.len();
-                        matched = true;
+            PatternChar::NumberRange(range) => {
+                // the whole run of digits, so that "45" does not match as "4" in `[<1-31>]`
+                if pos > 0 && text[pos - 1].is_ascii_digit() {
+                    return vec![];
+                }
+                let end = pos + text[pos..].iter().take_while(|c| c.is_ascii_digit()).count();
+                let contains = |digits: &[char]| if self.reversed {
+                    range.contains_digits(&digits.iter().rev().copied().collect::<Vec<char>>())
+                } else {
+                    range.contains_digits(digits)
+                };
+                if end > pos && self.is_boundary(end) && contains(&text[pos..end]) { vec![end] } else { vec![] }
+            }
+            PatternChar::Start => {
+                let at_start = pos == 0 || (self.options.multiline && text[pos - 1] == '\n');
+                if at_start { vec![pos] } else { vec![] }
+            }
+            PatternChar::End => {
+                let at_end = pos == text.len() || (self.options.multiline && text[pos] == '\n');
+                if at_end { vec![pos] } else { vec![] }
+            }
+            PatternChar::NotSelector(selector) => {
+                // a span of one or more characters which none of the alternatives matches entirely,
+                // ending at the first character which can start the next token
+                let (delimiters, nullable) = match next {
+                    Some(next) => automaton::first_chars(std::slice::from_ref(next), self.options),
+                    None => (vec![], true),
+                };
+                let is_delimiter = |unit: usize| {
+                    self.unit_char(unit).is_some_and(|c| automaton::ranges_contain(&delimiters, c as u32))
+                };
+                let mut ends = vec![];
+                let (mut unit, mut end) = (pos, self.unit_end(pos));
+                while end <= text.len() {
+                    // in the reversed text, the last unit is the first one of the span in the original order
+                    let delimited = if self.reversed { is_delimiter(unit) } else { is_delimiter(end) };
+                    // the next token starts at a delimiter unless it can match the empty string
+                    if self.reversed || nullable || delimited {
+                        let excluded = selector.iter().any(|sub_pattern| {
+                            self.sub_matcher(end).match_seq(sub_pattern, pos, &mut |e| e == end)
+                        });
+                        if !excluded {
+                            ends.push(end);
+                        }
+                    }
+                    if delimited || end == text.len() {
                         break;
                     }
+                    (unit, end) = (end, self.unit_end(end));
+                }
+                if is_last {
+                    ends.reverse();
                 }
-                if matched { continue; }
-                return false;
+                ends
             }
         }
     }
-    (i >= pattern.len()) && (j >= text.len())
-}
-
-fn charlist_contains(charlist: &[CharRange], ch: char) -> bool {
-    for range in charlist {
-        if range.contains(ch) {
-            return true;
-        }
-    }
-    false
 }
 
 /// extracts matched text from the beginning of string
@@ -363,127 +2086,9 @@ pub fn extract_match(pattern: &str, text: &str) -> Option<String> {
 /// extracts matched text from the beginning of string
 #[allow(dead_code)]
 pub fn extract_match_slice(pattern: &[PatternChar], text: &[char]) -> Option<String> {
-    let mut i = 0;
-    let mut j = 0;
-    let mut matched = String::new();
-    while i < pattern.len() && j < text.len() {
-        let pattern_char = &pattern[i];
-        // println!("@@@@{:?}==={}:{}", pattern_char, j, text[j]);
-        match pattern_char {
-            PatternChar::Char(ch) => {
-                if text[j] == *ch {
-                    matched.push(*ch);
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return None;
-            },
-            PatternChar::Number => {
-                if text[j].is_ascii_digit() {
-                    matched.push(text[j]);
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return None;
-            },
-            PatternChar::Question => {
-                matched.push(text[j]);
-                i += 1;
-                j += 1;
-                continue;
-            },
-            PatternChar::CharList(charlist) => {
-                let ch = text[j];
-                if charlist_contains(&charlist, ch) {
-                    matched.push(ch);
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return None;
-            },
-            PatternChar::NotCharList(charlist) => {
-                let ch = text[j];
-                if !charlist_contains(&charlist, ch) {
-                    matched.push(ch);
-                    i += 1;
-                    j += 1;
-                    continue;
-                }
-                return None;
-            },
-            PatternChar::CharListRepeat(charlist) => {
-                if !charlist_contains(&charlist, text[j]) { return None; }
-                matched.push(text[j]);
-                i += 1;
-                j += 1;
-                while j < text.len() {
-                    if !charlist_contains(&charlist, text[j]) {
-                        break;
-                    }
-                    matched.push(text[j]);
-                    j += 1;
-                }
-                continue;
-            },
-            PatternChar::NotCharListRepeat(charlist) => {
-                if charlist_contains(&charlist, text[j]) { return None; }
-                matched.push(text[j]);
-                i += 1;
-                j += 1;
-                while j < text.len() {
-                    if charlist_contains(&charlist, text[j]) {
-                        break;
-                    }
-                    matched.push(text[j]);
-                    j += 1;
-                }
-                continue;
-            },
-            PatternChar::Selector(selector) => {
-                let mut flag_matched = false;
-                i += 1;
-                for substr in selector {
-                    let substr_chars = substr.chars().collect::<Vec<char>>();
-                    let subtext = &text[j..];
-                    if subtext.starts_with(substr_chars.as_slice()){
-                        let substr = substr_chars.iter().collect::<String>();
-                        matched.push_str(&substr);
-                        j += substr_chars.len();
-                        flag_matched = true;
-                        break;
-                    }
-                }
-                if flag_matched { continue; }
-                return None;
-            },
-            PatternChar::Wildcard => {
-                i += 1; // skip '*'
-                if pattern.len() == i { // match until the end of the string
-                    let substring: String = text[j..].iter().collect();
-                    matched.push_str(&substring);
-                    return Some(matched);
-                }
-                // check patterns recursively
-                let sub_pattern = &pattern[i..];
-                for j2 in j..text.len() {
-                    if let Some(sub_matched) = extract_match_slice(sub_pattern, &text[j2..]) {
-                        matched.push_str(&sub_matched);
-                        return Some(matched);
-                    } else {
-                        matched.push(text[j2]);
-                    }
-                }
-                return None;
-            },
-        }
-    }
-    if pattern.len() == i {
-        return Some(matched)
-    }
-    None
+    let options = MatchOptions::default();
+    let end = Matcher::new(text, &options).first_end(pattern, 0)?;
+    Some(text[..end].iter().collect())
 }
 
 
@@ -495,7 +2100,7 @@ pub struct MatchedResult {
     pub end: usize,
     pub matched: String,
 }
-#[allow(dead_code)]
+#[allow(dead_code, clippy::len_without_is_empty)]
 impl MatchedResult {
     pub fn new(start: usize, end: usize, matched: String) -> Self {
         MatchedResult {
@@ -519,15 +2124,43 @@ pub fn find_match(pattern: &str, text: &str) -> Option<MatchedResult> {
 
 /// find a matching substring from the entire string.
 pub fn find_match_slice(pattern: &[PatternChar], text: &[char]) -> Option<MatchedResult> {
-    for j in 0..text.len() {
-        let sub_text = &text[j..];
-        if let Some(sub_matched) = extract_match_slice(pattern, sub_text) {
-            let result = MatchedResult {
-                start: j,
-                end: j + sub_matched.len(),
-                matched: sub_matched,
-            };
-            return Some(result);
+    let (start, end) = find_range(pattern, text, &MatchOptions::default(), 0)?;
+    let matched = text[start..end].iter().collect();
+    Some(MatchedResult::new(start, end, matched))
+}
+
+/// find the range of the first match starting at `from` or later
+fn find_range(pattern: &[PatternChar], text: &[char], options: &MatchOptions, from: usize) -> Option<(usize, usize)> {
+    search(&mut Matcher::new(text, options), pattern, from)
+}
+
+/// reverse the order of the tokens (and of the alternatives) to match from the end of the text
+fn reverse_pattern(pattern: &[PatternChar]) -> Vec<PatternChar> {
+    let reverse_selector = |selector: &[Vec<PatternChar>]| {
+        selector.iter().map(|sub_pattern| reverse_pattern(sub_pattern)).collect()
+    };
+    pattern.iter().rev().map(|token| match token {
+        PatternChar::Selector(selector) => PatternChar::Selector(reverse_selector(selector)),
+        PatternChar::NotSelector(selector) => PatternChar::NotSelector(reverse_selector(selector)),
+        PatternChar::Start => PatternChar::End,
+        PatternChar::End => PatternChar::Start,
+        token => token.clone(),
+    }).collect()
+}
+
+/// find the range of the first match at `from` or later with the matcher
+fn search(matcher: &mut Matcher, pattern: &[PatternChar], from: usize) -> Option<(usize, usize)> {
+    let text = matcher.text;
+    for start in from..=text.len() {
+        if !matcher.is_boundary(start) {
+            continue;
+        }
+        if let Some(end) = matcher.best_end(pattern, start) {
+            return Some((start, end));
+        }
+        // a leading `*` would have taken in the text up to any later start
+        if matches!(pattern.first(), Some(PatternChar::Wildcard | PatternChar::LazyWildcard)) {
+            return None;
         }
     }
     None
@@ -535,6 +2168,7 @@ pub fn find_match_slice(pattern: &[PatternChar], text: &[char]) -> Option<Matche
 
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     #[test]
@@ -599,19 +2233,324 @@ mod tests {
         assert_eq!(is_match("a[-\\-]-[+0-9].zip", "abc123-456.zip"), true);
     }
     #[test]
+    fn test_is_match_vblike_charlist_class() {
+        assert_eq!(is_match("[[:alpha:]]1", "a1"), true);
+        assert_eq!(is_match("[[:alpha:]]1", "あ1"), true);
+        assert_eq!(is_match("[[:alpha:]]1", "11"), false);
+        assert_eq!(is_match("[+[:digit:]]", "0123"), true);
+        assert_eq!(is_match("[+[:digit:]]", "٠١٢"), true); // Arabic-Indic digits
+        assert_eq!(is_match("[+[:digit:]]", "½"), false);
+        assert_eq!(is_match("a[![:space:]]b", "a b"), false);
+        assert_eq!(is_match("a[![:space:]]b", "a_b"), true);
+        assert_eq!(is_match("a[[:space:]]b", "a\u{3000}b"), true);
+        assert_eq!(is_match("[[:upper:]][+[:lower:]]", "Élan"), true);
+        assert_eq!(is_match("[[:upper:]][+[:lower:]]", "élan"), false);
+        assert_eq!(is_match("[+[:alnum:]_]", "abc_123_αβγ"), true);
+        assert_eq!(is_match("[-[:punct:]]", "hello"), true);
+        assert_eq!(is_match("[-[:punct:]]", "hello、"), false);
+        assert_eq!(is_match("[*[:punct:]]end", "!?。end"), true);
+        // classes combined with ranges
+        assert_eq!(is_match("[+a-f[:digit:]]", "c0ffee"), true);
+        assert_eq!(is_match("[+a-f[:digit:]]", "coffee"), false);
+        // unknown names are literal characters (`try_new` reports them)
+        assert_eq!(is_match("[[:foo:]]", ":]"), true);
+        assert_eq!(is_match("[\\[:alpha:]]", "a]"), true);
+        assert_eq!(is_match("[a-]", "-"), true);
+    }
+    #[test]
+    fn test_is_match_vblike_charlist_property() {
+        // general categories
+        assert_eq!(is_match("[+\\p{L}\\p{Nd}]", "abcДж日本123"), true);
+        assert_eq!(is_match("[+\\p{L}\\p{Nd}]", "abc-123"), false);
+        assert_eq!(is_match("[\\p{Lu}][+\\p{Ll}]", "Hello"), true);
+        assert_eq!(is_match("[\\p{Lu}][+\\p{Ll}]", "hello"), false);
+        assert_eq!(is_match("[+\\p{Uppercase_Letter}]", "ABC"), true);
+        assert_eq!(is_match("[+\\p{gc=Nd}]", "123١٢٣"), true);
+        // scripts
+        assert_eq!(is_match("[+\\p{Han}]", "日本語"), true);
+        assert_eq!(is_match("[+\\p{Han}]", "日本ご"), false);
+        assert_eq!(is_match("[+\\p{Greek}]", "αβγ"), true);
+        assert_eq!(is_match("[+\\p{sc=Latn}]", "abc"), true);
+        assert_eq!(is_match("[+\\p{script=cyrillic}]", "Жизнь"), true);
+        // negations
+        assert_eq!(is_match("[!\\p{Han}]", "ご"), true);
+        assert_eq!(is_match("[!\\p{Han}]", "語"), false);
+        assert_eq!(is_match("[+\\P{Han}]", "ひらがな"), true);
+        assert_eq!(is_match("[+\\p{^Han}]", "ひらがな"), true);
+        assert_eq!(is_match("[-\\p{Nd}]#", "abc1"), true);
+        // with other items
+        assert_eq!(is_match("[+\\p{Hiragana}ー_]", "らーめん_"), true);
+        // an unknown property is read as characters
+        assert_eq!(is_match("[\\p{Foo}]", "p"), true);
+    }
+    #[test]
+    fn test_is_match_vblike_charlist_set_operation() {
+        // difference
+        assert_eq!(is_match("[+a-z--aeiou]", "xyz"), true);
+        assert_eq!(is_match("[+a-z--aeiou]", "xyza"), false);
+        assert_eq!(is_match("[+[:alpha:]--[:upper:]]", "abc"), true);
+        assert_eq!(is_match("[+[:alpha:]--[:upper:]]", "aBc"), false);
+        assert_eq!(is_match("[+[:kanji:]--日月]", "火水木"), true);
+        assert_eq!(is_match("[+[:kanji:]--日月]", "日曜"), false);
+        // intersection
+        assert_eq!(is_match("[+\\p{Han}&&[:jis1:]]", "亜細亜"), true);
+        assert_eq!(is_match("[+\\p{Han}&&[:jis1:]]", "弌"), false); // level 2
+        assert_eq!(is_match("[\\p{L}&&\\p{Greek}]", "α"), true);
+        assert_eq!(is_match("[\\p{L}&&\\p{Greek}]", "a"), false);
+        // from left to right, and nested lists
+        assert_eq!(is_match("[+a-z--aeiou&&a-m]", "bcd"), true);
+        assert_eq!(is_match("[+a-z--aeiou&&a-m]", "xyz"), false);
+        assert_eq!(is_match("[+a-z--[a-m--aeiou]]", "aeixyz"), true);
+        assert_eq!(is_match("[+a-z--[a-m--aeiou]]", "b"), false);
+        // in other forms
+        assert_eq!(is_match("[!a-z--x]", "x"), true);
+        assert_eq!(is_match("[-0-9--5]5", "abc5"), true);
+        // a single '-' or '&' is a literal
+        assert_eq!(is_match("[+a-c&]", "a&b"), true);
+        assert_eq!(is_match("[+\\-]", "--"), true);
+    }
+    #[test]
+    fn test_charlist_ranges() {
+        let charlist = PatternChar::read_charlist(&mut StrCursor::new("c-fa-bx[:digit:]--e]"));
+        let ranges = charlist.ranges().iter().map(|r| (r.start, r.end)).collect::<Vec<_>>();
+        assert_eq!(ranges.len() > 3, true); // digits of every script
+        assert_eq!(ranges[0], ('0', '9'));
+        assert_eq!(charlist.contains('a'), true);
+        assert_eq!(charlist.contains('d'), true);
+        assert_eq!(charlist.contains('e'), false);
+        assert_eq!(charlist.contains('x'), true);
+        assert_eq!(charlist.contains('٣'), true);
+        let charlist = PatternChar::read_charlist(&mut StrCursor::new("a-z--b-y]"));
+        let ranges = charlist.ranges().iter().map(|r| (r.start, r.end)).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![('a', 'a'), ('z', 'z')]);
+        let charlist = PatternChar::read_charlist(&mut StrCursor::new("a-z&&0-9]"));
+        assert_eq!(charlist.ranges().len(), 0);
+    }
+    #[test]
+    fn test_is_match_jis_class() {
+        assert_eq!(is_match("[+[:jis1:]]", "亜唖娃阿"), true);
+        assert_eq!(is_match("[+[:jis1:]]", "亜弌"), false);
+        assert_eq!(is_match("[+[:jis2:]]", "弌丐丕"), true);
+        assert_eq!(is_match("[+[:jis1:][:jis2:]]", "亜弌"), true);
+        assert_eq!(is_match("[[:jis1:][:jis2:]]", "𠮷"), false);
+    }
+    #[test]
+    fn test_is_match_vblike_charlist_zero_or_one() {
+        // wildcard [*str]
+        assert_eq!(is_match("abc[*0-9].zip", "abc.zip"), true);
+        assert_eq!(is_match("abc[*0-9].zip", "abc123.zip"), true);
+        assert_eq!(is_match("abc[*0-9].zip", "abc12a.zip"), false);
+        // wildcard [?str]
+        assert_eq!(is_match("v[?0-9].txt", "v.txt"), true);
+        assert_eq!(is_match("v[?0-9].txt", "v1.txt"), true);
+        assert_eq!(is_match("v[?0-9].txt", "v12.txt"), false);
+        // optional selector
+        assert_eq!(is_match("img[=|_small].png", "img.png"), true);
+        assert_eq!(is_match("img[=|_small].png", "img_small.png"), true);
+        assert_eq!(is_match("img[=|_small].png", "img_large.png"), false);
+    }
+    #[test]
+    fn test_is_match_vblike_backtrack() {
+        // repeated char lists give characters back to the following tokens
+        assert_eq!(is_match("[+a-z]z", "abcz"), true);
+        assert_eq!(is_match("[*0-9]#", "123"), true);
+        assert_eq!(is_match("[*0-9]##", "1"), false);
+        assert_eq!(is_match("[?0-9]#", "1"), true);
+        assert_eq!(is_match("[-.].[-.]", "abc.txt"), true);
+        assert_eq!(is_match("[=ab|a]bc", "abc"), true);
+        // wildcard may match an empty string
+        assert_eq!(is_match("a*", "a"), true);
+        assert_eq!(is_match("*", ""), true);
+        assert_eq!(is_match("a*b*c", "abc"), true);
+    }
+    #[test]
     fn test_is_match_vblike_selector() {
         // wildcard [str]
         assert_eq!(is_match("[=cat|dog|penguin].zip", "cat.zip"), true);
         assert_eq!(is_match("[=cat|dog|penguin].zip", "pen.zip"), false);
     }
     #[test]
+    fn test_is_match_vblike_selector_pattern() {
+        // alternatives are patterns
+        assert_eq!(is_match("[=*.jpg|IMG_####.png]", "cat.jpg"), true);
+        assert_eq!(is_match("[=*.jpg|IMG_####.png]", "IMG_0123.png"), true);
+        assert_eq!(is_match("[=*.jpg|IMG_####.png]", "IMG_012.png"), false);
+        assert_eq!(is_match("[=*.jpg|IMG_####.png]", "cat.png"), false);
+        assert_eq!(is_match("file[=[+0-9]|[a-z]].txt", "file123.txt"), true);
+        assert_eq!(is_match("file[=[+0-9]|[a-z]].txt", "filex.txt"), true);
+        assert_eq!(is_match("file[=[+0-9]|[a-z]].txt", "filexy.txt"), false);
+        // nested selector
+        assert_eq!(is_match("[=a[=b|c]|d]e", "ace"), true);
+        assert_eq!(is_match("[=a[=b|c]|d]e", "de"), true);
+        assert_eq!(is_match("[=a[=b|c]|d]e", "ae"), false);
+        // '|' and ']' in a char list or escaped are literal
+        assert_eq!(is_match("[=a[|]b|c]", "a|b"), true);
+        assert_eq!(is_match("[=a\\|b|c]", "a|b"), true);
+        assert_eq!(is_match("[=a\\]|c]", "a]"), true);
+        assert_eq!(is_match("[=\\*|c]", "*"), true);
+        assert_eq!(is_match("[=\\*|c]", "x"), false);
+    }
+    #[test]
+    fn test_is_match_vblike_not_selector() {
+        assert_eq!(is_match("[^=tmp|cache].txt", "memo.txt"), true);
+        assert_eq!(is_match("[^=tmp|cache].txt", "tmp.txt"), false);
+        assert_eq!(is_match("[^=tmp|cache].txt", "cache.txt"), false);
+        assert_eq!(is_match("[^=tmp|cache].txt", "tmp2.txt"), true);
+        assert_eq!(is_match("[^=tmp|cache].txt", ".txt"), false);
+        assert_eq!(is_match("log/[^=tmp*]/*.log", "log/app/a.log"), true);
+        assert_eq!(is_match("log/[^=tmp*]/*.log", "log/tmp_1/a.log"), false);
+        // the span is delimited by the next token
+        assert_eq!(is_match("[^=tmp|cache]/*", "tmp/a/b"), false);
+        assert_eq!(is_match("[^=tmp|cache]/*", "src/tmp/b"), true);
+        assert_eq!(is_match("[^=tmp|cache].txt", "tmp.txt.txt"), false);
+        assert_eq!(is_match("log/[^=tmp|cache]/*.log", "log/tmp/x/a.log"), false);
+        assert_eq!(is_match("log/[^=tmp|cache]/*.log", "log/app/x/a.log"), true);
+        let result = Pattern::new("[^=tmp|cache]/").rfind_match("tmp/app/").unwrap();
+        assert_eq!(result.matched, "/app/".to_string());
+        assert_eq!(Pattern::new("x[^=tmp]/").rfind_match("xtmp/a/"), None);
+        // the automaton agrees with the matcher
+        let p = Pattern::new("[^=tmp|cache].txt");
+        assert_eq!(p.prefix_status("tmp.txt."), PrefixStatus::Dead);
+        assert_eq!(p.overlaps(&Pattern::new("tmp.*")), None);
+        assert_eq!(p.overlaps(&Pattern::new("tm*")), Some("tm.txt".to_string()));
+        // '[^' without '=' is still a char list
+        assert_eq!(is_match("[^_]", "^"), true);
+        assert_eq!(find_match("[^=a|b]=#", "a=1"), None);
+        let result = find_match("[^=a|b]=#", "bb=2").unwrap();
+        assert_eq!(result.matched, "bb=2".to_string());
+    }
+    #[test]
+    fn test_is_match_number_range() {
+        // IP address
+        let ip = Pattern::new("[<0-255>].[<0-255>].[<0-255>].[<0-255>]");
+        assert_eq!(ip.is_match("192.168.0.1"), true);
+        assert_eq!(ip.is_match("255.255.255.255"), true);
+        assert_eq!(ip.is_match("256.1.1.1"), false);
+        assert_eq!(ip.is_match("1.2.3"), false);
+        assert_eq!(ip.is_match("01.2.3.4"), false); // no leading zeros
+        // day of month and port
+        assert_eq!(is_match("[<1-31>]", "31"), true);
+        assert_eq!(is_match("[<1-31>]", "0"), false);
+        assert_eq!(is_match("[<1-31>]", "32"), false);
+        assert_eq!(is_match("port:[<1-65535>]", "port:8080"), true);
+        assert_eq!(is_match("port:[<1-65535>]", "port:65536"), false);
+        // fixed width with a zero-padded lower bound
+        assert_eq!(is_match("[<00-23>]:[<00-59>]", "09:05"), true);
+        assert_eq!(is_match("[<00-23>]:[<00-59>]", "9:05"), false);
+        assert_eq!(is_match("[<00-23>]:[<00-59>]", "24:00"), false);
+        // leading zeros
+        assert_eq!(is_match("[<0-255:z>]", "007"), true);
+        assert_eq!(is_match("[<0-255:z>]", "0256"), false);
+        assert_eq!(is_match("[<0-9>]", "0"), true);
+        // a number takes the whole run of digits
+        assert_eq!(is_match("[<1-12>]#", "123"), false);
+        assert_eq!(is_match("[<1-12>]-#", "12-3"), true);
+        assert_eq!(extract_match("[<1-31>]", "123"), None);
+        assert_eq!(find_match("[<1-31>]", "day 45"), None);
+        assert_eq!(find_match("[<1-31>]", "day 45, 7").map(|m| m.matched), Some("7".to_string()));
+        assert_eq!(Pattern::new("[<1-31>]").find_iter("3 45 12").map(|m| m.matched).collect::<Vec<_>>(), vec!["3", "12"]);
+        // an invalid range is a char list
+        assert_eq!(is_match("[<>]", ">"), true);
+        assert_eq!(Pattern::try_new("[<9-1>]").unwrap_err(), PatternError::InvalidNumberRange { position: 0 });
+        assert_eq!(Pattern::try_new("a[<1-x>]").unwrap_err(), PatternError::InvalidNumberRange { position: 1 });
+        assert_eq!(Pattern::try_new("[<01-100>]").unwrap_err(), PatternError::InvalidNumberRange { position: 0 });
+    }
+    #[test]
+    fn test_number_range_to_regex() {
+        assert_eq!(Pattern::new("[<0-255>]").to_regex(), Some("^(?s:(?:1[0-9]{2}|2[0-4][0-9]|25[0-5]|[1-9][0-9]|[0-9]))$".to_string()));
+        assert_eq!(Pattern::new("[<1-31>]").to_regex(), Some("^(?s:(?:[1-2][0-9]|3[0-1]|[1-9]))$".to_string()));
+        assert_eq!(Pattern::new("[<00-59>]").to_regex(), Some("^(?s:(?:[0-5][0-9]))$".to_string()));
+        assert_eq!(Pattern::new("[<0-9:z>]").to_regex(), Some("^(?s:0*(?:[0-9]))$".to_string()));
+        // a number next to a digit cannot be written as a regular expression
+        assert_eq!(Pattern::new("v[<1-9>]-[=a|b]").to_regex().is_some(), true);
+        assert_eq!(Pattern::new("[=x|[<1-9>]][$]a").to_regex().is_some(), true);
+        assert_eq!(Pattern::new("[<1-12>]#").to_regex(), None);
+        assert_eq!(Pattern::new("*[<1-12>]").to_regex(), None);
+        assert_eq!(Pattern::new("[=a|[*0-9]][<1-12>]").to_regex(), None);
+        assert_eq!(Pattern::new("[=a|[<1-12>]][?x]1").to_regex(), None);
+    }
+    #[test]
+    fn test_pattern_display() {
+        let patterns = [
+            "*.txt", "a??.txt", "###-####", "abc[0-3].zip", "abc[!0-3].zip", "[+a-z0-9].txt",
+            "v[*0-9]", "v[?0-9]", "[-.].zip", "[=cat|dog].txt", "[=*.jpg|IMG_####.png]",
+            "[^=tmp|cache].txt", "a\\*b\\[c\\]", "[=a\\|b|c\\]]", "[\\!a\\-z]", "a[\\t]b",
+            "[[:alpha:]_][*[:alnum:]_]", "[![:space:][:punct:]]", "[+\\p{L}\\p{Nd}]", "[\\P{Han}]",
+            "[+a-z--aeiou]", "[\\p{Han}&&[:jis1:]]", "[a-z--[a-m&&aeiou]]", "[\\&\\&]",
+            "[<0-255>]", "[<00-59>]", "[<1-31:z>]", "[^]abc[$]", "[\\^][\\$][\\@a][\\<1>]",
+            "a[*?]b[*+]", "[*\\?][*\\+]",
+        ];
+        for src in patterns {
+            assert_eq!(Pattern::new(src).to_string(), src);
+        }
+        // printing normalizes the escapes
+        assert_eq!(Pattern::new("a\\b[+\\x41-\\x43]").to_string(), "ab[+A-C]");
+        assert_eq!(Pattern::new("[\\p{^Letter}\\p{sc=hani}]").to_string(), "[\\P{L}\\p{Han}]");
+    }
+    #[test]
+    fn test_pattern_to_regex() {
+        assert_eq!(Pattern::new("*.txt").to_regex(), Some("^(?s:.*?\\.txt)$".to_string()));
+        assert_eq!(Pattern::new("a?*").to_regex(), Some("^(?s:a..*)$".to_string()));
+        assert_eq!(Pattern::new("###-[!0-3]").to_regex(), Some("^(?s:[0-9][0-9][0-9]\\-[^0-3])$".to_string()));
+        assert_eq!(Pattern::new("[+a-z][*0-9][?_][-.]").to_regex(), Some("^(?s:[a-z]+[0-9]*[_]?[^.]+)$".to_string()));
+        assert_eq!(Pattern::new("[=cat|d?g].txt").to_regex(), Some("^(?s:(?:cat|d.g)\\.txt)$".to_string()));
+        assert_eq!(Pattern::new("[\\t\\]]").to_regex(), Some("^(?s:[\\x{9}\\]])$".to_string()));
+        assert_eq!(Pattern::new("[![:digit:]x]").to_regex(), Some("^(?s:[^\\p{Nd}x])$".to_string()));
+        assert_eq!(Pattern::new("[+\\p{L}\\P{Han}]").to_regex(), Some("^(?s:[\\p{L}\\P{Han}]+)$".to_string()));
+        assert_eq!(Pattern::new("[+a-z--aeiou&&a-m]").to_regex(), Some("^(?s:[a-z--[aeiou]&&[a-m]]+)$".to_string()));
+        assert_eq!(Pattern::new("[a--a]").to_regex(), Some("^(?s:[^\\x{0}-\\x{10FFFF}])$".to_string()));
+        assert_eq!(Pattern::new("[^=tmp].txt").to_regex(), None);
+    }
+    #[test]
     fn test_is_match_vblike_esc() {
         // escape pattern
+        assert_eq!(is_match("[\\]]", "]"), true);
+        assert_eq!(is_match("[+\\[\\]]", "[]"), true);
         assert_eq!(is_match("a[\\t]b", "a\tb"), true);
         assert_eq!(is_match("a[\\x09]b", "a\tb"), true);
         assert_eq!(is_match("a[+\\x09]b", "a\t\tb"), true);
     }
     #[test]
+    fn test_is_match_vblike_esc_grammar() {
+        // the same escapes at top level, in char lists and in selectors
+        assert_eq!(is_match("a\\tb", "a\tb"), true);
+        assert_eq!(is_match("a\\x41\\u3042\\u{1F600}", "aAあ😀"), true);
+        assert_eq!(is_match("[+\\u{41}-\\u{43}]", "ABC"), true);
+        assert_eq!(is_match("[=\\t|\\x41]", "A"), true);
+        assert_eq!(is_match("[=\\t|\\x41]", "\t"), true);
+        // '\u' takes exactly four digits and '\x' two
+        assert_eq!(is_match("\\u30423", "あ3"), true);
+        assert_eq!(is_match("\\x414", "A4"), true);
+        // other escaped characters are literals
+        assert_eq!(is_match("\\*\\?\\#\\[\\]", "*?#[]"), true);
+        assert_eq!(is_match("\\a\\\\", "a\\"), true);
+        // printing keeps the control characters escaped
+        assert_eq!(Pattern::new("a\\tb[\\n]").to_string(), "a\\tb[\\n]");
+        assert_eq!(Pattern::new("\\u{41}").to_string(), "A");
+    }
+    #[test]
+    fn test_pattern_try_new() {
+        assert_eq!(Pattern::try_new("a\\u{41}[\\x42]").unwrap().is_match("aAB"), true);
+        assert_eq!(Pattern::try_new("ab\\xZ").unwrap_err(), PatternError::InvalidEscape { position: 2 });
+        assert_eq!(Pattern::try_new("\\u12").unwrap_err(), PatternError::InvalidEscape { position: 0 });
+        assert_eq!(Pattern::try_new("\\u{}").unwrap_err(), PatternError::InvalidEscape { position: 0 });
+        assert_eq!(Pattern::try_new("\\u{1234567}").unwrap_err(), PatternError::InvalidEscape { position: 0 });
+        assert_eq!(Pattern::try_new("[a\\u{D800}]").unwrap_err(), PatternError::InvalidCodePoint { position: 2, value: 0xD800 });
+        assert_eq!(Pattern::try_new("[=a|\\u{110000}]").unwrap_err(), PatternError::InvalidCodePoint { position: 4, value: 0x110000 });
+        assert_eq!(Pattern::try_new("abc\\").unwrap_err(), PatternError::TrailingEscape { position: 3 });
+        assert_eq!(
+            Pattern::try_new("[\\p{Foo}]").unwrap_err(),
+            PatternError::UnknownProperty { position: 1, name: "Foo".to_string() },
+        );
+        assert_eq!(
+            Pattern::try_new("ab[x[:foo:]]").unwrap_err(),
+            PatternError::UnknownClass { position: 4, name: "foo".to_string() },
+        );
+        // `new` is lenient
+        assert_eq!(Pattern::new("a\\u{D800}").is_match("a\u{FFFD}"), true);
+        assert_eq!(Pattern::new("abc\\").is_match("abc\\"), true);
+    }
+    #[test]
     fn test_is_match_multibytes() {
         assert_eq!(is_match("魚[あ-ん]ち.zip", "魚いち.zip"), true);
         assert_eq!(is_match("日本[!あ-ん].zip", "日本酒.zip"), true);
@@ -626,6 +2565,132 @@ mod tests {
         assert_eq!(is_match("魚[=図鑑|資料|市場]売店.zip", "魚市場売店.zip"), true);
     }
 
+    #[test]
+    fn test_is_match_japanese_class() {
+        // hiragana with small kana, 'ゔ' and the prolonged sound mark
+        assert_eq!(is_match("[+[:hiragana:]].txt", "いろは.txt"), true);
+        assert_eq!(is_match("[+[:hiragana:]].txt", "ゔぁーじょん.txt"), true);
+        assert_eq!(is_match("[+[:hiragana:]].txt", "カタカナ.txt"), false);
+        // katakana in full-width and half-width
+        assert_eq!(is_match("[+[:katakana:]]", "ヴァージョン"), true);
+        assert_eq!(is_match("[+[:katakana:]]", "ｶﾞｰﾃﾞﾝ"), true);
+        assert_eq!(is_match("[+[:katakana:]]", "かたかな"), false);
+        // kanji
+        assert_eq!(is_match("[+[:kanji:]]", "日本語"), true);
+        assert_eq!(is_match("[+[:kanji:]]", "人々"), true);
+        assert_eq!(is_match("[+[:kanji:]]", "日本ご"), false);
+        assert_eq!(is_match("[![:kanji:]]", "ご"), true);
+        // full-width and half-width
+        assert_eq!(is_match("[+[:fullwidth:]]", "ＡＢＣ１２３"), true);
+        assert_eq!(is_match("[+[:fullwidth:]]", "ABC123"), false);
+        assert_eq!(is_match("[+[:halfwidth_katakana:]]", "ｱｲｳ･ｰﾞ"), true);
+        assert_eq!(is_match("[+[:halfwidth_katakana:]]", "アイウ"), false);
+        // in every form of char lists
+        assert_eq!(is_match("[-[:hiragana:][:kanji:]]です", "テストです"), true);
+        assert_eq!(is_match("[*[:kanji:]][?[:hiragana:]]", "読む"), true);
+        assert_eq!(is_match("[*[:kanji:]][?[:hiragana:]]", "読"), true);
+    }
+    #[test]
+    fn test_is_match_fold() {
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let kana = MatchOptions { fold_kana: true, ..Default::default() };
+        let both = MatchOptions { fold_width: true, fold_kana: true, ..Default::default() };
+        // full-width ASCII and '#'
+        let pattern = Pattern::new_with_options("ABC###", width);
+        assert_eq!(pattern.is_match("ＡＢＣ１２３"), true);
+        assert_eq!(pattern.is_match("ABC123"), true);
+        assert_eq!(Pattern::new("ABC###").is_match("ＡＢＣ１２３"), false);
+        assert_eq!(Pattern::new_with_options("ＡＢＣ*", width).is_match("ABCD"), true);
+        // half-width katakana with voiced sound marks
+        let pattern = Pattern::new_with_options("カタカナ*", width);
+        assert_eq!(pattern.is_match("ｶﾀｶﾅ.txt"), true);
+        assert_eq!(Pattern::new_with_options("ガーデン", width).is_match("ｶﾞｰﾃﾞﾝ"), true);
+        assert_eq!(Pattern::new_with_options("ｶﾞｰﾃﾞﾝ", width).is_match("ガーデン"), true);
+        assert_eq!(Pattern::new_with_options("パン", width).is_match("ﾊﾟﾝ"), true);
+        assert_eq!(Pattern::new_with_options("パン", width).is_match("ﾊﾞﾝ"), false);
+        // hiragana and katakana
+        assert_eq!(Pattern::new_with_options("カタカナ", kana).is_match("かたかな"), true);
+        assert_eq!(Pattern::new_with_options("カタカナ", kana).is_match("ｶﾀｶﾅ"), false);
+        assert_eq!(Pattern::new_with_options("かたかな", both).is_match("ｶﾀｶﾅ"), true);
+        // char ranges and selectors
+        assert_eq!(Pattern::new_with_options("[+あ-ん]", kana).is_match("カタカナ"), true);
+        assert_eq!(Pattern::new_with_options("[+ア-ン]", both).is_match("ｶﾀかな"), true);
+        assert_eq!(Pattern::new_with_options("[!a-z]", width).is_match("ａ"), false);
+        assert_eq!(Pattern::new_with_options("[+[:fullwidth:]]", width).is_match("ABC"), true);
+        assert_eq!(Pattern::new_with_options("[=ねこ|いぬ].txt", both).is_match("ﾈｺ.txt"), true);
+        assert_eq!(Pattern::new_with_options("[^=ねこ].txt", kana).is_match("ネコ.txt"), false);
+        // the result is a part of the original text
+        let pattern = Pattern::new_with_options("ガ#", width);
+        let result = pattern.find_match("xｶﾞ１y").unwrap();
+        assert_eq!((result.start, result.end), (1, 4));
+        assert_eq!(result.matched, "ｶﾞ１".to_string());
+        assert_eq!(pattern.extract_match("ｶﾞ１y"), Some("ｶﾞ１".to_string()));
+    }
+    #[test]
+    fn test_is_match_normalization() {
+        let nfc = MatchOptions { normalization: Normalization::Nfc, ..Default::default() };
+        let nfkc = MatchOptions { normalization: Normalization::Nfkc, ..Default::default() };
+        // decomposed text (as on macOS) and a precomposed pattern
+        assert_eq!(Pattern::new("が*").is_match("か\u{3099}.txt"), false);
+        assert_eq!(Pattern::new_with_options("が*", nfc).is_match("か\u{3099}.txt"), true);
+        assert_eq!(Pattern::new_with_options("か\u{3099}?", nfc).is_match("がく"), true);
+        assert_eq!(Pattern::new_with_options("caf?", nfc).is_match("cafe\u{301}"), true);
+        assert_eq!(Pattern::new_with_options("[=ぱん|ぴざ].txt", nfc).is_match("ひ\u{309a}さ\u{3099}.txt"), true);
+        // compatibility characters
+        assert_eq!(Pattern::new_with_options("ガ*", nfc).is_match("ｶﾞ"), false);
+        assert_eq!(Pattern::new_with_options("ガ*", nfkc).is_match("ｶﾞ"), true);
+        assert_eq!(Pattern::new_with_options("No#", nfkc).is_match("№①"), true);
+        assert_eq!(Pattern::new_with_options("[+a-z]", nfkc).is_match("ｆｉ"), true);
+        // with folding
+        let both = MatchOptions { fold_kana: true, normalization: Normalization::Nfc, ..Default::default() };
+        assert_eq!(Pattern::new_with_options("ガ*", both).is_match("か\u{3099}"), true);
+        // the offsets are in the original text
+        let pattern = Pattern::new_with_options("が#", nfc);
+        let result = pattern.find_match("xか\u{3099}1y").unwrap();
+        assert_eq!((result.start, result.end), (1, 4));
+        assert_eq!(result.matched, "か\u{3099}1".to_string());
+        let result = Pattern::new_with_options("平成", nfkc).find_match("令和㍻").unwrap();
+        assert_eq!((result.start, result.end), (2, 3));
+        // a match which ends or starts inside the expansion of a character covers the whole character
+        let result = Pattern::new_with_options("平", nfkc).find_match("令和㍻").unwrap();
+        assert_eq!((result.start, result.end, result.matched.as_str()), (2, 3, "㍻"));
+        let result = Pattern::new_with_options("成元", nfkc).find_match("㍻元年").unwrap();
+        assert_eq!((result.start, result.end, result.matched.as_str()), (0, 2, "㍻元"));
+        let spans = |src: &str, text: &str| {
+            Pattern::new_with_options(src, nfkc).find_iter(text).map(|m| (m.start, m.end)).collect::<Vec<_>>()
+        };
+        assert_eq!(spans("?", "㍻"), vec![(0, 1)]);
+        assert_eq!(spans("?", "a㍻b"), vec![(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(spans("[*x]", "㍻"), vec![(0, 0), (1, 1)]);
+        let reversed = Pattern::new_with_options("?", nfkc).rfind_iter("㍻b").map(|m| (m.start, m.end)).collect::<Vec<_>>();
+        assert_eq!(reversed, vec![(1, 2), (0, 1)]);
+    }
+    #[test]
+    fn test_is_match_grapheme() {
+        let grapheme = MatchOptions { grapheme: true, ..Default::default() };
+        // '?' is one cluster
+        assert_eq!(Pattern::new("??.png").is_match("👍🏽.png"), true); // two chars
+        assert_eq!(Pattern::new_with_options("?.png", grapheme).is_match("👍🏽.png"), true);
+        assert_eq!(Pattern::new_with_options("??.png", grapheme).is_match("👍🏽.png"), false);
+        assert_eq!(Pattern::new_with_options("caf?", grapheme).is_match("cafe\u{301}"), true);
+        assert_eq!(Pattern::new_with_options("??", grapheme).is_match("🇯🇵🇺🇸"), true);
+        // char lists match a cluster by its first character
+        assert_eq!(Pattern::new_with_options("[a-z][a-z]", grapheme).is_match("e\u{301}x"), true);
+        assert_eq!(Pattern::new_with_options("[!a-z]x", grapheme).is_match("👨\u{200d}👩\u{200d}👧x"), true);
+        assert_eq!(Pattern::new_with_options("[+[:hiragana:]]", grapheme).is_match("か\u{3099}き"), true);
+        assert_eq!(Pattern::new_with_options("[*a-z]!", grapheme).is_match("e\u{301}e\u{301}!"), true);
+        assert_eq!(Pattern::new_with_options("[-.].txt", grapheme).is_match("👍🏽👍🏽.txt"), true);
+        // a literal does not match a part of a cluster
+        assert_eq!(Pattern::new_with_options("cafe", grapheme).is_match("cafe\u{301}"), false);
+        assert_eq!(Pattern::new_with_options("cafe\u{301}", grapheme).is_match("cafe\u{301}"), true);
+        assert_eq!(Pattern::new_with_options("e", grapheme).find_match("e\u{301}e"), Some(MatchedResult::new(2, 3, "e".to_string())));
+        // the offsets are valid for slicing
+        let result = Pattern::new_with_options("?.png", grapheme).find_match("a👍🏽.png").unwrap();
+        assert_eq!((result.start, result.end), (1, 7));
+        let result = Pattern::new_with_options("*!", grapheme).find_match("x👍🏽!").unwrap();
+        assert_eq!((result.start, result.end), (0, 4));
+        assert_eq!(Pattern::new_with_options("?", grapheme).extract_match("👍🏽!"), Some("👍🏽".to_string()));
+    }
     #[test]
     fn test_is_match_strcut() {
         let pattern = Pattern::new("*.txt");
@@ -672,6 +2737,8 @@ mod tests {
         assert_eq!(extract_match("小説-[+ぁ-ん].txt", "小説-こころ.txt"), Some("小説-こころ.txt".to_string()));
         assert_eq!(extract_match("[+あ\\-].txt", "ああ-ああ.txt"), Some("ああ-ああ.txt".to_string()));
         assert_eq!(extract_match("A[+いろは\\-]Z", "Aいろ-いろは-Zabcdabcd"), Some("Aいろ-いろは-Z".to_string()));
+        assert_eq!(extract_match("[*0-9]px", "px"), Some("px".to_string()));
+        assert_eq!(extract_match("[?+\\-]###", "-123"), Some("-123".to_string()));
     }
     #[test]
     fn test_find_match() {
@@ -686,8 +2753,364 @@ mod tests {
         let result = find_match("c*t", "01234cat_____").unwrap_or(MatchedResult::new(0, 0, "".to_string()));
         assert_eq!(result.start, 5);
         assert_eq!(result.matched, "cat".to_string());
+        // multibyte characters are counted as one character
+        let result = find_match("c*t", "魚cat").unwrap();
+        assert_eq!((result.start, result.end), (1, 4));
         // failed match
         let result = find_match("abc", "a");
         assert_eq!(result, None);
     }
+    #[test]
+    fn test_find_match_anchor() {
+        let result = find_match("[^]ab", "abab").unwrap();
+        assert_eq!((result.start, result.end), (0, 2));
+        assert_eq!(find_match("[^]b", "abab"), None);
+        let result = find_match("ab[$]", "abab").unwrap();
+        assert_eq!((result.start, result.end), (2, 4));
+        assert_eq!(find_match("a[$]", "abab"), None);
+        assert_eq!(is_match("[^]a*[$]", "abc"), true);
+        assert_eq!(is_match("a[^]b", "ab"), false);
+        // line boundaries
+        let text = "title\nname: value\nnext";
+        assert_eq!(find_match("[^]name:", text), None);
+        let multiline = MatchOptions { multiline: true, ..Default::default() };
+        let pattern = Pattern::new_with_options("[^][+a-z]: *[$]", multiline);
+        let result = pattern.find_match(text).unwrap();
+        assert_eq!((result.start, result.matched.as_str()), (6, "name: value"));
+        assert_eq!(Pattern::new_with_options("e[$]", multiline).find_match(text).unwrap().start, 4);
+        // regular expressions
+        assert_eq!(Pattern::new("[^]a[$]").to_regex(), Some("^(?s:^a$)$".to_string()));
+        assert_eq!(Pattern::new_with_options("*[$]", multiline).to_regex(), Some("^(?s:.*?(?m:$))$".to_string()));
+    }
+    #[test]
+    fn test_match_kind() {
+        // (pattern, text, shortest, leftmost-first, leftmost-longest)
+        let cases = [
+            ("abc*g", "abcdefgxg", "abcdefg", "abcdefg", "abcdefgxg"),
+            ("a*", "abc", "a", "abc", "abc"),
+            ("a?", "abc", "ab", "ab", "ab"),
+            ("#", "12", "1", "1", "1"),
+            ("[a-c]", "abc", "a", "a", "a"),
+            ("[!x]", "abc", "a", "a", "a"),
+            ("[+0-9]", "123x", "1", "123", "123"),
+            ("[*0-9]", "123x", "", "123", "123"),
+            ("[?0-9]", "1x", "", "1", "1"),
+            ("[-,]", "ab,c", "a", "ab", "ab"),
+            ("[=a|ab|abc]", "abcd", "a", "a", "abc"),
+            ("[=ab|a]b", "abb", "ab", "abb", "abb"),
+            ("[^=x]", "abc", "a", "abc", "abc"),
+            ("[^=x]c", "acbc", "ac", "ac", "ac"), // the span ends at the first "c"
+            ("[<1-12>]", "12y", "12", "12", "12"),
+            ("[^]a*[$]", "aba", "aba", "aba", "aba"),
+        ];
+        for (src, text, shortest, first, longest) in cases {
+            for (kind, expected) in [
+                (MatchKind::Shortest, shortest),
+                (MatchKind::LeftmostFirst, first),
+                (MatchKind::LeftmostLongest, longest),
+            ] {
+                let pattern = Pattern::new_with_options(src, MatchOptions { match_kind: kind, ..Default::default() });
+                assert_eq!(pattern.extract_match(text), Some(expected.to_string()), "{} {:?}", src, kind);
+            }
+        }
+        // the start is always the leftmost
+        let longest = MatchOptions { match_kind: MatchKind::LeftmostLongest, ..Default::default() };
+        let result = Pattern::new_with_options("b*", longest).find_match("abab").unwrap();
+        assert_eq!((result.start, result.end), (1, 4));
+        // is_match is not affected
+        let shortest = MatchOptions { match_kind: MatchKind::Shortest, ..Default::default() };
+        assert_eq!(Pattern::new_with_options("a*", shortest).is_match("abc"), true);
+    }
+    #[test]
+    fn test_lazy_and_possessive_wildcard() {
+        // the lazy wildcard prefers the shortest span even at the end
+        assert_eq!(extract_match("a*", "abc"), Some("abc".to_string()));
+        assert_eq!(extract_match("a[*?]", "abc"), Some("a".to_string()));
+        assert_eq!(is_match("a[*?]", "abc"), true);
+        let pattern = Pattern::new("[+a-z]=[*?];");
+        let pairs = pattern.find_iter("a=1;b=xy;c=;").map(|m| m.matched).collect::<Vec<String>>();
+        assert_eq!(pairs, vec!["a=1;", "b=xy;", "c=;"]);
+        // the possessive wildcard takes the rest of the text and never gives it back
+        assert_eq!(is_match("a[*+]", "abc"), true);
+        assert_eq!(is_match("a[*+]c", "abc"), false);
+        assert_eq!(is_match("a[*+][$]", "abc"), true);
+        assert_eq!(extract_match("[=a[*+]|ab]", "abc"), Some("abc".to_string()));
+        assert_eq!(find_match("b[*+]", "abc").unwrap().matched, "bc".to_string());
+        let shortest = MatchOptions { match_kind: MatchKind::Shortest, ..Default::default() };
+        assert_eq!(Pattern::new_with_options("a[*+]", shortest).extract_match("abc"), Some("abc".to_string()));
+        // `[*?]` and `[*+]` are whole tokens
+        assert_eq!(is_match("[*?a]", "?a?"), true);
+        assert_eq!(is_match("[*\\?]", "??"), true);
+        // regular expressions
+        assert_eq!(Pattern::new("a[*?]").to_regex(), Some("^(?s:a.*?)$".to_string()));
+        assert_eq!(Pattern::new("a[*+]").to_regex(), Some("^(?s:a.*$)$".to_string()));
+    }
+    #[test]
+    fn test_find_iter() {
+        let words = |pattern: &Pattern, text: &str| {
+            pattern.find_iter(text).map(|m| (m.start, m.matched)).collect::<Vec<(usize, String)>>()
+        };
+        let pattern = Pattern::new("[+0-9]");
+        assert_eq!(words(&pattern, "a1b22c333"), vec![(1, "1".to_string()), (3, "22".to_string()), (6, "333".to_string())]);
+        assert_eq!(words(&pattern, "abc"), vec![]);
+        // an empty match right after a match is skipped
+        let pattern = Pattern::new("[*0-9]");
+        assert_eq!(words(&pattern, "12a"), vec![(0, "12".to_string()), (3, "".to_string())]);
+        // the match kind applies to every match
+        let text = "<a><b>";
+        assert_eq!(words(&Pattern::new("<*>"), text), vec![(0, "<a>".to_string()), (3, "<b>".to_string())]);
+        let longest = MatchOptions { match_kind: MatchKind::LeftmostLongest, ..Default::default() };
+        assert_eq!(words(&Pattern::new_with_options("<*>", longest), text), vec![(0, "<a><b>".to_string())]);
+        let shortest = MatchOptions { match_kind: MatchKind::Shortest, ..Default::default() };
+        assert_eq!(words(&Pattern::new_with_options("[+a-z]", shortest), "ab"), vec![(0, "a".to_string()), (1, "b".to_string())]);
+        // the offsets refer to the original text
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let pattern = Pattern::new_with_options("[+0-9]", width);
+        assert_eq!(words(&pattern, "x１２y3"), vec![(1, "１２".to_string()), (4, "3".to_string())]);
+    }
+    #[test]
+    fn test_rfind_match() {
+        let rfind = |src: &str, text: &str| {
+            Pattern::new(src).rfind_match(text).map(|m| (m.start, m.end, m.matched))
+        };
+        assert_eq!(rfind("#", "a1b2c3"), Some((5, 6, "3".to_string())));
+        assert_eq!(rfind(".[+a-z]", "archive.tar.gz"), Some((11, 14, ".gz".to_string())));
+        assert_eq!(rfind("-v#", "app-v1-v2.txt"), Some((6, 9, "-v2".to_string())));
+        assert_eq!(rfind("x", "abc"), None);
+        // the match kind chooses the start from the right
+        assert_eq!(rfind("a*", "xaya"), Some((3, 4, "a".to_string())));
+        let longest = MatchOptions { match_kind: MatchKind::LeftmostLongest, ..Default::default() };
+        let result = Pattern::new_with_options("a*", longest).rfind_match("xaya").unwrap();
+        assert_eq!((result.start, result.end), (1, 4));
+        // anchors, number ranges and the possessive wildcard keep their meaning
+        assert_eq!(rfind("[^]#", "1a2"), Some((0, 1, "1".to_string())));
+        assert_eq!(rfind("#[$]", "1a2"), Some((2, 3, "2".to_string())));
+        assert_eq!(rfind("[<10-20>]", "15x12y9"), Some((3, 5, "12".to_string())));
+        assert_eq!(rfind("[<1-12>]", "123"), None);
+        assert_eq!(rfind("b[*+]", "abcb"), Some((3, 4, "b".to_string())));
+        assert_eq!(rfind("[=ab|b]c", "abcbc"), Some((3, 5, "bc".to_string())));
+        // clusters and offsets in the original text
+        let grapheme = MatchOptions { grapheme: true, ..Default::default() };
+        let result = Pattern::new_with_options("[a-z]", grapheme).rfind_match("xe\u{301}").unwrap();
+        assert_eq!((result.start, result.end), (1, 3));
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let result = Pattern::new_with_options("[+0-9]", width).rfind_match("１２x").unwrap();
+        assert_eq!((result.start, result.matched.as_str()), (0, "１２"));
+    }
+    #[test]
+    fn test_rfind_iter() {
+        let pattern = Pattern::new("[+0-9]");
+        let numbers = pattern.rfind_iter("1a22b333").map(|m| m.matched).collect::<Vec<String>>();
+        assert_eq!(numbers, vec!["333", "22", "1"]);
+        let pattern = Pattern::new("[*0-9]");
+        let ranges = pattern.rfind_iter("a12").map(|m| (m.start, m.end)).collect::<Vec<(usize, usize)>>();
+        assert_eq!(ranges, vec![(1, 3), (0, 0)]);
+    }
+    #[test]
+    fn test_split() {
+        fn split<'t>(src: &str, text: &'t str) -> Vec<&'t str> {
+            Pattern::new(src).split(text).collect()
+        }
+        assert_eq!(split("[+ \t]", "a  b\tc"), vec!["a", "b", "c"]);
+        assert_eq!(split("[=, |; ]", "a, b; c"), vec!["a", "b", "c"]);
+        assert_eq!(split(",", "a,,b,"), vec!["a", "", "b", ""]);
+        assert_eq!(split(",", ""), vec![""]);
+        assert_eq!(split("・", "日本・東京・港区"), vec!["日本", "東京", "港区"]);
+        // empty matches split between the characters
+        assert_eq!(split("[*,]", "a,b"), vec!["", "a", "b", ""]);
+        // the pieces are slices of the original text
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let pattern = Pattern::new_with_options("[+0-9]", width);
+        assert_eq!(pattern.split("ａ１２ｂ3c").collect::<Vec<&str>>(), vec!["ａ", "ｂ", "c"]);
+    }
+    #[test]
+    fn test_splitn_and_split_inclusive() {
+        let pattern = Pattern::new("[+ ]");
+        assert_eq!(pattern.splitn("a b  c d", 3).collect::<Vec<&str>>(), vec!["a", "b", "c d"]);
+        assert_eq!(pattern.splitn("a b", 5).collect::<Vec<&str>>(), vec!["a", "b"]);
+        assert_eq!(pattern.splitn("a b", 1).collect::<Vec<&str>>(), vec!["a b"]);
+        assert_eq!(pattern.splitn("a b", 0).count(), 0);
+        let pattern = Pattern::new("[=\r\n|\n]");
+        assert_eq!(pattern.split_inclusive("a\r\nb\nc").collect::<Vec<&str>>(), vec!["a\r\n", "b\n", "c"]);
+        assert_eq!(pattern.split_inclusive("a\n").collect::<Vec<&str>>(), vec!["a\n"]);
+        assert_eq!(pattern.split_inclusive("").count(), 0);
+    }
+    #[test]
+    fn test_match_suffix() {
+        let pattern = Pattern::new("[+0-9].txt");
+        let result = pattern.match_suffix("report-2024.txt").unwrap();
+        assert_eq!((result.start, result.end, result.matched.as_str()), (7, 15, "2024.txt"));
+        assert_eq!(pattern.match_suffix("2024.txt.bak"), None);
+        // the longest suffix
+        assert_eq!(Pattern::new("b*").match_suffix("abab").unwrap().start, 1);
+        assert_eq!(Pattern::new("").match_suffix("abc").unwrap().start, 3);
+        let result = Pattern::new("[=.tar.gz|.gz]").match_suffix("a.tar.gz").unwrap();
+        assert_eq!(result.matched, ".tar.gz");
+    }
+    #[test]
+    fn test_prefix_status() {
+        let pattern = Pattern::new("###-####");
+        assert_eq!(pattern.prefix_status(""), PrefixStatus::Viable);
+        assert_eq!(pattern.prefix_status("123"), PrefixStatus::Viable);
+        assert_eq!(pattern.prefix_status("123-4567"), PrefixStatus::Complete);
+        assert_eq!(pattern.prefix_status("12a"), PrefixStatus::Dead);
+        assert_eq!(pattern.prefix_status("123-45678"), PrefixStatus::Dead);
+        let pattern = Pattern::new("[<1-12>]/[<1-31>]");
+        assert_eq!(pattern.prefix_status("1"), PrefixStatus::Viable);
+        assert_eq!(pattern.prefix_status("13"), PrefixStatus::Dead);
+        assert_eq!(pattern.prefix_status("12/3"), PrefixStatus::Complete);
+        assert_eq!(pattern.prefix_status("12/32"), PrefixStatus::Dead);
+        assert_eq!(pattern.prefix_status("12/3/"), PrefixStatus::Dead);
+        assert_eq!(Pattern::new("[<1-12>]*").prefix_status("123"), PrefixStatus::Dead);
+        // a complete text may go on
+        assert_eq!(Pattern::new("[+0-9]").prefix_status("12"), PrefixStatus::Complete);
+        assert_eq!(Pattern::new("[=jpg|jpeg]").prefix_status("jpe"), PrefixStatus::Viable);
+        assert_eq!(Pattern::new("a*[$]").prefix_status("abc"), PrefixStatus::Complete);
+        assert_eq!(Pattern::new("[^=ab]").prefix_status("ab"), PrefixStatus::Viable);
+        assert_eq!(Pattern::new("[^=ab]").prefix_status("abc"), PrefixStatus::Complete);
+        assert_eq!(Pattern::new("[!0-9]x").prefix_status("1"), PrefixStatus::Dead);
+        // the text is folded with the options
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        assert_eq!(Pattern::new_with_options("###", width).prefix_status("１２"), PrefixStatus::Viable);
+    }
+    #[test]
+    fn test_next_chars() {
+        let ranges = |pattern: &str, text: &str| {
+            Pattern::new(pattern).next_chars(text).map(|ranges| {
+                ranges.iter().map(|r| format!("{}-{}", r.start, r.end)).collect::<Vec<String>>()
+            })
+        };
+        assert_eq!(ranges("###-####", "123"), Some(vec!["---".to_string()]));
+        assert_eq!(ranges("###-####", "12"), Some(vec!["0-9".to_string()]));
+        assert_eq!(ranges("###-####", "123-4567"), Some(vec![]));
+        assert_eq!(ranges("[=yes|no]", ""), Some(vec!["n-n".to_string(), "y-y".to_string()]));
+        assert_eq!(ranges("[<0-255>]", "25"), Some(vec!["0-5".to_string()]));
+        assert_eq!(ranges("a*", "a"), None);
+        assert_eq!(ranges("a", "b"), Some(vec![]));
+    }
+    #[test]
+    fn test_format_input() {
+        let pattern = Pattern::new("(###)###-####");
+        assert_eq!(pattern.format_input("1112223333"), Some("(111)222-3333".to_string()));
+        assert_eq!(pattern.format_input("111222333"), None);
+        assert_eq!(pattern.format_input("11122233334"), None);
+        assert_eq!(pattern.format_input("111222333a"), None);
+        assert_eq!(pattern.strip_literals("(111)222-3333"), Some("1112223333".to_string()));
+        assert_eq!(pattern.strip_literals("111-222-3333"), None);
+        let pattern = Pattern::new("[A-Z][A-Z]-[!0]##");
+        assert_eq!(pattern.format_input("JP123"), Some("JP-123".to_string()));
+        assert_eq!(pattern.format_input("JP023"), None);
+        assert_eq!(pattern.strip_literals("JP-123"), Some("JP123".to_string()));
+        // only literals and tokens of a single character
+        assert_eq!(Pattern::new("#*").format_input("1"), None);
+        assert_eq!(Pattern::new("[=a|b]").strip_literals("a"), None);
+        // the characters are folded with the options
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let pattern = Pattern::new_with_options("###-####", width);
+        assert_eq!(pattern.format_input("１２３４５６７"), Some("１２３-４５６７".to_string()));
+        assert_eq!(pattern.strip_literals("123－4567"), Some("1234567".to_string()));
+    }
+    #[test]
+    fn test_sample() {
+        for src in ["###-####", "*.[=jpg|png]", "[+a-z]@[+a-z].[=com|jp]", "[<1-12>]/[<1-31>]", "[^=a|b]x", "[!0-9][-a]"] {
+            let pattern = Pattern::new(src);
+            for seed in 0..20 {
+                let text = pattern.sample(seed).unwrap();
+                assert_eq!(pattern.is_match(&text), true, "{} {}", src, text);
+            }
+            // the same seed gives the same string
+            assert_eq!(pattern.sample(7), pattern.sample(7));
+        }
+        assert_eq!(Pattern::new("a[^]b").sample(0), None);
+        // the length of a wildcard is bounded
+        assert_eq!((0..20).all(|seed| Pattern::new("*").sample(seed).unwrap().len() <= 3), true);
+    }
+    #[test]
+    fn test_examples() {
+        assert_eq!(Pattern::new("IMG_##.[=jpg|png|gif]").examples(3), vec![
+            "IMG_00.jpg".to_string(), "IMG_99.png".to_string(), "IMG_00.gif".to_string(),
+        ]);
+        let examples = Pattern::new("[<1-12>]").examples(2);
+        assert_eq!(examples, vec!["1".to_string(), "12".to_string()]);
+        let examples = Pattern::new("a*").examples(3);
+        assert_eq!(examples[0], "a");
+        assert_eq!(examples.len(), 3);
+        assert_eq!(Pattern::new("abc").examples(5), vec!["abc".to_string()]);
+        assert_eq!(Pattern::new("[$]a").examples(5), Vec::<String>::new());
+    }
+    #[test]
+    fn test_expand() {
+        let expand = |src: &str| Pattern::new(src).expand().map(|strings| strings.collect::<Vec<String>>());
+        assert_eq!(expand("log-[=dev|prod]-[0-2].txt").unwrap(), vec![
+            "log-dev-0.txt", "log-dev-1.txt", "log-dev-2.txt", "log-prod-0.txt", "log-prod-1.txt", "log-prod-2.txt",
+        ]);
+        assert_eq!(expand("v[?2]").unwrap(), vec!["v", "v2"]);
+        assert_eq!(expand("[<8-11>]").unwrap(), vec!["8", "9", "10", "11"]);
+        assert_eq!(expand("[<8-10>]h").unwrap(), vec!["8h", "9h", "10h"]);
+        assert_eq!(expand("[=a|b[=c|d]]").unwrap(), vec!["a", "bc", "bd"]);
+        assert_eq!(expand("abc").unwrap(), vec!["abc"]);
+        // the duplicates are listed once
+        assert_eq!(expand("[=a|a]").unwrap(), vec!["a"]);
+        assert_eq!(expand("[?a][?a]").unwrap(), vec!["", "a", "aa"]);
+        assert_eq!(Pattern::new("[=ab|a][=b|]").expand_with_limit(4).unwrap(), vec!["abb", "ab", "a"]);
+        // an anchor rejects the string
+        assert_eq!(expand("a[^]b").unwrap(), Vec::<String>::new());
+        for src in ["*.txt", "?", "[!a]", "[+0-9]", "[*a]", "[-a]", "[^=a]x", "[<0-9:z>]", "[=a|b*]"] {
+            assert_eq!(expand(src), None, "{}", src);
+        }
+        // the count and the limit
+        let pattern = Pattern::new("[<00-99>]-[a-z]##");
+        assert_eq!(pattern.expand_count(), Some(260000));
+        assert_eq!(pattern.expand_with_limit(1000), None);
+        assert_eq!(pattern.expand().unwrap().nth(1), Some("00-a01".to_string()));
+        assert_eq!(Pattern::new("[=a|b][0-1]").expand_with_limit(4).unwrap().len(), 4);
+        assert_eq!(Pattern::new("*").expand_count(), None);
+    }
+    #[test]
+    fn test_subset_and_overlap() {
+        let p = Pattern::new;
+        assert_eq!(p("*.txt").is_subset_of(&p("*")), true);
+        assert_eq!(p("report-##.txt").is_subset_of(&p("report-*.txt")), true);
+        assert_eq!(p("*.txt").is_subset_of(&p("*.[=txt|csv]")), true);
+        assert_eq!(p("*.[=txt|csv]").is_subset_of(&p("*.txt")), false);
+        assert_eq!(p("[<1-12>]").is_subset_of(&p("[+0-9]")), true);
+        assert_eq!(p("[<0-12:z>]").is_subset_of(&p("[+0-9]")), true);
+        assert_eq!(p("[+0-9]").is_subset_of(&p("[<0-12>]")), false);
+        assert_eq!(p("[+a-z]").is_subset_of(&p("[^=abc]")), false);
+        assert_eq!(p("[+a-z]").is_subset_of(&p("[^=ABC]")), true);
+        // the shortest witness
+        assert_eq!(p("a*").overlaps(&p("*b")), Some("ab".to_string()));
+        assert_eq!(p("img_##.png").overlaps(&p("*_12.*")), Some("img_12.png".to_string()));
+        assert_eq!(p("*.txt").overlaps(&p("*.csv")), None);
+        assert_eq!(p("[<0-255>]").overlaps(&p("2[5-9]#")), Some("250".to_string()));
+        // a number takes the whole run of digits
+        assert_eq!(p("[<1-12>]#").is_empty(), true);
+        assert_eq!(p("[<1-12>]-#").is_subset_of(&p("*#")), true);
+        assert_eq!(p("[<1-12>][+0-9]").overlaps(&p("###")), None);
+        // empty and equivalent patterns
+        assert_eq!(p("a[^]b").is_empty(), true);
+        assert_eq!(p("[=a|b][$]").is_empty(), false);
+        assert_eq!(p("[!\u{0}-\u{10FFFF}]").is_empty(), true);
+        assert_eq!(p("[+a]").is_equivalent(&p("a[*a]")), true);
+        assert_eq!(p("[=ab|a]*").is_equivalent(&p("a*")), true);
+        assert_eq!(p("[<00-59>]").is_equivalent(&p("[0-5]#")), true);
+        assert_eq!(p("a*").is_equivalent(&p("a?*")), false);
+        // the options are applied to the text
+        let width = MatchOptions { fold_width: true, ..Default::default() };
+        let w = |src: &str| Pattern::new_with_options(src, width);
+        assert_eq!(w("ＡＢ*").is_subset_of(&w("AB*")), true);
+        assert_eq!(w("[Ａ]").is_subset_of(&w("Ａ")), true);
+        assert_eq!(w("[Ａ]").is_equivalent(&w("A")), true);
+        assert_eq!(w("[+ａ-ｚ]").is_equivalent(&w("[+a-z]")), true);
+        assert_eq!(w("[!Ａ]").overlaps(&w("A")), None);
+        assert_eq!(w("[ｶ]").overlaps(&w("?")), Some("カ".to_string()));
+        let kana = MatchOptions { fold_kana: true, ..Default::default() };
+        let k = |src: &str| Pattern::new_with_options(src, kana);
+        assert_eq!(k("[か]").is_equivalent(&k("か")), true);
+        assert_eq!(k("[+あ-ん]").is_subset_of(&k("[+ア-ン]")), true);
+        assert_eq!(k("[+ア-ン]").is_subset_of(&k("[+あ-ん]")), true);
+        assert_eq!(k("[+ア-ヴ]").is_subset_of(&k("[+あ-ん]")), false);
+        assert_eq!(k("[!か]").is_subset_of(&k("[!カ]")), true);
+        assert_eq!(k("ねこ*").overlaps(&k("*[コ]")), Some("ネコ".to_string()));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+//! Listing every string of a finite pattern
+//!
+//! A pattern is finite when it has none of `*`, `?`, `[!...]`, `[+...]`, `[*...]`, `[-...]`, `[^=...]`
+//! and `[<min-max:z>]` without a width. The strings are listed lazily in the order of the tokens,
+//! like the brace expansion of shells.
+
+use crate::ex::{CharList, PatternChar};
+use std::rc::Rc;
+
+/// check if the pattern matches a finite number of strings
+pub fn is_finite(pattern: &[PatternChar]) -> bool {
+    pattern.iter().all(|token| match token {
+        PatternChar::Char(_) | PatternChar::Number | PatternChar::CharList(_) | PatternChar::CharListOptional(_) => true,
+        PatternChar::Start | PatternChar::End => true,
+        PatternChar::Selector(selector) => selector.iter().all(|sub_pattern| is_finite(sub_pattern)),
+        PatternChar::NumberRange(range) => !range.leading_zeros || range.width.is_some(),
+        _ => false,
+    })
+}
+
+/// count the strings of a finite pattern (duplicates included, saturating at `u128::MAX`)
+pub fn count(pattern: &[PatternChar]) -> u128 {
+    pattern.iter().fold(1u128, |total, token| total.saturating_mul(count_token(token)))
+}
+
+fn count_token(token: &PatternChar) -> u128 {
+    let chars = |charlist: &CharList| -> u128 {
+        charlist.ranges().iter().map(|r| (r.end as u128) - (r.start as u128) + 1).sum()
+    };
+    match token {
+        PatternChar::Number => 10,
+        PatternChar::CharList(charlist) => chars(charlist),
+        PatternChar::CharListOptional(charlist) => chars(charlist) + 1,
+        PatternChar::Selector(selector) => {
+            selector.iter().fold(0u128, |total, sub_pattern| total.saturating_add(count(sub_pattern)))
+        }
+        PatternChar::NumberRange(range) => (range.max as u128).saturating_sub(range.min as u128) + 1,
+        _ => 1,
+    }
+}
+
+/// list the strings of the finite pattern from the token at `index`
+pub fn strings(pattern: Rc<Vec<PatternChar>>, index: usize) -> Box<dyn Iterator<Item = String>> {
+    if index == pattern.len() {
+        return Box::new(std::iter::once(String::new()));
+    }
+    let heads = token_strings(&pattern[index]);
+    Box::new(heads.flat_map(move |head| {
+        strings(pattern.clone(), index + 1).map(move |tail| format!("{}{}", head, tail))
+    }))
+}
+
+fn token_strings(token: &PatternChar) -> Box<dyn Iterator<Item = String>> {
+    let chars = |charlist: &CharList| {
+        let ranges = charlist.ranges().to_vec();
+        ranges.into_iter().flat_map(|r| (r.start..=r.end).map(String::from))
+    };
+    match token {
+        PatternChar::Char(c) => Box::new(std::iter::once(c.to_string())),
+        PatternChar::Number => Box::new(('0'..='9').map(String::from)),
+        PatternChar::CharList(charlist) => Box::new(chars(charlist)),
+        PatternChar::CharListOptional(charlist) => Box::new(std::iter::once(String::new()).chain(chars(charlist))),
+        PatternChar::Selector(selector) => {
+            let selector = selector.iter().map(|sub_pattern| Rc::new(sub_pattern.clone())).collect::<Vec<_>>();
+            Box::new(selector.into_iter().flat_map(|sub_pattern| strings(sub_pattern, 0)))
+        }
+        PatternChar::NumberRange(range) => {
+            let width = range.width.unwrap_or(0);
+            Box::new((range.min..=range.max).map(move |n| format!("{:0width$}", n)))
+        }
+        _ => Box::new(std::iter::once(String::new())),
+    }
+}
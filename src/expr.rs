@@ -0,0 +1,403 @@
+//! Boolean pattern expressions
+//!
+//! Combine extended wildcard patterns with `&` (and), `|` (or), `!` (not) and parentheses,
+//! such as `*.log & !debug-* | *.err`.
+//! `!` binds tighter than `&`, and `&` binds tighter than `|`.
+//! Inside a pattern, write `\&`, `\|`, `\!`, `\(` and `\)` for the literal characters
+//! (characters in `[...]` need no escape).
+//! Spaces around the operators are not part of the patterns, so write `\ ` for a space at either end of a pattern.
+
+use crate::ex::{Pattern, PatternChar, PatternError};
+use std::fmt;
+
+/// Boolean expression over patterns
+#[derive(Clone, Debug)]
+pub enum PatternExpr {
+    Pattern(Pattern),
+    Not(Box<PatternExpr>),
+    And(Vec<PatternExpr>),
+    Or(Vec<PatternExpr>),
+    True,
+    False,
+}
+
+/// Error of parsing a pattern expression (`position` is counted in characters)
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExprError {
+    /// an operator or parenthesis is not expected here
+    UnexpectedChar { position: usize, ch: char },
+    /// the expression ends where a pattern is expected
+    UnexpectedEnd,
+    /// the parenthesis is not closed
+    UnclosedParen { position: usize },
+    /// a pattern is invalid (the position in the error is counted from the start of the pattern)
+    Pattern(PatternError),
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprError::UnexpectedChar { position, ch } => write!(f, "unexpected '{}' at {}", ch, position),
+            ExprError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            ExprError::UnclosedParen { position } => write!(f, "parenthesis at {} is not closed", position),
+            ExprError::Pattern(error) => write!(f, "invalid pattern: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+impl PatternExpr {
+    /// parse a pattern expression
+    pub fn parse(expr_str: &str) -> Result<Self, ExprError> {
+        let mut parser = ExprParser {
+            chars: expr_str.chars().collect(),
+            index: 0,
+        };
+        let expr = parser.read_or()?;
+        parser.skip_spaces();
+        if parser.index < parser.chars.len() {
+            let ch = parser.chars[parser.index];
+            return Err(ExprError::UnexpectedChar { position: parser.index, ch });
+        }
+        Ok(expr)
+    }
+    /// check if the expression matches the text
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            PatternExpr::Pattern(pattern) => pattern.is_match(text),
+            PatternExpr::Not(expr) => !expr.is_match(text),
+            PatternExpr::And(list) => list.iter().all(|expr| expr.is_match(text)),
+            PatternExpr::Or(list) => list.iter().any(|expr| expr.is_match(text)),
+            PatternExpr::True => true,
+            PatternExpr::False => false,
+        }
+    }
+    /// filter the text list by the expression
+    pub fn filter(&self, str_list: &[String]) -> Vec<String> {
+        str_list.iter().filter(|text| self.is_match(text)).cloned().collect()
+    }
+    /// fold the branches which are trivially true or false.
+    /// a pattern of only `*` is always true.
+    pub fn simplify(self) -> PatternExpr {
+        match self {
+            PatternExpr::Pattern(pattern) => {
+                if !pattern.pattern.is_empty() && pattern.pattern.iter().all(|c| matches!(c, PatternChar::Wildcard)) {
+                    return PatternExpr::True;
+                }
+                PatternExpr::Pattern(pattern)
+            }
+            PatternExpr::Not(expr) => match expr.simplify() {
+                PatternExpr::True => PatternExpr::False,
+                PatternExpr::False => PatternExpr::True,
+                PatternExpr::Not(inner) => *inner,
+                expr => PatternExpr::Not(Box::new(expr)),
+            },
+            PatternExpr::And(list) => {
+                let mut result = vec![];
+                for expr in list {
+                    match expr.simplify() {
+                        PatternExpr::True => {}
+                        PatternExpr::False => return PatternExpr::False,
+                        PatternExpr::And(inner) => result.extend(inner),
+                        expr => result.push(expr),
+                    }
+                }
+                match result.len() {
+                    0 => PatternExpr::True,
+                    1 => result.remove(0),
+                    _ => PatternExpr::And(result),
+                }
+            }
+            PatternExpr::Or(list) => {
+                let mut result = vec![];
+                for expr in list {
+                    match expr.simplify() {
+                        PatternExpr::False => {}
+                        PatternExpr::True => return PatternExpr::True,
+                        PatternExpr::Or(inner) => result.extend(inner),
+                        expr => result.push(expr),
+                    }
+                }
+                match result.len() {
+                    0 => PatternExpr::False,
+                    1 => result.remove(0),
+                    _ => PatternExpr::Or(result),
+                }
+            }
+            expr => expr,
+        }
+    }
+}
+
+impl fmt::Display for PatternExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternExpr::Pattern(pattern) => {
+                // escape the operators of the expression outside of '[...]'
+                // ('|' is already escaped by the pattern) and the spaces at either end
+                let last = pattern.pattern.len().saturating_sub(1);
+                for (i, token) in pattern.pattern.iter().enumerate() {
+                    if let PatternChar::Char(c) = token {
+                        let is_space = (i == 0 || i == last) && c.is_whitespace() && !"\t\n\r".contains(*c);
+                        if "&!()".contains(*c) || is_space {
+                            write!(f, "\\")?;
+                        }
+                    }
+                    write!(f, "{}", token)?;
+                }
+                Ok(())
+            }
+            PatternExpr::Not(expr) => match expr.as_ref() {
+                PatternExpr::And(_) | PatternExpr::Or(_) => write!(f, "!({})", expr),
+                _ => write!(f, "!{}", expr),
+            },
+            PatternExpr::And(list) => {
+                for (i, expr) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " & ")?;
+                    }
+                    match expr {
+                        PatternExpr::Or(_) => write!(f, "({})", expr)?,
+                        _ => write!(f, "{}", expr)?,
+                    }
+                }
+                Ok(())
+            }
+            PatternExpr::Or(list) => {
+                for (i, expr) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", expr)?;
+                }
+                Ok(())
+            }
+            PatternExpr::True => write!(f, "*"),
+            PatternExpr::False => write!(f, "!*"),
+        }
+    }
+}
+
+/// Recursive descent parser of pattern expressions
+struct ExprParser {
+    chars: Vec<char>,
+    index: usize,
+}
+impl ExprParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.index += 1;
+        }
+    }
+    /// or := and ('|' and)*
+    fn read_or(&mut self) -> Result<PatternExpr, ExprError> {
+        let mut list = vec![self.read_and()?];
+        loop {
+            self.skip_spaces();
+            if self.peek() != Some('|') { break; }
+            self.index += 1; // skip '|'
+            list.push(self.read_and()?);
+        }
+        Ok(if list.len() == 1 { list.remove(0) } else { PatternExpr::Or(list) })
+    }
+    /// and := not ('&' not)*
+    fn read_and(&mut self) -> Result<PatternExpr, ExprError> {
+        let mut list = vec![self.read_not()?];
+        loop {
+            self.skip_spaces();
+            if self.peek() != Some('&') { break; }
+            self.index += 1; // skip '&'
+            list.push(self.read_not()?);
+        }
+        Ok(if list.len() == 1 { list.remove(0) } else { PatternExpr::And(list) })
+    }
+    /// not := '!' not | '(' or ')' | pattern
+    fn read_not(&mut self) -> Result<PatternExpr, ExprError> {
+        self.skip_spaces();
+        match self.peek() {
+            None => Err(ExprError::UnexpectedEnd),
+            Some('!') => {
+                self.index += 1; // skip '!'
+                Ok(PatternExpr::Not(Box::new(self.read_not()?)))
+            }
+            Some('(') => {
+                let position = self.index;
+                self.index += 1; // skip '('
+                let expr = self.read_or()?;
+                self.skip_spaces();
+                if self.peek() != Some(')') {
+                    return Err(ExprError::UnclosedParen { position });
+                }
+                self.index += 1; // skip ')'
+                Ok(expr)
+            }
+            Some(ch) if "&|)".contains(ch) => Err(ExprError::UnexpectedChar { position: self.index, ch }),
+            Some(_) => match Pattern::try_new(&self.read_pattern_str()) {
+                Ok(pattern) => Ok(PatternExpr::Pattern(pattern)),
+                Err(error) => Err(ExprError::Pattern(error)),
+            },
+        }
+    }
+    /// read the source of a pattern until an operator outside of '[...]',
+    /// without the spaces before the operator
+    fn read_pattern_str(&mut self) -> String {
+        let start = self.index;
+        let mut end = self.index;
+        while let Some(c) = self.peek() {
+            if "&|()".contains(c) { break; }
+            self.skip_token();
+            if !c.is_whitespace() {
+                end = self.index;
+            }
+        }
+        self.chars[start..end].iter().collect()
+    }
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+    /// skip a character, an escape sequence or '[...]' of the pattern
+    fn skip_token(&mut self) {
+        match self.peek() {
+            Some('\\') => self.index = (self.index + 2).min(self.chars.len()),
+            Some('[') => self.skip_bracket(),
+            _ => self.index += 1,
+        }
+    }
+    /// skip '[...]' in the same way as the pattern parser reads it
+    fn skip_bracket(&mut self) {
+        self.index += 1; // skip '['
+        match (self.peek(), self.peek_at(1), self.peek_at(2)) {
+            (Some('*'), Some('?' | '+'), Some(']')) => self.index += 3,
+            (Some('^' | '$'), Some(']'), _) => self.index += 2,
+            (Some('='), _, _) => {
+                self.index += 1; // skip '='
+                self.skip_selector();
+            }
+            (Some('^'), Some('='), _) => {
+                self.index += 2; // skip '^='
+                self.skip_selector();
+            }
+            (Some('!' | '+' | '*' | '?' | '-'), _, _) => {
+                self.index += 1; // skip the kind of the list
+                self.skip_charlist();
+            }
+            _ => self.skip_charlist(), // also `[@name]` and `[<min-max>]`
+        }
+    }
+    /// skip the alternatives of a selector and its ']'
+    fn skip_selector(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                ']' => {
+                    self.index += 1;
+                    return;
+                }
+                '|' => self.index += 1,
+                _ => self.skip_token(),
+            }
+        }
+    }
+    /// skip a character list and its ']', with the classes such as `[:alpha:]`
+    /// and the nested lists of the set operations such as `[a-z--[aeiou]]`
+    fn skip_charlist(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                ']' => {
+                    self.index += 1;
+                    return;
+                }
+                '\\' => self.index = (self.index + 2).min(self.chars.len()),
+                '[' if self.peek_at(1) == Some(':') => {
+                    let name_len = self.chars[self.index + 2..].iter().take_while(|c| c.is_ascii_alphanumeric() || **c == '_').count();
+                    let is_class = self.peek_at(name_len + 2) == Some(':') && self.peek_at(name_len + 3) == Some(']');
+                    self.index += if is_class { name_len + 4 } else { 1 };
+                }
+                '-' | '&' if self.peek_at(1) == Some(c) && self.peek_at(2) == Some('[') && self.peek_at(3) != Some(':') => {
+                    self.index += 3; // skip the operator and '['
+                    self.skip_charlist();
+                }
+                _ => self.index += 1,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_expr_is_match() {
+        let expr = PatternExpr::parse("*.log & !debug-* | *.err").unwrap();
+        assert_eq!(expr.is_match("app.log"), true);
+        assert_eq!(expr.is_match("debug-app.log"), false);
+        assert_eq!(expr.is_match("debug-app.err"), true);
+        assert_eq!(expr.is_match("app.txt"), false);
+        let expr = PatternExpr::parse("*.log & !(debug-* | trace-*)").unwrap();
+        assert_eq!(expr.is_match("app.log"), true);
+        assert_eq!(expr.is_match("trace-app.log"), false);
+        assert_eq!(expr.is_match("debug-app.log"), false);
+        // '|' in '[...]' and escaped operators belong to the pattern
+        let expr = PatternExpr::parse("[=cat|dog].txt | a\\&b").unwrap();
+        assert_eq!(expr.is_match("dog.txt"), true);
+        assert_eq!(expr.is_match("a&b"), true);
+        let expr = PatternExpr::parse("!!*.txt").unwrap();
+        assert_eq!(expr.is_match("a.txt"), true);
+        // '[' in a char list does not open another bracket
+        let expr = PatternExpr::parse("[[] | x").unwrap();
+        assert_eq!(expr.is_match("["), true);
+        assert_eq!(expr.is_match("x"), true);
+        let expr = PatternExpr::parse("[=a|[[]]b | [a-z--[aeiou]] | [[:digit:]|]").unwrap();
+        assert_eq!(expr.is_match("[b"), true);
+        assert_eq!(expr.is_match("b"), true);
+        assert_eq!(expr.is_match("|"), true);
+        assert_eq!(expr.is_match("a"), false);
+        // only the spaces around the operators are removed
+        let expr = PatternExpr::parse("a\\  | [= ]  & ?").unwrap();
+        assert_eq!(expr.is_match("a "), true);
+        assert_eq!(expr.is_match(" "), true);
+        assert_eq!(expr.is_match("a"), false);
+    }
+    #[test]
+    fn test_expr_parse_error() {
+        assert_eq!(PatternExpr::parse("").unwrap_err(), ExprError::UnexpectedEnd);
+        assert_eq!(PatternExpr::parse("*.log &").unwrap_err(), ExprError::UnexpectedEnd);
+        assert_eq!(PatternExpr::parse("*.log & | *.err").unwrap_err(), ExprError::UnexpectedChar { position: 8, ch: '|' });
+        assert_eq!(PatternExpr::parse("(*.log | *.err").unwrap_err(), ExprError::UnclosedParen { position: 0 });
+        assert_eq!(PatternExpr::parse("*.log)").unwrap_err(), ExprError::UnexpectedChar { position: 5, ch: ')' });
+        assert_eq!(PatternExpr::parse("a (b)").unwrap_err(), ExprError::UnexpectedChar { position: 2, ch: '(' });
+        assert_eq!(
+            PatternExpr::parse("*.log & a\\xZZ").unwrap_err(),
+            ExprError::Pattern(PatternError::InvalidEscape { position: 1 }),
+        );
+    }
+    #[test]
+    fn test_expr_simplify() {
+        let simplify = |src: &str| PatternExpr::parse(src).unwrap().simplify().to_string();
+        assert_eq!(simplify("* & *.log"), "*.log");
+        assert_eq!(simplify("* | *.log"), "*");
+        assert_eq!(simplify("!* | *.log"), "*.log");
+        assert_eq!(simplify("!* & *.log"), "!*");
+        assert_eq!(simplify("!!*.log"), "*.log");
+        assert_eq!(simplify("a* & (b* & c*)"), "a* & b* & c*");
+        assert_eq!(simplify("(a* | !**) & (b* | c*)"), "a* & (b* | c*)");
+        assert_eq!(simplify("!(a\\& | b*)"), "!(a\\& | b*)");
+    }
+    #[test]
+    fn test_expr_display() {
+        // the displayed expression is parsed into the same expression
+        for src in ["a\\|b", "a\\&b | \\(c\\)", "!x\\!y & [=p|q]*", "a\\\\ | *.log", "\\ a\\  | [\\[]"] {
+            let expr = PatternExpr::parse(src).unwrap();
+            let displayed = expr.to_string();
+            assert_eq!(PatternExpr::parse(&displayed).unwrap().to_string(), displayed);
+            assert_eq!(displayed, src);
+        }
+        let expr = PatternExpr::parse(&PatternExpr::parse("a\\|b").unwrap().to_string()).unwrap();
+        assert_eq!(expr.is_match("a|b"), true);
+        assert_eq!(expr.is_match("a\\"), false);
+    }
+}
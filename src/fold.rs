@@ -0,0 +1,147 @@
+//! Width and kana folding for matching Japanese text
+//!
+//! - `fold_width` folds full-width ASCII (and U+3000) to half-width and half-width katakana to full-width.
+//!   A half-width katakana followed by 'ﾞ' or 'ﾟ' is folded to one voiced katakana.
+//! - `fold_kana` folds hiragana to katakana.
+
+use crate::ex::MatchOptions;
+
+/// full-width forms of U+FF61..U+FF9F
+const HALFWIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// katakana and its voiced form
+const VOICED: &[(char, char)] = &[
+    ('ウ', 'ヴ'), ('カ', 'ガ'), ('キ', 'ギ'), ('ク', 'グ'), ('ケ', 'ゲ'), ('コ', 'ゴ'),
+    ('サ', 'ザ'), ('シ', 'ジ'), ('ス', 'ズ'), ('セ', 'ゼ'), ('ソ', 'ゾ'), ('タ', 'ダ'),
+    ('チ', 'ヂ'), ('ツ', 'ヅ'), ('テ', 'デ'), ('ト', 'ド'), ('ハ', 'バ'), ('ヒ', 'ビ'),
+    ('フ', 'ブ'), ('ヘ', 'ベ'), ('ホ', 'ボ'), ('ワ', 'ヷ'), ('ヲ', 'ヺ'),
+];
+
+/// katakana and its semi-voiced form
+const SEMI_VOICED: &[(char, char)] = &[
+    ('ハ', 'パ'), ('ヒ', 'ピ'), ('フ', 'プ'), ('ヘ', 'ペ'), ('ホ', 'ポ'),
+];
+
+/// check if any folding is enabled
+pub fn is_enabled(options: &MatchOptions) -> bool {
+    options.fold_width || options.fold_kana
+}
+
+/// fold the width of a character
+fn fold_width_char(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        '\u{FF61}'..='\u{FF9F}' => HALFWIDTH_KATAKANA.chars().nth(c as usize - 0xFF61).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// fold hiragana to katakana
+fn fold_kana_char(c: char) -> char {
+    match c {
+        '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309E}' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// fold a character
+pub fn fold_char(c: char, options: &MatchOptions) -> char {
+    let mut c = c;
+    if options.fold_width {
+        c = fold_width_char(c);
+    }
+    if options.fold_kana {
+        c = fold_kana_char(c);
+    }
+    c
+}
+
+/// fold characters and return them with the index of the original character of each
+pub fn fold_chars(chars: &[char], options: &MatchOptions) -> (Vec<char>, Vec<usize>) {
+    let mut folded = Vec::with_capacity(chars.len());
+    let mut origin = Vec::with_capacity(chars.len() + 1);
+    let mut i = 0;
+    while i < chars.len() {
+        let mut c = fold_char(chars[i], options);
+        origin.push(i);
+        i += 1;
+        // half-width (semi-)voiced sound mark
+        if options.fold_width && ('\u{FF66}'..='\u{FF9D}').contains(&chars[i - 1]) && i < chars.len() {
+            let table = match chars[i] {
+                '\u{FF9E}' => VOICED,
+                '\u{FF9F}' => SEMI_VOICED,
+                _ => &[],
+            };
+            let base = fold_width_char(chars[i - 1]);
+            if let Some(&(_, voiced)) = table.iter().find(|(k, _)| *k == base) {
+                c = fold_char(voiced, options);
+                i += 1;
+            }
+        }
+        folded.push(c);
+    }
+    origin.push(chars.len());
+    (folded, origin)
+}
+
+/// characters which may be folded into another character
+const FOLDABLE: &[(char, char)] = &[
+    ('\u{3000}', '\u{3000}'), ('\u{3041}', '\u{3096}'), ('\u{309D}', '\u{309E}'),
+    ('\u{FF01}', '\u{FF5E}'), ('\u{FF61}', '\u{FF9F}'),
+];
+
+/// get the characters which are changed by folding (they never appear in a folded text)
+pub fn changed_ranges(options: &MatchOptions) -> Vec<(u32, u32)> {
+    let mut ranges: Vec<(u32, u32)> = vec![];
+    for &(start, end) in FOLDABLE {
+        for c in start..=end {
+            if fold_char(c, options) == c {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.1 + 1 == c as u32 => last.1 = c as u32,
+                _ => ranges.push((c as u32, c as u32)),
+            }
+        }
+    }
+    ranges
+}
+
+/// list the folded forms of the characters which satisfy `f` and are changed by folding
+pub fn folded_forms(f: impl Fn(char) -> bool, options: &MatchOptions) -> Vec<char> {
+    let mut list = vec![];
+    for &(start, end) in FOLDABLE {
+        for c in start..=end {
+            let folded = fold_char(c, options);
+            if folded != c && f(c) {
+                list.push(folded);
+            }
+        }
+    }
+    list
+}
+
+/// list the characters which are folded into `c` (including `c` itself)
+pub fn unfold_char(c: char, options: &MatchOptions) -> Vec<char> {
+    let mut list = vec![c];
+    if options.fold_kana {
+        if let '\u{30A1}'..='\u{30F6}' | '\u{30FD}'..='\u{30FE}' = c {
+            list.extend(char::from_u32(c as u32 - 0x60));
+        }
+    }
+    if options.fold_width {
+        for c in list.clone() {
+            match c {
+                '!'..='~' => list.extend(char::from_u32(c as u32 + 0xFEE0)),
+                ' ' => list.push('\u{3000}'),
+                _ => {
+                    if let Some(i) = HALFWIDTH_KATAKANA.chars().position(|k| k == c) {
+                        list.extend(char::from_u32(0xFF61 + i as u32));
+                    }
+                }
+            }
+        }
+    }
+    list
+}
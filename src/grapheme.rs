@@ -0,0 +1,101 @@
+//! Extended grapheme cluster boundaries (UAX #29)
+
+use crate::unicode_tables;
+
+/// Grapheme_Cluster_Break property of a character
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// get the Grapheme_Cluster_Break property of the character
+fn break_property(c: char) -> GraphemeBreak {
+    let table = unicode_tables::GRAPHEME_BREAK;
+    match table.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            std::cmp::Ordering::Less
+        } else if start > c {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => table[i].2,
+        Err(_) => GraphemeBreak::Other,
+    }
+}
+
+/// list whether each position of the text (including its end) is a cluster boundary
+pub fn boundaries(chars: &[char]) -> Vec<bool> {
+    use GraphemeBreak::*;
+    let mut result = vec![true; chars.len() + 1];
+    let mut prev = Other;
+    let mut emoji = false; // after ExtendedPictographic Extend*
+    let mut emoji_zwj = false; // after ExtendedPictographic Extend* Zwj
+    let mut regional_count = 0; // count of the preceding regional indicators
+    for (i, &c) in chars.iter().enumerate() {
+        let cur = break_property(c);
+        if i > 0 {
+            result[i] = match (prev, cur) {
+                (Cr, Lf) => false, // GB3
+                (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => true, // GB4, GB5
+                (L, L | V | Lv | Lvt) => false, // GB6
+                (Lv | V, V | T) => false, // GB7
+                (Lvt | T, T) => false, // GB8
+                (_, Extend | Zwj | SpacingMark) => false, // GB9, GB9a
+                (Prepend, _) => false, // GB9b
+                (Zwj, ExtendedPictographic) => !emoji_zwj, // GB11
+                (RegionalIndicator, RegionalIndicator) => regional_count % 2 == 0, // GB12, GB13
+                _ => true, // GB999
+            };
+        }
+        emoji_zwj = emoji && cur == Zwj;
+        emoji = cur == ExtendedPictographic || (emoji && cur == Extend);
+        regional_count = if cur == RegionalIndicator { regional_count + 1 } else { 0 };
+        prev = cur;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn clusters(s: &str) -> Vec<String> {
+        let chars = s.chars().collect::<Vec<char>>();
+        let bounds = boundaries(&chars);
+        let mut result = vec![];
+        let mut start = 0;
+        for end in 1..=chars.len() {
+            if bounds[end] {
+                result.push(chars[start..end].iter().collect());
+                start = end;
+            }
+        }
+        result
+    }
+    #[test]
+    fn test_boundaries() {
+        assert_eq!(clusters("abc"), vec!["a", "b", "c"]);
+        assert_eq!(clusters("e\u{301}x"), vec!["e\u{301}", "x"]);
+        assert_eq!(clusters("\r\nか\u{3099}"), vec!["\r\n", "か\u{3099}"]);
+        assert_eq!(clusters("👍🏽."), vec!["👍🏽", "."]);
+        assert_eq!(clusters("👨\u{200d}👩\u{200d}👧!"), vec!["👨\u{200d}👩\u{200d}👧", "!"]);
+        assert_eq!(clusters("🇯🇵🇺🇸🇫"), vec!["🇯🇵", "🇺🇸", "🇫"]);
+        assert_eq!(clusters("\u{1100}\u{1161}\u{11a8}가"), vec!["\u{1100}\u{1161}\u{11a8}", "가"]);
+        assert_eq!(clusters("a\u{200d}👩"), vec!["a\u{200d}", "👩"]);
+    }
+}
@@ -35,6 +35,8 @@
 //!     assert_eq!(is_match("[!0-9][0-9].txt", "c3.txt"), true); // [!str] is other than str.
 //!     assert_eq!(is_match("[+0-9].txt", "12345.txt"), true); // [+str] is repeated arbitrary string
 //!     assert_eq!(is_match("[+a-z0-9].txt", "abc12345.txt"), true);
+//!     assert_eq!(is_match("v[*0-9].txt", "v.txt"), true); // [*str] is repeated 0 or more times
+//!     assert_eq!(is_match("v[?0-9].txt", "v1.txt"), true); // [?str] is zero or one character
 //!     assert_eq!(is_match("[=cat|dog].txt", "cat.txt"), true); // [=str1|str2] is str1 or str2
 //! }
 //! ```
@@ -56,6 +58,17 @@
 //! }
 //! ```
 //! 
+//! ### Boolean pattern expressions
+//! 
+//! ```rust
+//! use wildcard_ex::expr::PatternExpr;
+//! fn main() {
+//!     let expr = PatternExpr::parse("*.log & !debug-* | *.err").unwrap();
+//!     assert_eq!(expr.is_match("app.log"), true);
+//!     assert_eq!(expr.is_match("debug-app.log"), false);
+//! }
+//! ```
+//! 
 //! ### extract matched part from beginning
 //! 
 //! ```rust
@@ -73,6 +86,16 @@
 
 pub mod simple;
 pub mod ex;
+pub mod expr;
+pub mod registry;
+pub mod stream;
+mod automaton;
+mod expand;
+mod fold;
+mod grapheme;
+mod normalize;
+mod sample;
+mod unicode_tables;
 
 /// checks if the specified text completely matches the pattern and returns true if it. The pattern can include wildcards such as ['*', '?', '#'].
 pub fn is_match_simple(pattern: &str, text: &str) -> bool {
@@ -95,6 +118,7 @@ pub fn find_match(pattern: &str, text: &str) -> Option<ex::MatchedResult> {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     #[test]
@@ -1,5 +1,4 @@
-mod ex;
-use ex::is_match;
+use wildcard_ex::ex::is_match;
 fn main() {
     // This command line tests whether $2 matches $1.
     let args: Vec<String> = std::env::args().collect();
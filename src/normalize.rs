@@ -0,0 +1,222 @@
+//! Unicode normalization (NFC / NFKC) for matching
+//!
+//! The text is split into segments which are normalized independently,
+//! so every normalized character can be traced back to its segment in the original text.
+
+use crate::ex::Normalization;
+use crate::unicode_tables;
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// get the canonical combining class of the character
+fn combining_class(c: char) -> u8 {
+    let table = unicode_tables::COMBINING_CLASS;
+    match table.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            std::cmp::Ordering::Less
+        } else if start > c {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => table[i].2,
+        Err(_) => 0,
+    }
+}
+
+/// look up the decomposition of the character
+fn lookup(table: &[(char, &'static str)], c: char) -> Option<&'static str> {
+    table.binary_search_by_key(&c, |&(k, _)| k).ok().map(|i| table[i].1)
+}
+
+/// decompose the character fully
+// `is_multiple_of` needs Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn decompose_char(c: char, compat: bool, out: &mut Vec<char>) {
+    let s = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&s) {
+        let index = s - S_BASE;
+        out.extend(char::from_u32(L_BASE + index / N_COUNT));
+        out.extend(char::from_u32(V_BASE + (index % N_COUNT) / T_COUNT));
+        if index % T_COUNT != 0 {
+            out.extend(char::from_u32(T_BASE + index % T_COUNT));
+        }
+        return;
+    }
+    let decomposition = if compat {
+        lookup(unicode_tables::COMPATIBILITY_DECOMPOSITION, c)
+            .or_else(|| lookup(unicode_tables::CANONICAL_DECOMPOSITION, c))
+    } else {
+        lookup(unicode_tables::CANONICAL_DECOMPOSITION, c)
+    };
+    match decomposition {
+        Some(s) => out.extend(s.chars()),
+        None => out.push(c),
+    }
+}
+
+/// compose the pair of characters into a primary composite
+#[allow(clippy::manual_is_multiple_of)]
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a32, b32) = (a as u32, b as u32);
+    // Hangul L + V
+    if (L_BASE..L_BASE + L_COUNT).contains(&a32) && (V_BASE..V_BASE + V_COUNT).contains(&b32) {
+        return char::from_u32(S_BASE + ((a32 - L_BASE) * V_COUNT + (b32 - V_BASE)) * T_COUNT);
+    }
+    // Hangul LV + T
+    if (S_BASE..S_BASE + S_COUNT).contains(&a32) && (a32 - S_BASE) % T_COUNT == 0
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b32) {
+        return char::from_u32(a32 + (b32 - T_BASE));
+    }
+    let table = unicode_tables::COMPOSITION;
+    table.binary_search_by_key(&(a, b), |&(x, y, _)| (x, y)).ok().map(|i| table[i].2)
+}
+
+/// sort the runs of combining characters by the combining class
+fn reorder(chars: &mut [char]) {
+    let mut i = 0;
+    while i < chars.len() {
+        if combining_class(chars[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        chars[start..i].sort_by_key(|&c| combining_class(c)); // stable
+    }
+}
+
+/// compose the decomposed characters
+fn compose(chars: &[char]) -> Vec<char> {
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    let mut last_class: Option<u8> = None; // class of the last character after the starter
+    for &c in chars {
+        let class = combining_class(c);
+        if let Some(si) = starter {
+            let blocked = last_class.is_some_and(|last| last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(out[si], c) {
+                    out[si] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// normalize the characters
+pub fn normalize(chars: &[char], form: Normalization) -> Vec<char> {
+    let compat = match form {
+        Normalization::None => return chars.to_vec(),
+        Normalization::Nfc => false,
+        Normalization::Nfkc => true,
+    };
+    let mut decomposed = vec![];
+    for &c in chars {
+        decompose_char(c, compat, &mut decomposed);
+    }
+    reorder(&mut decomposed);
+    compose(&decomposed)
+}
+
+/// normalize the characters and return them with the index of the original character of each
+pub fn normalize_chars(chars: &[char], form: Normalization) -> (Vec<char>, Vec<usize>) {
+    let (quick_check, compat) = match form {
+        Normalization::None => return (chars.to_vec(), (0..=chars.len()).collect()),
+        Normalization::Nfc => (unicode_tables::NFC_QUICK_CHECK, false),
+        Normalization::Nfkc => (unicode_tables::NFKC_QUICK_CHECK, true),
+    };
+    // a segment starts at a character which decomposes into a starter
+    // that never combines with the preceding character
+    let is_boundary = |c: char| {
+        let mut decomposed = vec![];
+        decompose_char(c, compat, &mut decomposed);
+        let first = decomposed[0];
+        combining_class(first) == 0
+            && quick_check.binary_search_by(|&(start, end)| {
+                if end < first {
+                    std::cmp::Ordering::Less
+                } else if start > first {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            }).is_err()
+    };
+    let mut normalized = Vec::with_capacity(chars.len());
+    let mut origin = Vec::with_capacity(chars.len() + 1);
+    let mut start = 0;
+    while start < chars.len() {
+        let mut end = start + 1;
+        while end < chars.len() && !is_boundary(chars[end]) {
+            end += 1;
+        }
+        for c in normalize(&chars[start..end], form) {
+            normalized.push(c);
+            origin.push(start);
+        }
+        start = end;
+    }
+    origin.push(chars.len());
+    (normalized, origin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn nfc(s: &str) -> String {
+        normalize(&s.chars().collect::<Vec<char>>(), Normalization::Nfc).into_iter().collect()
+    }
+    fn nfkc(s: &str) -> String {
+        normalize(&s.chars().collect::<Vec<char>>(), Normalization::Nfkc).into_iter().collect()
+    }
+    #[test]
+    fn test_normalize() {
+        assert_eq!(nfc("か\u{3099}"), "が");
+        assert_eq!(nfc("e\u{301}"), "é");
+        assert_eq!(nfc("a\u{323}\u{302}"), "\u{1ead}"); // ậ: reordered and composed
+        assert_eq!(nfc("a\u{302}\u{323}"), "\u{1ead}");
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11a8}"), "각");
+        assert_eq!(nfc("\u{212b}"), "Å"); // singleton
+        assert_eq!(nfc("ｶﾞ"), "ｶﾞ");
+        assert_eq!(nfkc("ｶﾞ"), "ガ");
+        assert_eq!(nfkc("ＡＢＣ①"), "ABC1");
+        assert_eq!(nfkc("㍻"), "平成");
+    }
+    #[test]
+    fn test_normalize_chars() {
+        let chars = "xか\u{3099}y".chars().collect::<Vec<char>>();
+        let (normalized, origin) = normalize_chars(&chars, Normalization::Nfc);
+        assert_eq!(normalized, vec!['x', 'が', 'y']);
+        assert_eq!(origin, vec![0, 1, 3, 4]);
+        let chars = "和㍻x".chars().collect::<Vec<char>>();
+        let (normalized, origin) = normalize_chars(&chars, Normalization::Nfkc);
+        assert_eq!(normalized, vec!['和', '平', '成', 'x']);
+        assert_eq!(origin, vec![0, 1, 1, 2, 3]);
+        // a compatibility jamo composes with the preceding one
+        let chars = "\u{1100}\u{314f}".chars().collect::<Vec<char>>();
+        let (normalized, origin) = normalize_chars(&chars, Normalization::Nfkc);
+        assert_eq!(normalized, vec!['가']);
+        assert_eq!(origin, vec![0, 2]);
+    }
+}
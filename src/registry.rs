@@ -0,0 +1,183 @@
+//! Named sub-patterns referenced as `[@name]`
+//!
+//! ```rust
+//! use wildcard_ex::registry::PatternRegistry;
+//! let mut registry = PatternRegistry::new();
+//! registry.define("id", "[=A|B]-[@zip]").unwrap();
+//! let pattern = registry.compile("[@id].csv").unwrap();
+//! assert_eq!(pattern.is_match("A-123-4567.csv"), true);
+//! ```
+
+use crate::ex::{MatchOptions, Pattern, PatternError, StrCursor};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// one hex digit
+const HEX: &str = "[0-9a-fA-F]";
+
+/// built-in definitions: (name, pattern)
+fn builtin_definitions() -> Vec<(&'static str, String)> {
+    let uuid = [8, 4, 4, 4, 12].iter().map(|&n| HEX.repeat(n)).collect::<Vec<String>>().join("-");
+    vec![
+        ("zip", "###-####".to_string()),
+        ("date", "[<0000-9999>]-[<01-12>]-[<01-31>]".to_string()),
+        ("time", "[<00-23>]:[<00-59>][=|:[<00-59>]]".to_string()),
+        ("datetime", "[@date][=T| ][@time]".to_string()),
+        ("uuid", uuid),
+        ("ipv4", "[<0-255>].[<0-255>].[<0-255>].[<0-255>]".to_string()),
+        ("int", "[?+\\-][+0-9]".to_string()),
+        ("hex", "[+0-9a-fA-F]".to_string()),
+    ]
+}
+
+/// check if the name can be written as `[@name]`
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Registry of named sub-patterns
+#[derive(Clone, Debug)]
+pub struct PatternRegistry {
+    definitions: HashMap<String, String>,
+}
+
+impl Default for PatternRegistry {
+    fn default() -> Self {
+        PatternRegistry::new()
+    }
+}
+
+impl PatternRegistry {
+    /// create a registry with the built-in definitions
+    /// (`zip`, `date`, `time`, `datetime`, `uuid`, `ipv4`, `int` and `hex`)
+    pub fn new() -> Self {
+        let definitions = builtin_definitions()
+            .into_iter()
+            .map(|(name, pattern_str)| (name.to_string(), pattern_str))
+            .collect();
+        PatternRegistry { definitions }
+    }
+    /// create a registry without definitions
+    pub fn empty() -> Self {
+        PatternRegistry { definitions: HashMap::new() }
+    }
+    /// the registry used by `Pattern::new`
+    pub(crate) fn builtin() -> &'static PatternRegistry {
+        static BUILTIN: OnceLock<PatternRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(PatternRegistry::new)
+    }
+    /// define (or redefine) the name as the pattern.
+    /// the pattern may refer to names defined later, which are checked when compiling
+    pub fn define(&mut self, name: &str, pattern_str: &str) -> Result<(), PatternError> {
+        if !is_valid_name(name) {
+            return Err(PatternError::InvalidName { name: name.to_string() });
+        }
+        let mut pattern_cur = StrCursor::new(pattern_str);
+        Pattern::read_definition(&mut pattern_cur, name, self);
+        match pattern_cur.error {
+            None | Some(PatternError::UnknownReference { .. }) => {}
+            Some(error) => return Err(error),
+        }
+        self.definitions.insert(name.to_string(), pattern_str.to_string());
+        Ok(())
+    }
+    /// remove the definition of the name
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.definitions.remove(name)
+    }
+    /// get the pattern defined for the name
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.definitions.get(name).map(|s| s.as_str())
+    }
+    /// get the defined names in order
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.definitions.keys().map(|s| s.as_str()).collect::<Vec<&str>>();
+        names.sort();
+        names
+    }
+    /// create a pattern whose `[@name]` refers to this registry
+    pub fn compile(&self, pattern_str: &str) -> Result<Pattern, PatternError> {
+        self.compile_with_options(pattern_str, MatchOptions::default())
+    }
+    /// create a pattern which matches with the options and whose `[@name]` refers to this registry
+    pub fn compile_with_options(&self, pattern_str: &str, options: MatchOptions) -> Result<Pattern, PatternError> {
+        let mut pattern_cur = StrCursor::new(pattern_str);
+        let pattern = Pattern::read(&mut pattern_cur, options, self);
+        match pattern_cur.error {
+            Some(error) => Err(error),
+            None => Ok(pattern),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_builtin() {
+        let registry = PatternRegistry::new();
+        let is_match = |pattern: &str, text: &str| registry.compile(pattern).unwrap().is_match(text);
+        assert_eq!(is_match("[@zip]", "123-4567"), true);
+        assert_eq!(is_match("[@zip]", "1234-567"), false);
+        assert_eq!(is_match("[@date]", "2024-02-29"), true);
+        assert_eq!(is_match("[@date]", "2024-13-01"), false);
+        assert_eq!(is_match("[@time]", "23:59"), true);
+        assert_eq!(is_match("[@time]", "23:59:60"), false);
+        assert_eq!(is_match("[@datetime]", "2024-02-29T09:30:00"), true);
+        assert_eq!(is_match("[@datetime]", "2024-02-29 09:30"), true);
+        assert_eq!(is_match("[@uuid]", "123e4567-e89b-12d3-a456-426614174000"), true);
+        assert_eq!(is_match("[@uuid]", "123e4567-e89b-12d3-a456-42661417400"), false);
+        assert_eq!(is_match("[@ipv4]", "192.168.0.1"), true);
+        assert_eq!(is_match("[@ipv4]", "192.168.0.256"), false);
+        assert_eq!(is_match("[@int]", "-42"), true);
+        assert_eq!(is_match("[@hex]", "0xff"), false);
+        assert_eq!(is_match("0x[@hex]", "0xff"), true);
+        // the built-in names are available without a registry
+        assert_eq!(Pattern::new("zip:[@zip]").is_match("zip:123-4567"), true);
+        assert_eq!(Pattern::new("[=[@zip]|none]").is_match("none"), true);
+    }
+    #[test]
+    fn test_define() {
+        let mut registry = PatternRegistry::empty();
+        assert_eq!(registry.get("zip"), None);
+        registry.define("ext", "[=jpg|png]").unwrap();
+        registry.define("image", "IMG_####.[@ext]").unwrap();
+        assert_eq!(registry.names(), vec!["ext", "image"]);
+        let pattern = registry.compile("[@image]").unwrap();
+        assert_eq!(pattern.is_match("IMG_0001.png"), true);
+        assert_eq!(pattern.to_string(), "IMG_####.[=jpg|png]");
+        // redefine
+        registry.define("ext", "gif").unwrap();
+        assert_eq!(registry.compile("[@image]").unwrap().is_match("IMG_0001.gif"), true);
+        assert_eq!(registry.remove("ext"), Some("gif".to_string()));
+        // errors
+        assert_eq!(registry.define("a b", "x"), Err(PatternError::InvalidName { name: "a b".to_string() }));
+        assert_eq!(registry.define("bad", "\\x1"), Err(PatternError::InvalidEscape { position: 0 }));
+        assert_eq!(
+            registry.compile("x[@image]").unwrap_err(),
+            PatternError::UnknownReference { position: 1, name: "ext".to_string() },
+        );
+        assert_eq!(
+            registry.compile("[@nothing]").unwrap_err(),
+            PatternError::UnknownReference { position: 0, name: "nothing".to_string() },
+        );
+    }
+    #[test]
+    fn test_cycle() {
+        let mut registry = PatternRegistry::empty();
+        assert_eq!(
+            registry.define("self", "a[@self]"),
+            Err(PatternError::CyclicReference { position: 1, name: "self".to_string() }),
+        );
+        registry.define("a", "[@b]").unwrap(); // b is defined later
+        registry.define("b", "[@c]").unwrap();
+        assert_eq!(
+            registry.define("c", "--[=x|[@a]]"),
+            Err(PatternError::CyclicReference { position: 6, name: "c".to_string() }),
+        );
+        assert_eq!(registry.get("c"), None);
+        // Pattern::new reads an unknown or cyclic reference as a character list
+        assert_eq!(Pattern::new("[@nothing]").is_match("g"), true);
+    }
+}
@@ -0,0 +1,185 @@
+//! Generating strings which a pattern matches
+//!
+//! The tokens are filled from left to right, either at random (`Pick::Random`)
+//! or systematically (`Pick::Variant`), and the caller checks the result with the pattern
+//! because `[^]`, `[$]` and the options may reject it.
+//! `*` and the repeated character lists take at most `MAX_REPEAT` characters.
+
+use crate::ex::{is_match_slice, CharList, PatternChar};
+
+/// the maximum number of characters generated for `*`, `[+...]`, `[*...]` and `[-...]`
+pub const MAX_REPEAT: usize = 3;
+
+/// the number of strings generated at random before giving up
+pub const MAX_TRIES: u64 = 100;
+
+/// characters used for `?`, `*` and the negated lists
+const POOL: &str = "abcxyzABCXYZ0123456789_-";
+
+/// Small pseudo random number generator (SplitMix64)
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// get a number in `0..n` (n > 0)
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// How to choose among the possibilities of each token
+pub enum Pick {
+    Random(Rng),
+    /// take the `index`-th alternative of the selectors, the lowest (even) or the highest (odd) character
+    /// or number, and the fewest (or with `long`, the most) repetitions
+    Variant { index: usize, long: bool },
+}
+
+impl Pick {
+    /// choose a number in `0..n` (n > 0)
+    fn choose(&mut self, n: usize) -> usize {
+        match self {
+            Pick::Random(rng) => rng.below(n as u64) as usize,
+            Pick::Variant { index, .. } => *index % n,
+        }
+    }
+    /// choose the number of repetitions in `min..=MAX_REPEAT`
+    fn count(&mut self, min: usize) -> usize {
+        match self {
+            Pick::Random(rng) => min + rng.below((MAX_REPEAT - min + 1) as u64) as usize,
+            Pick::Variant { long: true, .. } => MAX_REPEAT,
+            Pick::Variant { long: false, .. } => min,
+        }
+    }
+    /// choose the lowest or the highest of the candidates
+    fn bound<T: Copy>(&mut self, candidates: &[T]) -> T {
+        match self {
+            Pick::Random(rng) => candidates[rng.below(candidates.len() as u64) as usize],
+            Pick::Variant { index, .. } if *index % 2 == 1 => candidates[candidates.len() - 1],
+            Pick::Variant { .. } => candidates[0],
+        }
+    }
+    /// choose a character of the list (printable ASCII characters are preferred)
+    fn char_in(&mut self, charlist: &CharList) -> Option<char> {
+        let ascii = POOL.chars().chain('!'..='~').filter(|&c| charlist.contains(c)).collect::<Vec<char>>();
+        if let Pick::Random(rng) = self {
+            if ascii.is_empty() || rng.below(4) == 0 {
+                let ranges = charlist.ranges();
+                if ranges.is_empty() {
+                    return None;
+                }
+                let range = ranges[rng.below(ranges.len() as u64) as usize];
+                let offset = rng.below((range.end as u64) - (range.start as u64) + 1) as u32;
+                return char::from_u32(range.start as u32 + offset).or(Some(range.start));
+            }
+        }
+        if !ascii.is_empty() {
+            let mut sorted = ascii;
+            sorted.sort();
+            sorted.dedup();
+            return Some(self.bound(&sorted));
+        }
+        let ranges = charlist.ranges();
+        Some(self.bound(&[ranges.first()?.start, ranges.last()?.end]))
+    }
+    /// choose a character which is not in the list
+    fn char_not_in(&mut self, charlist: &CharList) -> Option<char> {
+        let candidates = POOL.chars().chain('!'..='~').filter(|&c| !charlist.contains(c)).collect::<Vec<char>>();
+        if candidates.is_empty() {
+            // the list contains all the printable ASCII characters
+            return ('\u{A1}'..=char::MAX).find(|&c| !charlist.contains(c));
+        }
+        let index = self.choose(candidates.len());
+        Some(candidates[index])
+    }
+}
+
+/// generate a string for the pattern, or None if a token cannot be filled
+pub fn generate(pattern: &[PatternChar], pick: &mut Pick) -> Option<String> {
+    let mut result = String::new();
+    for token in pattern {
+        push_token(&mut result, token, pick)?;
+    }
+    Some(result)
+}
+
+/// get the largest number of alternatives of the selectors in the pattern
+pub fn max_alternatives(pattern: &[PatternChar]) -> usize {
+    pattern.iter().map(|token| match token {
+        PatternChar::Selector(selector) => {
+            selector.iter().map(|sub_pattern| max_alternatives(sub_pattern)).max().unwrap_or(0).max(selector.len())
+        }
+        _ => 0,
+    }).max().unwrap_or(0)
+}
+
+fn push_token(result: &mut String, token: &PatternChar, pick: &mut Pick) -> Option<()> {
+    let pool = &POOL.chars().collect::<Vec<char>>();
+    match token {
+        PatternChar::Char(c) => result.push(*c),
+        PatternChar::Number => result.push(pick.bound(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'])),
+        PatternChar::Question => result.push(pool[pick.choose(pool.len())]),
+        PatternChar::Wildcard | PatternChar::LazyWildcard | PatternChar::PossessiveWildcard => {
+            for _ in 0..pick.count(0) {
+                result.push(pool[pick.choose(pool.len())]);
+            }
+        }
+        PatternChar::CharList(charlist) => result.push(pick.char_in(charlist)?),
+        PatternChar::NotCharList(charlist) => result.push(pick.char_not_in(charlist)?),
+        PatternChar::CharListRepeat(charlist) | PatternChar::CharListRepeatZero(charlist) => {
+            let min = if matches!(token, PatternChar::CharListRepeat(_)) { 1 } else { 0 };
+            for _ in 0..pick.count(min) {
+                result.push(pick.char_in(charlist)?);
+            }
+        }
+        PatternChar::CharListOptional(charlist) => {
+            if pick.count(0) > 0 {
+                result.push(pick.char_in(charlist)?);
+            }
+        }
+        PatternChar::NotCharListRepeat(charlist) => {
+            for _ in 0..pick.count(1) {
+                result.push(pick.char_not_in(charlist)?);
+            }
+        }
+        PatternChar::Selector(selector) => {
+            if selector.is_empty() {
+                return None;
+            }
+            let index = pick.choose(selector.len());
+            result.push_str(&generate(&selector[index], pick)?);
+        }
+        PatternChar::NotSelector(selector) => {
+            // try the strings of the pool from the shortest until none of the alternatives matches
+            let start = pick.choose(pool.len());
+            let candidates = (1..=MAX_REPEAT).flat_map(|len| {
+                (0..pool.len()).map(move |i| vec![pool[(start + i) % pool.len()]; len])
+            });
+            let found = candidates.into_iter().find(|chars| {
+                selector.iter().all(|sub_pattern| !is_match_slice(sub_pattern, chars))
+            })?;
+            result.extend(found);
+        }
+        PatternChar::NumberRange(range) => {
+            let value = match pick {
+                Pick::Random(rng) => range.min + rng.below((range.max - range.min).saturating_add(1)),
+                _ => pick.bound(&[range.min, range.max]),
+            };
+            match range.width {
+                Some(width) => result.push_str(&format!("{:0width$}", value)),
+                None => result.push_str(&value.to_string()),
+            }
+        }
+        PatternChar::Start | PatternChar::End => {}
+    }
+    Some(())
+}
@@ -1,4 +1,4 @@
-/** Wildcard library simple implementation */
+//! Wildcard library simple implementation
 
  /// check if the pattern matches the text with wildcard characters ['*', '?', '#']
  pub fn is_match(pattern: &str, text: &str) -> bool {
@@ -112,6 +112,7 @@ pub struct MatchedResult {
     pub end: usize,
     pub matched: String,
 }
+#[allow(clippy::len_without_is_empty)]
 impl MatchedResult {
     pub fn new(start: usize, end: usize, matched: String) -> Self {
         MatchedResult {
@@ -150,6 +151,7 @@ pub fn find_match_slice(pattern: &[char], text: &[char]) -> Option<MatchedResult
 
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     #[test]
@@ -0,0 +1,258 @@
+//! Searching a pattern in a stream of text (such as a large log file)
+//!
+//! The stream is read line by line and each line is searched separately,
+//! so a match does not contain a line break and the memory is bounded by the length of a line.
+//! A line longer than `max_line_len` bytes is searched in segments of that length,
+//! with the last `max_line_len` bytes of the line kept before each segment, so a match across
+//! the segments is found unless it is longer than that. `[^]` and `[$]` only match at the ends of the line.
+//!
+//! ```rust
+//! use wildcard_ex::ex::Pattern;
+//! use wildcard_ex::stream::StreamMatches;
+//! let pattern = Pattern::new("ERROR:*");
+//! let log = "INFO: start\nERROR: disk full\n";
+//! let matches = StreamMatches::new(&pattern, log.as_bytes()).collect::<std::io::Result<Vec<_>>>().unwrap();
+//! assert_eq!(matches[0].line, 2);
+//! assert_eq!(matches[0].start, 12);
+//! assert_eq!(matches[0].matched, "ERROR: disk full");
+//! ```
+
+use crate::ex::Pattern;
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+/// default limit of the length of a line (1 MiB)
+pub const DEFAULT_MAX_LINE_LEN: usize = 1 << 20;
+
+/// A match in a stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamMatch {
+    /// byte offset of the start of the match from the beginning of the stream
+    pub start: u64,
+    /// byte offset of the end of the match from the beginning of the stream
+    pub end: u64,
+    /// line number of the match (starting at 1)
+    pub line: u64,
+    pub matched: String,
+}
+
+/// Iterator over the matches of a pattern in a stream
+pub struct StreamMatches<'p, R> {
+    pattern: &'p Pattern,
+    reader: R,
+    max_line_len: usize,
+    /// byte offset of the next segment
+    offset: u64,
+    /// line number of the next segment
+    line: u64,
+    /// bytes of a character split at the end of the last segment
+    carry: Vec<u8>,
+    /// tail of the current line which is searched again with the next segment,
+    /// preceded by one character for `[^]` and `[<min-max>]` unless it starts the line
+    line_text: String,
+    /// byte offset of `line_text`
+    line_offset: u64,
+    /// index of the character in `line_text` to search from
+    from: usize,
+    /// position of an empty match which is skipped (right after the last match)
+    skip_empty: Option<usize>,
+    /// matches found in the last segment
+    pending: VecDeque<StreamMatch>,
+    done: bool,
+}
+
+impl<'p, R: BufRead> StreamMatches<'p, R> {
+    /// search the pattern in the lines read from the reader
+    pub fn new(pattern: &'p Pattern, reader: R) -> Self {
+        StreamMatches {
+            pattern,
+            reader,
+            max_line_len: DEFAULT_MAX_LINE_LEN,
+            offset: 0,
+            line: 1,
+            carry: vec![],
+            line_text: String::new(),
+            line_offset: 0,
+            from: 0,
+            skip_empty: None,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+    /// set the length of the segments in which a long line is searched (at least 4 bytes)
+    pub fn max_line_len(mut self, max_line_len: usize) -> Self {
+        self.max_line_len = max_line_len.max(4);
+        self
+    }
+    /// read the next line (or segment of a long line) and whether it ends the line
+    fn read_segment(&mut self) -> io::Result<Option<(Vec<u8>, bool)>> {
+        let mut segment = std::mem::take(&mut self.carry);
+        loop {
+            let available = self.reader.fill_buf()?;
+            if available.is_empty() { // end of the stream
+                return Ok(if segment.is_empty() { None } else { Some((segment, true)) });
+            }
+            let room = self.max_line_len - segment.len();
+            let window = &available[..available.len().min(room)];
+            if let Some(i) = window.iter().position(|&b| b == b'\n') {
+                segment.extend_from_slice(&window[..=i]);
+                self.reader.consume(i + 1);
+                return Ok(Some((segment, true)));
+            }
+            let len = window.len();
+            segment.extend_from_slice(window);
+            self.reader.consume(len);
+            if segment.len() >= self.max_line_len {
+                return Ok(Some((segment, false)));
+            }
+        }
+    }
+    /// decode the segment, keeping an incomplete character (or '\r' which may start "\r\n")
+    /// at its end for the next segment
+    fn decode(&mut self, mut segment: Vec<u8>, ends_line: bool) -> io::Result<String> {
+        if let Err(error) = std::str::from_utf8(&segment) {
+            if ends_line || error.error_len().is_some() {
+                let message = format!("invalid UTF-8 at byte {}", self.offset + error.valid_up_to() as u64);
+                return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+            }
+            self.carry = segment.split_off(error.valid_up_to());
+        } else if !ends_line && segment.last() == Some(&b'\r') {
+            self.carry = segment.split_off(segment.len() - 1);
+        }
+        String::from_utf8(segment).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+    /// search the next segment and queue its matches
+    fn search_segment(&mut self) -> io::Result<bool> {
+        let Some((segment, ends_line)) = self.read_segment()? else {
+            return Ok(false);
+        };
+        let segment = self.decode(segment, ends_line)?;
+        let mut text = segment.as_str();
+        if ends_line {
+            text = text.strip_suffix('\n').map_or(text, |text| text.strip_suffix('\r').unwrap_or(text));
+        }
+        self.line_text.push_str(text);
+        let line_text = self.line_text.as_str();
+        let offsets = line_text.char_indices().map(|(i, _)| i).chain(std::iter::once(line_text.len())).collect::<Vec<usize>>();
+        let len = offsets.len() - 1;
+        // the characters of the tail window kept for the next segment
+        let window = offsets.partition_point(|&o| o < line_text.len().saturating_sub(self.max_line_len));
+        let mut deferred = None;
+        for m in self.pattern.find_iter_from(line_text, self.from) {
+            if m.start == m.end && self.skip_empty == Some(m.start) {
+                continue;
+            }
+            // a match reaching the end of the segment may go on in the next one
+            if !ends_line && m.end == len && m.start >= window {
+                deferred = Some(m.start);
+                break;
+            }
+            self.pending.push_back(StreamMatch {
+                start: self.line_offset + offsets[m.start] as u64,
+                end: self.line_offset + offsets[m.end] as u64,
+                line: self.line,
+                matched: m.matched,
+            });
+            self.from = m.end;
+            self.skip_empty = Some(m.end);
+        }
+        self.offset += segment.len() as u64;
+        if ends_line {
+            self.line_text.clear();
+            self.line_offset = self.offset;
+            self.from = 0;
+            self.skip_empty = None;
+            self.line += 1;
+        } else {
+            let keep = deferred.unwrap_or(self.from.max(window));
+            let cut = keep.saturating_sub(1);
+            self.line_text.drain(..offsets[cut]);
+            self.line_offset += offsets[cut] as u64;
+            self.from = keep - cut;
+            self.skip_empty = self.skip_empty.and_then(|pos| pos.checked_sub(cut));
+        }
+        Ok(true)
+    }
+}
+
+impl<R: BufRead> Iterator for StreamMatches<'_, R> {
+    type Item = io::Result<StreamMatch>;
+    fn next(&mut self) -> Option<io::Result<StreamMatch>> {
+        loop {
+            if let Some(m) = self.pending.pop_front() {
+                return Some(Ok(m));
+            }
+            if self.done {
+                return None;
+            }
+            match self.search_segment() {
+                Ok(true) => {}
+                Ok(false) => self.done = true,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+    fn search(pattern: &str, reader: impl BufRead, max_line_len: usize) -> Vec<(u64, u64, u64, String)> {
+        let pattern = Pattern::new(pattern);
+        StreamMatches::new(&pattern, reader)
+            .max_line_len(max_line_len)
+            .map(|m| m.map(|m| (m.line, m.start, m.end, m.matched)))
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap()
+    }
+    #[test]
+    fn test_stream_matches() {
+        let text = "id=1 ok\r\nid=22 ng\n\nid=333";
+        let expected = vec![
+            (1, 0, 4, "id=1".to_string()),
+            (2, 9, 14, "id=22".to_string()),
+            (4, 19, 25, "id=333".to_string()),
+        ];
+        assert_eq!(search("id=[+0-9]", text.as_bytes(), DEFAULT_MAX_LINE_LEN), expected);
+        // a small buffer splits the lines and the characters
+        let text = "東京: 晴れ\n大阪: 雨\n";
+        let reader = BufReader::with_capacity(2, text.as_bytes());
+        let expected = vec![(1, 0, 7, "東京:".to_string()), (2, 15, 22, "大阪:".to_string())];
+        assert_eq!(search("[+[:kanji:]]:", reader, DEFAULT_MAX_LINE_LEN), expected);
+        // `[^=...]` ends at the next token
+        let text = "user=alice ok\nuser=carol ok\nuser=bob ok\n";
+        assert_eq!(search("user=[^=alice|bob] ", text.as_bytes(), DEFAULT_MAX_LINE_LEN), vec![(2, 14, 25, "user=carol ".to_string())]);
+        // anchors match at the start and the end of each line
+        assert_eq!(search("[^]b", "ab\nba\n".as_bytes(), DEFAULT_MAX_LINE_LEN), vec![(2, 3, 4, "b".to_string())]);
+    }
+    #[test]
+    fn test_stream_long_line() {
+        // a long line is searched in segments of 5 bytes without splitting the characters
+        let text = "aあいbうc\nxy";
+        let result = search("?", text.as_bytes(), 5);
+        let chars = result.iter().map(|(line, start, _, m)| (*line, *start, m.as_str())).collect::<Vec<_>>();
+        assert_eq!(chars, vec![
+            (1, 0, "a"), (1, 1, "あ"), (1, 4, "い"), (1, 7, "b"), (1, 8, "う"), (1, 11, "c"), (2, 13, "x"), (2, 14, "y"),
+        ]);
+        // "\r\n" split between the segments is a line break
+        assert_eq!(search("*", "abc\r\nxy\n".as_bytes(), 4), vec![(1, 0, 3, "abc".to_string()), (2, 5, 7, "xy".to_string())]);
+        // a match across the segments is found
+        assert_eq!(search("abc", "xxabcxx".as_bytes(), 4), vec![(1, 2, 5, "abc".to_string())]);
+        assert_eq!(search("[+a]", "xaaaaaax\n".as_bytes(), 4), vec![(1, 1, 7, "aaaaaa".to_string())]);
+        assert_eq!(search("[<1-99>]", "ab12345".as_bytes(), 4), vec![]);
+        // the anchors match only at the ends of the line, not of the segments
+        assert_eq!(search("[^]x", "abcdxy".as_bytes(), 4), vec![]);
+        assert_eq!(search("d[$]", "abcdxy".as_bytes(), 4), vec![]);
+        assert_eq!(search("y[$]", "abcdxy\n".as_bytes(), 4), vec![(1, 5, 6, "y".to_string())]);
+        // invalid UTF-8 is an error
+        let pattern = Pattern::new("*");
+        let mut matches = StreamMatches::new(&pattern, &b"ok\n\xff\n"[..]);
+        assert_eq!(matches.next().unwrap().unwrap().matched, "ok");
+        assert_eq!(matches.next().unwrap().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert!(matches.next().is_none());
+    }
+}
@@ -0,0 +1,7999 @@
+// This file is generated by tools/gen_unicode_tables.pl. Do not edit.
+// Unicode version: 14.0.0
+
+use crate::grapheme::GraphemeBreak;
+
+pub const ALPHABETIC: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{61}', '\u{7a}'), ('\u{aa}', '\u{aa}'), ('\u{b5}', '\u{b5}'),
+    ('\u{ba}', '\u{ba}'), ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'), ('\u{2e0}', '\u{2e4}'), ('\u{2ec}', '\u{2ec}'), ('\u{2ee}', '\u{2ee}'),
+    ('\u{345}', '\u{345}'), ('\u{370}', '\u{374}'), ('\u{376}', '\u{377}'), ('\u{37a}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'), ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'), ('\u{3a3}', '\u{3f5}'), ('\u{3f7}', '\u{481}'), ('\u{48a}', '\u{52f}'),
+    ('\u{531}', '\u{556}'), ('\u{559}', '\u{559}'), ('\u{560}', '\u{588}'), ('\u{5b0}', '\u{5bd}'),
+    ('\u{5bf}', '\u{5bf}'), ('\u{5c1}', '\u{5c2}'), ('\u{5c4}', '\u{5c5}'), ('\u{5c7}', '\u{5c7}'),
+    ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f2}'), ('\u{610}', '\u{61a}'), ('\u{620}', '\u{657}'),
+    ('\u{659}', '\u{65f}'), ('\u{66e}', '\u{6d3}'), ('\u{6d5}', '\u{6dc}'), ('\u{6e1}', '\u{6e8}'),
+    ('\u{6ed}', '\u{6ef}'), ('\u{6fa}', '\u{6fc}'), ('\u{6ff}', '\u{6ff}'), ('\u{710}', '\u{73f}'),
+    ('\u{74d}', '\u{7b1}'), ('\u{7ca}', '\u{7ea}'), ('\u{7f4}', '\u{7f5}'), ('\u{7fa}', '\u{7fa}'),
+    ('\u{800}', '\u{817}'), ('\u{81a}', '\u{82c}'), ('\u{840}', '\u{858}'), ('\u{860}', '\u{86a}'),
+    ('\u{870}', '\u{887}'), ('\u{889}', '\u{88e}'), ('\u{8a0}', '\u{8c9}'), ('\u{8d4}', '\u{8df}'),
+    ('\u{8e3}', '\u{8e9}'), ('\u{8f0}', '\u{93b}'), ('\u{93d}', '\u{94c}'), ('\u{94e}', '\u{950}'),
+    ('\u{955}', '\u{963}'), ('\u{971}', '\u{983}'), ('\u{985}', '\u{98c}'), ('\u{98f}', '\u{990}'),
+    ('\u{993}', '\u{9a8}'), ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'), ('\u{9b6}', '\u{9b9}'),
+    ('\u{9bd}', '\u{9c4}'), ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9cc}'), ('\u{9ce}', '\u{9ce}'),
+    ('\u{9d7}', '\u{9d7}'), ('\u{9dc}', '\u{9dd}'), ('\u{9df}', '\u{9e3}'), ('\u{9f0}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'), ('\u{a01}', '\u{a03}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'),
+    ('\u{a13}', '\u{a28}'), ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'), ('\u{a35}', '\u{a36}'),
+    ('\u{a38}', '\u{a39}'), ('\u{a3e}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4c}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'), ('\u{a70}', '\u{a75}'),
+    ('\u{a81}', '\u{a83}'), ('\u{a85}', '\u{a8d}'), ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'), ('\u{ab5}', '\u{ab9}'), ('\u{abd}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acc}'), ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae3}'),
+    ('\u{af9}', '\u{afc}'), ('\u{b01}', '\u{b03}'), ('\u{b05}', '\u{b0c}'), ('\u{b0f}', '\u{b10}'),
+    ('\u{b13}', '\u{b28}'), ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'), ('\u{b35}', '\u{b39}'),
+    ('\u{b3d}', '\u{b44}'), ('\u{b47}', '\u{b48}'), ('\u{b4b}', '\u{b4c}'), ('\u{b56}', '\u{b57}'),
+    ('\u{b5c}', '\u{b5d}'), ('\u{b5f}', '\u{b63}'), ('\u{b71}', '\u{b71}'), ('\u{b82}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'), ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'), ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'), ('\u{bbe}', '\u{bc2}'), ('\u{bc6}', '\u{bc8}'), ('\u{bca}', '\u{bcc}'),
+    ('\u{bd0}', '\u{bd0}'), ('\u{bd7}', '\u{bd7}'), ('\u{c00}', '\u{c03}'), ('\u{c05}', '\u{c0c}'),
+    ('\u{c0e}', '\u{c10}'), ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c39}'), ('\u{c3d}', '\u{c44}'),
+    ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4c}'), ('\u{c55}', '\u{c56}'), ('\u{c58}', '\u{c5a}'),
+    ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c63}'), ('\u{c80}', '\u{c83}'), ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'), ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb3}'), ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbd}', '\u{cc4}'), ('\u{cc6}', '\u{cc8}'), ('\u{cca}', '\u{ccc}'), ('\u{cd5}', '\u{cd6}'),
+    ('\u{cdd}', '\u{cde}'), ('\u{ce0}', '\u{ce3}'), ('\u{cf1}', '\u{cf2}'), ('\u{d00}', '\u{d0c}'),
+    ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d3a}'), ('\u{d3d}', '\u{d44}'), ('\u{d46}', '\u{d48}'),
+    ('\u{d4a}', '\u{d4c}'), ('\u{d4e}', '\u{d4e}'), ('\u{d54}', '\u{d57}'), ('\u{d5f}', '\u{d63}'),
+    ('\u{d7a}', '\u{d7f}'), ('\u{d81}', '\u{d83}'), ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'),
+    ('\u{db3}', '\u{dbb}'), ('\u{dbd}', '\u{dbd}'), ('\u{dc0}', '\u{dc6}'), ('\u{dcf}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'), ('\u{dd8}', '\u{ddf}'), ('\u{df2}', '\u{df3}'), ('\u{e01}', '\u{e3a}'),
+    ('\u{e40}', '\u{e46}'), ('\u{e4d}', '\u{e4d}'), ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'),
+    ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'), ('\u{ea5}', '\u{ea5}'), ('\u{ea7}', '\u{eb9}'),
+    ('\u{ebb}', '\u{ebd}'), ('\u{ec0}', '\u{ec4}'), ('\u{ec6}', '\u{ec6}'), ('\u{ecd}', '\u{ecd}'),
+    ('\u{edc}', '\u{edf}'), ('\u{f00}', '\u{f00}'), ('\u{f40}', '\u{f47}'), ('\u{f49}', '\u{f6c}'),
+    ('\u{f71}', '\u{f81}'), ('\u{f88}', '\u{f97}'), ('\u{f99}', '\u{fbc}'), ('\u{1000}', '\u{1036}'),
+    ('\u{1038}', '\u{1038}'), ('\u{103b}', '\u{103f}'), ('\u{1050}', '\u{108f}'), ('\u{109a}', '\u{109d}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'), ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'), ('\u{13a0}', '\u{13f5}'), ('\u{13f8}', '\u{13fd}'), ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'), ('\u{1681}', '\u{169a}'), ('\u{16a0}', '\u{16ea}'), ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1713}'), ('\u{171f}', '\u{1733}'), ('\u{1740}', '\u{1753}'), ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'), ('\u{1772}', '\u{1773}'), ('\u{1780}', '\u{17b3}'), ('\u{17b6}', '\u{17c8}'),
+    ('\u{17d7}', '\u{17d7}'), ('\u{17dc}', '\u{17dc}'), ('\u{1820}', '\u{1878}'), ('\u{1880}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'), ('\u{1900}', '\u{191e}'), ('\u{1920}', '\u{192b}'), ('\u{1930}', '\u{1938}'),
+    ('\u{1950}', '\u{196d}'), ('\u{1970}', '\u{1974}'), ('\u{1980}', '\u{19ab}'), ('\u{19b0}', '\u{19c9}'),
+    ('\u{1a00}', '\u{1a1b}'), ('\u{1a20}', '\u{1a5e}'), ('\u{1a61}', '\u{1a74}'), ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1abf}', '\u{1ac0}'), ('\u{1acc}', '\u{1ace}'), ('\u{1b00}', '\u{1b33}'), ('\u{1b35}', '\u{1b43}'),
+    ('\u{1b45}', '\u{1b4c}'), ('\u{1b80}', '\u{1ba9}'), ('\u{1bac}', '\u{1baf}'), ('\u{1bba}', '\u{1be5}'),
+    ('\u{1be7}', '\u{1bf1}'), ('\u{1c00}', '\u{1c36}'), ('\u{1c4d}', '\u{1c4f}'), ('\u{1c5a}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c88}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'), ('\u{1cf5}', '\u{1cf6}'), ('\u{1cfa}', '\u{1cfa}'), ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1de7}', '\u{1df4}'), ('\u{1e00}', '\u{1f15}'), ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'), ('\u{1f50}', '\u{1f57}'), ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f7d}'), ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fcc}'), ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'), ('\u{1fe0}', '\u{1fec}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{2071}', '\u{2071}'), ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'), ('\u{210a}', '\u{2113}'), ('\u{2115}', '\u{2115}'), ('\u{2119}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'), ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'),
+    ('\u{212f}', '\u{2139}'), ('\u{213c}', '\u{213f}'), ('\u{2145}', '\u{2149}'), ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'), ('\u{24b6}', '\u{24e9}'), ('\u{2c00}', '\u{2ce4}'), ('\u{2ceb}', '\u{2cee}'),
+    ('\u{2cf2}', '\u{2cf3}'), ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'), ('\u{2d6f}', '\u{2d6f}'), ('\u{2d80}', '\u{2d96}'), ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'), ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'), ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'), ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{2de0}', '\u{2dff}'),
+    ('\u{2e2f}', '\u{2e2f}'), ('\u{3005}', '\u{3007}'), ('\u{3021}', '\u{3029}'), ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303c}'), ('\u{3041}', '\u{3096}'), ('\u{309d}', '\u{309f}'), ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fc}', '\u{30ff}'), ('\u{3105}', '\u{312f}'), ('\u{3131}', '\u{318e}'), ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{4dbf}'), ('\u{4e00}', '\u{a48c}'), ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'), ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'), ('\u{a640}', '\u{a66e}'),
+    ('\u{a674}', '\u{a67b}'), ('\u{a67f}', '\u{a6ef}'), ('\u{a717}', '\u{a71f}'), ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7ca}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'), ('\u{a7d5}', '\u{a7d9}'),
+    ('\u{a7f2}', '\u{a805}'), ('\u{a807}', '\u{a827}'), ('\u{a840}', '\u{a873}'), ('\u{a880}', '\u{a8c3}'),
+    ('\u{a8c5}', '\u{a8c5}'), ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'), ('\u{a8fd}', '\u{a8ff}'),
+    ('\u{a90a}', '\u{a92a}'), ('\u{a930}', '\u{a952}'), ('\u{a960}', '\u{a97c}'), ('\u{a980}', '\u{a9b2}'),
+    ('\u{a9b4}', '\u{a9bf}'), ('\u{a9cf}', '\u{a9cf}'), ('\u{a9e0}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa36}'), ('\u{aa40}', '\u{aa4d}'), ('\u{aa60}', '\u{aa76}'), ('\u{aa7a}', '\u{aabe}'),
+    ('\u{aac0}', '\u{aac0}'), ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadd}'), ('\u{aae0}', '\u{aaef}'),
+    ('\u{aaf2}', '\u{aaf5}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'), ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abea}'), ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'),
+    ('\u{fe76}', '\u{fefc}'), ('\u{ff21}', '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{ff66}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'), ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{10300}', '\u{1031f}'), ('\u{1032d}', '\u{1034a}'),
+    ('\u{10350}', '\u{1037a}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'), ('\u{103c8}', '\u{103cf}'),
+    ('\u{103d1}', '\u{103d5}'), ('\u{10400}', '\u{1049d}'), ('\u{104b0}', '\u{104d3}'), ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'), ('\u{10530}', '\u{10563}'), ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'), ('\u{10594}', '\u{10595}'), ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'), ('\u{10780}', '\u{10785}'), ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'), ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'), ('\u{108f4}', '\u{108f5}'), ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109b7}'), ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a03}'), ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a13}'), ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a35}'), ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'), ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'), ('\u{10b80}', '\u{10b91}'), ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{10d00}', '\u{10d27}'), ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eab}', '\u{10eac}'), ('\u{10eb0}', '\u{10eb1}'), ('\u{10f00}', '\u{10f1c}'), ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f45}'), ('\u{10f70}', '\u{10f81}'), ('\u{10fb0}', '\u{10fc4}'), ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11000}', '\u{11045}'), ('\u{11071}', '\u{11075}'), ('\u{11082}', '\u{110b8}'), ('\u{110c2}', '\u{110c2}'),
+    ('\u{110d0}', '\u{110e8}'), ('\u{11100}', '\u{11132}'), ('\u{11144}', '\u{11147}'), ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'), ('\u{11180}', '\u{111bf}'), ('\u{111c1}', '\u{111c4}'), ('\u{111ce}', '\u{111cf}'),
+    ('\u{111da}', '\u{111da}'), ('\u{111dc}', '\u{111dc}'), ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{11234}'),
+    ('\u{11237}', '\u{11237}'), ('\u{1123e}', '\u{1123e}'), ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'), ('\u{1128f}', '\u{1129d}'), ('\u{1129f}', '\u{112a8}'), ('\u{112b0}', '\u{112e8}'),
+    ('\u{11300}', '\u{11303}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133d}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'), ('\u{1134b}', '\u{1134c}'), ('\u{11350}', '\u{11350}'), ('\u{11357}', '\u{11357}'),
+    ('\u{1135d}', '\u{11363}'), ('\u{11400}', '\u{11441}'), ('\u{11443}', '\u{11445}'), ('\u{11447}', '\u{1144a}'),
+    ('\u{1145f}', '\u{11461}'), ('\u{11480}', '\u{114c1}'), ('\u{114c4}', '\u{114c5}'), ('\u{114c7}', '\u{114c7}'),
+    ('\u{11580}', '\u{115b5}'), ('\u{115b8}', '\u{115be}'), ('\u{115d8}', '\u{115dd}'), ('\u{11600}', '\u{1163e}'),
+    ('\u{11640}', '\u{11640}'), ('\u{11644}', '\u{11644}'), ('\u{11680}', '\u{116b5}'), ('\u{116b8}', '\u{116b8}'),
+    ('\u{11700}', '\u{1171a}'), ('\u{1171d}', '\u{1172a}'), ('\u{11740}', '\u{11746}'), ('\u{11800}', '\u{11838}'),
+    ('\u{118a0}', '\u{118df}'), ('\u{118ff}', '\u{11906}'), ('\u{11909}', '\u{11909}'), ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'), ('\u{11918}', '\u{11935}'), ('\u{11937}', '\u{11938}'), ('\u{1193b}', '\u{1193c}'),
+    ('\u{1193f}', '\u{11942}'), ('\u{119a0}', '\u{119a7}'), ('\u{119aa}', '\u{119d7}'), ('\u{119da}', '\u{119df}'),
+    ('\u{119e1}', '\u{119e1}'), ('\u{119e3}', '\u{119e4}'), ('\u{11a00}', '\u{11a32}'), ('\u{11a35}', '\u{11a3e}'),
+    ('\u{11a50}', '\u{11a97}'), ('\u{11a9d}', '\u{11a9d}'), ('\u{11ab0}', '\u{11af8}'), ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c36}'), ('\u{11c38}', '\u{11c3e}'), ('\u{11c40}', '\u{11c40}'), ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11c92}', '\u{11ca7}'), ('\u{11ca9}', '\u{11cb6}'), ('\u{11d00}', '\u{11d06}'), ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'), ('\u{11d3c}', '\u{11d3d}'), ('\u{11d3f}', '\u{11d41}'),
+    ('\u{11d43}', '\u{11d43}'), ('\u{11d46}', '\u{11d47}'), ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d8e}'), ('\u{11d90}', '\u{11d91}'), ('\u{11d93}', '\u{11d96}'), ('\u{11d98}', '\u{11d98}'),
+    ('\u{11ee0}', '\u{11ef6}'), ('\u{11fb0}', '\u{11fb0}'), ('\u{12000}', '\u{12399}'), ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'), ('\u{12f90}', '\u{12ff0}'), ('\u{13000}', '\u{1342e}'), ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16a70}', '\u{16abe}'), ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16b00}', '\u{16b2f}'), ('\u{16b40}', '\u{16b43}'), ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16e40}', '\u{16e7f}'), ('\u{16f00}', '\u{16f4a}'), ('\u{16f4f}', '\u{16f87}'), ('\u{16f8f}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'), ('\u{16fe3}', '\u{16fe3}'), ('\u{16ff0}', '\u{16ff1}'), ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'), ('\u{18d00}', '\u{18d08}'), ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b122}'), ('\u{1b150}', '\u{1b152}'), ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'), ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'), ('\u{1bc9e}', '\u{1bc9e}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'), ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1df00}', '\u{1df1e}'), ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'), ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'), ('\u{1e026}', '\u{1e02a}'), ('\u{1e100}', '\u{1e12c}'), ('\u{1e137}', '\u{1e13d}'),
+    ('\u{1e14e}', '\u{1e14e}'), ('\u{1e290}', '\u{1e2ad}'), ('\u{1e2c0}', '\u{1e2eb}'), ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'), ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e900}', '\u{1e943}'), ('\u{1e947}', '\u{1e947}'), ('\u{1e94b}', '\u{1e94b}'), ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1f130}', '\u{1f149}'), ('\u{1f150}', '\u{1f169}'), ('\u{1f170}', '\u{1f189}'), ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b738}'), ('\u{2b740}', '\u{2b81d}'), ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'),
+];
+
+pub const DECIMAL_NUMBER: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'), ('\u{660}', '\u{669}'), ('\u{6f0}', '\u{6f9}'), ('\u{7c0}', '\u{7c9}'),
+    ('\u{966}', '\u{96f}'), ('\u{9e6}', '\u{9ef}'), ('\u{a66}', '\u{a6f}'), ('\u{ae6}', '\u{aef}'),
+    ('\u{b66}', '\u{b6f}'), ('\u{be6}', '\u{bef}'), ('\u{c66}', '\u{c6f}'), ('\u{ce6}', '\u{cef}'),
+    ('\u{d66}', '\u{d6f}'), ('\u{de6}', '\u{def}'), ('\u{e50}', '\u{e59}'), ('\u{ed0}', '\u{ed9}'),
+    ('\u{f20}', '\u{f29}'), ('\u{1040}', '\u{1049}'), ('\u{1090}', '\u{1099}'), ('\u{17e0}', '\u{17e9}'),
+    ('\u{1810}', '\u{1819}'), ('\u{1946}', '\u{194f}'), ('\u{19d0}', '\u{19d9}'), ('\u{1a80}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'), ('\u{1b50}', '\u{1b59}'), ('\u{1bb0}', '\u{1bb9}'), ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c50}', '\u{1c59}'), ('\u{a620}', '\u{a629}'), ('\u{a8d0}', '\u{a8d9}'), ('\u{a900}', '\u{a909}'),
+    ('\u{a9d0}', '\u{a9d9}'), ('\u{a9f0}', '\u{a9f9}'), ('\u{aa50}', '\u{aa59}'), ('\u{abf0}', '\u{abf9}'),
+    ('\u{ff10}', '\u{ff19}'), ('\u{104a0}', '\u{104a9}'), ('\u{10d30}', '\u{10d39}'), ('\u{11066}', '\u{1106f}'),
+    ('\u{110f0}', '\u{110f9}'), ('\u{11136}', '\u{1113f}'), ('\u{111d0}', '\u{111d9}'), ('\u{112f0}', '\u{112f9}'),
+    ('\u{11450}', '\u{11459}'), ('\u{114d0}', '\u{114d9}'), ('\u{11650}', '\u{11659}'), ('\u{116c0}', '\u{116c9}'),
+    ('\u{11730}', '\u{11739}'), ('\u{118e0}', '\u{118e9}'), ('\u{11950}', '\u{11959}'), ('\u{11c50}', '\u{11c59}'),
+    ('\u{11d50}', '\u{11d59}'), ('\u{11da0}', '\u{11da9}'), ('\u{16a60}', '\u{16a69}'), ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16b50}', '\u{16b59}'), ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1e140}', '\u{1e149}'), ('\u{1e2f0}', '\u{1e2f9}'),
+    ('\u{1e950}', '\u{1e959}'), ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+pub const WHITE_SPACE: &[(char, char)] = &[
+    ('\u{9}', '\u{d}'), ('\u{20}', '\u{20}'), ('\u{85}', '\u{85}'), ('\u{a0}', '\u{a0}'),
+    ('\u{1680}', '\u{1680}'), ('\u{2000}', '\u{200a}'), ('\u{2028}', '\u{2029}'), ('\u{202f}', '\u{202f}'),
+    ('\u{205f}', '\u{205f}'), ('\u{3000}', '\u{3000}'),
+];
+
+pub const UPPERCASE: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{de}'), ('\u{100}', '\u{100}'),
+    ('\u{102}', '\u{102}'), ('\u{104}', '\u{104}'), ('\u{106}', '\u{106}'), ('\u{108}', '\u{108}'),
+    ('\u{10a}', '\u{10a}'), ('\u{10c}', '\u{10c}'), ('\u{10e}', '\u{10e}'), ('\u{110}', '\u{110}'),
+    ('\u{112}', '\u{112}'), ('\u{114}', '\u{114}'), ('\u{116}', '\u{116}'), ('\u{118}', '\u{118}'),
+    ('\u{11a}', '\u{11a}'), ('\u{11c}', '\u{11c}'), ('\u{11e}', '\u{11e}'), ('\u{120}', '\u{120}'),
+    ('\u{122}', '\u{122}'), ('\u{124}', '\u{124}'), ('\u{126}', '\u{126}'), ('\u{128}', '\u{128}'),
+    ('\u{12a}', '\u{12a}'), ('\u{12c}', '\u{12c}'), ('\u{12e}', '\u{12e}'), ('\u{130}', '\u{130}'),
+    ('\u{132}', '\u{132}'), ('\u{134}', '\u{134}'), ('\u{136}', '\u{136}'), ('\u{139}', '\u{139}'),
+    ('\u{13b}', '\u{13b}'), ('\u{13d}', '\u{13d}'), ('\u{13f}', '\u{13f}'), ('\u{141}', '\u{141}'),
+    ('\u{143}', '\u{143}'), ('\u{145}', '\u{145}'), ('\u{147}', '\u{147}'), ('\u{14a}', '\u{14a}'),
+    ('\u{14c}', '\u{14c}'), ('\u{14e}', '\u{14e}'), ('\u{150}', '\u{150}'), ('\u{152}', '\u{152}'),
+    ('\u{154}', '\u{154}'), ('\u{156}', '\u{156}'), ('\u{158}', '\u{158}'), ('\u{15a}', '\u{15a}'),
+    ('\u{15c}', '\u{15c}'), ('\u{15e}', '\u{15e}'), ('\u{160}', '\u{160}'), ('\u{162}', '\u{162}'),
+    ('\u{164}', '\u{164}'), ('\u{166}', '\u{166}'), ('\u{168}', '\u{168}'), ('\u{16a}', '\u{16a}'),
+    ('\u{16c}', '\u{16c}'), ('\u{16e}', '\u{16e}'), ('\u{170}', '\u{170}'), ('\u{172}', '\u{172}'),
+    ('\u{174}', '\u{174}'), ('\u{176}', '\u{176}'), ('\u{178}', '\u{179}'), ('\u{17b}', '\u{17b}'),
+    ('\u{17d}', '\u{17d}'), ('\u{181}', '\u{182}'), ('\u{184}', '\u{184}'), ('\u{186}', '\u{187}'),
+    ('\u{189}', '\u{18b}'), ('\u{18e}', '\u{191}'), ('\u{193}', '\u{194}'), ('\u{196}', '\u{198}'),
+    ('\u{19c}', '\u{19d}'), ('\u{19f}', '\u{1a0}'), ('\u{1a2}', '\u{1a2}'), ('\u{1a4}', '\u{1a4}'),
+    ('\u{1a6}', '\u{1a7}'), ('\u{1a9}', '\u{1a9}'), ('\u{1ac}', '\u{1ac}'), ('\u{1ae}', '\u{1af}'),
+    ('\u{1b1}', '\u{1b3}'), ('\u{1b5}', '\u{1b5}'), ('\u{1b7}', '\u{1b8}'), ('\u{1bc}', '\u{1bc}'),
+    ('\u{1c4}', '\u{1c4}'), ('\u{1c7}', '\u{1c7}'), ('\u{1ca}', '\u{1ca}'), ('\u{1cd}', '\u{1cd}'),
+    ('\u{1cf}', '\u{1cf}'), ('\u{1d1}', '\u{1d1}'), ('\u{1d3}', '\u{1d3}'), ('\u{1d5}', '\u{1d5}'),
+    ('\u{1d7}', '\u{1d7}'), ('\u{1d9}', '\u{1d9}'), ('\u{1db}', '\u{1db}'), ('\u{1de}', '\u{1de}'),
+    ('\u{1e0}', '\u{1e0}'), ('\u{1e2}', '\u{1e2}'), ('\u{1e4}', '\u{1e4}'), ('\u{1e6}', '\u{1e6}'),
+    ('\u{1e8}', '\u{1e8}'), ('\u{1ea}', '\u{1ea}'), ('\u{1ec}', '\u{1ec}'), ('\u{1ee}', '\u{1ee}'),
+    ('\u{1f1}', '\u{1f1}'), ('\u{1f4}', '\u{1f4}'), ('\u{1f6}', '\u{1f8}'), ('\u{1fa}', '\u{1fa}'),
+    ('\u{1fc}', '\u{1fc}'), ('\u{1fe}', '\u{1fe}'), ('\u{200}', '\u{200}'), ('\u{202}', '\u{202}'),
+    ('\u{204}', '\u{204}'), ('\u{206}', '\u{206}'), ('\u{208}', '\u{208}'), ('\u{20a}', '\u{20a}'),
+    ('\u{20c}', '\u{20c}'), ('\u{20e}', '\u{20e}'), ('\u{210}', '\u{210}'), ('\u{212}', '\u{212}'),
+    ('\u{214}', '\u{214}'), ('\u{216}', '\u{216}'), ('\u{218}', '\u{218}'), ('\u{21a}', '\u{21a}'),
+    ('\u{21c}', '\u{21c}'), ('\u{21e}', '\u{21e}'), ('\u{220}', '\u{220}'), ('\u{222}', '\u{222}'),
+    ('\u{224}', '\u{224}'), ('\u{226}', '\u{226}'), ('\u{228}', '\u{228}'), ('\u{22a}', '\u{22a}'),
+    ('\u{22c}', '\u{22c}'), ('\u{22e}', '\u{22e}'), ('\u{230}', '\u{230}'), ('\u{232}', '\u{232}'),
+    ('\u{23a}', '\u{23b}'), ('\u{23d}', '\u{23e}'), ('\u{241}', '\u{241}'), ('\u{243}', '\u{246}'),
+    ('\u{248}', '\u{248}'), ('\u{24a}', '\u{24a}'), ('\u{24c}', '\u{24c}'), ('\u{24e}', '\u{24e}'),
+    ('\u{370}', '\u{370}'), ('\u{372}', '\u{372}'), ('\u{376}', '\u{376}'), ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'), ('\u{38e}', '\u{38f}'),
+    ('\u{391}', '\u{3a1}'), ('\u{3a3}', '\u{3ab}'), ('\u{3cf}', '\u{3cf}'), ('\u{3d2}', '\u{3d4}'),
+    ('\u{3d8}', '\u{3d8}'), ('\u{3da}', '\u{3da}'), ('\u{3dc}', '\u{3dc}'), ('\u{3de}', '\u{3de}'),
+    ('\u{3e0}', '\u{3e0}'), ('\u{3e2}', '\u{3e2}'), ('\u{3e4}', '\u{3e4}'), ('\u{3e6}', '\u{3e6}'),
+    ('\u{3e8}', '\u{3e8}'), ('\u{3ea}', '\u{3ea}'), ('\u{3ec}', '\u{3ec}'), ('\u{3ee}', '\u{3ee}'),
+    ('\u{3f4}', '\u{3f4}'), ('\u{3f7}', '\u{3f7}'), ('\u{3f9}', '\u{3fa}'), ('\u{3fd}', '\u{42f}'),
+    ('\u{460}', '\u{460}'), ('\u{462}', '\u{462}'), ('\u{464}', '\u{464}'), ('\u{466}', '\u{466}'),
+    ('\u{468}', '\u{468}'), ('\u{46a}', '\u{46a}'), ('\u{46c}', '\u{46c}'), ('\u{46e}', '\u{46e}'),
+    ('\u{470}', '\u{470}'), ('\u{472}', '\u{472}'), ('\u{474}', '\u{474}'), ('\u{476}', '\u{476}'),
+    ('\u{478}', '\u{478}'), ('\u{47a}', '\u{47a}'), ('\u{47c}', '\u{47c}'), ('\u{47e}', '\u{47e}'),
+    ('\u{480}', '\u{480}'), ('\u{48a}', '\u{48a}'), ('\u{48c}', '\u{48c}'), ('\u{48e}', '\u{48e}'),
+    ('\u{490}', '\u{490}'), ('\u{492}', '\u{492}'), ('\u{494}', '\u{494}'), ('\u{496}', '\u{496}'),
+    ('\u{498}', '\u{498}'), ('\u{49a}', '\u{49a}'), ('\u{49c}', '\u{49c}'), ('\u{49e}', '\u{49e}'),
+    ('\u{4a0}', '\u{4a0}'), ('\u{4a2}', '\u{4a2}'), ('\u{4a4}', '\u{4a4}'), ('\u{4a6}', '\u{4a6}'),
+    ('\u{4a8}', '\u{4a8}'), ('\u{4aa}', '\u{4aa}'), ('\u{4ac}', '\u{4ac}'), ('\u{4ae}', '\u{4ae}'),
+    ('\u{4b0}', '\u{4b0}'), ('\u{4b2}', '\u{4b2}'), ('\u{4b4}', '\u{4b4}'), ('\u{4b6}', '\u{4b6}'),
+    ('\u{4b8}', '\u{4b8}'), ('\u{4ba}', '\u{4ba}'), ('\u{4bc}', '\u{4bc}'), ('\u{4be}', '\u{4be}'),
+    ('\u{4c0}', '\u{4c1}'), ('\u{4c3}', '\u{4c3}'), ('\u{4c5}', '\u{4c5}'), ('\u{4c7}', '\u{4c7}'),
+    ('\u{4c9}', '\u{4c9}'), ('\u{4cb}', '\u{4cb}'), ('\u{4cd}', '\u{4cd}'), ('\u{4d0}', '\u{4d0}'),
+    ('\u{4d2}', '\u{4d2}'), ('\u{4d4}', '\u{4d4}'), ('\u{4d6}', '\u{4d6}'), ('\u{4d8}', '\u{4d8}'),
+    ('\u{4da}', '\u{4da}'), ('\u{4dc}', '\u{4dc}'), ('\u{4de}', '\u{4de}'), ('\u{4e0}', '\u{4e0}'),
+    ('\u{4e2}', '\u{4e2}'), ('\u{4e4}', '\u{4e4}'), ('\u{4e6}', '\u{4e6}'), ('\u{4e8}', '\u{4e8}'),
+    ('\u{4ea}', '\u{4ea}'), ('\u{4ec}', '\u{4ec}'), ('\u{4ee}', '\u{4ee}'), ('\u{4f0}', '\u{4f0}'),
+    ('\u{4f2}', '\u{4f2}'), ('\u{4f4}', '\u{4f4}'), ('\u{4f6}', '\u{4f6}'), ('\u{4f8}', '\u{4f8}'),
+    ('\u{4fa}', '\u{4fa}'), ('\u{4fc}', '\u{4fc}'), ('\u{4fe}', '\u{4fe}'), ('\u{500}', '\u{500}'),
+    ('\u{502}', '\u{502}'), ('\u{504}', '\u{504}'), ('\u{506}', '\u{506}'), ('\u{508}', '\u{508}'),
+    ('\u{50a}', '\u{50a}'), ('\u{50c}', '\u{50c}'), ('\u{50e}', '\u{50e}'), ('\u{510}', '\u{510}'),
+    ('\u{512}', '\u{512}'), ('\u{514}', '\u{514}'), ('\u{516}', '\u{516}'), ('\u{518}', '\u{518}'),
+    ('\u{51a}', '\u{51a}'), ('\u{51c}', '\u{51c}'), ('\u{51e}', '\u{51e}'), ('\u{520}', '\u{520}'),
+    ('\u{522}', '\u{522}'), ('\u{524}', '\u{524}'), ('\u{526}', '\u{526}'), ('\u{528}', '\u{528}'),
+    ('\u{52a}', '\u{52a}'), ('\u{52c}', '\u{52c}'), ('\u{52e}', '\u{52e}'), ('\u{531}', '\u{556}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{13a0}', '\u{13f5}'),
+    ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{1e00}', '\u{1e00}'), ('\u{1e02}', '\u{1e02}'),
+    ('\u{1e04}', '\u{1e04}'), ('\u{1e06}', '\u{1e06}'), ('\u{1e08}', '\u{1e08}'), ('\u{1e0a}', '\u{1e0a}'),
+    ('\u{1e0c}', '\u{1e0c}'), ('\u{1e0e}', '\u{1e0e}'), ('\u{1e10}', '\u{1e10}'), ('\u{1e12}', '\u{1e12}'),
+    ('\u{1e14}', '\u{1e14}'), ('\u{1e16}', '\u{1e16}'), ('\u{1e18}', '\u{1e18}'), ('\u{1e1a}', '\u{1e1a}'),
+    ('\u{1e1c}', '\u{1e1c}'), ('\u{1e1e}', '\u{1e1e}'), ('\u{1e20}', '\u{1e20}'), ('\u{1e22}', '\u{1e22}'),
+    ('\u{1e24}', '\u{1e24}'), ('\u{1e26}', '\u{1e26}'), ('\u{1e28}', '\u{1e28}'), ('\u{1e2a}', '\u{1e2a}'),
+    ('\u{1e2c}', '\u{1e2c}'), ('\u{1e2e}', '\u{1e2e}'), ('\u{1e30}', '\u{1e30}'), ('\u{1e32}', '\u{1e32}'),
+    ('\u{1e34}', '\u{1e34}'), ('\u{1e36}', '\u{1e36}'), ('\u{1e38}', '\u{1e38}'), ('\u{1e3a}', '\u{1e3a}'),
+    ('\u{1e3c}', '\u{1e3c}'), ('\u{1e3e}', '\u{1e3e}'), ('\u{1e40}', '\u{1e40}'), ('\u{1e42}', '\u{1e42}'),
+    ('\u{1e44}', '\u{1e44}'), ('\u{1e46}', '\u{1e46}'), ('\u{1e48}', '\u{1e48}'), ('\u{1e4a}', '\u{1e4a}'),
+    ('\u{1e4c}', '\u{1e4c}'), ('\u{1e4e}', '\u{1e4e}'), ('\u{1e50}', '\u{1e50}'), ('\u{1e52}', '\u{1e52}'),
+    ('\u{1e54}', '\u{1e54}'), ('\u{1e56}', '\u{1e56}'), ('\u{1e58}', '\u{1e58}'), ('\u{1e5a}', '\u{1e5a}'),
+    ('\u{1e5c}', '\u{1e5c}'), ('\u{1e5e}', '\u{1e5e}'), ('\u{1e60}', '\u{1e60}'), ('\u{1e62}', '\u{1e62}'),
+    ('\u{1e64}', '\u{1e64}'), ('\u{1e66}', '\u{1e66}'), ('\u{1e68}', '\u{1e68}'), ('\u{1e6a}', '\u{1e6a}'),
+    ('\u{1e6c}', '\u{1e6c}'), ('\u{1e6e}', '\u{1e6e}'), ('\u{1e70}', '\u{1e70}'), ('\u{1e72}', '\u{1e72}'),
+    ('\u{1e74}', '\u{1e74}'), ('\u{1e76}', '\u{1e76}'), ('\u{1e78}', '\u{1e78}'), ('\u{1e7a}', '\u{1e7a}'),
+    ('\u{1e7c}', '\u{1e7c}'), ('\u{1e7e}', '\u{1e7e}'), ('\u{1e80}', '\u{1e80}'), ('\u{1e82}', '\u{1e82}'),
+    ('\u{1e84}', '\u{1e84}'), ('\u{1e86}', '\u{1e86}'), ('\u{1e88}', '\u{1e88}'), ('\u{1e8a}', '\u{1e8a}'),
+    ('\u{1e8c}', '\u{1e8c}'), ('\u{1e8e}', '\u{1e8e}'), ('\u{1e90}', '\u{1e90}'), ('\u{1e92}', '\u{1e92}'),
+    ('\u{1e94}', '\u{1e94}'), ('\u{1e9e}', '\u{1e9e}'), ('\u{1ea0}', '\u{1ea0}'), ('\u{1ea2}', '\u{1ea2}'),
+    ('\u{1ea4}', '\u{1ea4}'), ('\u{1ea6}', '\u{1ea6}'), ('\u{1ea8}', '\u{1ea8}'), ('\u{1eaa}', '\u{1eaa}'),
+    ('\u{1eac}', '\u{1eac}'), ('\u{1eae}', '\u{1eae}'), ('\u{1eb0}', '\u{1eb0}'), ('\u{1eb2}', '\u{1eb2}'),
+    ('\u{1eb4}', '\u{1eb4}'), ('\u{1eb6}', '\u{1eb6}'), ('\u{1eb8}', '\u{1eb8}'), ('\u{1eba}', '\u{1eba}'),
+    ('\u{1ebc}', '\u{1ebc}'), ('\u{1ebe}', '\u{1ebe}'), ('\u{1ec0}', '\u{1ec0}'), ('\u{1ec2}', '\u{1ec2}'),
+    ('\u{1ec4}', '\u{1ec4}'), ('\u{1ec6}', '\u{1ec6}'), ('\u{1ec8}', '\u{1ec8}'), ('\u{1eca}', '\u{1eca}'),
+    ('\u{1ecc}', '\u{1ecc}'), ('\u{1ece}', '\u{1ece}'), ('\u{1ed0}', '\u{1ed0}'), ('\u{1ed2}', '\u{1ed2}'),
+    ('\u{1ed4}', '\u{1ed4}'), ('\u{1ed6}', '\u{1ed6}'), ('\u{1ed8}', '\u{1ed8}'), ('\u{1eda}', '\u{1eda}'),
+    ('\u{1edc}', '\u{1edc}'), ('\u{1ede}', '\u{1ede}'), ('\u{1ee0}', '\u{1ee0}'), ('\u{1ee2}', '\u{1ee2}'),
+    ('\u{1ee4}', '\u{1ee4}'), ('\u{1ee6}', '\u{1ee6}'), ('\u{1ee8}', '\u{1ee8}'), ('\u{1eea}', '\u{1eea}'),
+    ('\u{1eec}', '\u{1eec}'), ('\u{1eee}', '\u{1eee}'), ('\u{1ef0}', '\u{1ef0}'), ('\u{1ef2}', '\u{1ef2}'),
+    ('\u{1ef4}', '\u{1ef4}'), ('\u{1ef6}', '\u{1ef6}'), ('\u{1ef8}', '\u{1ef8}'), ('\u{1efa}', '\u{1efa}'),
+    ('\u{1efc}', '\u{1efc}'), ('\u{1efe}', '\u{1efe}'), ('\u{1f08}', '\u{1f0f}'), ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f28}', '\u{1f2f}'), ('\u{1f38}', '\u{1f3f}'), ('\u{1f48}', '\u{1f4d}'), ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'), ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f5f}'), ('\u{1f68}', '\u{1f6f}'),
+    ('\u{1fb8}', '\u{1fbb}'), ('\u{1fc8}', '\u{1fcb}'), ('\u{1fd8}', '\u{1fdb}'), ('\u{1fe8}', '\u{1fec}'),
+    ('\u{1ff8}', '\u{1ffb}'), ('\u{2102}', '\u{2102}'), ('\u{2107}', '\u{2107}'), ('\u{210b}', '\u{210d}'),
+    ('\u{2110}', '\u{2112}'), ('\u{2115}', '\u{2115}'), ('\u{2119}', '\u{211d}'), ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'), ('\u{2130}', '\u{2133}'),
+    ('\u{213e}', '\u{213f}'), ('\u{2145}', '\u{2145}'), ('\u{2160}', '\u{216f}'), ('\u{2183}', '\u{2183}'),
+    ('\u{24b6}', '\u{24cf}'), ('\u{2c00}', '\u{2c2f}'), ('\u{2c60}', '\u{2c60}'), ('\u{2c62}', '\u{2c64}'),
+    ('\u{2c67}', '\u{2c67}'), ('\u{2c69}', '\u{2c69}'), ('\u{2c6b}', '\u{2c6b}'), ('\u{2c6d}', '\u{2c70}'),
+    ('\u{2c72}', '\u{2c72}'), ('\u{2c75}', '\u{2c75}'), ('\u{2c7e}', '\u{2c80}'), ('\u{2c82}', '\u{2c82}'),
+    ('\u{2c84}', '\u{2c84}'), ('\u{2c86}', '\u{2c86}'), ('\u{2c88}', '\u{2c88}'), ('\u{2c8a}', '\u{2c8a}'),
+    ('\u{2c8c}', '\u{2c8c}'), ('\u{2c8e}', '\u{2c8e}'), ('\u{2c90}', '\u{2c90}'), ('\u{2c92}', '\u{2c92}'),
+    ('\u{2c94}', '\u{2c94}'), ('\u{2c96}', '\u{2c96}'), ('\u{2c98}', '\u{2c98}'), ('\u{2c9a}', '\u{2c9a}'),
+    ('\u{2c9c}', '\u{2c9c}'), ('\u{2c9e}', '\u{2c9e}'), ('\u{2ca0}', '\u{2ca0}'), ('\u{2ca2}', '\u{2ca2}'),
+    ('\u{2ca4}', '\u{2ca4}'), ('\u{2ca6}', '\u{2ca6}'), ('\u{2ca8}', '\u{2ca8}'), ('\u{2caa}', '\u{2caa}'),
+    ('\u{2cac}', '\u{2cac}'), ('\u{2cae}', '\u{2cae}'), ('\u{2cb0}', '\u{2cb0}'), ('\u{2cb2}', '\u{2cb2}'),
+    ('\u{2cb4}', '\u{2cb4}'), ('\u{2cb6}', '\u{2cb6}'), ('\u{2cb8}', '\u{2cb8}'), ('\u{2cba}', '\u{2cba}'),
+    ('\u{2cbc}', '\u{2cbc}'), ('\u{2cbe}', '\u{2cbe}'), ('\u{2cc0}', '\u{2cc0}'), ('\u{2cc2}', '\u{2cc2}'),
+    ('\u{2cc4}', '\u{2cc4}'), ('\u{2cc6}', '\u{2cc6}'), ('\u{2cc8}', '\u{2cc8}'), ('\u{2cca}', '\u{2cca}'),
+    ('\u{2ccc}', '\u{2ccc}'), ('\u{2cce}', '\u{2cce}'), ('\u{2cd0}', '\u{2cd0}'), ('\u{2cd2}', '\u{2cd2}'),
+    ('\u{2cd4}', '\u{2cd4}'), ('\u{2cd6}', '\u{2cd6}'), ('\u{2cd8}', '\u{2cd8}'), ('\u{2cda}', '\u{2cda}'),
+    ('\u{2cdc}', '\u{2cdc}'), ('\u{2cde}', '\u{2cde}'), ('\u{2ce0}', '\u{2ce0}'), ('\u{2ce2}', '\u{2ce2}'),
+    ('\u{2ceb}', '\u{2ceb}'), ('\u{2ced}', '\u{2ced}'), ('\u{2cf2}', '\u{2cf2}'), ('\u{a640}', '\u{a640}'),
+    ('\u{a642}', '\u{a642}'), ('\u{a644}', '\u{a644}'), ('\u{a646}', '\u{a646}'), ('\u{a648}', '\u{a648}'),
+    ('\u{a64a}', '\u{a64a}'), ('\u{a64c}', '\u{a64c}'), ('\u{a64e}', '\u{a64e}'), ('\u{a650}', '\u{a650}'),
+    ('\u{a652}', '\u{a652}'), ('\u{a654}', '\u{a654}'), ('\u{a656}', '\u{a656}'), ('\u{a658}', '\u{a658}'),
+    ('\u{a65a}', '\u{a65a}'), ('\u{a65c}', '\u{a65c}'), ('\u{a65e}', '\u{a65e}'), ('\u{a660}', '\u{a660}'),
+    ('\u{a662}', '\u{a662}'), ('\u{a664}', '\u{a664}'), ('\u{a666}', '\u{a666}'), ('\u{a668}', '\u{a668}'),
+    ('\u{a66a}', '\u{a66a}'), ('\u{a66c}', '\u{a66c}'), ('\u{a680}', '\u{a680}'), ('\u{a682}', '\u{a682}'),
+    ('\u{a684}', '\u{a684}'), ('\u{a686}', '\u{a686}'), ('\u{a688}', '\u{a688}'), ('\u{a68a}', '\u{a68a}'),
+    ('\u{a68c}', '\u{a68c}'), ('\u{a68e}', '\u{a68e}'), ('\u{a690}', '\u{a690}'), ('\u{a692}', '\u{a692}'),
+    ('\u{a694}', '\u{a694}'), ('\u{a696}', '\u{a696}'), ('\u{a698}', '\u{a698}'), ('\u{a69a}', '\u{a69a}'),
+    ('\u{a722}', '\u{a722}'), ('\u{a724}', '\u{a724}'), ('\u{a726}', '\u{a726}'), ('\u{a728}', '\u{a728}'),
+    ('\u{a72a}', '\u{a72a}'), ('\u{a72c}', '\u{a72c}'), ('\u{a72e}', '\u{a72e}'), ('\u{a732}', '\u{a732}'),
+    ('\u{a734}', '\u{a734}'), ('\u{a736}', '\u{a736}'), ('\u{a738}', '\u{a738}'), ('\u{a73a}', '\u{a73a}'),
+    ('\u{a73c}', '\u{a73c}'), ('\u{a73e}', '\u{a73e}'), ('\u{a740}', '\u{a740}'), ('\u{a742}', '\u{a742}'),
+    ('\u{a744}', '\u{a744}'), ('\u{a746}', '\u{a746}'), ('\u{a748}', '\u{a748}'), ('\u{a74a}', '\u{a74a}'),
+    ('\u{a74c}', '\u{a74c}'), ('\u{a74e}', '\u{a74e}'), ('\u{a750}', '\u{a750}'), ('\u{a752}', '\u{a752}'),
+    ('\u{a754}', '\u{a754}'), ('\u{a756}', '\u{a756}'), ('\u{a758}', '\u{a758}'), ('\u{a75a}', '\u{a75a}'),
+    ('\u{a75c}', '\u{a75c}'), ('\u{a75e}', '\u{a75e}'), ('\u{a760}', '\u{a760}'), ('\u{a762}', '\u{a762}'),
+    ('\u{a764}', '\u{a764}'), ('\u{a766}', '\u{a766}'), ('\u{a768}', '\u{a768}'), ('\u{a76a}', '\u{a76a}'),
+    ('\u{a76c}', '\u{a76c}'), ('\u{a76e}', '\u{a76e}'), ('\u{a779}', '\u{a779}'), ('\u{a77b}', '\u{a77b}'),
+    ('\u{a77d}', '\u{a77e}'), ('\u{a780}', '\u{a780}'), ('\u{a782}', '\u{a782}'), ('\u{a784}', '\u{a784}'),
+    ('\u{a786}', '\u{a786}'), ('\u{a78b}', '\u{a78b}'), ('\u{a78d}', '\u{a78d}'), ('\u{a790}', '\u{a790}'),
+    ('\u{a792}', '\u{a792}'), ('\u{a796}', '\u{a796}'), ('\u{a798}', '\u{a798}'), ('\u{a79a}', '\u{a79a}'),
+    ('\u{a79c}', '\u{a79c}'), ('\u{a79e}', '\u{a79e}'), ('\u{a7a0}', '\u{a7a0}'), ('\u{a7a2}', '\u{a7a2}'),
+    ('\u{a7a4}', '\u{a7a4}'), ('\u{a7a6}', '\u{a7a6}'), ('\u{a7a8}', '\u{a7a8}'), ('\u{a7aa}', '\u{a7ae}'),
+    ('\u{a7b0}', '\u{a7b4}'), ('\u{a7b6}', '\u{a7b6}'), ('\u{a7b8}', '\u{a7b8}'), ('\u{a7ba}', '\u{a7ba}'),
+    ('\u{a7bc}', '\u{a7bc}'), ('\u{a7be}', '\u{a7be}'), ('\u{a7c0}', '\u{a7c0}'), ('\u{a7c2}', '\u{a7c2}'),
+    ('\u{a7c4}', '\u{a7c7}'), ('\u{a7c9}', '\u{a7c9}'), ('\u{a7d0}', '\u{a7d0}'), ('\u{a7d6}', '\u{a7d6}'),
+    ('\u{a7d8}', '\u{a7d8}'), ('\u{a7f5}', '\u{a7f5}'), ('\u{ff21}', '\u{ff3a}'), ('\u{10400}', '\u{10427}'),
+    ('\u{104b0}', '\u{104d3}'), ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'), ('\u{10c80}', '\u{10cb2}'), ('\u{118a0}', '\u{118bf}'), ('\u{16e40}', '\u{16e5f}'),
+    ('\u{1d400}', '\u{1d419}'), ('\u{1d434}', '\u{1d44d}'), ('\u{1d468}', '\u{1d481}'), ('\u{1d49c}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b5}'), ('\u{1d4d0}', '\u{1d4e9}'), ('\u{1d504}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d538}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'), ('\u{1d56c}', '\u{1d585}'),
+    ('\u{1d5a0}', '\u{1d5b9}'), ('\u{1d5d4}', '\u{1d5ed}'), ('\u{1d608}', '\u{1d621}'), ('\u{1d63c}', '\u{1d655}'),
+    ('\u{1d670}', '\u{1d689}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6e2}', '\u{1d6fa}'), ('\u{1d71c}', '\u{1d734}'),
+    ('\u{1d756}', '\u{1d76e}'), ('\u{1d790}', '\u{1d7a8}'), ('\u{1d7ca}', '\u{1d7ca}'), ('\u{1e900}', '\u{1e921}'),
+    ('\u{1f130}', '\u{1f149}'), ('\u{1f150}', '\u{1f169}'), ('\u{1f170}', '\u{1f189}'),
+];
+
+pub const LOWERCASE: &[(char, char)] = &[
+    ('\u{61}', '\u{7a}'), ('\u{aa}', '\u{aa}'), ('\u{b5}', '\u{b5}'), ('\u{ba}', '\u{ba}'),
+    ('\u{df}', '\u{f6}'), ('\u{f8}', '\u{ff}'), ('\u{101}', '\u{101}'), ('\u{103}', '\u{103}'),
+    ('\u{105}', '\u{105}'), ('\u{107}', '\u{107}'), ('\u{109}', '\u{109}'), ('\u{10b}', '\u{10b}'),
+    ('\u{10d}', '\u{10d}'), ('\u{10f}', '\u{10f}'), ('\u{111}', '\u{111}'), ('\u{113}', '\u{113}'),
+    ('\u{115}', '\u{115}'), ('\u{117}', '\u{117}'), ('\u{119}', '\u{119}'), ('\u{11b}', '\u{11b}'),
+    ('\u{11d}', '\u{11d}'), ('\u{11f}', '\u{11f}'), ('\u{121}', '\u{121}'), ('\u{123}', '\u{123}'),
+    ('\u{125}', '\u{125}'), ('\u{127}', '\u{127}'), ('\u{129}', '\u{129}'), ('\u{12b}', '\u{12b}'),
+    ('\u{12d}', '\u{12d}'), ('\u{12f}', '\u{12f}'), ('\u{131}', '\u{131}'), ('\u{133}', '\u{133}'),
+    ('\u{135}', '\u{135}'), ('\u{137}', '\u{138}'), ('\u{13a}', '\u{13a}'), ('\u{13c}', '\u{13c}'),
+    ('\u{13e}', '\u{13e}'), ('\u{140}', '\u{140}'), ('\u{142}', '\u{142}'), ('\u{144}', '\u{144}'),
+    ('\u{146}', '\u{146}'), ('\u{148}', '\u{149}'), ('\u{14b}', '\u{14b}'), ('\u{14d}', '\u{14d}'),
+    ('\u{14f}', '\u{14f}'), ('\u{151}', '\u{151}'), ('\u{153}', '\u{153}'), ('\u{155}', '\u{155}'),
+    ('\u{157}', '\u{157}'), ('\u{159}', '\u{159}'), ('\u{15b}', '\u{15b}'), ('\u{15d}', '\u{15d}'),
+    ('\u{15f}', '\u{15f}'), ('\u{161}', '\u{161}'), ('\u{163}', '\u{163}'), ('\u{165}', '\u{165}'),
+    ('\u{167}', '\u{167}'), ('\u{169}', '\u{169}'), ('\u{16b}', '\u{16b}'), ('\u{16d}', '\u{16d}'),
+    ('\u{16f}', '\u{16f}'), ('\u{171}', '\u{171}'), ('\u{173}', '\u{173}'), ('\u{175}', '\u{175}'),
+    ('\u{177}', '\u{177}'), ('\u{17a}', '\u{17a}'), ('\u{17c}', '\u{17c}'), ('\u{17e}', '\u{180}'),
+    ('\u{183}', '\u{183}'), ('\u{185}', '\u{185}'), ('\u{188}', '\u{188}'), ('\u{18c}', '\u{18d}'),
+    ('\u{192}', '\u{192}'), ('\u{195}', '\u{195}'), ('\u{199}', '\u{19b}'), ('\u{19e}', '\u{19e}'),
+    ('\u{1a1}', '\u{1a1}'), ('\u{1a3}', '\u{1a3}'), ('\u{1a5}', '\u{1a5}'), ('\u{1a8}', '\u{1a8}'),
+    ('\u{1aa}', '\u{1ab}'), ('\u{1ad}', '\u{1ad}'), ('\u{1b0}', '\u{1b0}'), ('\u{1b4}', '\u{1b4}'),
+    ('\u{1b6}', '\u{1b6}'), ('\u{1b9}', '\u{1ba}'), ('\u{1bd}', '\u{1bf}'), ('\u{1c6}', '\u{1c6}'),
+    ('\u{1c9}', '\u{1c9}'), ('\u{1cc}', '\u{1cc}'), ('\u{1ce}', '\u{1ce}'), ('\u{1d0}', '\u{1d0}'),
+    ('\u{1d2}', '\u{1d2}'), ('\u{1d4}', '\u{1d4}'), ('\u{1d6}', '\u{1d6}'), ('\u{1d8}', '\u{1d8}'),
+    ('\u{1da}', '\u{1da}'), ('\u{1dc}', '\u{1dd}'), ('\u{1df}', '\u{1df}'), ('\u{1e1}', '\u{1e1}'),
+    ('\u{1e3}', '\u{1e3}'), ('\u{1e5}', '\u{1e5}'), ('\u{1e7}', '\u{1e7}'), ('\u{1e9}', '\u{1e9}'),
+    ('\u{1eb}', '\u{1eb}'), ('\u{1ed}', '\u{1ed}'), ('\u{1ef}', '\u{1f0}'), ('\u{1f3}', '\u{1f3}'),
+    ('\u{1f5}', '\u{1f5}'), ('\u{1f9}', '\u{1f9}'), ('\u{1fb}', '\u{1fb}'), ('\u{1fd}', '\u{1fd}'),
+    ('\u{1ff}', '\u{1ff}'), ('\u{201}', '\u{201}'), ('\u{203}', '\u{203}'), ('\u{205}', '\u{205}'),
+    ('\u{207}', '\u{207}'), ('\u{209}', '\u{209}'), ('\u{20b}', '\u{20b}'), ('\u{20d}', '\u{20d}'),
+    ('\u{20f}', '\u{20f}'), ('\u{211}', '\u{211}'), ('\u{213}', '\u{213}'), ('\u{215}', '\u{215}'),
+    ('\u{217}', '\u{217}'), ('\u{219}', '\u{219}'), ('\u{21b}', '\u{21b}'), ('\u{21d}', '\u{21d}'),
+    ('\u{21f}', '\u{21f}'), ('\u{221}', '\u{221}'), ('\u{223}', '\u{223}'), ('\u{225}', '\u{225}'),
+    ('\u{227}', '\u{227}'), ('\u{229}', '\u{229}'), ('\u{22b}', '\u{22b}'), ('\u{22d}', '\u{22d}'),
+    ('\u{22f}', '\u{22f}'), ('\u{231}', '\u{231}'), ('\u{233}', '\u{239}'), ('\u{23c}', '\u{23c}'),
+    ('\u{23f}', '\u{240}'), ('\u{242}', '\u{242}'), ('\u{247}', '\u{247}'), ('\u{249}', '\u{249}'),
+    ('\u{24b}', '\u{24b}'), ('\u{24d}', '\u{24d}'), ('\u{24f}', '\u{293}'), ('\u{295}', '\u{2b8}'),
+    ('\u{2c0}', '\u{2c1}'), ('\u{2e0}', '\u{2e4}'), ('\u{345}', '\u{345}'), ('\u{371}', '\u{371}'),
+    ('\u{373}', '\u{373}'), ('\u{377}', '\u{377}'), ('\u{37a}', '\u{37d}'), ('\u{390}', '\u{390}'),
+    ('\u{3ac}', '\u{3ce}'), ('\u{3d0}', '\u{3d1}'), ('\u{3d5}', '\u{3d7}'), ('\u{3d9}', '\u{3d9}'),
+    ('\u{3db}', '\u{3db}'), ('\u{3dd}', '\u{3dd}'), ('\u{3df}', '\u{3df}'), ('\u{3e1}', '\u{3e1}'),
+    ('\u{3e3}', '\u{3e3}'), ('\u{3e5}', '\u{3e5}'), ('\u{3e7}', '\u{3e7}'), ('\u{3e9}', '\u{3e9}'),
+    ('\u{3eb}', '\u{3eb}'), ('\u{3ed}', '\u{3ed}'), ('\u{3ef}', '\u{3f3}'), ('\u{3f5}', '\u{3f5}'),
+    ('\u{3f8}', '\u{3f8}'), ('\u{3fb}', '\u{3fc}'), ('\u{430}', '\u{45f}'), ('\u{461}', '\u{461}'),
+    ('\u{463}', '\u{463}'), ('\u{465}', '\u{465}'), ('\u{467}', '\u{467}'), ('\u{469}', '\u{469}'),
+    ('\u{46b}', '\u{46b}'), ('\u{46d}', '\u{46d}'), ('\u{46f}', '\u{46f}'), ('\u{471}', '\u{471}'),
+    ('\u{473}', '\u{473}'), ('\u{475}', '\u{475}'), ('\u{477}', '\u{477}'), ('\u{479}', '\u{479}'),
+    ('\u{47b}', '\u{47b}'), ('\u{47d}', '\u{47d}'), ('\u{47f}', '\u{47f}'), ('\u{481}', '\u{481}'),
+    ('\u{48b}', '\u{48b}'), ('\u{48d}', '\u{48d}'), ('\u{48f}', '\u{48f}'), ('\u{491}', '\u{491}'),
+    ('\u{493}', '\u{493}'), ('\u{495}', '\u{495}'), ('\u{497}', '\u{497}'), ('\u{499}', '\u{499}'),
+    ('\u{49b}', '\u{49b}'), ('\u{49d}', '\u{49d}'), ('\u{49f}', '\u{49f}'), ('\u{4a1}', '\u{4a1}'),
+    ('\u{4a3}', '\u{4a3}'), ('\u{4a5}', '\u{4a5}'), ('\u{4a7}', '\u{4a7}'), ('\u{4a9}', '\u{4a9}'),
+    ('\u{4ab}', '\u{4ab}'), ('\u{4ad}', '\u{4ad}'), ('\u{4af}', '\u{4af}'), ('\u{4b1}', '\u{4b1}'),
+    ('\u{4b3}', '\u{4b3}'), ('\u{4b5}', '\u{4b5}'), ('\u{4b7}', '\u{4b7}'), ('\u{4b9}', '\u{4b9}'),
+    ('\u{4bb}', '\u{4bb}'), ('\u{4bd}', '\u{4bd}'), ('\u{4bf}', '\u{4bf}'), ('\u{4c2}', '\u{4c2}'),
+    ('\u{4c4}', '\u{4c4}'), ('\u{4c6}', '\u{4c6}'), ('\u{4c8}', '\u{4c8}'), ('\u{4ca}', '\u{4ca}'),
+    ('\u{4cc}', '\u{4cc}'), ('\u{4ce}', '\u{4cf}'), ('\u{4d1}', '\u{4d1}'), ('\u{4d3}', '\u{4d3}'),
+    ('\u{4d5}', '\u{4d5}'), ('\u{4d7}', '\u{4d7}'), ('\u{4d9}', '\u{4d9}'), ('\u{4db}', '\u{4db}'),
+    ('\u{4dd}', '\u{4dd}'), ('\u{4df}', '\u{4df}'), ('\u{4e1}', '\u{4e1}'), ('\u{4e3}', '\u{4e3}'),
+    ('\u{4e5}', '\u{4e5}'), ('\u{4e7}', '\u{4e7}'), ('\u{4e9}', '\u{4e9}'), ('\u{4eb}', '\u{4eb}'),
+    ('\u{4ed}', '\u{4ed}'), ('\u{4ef}', '\u{4ef}'), ('\u{4f1}', '\u{4f1}'), ('\u{4f3}', '\u{4f3}'),
+    ('\u{4f5}', '\u{4f5}'), ('\u{4f7}', '\u{4f7}'), ('\u{4f9}', '\u{4f9}'), ('\u{4fb}', '\u{4fb}'),
+    ('\u{4fd}', '\u{4fd}'), ('\u{4ff}', '\u{4ff}'), ('\u{501}', '\u{501}'), ('\u{503}', '\u{503}'),
+    ('\u{505}', '\u{505}'), ('\u{507}', '\u{507}'), ('\u{509}', '\u{509}'), ('\u{50b}', '\u{50b}'),
+    ('\u{50d}', '\u{50d}'), ('\u{50f}', '\u{50f}'), ('\u{511}', '\u{511}'), ('\u{513}', '\u{513}'),
+    ('\u{515}', '\u{515}'), ('\u{517}', '\u{517}'), ('\u{519}', '\u{519}'), ('\u{51b}', '\u{51b}'),
+    ('\u{51d}', '\u{51d}'), ('\u{51f}', '\u{51f}'), ('\u{521}', '\u{521}'), ('\u{523}', '\u{523}'),
+    ('\u{525}', '\u{525}'), ('\u{527}', '\u{527}'), ('\u{529}', '\u{529}'), ('\u{52b}', '\u{52b}'),
+    ('\u{52d}', '\u{52d}'), ('\u{52f}', '\u{52f}'), ('\u{560}', '\u{588}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fd}', '\u{10ff}'), ('\u{13f8}', '\u{13fd}'), ('\u{1c80}', '\u{1c88}'), ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1e01}', '\u{1e01}'), ('\u{1e03}', '\u{1e03}'), ('\u{1e05}', '\u{1e05}'), ('\u{1e07}', '\u{1e07}'),
+    ('\u{1e09}', '\u{1e09}'), ('\u{1e0b}', '\u{1e0b}'), ('\u{1e0d}', '\u{1e0d}'), ('\u{1e0f}', '\u{1e0f}'),
+    ('\u{1e11}', '\u{1e11}'), ('\u{1e13}', '\u{1e13}'), ('\u{1e15}', '\u{1e15}'), ('\u{1e17}', '\u{1e17}'),
+    ('\u{1e19}', '\u{1e19}'), ('\u{1e1b}', '\u{1e1b}'), ('\u{1e1d}', '\u{1e1d}'), ('\u{1e1f}', '\u{1e1f}'),
+    ('\u{1e21}', '\u{1e21}'), ('\u{1e23}', '\u{1e23}'), ('\u{1e25}', '\u{1e25}'), ('\u{1e27}', '\u{1e27}'),
+    ('\u{1e29}', '\u{1e29}'), ('\u{1e2b}', '\u{1e2b}'), ('\u{1e2d}', '\u{1e2d}'), ('\u{1e2f}', '\u{1e2f}'),
+    ('\u{1e31}', '\u{1e31}'), ('\u{1e33}', '\u{1e33}'), ('\u{1e35}', '\u{1e35}'), ('\u{1e37}', '\u{1e37}'),
+    ('\u{1e39}', '\u{1e39}'), ('\u{1e3b}', '\u{1e3b}'), ('\u{1e3d}', '\u{1e3d}'), ('\u{1e3f}', '\u{1e3f}'),
+    ('\u{1e41}', '\u{1e41}'), ('\u{1e43}', '\u{1e43}'), ('\u{1e45}', '\u{1e45}'), ('\u{1e47}', '\u{1e47}'),
+    ('\u{1e49}', '\u{1e49}'), ('\u{1e4b}', '\u{1e4b}'), ('\u{1e4d}', '\u{1e4d}'), ('\u{1e4f}', '\u{1e4f}'),
+    ('\u{1e51}', '\u{1e51}'), ('\u{1e53}', '\u{1e53}'), ('\u{1e55}', '\u{1e55}'), ('\u{1e57}', '\u{1e57}'),
+    ('\u{1e59}', '\u{1e59}'), ('\u{1e5b}', '\u{1e5b}'), ('\u{1e5d}', '\u{1e5d}'), ('\u{1e5f}', '\u{1e5f}'),
+    ('\u{1e61}', '\u{1e61}'), ('\u{1e63}', '\u{1e63}'), ('\u{1e65}', '\u{1e65}'), ('\u{1e67}', '\u{1e67}'),
+    ('\u{1e69}', '\u{1e69}'), ('\u{1e6b}', '\u{1e6b}'), ('\u{1e6d}', '\u{1e6d}'), ('\u{1e6f}', '\u{1e6f}'),
+    ('\u{1e71}', '\u{1e71}'), ('\u{1e73}', '\u{1e73}'), ('\u{1e75}', '\u{1e75}'), ('\u{1e77}', '\u{1e77}'),
+    ('\u{1e79}', '\u{1e79}'), ('\u{1e7b}', '\u{1e7b}'), ('\u{1e7d}', '\u{1e7d}'), ('\u{1e7f}', '\u{1e7f}'),
+    ('\u{1e81}', '\u{1e81}'), ('\u{1e83}', '\u{1e83}'), ('\u{1e85}', '\u{1e85}'), ('\u{1e87}', '\u{1e87}'),
+    ('\u{1e89}', '\u{1e89}'), ('\u{1e8b}', '\u{1e8b}'), ('\u{1e8d}', '\u{1e8d}'), ('\u{1e8f}', '\u{1e8f}'),
+    ('\u{1e91}', '\u{1e91}'), ('\u{1e93}', '\u{1e93}'), ('\u{1e95}', '\u{1e9d}'), ('\u{1e9f}', '\u{1e9f}'),
+    ('\u{1ea1}', '\u{1ea1}'), ('\u{1ea3}', '\u{1ea3}'), ('\u{1ea5}', '\u{1ea5}'), ('\u{1ea7}', '\u{1ea7}'),
+    ('\u{1ea9}', '\u{1ea9}'), ('\u{1eab}', '\u{1eab}'), ('\u{1ead}', '\u{1ead}'), ('\u{1eaf}', '\u{1eaf}'),
+    ('\u{1eb1}', '\u{1eb1}'), ('\u{1eb3}', '\u{1eb3}'), ('\u{1eb5}', '\u{1eb5}'), ('\u{1eb7}', '\u{1eb7}'),
+    ('\u{1eb9}', '\u{1eb9}'), ('\u{1ebb}', '\u{1ebb}'), ('\u{1ebd}', '\u{1ebd}'), ('\u{1ebf}', '\u{1ebf}'),
+    ('\u{1ec1}', '\u{1ec1}'), ('\u{1ec3}', '\u{1ec3}'), ('\u{1ec5}', '\u{1ec5}'), ('\u{1ec7}', '\u{1ec7}'),
+    ('\u{1ec9}', '\u{1ec9}'), ('\u{1ecb}', '\u{1ecb}'), ('\u{1ecd}', '\u{1ecd}'), ('\u{1ecf}', '\u{1ecf}'),
+    ('\u{1ed1}', '\u{1ed1}'), ('\u{1ed3}', '\u{1ed3}'), ('\u{1ed5}', '\u{1ed5}'), ('\u{1ed7}', '\u{1ed7}'),
+    ('\u{1ed9}', '\u{1ed9}'), ('\u{1edb}', '\u{1edb}'), ('\u{1edd}', '\u{1edd}'), ('\u{1edf}', '\u{1edf}'),
+    ('\u{1ee1}', '\u{1ee1}'), ('\u{1ee3}', '\u{1ee3}'), ('\u{1ee5}', '\u{1ee5}'), ('\u{1ee7}', '\u{1ee7}'),
+    ('\u{1ee9}', '\u{1ee9}'), ('\u{1eeb}', '\u{1eeb}'), ('\u{1eed}', '\u{1eed}'), ('\u{1eef}', '\u{1eef}'),
+    ('\u{1ef1}', '\u{1ef1}'), ('\u{1ef3}', '\u{1ef3}'), ('\u{1ef5}', '\u{1ef5}'), ('\u{1ef7}', '\u{1ef7}'),
+    ('\u{1ef9}', '\u{1ef9}'), ('\u{1efb}', '\u{1efb}'), ('\u{1efd}', '\u{1efd}'), ('\u{1eff}', '\u{1f07}'),
+    ('\u{1f10}', '\u{1f15}'), ('\u{1f20}', '\u{1f27}'), ('\u{1f30}', '\u{1f37}'), ('\u{1f40}', '\u{1f45}'),
+    ('\u{1f50}', '\u{1f57}'), ('\u{1f60}', '\u{1f67}'), ('\u{1f70}', '\u{1f7d}'), ('\u{1f80}', '\u{1f87}'),
+    ('\u{1f90}', '\u{1f97}'), ('\u{1fa0}', '\u{1fa7}'), ('\u{1fb0}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fb7}'),
+    ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fc7}'), ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fd7}'), ('\u{1fe0}', '\u{1fe7}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ff7}'),
+    ('\u{2071}', '\u{2071}'), ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{210a}', '\u{210a}'),
+    ('\u{210e}', '\u{210f}'), ('\u{2113}', '\u{2113}'), ('\u{212f}', '\u{212f}'), ('\u{2134}', '\u{2134}'),
+    ('\u{2139}', '\u{2139}'), ('\u{213c}', '\u{213d}'), ('\u{2146}', '\u{2149}'), ('\u{214e}', '\u{214e}'),
+    ('\u{2170}', '\u{217f}'), ('\u{2184}', '\u{2184}'), ('\u{24d0}', '\u{24e9}'), ('\u{2c30}', '\u{2c5f}'),
+    ('\u{2c61}', '\u{2c61}'), ('\u{2c65}', '\u{2c66}'), ('\u{2c68}', '\u{2c68}'), ('\u{2c6a}', '\u{2c6a}'),
+    ('\u{2c6c}', '\u{2c6c}'), ('\u{2c71}', '\u{2c71}'), ('\u{2c73}', '\u{2c74}'), ('\u{2c76}', '\u{2c7d}'),
+    ('\u{2c81}', '\u{2c81}'), ('\u{2c83}', '\u{2c83}'), ('\u{2c85}', '\u{2c85}'), ('\u{2c87}', '\u{2c87}'),
+    ('\u{2c89}', '\u{2c89}'), ('\u{2c8b}', '\u{2c8b}'), ('\u{2c8d}', '\u{2c8d}'), ('\u{2c8f}', '\u{2c8f}'),
+    ('\u{2c91}', '\u{2c91}'), ('\u{2c93}', '\u{2c93}'), ('\u{2c95}', '\u{2c95}'), ('\u{2c97}', '\u{2c97}'),
+    ('\u{2c99}', '\u{2c99}'), ('\u{2c9b}', '\u{2c9b}'), ('\u{2c9d}', '\u{2c9d}'), ('\u{2c9f}', '\u{2c9f}'),
+    ('\u{2ca1}', '\u{2ca1}'), ('\u{2ca3}', '\u{2ca3}'), ('\u{2ca5}', '\u{2ca5}'), ('\u{2ca7}', '\u{2ca7}'),
+    ('\u{2ca9}', '\u{2ca9}'), ('\u{2cab}', '\u{2cab}'), ('\u{2cad}', '\u{2cad}'), ('\u{2caf}', '\u{2caf}'),
+    ('\u{2cb1}', '\u{2cb1}'), ('\u{2cb3}', '\u{2cb3}'), ('\u{2cb5}', '\u{2cb5}'), ('\u{2cb7}', '\u{2cb7}'),
+    ('\u{2cb9}', '\u{2cb9}'), ('\u{2cbb}', '\u{2cbb}'), ('\u{2cbd}', '\u{2cbd}'), ('\u{2cbf}', '\u{2cbf}'),
+    ('\u{2cc1}', '\u{2cc1}'), ('\u{2cc3}', '\u{2cc3}'), ('\u{2cc5}', '\u{2cc5}'), ('\u{2cc7}', '\u{2cc7}'),
+    ('\u{2cc9}', '\u{2cc9}'), ('\u{2ccb}', '\u{2ccb}'), ('\u{2ccd}', '\u{2ccd}'), ('\u{2ccf}', '\u{2ccf}'),
+    ('\u{2cd1}', '\u{2cd1}'), ('\u{2cd3}', '\u{2cd3}'), ('\u{2cd5}', '\u{2cd5}'), ('\u{2cd7}', '\u{2cd7}'),
+    ('\u{2cd9}', '\u{2cd9}'), ('\u{2cdb}', '\u{2cdb}'), ('\u{2cdd}', '\u{2cdd}'), ('\u{2cdf}', '\u{2cdf}'),
+    ('\u{2ce1}', '\u{2ce1}'), ('\u{2ce3}', '\u{2ce4}'), ('\u{2cec}', '\u{2cec}'), ('\u{2cee}', '\u{2cee}'),
+    ('\u{2cf3}', '\u{2cf3}'), ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{a641}', '\u{a641}'), ('\u{a643}', '\u{a643}'), ('\u{a645}', '\u{a645}'), ('\u{a647}', '\u{a647}'),
+    ('\u{a649}', '\u{a649}'), ('\u{a64b}', '\u{a64b}'), ('\u{a64d}', '\u{a64d}'), ('\u{a64f}', '\u{a64f}'),
+    ('\u{a651}', '\u{a651}'), ('\u{a653}', '\u{a653}'), ('\u{a655}', '\u{a655}'), ('\u{a657}', '\u{a657}'),
+    ('\u{a659}', '\u{a659}'), ('\u{a65b}', '\u{a65b}'), ('\u{a65d}', '\u{a65d}'), ('\u{a65f}', '\u{a65f}'),
+    ('\u{a661}', '\u{a661}'), ('\u{a663}', '\u{a663}'), ('\u{a665}', '\u{a665}'), ('\u{a667}', '\u{a667}'),
+    ('\u{a669}', '\u{a669}'), ('\u{a66b}', '\u{a66b}'), ('\u{a66d}', '\u{a66d}'), ('\u{a681}', '\u{a681}'),
+    ('\u{a683}', '\u{a683}'), ('\u{a685}', '\u{a685}'), ('\u{a687}', '\u{a687}'), ('\u{a689}', '\u{a689}'),
+    ('\u{a68b}', '\u{a68b}'), ('\u{a68d}', '\u{a68d}'), ('\u{a68f}', '\u{a68f}'), ('\u{a691}', '\u{a691}'),
+    ('\u{a693}', '\u{a693}'), ('\u{a695}', '\u{a695}'), ('\u{a697}', '\u{a697}'), ('\u{a699}', '\u{a699}'),
+    ('\u{a69b}', '\u{a69d}'), ('\u{a723}', '\u{a723}'), ('\u{a725}', '\u{a725}'), ('\u{a727}', '\u{a727}'),
+    ('\u{a729}', '\u{a729}'), ('\u{a72b}', '\u{a72b}'), ('\u{a72d}', '\u{a72d}'), ('\u{a72f}', '\u{a731}'),
+    ('\u{a733}', '\u{a733}'), ('\u{a735}', '\u{a735}'), ('\u{a737}', '\u{a737}'), ('\u{a739}', '\u{a739}'),
+    ('\u{a73b}', '\u{a73b}'), ('\u{a73d}', '\u{a73d}'), ('\u{a73f}', '\u{a73f}'), ('\u{a741}', '\u{a741}'),
+    ('\u{a743}', '\u{a743}'), ('\u{a745}', '\u{a745}'), ('\u{a747}', '\u{a747}'), ('\u{a749}', '\u{a749}'),
+    ('\u{a74b}', '\u{a74b}'), ('\u{a74d}', '\u{a74d}'), ('\u{a74f}', '\u{a74f}'), ('\u{a751}', '\u{a751}'),
+    ('\u{a753}', '\u{a753}'), ('\u{a755}', '\u{a755}'), ('\u{a757}', '\u{a757}'), ('\u{a759}', '\u{a759}'),
+    ('\u{a75b}', '\u{a75b}'), ('\u{a75d}', '\u{a75d}'), ('\u{a75f}', '\u{a75f}'), ('\u{a761}', '\u{a761}'),
+    ('\u{a763}', '\u{a763}'), ('\u{a765}', '\u{a765}'), ('\u{a767}', '\u{a767}'), ('\u{a769}', '\u{a769}'),
+    ('\u{a76b}', '\u{a76b}'), ('\u{a76d}', '\u{a76d}'), ('\u{a76f}', '\u{a778}'), ('\u{a77a}', '\u{a77a}'),
+    ('\u{a77c}', '\u{a77c}'), ('\u{a77f}', '\u{a77f}'), ('\u{a781}', '\u{a781}'), ('\u{a783}', '\u{a783}'),
+    ('\u{a785}', '\u{a785}'), ('\u{a787}', '\u{a787}'), ('\u{a78c}', '\u{a78c}'), ('\u{a78e}', '\u{a78e}'),
+    ('\u{a791}', '\u{a791}'), ('\u{a793}', '\u{a795}'), ('\u{a797}', '\u{a797}'), ('\u{a799}', '\u{a799}'),
+    ('\u{a79b}', '\u{a79b}'), ('\u{a79d}', '\u{a79d}'), ('\u{a79f}', '\u{a79f}'), ('\u{a7a1}', '\u{a7a1}'),
+    ('\u{a7a3}', '\u{a7a3}'), ('\u{a7a5}', '\u{a7a5}'), ('\u{a7a7}', '\u{a7a7}'), ('\u{a7a9}', '\u{a7a9}'),
+    ('\u{a7af}', '\u{a7af}'), ('\u{a7b5}', '\u{a7b5}'), ('\u{a7b7}', '\u{a7b7}'), ('\u{a7b9}', '\u{a7b9}'),
+    ('\u{a7bb}', '\u{a7bb}'), ('\u{a7bd}', '\u{a7bd}'), ('\u{a7bf}', '\u{a7bf}'), ('\u{a7c1}', '\u{a7c1}'),
+    ('\u{a7c3}', '\u{a7c3}'), ('\u{a7c8}', '\u{a7c8}'), ('\u{a7ca}', '\u{a7ca}'), ('\u{a7d1}', '\u{a7d1}'),
+    ('\u{a7d3}', '\u{a7d3}'), ('\u{a7d5}', '\u{a7d5}'), ('\u{a7d7}', '\u{a7d7}'), ('\u{a7d9}', '\u{a7d9}'),
+    ('\u{a7f6}', '\u{a7f6}'), ('\u{a7f8}', '\u{a7fa}'), ('\u{ab30}', '\u{ab5a}'), ('\u{ab5c}', '\u{ab68}'),
+    ('\u{ab70}', '\u{abbf}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{ff41}', '\u{ff5a}'),
+    ('\u{10428}', '\u{1044f}'), ('\u{104d8}', '\u{104fb}'), ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'), ('\u{10780}', '\u{10780}'), ('\u{10783}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{118c0}', '\u{118df}'),
+    ('\u{16e60}', '\u{16e7f}'), ('\u{1d41a}', '\u{1d433}'), ('\u{1d44e}', '\u{1d454}'), ('\u{1d456}', '\u{1d467}'),
+    ('\u{1d482}', '\u{1d49b}'), ('\u{1d4b6}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d4cf}'), ('\u{1d4ea}', '\u{1d503}'), ('\u{1d51e}', '\u{1d537}'), ('\u{1d552}', '\u{1d56b}'),
+    ('\u{1d586}', '\u{1d59f}'), ('\u{1d5ba}', '\u{1d5d3}'), ('\u{1d5ee}', '\u{1d607}'), ('\u{1d622}', '\u{1d63b}'),
+    ('\u{1d656}', '\u{1d66f}'), ('\u{1d68a}', '\u{1d6a5}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6e1}'),
+    ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d71b}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d755}'),
+    ('\u{1d770}', '\u{1d788}'), ('\u{1d78a}', '\u{1d78f}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7c9}'),
+    ('\u{1d7cb}', '\u{1d7cb}'), ('\u{1df00}', '\u{1df09}'), ('\u{1df0b}', '\u{1df1e}'), ('\u{1e922}', '\u{1e943}'),
+];
+
+pub const PUNCTUATION: &[(char, char)] = &[
+    ('\u{21}', '\u{23}'), ('\u{25}', '\u{2a}'), ('\u{2c}', '\u{2f}'), ('\u{3a}', '\u{3b}'),
+    ('\u{3f}', '\u{40}'), ('\u{5b}', '\u{5d}'), ('\u{5f}', '\u{5f}'), ('\u{7b}', '\u{7b}'),
+    ('\u{7d}', '\u{7d}'), ('\u{a1}', '\u{a1}'), ('\u{a7}', '\u{a7}'), ('\u{ab}', '\u{ab}'),
+    ('\u{b6}', '\u{b7}'), ('\u{bb}', '\u{bb}'), ('\u{bf}', '\u{bf}'), ('\u{37e}', '\u{37e}'),
+    ('\u{387}', '\u{387}'), ('\u{55a}', '\u{55f}'), ('\u{589}', '\u{58a}'), ('\u{5be}', '\u{5be}'),
+    ('\u{5c0}', '\u{5c0}'), ('\u{5c3}', '\u{5c3}'), ('\u{5c6}', '\u{5c6}'), ('\u{5f3}', '\u{5f4}'),
+    ('\u{609}', '\u{60a}'), ('\u{60c}', '\u{60d}'), ('\u{61b}', '\u{61b}'), ('\u{61d}', '\u{61f}'),
+    ('\u{66a}', '\u{66d}'), ('\u{6d4}', '\u{6d4}'), ('\u{700}', '\u{70d}'), ('\u{7f7}', '\u{7f9}'),
+    ('\u{830}', '\u{83e}'), ('\u{85e}', '\u{85e}'), ('\u{964}', '\u{965}'), ('\u{970}', '\u{970}'),
+    ('\u{9fd}', '\u{9fd}'), ('\u{a76}', '\u{a76}'), ('\u{af0}', '\u{af0}'), ('\u{c77}', '\u{c77}'),
+    ('\u{c84}', '\u{c84}'), ('\u{df4}', '\u{df4}'), ('\u{e4f}', '\u{e4f}'), ('\u{e5a}', '\u{e5b}'),
+    ('\u{f04}', '\u{f12}'), ('\u{f14}', '\u{f14}'), ('\u{f3a}', '\u{f3d}'), ('\u{f85}', '\u{f85}'),
+    ('\u{fd0}', '\u{fd4}'), ('\u{fd9}', '\u{fda}'), ('\u{104a}', '\u{104f}'), ('\u{10fb}', '\u{10fb}'),
+    ('\u{1360}', '\u{1368}'), ('\u{1400}', '\u{1400}'), ('\u{166e}', '\u{166e}'), ('\u{169b}', '\u{169c}'),
+    ('\u{16eb}', '\u{16ed}'), ('\u{1735}', '\u{1736}'), ('\u{17d4}', '\u{17d6}'), ('\u{17d8}', '\u{17da}'),
+    ('\u{1800}', '\u{180a}'), ('\u{1944}', '\u{1945}'), ('\u{1a1e}', '\u{1a1f}'), ('\u{1aa0}', '\u{1aa6}'),
+    ('\u{1aa8}', '\u{1aad}'), ('\u{1b5a}', '\u{1b60}'), ('\u{1b7d}', '\u{1b7e}'), ('\u{1bfc}', '\u{1bff}'),
+    ('\u{1c3b}', '\u{1c3f}'), ('\u{1c7e}', '\u{1c7f}'), ('\u{1cc0}', '\u{1cc7}'), ('\u{1cd3}', '\u{1cd3}'),
+    ('\u{2010}', '\u{2027}'), ('\u{2030}', '\u{2043}'), ('\u{2045}', '\u{2051}'), ('\u{2053}', '\u{205e}'),
+    ('\u{207d}', '\u{207e}'), ('\u{208d}', '\u{208e}'), ('\u{2308}', '\u{230b}'), ('\u{2329}', '\u{232a}'),
+    ('\u{2768}', '\u{2775}'), ('\u{27c5}', '\u{27c6}'), ('\u{27e6}', '\u{27ef}'), ('\u{2983}', '\u{2998}'),
+    ('\u{29d8}', '\u{29db}'), ('\u{29fc}', '\u{29fd}'), ('\u{2cf9}', '\u{2cfc}'), ('\u{2cfe}', '\u{2cff}'),
+    ('\u{2d70}', '\u{2d70}'), ('\u{2e00}', '\u{2e2e}'), ('\u{2e30}', '\u{2e4f}'), ('\u{2e52}', '\u{2e5d}'),
+    ('\u{3001}', '\u{3003}'), ('\u{3008}', '\u{3011}'), ('\u{3014}', '\u{301f}'), ('\u{3030}', '\u{3030}'),
+    ('\u{303d}', '\u{303d}'), ('\u{30a0}', '\u{30a0}'), ('\u{30fb}', '\u{30fb}'), ('\u{a4fe}', '\u{a4ff}'),
+    ('\u{a60d}', '\u{a60f}'), ('\u{a673}', '\u{a673}'), ('\u{a67e}', '\u{a67e}'), ('\u{a6f2}', '\u{a6f7}'),
+    ('\u{a874}', '\u{a877}'), ('\u{a8ce}', '\u{a8cf}'), ('\u{a8f8}', '\u{a8fa}'), ('\u{a8fc}', '\u{a8fc}'),
+    ('\u{a92e}', '\u{a92f}'), ('\u{a95f}', '\u{a95f}'), ('\u{a9c1}', '\u{a9cd}'), ('\u{a9de}', '\u{a9df}'),
+    ('\u{aa5c}', '\u{aa5f}'), ('\u{aade}', '\u{aadf}'), ('\u{aaf0}', '\u{aaf1}'), ('\u{abeb}', '\u{abeb}'),
+    ('\u{fd3e}', '\u{fd3f}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'), ('\u{fe54}', '\u{fe61}'),
+    ('\u{fe63}', '\u{fe63}'), ('\u{fe68}', '\u{fe68}'), ('\u{fe6a}', '\u{fe6b}'), ('\u{ff01}', '\u{ff03}'),
+    ('\u{ff05}', '\u{ff0a}'), ('\u{ff0c}', '\u{ff0f}'), ('\u{ff1a}', '\u{ff1b}'), ('\u{ff1f}', '\u{ff20}'),
+    ('\u{ff3b}', '\u{ff3d}'), ('\u{ff3f}', '\u{ff3f}'), ('\u{ff5b}', '\u{ff5b}'), ('\u{ff5d}', '\u{ff5d}'),
+    ('\u{ff5f}', '\u{ff65}'), ('\u{10100}', '\u{10102}'), ('\u{1039f}', '\u{1039f}'), ('\u{103d0}', '\u{103d0}'),
+    ('\u{1056f}', '\u{1056f}'), ('\u{10857}', '\u{10857}'), ('\u{1091f}', '\u{1091f}'), ('\u{1093f}', '\u{1093f}'),
+    ('\u{10a50}', '\u{10a58}'), ('\u{10a7f}', '\u{10a7f}'), ('\u{10af0}', '\u{10af6}'), ('\u{10b39}', '\u{10b3f}'),
+    ('\u{10b99}', '\u{10b9c}'), ('\u{10ead}', '\u{10ead}'), ('\u{10f55}', '\u{10f59}'), ('\u{10f86}', '\u{10f89}'),
+    ('\u{11047}', '\u{1104d}'), ('\u{110bb}', '\u{110bc}'), ('\u{110be}', '\u{110c1}'), ('\u{11140}', '\u{11143}'),
+    ('\u{11174}', '\u{11175}'), ('\u{111c5}', '\u{111c8}'), ('\u{111cd}', '\u{111cd}'), ('\u{111db}', '\u{111db}'),
+    ('\u{111dd}', '\u{111df}'), ('\u{11238}', '\u{1123d}'), ('\u{112a9}', '\u{112a9}'), ('\u{1144b}', '\u{1144f}'),
+    ('\u{1145a}', '\u{1145b}'), ('\u{1145d}', '\u{1145d}'), ('\u{114c6}', '\u{114c6}'), ('\u{115c1}', '\u{115d7}'),
+    ('\u{11641}', '\u{11643}'), ('\u{11660}', '\u{1166c}'), ('\u{116b9}', '\u{116b9}'), ('\u{1173c}', '\u{1173e}'),
+    ('\u{1183b}', '\u{1183b}'), ('\u{11944}', '\u{11946}'), ('\u{119e2}', '\u{119e2}'), ('\u{11a3f}', '\u{11a46}'),
+    ('\u{11a9a}', '\u{11a9c}'), ('\u{11a9e}', '\u{11aa2}'), ('\u{11c41}', '\u{11c45}'), ('\u{11c70}', '\u{11c71}'),
+    ('\u{11ef7}', '\u{11ef8}'), ('\u{11fff}', '\u{11fff}'), ('\u{12470}', '\u{12474}'), ('\u{12ff1}', '\u{12ff2}'),
+    ('\u{16a6e}', '\u{16a6f}'), ('\u{16af5}', '\u{16af5}'), ('\u{16b37}', '\u{16b3b}'), ('\u{16b44}', '\u{16b44}'),
+    ('\u{16e97}', '\u{16e9a}'), ('\u{16fe2}', '\u{16fe2}'), ('\u{1bc9f}', '\u{1bc9f}'), ('\u{1da87}', '\u{1da8b}'),
+    ('\u{1e95e}', '\u{1e95f}'),
+];
+
+pub const HIRAGANA: &[(char, char)] = &[
+    ('\u{3041}', '\u{3096}'), ('\u{3099}', '\u{309f}'), ('\u{30fc}', '\u{30fc}'), ('\u{1b001}', '\u{1b11f}'),
+    ('\u{1b150}', '\u{1b152}'), ('\u{1f200}', '\u{1f200}'),
+];
+
+pub const KATAKANA: &[(char, char)] = &[
+    ('\u{3099}', '\u{309c}'), ('\u{30a1}', '\u{30fa}'), ('\u{30fc}', '\u{30ff}'), ('\u{31f0}', '\u{31ff}'),
+    ('\u{32d0}', '\u{32fe}'), ('\u{3300}', '\u{3357}'), ('\u{ff66}', '\u{ff9f}'), ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'), ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b000}'), ('\u{1b120}', '\u{1b122}'),
+    ('\u{1b164}', '\u{1b167}'),
+];
+
+pub const KANJI: &[(char, char)] = &[
+    ('\u{2e80}', '\u{2e99}'), ('\u{2e9b}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{3005}', '\u{3005}'),
+    ('\u{3007}', '\u{3007}'), ('\u{3021}', '\u{3029}'), ('\u{3038}', '\u{303b}'), ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{9fff}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'), ('\u{16fe2}', '\u{16fe3}'),
+    ('\u{16ff0}', '\u{16ff1}'), ('\u{20000}', '\u{2a6df}'), ('\u{2a700}', '\u{2b738}'), ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'), ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'),
+];
+
+pub const FULLWIDTH: &[(char, char)] = &[
+    ('\u{3000}', '\u{3000}'), ('\u{ff01}', '\u{ff60}'), ('\u{ffe0}', '\u{ffe6}'),
+];
+
+pub const HALFWIDTH_KATAKANA: &[(char, char)] = &[
+    ('\u{ff65}', '\u{ff9f}'),
+];
+
+pub const JIS_LEVEL1: &[(char, char)] = &[
+    ('\u{4e00}', '\u{4e01}'), ('\u{4e03}', '\u{4e03}'), ('\u{4e07}', '\u{4e0b}'), ('\u{4e0d}', '\u{4e0e}'),
+    ('\u{4e11}', '\u{4e11}'), ('\u{4e14}', '\u{4e14}'), ('\u{4e16}', '\u{4e16}'), ('\u{4e18}', '\u{4e19}'),
+    ('\u{4e1e}', '\u{4e1e}'), ('\u{4e21}', '\u{4e21}'), ('\u{4e26}', '\u{4e26}'), ('\u{4e2d}', '\u{4e2d}'),
+    ('\u{4e32}', '\u{4e32}'), ('\u{4e38}', '\u{4e39}'), ('\u{4e3b}', '\u{4e3b}'), ('\u{4e43}', '\u{4e43}'),
+    ('\u{4e45}', '\u{4e45}'), ('\u{4e4b}', '\u{4e4b}'), ('\u{4e4d}', '\u{4e4f}'), ('\u{4e57}', '\u{4e57}'),
+    ('\u{4e59}', '\u{4e59}'), ('\u{4e5d}', '\u{4e5f}'), ('\u{4e71}', '\u{4e71}'), ('\u{4e73}', '\u{4e73}'),
+    ('\u{4e7e}', '\u{4e7e}'), ('\u{4e80}', '\u{4e80}'), ('\u{4e86}', '\u{4e86}'), ('\u{4e88}', '\u{4e89}'),
+    ('\u{4e8b}', '\u{4e8c}'), ('\u{4e91}', '\u{4e92}'), ('\u{4e94}', '\u{4e95}'), ('\u{4e98}', '\u{4e99}'),
+    ('\u{4e9b}', '\u{4e9c}'), ('\u{4ea1}', '\u{4ea1}'), ('\u{4ea4}', '\u{4ea6}'), ('\u{4ea8}', '\u{4ea8}'),
+    ('\u{4eab}', '\u{4eae}'), ('\u{4eba}', '\u{4eba}'), ('\u{4ec0}', '\u{4ec1}'), ('\u{4ec7}', '\u{4ec7}'),
+    ('\u{4eca}', '\u{4ecb}'), ('\u{4ecf}', '\u{4ecf}'), ('\u{4ed4}', '\u{4ed6}'), ('\u{4ed8}', '\u{4ed9}'),
+    ('\u{4ee3}', '\u{4ee5}'), ('\u{4eee}', '\u{4eee}'), ('\u{4ef0}', '\u{4ef0}'), ('\u{4ef2}', '\u{4ef2}'),
+    ('\u{4ef6}', '\u{4ef6}'), ('\u{4efb}', '\u{4efb}'), ('\u{4f01}', '\u{4f01}'), ('\u{4f0a}', '\u{4f0a}'),
+    ('\u{4f0d}', '\u{4f11}'), ('\u{4f1a}', '\u{4f1a}'), ('\u{4f1d}', '\u{4f1d}'), ('\u{4f2f}', '\u{4f2f}'),
+    ('\u{4f34}', '\u{4f34}'), ('\u{4f36}', '\u{4f36}'), ('\u{4f38}', '\u{4f38}'), ('\u{4f3a}', '\u{4f3a}'),
+    ('\u{4f3c}', '\u{4f3d}'), ('\u{4f43}', '\u{4f43}'), ('\u{4f46}', '\u{4f46}'), ('\u{4f4d}', '\u{4f51}'),
+    ('\u{4f53}', '\u{4f53}'), ('\u{4f55}', '\u{4f55}'), ('\u{4f59}', '\u{4f59}'), ('\u{4f5c}', '\u{4f5c}'),
+    ('\u{4f73}', '\u{4f73}'), ('\u{4f75}', '\u{4f75}'), ('\u{4f7c}', '\u{4f7c}'), ('\u{4f7f}', '\u{4f7f}'),
+    ('\u{4f83}', '\u{4f83}'), ('\u{4f8b}', '\u{4f8b}'), ('\u{4f8d}', '\u{4f8d}'), ('\u{4f9b}', '\u{4f9b}'),
+    ('\u{4f9d}', '\u{4f9d}'), ('\u{4fa0}', '\u{4fa1}'), ('\u{4fad}', '\u{4faf}'), ('\u{4fb5}', '\u{4fb6}'),
+    ('\u{4fbf}', '\u{4fbf}'), ('\u{4fc2}', '\u{4fc4}'), ('\u{4fca}', '\u{4fca}'), ('\u{4fd7}', '\u{4fd7}'),
+    ('\u{4fdd}', '\u{4fdd}'), ('\u{4fe1}', '\u{4fe1}'), ('\u{4fe3}', '\u{4fe3}'), ('\u{4fee}', '\u{4fee}'),
+    ('\u{4ff3}', '\u{4ff3}'), ('\u{4ff5}', '\u{4ff5}'), ('\u{4ff8}', '\u{4ff8}'), ('\u{4ffa}', '\u{4ffa}'),
+    ('\u{5009}', '\u{5009}'), ('\u{500b}', '\u{500b}'), ('\u{500d}', '\u{500d}'), ('\u{5012}', '\u{5012}'),
+    ('\u{5016}', '\u{5016}'), ('\u{5019}', '\u{5019}'), ('\u{501f}', '\u{501f}'), ('\u{5023}', '\u{5024}'),
+    ('\u{5026}', '\u{5026}'), ('\u{502b}', '\u{502b}'), ('\u{502d}', '\u{502d}'), ('\u{5036}', '\u{5036}'),
+    ('\u{5039}', '\u{5039}'), ('\u{5049}', '\u{5049}'), ('\u{504f}', '\u{504f}'), ('\u{505c}', '\u{505c}'),
+    ('\u{5065}', '\u{5065}'), ('\u{5072}', '\u{5072}'), ('\u{5074}', '\u{5076}'), ('\u{507d}', '\u{507d}'),
+    ('\u{508d}', '\u{508d}'), ('\u{5091}', '\u{5091}'), ('\u{5098}', '\u{5099}'), ('\u{50ac}', '\u{50ad}'),
+    ('\u{50b5}', '\u{50b5}'), ('\u{50b7}', '\u{50b7}'), ('\u{50be}', '\u{50be}'), ('\u{50c5}', '\u{50c5}'),
+    ('\u{50cd}', '\u{50cd}'), ('\u{50cf}', '\u{50cf}'), ('\u{50d1}', '\u{50d1}'), ('\u{50d5}', '\u{50d5}'),
+    ('\u{50da}', '\u{50da}'), ('\u{50e7}', '\u{50e7}'), ('\u{50fb}', '\u{50fb}'), ('\u{5100}', '\u{5100}'),
+    ('\u{5104}', '\u{5104}'), ('\u{5112}', '\u{5112}'), ('\u{511f}', '\u{511f}'), ('\u{512a}', '\u{512a}'),
+    ('\u{5132}', '\u{5132}'), ('\u{5141}', '\u{5141}'), ('\u{5143}', '\u{5149}'), ('\u{514b}', '\u{514b}'),
+    ('\u{514d}', '\u{514e}'), ('\u{5150}', '\u{5150}'), ('\u{515a}', '\u{515a}'), ('\u{515c}', '\u{515c}'),
+    ('\u{5165}', '\u{5165}'), ('\u{5168}', '\u{5168}'), ('\u{516b}', '\u{516d}'), ('\u{5171}', '\u{5171}'),
+    ('\u{5175}', '\u{5178}'), ('\u{517c}', '\u{517c}'), ('\u{5185}', '\u{5186}'), ('\u{518a}', '\u{518a}'),
+    ('\u{518d}', '\u{518d}'), ('\u{5192}', '\u{5192}'), ('\u{5197}', '\u{5197}'), ('\u{5199}', '\u{5199}'),
+    ('\u{51a0}', '\u{51a0}'), ('\u{51a5}', '\u{51a5}'), ('\u{51a8}', '\u{51a8}'), ('\u{51ac}', '\u{51ac}'),
+    ('\u{51b4}', '\u{51b4}'), ('\u{51b6}', '\u{51b7}'), ('\u{51c4}', '\u{51c4}'), ('\u{51c6}', '\u{51c6}'),
+    ('\u{51cb}', '\u{51cd}'), ('\u{51dd}', '\u{51dd}'), ('\u{51e1}', '\u{51e1}'), ('\u{51e6}', '\u{51e7}'),
+    ('\u{51ea}', '\u{51ea}'), ('\u{51f1}', '\u{51f1}'), ('\u{51f6}', '\u{51f6}'), ('\u{51f8}', '\u{51fa}'),
+    ('\u{51fd}', '\u{51fd}'), ('\u{5200}', '\u{5200}'), ('\u{5203}', '\u{5203}'), ('\u{5206}', '\u{5208}'),
+    ('\u{520a}', '\u{520a}'), ('\u{5211}', '\u{5211}'), ('\u{5217}', '\u{5217}'), ('\u{521d}', '\u{521d}'),
+    ('\u{5224}', '\u{5225}'), ('\u{5229}', '\u{5229}'), ('\u{5230}', '\u{5230}'), ('\u{5236}', '\u{5238}'),
+    ('\u{523a}', '\u{523b}'), ('\u{5243}', '\u{5243}'), ('\u{5247}', '\u{5247}'), ('\u{524a}', '\u{524a}'),
+    ('\u{524d}', '\u{524d}'), ('\u{5256}', '\u{5256}'), ('\u{525b}', '\u{525b}'), ('\u{5263}', '\u{5265}'),
+    ('\u{526f}', '\u{5270}'), ('\u{5272}', '\u{5272}'), ('\u{5275}', '\u{5275}'), ('\u{5283}', '\u{5283}'),
+    ('\u{5287}', '\u{5287}'), ('\u{5289}', '\u{5289}'), ('\u{529b}', '\u{529b}'), ('\u{529f}', '\u{52a0}'),
+    ('\u{52a3}', '\u{52a3}'), ('\u{52a9}', '\u{52ab}'), ('\u{52b1}', '\u{52b1}'), ('\u{52b4}', '\u{52b4}'),
+    ('\u{52b9}', '\u{52b9}'), ('\u{52be}', '\u{52be}'), ('\u{52c3}', '\u{52c3}'), ('\u{52c5}', '\u{52c5}'),
+    ('\u{52c7}', '\u{52c7}'), ('\u{52c9}', '\u{52c9}'), ('\u{52d5}', '\u{52d5}'), ('\u{52d8}', '\u{52d9}'),
+    ('\u{52dd}', '\u{52dd}'), ('\u{52df}', '\u{52df}'), ('\u{52e2}', '\u{52e2}'), ('\u{52e4}', '\u{52e4}'),
+    ('\u{52e7}', '\u{52e7}'), ('\u{52f2}', '\u{52f2}'), ('\u{52fa}', '\u{52fa}'), ('\u{52fe}', '\u{52ff}'),
+    ('\u{5301}', '\u{5302}'), ('\u{5305}', '\u{5305}'), ('\u{5316}', '\u{5317}'), ('\u{5319}', '\u{5319}'),
+    ('\u{531d}', '\u{531d}'), ('\u{5320}', '\u{5321}'), ('\u{532a}', '\u{532a}'), ('\u{5339}', '\u{533b}'),
+    ('\u{533f}', '\u{533f}'), ('\u{5341}', '\u{5341}'), ('\u{5343}', '\u{5343}'), ('\u{5347}', '\u{5348}'),
+    ('\u{534a}', '\u{534a}'), ('\u{5351}', '\u{5354}'), ('\u{5357}', '\u{5358}'), ('\u{535a}', '\u{535a}'),
+    ('\u{535c}', '\u{535c}'), ('\u{5360}', '\u{5360}'), ('\u{5366}', '\u{5366}'), ('\u{536f}', '\u{5371}'),
+    ('\u{5373}', '\u{5375}'), ('\u{5378}', '\u{5378}'), ('\u{537f}', '\u{537f}'), ('\u{5384}', '\u{5384}'),
+    ('\u{5398}', '\u{5398}'), ('\u{539a}', '\u{539a}'), ('\u{539f}', '\u{539f}'), ('\u{53a8}', '\u{53a9}'),
+    ('\u{53ad}', '\u{53ad}'), ('\u{53b3}', '\u{53b3}'), ('\u{53bb}', '\u{53bb}'), ('\u{53c2}', '\u{53c2}'),
+    ('\u{53c8}', '\u{53ce}'), ('\u{53d4}', '\u{53d4}'), ('\u{53d6}', '\u{53d7}'), ('\u{53d9}', '\u{53d9}'),
+    ('\u{53db}', '\u{53db}'), ('\u{53e1}', '\u{53e5}'), ('\u{53e9}', '\u{53ec}'), ('\u{53ef}', '\u{53f3}'),
+    ('\u{53f6}', '\u{53f8}'), ('\u{5403}', '\u{5404}'), ('\u{5408}', '\u{5411}'), ('\u{541b}', '\u{541b}'),
+    ('\u{541f}', '\u{5420}'), ('\u{5426}', '\u{5426}'), ('\u{542b}', '\u{542b}'), ('\u{5438}', '\u{5439}'),
+    ('\u{543b}', '\u{543b}'), ('\u{543e}', '\u{543e}'), ('\u{5442}', '\u{5442}'), ('\u{5446}', '\u{5446}'),
+    ('\u{5448}', '\u{544a}'), ('\u{5451}', '\u{5451}'), ('\u{5468}', '\u{5468}'), ('\u{546a}', '\u{546a}'),
+    ('\u{5473}', '\u{5473}'), ('\u{547c}', '\u{547d}'), ('\u{548b}', '\u{548c}'), ('\u{54b2}', '\u{54b3}'),
+    ('\u{54bd}', '\u{54bd}'), ('\u{54c0}', '\u{54c1}'), ('\u{54c9}', '\u{54c9}'), ('\u{54e1}', '\u{54e1}'),
+    ('\u{54e8}', '\u{54e9}'), ('\u{54f2}', '\u{54f2}'), ('\u{5504}', '\u{5504}'), ('\u{5506}', '\u{5507}'),
+    ('\u{5510}', '\u{5510}'), ('\u{5516}', '\u{5516}'), ('\u{552f}', '\u{552f}'), ('\u{5531}', '\u{5531}'),
+    ('\u{553e}', '\u{553e}'), ('\u{5544}', '\u{5544}'), ('\u{5546}', '\u{5546}'), ('\u{554f}', '\u{554f}'),
+    ('\u{5553}', '\u{5553}'), ('\u{5584}', '\u{5584}'), ('\u{5589}', '\u{5589}'), ('\u{558b}', '\u{558b}'),
+    ('\u{559a}', '\u{559a}'), ('\u{559c}', '\u{559d}'), ('\u{55a7}', '\u{55a7}'), ('\u{55aa}', '\u{55ac}'),
+    ('\u{55b0}', '\u{55b0}'), ('\u{55b6}', '\u{55b6}'), ('\u{55e3}', '\u{55e3}'), ('\u{5606}', '\u{5606}'),
+    ('\u{5609}', '\u{5609}'), ('\u{5617}', '\u{5618}'), ('\u{5629}', '\u{5629}'), ('\u{5631}', '\u{5631}'),
+    ('\u{5642}', '\u{5642}'), ('\u{564c}', '\u{564c}'), ('\u{565b}', '\u{565b}'), ('\u{5668}', '\u{5668}'),
+    ('\u{5674}', '\u{5674}'), ('\u{5678}', '\u{5678}'), ('\u{567a}', '\u{567a}'), ('\u{5687}', '\u{5687}'),
+    ('\u{56a2}', '\u{56a2}'), ('\u{56da}', '\u{56db}'), ('\u{56de}', '\u{56de}'), ('\u{56e0}', '\u{56e0}'),
+    ('\u{56e3}', '\u{56e3}'), ('\u{56f0}', '\u{56f0}'), ('\u{56f2}', '\u{56f3}'), ('\u{56fa}', '\u{56fa}'),
+    ('\u{56fd}', '\u{56fd}'), ('\u{5703}', '\u{5703}'), ('\u{570f}', '\u{570f}'), ('\u{5712}', '\u{5712}'),
+    ('\u{571f}', '\u{571f}'), ('\u{5727}', '\u{5728}'), ('\u{572d}', '\u{572d}'), ('\u{5730}', '\u{5730}'),
+    ('\u{5742}', '\u{5742}'), ('\u{5747}', '\u{5747}'), ('\u{574a}', '\u{574a}'), ('\u{5750}', '\u{5751}'),
+    ('\u{5764}', '\u{5764}'), ('\u{5766}', '\u{5766}'), ('\u{576a}', '\u{576a}'), ('\u{5782}', '\u{5782}'),
+    ('\u{578b}', '\u{578b}'), ('\u{57a2}', '\u{57a3}'), ('\u{57cb}', '\u{57cb}'), ('\u{57ce}', '\u{57ce}'),
+    ('\u{57dc}', '\u{57dc}'), ('\u{57df}', '\u{57e0}'), ('\u{57f4}', '\u{57f4}'), ('\u{57f7}', '\u{57f7}'),
+    ('\u{57f9}', '\u{57fa}'), ('\u{57fc}', '\u{57fc}'), ('\u{5800}', '\u{5800}'), ('\u{5802}', '\u{5802}'),
+    ('\u{5805}', '\u{5806}'), ('\u{5815}', '\u{5815}'), ('\u{5824}', '\u{5824}'), ('\u{582a}', '\u{582a}'),
+    ('\u{5830}', '\u{5831}'), ('\u{5834}', '\u{5835}'), ('\u{583a}', '\u{583a}'), ('\u{5840}', '\u{5841}'),
+    ('\u{584a}', '\u{584a}'), ('\u{5851}', '\u{5851}'), ('\u{5854}', '\u{5854}'), ('\u{5857}', '\u{585a}'),
+    ('\u{585e}', '\u{585e}'), ('\u{5869}', '\u{5869}'), ('\u{586b}', '\u{586b}'), ('\u{5875}', '\u{5875}'),
+    ('\u{587e}', '\u{587e}'), ('\u{5883}', '\u{5883}'), ('\u{5893}', '\u{5893}'), ('\u{5897}', '\u{5897}'),
+    ('\u{589c}', '\u{589c}'), ('\u{58a8}', '\u{58a8}'), ('\u{58b3}', '\u{58b3}'), ('\u{58be}', '\u{58be}'),
+    ('\u{58c1}', '\u{58c1}'), ('\u{58c7}', '\u{58c7}'), ('\u{58ca}', '\u{58ca}'), ('\u{58cc}', '\u{58cc}'),
+    ('\u{58d5}', '\u{58d5}'), ('\u{58eb}', '\u{58ec}'), ('\u{58ee}', '\u{58ee}'), ('\u{58f0}', '\u{58f2}'),
+    ('\u{58f7}', '\u{58f7}'), ('\u{5909}', '\u{5909}'), ('\u{590f}', '\u{590f}'), ('\u{5915}', '\u{5916}'),
+    ('\u{5919}', '\u{591a}'), ('\u{591c}', '\u{591c}'), ('\u{5922}', '\u{5922}'), ('\u{5927}', '\u{5927}'),
+    ('\u{5929}', '\u{592b}'), ('\u{592e}', '\u{592e}'), ('\u{5931}', '\u{5931}'), ('\u{5937}', '\u{5937}'),
+    ('\u{5944}', '\u{5944}'), ('\u{5947}', '\u{5949}'), ('\u{594f}', '\u{594f}'), ('\u{5951}', '\u{5951}'),
+    ('\u{5954}', '\u{5954}'), ('\u{5957}', '\u{5957}'), ('\u{5965}', '\u{5965}'), ('\u{5968}', '\u{5968}'),
+    ('\u{596a}', '\u{596a}'), ('\u{596e}', '\u{596e}'), ('\u{5973}', '\u{5974}'), ('\u{597d}', '\u{597d}'),
+    ('\u{5982}', '\u{5984}'), ('\u{598a}', '\u{598a}'), ('\u{5993}', '\u{5993}'), ('\u{5996}', '\u{5996}'),
+    ('\u{5999}', '\u{5999}'), ('\u{59a5}', '\u{59a5}'), ('\u{59a8}', '\u{59a8}'), ('\u{59ac}', '\u{59ac}'),
+    ('\u{59b9}', '\u{59b9}'), ('\u{59bb}', '\u{59bb}'), ('\u{59be}', '\u{59be}'), ('\u{59c9}', '\u{59c9}'),
+    ('\u{59cb}', '\u{59cb}'), ('\u{59d0}', '\u{59d1}'), ('\u{59d3}', '\u{59d4}'), ('\u{59e5}', '\u{59e6}'),
+    ('\u{59ea}', '\u{59eb}'), ('\u{59f6}', '\u{59f6}'), ('\u{59fb}', '\u{59fb}'), ('\u{59ff}', '\u{59ff}'),
+    ('\u{5a01}', '\u{5a01}'), ('\u{5a03}', '\u{5a03}'), ('\u{5a18}', '\u{5a18}'), ('\u{5a20}', '\u{5a20}'),
+    ('\u{5a29}', '\u{5a29}'), ('\u{5a2f}', '\u{5a2f}'), ('\u{5a3c}', '\u{5a3c}'), ('\u{5a41}', '\u{5a41}'),
+    ('\u{5a46}', '\u{5a46}'), ('\u{5a5a}', '\u{5a5a}'), ('\u{5a66}', '\u{5a66}'), ('\u{5a7f}', '\u{5a7f}'),
+    ('\u{5a92}', '\u{5a92}'), ('\u{5a9b}', '\u{5a9b}'), ('\u{5ac1}', '\u{5ac1}'), ('\u{5ac9}', '\u{5ac9}'),
+    ('\u{5acc}', '\u{5acc}'), ('\u{5ae1}', '\u{5ae1}'), ('\u{5b09}', '\u{5b09}'), ('\u{5b22}', '\u{5b22}'),
+    ('\u{5b2c}', '\u{5b2c}'), ('\u{5b30}', '\u{5b30}'), ('\u{5b50}', '\u{5b50}'), ('\u{5b54}', '\u{5b54}'),
+    ('\u{5b57}', '\u{5b58}'), ('\u{5b5c}', '\u{5b5d}'), ('\u{5b5f}', '\u{5b5f}'), ('\u{5b63}', '\u{5b64}'),
+    ('\u{5b66}', '\u{5b66}'), ('\u{5b6b}', '\u{5b6b}'), ('\u{5b85}', '\u{5b85}'), ('\u{5b87}', '\u{5b89}'),
+    ('\u{5b8b}', '\u{5b8d}'), ('\u{5b8f}', '\u{5b8f}'), ('\u{5b95}', '\u{5b95}'), ('\u{5b97}', '\u{5b9d}'),
+    ('\u{5b9f}', '\u{5b9f}'), ('\u{5ba2}', '\u{5ba5}'), ('\u{5bae}', '\u{5bae}'), ('\u{5bb0}', '\u{5bb0}'),
+    ('\u{5bb3}', '\u{5bb6}'), ('\u{5bb9}', '\u{5bb9}'), ('\u{5bbf}', '\u{5bbf}'), ('\u{5bc2}', '\u{5bc2}'),
+    ('\u{5bc4}', '\u{5bc6}'), ('\u{5bcc}', '\u{5bcc}'), ('\u{5bd2}', '\u{5bd3}'), ('\u{5bdb}', '\u{5bdb}'),
+    ('\u{5bdd}', '\u{5bdd}'), ('\u{5bdf}', '\u{5bdf}'), ('\u{5be1}', '\u{5be1}'), ('\u{5be7}', '\u{5be7}'),
+    ('\u{5be9}', '\u{5be9}'), ('\u{5bee}', '\u{5bee}'), ('\u{5bf5}', '\u{5bf5}'), ('\u{5bf8}', '\u{5bf8}'),
+    ('\u{5bfa}', '\u{5bfa}'), ('\u{5bfe}', '\u{5bff}'), ('\u{5c01}', '\u{5c02}'), ('\u{5c04}', '\u{5c04}'),
+    ('\u{5c06}', '\u{5c06}'), ('\u{5c09}', '\u{5c0b}'), ('\u{5c0e}', '\u{5c0f}'), ('\u{5c11}', '\u{5c11}'),
+    ('\u{5c16}', '\u{5c16}'), ('\u{5c1a}', '\u{5c1a}'), ('\u{5c24}', '\u{5c24}'), ('\u{5c2d}', '\u{5c2d}'),
+    ('\u{5c31}', '\u{5c31}'), ('\u{5c3a}', '\u{5c40}'), ('\u{5c45}', '\u{5c45}'), ('\u{5c48}', '\u{5c48}'),
+    ('\u{5c4a}', '\u{5c4b}'), ('\u{5c4d}', '\u{5c4d}'), ('\u{5c51}', '\u{5c51}'), ('\u{5c55}', '\u{5c55}'),
+    ('\u{5c5e}', '\u{5c5e}'), ('\u{5c60}', '\u{5c61}'), ('\u{5c64}', '\u{5c65}'), ('\u{5c6f}', '\u{5c6f}'),
+    ('\u{5c71}', '\u{5c71}'), ('\u{5c90}', '\u{5c90}'), ('\u{5ca1}', '\u{5ca1}'), ('\u{5ca8}', '\u{5ca9}'),
+    ('\u{5cac}', '\u{5cac}'), ('\u{5cb1}', '\u{5cb1}'), ('\u{5cb3}', '\u{5cb3}'), ('\u{5cb8}', '\u{5cb8}'),
+    ('\u{5ce0}', '\u{5ce1}'), ('\u{5ce8}', '\u{5ce8}'), ('\u{5cef}', '\u{5cf0}'), ('\u{5cf6}', '\u{5cf6}'),
+    ('\u{5cfb}', '\u{5cfb}'), ('\u{5d07}', '\u{5d07}'), ('\u{5d0e}', '\u{5d0e}'), ('\u{5d16}', '\u{5d16}'),
+    ('\u{5d29}', '\u{5d29}'), ('\u{5d50}', '\u{5d50}'), ('\u{5d69}', '\u{5d69}'), ('\u{5d6f}', '\u{5d6f}'),
+    ('\u{5d8b}', '\u{5d8b}'), ('\u{5dba}', '\u{5dba}'), ('\u{5dcc}', '\u{5dcc}'), ('\u{5ddd}', '\u{5dde}'),
+    ('\u{5de1}', '\u{5de1}'), ('\u{5de3}', '\u{5de3}'), ('\u{5de5}', '\u{5de8}'), ('\u{5dee}', '\u{5dee}'),
+    ('\u{5df1}', '\u{5df1}'), ('\u{5df3}', '\u{5df4}'), ('\u{5df7}', '\u{5df7}'), ('\u{5dfb}', '\u{5dfb}'),
+    ('\u{5dfd}', '\u{5dfe}'), ('\u{5e02}', '\u{5e03}'), ('\u{5e06}', '\u{5e06}'), ('\u{5e0c}', '\u{5e0c}'),
+    ('\u{5e16}', '\u{5e16}'), ('\u{5e1d}', '\u{5e1d}'), ('\u{5e25}', '\u{5e25}'), ('\u{5e2b}', '\u{5e2b}'),
+    ('\u{5e2d}', '\u{5e2d}'), ('\u{5e2f}', '\u{5e30}'), ('\u{5e33}', '\u{5e33}'), ('\u{5e38}', '\u{5e38}'),
+    ('\u{5e3d}', '\u{5e3d}'), ('\u{5e45}', '\u{5e45}'), ('\u{5e4c}', '\u{5e4c}'), ('\u{5e55}', '\u{5e55}'),
+    ('\u{5e61}', '\u{5e61}'), ('\u{5e63}', '\u{5e63}'), ('\u{5e72}', '\u{5e74}'), ('\u{5e78}', '\u{5e79}'),
+    ('\u{5e7b}', '\u{5e7e}'), ('\u{5e81}', '\u{5e81}'), ('\u{5e83}', '\u{5e84}'), ('\u{5e87}', '\u{5e87}'),
+    ('\u{5e8a}', '\u{5e8a}'), ('\u{5e8f}', '\u{5e8f}'), ('\u{5e95}', '\u{5e97}'), ('\u{5e9a}', '\u{5e9a}'),
+    ('\u{5e9c}', '\u{5e9c}'), ('\u{5ea6}', '\u{5ea7}'), ('\u{5eab}', '\u{5eab}'), ('\u{5ead}', '\u{5ead}'),
+    ('\u{5eb5}', '\u{5eb8}'), ('\u{5ec3}', '\u{5ec3}'), ('\u{5ec9}', '\u{5eca}'), ('\u{5ed3}', '\u{5ed3}'),
+    ('\u{5edf}', '\u{5ee0}'), ('\u{5ef6}', '\u{5ef7}'), ('\u{5efa}', '\u{5efc}'), ('\u{5eff}', '\u{5eff}'),
+    ('\u{5f01}', '\u{5f01}'), ('\u{5f04}', '\u{5f04}'), ('\u{5f0a}', '\u{5f0a}'), ('\u{5f0f}', '\u{5f10}'),
+    ('\u{5f13}', '\u{5f15}'), ('\u{5f17}', '\u{5f18}'), ('\u{5f1b}', '\u{5f1b}'), ('\u{5f1f}', '\u{5f1f}'),
+    ('\u{5f25}', '\u{5f27}'), ('\u{5f31}', '\u{5f31}'), ('\u{5f35}', '\u{5f35}'), ('\u{5f37}', '\u{5f37}'),
+    ('\u{5f3c}', '\u{5f3c}'), ('\u{5f3e}', '\u{5f3e}'), ('\u{5f4a}', '\u{5f4a}'), ('\u{5f53}', '\u{5f53}'),
+    ('\u{5f62}', '\u{5f62}'), ('\u{5f66}', '\u{5f66}'), ('\u{5f69}', '\u{5f6c}'), ('\u{5f70}', '\u{5f71}'),
+    ('\u{5f79}', '\u{5f79}'), ('\u{5f7c}', '\u{5f7c}'), ('\u{5f80}', '\u{5f81}'), ('\u{5f84}', '\u{5f85}'),
+    ('\u{5f8b}', '\u{5f8c}'), ('\u{5f90}', '\u{5f90}'), ('\u{5f92}', '\u{5f93}'), ('\u{5f97}', '\u{5f97}'),
+    ('\u{5fa1}', '\u{5fa1}'), ('\u{5fa9}', '\u{5faa}'), ('\u{5fae}', '\u{5fae}'), ('\u{5fb3}', '\u{5fb4}'),
+    ('\u{5fb9}', '\u{5fb9}'), ('\u{5fbd}', '\u{5fbd}'), ('\u{5fc3}', '\u{5fc3}'), ('\u{5fc5}', '\u{5fc5}'),
+    ('\u{5fcc}', '\u{5fcd}'), ('\u{5fd7}', '\u{5fd9}'), ('\u{5fdc}', '\u{5fdc}'), ('\u{5fe0}', '\u{5fe0}'),
+    ('\u{5feb}', '\u{5feb}'), ('\u{5ff5}', '\u{5ff5}'), ('\u{5ffd}', '\u{5ffd}'), ('\u{6012}', '\u{6012}'),
+    ('\u{6016}', '\u{6016}'), ('\u{601c}', '\u{601d}'), ('\u{6020}', '\u{6020}'), ('\u{6025}', '\u{6025}'),
+    ('\u{6027}', '\u{6028}'), ('\u{602a}', '\u{602a}'), ('\u{602f}', '\u{602f}'), ('\u{604b}', '\u{604b}'),
+    ('\u{6050}', '\u{6050}'), ('\u{6052}', '\u{6052}'), ('\u{6055}', '\u{6055}'), ('\u{6062}', '\u{6062}'),
+    ('\u{6065}', '\u{6065}'), ('\u{6068}', '\u{6069}'), ('\u{606d}', '\u{606d}'), ('\u{606f}', '\u{6070}'),
+    ('\u{6075}', '\u{6075}'), ('\u{6089}', '\u{6089}'), ('\u{608c}', '\u{608c}'), ('\u{6094}', '\u{6094}'),
+    ('\u{609f}', '\u{60a0}'), ('\u{60a3}', '\u{60a3}'), ('\u{60a6}', '\u{60a6}'), ('\u{60a9}', '\u{60aa}'),
+    ('\u{60b2}', '\u{60b2}'), ('\u{60b6}', '\u{60b6}'), ('\u{60bc}', '\u{60bc}'), ('\u{60c5}', '\u{60c5}'),
+    ('\u{60c7}', '\u{60c7}'), ('\u{60d1}', '\u{60d1}'), ('\u{60da}', '\u{60da}'), ('\u{60dc}', '\u{60dc}'),
+    ('\u{60df}', '\u{60df}'), ('\u{60e3}', '\u{60e3}'), ('\u{60e8}', '\u{60e8}'), ('\u{60f0}', '\u{60f0}'),
+    ('\u{60f3}', '\u{60f3}'), ('\u{60f9}', '\u{60f9}'), ('\u{6101}', '\u{6101}'), ('\u{6108}', '\u{6109}'),
+    ('\u{610f}', '\u{610f}'), ('\u{611a}', '\u{611b}'), ('\u{611f}', '\u{611f}'), ('\u{6148}', '\u{6148}'),
+    ('\u{614b}', '\u{614c}'), ('\u{614e}', '\u{614e}'), ('\u{6155}', '\u{6155}'), ('\u{6162}', '\u{6163}'),
+    ('\u{6167}', '\u{6168}'), ('\u{616e}', '\u{616e}'), ('\u{6170}', '\u{6170}'), ('\u{6176}', '\u{6176}'),
+    ('\u{617e}', '\u{617e}'), ('\u{6182}', '\u{6182}'), ('\u{618e}', '\u{618e}'), ('\u{6190}', '\u{6190}'),
+    ('\u{61a4}', '\u{61a4}'), ('\u{61a7}', '\u{61a7}'), ('\u{61a9}', '\u{61a9}'), ('\u{61b2}', '\u{61b2}'),
+    ('\u{61b6}', '\u{61b6}'), ('\u{61be}', '\u{61be}'), ('\u{61c7}', '\u{61c7}'), ('\u{61d0}', '\u{61d0}'),
+    ('\u{61f2}', '\u{61f2}'), ('\u{61f8}', '\u{61f8}'), ('\u{620a}', '\u{620a}'), ('\u{620e}', '\u{620e}'),
+    ('\u{6210}', '\u{6212}'), ('\u{6216}', '\u{6216}'), ('\u{621a}', '\u{621a}'), ('\u{621f}', '\u{621f}'),
+    ('\u{6226}', '\u{6226}'), ('\u{622f}', '\u{622f}'), ('\u{6234}', '\u{6234}'), ('\u{6238}', '\u{6238}'),
+    ('\u{623b}', '\u{623b}'), ('\u{623f}', '\u{6240}'), ('\u{6247}', '\u{6247}'), ('\u{6249}', '\u{6249}'),
+    ('\u{624b}', '\u{624b}'), ('\u{624d}', '\u{624d}'), ('\u{6253}', '\u{6253}'), ('\u{6255}', '\u{6255}'),
+    ('\u{6258}', '\u{6258}'), ('\u{626e}', '\u{626e}'), ('\u{6271}', '\u{6271}'), ('\u{6276}', '\u{6276}'),
+    ('\u{6279}', '\u{6279}'), ('\u{627f}', '\u{6280}'), ('\u{6284}', '\u{6284}'), ('\u{628a}', '\u{628a}'),
+    ('\u{6291}', '\u{6291}'), ('\u{6295}', '\u{6295}'), ('\u{6297}', '\u{6298}'), ('\u{629c}', '\u{629c}'),
+    ('\u{629e}', '\u{629e}'), ('\u{62ab}', '\u{62ab}'), ('\u{62b1}', '\u{62b1}'), ('\u{62b5}', '\u{62b5}'),
+    ('\u{62b9}', '\u{62b9}'), ('\u{62bc}', '\u{62bd}'), ('\u{62c5}', '\u{62c5}'), ('\u{62cd}', '\u{62cd}'),
+    ('\u{62d0}', '\u{62d0}'), ('\u{62d2}', '\u{62d3}'), ('\u{62d8}', '\u{62d9}'), ('\u{62db}', '\u{62db}'),
+    ('\u{62dd}', '\u{62dd}'), ('\u{62e0}', '\u{62e1}'), ('\u{62ec}', '\u{62ed}'), ('\u{62f3}', '\u{62f3}'),
+    ('\u{62f6}', '\u{62f7}'), ('\u{62fe}', '\u{62fe}'), ('\u{6301}', '\u{6301}'), ('\u{6307}', '\u{6307}'),
+    ('\u{6309}', '\u{6309}'), ('\u{6311}', '\u{6311}'), ('\u{6319}', '\u{6319}'), ('\u{631f}', '\u{631f}'),
+    ('\u{6328}', '\u{6328}'), ('\u{632b}', '\u{632b}'), ('\u{632f}', '\u{632f}'), ('\u{633a}', '\u{633a}'),
+    ('\u{633d}', '\u{633d}'), ('\u{633f}', '\u{633f}'), ('\u{6349}', '\u{6349}'), ('\u{634c}', '\u{634c}'),
+    ('\u{6355}', '\u{6355}'), ('\u{6357}', '\u{6357}'), ('\u{635c}', '\u{635c}'), ('\u{6367}', '\u{6368}'),
+    ('\u{636e}', '\u{636e}'), ('\u{6372}', '\u{6372}'), ('\u{6377}', '\u{6377}'), ('\u{637a}', '\u{637b}'),
+    ('\u{6383}', '\u{6383}'), ('\u{6388}', '\u{6388}'), ('\u{638c}', '\u{638c}'), ('\u{6392}', '\u{6392}'),
+    ('\u{6398}', '\u{6398}'), ('\u{639b}', '\u{639b}'), ('\u{63a0}', '\u{63a2}'), ('\u{63a5}', '\u{63a5}'),
+    ('\u{63a7}', '\u{63aa}'), ('\u{63ac}', '\u{63ac}'), ('\u{63b2}', '\u{63b2}'), ('\u{63b4}', '\u{63b4}'),
+    ('\u{63bb}', '\u{63bb}'), ('\u{63c3}', '\u{63c3}'), ('\u{63cf}', '\u{63d0}'), ('\u{63d6}', '\u{63d6}'),
+    ('\u{63da}', '\u{63db}'), ('\u{63e1}', '\u{63e1}'), ('\u{63ee}', '\u{63ee}'), ('\u{63f4}', '\u{63f4}'),
+    ('\u{63fa}', '\u{63fa}'), ('\u{640d}', '\u{640d}'), ('\u{642c}', '\u{642d}'), ('\u{643a}', '\u{643a}'),
+    ('\u{643e}', '\u{643e}'), ('\u{6442}', '\u{6442}'), ('\u{6458}', '\u{6458}'), ('\u{6469}', '\u{6469}'),
+    ('\u{6478}', '\u{6478}'), ('\u{647a}', '\u{647a}'), ('\u{6483}', '\u{6483}'), ('\u{6492}', '\u{6492}'),
+    ('\u{649a}', '\u{649a}'), ('\u{649e}', '\u{649e}'), ('\u{64a4}', '\u{64a4}'), ('\u{64ab}', '\u{64ab}'),
+    ('\u{64ad}', '\u{64ae}'), ('\u{64b0}', '\u{64b0}'), ('\u{64b2}', '\u{64b2}'), ('\u{64b9}', '\u{64b9}'),
+    ('\u{64c1}', '\u{64c1}'), ('\u{64cd}', '\u{64cd}'), ('\u{64e2}', '\u{64e2}'), ('\u{64e6}', '\u{64e6}'),
+    ('\u{64ec}', '\u{64ec}'), ('\u{64fe}', '\u{64fe}'), ('\u{652f}', '\u{652f}'), ('\u{6539}', '\u{6539}'),
+    ('\u{653b}', '\u{653b}'), ('\u{653e}', '\u{653f}'), ('\u{6545}', '\u{6545}'), ('\u{654f}', '\u{654f}'),
+    ('\u{6551}', '\u{6551}'), ('\u{6557}', '\u{6557}'), ('\u{6559}', '\u{6559}'), ('\u{6562}', '\u{6563}'),
+    ('\u{6566}', '\u{6566}'), ('\u{656c}', '\u{656c}'), ('\u{6570}', '\u{6570}'), ('\u{6574}', '\u{6575}'),
+    ('\u{6577}', '\u{6577}'), ('\u{6587}', '\u{6587}'), ('\u{6589}', '\u{6589}'), ('\u{658c}', '\u{658c}'),
+    ('\u{658e}', '\u{658e}'), ('\u{6590}', '\u{6591}'), ('\u{6597}', '\u{6597}'), ('\u{6599}', '\u{6599}'),
+    ('\u{659c}', '\u{659c}'), ('\u{65a1}', '\u{65a1}'), ('\u{65a4}', '\u{65a5}'), ('\u{65a7}', '\u{65a7}'),
+    ('\u{65ac}', '\u{65ad}'), ('\u{65af}', '\u{65b0}'), ('\u{65b9}', '\u{65b9}'), ('\u{65bc}', '\u{65bd}'),
+    ('\u{65c5}', '\u{65c5}'), ('\u{65cb}', '\u{65cb}'), ('\u{65cf}', '\u{65cf}'), ('\u{65d7}', '\u{65d7}'),
+    ('\u{65e2}', '\u{65e2}'), ('\u{65e5}', '\u{65e9}'), ('\u{65ec}', '\u{65ed}'), ('\u{65fa}', '\u{65fa}'),
+    ('\u{6602}', '\u{6602}'), ('\u{6606}', '\u{6607}'), ('\u{660c}', '\u{660c}'), ('\u{660e}', '\u{660f}'),
+    ('\u{6613}', '\u{6614}'), ('\u{661f}', '\u{6620}'), ('\u{6625}', '\u{6625}'), ('\u{6627}', '\u{6628}'),
+    ('\u{662d}', '\u{662d}'), ('\u{662f}', '\u{662f}'), ('\u{663c}', '\u{663c}'), ('\u{6642}', '\u{6643}'),
+    ('\u{664b}', '\u{664b}'), ('\u{6652}', '\u{6652}'), ('\u{6666}', '\u{6666}'), ('\u{6669}', '\u{6669}'),
+    ('\u{666e}', '\u{666f}'), ('\u{6674}', '\u{6674}'), ('\u{6676}', '\u{6676}'), ('\u{667a}', '\u{667a}'),
+    ('\u{6681}', '\u{6681}'), ('\u{6687}', '\u{6687}'), ('\u{6691}', '\u{6691}'), ('\u{6696}', '\u{6697}'),
+    ('\u{66a2}', '\u{66a2}'), ('\u{66a6}', '\u{66a6}'), ('\u{66ab}', '\u{66ab}'), ('\u{66ae}', '\u{66ae}'),
+    ('\u{66b4}', '\u{66b4}'), ('\u{66c7}', '\u{66c7}'), ('\u{66d9}', '\u{66d9}'), ('\u{66dc}', '\u{66dd}'),
+    ('\u{66f2}', '\u{66f4}'), ('\u{66f8}', '\u{66f9}'), ('\u{66fd}', '\u{6700}'), ('\u{6708}', '\u{6709}'),
+    ('\u{670b}', '\u{670b}'), ('\u{670d}', '\u{670d}'), ('\u{6714}', '\u{6715}'), ('\u{6717}', '\u{6717}'),
+    ('\u{671b}', '\u{671b}'), ('\u{671d}', '\u{671d}'), ('\u{671f}', '\u{671f}'), ('\u{6728}', '\u{6728}'),
+    ('\u{672a}', '\u{672d}'), ('\u{6731}', '\u{6731}'), ('\u{6734}', '\u{6734}'), ('\u{673a}', '\u{673a}'),
+    ('\u{673d}', '\u{673d}'), ('\u{6749}', '\u{6749}'), ('\u{674e}', '\u{6751}'), ('\u{6753}', '\u{6753}'),
+    ('\u{6756}', '\u{6756}'), ('\u{675c}', '\u{675c}'), ('\u{675f}', '\u{675f}'), ('\u{6761}', '\u{6762}'),
+    ('\u{6765}', '\u{6765}'), ('\u{676d}', '\u{676d}'), ('\u{676f}', '\u{676f}'), ('\u{6771}', '\u{6771}'),
+    ('\u{6775}', '\u{6775}'), ('\u{6777}', '\u{6777}'), ('\u{677e}', '\u{677f}'), ('\u{6787}', '\u{6787}'),
+    ('\u{6790}', '\u{6790}'), ('\u{6795}', '\u{6795}'), ('\u{6797}', '\u{6797}'), ('\u{679a}', '\u{679a}'),
+    ('\u{679c}', '\u{679d}'), ('\u{67a0}', '\u{67a0}'), ('\u{67a2}', '\u{67a2}'), ('\u{67af}', '\u{67af}'),
+    ('\u{67b6}', '\u{67b6}'), ('\u{67c1}', '\u{67c1}'), ('\u{67c4}', '\u{67c4}'), ('\u{67ca}', '\u{67ca}'),
+    ('\u{67cf}', '\u{67d1}'), ('\u{67d3}', '\u{67d4}'), ('\u{67d8}', '\u{67d8}'), ('\u{67da}', '\u{67da}'),
+    ('\u{67f1}', '\u{67f1}'), ('\u{67f3}', '\u{67f5}'), ('\u{67fb}', '\u{67fb}'), ('\u{67fe}', '\u{67ff}'),
+    ('\u{6802}', '\u{6804}'), ('\u{6813}', '\u{6813}'), ('\u{6816}', '\u{6817}'), ('\u{6821}', '\u{6822}'),
+    ('\u{682a}', '\u{682a}'), ('\u{6834}', '\u{6834}'), ('\u{6838}', '\u{6839}'), ('\u{683c}', '\u{683d}'),
+    ('\u{6841}', '\u{6843}'), ('\u{6848}', '\u{6848}'), ('\u{6850}', '\u{6851}'), ('\u{6853}', '\u{6854}'),
+    ('\u{685c}', '\u{685d}'), ('\u{685f}', '\u{685f}'), ('\u{6867}', '\u{6867}'), ('\u{6876}', '\u{6876}'),
+    ('\u{6881}', '\u{6881}'), ('\u{6885}', '\u{6885}'), ('\u{6893}', '\u{6893}'), ('\u{6897}', '\u{6897}'),
+    ('\u{68a2}', '\u{68a2}'), ('\u{68a7}', '\u{68a8}'), ('\u{68af}', '\u{68b1}'), ('\u{68b6}', '\u{68b6}'),
+    ('\u{68bc}', '\u{68bc}'), ('\u{68c4}', '\u{68c4}'), ('\u{68c9}', '\u{68c9}'), ('\u{68cb}', '\u{68cb}'),
+    ('\u{68d2}', '\u{68d2}'), ('\u{68da}', '\u{68da}'), ('\u{68df}', '\u{68df}'), ('\u{68ee}', '\u{68ee}'),
+    ('\u{68f2}', '\u{68f2}'), ('\u{68fa}', '\u{68fa}'), ('\u{6900}', '\u{6900}'), ('\u{6905}', '\u{6905}'),
+    ('\u{690b}', '\u{690b}'), ('\u{690d}', '\u{690e}'), ('\u{6919}', '\u{6919}'), ('\u{691b}', '\u{691c}'),
+    ('\u{6934}', '\u{6934}'), ('\u{693f}', '\u{693f}'), ('\u{694a}', '\u{694a}'), ('\u{6953}', '\u{6953}'),
+    ('\u{6955}', '\u{6955}'), ('\u{695a}', '\u{695a}'), ('\u{6960}', '\u{6960}'), ('\u{6962}', '\u{6962}'),
+    ('\u{696d}', '\u{696d}'), ('\u{696f}', '\u{696f}'), ('\u{6973}', '\u{6973}'), ('\u{6975}', '\u{6975}'),
+    ('\u{697c}', '\u{697d}'), ('\u{6982}', '\u{6982}'), ('\u{698a}', '\u{698a}'), ('\u{698e}', '\u{698e}'),
+    ('\u{6994}', '\u{6994}'), ('\u{699b}', '\u{699b}'), ('\u{69cb}', '\u{69cd}'), ('\u{69d8}', '\u{69d9}'),
+    ('\u{69fb}', '\u{69fb}'), ('\u{69fd}', '\u{69fd}'), ('\u{6a0b}', '\u{6a0b}'), ('\u{6a17}', '\u{6a17}'),
+    ('\u{6a19}', '\u{6a19}'), ('\u{6a1f}', '\u{6a1f}'), ('\u{6a21}', '\u{6a21}'), ('\u{6a29}', '\u{6a2b}'),
+    ('\u{6a35}', '\u{6a35}'), ('\u{6a39}', '\u{6a3a}'), ('\u{6a3d}', '\u{6a3d}'), ('\u{6a4b}', '\u{6a4b}'),
+    ('\u{6a58}', '\u{6a58}'), ('\u{6a5f}', '\u{6a5f}'), ('\u{6a61}', '\u{6a61}'), ('\u{6a7f}', '\u{6a80}'),
+    ('\u{6a8e}', '\u{6a8e}'), ('\u{6ad3}', '\u{6ad3}'), ('\u{6adb}', '\u{6adb}'), ('\u{6ae8}', '\u{6ae8}'),
+    ('\u{6b04}', '\u{6b04}'), ('\u{6b1d}', '\u{6b1d}'), ('\u{6b20}', '\u{6b21}'), ('\u{6b23}', '\u{6b23}'),
+    ('\u{6b27}', '\u{6b27}'), ('\u{6b32}', '\u{6b32}'), ('\u{6b3a}', '\u{6b3a}'), ('\u{6b3d}', '\u{6b3e}'),
+    ('\u{6b4c}', '\u{6b4c}'), ('\u{6b4e}', '\u{6b4e}'), ('\u{6b53}', '\u{6b53}'), ('\u{6b62}', '\u{6b64}'),
+    ('\u{6b66}', '\u{6b66}'), ('\u{6b69}', '\u{6b6a}'), ('\u{6b6f}', '\u{6b6f}'), ('\u{6b73}', '\u{6b74}'),
+    ('\u{6b7b}', '\u{6b7b}'), ('\u{6b86}', '\u{6b86}'), ('\u{6b89}', '\u{6b8b}'), ('\u{6b96}', '\u{6b96}'),
+    ('\u{6bb4}', '\u{6bb5}'), ('\u{6bba}', '\u{6bbb}'), ('\u{6bbf}', '\u{6bbf}'), ('\u{6bc5}', '\u{6bc5}'),
+    ('\u{6bcd}', '\u{6bce}'), ('\u{6bd2}', '\u{6bd2}'), ('\u{6bd4}', '\u{6bd4}'), ('\u{6bd8}', '\u{6bd8}'),
+    ('\u{6bdb}', '\u{6bdb}'), ('\u{6c0f}', '\u{6c0f}'), ('\u{6c11}', '\u{6c11}'), ('\u{6c17}', '\u{6c17}'),
+    ('\u{6c34}', '\u{6c34}'), ('\u{6c37}', '\u{6c38}'), ('\u{6c3e}', '\u{6c3e}'), ('\u{6c40}', '\u{6c42}'),
+    ('\u{6c4e}', '\u{6c4e}'), ('\u{6c50}', '\u{6c50}'), ('\u{6c57}', '\u{6c57}'), ('\u{6c5a}', '\u{6c5a}'),
+    ('\u{6c5d}', '\u{6c5d}'), ('\u{6c5f}', '\u{6c60}'), ('\u{6c70}', '\u{6c70}'), ('\u{6c72}', '\u{6c72}'),
+    ('\u{6c7a}', '\u{6c7a}'), ('\u{6c7d}', '\u{6c7d}'), ('\u{6c83}', '\u{6c83}'), ('\u{6c88}', '\u{6c88}'),
+    ('\u{6c8c}', '\u{6c8c}'), ('\u{6c93}', '\u{6c93}'), ('\u{6c96}', '\u{6c96}'), ('\u{6c99}', '\u{6c99}'),
+    ('\u{6ca1}', '\u{6ca2}'), ('\u{6cab}', '\u{6cab}'), ('\u{6cb3}', '\u{6cb3}'), ('\u{6cb8}', '\u{6cb9}'),
+    ('\u{6cbb}', '\u{6cbc}'), ('\u{6cbf}', '\u{6cbf}'), ('\u{6cc1}', '\u{6cc1}'), ('\u{6cc9}', '\u{6cca}'),
+    ('\u{6ccc}', '\u{6ccc}'), ('\u{6cd5}', '\u{6cd5}'), ('\u{6ce1}', '\u{6ce3}'), ('\u{6ce5}', '\u{6ce5}'),
+    ('\u{6ce8}', '\u{6ce8}'), ('\u{6cf0}', '\u{6cf0}'), ('\u{6cf3}', '\u{6cf3}'), ('\u{6d0b}', '\u{6d0b}'),
+    ('\u{6d17}', '\u{6d17}'), ('\u{6d1b}', '\u{6d1b}'), ('\u{6d1e}', '\u{6d1e}'), ('\u{6d25}', '\u{6d25}'),
+    ('\u{6d29}', '\u{6d2a}'), ('\u{6d32}', '\u{6d32}'), ('\u{6d3b}', '\u{6d3b}'), ('\u{6d3e}', '\u{6d3e}'),
+    ('\u{6d41}', '\u{6d41}'), ('\u{6d44}', '\u{6d45}'), ('\u{6d5c}', '\u{6d5c}'), ('\u{6d66}', '\u{6d66}'),
+    ('\u{6d69}', '\u{6d6a}'), ('\u{6d6c}', '\u{6d6c}'), ('\u{6d6e}', '\u{6d6e}'), ('\u{6d74}', '\u{6d74}'),
+    ('\u{6d77}', '\u{6d78}'), ('\u{6d88}', '\u{6d88}'), ('\u{6d8c}', '\u{6d8c}'), ('\u{6d99}', '\u{6d99}'),
+    ('\u{6d9b}', '\u{6d9c}'), ('\u{6daf}', '\u{6daf}'), ('\u{6db2}', '\u{6db2}'), ('\u{6dbc}', '\u{6dbc}'),
+    ('\u{6dc0}', '\u{6dc0}'), ('\u{6dcb}', '\u{6dcb}'), ('\u{6dd1}', '\u{6dd1}'), ('\u{6dd8}', '\u{6dd8}'),
+    ('\u{6de1}', '\u{6de1}'), ('\u{6deb}', '\u{6deb}'), ('\u{6df1}', '\u{6df1}'), ('\u{6df3}', '\u{6df3}'),
+    ('\u{6df5}', '\u{6df5}'), ('\u{6df7}', '\u{6df7}'), ('\u{6dfb}', '\u{6dfb}'), ('\u{6e05}', '\u{6e05}'),
+    ('\u{6e07}', '\u{6e09}'), ('\u{6e0b}', '\u{6e0b}'), ('\u{6e13}', '\u{6e13}'), ('\u{6e1a}', '\u{6e1b}'),
+    ('\u{6e20}', '\u{6e21}'), ('\u{6e25}', '\u{6e26}'), ('\u{6e29}', '\u{6e29}'), ('\u{6e2c}', '\u{6e2c}'),
+    ('\u{6e2f}', '\u{6e2f}'), ('\u{6e4a}', '\u{6e4a}'), ('\u{6e56}', '\u{6e56}'), ('\u{6e58}', '\u{6e58}'),
+    ('\u{6e5b}', '\u{6e5b}'), ('\u{6e67}', '\u{6e67}'), ('\u{6e6f}', '\u{6e6f}'), ('\u{6e7e}', '\u{6e80}'),
+    ('\u{6e8c}', '\u{6e8c}'), ('\u{6e90}', '\u{6e90}'), ('\u{6e96}', '\u{6e96}'), ('\u{6e9c}', '\u{6e9d}'),
+    ('\u{6ea2}', '\u{6ea2}'), ('\u{6eb6}', '\u{6eb6}'), ('\u{6eba}', '\u{6eba}'), ('\u{6ec5}', '\u{6ec5}'),
+    ('\u{6ecb}', '\u{6ecb}'), ('\u{6ed1}', '\u{6ed1}'), ('\u{6edd}', '\u{6ede}'), ('\u{6ef4}', '\u{6ef4}'),
+    ('\u{6f01}', '\u{6f02}'), ('\u{6f06}', '\u{6f06}'), ('\u{6f09}', '\u{6f09}'), ('\u{6f0f}', '\u{6f0f}'),
+    ('\u{6f14}', '\u{6f15}'), ('\u{6f20}', '\u{6f20}'), ('\u{6f22}', '\u{6f23}'), ('\u{6f2b}', '\u{6f2c}'),
+    ('\u{6f38}', '\u{6f38}'), ('\u{6f45}', '\u{6f45}'), ('\u{6f54}', '\u{6f54}'), ('\u{6f5c}', '\u{6f5c}'),
+    ('\u{6f5f}', '\u{6f5f}'), ('\u{6f64}', '\u{6f64}'), ('\u{6f6e}', '\u{6f6e}'), ('\u{6f70}', '\u{6f70}'),
+    ('\u{6f84}', '\u{6f84}'), ('\u{6f97}', '\u{6f97}'), ('\u{6fb1}', '\u{6fb1}'), ('\u{6fc0}', '\u{6fc1}'),
+    ('\u{6fc3}', '\u{6fc3}'), ('\u{6fe0}', '\u{6fe1}'), ('\u{6feb}', '\u{6feb}'), ('\u{6fef}', '\u{6fef}'),
+    ('\u{7015}', '\u{7015}'), ('\u{701e}', '\u{701e}'), ('\u{7026}', '\u{7027}'), ('\u{702c}', '\u{702c}'),
+    ('\u{7058}', '\u{7058}'), ('\u{706b}', '\u{706b}'), ('\u{706f}', '\u{7070}'), ('\u{7078}', '\u{7078}'),
+    ('\u{707c}', '\u{707d}'), ('\u{7089}', '\u{708a}'), ('\u{708e}', '\u{708e}'), ('\u{70ad}', '\u{70ad}'),
+    ('\u{70b9}', '\u{70ba}'), ('\u{70c8}', '\u{70c8}'), ('\u{70cf}', '\u{70cf}'), ('\u{70f9}', '\u{70f9}'),
+    ('\u{7114}', '\u{7114}'), ('\u{711a}', '\u{711a}'), ('\u{7121}', '\u{7121}'), ('\u{7126}', '\u{7126}'),
+    ('\u{7136}', '\u{7136}'), ('\u{713c}', '\u{713c}'), ('\u{7149}', '\u{7149}'), ('\u{714e}', '\u{714e}'),
+    ('\u{7159}', '\u{7159}'), ('\u{7164}', '\u{7164}'), ('\u{7167}', '\u{7167}'), ('\u{7169}', '\u{7169}'),
+    ('\u{716e}', '\u{716e}'), ('\u{717d}', '\u{717d}'), ('\u{718a}', '\u{718a}'), ('\u{7194}', '\u{7194}'),
+    ('\u{719f}', '\u{719f}'), ('\u{71b1}', '\u{71b1}'), ('\u{71c3}', '\u{71c3}'), ('\u{71c8}', '\u{71c8}'),
+    ('\u{71d0}', '\u{71d0}'), ('\u{71d5}', '\u{71d5}'), ('\u{71e5}', '\u{71e6}'), ('\u{71ed}', '\u{71ed}'),
+    ('\u{7206}', '\u{7206}'), ('\u{722a}', '\u{722a}'), ('\u{7235}', '\u{7236}'), ('\u{723a}', '\u{723a}'),
+    ('\u{723d}', '\u{723e}'), ('\u{7247}', '\u{7248}'), ('\u{724c}', '\u{724c}'), ('\u{7252}', '\u{7252}'),
+    ('\u{7259}', '\u{7259}'), ('\u{725b}', '\u{725b}'), ('\u{725d}', '\u{725d}'), ('\u{725f}', '\u{725f}'),
+    ('\u{7261}', '\u{7262}'), ('\u{7267}', '\u{7267}'), ('\u{7269}', '\u{7269}'), ('\u{7272}', '\u{7272}'),
+    ('\u{7279}', '\u{7279}'), ('\u{727d}', '\u{727d}'), ('\u{7280}', '\u{7280}'), ('\u{72a0}', '\u{72a0}'),
+    ('\u{72ac}', '\u{72ac}'), ('\u{72af}', '\u{72af}'), ('\u{72b6}', '\u{72b6}'), ('\u{72c2}', '\u{72c2}'),
+    ('\u{72d0}', '\u{72d0}'), ('\u{72d7}', '\u{72d7}'), ('\u{72d9}', '\u{72d9}'), ('\u{72db}', '\u{72db}'),
+    ('\u{72e9}', '\u{72e9}'), ('\u{72ec}', '\u{72ed}'), ('\u{72f8}', '\u{72f8}'), ('\u{72fc}', '\u{72fd}'),
+    ('\u{731b}', '\u{731b}'), ('\u{731f}', '\u{731f}'), ('\u{732a}', '\u{732b}'), ('\u{732e}', '\u{732e}'),
+    ('\u{7336}', '\u{7337}'), ('\u{733f}', '\u{733f}'), ('\u{7344}', '\u{7345}'), ('\u{7363}', '\u{7363}'),
+    ('\u{7372}', '\u{7372}'), ('\u{7384}', '\u{7384}'), ('\u{7387}', '\u{7387}'), ('\u{7389}', '\u{7389}'),
+    ('\u{738b}', '\u{738b}'), ('\u{7396}', '\u{7396}'), ('\u{73a9}', '\u{73a9}'), ('\u{73b2}', '\u{73b2}'),
+    ('\u{73c2}', '\u{73c2}'), ('\u{73ca}', '\u{73ca}'), ('\u{73cd}', '\u{73cd}'), ('\u{73e0}', '\u{73e0}'),
+    ('\u{73ea}', '\u{73ea}'), ('\u{73ed}', '\u{73ed}'), ('\u{73fe}', '\u{73fe}'), ('\u{7403}', '\u{7403}'),
+    ('\u{7406}', '\u{7406}'), ('\u{7409}', '\u{7409}'), ('\u{7422}', '\u{7422}'), ('\u{7433}', '\u{7436}'),
+    ('\u{745a}', '\u{745b}'), ('\u{745e}', '\u{745e}'), ('\u{7460}', '\u{7460}'), ('\u{7473}', '\u{7473}'),
+    ('\u{7483}', '\u{7483}'), ('\u{74b0}', '\u{74b0}'), ('\u{74bd}', '\u{74bd}'), ('\u{74dc}', '\u{74dc}'),
+    ('\u{74e2}', '\u{74e2}'), ('\u{74e6}', '\u{74e6}'), ('\u{74f6}', '\u{74f6}'), ('\u{7511}', '\u{7511}'),
+    ('\u{7518}', '\u{7518}'), ('\u{751a}', '\u{751a}'), ('\u{751c}', '\u{751c}'), ('\u{751f}', '\u{751f}'),
+    ('\u{7523}', '\u{7523}'), ('\u{7525}', '\u{7525}'), ('\u{7528}', '\u{7528}'), ('\u{752b}', '\u{752b}'),
+    ('\u{7530}', '\u{7533}'), ('\u{7537}', '\u{7537}'), ('\u{753a}', '\u{753b}'), ('\u{754c}', '\u{754c}'),
+    ('\u{754f}', '\u{754f}'), ('\u{7551}', '\u{7551}'), ('\u{7554}', '\u{7554}'), ('\u{7559}', '\u{7559}'),
+    ('\u{755c}', '\u{755d}'), ('\u{7560}', '\u{7560}'), ('\u{7562}', '\u{7562}'), ('\u{7565}', '\u{7566}'),
+    ('\u{756a}', '\u{756a}'), ('\u{7570}', '\u{7570}'), ('\u{7573}', '\u{7573}'), ('\u{7577}', '\u{7577}'),
+    ('\u{757f}', '\u{757f}'), ('\u{758b}', '\u{758b}'), ('\u{758e}', '\u{758f}'), ('\u{7591}', '\u{7591}'),
+    ('\u{75ab}', '\u{75ab}'), ('\u{75b2}', '\u{75b2}'), ('\u{75b9}', '\u{75b9}'), ('\u{75be}', '\u{75be}'),
+    ('\u{75c5}', '\u{75c5}'), ('\u{75c7}', '\u{75c7}'), ('\u{75d4}', '\u{75d5}'), ('\u{75d8}', '\u{75d8}'),
+    ('\u{75db}', '\u{75db}'), ('\u{75e2}', '\u{75e2}'), ('\u{75e9}', '\u{75e9}'), ('\u{75f4}', '\u{75f4}'),
+    ('\u{7642}', '\u{7642}'), ('\u{764c}', '\u{764c}'), ('\u{7652}', '\u{7652}'), ('\u{7656}', '\u{7656}'),
+    ('\u{767a}', '\u{767b}'), ('\u{767d}', '\u{767e}'), ('\u{7684}', '\u{7684}'), ('\u{7686}', '\u{7687}'),
+    ('\u{7690}', '\u{7690}'), ('\u{76ae}', '\u{76ae}'), ('\u{76bf}', '\u{76bf}'), ('\u{76c3}', '\u{76c3}'),
+    ('\u{76c6}', '\u{76c6}'), ('\u{76c8}', '\u{76c8}'), ('\u{76ca}', '\u{76ca}'), ('\u{76d7}', '\u{76d7}'),
+    ('\u{76db}', '\u{76db}'), ('\u{76df}', '\u{76df}'), ('\u{76e3}', '\u{76e4}'), ('\u{76ee}', '\u{76ee}'),
+    ('\u{76f2}', '\u{76f2}'), ('\u{76f4}', '\u{76f4}'), ('\u{76f8}', '\u{76f8}'), ('\u{76fe}', '\u{76fe}'),
+    ('\u{7701}', '\u{7701}'), ('\u{7709}', '\u{7709}'), ('\u{770b}', '\u{770c}'), ('\u{771f}', '\u{7720}'),
+    ('\u{773a}', '\u{773a}'), ('\u{773c}', '\u{773c}'), ('\u{7740}', '\u{7740}'), ('\u{7761}', '\u{7761}'),
+    ('\u{7763}', '\u{7763}'), ('\u{7766}', '\u{7766}'), ('\u{77a5}', '\u{77a5}'), ('\u{77ac}', '\u{77ad}'),
+    ('\u{77b3}', '\u{77b3}'), ('\u{77db}', '\u{77db}'), ('\u{77e2}', '\u{77e2}'), ('\u{77e5}', '\u{77e5}'),
+    ('\u{77e7}', '\u{77e7}'), ('\u{77e9}', '\u{77e9}'), ('\u{77ed}', '\u{77ed}'), ('\u{77ef}', '\u{77ef}'),
+    ('\u{77f3}', '\u{77f3}'), ('\u{7802}', '\u{7802}'), ('\u{7814}', '\u{7815}'), ('\u{7825}', '\u{7827}'),
+    ('\u{7832}', '\u{7832}'), ('\u{7834}', '\u{7834}'), ('\u{783a}', '\u{783a}'), ('\u{783f}', '\u{783f}'),
+    ('\u{785d}', '\u{785d}'), ('\u{786b}', '\u{786c}'), ('\u{786f}', '\u{786f}'), ('\u{7872}', '\u{7872}'),
+    ('\u{7881}', '\u{7881}'), ('\u{7887}', '\u{7887}'), ('\u{788d}', '\u{788d}'), ('\u{7891}', '\u{7891}'),
+    ('\u{7893}', '\u{7893}'), ('\u{7895}', '\u{7895}'), ('\u{7897}', '\u{7897}'), ('\u{78a7}', '\u{78a7}'),
+    ('\u{78a9}', '\u{78a9}'), ('\u{78ba}', '\u{78ba}'), ('\u{78c1}', '\u{78c1}'), ('\u{78d0}', '\u{78d0}'),
+    ('\u{78e8}', '\u{78e8}'), ('\u{78ef}', '\u{78ef}'), ('\u{7901}', '\u{7901}'), ('\u{790e}', '\u{790e}'),
+    ('\u{793a}', '\u{793a}'), ('\u{793c}', '\u{793c}'), ('\u{793e}', '\u{793e}'), ('\u{7941}', '\u{7941}'),
+    ('\u{7947}', '\u{7949}'), ('\u{7950}', '\u{7950}'), ('\u{7956}', '\u{7956}'), ('\u{795d}', '\u{795e}'),
+    ('\u{7962}', '\u{7962}'), ('\u{7965}', '\u{7965}'), ('\u{7968}', '\u{7968}'), ('\u{796d}', '\u{796d}'),
+    ('\u{7977}', '\u{7977}'), ('\u{7981}', '\u{7981}'), ('\u{7984}', '\u{7985}'), ('\u{798d}', '\u{798f}'),
+    ('\u{79a6}', '\u{79a6}'), ('\u{79b0}', '\u{79b0}'), ('\u{79bd}', '\u{79c1}'), ('\u{79cb}', '\u{79cb}'),
+    ('\u{79d1}', '\u{79d2}'), ('\u{79d8}', '\u{79d8}'), ('\u{79df}', '\u{79df}'), ('\u{79e4}', '\u{79e4}'),
+    ('\u{79e6}', '\u{79e6}'), ('\u{79e9}', '\u{79e9}'), ('\u{79f0}', '\u{79f0}'), ('\u{79fb}', '\u{79fb}'),
+    ('\u{7a00}', '\u{7a00}'), ('\u{7a0b}', '\u{7a0b}'), ('\u{7a0e}', '\u{7a0e}'), ('\u{7a14}', '\u{7a14}'),
+    ('\u{7a17}', '\u{7a17}'), ('\u{7a1a}', '\u{7a1a}'), ('\u{7a1c}', '\u{7a1c}'), ('\u{7a2e}', '\u{7a2e}'),
+    ('\u{7a32}', '\u{7a32}'), ('\u{7a3c}', '\u{7a3d}'), ('\u{7a3f}', '\u{7a40}'), ('\u{7a42}', '\u{7a42}'),
+    ('\u{7a46}', '\u{7a46}'), ('\u{7a4d}', '\u{7a50}'), ('\u{7a63}', '\u{7a63}'), ('\u{7a6b}', '\u{7a6b}'),
+    ('\u{7a74}', '\u{7a74}'), ('\u{7a76}', '\u{7a76}'), ('\u{7a7a}', '\u{7a7a}'), ('\u{7a7f}', '\u{7a7f}'),
+    ('\u{7a81}', '\u{7a81}'), ('\u{7a83}', '\u{7a84}'), ('\u{7a92}', '\u{7a93}'), ('\u{7a9f}', '\u{7a9f}'),
+    ('\u{7aaa}', '\u{7aaa}'), ('\u{7aae}', '\u{7aaf}'), ('\u{7aba}', '\u{7aba}'), ('\u{7ac3}', '\u{7ac3}'),
+    ('\u{7acb}', '\u{7acb}'), ('\u{7adc}', '\u{7adc}'), ('\u{7ae0}', '\u{7ae0}'), ('\u{7ae3}', '\u{7ae3}'),
+    ('\u{7ae5}', '\u{7ae5}'), ('\u{7aea}', '\u{7aea}'), ('\u{7aef}', '\u{7aef}'), ('\u{7af6}', '\u{7af6}'),
+    ('\u{7af9}', '\u{7afa}'), ('\u{7aff}', '\u{7aff}'), ('\u{7b08}', '\u{7b08}'), ('\u{7b11}', '\u{7b11}'),
+    ('\u{7b1b}', '\u{7b1b}'), ('\u{7b20}', '\u{7b20}'), ('\u{7b25}', '\u{7b26}'), ('\u{7b2c}', '\u{7b2c}'),
+    ('\u{7b39}', '\u{7b39}'), ('\u{7b46}', '\u{7b46}'), ('\u{7b48}', '\u{7b49}'), ('\u{7b4b}', '\u{7b4b}'),
+    ('\u{7b4f}', '\u{7b4f}'), ('\u{7b51}', '\u{7b52}'), ('\u{7b54}', '\u{7b54}'), ('\u{7b56}', '\u{7b56}'),
+    ('\u{7b86}', '\u{7b87}'), ('\u{7b94}', '\u{7b95}'), ('\u{7b97}', '\u{7b97}'), ('\u{7ba1}', '\u{7ba1}'),
+    ('\u{7baa}', '\u{7baa}'), ('\u{7bad}', '\u{7bad}'), ('\u{7bb1}', '\u{7bb1}'), ('\u{7bb8}', '\u{7bb8}'),
+    ('\u{7bc0}', '\u{7bc0}'), ('\u{7bc4}', '\u{7bc4}'), ('\u{7bc7}', '\u{7bc7}'), ('\u{7bc9}', '\u{7bc9}'),
+    ('\u{7be0}', '\u{7be0}'), ('\u{7be4}', '\u{7be4}'), ('\u{7bed}', '\u{7bed}'), ('\u{7c21}', '\u{7c21}'),
+    ('\u{7c38}', '\u{7c38}'), ('\u{7c3e}', '\u{7c3f}'), ('\u{7c4d}', '\u{7c4d}'), ('\u{7c73}', '\u{7c73}'),
+    ('\u{7c7e}', '\u{7c7e}'), ('\u{7c81}', '\u{7c82}'), ('\u{7c89}', '\u{7c89}'), ('\u{7c8b}', '\u{7c8b}'),
+    ('\u{7c8d}', '\u{7c8d}'), ('\u{7c92}', '\u{7c92}'), ('\u{7c95}', '\u{7c95}'), ('\u{7c97}', '\u{7c98}'),
+    ('\u{7c9b}', '\u{7c9b}'), ('\u{7c9f}', '\u{7c9f}'), ('\u{7ca5}', '\u{7ca5}'), ('\u{7ca7}', '\u{7ca7}'),
+    ('\u{7cbe}', '\u{7cbe}'), ('\u{7cca}', '\u{7cca}'), ('\u{7cce}', '\u{7cce}'), ('\u{7cd6}', '\u{7cd6}'),
+    ('\u{7cde}', '\u{7ce0}'), ('\u{7ce7}', '\u{7ce7}'), ('\u{7cf8}', '\u{7cf8}'), ('\u{7cfb}', '\u{7cfb}'),
+    ('\u{7cfe}', '\u{7cfe}'), ('\u{7d00}', '\u{7d00}'), ('\u{7d04}', '\u{7d05}'), ('\u{7d0b}', '\u{7d0b}'),
+    ('\u{7d0d}', '\u{7d0d}'), ('\u{7d10}', '\u{7d10}'), ('\u{7d14}', '\u{7d14}'), ('\u{7d17}', '\u{7d1b}'),
+    ('\u{7d20}', '\u{7d22}'), ('\u{7d2b}', '\u{7d2c}'), ('\u{7d2f}', '\u{7d30}'), ('\u{7d33}', '\u{7d33}'),
+    ('\u{7d39}', '\u{7d3a}'), ('\u{7d42}', '\u{7d44}'), ('\u{7d4c}', '\u{7d4c}'), ('\u{7d50}', '\u{7d50}'),
+    ('\u{7d5e}', '\u{7d5e}'), ('\u{7d61}', '\u{7d62}'), ('\u{7d66}', '\u{7d66}'), ('\u{7d71}', '\u{7d71}'),
+    ('\u{7d75}', '\u{7d76}'), ('\u{7d79}', '\u{7d79}'), ('\u{7d99}', '\u{7d9a}'), ('\u{7d9c}', '\u{7d9c}'),
+    ('\u{7dac}', '\u{7dad}'), ('\u{7db1}', '\u{7db2}'), ('\u{7db4}', '\u{7db4}'), ('\u{7dbb}', '\u{7dbb}'),
+    ('\u{7dbe}', '\u{7dbf}'), ('\u{7dca}', '\u{7dcb}'), ('\u{7dcf}', '\u{7dcf}'), ('\u{7dd1}', '\u{7dd2}'),
+    ('\u{7dda}', '\u{7dda}'), ('\u{7de0}', '\u{7de0}'), ('\u{7de8}', '\u{7de9}'), ('\u{7dec}', '\u{7dec}'),
+    ('\u{7def}', '\u{7def}'), ('\u{7df4}', '\u{7df4}'), ('\u{7e01}', '\u{7e01}'), ('\u{7e04}', '\u{7e04}'),
+    ('\u{7e1b}', '\u{7e1b}'), ('\u{7e1e}', '\u{7e1e}'), ('\u{7e26}', '\u{7e26}'), ('\u{7e2b}', '\u{7e2b}'),
+    ('\u{7e2e}', '\u{7e2e}'), ('\u{7e3e}', '\u{7e3e}'), ('\u{7e41}', '\u{7e41}'), ('\u{7e4a}', '\u{7e4b}'),
+    ('\u{7e4d}', '\u{7e4d}'), ('\u{7e54}', '\u{7e55}'), ('\u{7e6d}', '\u{7e6d}'), ('\u{7e70}', '\u{7e70}'),
+    ('\u{7e82}', '\u{7e82}'), ('\u{7e8f}', '\u{7e8f}'), ('\u{7f36}', '\u{7f36}'), ('\u{7f6a}', '\u{7f6b}'),
+    ('\u{7f6e}', '\u{7f6e}'), ('\u{7f70}', '\u{7f70}'), ('\u{7f72}', '\u{7f72}'), ('\u{7f75}', '\u{7f75}'),
+    ('\u{7f77}', '\u{7f77}'), ('\u{7f85}', '\u{7f85}'), ('\u{7f8a}', '\u{7f8a}'), ('\u{7f8e}', '\u{7f8e}'),
+    ('\u{7fa4}', '\u{7fa4}'), ('\u{7fa8}', '\u{7fa9}'), ('\u{7fbd}', '\u{7fbd}'), ('\u{7fc1}', '\u{7fc1}'),
+    ('\u{7fcc}', '\u{7fcc}'), ('\u{7fd2}', '\u{7fd2}'), ('\u{7fe0}', '\u{7fe0}'), ('\u{7feb}', '\u{7feb}'),
+    ('\u{7ff0}', '\u{7ff0}'), ('\u{7ffb}', '\u{7ffc}'), ('\u{8000}', '\u{8001}'), ('\u{8003}', '\u{8003}'),
+    ('\u{8005}', '\u{8005}'), ('\u{800c}', '\u{800c}'), ('\u{8010}', '\u{8010}'), ('\u{8015}', '\u{8015}'),
+    ('\u{8017}', '\u{8017}'), ('\u{8033}', '\u{8033}'), ('\u{8036}', '\u{8036}'), ('\u{803d}', '\u{803d}'),
+    ('\u{8056}', '\u{8056}'), ('\u{805e}', '\u{805e}'), ('\u{8061}', '\u{8061}'), ('\u{806f}', '\u{806f}'),
+    ('\u{8074}', '\u{8074}'), ('\u{8077}', '\u{8077}'), ('\u{807e}', '\u{807e}'), ('\u{8087}', '\u{8087}'),
+    ('\u{8089}', '\u{8089}'), ('\u{808b}', '\u{808c}'), ('\u{8096}', '\u{8096}'), ('\u{8098}', '\u{8098}'),
+    ('\u{809d}', '\u{809d}'), ('\u{80a1}', '\u{80a2}'), ('\u{80a5}', '\u{80a5}'), ('\u{80a9}', '\u{80aa}'),
+    ('\u{80af}', '\u{80af}'), ('\u{80b1}', '\u{80b2}'), ('\u{80b4}', '\u{80b4}'), ('\u{80ba}', '\u{80ba}'),
+    ('\u{80c3}', '\u{80c3}'), ('\u{80c6}', '\u{80c6}'), ('\u{80cc}', '\u{80cc}'), ('\u{80ce}', '\u{80ce}'),
+    ('\u{80de}', '\u{80de}'), ('\u{80e1}', '\u{80e1}'), ('\u{80e4}', '\u{80e4}'), ('\u{80f4}', '\u{80f4}'),
+    ('\u{80f8}', '\u{80f8}'), ('\u{80fd}', '\u{80fd}'), ('\u{8102}', '\u{8102}'), ('\u{8105}', '\u{8108}'),
+    ('\u{810a}', '\u{810a}'), ('\u{811a}', '\u{811a}'), ('\u{8131}', '\u{8131}'), ('\u{8133}', '\u{8133}'),
+    ('\u{8139}', '\u{8139}'), ('\u{814e}', '\u{814e}'), ('\u{8150}', '\u{8150}'), ('\u{8154}', '\u{8155}'),
+    ('\u{816b}', '\u{816b}'), ('\u{8170}', '\u{8170}'), ('\u{8178}', '\u{817a}'), ('\u{817f}', '\u{817f}'),
+    ('\u{818f}', '\u{818f}'), ('\u{819a}', '\u{819a}'), ('\u{819c}', '\u{819d}'), ('\u{81a8}', '\u{81a8}'),
+    ('\u{81b3}', '\u{81b3}'), ('\u{81bf}', '\u{81bf}'), ('\u{81c6}', '\u{81c6}'), ('\u{81d3}', '\u{81d3}'),
+    ('\u{81e3}', '\u{81e3}'), ('\u{81e5}', '\u{81e5}'), ('\u{81e8}', '\u{81e8}'), ('\u{81ea}', '\u{81ea}'),
+    ('\u{81ed}', '\u{81ed}'), ('\u{81f3}', '\u{81f4}'), ('\u{81fc}', '\u{81fc}'), ('\u{8208}', '\u{8208}'),
+    ('\u{820c}', '\u{820c}'), ('\u{820e}', '\u{820e}'), ('\u{8217}', '\u{8218}'), ('\u{821b}', '\u{821c}'),
+    ('\u{821e}', '\u{821f}'), ('\u{822a}', '\u{822a}'), ('\u{822c}', '\u{822c}'), ('\u{8235}', '\u{8237}'),
+    ('\u{8239}', '\u{8239}'), ('\u{8247}', '\u{8247}'), ('\u{8266}', '\u{8266}'), ('\u{826e}', '\u{826f}'),
+    ('\u{8272}', '\u{8272}'), ('\u{8276}', '\u{8276}'), ('\u{828b}', '\u{828b}'), ('\u{8299}', '\u{8299}'),
+    ('\u{829d}', '\u{829d}'), ('\u{82a5}', '\u{82a6}'), ('\u{82ad}', '\u{82ad}'), ('\u{82af}', '\u{82af}'),
+    ('\u{82b1}', '\u{82b1}'), ('\u{82b3}', '\u{82b3}'), ('\u{82b8}', '\u{82b9}'), ('\u{82bd}', '\u{82bd}'),
+    ('\u{82c5}', '\u{82c5}'), ('\u{82d1}', '\u{82d1}'), ('\u{82d3}', '\u{82d4}'), ('\u{82d7}', '\u{82d7}'),
+    ('\u{82db}', '\u{82db}'), ('\u{82e5}', '\u{82e7}'), ('\u{82eb}', '\u{82eb}'), ('\u{82f1}', '\u{82f1}'),
+    ('\u{8302}', '\u{8302}'), ('\u{8304}', '\u{8305}'), ('\u{830e}', '\u{830e}'), ('\u{831c}', '\u{831c}'),
+    ('\u{8328}', '\u{8328}'), ('\u{8336}', '\u{8336}'), ('\u{8338}', '\u{8338}'), ('\u{8349}', '\u{834a}'),
+    ('\u{834f}', '\u{834f}'), ('\u{8352}', '\u{8352}'), ('\u{8358}', '\u{8358}'), ('\u{8377}', '\u{8377}'),
+    ('\u{837b}', '\u{837b}'), ('\u{839e}', '\u{839e}'), ('\u{83ab}', '\u{83ab}'), ('\u{83b1}', '\u{83b1}'),
+    ('\u{83c5}', '\u{83c5}'), ('\u{83ca}', '\u{83ca}'), ('\u{83cc}', '\u{83cc}'), ('\u{83d3}', '\u{83d3}'),
+    ('\u{83d6}', '\u{83d6}'), ('\u{83dc}', '\u{83dc}'), ('\u{83df}', '\u{83df}'), ('\u{83e9}', '\u{83e9}'),
+    ('\u{83ef}', '\u{83f1}'), ('\u{8404}', '\u{8404}'), ('\u{840c}', '\u{840c}'), ('\u{840e}', '\u{840e}'),
+    ('\u{8429}', '\u{8429}'), ('\u{8431}', '\u{8431}'), ('\u{843d}', '\u{843d}'), ('\u{8449}', '\u{8449}'),
+    ('\u{844e}', '\u{844e}'), ('\u{8457}', '\u{8457}'), ('\u{845b}', '\u{845b}'), ('\u{8461}', '\u{8461}'),
+    ('\u{8463}', '\u{8463}'), ('\u{8466}', '\u{8466}'), ('\u{846c}', '\u{846c}'), ('\u{8471}', '\u{8471}'),
+    ('\u{8475}', '\u{8475}'), ('\u{847a}', '\u{847a}'), ('\u{848b}', '\u{848b}'), ('\u{8490}', '\u{8490}'),
+    ('\u{8494}', '\u{8494}'), ('\u{8499}', '\u{8499}'), ('\u{849c}', '\u{849c}'), ('\u{84b2}', '\u{84b2}'),
+    ('\u{84b8}', '\u{84b8}'), ('\u{84bc}', '\u{84bc}'), ('\u{84c4}', '\u{84c4}'), ('\u{84c9}', '\u{84c9}'),
+    ('\u{84cb}', '\u{84cb}'), ('\u{84d1}', '\u{84d1}'), ('\u{84ec}', '\u{84ec}'), ('\u{84ee}', '\u{84ee}'),
+    ('\u{8500}', '\u{8500}'), ('\u{8511}', '\u{8511}'), ('\u{8513}', '\u{8513}'), ('\u{851a}', '\u{851a}'),
+    ('\u{8526}', '\u{8526}'), ('\u{852d}', '\u{852d}'), ('\u{8535}', '\u{8535}'), ('\u{853d}', '\u{853d}'),
+    ('\u{8543}', '\u{8543}'), ('\u{8549}', '\u{854a}'), ('\u{854e}', '\u{854e}'), ('\u{8557}', '\u{8557}'),
+    ('\u{8568}', '\u{856a}'), ('\u{8584}', '\u{8584}'), ('\u{8597}', '\u{8597}'), ('\u{8599}', '\u{8599}'),
+    ('\u{85a6}', '\u{85a6}'), ('\u{85a9}', '\u{85ac}'), ('\u{85ae}', '\u{85af}'), ('\u{85c1}', '\u{85c1}'),
+    ('\u{85cd}', '\u{85cd}'), ('\u{85e4}', '\u{85e4}'), ('\u{85e9}', '\u{85e9}'), ('\u{85f7}', '\u{85f7}'),
+    ('\u{85fb}', '\u{85fb}'), ('\u{8607}', '\u{8607}'), ('\u{862d}', '\u{862d}'), ('\u{864e}', '\u{864e}'),
+    ('\u{8650}', '\u{8650}'), ('\u{865a}', '\u{865a}'), ('\u{865c}', '\u{865c}'), ('\u{865e}', '\u{865e}'),
+    ('\u{866b}', '\u{866b}'), ('\u{8679}', '\u{8679}'), ('\u{867b}', '\u{867b}'), ('\u{868a}', '\u{868a}'),
+    ('\u{8695}', '\u{8695}'), ('\u{86a4}', '\u{86a4}'), ('\u{86c7}', '\u{86c7}'), ('\u{86cb}', '\u{86cb}'),
+    ('\u{86cd}', '\u{86ce}'), ('\u{86d9}', '\u{86d9}'), ('\u{86e4}', '\u{86e4}'), ('\u{86ed}', '\u{86ee}'),
+    ('\u{86f8}', '\u{86f8}'), ('\u{86fe}', '\u{86fe}'), ('\u{8702}', '\u{8702}'), ('\u{8718}', '\u{8718}'),
+    ('\u{871c}', '\u{871c}'), ('\u{8749}', '\u{8749}'), ('\u{874b}', '\u{874b}'), ('\u{8755}', '\u{8755}'),
+    ('\u{8766}', '\u{8766}'), ('\u{8776}', '\u{8776}'), ('\u{877f}', '\u{877f}'), ('\u{878d}', '\u{878d}'),
+    ('\u{87ba}', '\u{87ba}'), ('\u{87f9}', '\u{87f9}'), ('\u{87fb}', '\u{87fb}'), ('\u{8840}', '\u{8840}'),
+    ('\u{8846}', '\u{8846}'), ('\u{884c}', '\u{884c}'), ('\u{8853}', '\u{8853}'), ('\u{8857}', '\u{8857}'),
+    ('\u{885b}', '\u{885b}'), ('\u{885d}', '\u{885d}'), ('\u{8861}', '\u{8861}'), ('\u{8863}', '\u{8863}'),
+    ('\u{8868}', '\u{8868}'), ('\u{8870}', '\u{8870}'), ('\u{8877}', '\u{8877}'), ('\u{887f}', '\u{887f}'),
+    ('\u{8888}', '\u{8888}'), ('\u{888b}', '\u{888b}'), ('\u{8896}', '\u{8896}'), ('\u{88ab}', '\u{88ab}'),
+    ('\u{88b4}', '\u{88b4}'), ('\u{88b7}', '\u{88b7}'), ('\u{88c1}', '\u{88c2}'), ('\u{88c5}', '\u{88c5}'),
+    ('\u{88cf}', '\u{88cf}'), ('\u{88d5}', '\u{88d5}'), ('\u{88dc}', '\u{88dc}'), ('\u{88df}', '\u{88df}'),
+    ('\u{88e1}', '\u{88e1}'), ('\u{88f3}', '\u{88f3}'), ('\u{88f8}', '\u{88f8}'), ('\u{88fd}', '\u{88fe}'),
+    ('\u{8907}', '\u{8907}'), ('\u{8910}', '\u{8910}'), ('\u{8912}', '\u{8912}'), ('\u{8956}', '\u{8956}'),
+    ('\u{895f}', '\u{895f}'), ('\u{8972}', '\u{8972}'), ('\u{897f}', '\u{897f}'), ('\u{8981}', '\u{8981}'),
+    ('\u{8986}', '\u{8987}'), ('\u{898b}', '\u{898b}'), ('\u{898f}', '\u{898f}'), ('\u{8996}', '\u{8997}'),
+    ('\u{899a}', '\u{899a}'), ('\u{89a7}', '\u{89a7}'), ('\u{89aa}', '\u{89aa}'), ('\u{89b3}', '\u{89b3}'),
+    ('\u{89d2}', '\u{89d2}'), ('\u{89e3}', '\u{89e3}'), ('\u{89e6}', '\u{89e6}'), ('\u{8a00}', '\u{8a00}'),
+    ('\u{8a02}', '\u{8a02}'), ('\u{8a08}', '\u{8a08}'), ('\u{8a0a}', '\u{8a0a}'), ('\u{8a0e}', '\u{8a0e}'),
+    ('\u{8a13}', '\u{8a13}'), ('\u{8a17}', '\u{8a18}'), ('\u{8a1f}', '\u{8a1f}'), ('\u{8a23}', '\u{8a23}'),
+    ('\u{8a2a}', '\u{8a2a}'), ('\u{8a2d}', '\u{8a2d}'), ('\u{8a31}', '\u{8a31}'), ('\u{8a33}', '\u{8a34}'),
+    ('\u{8a3a}', '\u{8a3c}'), ('\u{8a50}', '\u{8a51}'), ('\u{8a54}', '\u{8a55}'), ('\u{8a5e}', '\u{8a5e}'),
+    ('\u{8a60}', '\u{8a60}'), ('\u{8a63}', '\u{8a63}'), ('\u{8a66}', '\u{8a66}'), ('\u{8a69}', '\u{8a69}'),
+    ('\u{8a6b}', '\u{8a6b}'), ('\u{8a6e}', '\u{8a6e}'), ('\u{8a70}', '\u{8a73}'), ('\u{8a87}', '\u{8a87}'),
+    ('\u{8a89}', '\u{8a89}'), ('\u{8a8c}', '\u{8a8d}'), ('\u{8a93}', '\u{8a93}'), ('\u{8a95}', '\u{8a95}'),
+    ('\u{8a98}', '\u{8a98}'), ('\u{8a9e}', '\u{8a9e}'), ('\u{8aa0}', '\u{8aa0}'), ('\u{8aa4}', '\u{8aa4}'),
+    ('\u{8aac}', '\u{8aad}'), ('\u{8ab0}', '\u{8ab0}'), ('\u{8ab2}', '\u{8ab2}'), ('\u{8ab9}', '\u{8ab9}'),
+    ('\u{8abc}', '\u{8abc}'), ('\u{8abf}', '\u{8abf}'), ('\u{8ac7}', '\u{8ac7}'), ('\u{8acb}', '\u{8acc}'),
+    ('\u{8acf}', '\u{8acf}'), ('\u{8ad2}', '\u{8ad2}'), ('\u{8ad6}', '\u{8ad6}'), ('\u{8adc}', '\u{8adc}'),
+    ('\u{8ae6}', '\u{8ae6}'), ('\u{8aed}', '\u{8aee}'), ('\u{8af8}', '\u{8af8}'), ('\u{8afa}', '\u{8afa}'),
+    ('\u{8afe}', '\u{8afe}'), ('\u{8b00}', '\u{8b02}'), ('\u{8b04}', '\u{8b04}'), ('\u{8b0e}', '\u{8b0e}'),
+    ('\u{8b19}', '\u{8b19}'), ('\u{8b1b}', '\u{8b1b}'), ('\u{8b1d}', '\u{8b1d}'), ('\u{8b21}', '\u{8b21}'),
+    ('\u{8b2c}', '\u{8b2c}'), ('\u{8b39}', '\u{8b39}'), ('\u{8b58}', '\u{8b58}'), ('\u{8b5c}', '\u{8b5c}'),
+    ('\u{8b66}', '\u{8b66}'), ('\u{8b70}', '\u{8b70}'), ('\u{8b72}', '\u{8b72}'), ('\u{8b77}', '\u{8b77}'),
+    ('\u{8b83}', '\u{8b83}'), ('\u{8b90}', '\u{8b90}'), ('\u{8c37}', '\u{8c37}'), ('\u{8c46}', '\u{8c46}'),
+    ('\u{8c4a}', '\u{8c4a}'), ('\u{8c5a}', '\u{8c5a}'), ('\u{8c61}', '\u{8c61}'), ('\u{8c6a}', '\u{8c6a}'),
+    ('\u{8c79}', '\u{8c79}'), ('\u{8c8c}', '\u{8c8c}'), ('\u{8c9d}', '\u{8c9e}'), ('\u{8ca0}', '\u{8ca2}'),
+    ('\u{8ca7}', '\u{8ca9}'), ('\u{8cab}', '\u{8cac}'), ('\u{8caf}', '\u{8cb0}'), ('\u{8cb4}', '\u{8cb4}'),
+    ('\u{8cb7}', '\u{8cb8}'), ('\u{8cbb}', '\u{8cbc}'), ('\u{8cbf}', '\u{8cc0}'), ('\u{8cc2}', '\u{8cc4}'),
+    ('\u{8cc7}', '\u{8cc7}'), ('\u{8cca}', '\u{8cca}'), ('\u{8cce}', '\u{8cce}'), ('\u{8cd1}', '\u{8cd1}'),
+    ('\u{8cd3}', '\u{8cd3}'), ('\u{8cdb}', '\u{8cdc}'), ('\u{8cde}', '\u{8cde}'), ('\u{8ce0}', '\u{8ce0}'),
+    ('\u{8ce2}', '\u{8ce2}'), ('\u{8ce6}', '\u{8ce6}'), ('\u{8cea}', '\u{8cea}'), ('\u{8ced}', '\u{8ced}'),
+    ('\u{8cfc}', '\u{8cfc}'), ('\u{8d08}', '\u{8d08}'), ('\u{8d0b}', '\u{8d0b}'), ('\u{8d64}', '\u{8d64}'),
+    ('\u{8d66}', '\u{8d66}'), ('\u{8d6b}', '\u{8d6b}'), ('\u{8d70}', '\u{8d70}'), ('\u{8d74}', '\u{8d74}'),
+    ('\u{8d77}', '\u{8d77}'), ('\u{8d85}', '\u{8d85}'), ('\u{8d8a}', '\u{8d8a}'), ('\u{8da3}', '\u{8da3}'),
+    ('\u{8da8}', '\u{8da8}'), ('\u{8db3}', '\u{8db3}'), ('\u{8ddd}', '\u{8ddd}'), ('\u{8de1}', '\u{8de1}'),
+    ('\u{8de8}', '\u{8de8}'), ('\u{8def}', '\u{8def}'), ('\u{8df3}', '\u{8df3}'), ('\u{8df5}', '\u{8df5}'),
+    ('\u{8e0a}', '\u{8e0a}'), ('\u{8e0f}', '\u{8e0f}'), ('\u{8e44}', '\u{8e44}'), ('\u{8e5f}', '\u{8e5f}'),
+    ('\u{8e74}', '\u{8e74}'), ('\u{8e8d}', '\u{8e8d}'), ('\u{8eab}', '\u{8eab}'), ('\u{8eaf}', '\u{8eaf}'),
+    ('\u{8eca}', '\u{8eca}'), ('\u{8ecc}', '\u{8ecd}'), ('\u{8ed2}', '\u{8ed2}'), ('\u{8edf}', '\u{8edf}'),
+    ('\u{8ee2}', '\u{8ee2}'), ('\u{8ef8}', '\u{8ef8}'), ('\u{8efd}', '\u{8efd}'), ('\u{8f03}', '\u{8f03}'),
+    ('\u{8f09}', '\u{8f09}'), ('\u{8f14}', '\u{8f14}'), ('\u{8f1d}', '\u{8f1d}'), ('\u{8f29}', '\u{8f2a}'),
+    ('\u{8f2f}', '\u{8f2f}'), ('\u{8f38}', '\u{8f38}'), ('\u{8f3f}', '\u{8f3f}'), ('\u{8f44}', '\u{8f44}'),
+    ('\u{8f4d}', '\u{8f4d}'), ('\u{8f5f}', '\u{8f5f}'), ('\u{8f61}', '\u{8f61}'), ('\u{8f9b}', '\u{8f9b}'),
+    ('\u{8f9e}', '\u{8f9e}'), ('\u{8fb0}', '\u{8fb2}'), ('\u{8fba}', '\u{8fbc}'), ('\u{8fbf}', '\u{8fbf}'),
+    ('\u{8fc2}', '\u{8fc2}'), ('\u{8fc4}', '\u{8fc5}'), ('\u{8fce}', '\u{8fce}'), ('\u{8fd1}', '\u{8fd1}'),
+    ('\u{8fd4}', '\u{8fd4}'), ('\u{8fe6}', '\u{8fe6}'), ('\u{8fe9}', '\u{8fe9}'), ('\u{8feb}', '\u{8feb}'),
+    ('\u{8fed}', '\u{8fed}'), ('\u{8ff0}', '\u{8ff0}'), ('\u{8ff7}', '\u{8ff7}'), ('\u{8ffd}', '\u{8ffd}'),
+    ('\u{9000}', '\u{9001}'), ('\u{9003}', '\u{9003}'), ('\u{9006}', '\u{9006}'), ('\u{900f}', '\u{9010}'),
+    ('\u{9013}', '\u{9014}'), ('\u{9017}', '\u{9017}'), ('\u{9019}', '\u{901a}'), ('\u{901d}', '\u{901d}'),
+    ('\u{901f}', '\u{9020}'), ('\u{9022}', '\u{9023}'), ('\u{902e}', '\u{902e}'), ('\u{9031}', '\u{9032}'),
+    ('\u{9038}', '\u{9038}'), ('\u{903c}', '\u{903c}'), ('\u{9041}', '\u{9042}'), ('\u{9045}', '\u{9045}'),
+    ('\u{9047}', '\u{9047}'), ('\u{904a}', '\u{904b}'), ('\u{904d}', '\u{904e}'), ('\u{9053}', '\u{9055}'),
+    ('\u{905c}', '\u{905c}'), ('\u{9060}', '\u{9061}'), ('\u{9063}', '\u{9063}'), ('\u{9065}', '\u{9065}'),
+    ('\u{9069}', '\u{9069}'), ('\u{906d}', '\u{906e}'), ('\u{9075}', '\u{9075}'), ('\u{9077}', '\u{9078}'),
+    ('\u{907a}', '\u{907a}'), ('\u{907c}', '\u{907c}'), ('\u{907f}', '\u{907f}'), ('\u{9084}', '\u{9084}'),
+    ('\u{9091}', '\u{9091}'), ('\u{90a3}', '\u{90a3}'), ('\u{90a6}', '\u{90a6}'), ('\u{90aa}', '\u{90aa}'),
+    ('\u{90b8}', '\u{90b8}'), ('\u{90c1}', '\u{90c1}'), ('\u{90ca}', '\u{90ca}'), ('\u{90ce}', '\u{90ce}'),
+    ('\u{90e1}', '\u{90e1}'), ('\u{90e8}', '\u{90e8}'), ('\u{90ed}', '\u{90ed}'), ('\u{90f5}', '\u{90f5}'),
+    ('\u{90f7}', '\u{90f7}'), ('\u{90fd}', '\u{90fd}'), ('\u{912d}', '\u{912d}'), ('\u{9149}', '\u{9149}'),
+    ('\u{914b}', '\u{914e}'), ('\u{9152}', '\u{9152}'), ('\u{9154}', '\u{9154}'), ('\u{9162}', '\u{9162}'),
+    ('\u{916a}', '\u{916a}'), ('\u{916c}', '\u{916c}'), ('\u{9175}', '\u{9175}'), ('\u{9177}', '\u{9178}'),
+    ('\u{9187}', '\u{9187}'), ('\u{918d}', '\u{918d}'), ('\u{9190}', '\u{9190}'), ('\u{9192}', '\u{9192}'),
+    ('\u{9197}', '\u{9197}'), ('\u{919c}', '\u{919c}'), ('\u{91a4}', '\u{91a4}'), ('\u{91b8}', '\u{91b8}'),
+    ('\u{91c6}', '\u{91c8}'), ('\u{91cc}', '\u{91cf}'), ('\u{91d1}', '\u{91d1}'), ('\u{91d8}', '\u{91d8}'),
+    ('\u{91dc}', '\u{91dd}'), ('\u{91e3}', '\u{91e3}'), ('\u{91e6}', '\u{91e7}'), ('\u{920d}', '\u{920e}'),
+    ('\u{9234}', '\u{9234}'), ('\u{9237}', '\u{9237}'), ('\u{9244}', '\u{9244}'), ('\u{925b}', '\u{925b}'),
+    ('\u{9262}', '\u{9262}'), ('\u{9266}', '\u{9266}'), ('\u{9271}', '\u{9271}'), ('\u{927e}', '\u{927e}'),
+    ('\u{9280}', '\u{9280}'), ('\u{9283}', '\u{9283}'), ('\u{9285}', '\u{9285}'), ('\u{9291}', '\u{9291}'),
+    ('\u{9298}', '\u{9298}'), ('\u{929a}', '\u{929a}'), ('\u{92ad}', '\u{92ad}'), ('\u{92d2}', '\u{92d2}'),
+    ('\u{92e4}', '\u{92e4}'), ('\u{92ea}', '\u{92ea}'), ('\u{92ed}', '\u{92ed}'), ('\u{92f2}', '\u{92f3}'),
+    ('\u{92f8}', '\u{92f8}'), ('\u{92fc}', '\u{92fc}'), ('\u{9306}', '\u{9306}'), ('\u{9310}', '\u{9310}'),
+    ('\u{9318}', '\u{9318}'), ('\u{9320}', '\u{9320}'), ('\u{9326}', '\u{9326}'), ('\u{9328}', '\u{9328}'),
+    ('\u{932b}', '\u{932c}'), ('\u{932f}', '\u{932f}'), ('\u{9332}', '\u{9332}'), ('\u{934b}', '\u{934b}'),
+    ('\u{934d}', '\u{934d}'), ('\u{9354}', '\u{9354}'), ('\u{935b}', '\u{935b}'), ('\u{936c}', '\u{936c}'),
+    ('\u{9375}', '\u{9375}'), ('\u{937e}', '\u{937e}'), ('\u{938c}', '\u{938c}'), ('\u{9396}', '\u{9397}'),
+    ('\u{939a}', '\u{939a}'), ('\u{93a7}', '\u{93a7}'), ('\u{93ae}', '\u{93ae}'), ('\u{93d1}', '\u{93d1}'),
+    ('\u{93e1}', '\u{93e1}'), ('\u{9418}', '\u{9419}'), ('\u{9438}', '\u{9438}'), ('\u{9451}', '\u{9451}'),
+    ('\u{9453}', '\u{9453}'), ('\u{9577}', '\u{9577}'), ('\u{9580}', '\u{9580}'), ('\u{9583}', '\u{9583}'),
+    ('\u{9589}', '\u{9589}'), ('\u{958b}', '\u{958b}'), ('\u{958f}', '\u{958f}'), ('\u{9591}', '\u{9591}'),
+    ('\u{9593}', '\u{9593}'), ('\u{95a2}', '\u{95a5}'), ('\u{95b2}', '\u{95b2}'), ('\u{95c7}', '\u{95c7}'),
+    ('\u{95d8}', '\u{95d8}'), ('\u{961c}', '\u{961c}'), ('\u{962a}', '\u{962a}'), ('\u{9632}', '\u{9632}'),
+    ('\u{963b}', '\u{963b}'), ('\u{963f}', '\u{9640}'), ('\u{9644}', '\u{9644}'), ('\u{964d}', '\u{964d}'),
+    ('\u{9650}', '\u{9650}'), ('\u{965b}', '\u{965b}'), ('\u{9662}', '\u{9665}'), ('\u{966a}', '\u{966a}'),
+    ('\u{9670}', '\u{9670}'), ('\u{9673}', '\u{9673}'), ('\u{9675}', '\u{9676}'), ('\u{9678}', '\u{9678}'),
+    ('\u{967a}', '\u{967a}'), ('\u{967d}', '\u{967d}'), ('\u{9685}', '\u{9686}'), ('\u{9688}', '\u{9688}'),
+    ('\u{968a}', '\u{968a}'), ('\u{968e}', '\u{968f}'), ('\u{9694}', '\u{9694}'), ('\u{9699}', '\u{9699}'),
+    ('\u{969b}', '\u{969c}'), ('\u{96a0}', '\u{96a0}'), ('\u{96a3}', '\u{96a3}'), ('\u{96b7}', '\u{96b7}'),
+    ('\u{96bb}', '\u{96bc}'), ('\u{96c0}', '\u{96c1}'), ('\u{96c4}', '\u{96c7}'), ('\u{96cc}', '\u{96cc}'),
+    ('\u{96d1}', '\u{96d1}'), ('\u{96db}', '\u{96db}'), ('\u{96e2}', '\u{96e3}'), ('\u{96e8}', '\u{96e8}'),
+    ('\u{96ea}', '\u{96eb}'), ('\u{96f0}', '\u{96f0}'), ('\u{96f2}', '\u{96f2}'), ('\u{96f6}', '\u{96f7}'),
+    ('\u{96fb}', '\u{96fb}'), ('\u{9700}', '\u{9700}'), ('\u{9707}', '\u{9707}'), ('\u{970a}', '\u{970a}'),
+    ('\u{971c}', '\u{971c}'), ('\u{971e}', '\u{971e}'), ('\u{9727}', '\u{9727}'), ('\u{9732}', '\u{9732}'),
+    ('\u{9752}', '\u{9752}'), ('\u{9756}', '\u{9756}'), ('\u{9759}', '\u{9759}'), ('\u{975e}', '\u{975e}'),
+    ('\u{9762}', '\u{9762}'), ('\u{9769}', '\u{9769}'), ('\u{976d}', '\u{976d}'), ('\u{9774}', '\u{9774}'),
+    ('\u{9784}', '\u{9784}'), ('\u{978d}', '\u{978d}'), ('\u{9798}', '\u{9798}'), ('\u{97a0}', '\u{97a0}'),
+    ('\u{97ad}', '\u{97ad}'), ('\u{97d3}', '\u{97d3}'), ('\u{97ee}', '\u{97ee}'), ('\u{97f3}', '\u{97f3}'),
+    ('\u{97fb}', '\u{97fb}'), ('\u{97ff}', '\u{97ff}'), ('\u{9801}', '\u{9803}'), ('\u{9805}', '\u{9806}'),
+    ('\u{9808}', '\u{9808}'), ('\u{9810}', '\u{9813}'), ('\u{9817}', '\u{9818}'), ('\u{981a}', '\u{981a}'),
+    ('\u{982c}', '\u{982d}'), ('\u{9834}', '\u{9834}'), ('\u{983b}', '\u{983c}'), ('\u{984c}', '\u{984e}'),
+    ('\u{9854}', '\u{9855}'), ('\u{9858}', '\u{9858}'), ('\u{985b}', '\u{985b}'), ('\u{985e}', '\u{985e}'),
+    ('\u{9867}', '\u{9867}'), ('\u{98a8}', '\u{98a8}'), ('\u{98db}', '\u{98db}'), ('\u{98df}', '\u{98df}'),
+    ('\u{98e2}', '\u{98e2}'), ('\u{98ef}', '\u{98ef}'), ('\u{98f2}', '\u{98f2}'), ('\u{98f4}', '\u{98f4}'),
+    ('\u{98fc}', '\u{98fe}'), ('\u{9905}', '\u{9905}'), ('\u{990a}', '\u{990a}'), ('\u{990c}', '\u{990c}'),
+    ('\u{9910}', '\u{9910}'), ('\u{9913}', '\u{9913}'), ('\u{9928}', '\u{9928}'), ('\u{9957}', '\u{9957}'),
+    ('\u{9996}', '\u{9996}'), ('\u{9999}', '\u{9999}'), ('\u{99a8}', '\u{99a8}'), ('\u{99ac}', '\u{99ac}'),
+    ('\u{99b3}', '\u{99b4}'), ('\u{99c1}', '\u{99c1}'), ('\u{99c4}', '\u{99c6}'), ('\u{99c8}', '\u{99c8}'),
+    ('\u{99d0}', '\u{99d0}'), ('\u{99d2}', '\u{99d2}'), ('\u{99d5}', '\u{99d5}'), ('\u{99ff}', '\u{99ff}'),
+    ('\u{9a0e}', '\u{9a0e}'), ('\u{9a12}', '\u{9a13}'), ('\u{9a28}', '\u{9a28}'), ('\u{9a30}', '\u{9a30}'),
+    ('\u{9a5a}', '\u{9a5a}'), ('\u{9aa8}', '\u{9aa8}'), ('\u{9ab8}', '\u{9ab8}'), ('\u{9ac4}', '\u{9ac4}'),
+    ('\u{9ad8}', '\u{9ad8}'), ('\u{9aea}', '\u{9aea}'), ('\u{9aed}', '\u{9aed}'), ('\u{9b3c}', '\u{9b3c}'),
+    ('\u{9b41}', '\u{9b42}'), ('\u{9b45}', '\u{9b45}'), ('\u{9b54}', '\u{9b54}'), ('\u{9b5a}', '\u{9b5a}'),
+    ('\u{9b6f}', '\u{9b6f}'), ('\u{9b8e}', '\u{9b8e}'), ('\u{9b92}', '\u{9b92}'), ('\u{9baa}', '\u{9bab}'),
+    ('\u{9bad}', '\u{9bae}'), ('\u{9bc9}', '\u{9bc9}'), ('\u{9bd6}', '\u{9bd6}'), ('\u{9bdb}', '\u{9bdb}'),
+    ('\u{9be8}', '\u{9be8}'), ('\u{9bf5}', '\u{9bf5}'), ('\u{9c0d}', '\u{9c0d}'), ('\u{9c10}', '\u{9c10}'),
+    ('\u{9c2d}', '\u{9c2d}'), ('\u{9c2f}', '\u{9c2f}'), ('\u{9c39}', '\u{9c39}'), ('\u{9c3b}', '\u{9c3b}'),
+    ('\u{9c48}', '\u{9c48}'), ('\u{9c52}', '\u{9c52}'), ('\u{9c57}', '\u{9c57}'), ('\u{9ce5}', '\u{9ce5}'),
+    ('\u{9ce9}', '\u{9ce9}'), ('\u{9cf3}', '\u{9cf4}'), ('\u{9cf6}', '\u{9cf6}'), ('\u{9d07}', '\u{9d07}'),
+    ('\u{9d0e}', '\u{9d0e}'), ('\u{9d1b}', '\u{9d1b}'), ('\u{9d28}', '\u{9d28}'), ('\u{9d2b}', '\u{9d2c}'),
+    ('\u{9d3b}', '\u{9d3b}'), ('\u{9d5c}', '\u{9d5c}'), ('\u{9d60}', '\u{9d61}'), ('\u{9d6c}', '\u{9d6c}'),
+    ('\u{9d8f}', '\u{9d8f}'), ('\u{9db4}', '\u{9db4}'), ('\u{9df2}', '\u{9df2}'), ('\u{9df9}', '\u{9dfa}'),
+    ('\u{9e78}', '\u{9e78}'), ('\u{9e7f}', '\u{9e7f}'), ('\u{9e93}', '\u{9e93}'), ('\u{9e97}', '\u{9e97}'),
+    ('\u{9e9f}', '\u{9e9f}'), ('\u{9ea6}', '\u{9ea6}'), ('\u{9eb9}', '\u{9ebb}'), ('\u{9ebf}', '\u{9ebf}'),
+    ('\u{9ec4}', '\u{9ec4}'), ('\u{9ecd}', '\u{9ecd}'), ('\u{9ed2}', '\u{9ed2}'), ('\u{9ed9}', '\u{9ed9}'),
+    ('\u{9edb}', '\u{9edb}'), ('\u{9f0e}', '\u{9f0e}'), ('\u{9f13}', '\u{9f13}'), ('\u{9f20}', '\u{9f20}'),
+    ('\u{9f3b}', '\u{9f3b}'), ('\u{9f62}', '\u{9f62}'), ('\u{9f8d}', '\u{9f8d}'),
+];
+
+pub const JIS_LEVEL2: &[(char, char)] = &[
+    ('\u{4e10}', '\u{4e10}'), ('\u{4e15}', '\u{4e15}'), ('\u{4e17}', '\u{4e17}'), ('\u{4e2a}', '\u{4e2a}'),
+    ('\u{4e31}', '\u{4e31}'), ('\u{4e36}', '\u{4e36}'), ('\u{4e3c}', '\u{4e3c}'), ('\u{4e3f}', '\u{4e3f}'),
+    ('\u{4e42}', '\u{4e42}'), ('\u{4e55}', '\u{4e56}'), ('\u{4e58}', '\u{4e58}'), ('\u{4e62}', '\u{4e62}'),
+    ('\u{4e82}', '\u{4e82}'), ('\u{4e85}', '\u{4e85}'), ('\u{4e8a}', '\u{4e8a}'), ('\u{4e8e}', '\u{4e8e}'),
+    ('\u{4e9e}', '\u{4ea0}'), ('\u{4ea2}', '\u{4ea2}'), ('\u{4eb0}', '\u{4eb0}'), ('\u{4eb3}', '\u{4eb3}'),
+    ('\u{4eb6}', '\u{4eb6}'), ('\u{4ec2}', '\u{4ec2}'), ('\u{4ec4}', '\u{4ec4}'), ('\u{4ec6}', '\u{4ec6}'),
+    ('\u{4ecd}', '\u{4ece}'), ('\u{4ed7}', '\u{4ed7}'), ('\u{4ede}', '\u{4edf}'), ('\u{4eed}', '\u{4eed}'),
+    ('\u{4ef7}', '\u{4ef7}'), ('\u{4f09}', '\u{4f09}'), ('\u{4f1c}', '\u{4f1c}'), ('\u{4f30}', '\u{4f30}'),
+    ('\u{4f47}', '\u{4f47}'), ('\u{4f57}', '\u{4f57}'), ('\u{4f5a}', '\u{4f5b}'), ('\u{4f5d}', '\u{4f5e}'),
+    ('\u{4f69}', '\u{4f69}'), ('\u{4f6f}', '\u{4f70}'), ('\u{4f76}', '\u{4f76}'), ('\u{4f7b}', '\u{4f7b}'),
+    ('\u{4f86}', '\u{4f86}'), ('\u{4f88}', '\u{4f88}'), ('\u{4f8f}', '\u{4f8f}'), ('\u{4f91}', '\u{4f91}'),
+    ('\u{4f96}', '\u{4f96}'), ('\u{4f98}', '\u{4f98}'), ('\u{4fab}', '\u{4fab}'), ('\u{4fce}', '\u{4fce}'),
+    ('\u{4fd0}', '\u{4fd1}'), ('\u{4fd4}', '\u{4fd4}'), ('\u{4fd8}', '\u{4fd8}'), ('\u{4fda}', '\u{4fdb}'),
+    ('\u{4fdf}', '\u{4fdf}'), ('\u{4fe4}', '\u{4fe5}'), ('\u{4fef}', '\u{4fef}'), ('\u{4ff6}', '\u{4ff6}'),
+    ('\u{4ffe}', '\u{4ffe}'), ('\u{5005}', '\u{5006}'), ('\u{500f}', '\u{500f}'), ('\u{5011}', '\u{5011}'),
+    ('\u{5014}', '\u{5014}'), ('\u{501a}', '\u{501a}'), ('\u{5021}', '\u{5021}'), ('\u{5025}', '\u{5025}'),
+    ('\u{5028}', '\u{502a}'), ('\u{502c}', '\u{502c}'), ('\u{5043}', '\u{5043}'), ('\u{5047}', '\u{5048}'),
+    ('\u{5050}', '\u{5050}'), ('\u{5055}', '\u{5056}'), ('\u{505a}', '\u{505a}'), ('\u{506c}', '\u{506c}'),
+    ('\u{5078}', '\u{5078}'), ('\u{5080}', '\u{5080}'), ('\u{5085}', '\u{5085}'), ('\u{509a}', '\u{509a}'),
+    ('\u{50b2}', '\u{50b4}'), ('\u{50c2}', '\u{50c2}'), ('\u{50c9}', '\u{50ca}'), ('\u{50d6}', '\u{50d6}'),
+    ('\u{50de}', '\u{50de}'), ('\u{50e3}', '\u{50e3}'), ('\u{50e5}', '\u{50e5}'), ('\u{50ed}', '\u{50ee}'),
+    ('\u{50f5}', '\u{50f5}'), ('\u{50f9}', '\u{50f9}'), ('\u{5101}', '\u{5102}'), ('\u{5109}', '\u{5109}'),
+    ('\u{5114}', '\u{5116}'), ('\u{5118}', '\u{5118}'), ('\u{511a}', '\u{511a}'), ('\u{5121}', '\u{5121}'),
+    ('\u{5137}', '\u{5137}'), ('\u{513a}', '\u{513c}'), ('\u{513f}', '\u{5140}'), ('\u{514c}', '\u{514c}'),
+    ('\u{5152}', '\u{5152}'), ('\u{5154}', '\u{5154}'), ('\u{5162}', '\u{5162}'), ('\u{5169}', '\u{516a}'),
+    ('\u{516e}', '\u{516e}'), ('\u{5180}', '\u{5180}'), ('\u{5182}', '\u{5182}'), ('\u{5189}', '\u{5189}'),
+    ('\u{518c}', '\u{518c}'), ('\u{518f}', '\u{5191}'), ('\u{5193}', '\u{5193}'), ('\u{5195}', '\u{5196}'),
+    ('\u{51a2}', '\u{51a2}'), ('\u{51a4}', '\u{51a4}'), ('\u{51a6}', '\u{51a6}'), ('\u{51a9}', '\u{51ab}'),
+    ('\u{51b0}', '\u{51b3}'), ('\u{51b5}', '\u{51b5}'), ('\u{51bd}', '\u{51bd}'), ('\u{51c5}', '\u{51c5}'),
+    ('\u{51c9}', '\u{51c9}'), ('\u{51d6}', '\u{51d6}'), ('\u{51db}', '\u{51dc}'), ('\u{51e0}', '\u{51e0}'),
+    ('\u{51e9}', '\u{51e9}'), ('\u{51ed}', '\u{51ed}'), ('\u{51f0}', '\u{51f0}'), ('\u{51f5}', '\u{51f5}'),
+    ('\u{51fe}', '\u{51fe}'), ('\u{5204}', '\u{5204}'), ('\u{520b}', '\u{520b}'), ('\u{520e}', '\u{520e}'),
+    ('\u{5214}', '\u{5214}'), ('\u{5227}', '\u{5227}'), ('\u{522a}', '\u{522a}'), ('\u{522e}', '\u{522e}'),
+    ('\u{5233}', '\u{5233}'), ('\u{5239}', '\u{5239}'), ('\u{5244}', '\u{5244}'), ('\u{524b}', '\u{524c}'),
+    ('\u{524f}', '\u{524f}'), ('\u{5254}', '\u{5254}'), ('\u{525e}', '\u{525e}'), ('\u{5269}', '\u{526a}'),
+    ('\u{5271}', '\u{5271}'), ('\u{5273}', '\u{5274}'), ('\u{527d}', '\u{527d}'), ('\u{527f}', '\u{527f}'),
+    ('\u{5288}', '\u{5288}'), ('\u{528d}', '\u{528d}'), ('\u{5291}', '\u{5292}'), ('\u{5294}', '\u{5294}'),
+    ('\u{52ac}', '\u{52ad}'), ('\u{52b5}', '\u{52b5}'), ('\u{52bc}', '\u{52bc}'), ('\u{52c1}', '\u{52c1}'),
+    ('\u{52cd}', '\u{52cd}'), ('\u{52d2}', '\u{52d2}'), ('\u{52d7}', '\u{52d7}'), ('\u{52de}', '\u{52de}'),
+    ('\u{52e0}', '\u{52e0}'), ('\u{52e3}', '\u{52e3}'), ('\u{52e6}', '\u{52e6}'), ('\u{52f3}', '\u{52f3}'),
+    ('\u{52f5}', '\u{52f5}'), ('\u{52f8}', '\u{52f9}'), ('\u{5306}', '\u{5306}'), ('\u{5308}', '\u{5308}'),
+    ('\u{530d}', '\u{530d}'), ('\u{530f}', '\u{5310}'), ('\u{5315}', '\u{5315}'), ('\u{531a}', '\u{531a}'),
+    ('\u{5323}', '\u{5323}'), ('\u{532f}', '\u{532f}'), ('\u{5331}', '\u{5331}'), ('\u{5333}', '\u{5333}'),
+    ('\u{5338}', '\u{5338}'), ('\u{5340}', '\u{5340}'), ('\u{5345}', '\u{5346}'), ('\u{5349}', '\u{5349}'),
+    ('\u{534d}', '\u{534d}'), ('\u{535e}', '\u{535e}'), ('\u{5369}', '\u{5369}'), ('\u{536e}', '\u{536e}'),
+    ('\u{5377}', '\u{5377}'), ('\u{537b}', '\u{537b}'), ('\u{5382}', '\u{5382}'), ('\u{5396}', '\u{5396}'),
+    ('\u{53a0}', '\u{53a0}'), ('\u{53a5}', '\u{53a6}'), ('\u{53ae}', '\u{53ae}'), ('\u{53b0}', '\u{53b0}'),
+    ('\u{53b6}', '\u{53b6}'), ('\u{53c3}', '\u{53c3}'), ('\u{53df}', '\u{53df}'), ('\u{53e8}', '\u{53e8}'),
+    ('\u{53ed}', '\u{53ee}'), ('\u{53fa}', '\u{53fa}'), ('\u{5401}', '\u{5401}'), ('\u{541d}', '\u{541d}'),
+    ('\u{5429}', '\u{5429}'), ('\u{542c}', '\u{542e}'), ('\u{5436}', '\u{5436}'), ('\u{543c}', '\u{543d}'),
+    ('\u{5440}', '\u{5440}'), ('\u{544e}', '\u{544e}'), ('\u{545f}', '\u{545f}'), ('\u{5470}', '\u{5471}'),
+    ('\u{5475}', '\u{5477}'), ('\u{547b}', '\u{547b}'), ('\u{5480}', '\u{5480}'), ('\u{5484}', '\u{5484}'),
+    ('\u{5486}', '\u{5486}'), ('\u{548e}', '\u{5490}'), ('\u{5492}', '\u{5492}'), ('\u{54a2}', '\u{54a2}'),
+    ('\u{54a4}', '\u{54a5}'), ('\u{54a8}', '\u{54a8}'), ('\u{54ab}', '\u{54ac}'), ('\u{54af}', '\u{54af}'),
+    ('\u{54b8}', '\u{54b8}'), ('\u{54bc}', '\u{54bc}'), ('\u{54be}', '\u{54be}'), ('\u{54c2}', '\u{54c2}'),
+    ('\u{54c4}', '\u{54c4}'), ('\u{54c7}', '\u{54c8}'), ('\u{54d8}', '\u{54d8}'), ('\u{54e2}', '\u{54e2}'),
+    ('\u{54e5}', '\u{54e6}'), ('\u{54ed}', '\u{54ee}'), ('\u{54fa}', '\u{54fa}'), ('\u{54fd}', '\u{54fd}'),
+    ('\u{550f}', '\u{550f}'), ('\u{5514}', '\u{5514}'), ('\u{552e}', '\u{552e}'), ('\u{5533}', '\u{5533}'),
+    ('\u{5538}', '\u{5539}'), ('\u{5540}', '\u{5540}'), ('\u{5545}', '\u{5545}'), ('\u{554c}', '\u{554c}'),
+    ('\u{5556}', '\u{5557}'), ('\u{555c}', '\u{555d}'), ('\u{5563}', '\u{5563}'), ('\u{557b}', '\u{557c}'),
+    ('\u{557e}', '\u{557e}'), ('\u{5580}', '\u{5580}'), ('\u{5583}', '\u{5583}'), ('\u{5587}', '\u{5587}'),
+    ('\u{558a}', '\u{558a}'), ('\u{5598}', '\u{5599}'), ('\u{559e}', '\u{559f}'), ('\u{55a8}', '\u{55a9}'),
+    ('\u{55ae}', '\u{55ae}'), ('\u{55c4}', '\u{55c5}'), ('\u{55c7}', '\u{55c7}'), ('\u{55d4}', '\u{55d4}'),
+    ('\u{55da}', '\u{55da}'), ('\u{55dc}', '\u{55dc}'), ('\u{55df}', '\u{55df}'), ('\u{55e4}', '\u{55e4}'),
+    ('\u{55f7}', '\u{55f7}'), ('\u{55f9}', '\u{55f9}'), ('\u{55fd}', '\u{55fe}'), ('\u{5614}', '\u{5614}'),
+    ('\u{5616}', '\u{5616}'), ('\u{561b}', '\u{561b}'), ('\u{562f}', '\u{562f}'), ('\u{5632}', '\u{5632}'),
+    ('\u{5634}', '\u{5634}'), ('\u{5636}', '\u{5636}'), ('\u{5638}', '\u{5638}'), ('\u{564e}', '\u{564e}'),
+    ('\u{5650}', '\u{5650}'), ('\u{5664}', '\u{5664}'), ('\u{566a}', '\u{566c}'), ('\u{5680}', '\u{5680}'),
+    ('\u{5686}', '\u{5686}'), ('\u{568a}', '\u{568a}'), ('\u{568f}', '\u{568f}'), ('\u{5694}', '\u{5694}'),
+    ('\u{56a0}', '\u{56a0}'), ('\u{56a5}', '\u{56a5}'), ('\u{56ae}', '\u{56ae}'), ('\u{56b4}', '\u{56b4}'),
+    ('\u{56b6}', '\u{56b6}'), ('\u{56bc}', '\u{56bc}'), ('\u{56c0}', '\u{56c3}'), ('\u{56c8}', '\u{56c8}'),
+    ('\u{56ce}', '\u{56ce}'), ('\u{56d1}', '\u{56d1}'), ('\u{56d3}', '\u{56d3}'), ('\u{56d7}', '\u{56d8}'),
+    ('\u{56ee}', '\u{56ee}'), ('\u{56f9}', '\u{56f9}'), ('\u{56ff}', '\u{5700}'), ('\u{5704}', '\u{5704}'),
+    ('\u{5708}', '\u{5709}'), ('\u{570b}', '\u{570b}'), ('\u{570d}', '\u{570d}'), ('\u{5713}', '\u{5713}'),
+    ('\u{5716}', '\u{5716}'), ('\u{5718}', '\u{5718}'), ('\u{571c}', '\u{571c}'), ('\u{5726}', '\u{5726}'),
+    ('\u{5737}', '\u{5738}'), ('\u{573b}', '\u{573b}'), ('\u{5740}', '\u{5740}'), ('\u{574e}', '\u{574f}'),
+    ('\u{5761}', '\u{5761}'), ('\u{5769}', '\u{5769}'), ('\u{577f}', '\u{577f}'), ('\u{5788}', '\u{5789}'),
+    ('\u{5793}', '\u{5793}'), ('\u{57a0}', '\u{57a0}'), ('\u{57a4}', '\u{57a4}'), ('\u{57aa}', '\u{57aa}'),
+    ('\u{57b0}', '\u{57b0}'), ('\u{57b3}', '\u{57b3}'), ('\u{57c0}', '\u{57c0}'), ('\u{57c3}', '\u{57c3}'),
+    ('\u{57c6}', '\u{57c6}'), ('\u{57d2}', '\u{57d4}'), ('\u{57d6}', '\u{57d6}'), ('\u{57e3}', '\u{57e3}'),
+    ('\u{580a}', '\u{580b}'), ('\u{5819}', '\u{5819}'), ('\u{581d}', '\u{581d}'), ('\u{5821}', '\u{5821}'),
+    ('\u{582f}', '\u{582f}'), ('\u{583d}', '\u{583d}'), ('\u{584b}', '\u{584b}'), ('\u{5852}', '\u{5852}'),
+    ('\u{5862}', '\u{5862}'), ('\u{5870}', '\u{5870}'), ('\u{5872}', '\u{5872}'), ('\u{5879}', '\u{5879}'),
+    ('\u{5885}', '\u{5885}'), ('\u{589f}', '\u{589f}'), ('\u{58ab}', '\u{58ab}'), ('\u{58ae}', '\u{58ae}'),
+    ('\u{58b8}', '\u{58bb}'), ('\u{58c5}', '\u{58c5}'), ('\u{58d1}', '\u{58d1}'), ('\u{58d3}', '\u{58d3}'),
+    ('\u{58d7}', '\u{58d9}'), ('\u{58dc}', '\u{58dc}'), ('\u{58de}', '\u{58df}'), ('\u{58e4}', '\u{58e5}'),
+    ('\u{58ef}', '\u{58ef}'), ('\u{58f9}', '\u{58fd}'), ('\u{5902}', '\u{5902}'), ('\u{590a}', '\u{590a}'),
+    ('\u{5910}', '\u{5910}'), ('\u{5918}', '\u{5918}'), ('\u{591b}', '\u{591b}'), ('\u{5925}', '\u{5925}'),
+    ('\u{592c}', '\u{592d}'), ('\u{5932}', '\u{5932}'), ('\u{5938}', '\u{5938}'), ('\u{593e}', '\u{593e}'),
+    ('\u{594e}', '\u{594e}'), ('\u{5950}', '\u{5950}'), ('\u{5955}', '\u{5955}'), ('\u{5958}', '\u{5958}'),
+    ('\u{595a}', '\u{595a}'), ('\u{5960}', '\u{5960}'), ('\u{5962}', '\u{5962}'), ('\u{5967}', '\u{5967}'),
+    ('\u{5969}', '\u{5969}'), ('\u{596c}', '\u{596c}'), ('\u{5978}', '\u{5978}'), ('\u{5981}', '\u{5981}'),
+    ('\u{598d}', '\u{598d}'), ('\u{599b}', '\u{599b}'), ('\u{599d}', '\u{599d}'), ('\u{59a3}', '\u{59a3}'),
+    ('\u{59b2}', '\u{59b2}'), ('\u{59c6}', '\u{59c6}'), ('\u{59d9}', '\u{59da}'), ('\u{59dc}', '\u{59dc}'),
+    ('\u{59e8}', '\u{59e8}'), ('\u{5a09}', '\u{5a09}'), ('\u{5a11}', '\u{5a11}'), ('\u{5a1a}', '\u{5a1a}'),
+    ('\u{5a1c}', '\u{5a1c}'), ('\u{5a1f}', '\u{5a1f}'), ('\u{5a25}', '\u{5a25}'), ('\u{5a35}', '\u{5a36}'),
+    ('\u{5a40}', '\u{5a40}'), ('\u{5a49}', '\u{5a49}'), ('\u{5a62}', '\u{5a62}'), ('\u{5a6a}', '\u{5a6a}'),
+    ('\u{5a6c}', '\u{5a6c}'), ('\u{5a9a}', '\u{5a9a}'), ('\u{5abc}', '\u{5abe}'), ('\u{5ac2}', '\u{5ac2}'),
+    ('\u{5acb}', '\u{5acb}'), ('\u{5ad0}', '\u{5ad0}'), ('\u{5ad6}', '\u{5ad7}'), ('\u{5ae3}', '\u{5ae3}'),
+    ('\u{5ae6}', '\u{5ae6}'), ('\u{5ae9}', '\u{5ae9}'), ('\u{5afa}', '\u{5afb}'), ('\u{5b0b}', '\u{5b0c}'),
+    ('\u{5b16}', '\u{5b16}'), ('\u{5b2a}', '\u{5b2a}'), ('\u{5b32}', '\u{5b32}'), ('\u{5b36}', '\u{5b36}'),
+    ('\u{5b3e}', '\u{5b3e}'), ('\u{5b40}', '\u{5b40}'), ('\u{5b43}', '\u{5b43}'), ('\u{5b45}', '\u{5b45}'),
+    ('\u{5b51}', '\u{5b51}'), ('\u{5b55}', '\u{5b55}'), ('\u{5b5a}', '\u{5b5b}'), ('\u{5b65}', '\u{5b65}'),
+    ('\u{5b69}', '\u{5b69}'), ('\u{5b70}', '\u{5b71}'), ('\u{5b73}', '\u{5b73}'), ('\u{5b75}', '\u{5b75}'),
+    ('\u{5b78}', '\u{5b78}'), ('\u{5b7a}', '\u{5b7a}'), ('\u{5b80}', '\u{5b80}'), ('\u{5b83}', '\u{5b83}'),
+    ('\u{5ba6}', '\u{5ba6}'), ('\u{5bb8}', '\u{5bb8}'), ('\u{5bc3}', '\u{5bc3}'), ('\u{5bc7}', '\u{5bc7}'),
+    ('\u{5bc9}', '\u{5bc9}'), ('\u{5bd0}', '\u{5bd0}'), ('\u{5bd4}', '\u{5bd4}'), ('\u{5bde}', '\u{5bde}'),
+    ('\u{5be2}', '\u{5be2}'), ('\u{5be4}', '\u{5be6}'), ('\u{5be8}', '\u{5be8}'), ('\u{5beb}', '\u{5beb}'),
+    ('\u{5bf0}', '\u{5bf0}'), ('\u{5bf3}', '\u{5bf3}'), ('\u{5bf6}', '\u{5bf6}'), ('\u{5c05}', '\u{5c05}'),
+    ('\u{5c07}', '\u{5c08}'), ('\u{5c0d}', '\u{5c0d}'), ('\u{5c13}', '\u{5c13}'), ('\u{5c20}', '\u{5c20}'),
+    ('\u{5c22}', '\u{5c22}'), ('\u{5c28}', '\u{5c28}'), ('\u{5c38}', '\u{5c39}'), ('\u{5c41}', '\u{5c41}'),
+    ('\u{5c46}', '\u{5c46}'), ('\u{5c4e}', '\u{5c50}'), ('\u{5c53}', '\u{5c53}'), ('\u{5c6c}', '\u{5c6c}'),
+    ('\u{5c6e}', '\u{5c6e}'), ('\u{5c76}', '\u{5c76}'), ('\u{5c79}', '\u{5c79}'), ('\u{5c8c}', '\u{5c8c}'),
+    ('\u{5c91}', '\u{5c91}'), ('\u{5c94}', '\u{5c94}'), ('\u{5cab}', '\u{5cab}'), ('\u{5cb6}', '\u{5cb7}'),
+    ('\u{5cbb}', '\u{5cbc}'), ('\u{5cbe}', '\u{5cbe}'), ('\u{5cc5}', '\u{5cc5}'), ('\u{5cc7}', '\u{5cc7}'),
+    ('\u{5cd9}', '\u{5cd9}'), ('\u{5ce9}', '\u{5cea}'), ('\u{5ced}', '\u{5ced}'), ('\u{5cfa}', '\u{5cfa}'),
+    ('\u{5cfd}', '\u{5cfd}'), ('\u{5d0b}', '\u{5d0b}'), ('\u{5d11}', '\u{5d11}'), ('\u{5d14}', '\u{5d15}'),
+    ('\u{5d17}', '\u{5d1b}'), ('\u{5d1f}', '\u{5d1f}'), ('\u{5d22}', '\u{5d22}'), ('\u{5d4b}', '\u{5d4c}'),
+    ('\u{5d4e}', '\u{5d4e}'), ('\u{5d52}', '\u{5d52}'), ('\u{5d5c}', '\u{5d5c}'), ('\u{5d6c}', '\u{5d6c}'),
+    ('\u{5d73}', '\u{5d73}'), ('\u{5d76}', '\u{5d76}'), ('\u{5d82}', '\u{5d82}'), ('\u{5d84}', '\u{5d84}'),
+    ('\u{5d87}', '\u{5d87}'), ('\u{5d8c}', '\u{5d8c}'), ('\u{5d90}', '\u{5d90}'), ('\u{5d9d}', '\u{5d9d}'),
+    ('\u{5da2}', '\u{5da2}'), ('\u{5dac}', '\u{5dac}'), ('\u{5dae}', '\u{5dae}'), ('\u{5db7}', '\u{5db7}'),
+    ('\u{5dbc}', '\u{5dbd}'), ('\u{5dc9}', '\u{5dc9}'), ('\u{5dcd}', '\u{5dcd}'), ('\u{5dd2}', '\u{5dd3}'),
+    ('\u{5dd6}', '\u{5dd6}'), ('\u{5ddb}', '\u{5ddb}'), ('\u{5deb}', '\u{5deb}'), ('\u{5df2}', '\u{5df2}'),
+    ('\u{5df5}', '\u{5df5}'), ('\u{5e0b}', '\u{5e0b}'), ('\u{5e11}', '\u{5e11}'), ('\u{5e19}', '\u{5e1b}'),
+    ('\u{5e36}', '\u{5e37}'), ('\u{5e40}', '\u{5e40}'), ('\u{5e43}', '\u{5e44}'), ('\u{5e47}', '\u{5e47}'),
+    ('\u{5e4e}', '\u{5e4e}'), ('\u{5e54}', '\u{5e54}'), ('\u{5e57}', '\u{5e57}'), ('\u{5e5f}', '\u{5e5f}'),
+    ('\u{5e62}', '\u{5e62}'), ('\u{5e64}', '\u{5e64}'), ('\u{5e75}', '\u{5e76}'), ('\u{5e7a}', '\u{5e7a}'),
+    ('\u{5e7f}', '\u{5e7f}'), ('\u{5ea0}', '\u{5ea0}'), ('\u{5ec1}', '\u{5ec2}'), ('\u{5ec8}', '\u{5ec8}'),
+    ('\u{5ecf}', '\u{5ed0}'), ('\u{5ed6}', '\u{5ed6}'), ('\u{5eda}', '\u{5edb}'), ('\u{5edd}', '\u{5edd}'),
+    ('\u{5ee1}', '\u{5ee3}'), ('\u{5ee8}', '\u{5ee9}'), ('\u{5eec}', '\u{5eec}'), ('\u{5ef0}', '\u{5ef1}'),
+    ('\u{5ef3}', '\u{5ef4}'), ('\u{5ef8}', '\u{5ef8}'), ('\u{5efe}', '\u{5efe}'), ('\u{5f03}', '\u{5f03}'),
+    ('\u{5f09}', '\u{5f09}'), ('\u{5f0b}', '\u{5f0d}'), ('\u{5f11}', '\u{5f11}'), ('\u{5f16}', '\u{5f16}'),
+    ('\u{5f29}', '\u{5f29}'), ('\u{5f2d}', '\u{5f2d}'), ('\u{5f2f}', '\u{5f2f}'), ('\u{5f38}', '\u{5f38}'),
+    ('\u{5f41}', '\u{5f41}'), ('\u{5f48}', '\u{5f48}'), ('\u{5f4c}', '\u{5f4c}'), ('\u{5f4e}', '\u{5f4e}'),
+    ('\u{5f51}', '\u{5f51}'), ('\u{5f56}', '\u{5f57}'), ('\u{5f59}', '\u{5f59}'), ('\u{5f5c}', '\u{5f5d}'),
+    ('\u{5f61}', '\u{5f61}'), ('\u{5f6d}', '\u{5f6d}'), ('\u{5f73}', '\u{5f73}'), ('\u{5f77}', '\u{5f77}'),
+    ('\u{5f7f}', '\u{5f7f}'), ('\u{5f82}', '\u{5f83}'), ('\u{5f87}', '\u{5f88}'), ('\u{5f8a}', '\u{5f8a}'),
+    ('\u{5f91}', '\u{5f91}'), ('\u{5f98}', '\u{5f99}'), ('\u{5f9e}', '\u{5f9e}'), ('\u{5fa0}', '\u{5fa0}'),
+    ('\u{5fa8}', '\u{5fa8}'), ('\u{5fad}', '\u{5fad}'), ('\u{5fbc}', '\u{5fbc}'), ('\u{5fd6}', '\u{5fd6}'),
+    ('\u{5fdd}', '\u{5fdd}'), ('\u{5fe4}', '\u{5fe4}'), ('\u{5ff0}', '\u{5ff1}'), ('\u{5ff8}', '\u{5ff8}'),
+    ('\u{5ffb}', '\u{5ffb}'), ('\u{5fff}', '\u{5fff}'), ('\u{600e}', '\u{6010}'), ('\u{6015}', '\u{6015}'),
+    ('\u{6019}', '\u{6019}'), ('\u{601b}', '\u{601b}'), ('\u{6021}', '\u{6021}'), ('\u{6026}', '\u{6026}'),
+    ('\u{6029}', '\u{6029}'), ('\u{602b}', '\u{602b}'), ('\u{6031}', '\u{6031}'), ('\u{603a}', '\u{603a}'),
+    ('\u{6041}', '\u{6043}'), ('\u{6046}', '\u{6046}'), ('\u{604a}', '\u{604a}'), ('\u{604d}', '\u{604d}'),
+    ('\u{6059}', '\u{605a}'), ('\u{605f}', '\u{6060}'), ('\u{6063}', '\u{6064}'), ('\u{606a}', '\u{606c}'),
+    ('\u{6077}', '\u{6077}'), ('\u{6081}', '\u{6081}'), ('\u{6083}', '\u{6084}'), ('\u{608b}', '\u{608b}'),
+    ('\u{608d}', '\u{608d}'), ('\u{6092}', '\u{6092}'), ('\u{6096}', '\u{6097}'), ('\u{609a}', '\u{609b}'),
+    ('\u{60a7}', '\u{60a7}'), ('\u{60b3}', '\u{60b5}'), ('\u{60b8}', '\u{60b8}'), ('\u{60bd}', '\u{60bd}'),
+    ('\u{60c6}', '\u{60c6}'), ('\u{60d3}', '\u{60d3}'), ('\u{60d8}', '\u{60d8}'), ('\u{60e0}', '\u{60e1}'),
+    ('\u{60e7}', '\u{60e7}'), ('\u{60f1}', '\u{60f1}'), ('\u{60f4}', '\u{60f4}'), ('\u{60f6}', '\u{60f7}'),
+    ('\u{60fa}', '\u{60fb}'), ('\u{6100}', '\u{6100}'), ('\u{6103}', '\u{6103}'), ('\u{6106}', '\u{6106}'),
+    ('\u{610d}', '\u{610e}'), ('\u{6115}', '\u{6115}'), ('\u{6121}', '\u{6121}'), ('\u{6127}', '\u{6128}'),
+    ('\u{612c}', '\u{612c}'), ('\u{6134}', '\u{6134}'), ('\u{613c}', '\u{613f}'), ('\u{6142}', '\u{6142}'),
+    ('\u{6144}', '\u{6144}'), ('\u{6147}', '\u{6147}'), ('\u{614a}', '\u{614a}'), ('\u{614d}', '\u{614d}'),
+    ('\u{6153}', '\u{6153}'), ('\u{6158}', '\u{615a}'), ('\u{615d}', '\u{615d}'), ('\u{615f}', '\u{615f}'),
+    ('\u{6165}', '\u{6165}'), ('\u{616b}', '\u{616b}'), ('\u{616f}', '\u{616f}'), ('\u{6171}', '\u{6171}'),
+    ('\u{6173}', '\u{6175}'), ('\u{6177}', '\u{6177}'), ('\u{6187}', '\u{6187}'), ('\u{618a}', '\u{618a}'),
+    ('\u{6191}', '\u{6191}'), ('\u{6194}', '\u{6194}'), ('\u{6196}', '\u{6196}'), ('\u{6199}', '\u{619a}'),
+    ('\u{61ab}', '\u{61ac}'), ('\u{61ae}', '\u{61ae}'), ('\u{61ba}', '\u{61ba}'), ('\u{61c3}', '\u{61c3}'),
+    ('\u{61c6}', '\u{61c6}'), ('\u{61c8}', '\u{61cd}'), ('\u{61e3}', '\u{61e3}'), ('\u{61e6}', '\u{61e6}'),
+    ('\u{61f4}', '\u{61f4}'), ('\u{61f6}', '\u{61f7}'), ('\u{61fa}', '\u{61fa}'), ('\u{61fc}', '\u{6200}'),
+    ('\u{6208}', '\u{6209}'), ('\u{620c}', '\u{620d}'), ('\u{6214}', '\u{6214}'), ('\u{621b}', '\u{621b}'),
+    ('\u{621d}', '\u{621e}'), ('\u{6221}', '\u{6221}'), ('\u{622a}', '\u{622a}'), ('\u{622e}', '\u{622e}'),
+    ('\u{6230}', '\u{6230}'), ('\u{6232}', '\u{6233}'), ('\u{6241}', '\u{6241}'), ('\u{6248}', '\u{6248}'),
+    ('\u{624e}', '\u{624e}'), ('\u{625b}', '\u{625b}'), ('\u{625e}', '\u{625e}'), ('\u{6260}', '\u{6260}'),
+    ('\u{6263}', '\u{6263}'), ('\u{6268}', '\u{6268}'), ('\u{627c}', '\u{627c}'), ('\u{627e}', '\u{627e}'),
+    ('\u{6282}', '\u{6283}'), ('\u{6289}', '\u{6289}'), ('\u{6292}', '\u{6294}'), ('\u{6296}', '\u{6296}'),
+    ('\u{629b}', '\u{629b}'), ('\u{62ac}', '\u{62ac}'), ('\u{62bb}', '\u{62bb}'), ('\u{62c2}', '\u{62c2}'),
+    ('\u{62c6}', '\u{62ca}'), ('\u{62cc}', '\u{62cc}'), ('\u{62cf}', '\u{62cf}'), ('\u{62d1}', '\u{62d1}'),
+    ('\u{62d4}', '\u{62d4}'), ('\u{62d7}', '\u{62d7}'), ('\u{62dc}', '\u{62dc}'), ('\u{62ee}', '\u{62ef}'),
+    ('\u{62f1}', '\u{62f1}'), ('\u{62f5}', '\u{62f5}'), ('\u{62ff}', '\u{62ff}'), ('\u{6302}', '\u{6302}'),
+    ('\u{6308}', '\u{6308}'), ('\u{630c}', '\u{630c}'), ('\u{6327}', '\u{6327}'), ('\u{633e}', '\u{633e}'),
+    ('\u{634d}', '\u{634d}'), ('\u{634f}', '\u{6350}'), ('\u{6369}', '\u{6369}'), ('\u{636b}', '\u{636b}'),
+    ('\u{6376}', '\u{6376}'), ('\u{6380}', '\u{6380}'), ('\u{6389}', '\u{6389}'), ('\u{638e}', '\u{638f}'),
+    ('\u{6396}', '\u{6396}'), ('\u{639f}', '\u{639f}'), ('\u{63a3}', '\u{63a3}'), ('\u{63ab}', '\u{63ab}'),
+    ('\u{63b5}', '\u{63b5}'), ('\u{63be}', '\u{63be}'), ('\u{63c0}', '\u{63c0}'), ('\u{63c4}', '\u{63c4}'),
+    ('\u{63c6}', '\u{63c6}'), ('\u{63c9}', '\u{63c9}'), ('\u{63d2}', '\u{63d2}'), ('\u{63e3}', '\u{63e3}'),
+    ('\u{63e9}', '\u{63e9}'), ('\u{63f6}', '\u{63f6}'), ('\u{6406}', '\u{6406}'), ('\u{640f}', '\u{640f}'),
+    ('\u{6413}', '\u{6413}'), ('\u{6416}', '\u{6417}'), ('\u{641c}', '\u{641c}'), ('\u{6426}', '\u{6426}'),
+    ('\u{6428}', '\u{6428}'), ('\u{6434}', '\u{6434}'), ('\u{6436}', '\u{6436}'), ('\u{644e}', '\u{644e}'),
+    ('\u{6467}', '\u{6467}'), ('\u{646f}', '\u{646f}'), ('\u{6476}', '\u{6476}'), ('\u{6488}', '\u{6488}'),
+    ('\u{6493}', '\u{6493}'), ('\u{6495}', '\u{6495}'), ('\u{64a5}', '\u{64a5}'), ('\u{64a9}', '\u{64a9}'),
+    ('\u{64bb}', '\u{64bc}'), ('\u{64c2}', '\u{64c2}'), ('\u{64c5}', '\u{64c5}'), ('\u{64c7}', '\u{64c7}'),
+    ('\u{64d2}', '\u{64d2}'), ('\u{64d4}', '\u{64d4}'), ('\u{64d8}', '\u{64d8}'), ('\u{64da}', '\u{64da}'),
+    ('\u{64e0}', '\u{64e1}'), ('\u{64e3}', '\u{64e3}'), ('\u{64e7}', '\u{64e7}'), ('\u{64ef}', '\u{64ef}'),
+    ('\u{64f1}', '\u{64f2}'), ('\u{64f4}', '\u{64f4}'), ('\u{64f6}', '\u{64f6}'), ('\u{64fa}', '\u{64fa}'),
+    ('\u{64fd}', '\u{64fd}'), ('\u{6500}', '\u{6500}'), ('\u{6505}', '\u{6505}'), ('\u{6518}', '\u{6518}'),
+    ('\u{651c}', '\u{651d}'), ('\u{6523}', '\u{6524}'), ('\u{652a}', '\u{652c}'), ('\u{6534}', '\u{6538}'),
+    ('\u{6548}', '\u{6548}'), ('\u{654d}', '\u{654d}'), ('\u{6555}', '\u{6556}'), ('\u{6558}', '\u{6558}'),
+    ('\u{655d}', '\u{655e}'), ('\u{6572}', '\u{6572}'), ('\u{6578}', '\u{6578}'), ('\u{6582}', '\u{6583}'),
+    ('\u{6588}', '\u{6588}'), ('\u{659b}', '\u{659b}'), ('\u{659f}', '\u{659f}'), ('\u{65ab}', '\u{65ab}'),
+    ('\u{65b7}', '\u{65b7}'), ('\u{65c1}', '\u{65c1}'), ('\u{65c3}', '\u{65c4}'), ('\u{65c6}', '\u{65c6}'),
+    ('\u{65cc}', '\u{65cc}'), ('\u{65d2}', '\u{65d2}'), ('\u{65d9}', '\u{65d9}'), ('\u{65db}', '\u{65db}'),
+    ('\u{65e0}', '\u{65e1}'), ('\u{65f1}', '\u{65f1}'), ('\u{65fb}', '\u{65fb}'), ('\u{6603}', '\u{6603}'),
+    ('\u{660a}', '\u{660a}'), ('\u{661c}', '\u{661c}'), ('\u{6634}', '\u{6636}'), ('\u{663f}', '\u{663f}'),
+    ('\u{6641}', '\u{6641}'), ('\u{6644}', '\u{6644}'), ('\u{6649}', '\u{6649}'), ('\u{664f}', '\u{664f}'),
+    ('\u{665d}', '\u{665f}'), ('\u{6662}', '\u{6662}'), ('\u{6664}', '\u{6664}'), ('\u{6667}', '\u{6668}'),
+    ('\u{6670}', '\u{6670}'), ('\u{6683}', '\u{6684}'), ('\u{6688}', '\u{6689}'), ('\u{668e}', '\u{668e}'),
+    ('\u{6698}', '\u{6698}'), ('\u{669d}', '\u{669d}'), ('\u{66b8}', '\u{66b9}'), ('\u{66bc}', '\u{66bc}'),
+    ('\u{66be}', '\u{66be}'), ('\u{66c1}', '\u{66c1}'), ('\u{66c4}', '\u{66c4}'), ('\u{66c9}', '\u{66c9}'),
+    ('\u{66d6}', '\u{66d6}'), ('\u{66da}', '\u{66da}'), ('\u{66e0}', '\u{66e0}'), ('\u{66e6}', '\u{66e6}'),
+    ('\u{66e9}', '\u{66e9}'), ('\u{66f0}', '\u{66f0}'), ('\u{66f5}', '\u{66f5}'), ('\u{66f7}', '\u{66f7}'),
+    ('\u{66fc}', '\u{66fc}'), ('\u{6703}', '\u{6703}'), ('\u{670f}', '\u{670f}'), ('\u{6716}', '\u{6716}'),
+    ('\u{671e}', '\u{671e}'), ('\u{6726}', '\u{6727}'), ('\u{672e}', '\u{672e}'), ('\u{6736}', '\u{6738}'),
+    ('\u{673f}', '\u{673f}'), ('\u{6741}', '\u{6741}'), ('\u{6746}', '\u{6746}'), ('\u{6759}', '\u{6759}'),
+    ('\u{675e}', '\u{675e}'), ('\u{6760}', '\u{6760}'), ('\u{6763}', '\u{6764}'), ('\u{676a}', '\u{676a}'),
+    ('\u{6770}', '\u{6770}'), ('\u{6772}', '\u{6773}'), ('\u{677c}', '\u{677c}'), ('\u{6785}', '\u{6785}'),
+    ('\u{6789}', '\u{6789}'), ('\u{678b}', '\u{678c}'), ('\u{67a1}', '\u{67a1}'), ('\u{67a6}', '\u{67a6}'),
+    ('\u{67a9}', '\u{67a9}'), ('\u{67b3}', '\u{67b4}'), ('\u{67b7}', '\u{67b9}'), ('\u{67c6}', '\u{67c6}'),
+    ('\u{67ce}', '\u{67ce}'), ('\u{67dd}', '\u{67de}'), ('\u{67e2}', '\u{67e2}'), ('\u{67e4}', '\u{67e4}'),
+    ('\u{67e7}', '\u{67e7}'), ('\u{67e9}', '\u{67e9}'), ('\u{67ec}', '\u{67ec}'), ('\u{67ee}', '\u{67ef}'),
+    ('\u{681e}', '\u{681e}'), ('\u{6829}', '\u{6829}'), ('\u{682b}', '\u{682b}'), ('\u{6832}', '\u{6832}'),
+    ('\u{6840}', '\u{6840}'), ('\u{6846}', '\u{6846}'), ('\u{684d}', '\u{684e}'), ('\u{6859}', '\u{6859}'),
+    ('\u{6863}', '\u{6863}'), ('\u{6874}', '\u{6874}'), ('\u{6877}', '\u{6877}'), ('\u{687e}', '\u{687f}'),
+    ('\u{6883}', '\u{6883}'), ('\u{688d}', '\u{688d}'), ('\u{688f}', '\u{688f}'), ('\u{6894}', '\u{6894}'),
+    ('\u{689b}', '\u{689b}'), ('\u{689d}', '\u{689d}'), ('\u{689f}', '\u{68a0}'), ('\u{68a6}', '\u{68a6}'),
+    ('\u{68ad}', '\u{68ad}'), ('\u{68b3}', '\u{68b3}'), ('\u{68b5}', '\u{68b5}'), ('\u{68b9}', '\u{68ba}'),
+    ('\u{68c6}', '\u{68c6}'), ('\u{68ca}', '\u{68ca}'), ('\u{68cd}', '\u{68cd}'), ('\u{68d4}', '\u{68d5}'),
+    ('\u{68d7}', '\u{68d8}'), ('\u{68e0}', '\u{68e1}'), ('\u{68e3}', '\u{68e3}'), ('\u{68e7}', '\u{68e7}'),
+    ('\u{68ef}', '\u{68ef}'), ('\u{68f9}', '\u{68f9}'), ('\u{6901}', '\u{6901}'), ('\u{6904}', '\u{6904}'),
+    ('\u{6908}', '\u{6908}'), ('\u{690c}', '\u{690c}'), ('\u{690f}', '\u{690f}'), ('\u{6912}', '\u{6912}'),
+    ('\u{691a}', '\u{691a}'), ('\u{6921}', '\u{6923}'), ('\u{6925}', '\u{6926}'), ('\u{6928}', '\u{6928}'),
+    ('\u{692a}', '\u{692a}'), ('\u{6930}', '\u{6930}'), ('\u{6936}', '\u{6936}'), ('\u{6939}', '\u{6939}'),
+    ('\u{693d}', '\u{693d}'), ('\u{6954}', '\u{6954}'), ('\u{6959}', '\u{6959}'), ('\u{695c}', '\u{695e}'),
+    ('\u{6961}', '\u{6961}'), ('\u{696a}', '\u{696b}'), ('\u{696e}', '\u{696e}'), ('\u{6974}', '\u{6974}'),
+    ('\u{6977}', '\u{6979}'), ('\u{697e}', '\u{697e}'), ('\u{6981}', '\u{6981}'), ('\u{6991}', '\u{6991}'),
+    ('\u{6995}', '\u{6995}'), ('\u{699c}', '\u{699c}'), ('\u{69a0}', '\u{69a0}'), ('\u{69a7}', '\u{69a7}'),
+    ('\u{69ae}', '\u{69ae}'), ('\u{69b1}', '\u{69b2}'), ('\u{69b4}', '\u{69b4}'), ('\u{69bb}', '\u{69bb}'),
+    ('\u{69be}', '\u{69bf}'), ('\u{69c1}', '\u{69c1}'), ('\u{69c3}', '\u{69c3}'), ('\u{69c7}', '\u{69c7}'),
+    ('\u{69ca}', '\u{69ca}'), ('\u{69ce}', '\u{69ce}'), ('\u{69d0}', '\u{69d0}'), ('\u{69d3}', '\u{69d3}'),
+    ('\u{69dd}', '\u{69de}'), ('\u{69e7}', '\u{69e8}'), ('\u{69eb}', '\u{69eb}'), ('\u{69ed}', '\u{69ed}'),
+    ('\u{69f2}', '\u{69f2}'), ('\u{69f9}', '\u{69f9}'), ('\u{69ff}', '\u{69ff}'), ('\u{6a02}', '\u{6a02}'),
+    ('\u{6a05}', '\u{6a05}'), ('\u{6a0a}', '\u{6a0a}'), ('\u{6a0c}', '\u{6a0c}'), ('\u{6a12}', '\u{6a14}'),
+    ('\u{6a1b}', '\u{6a1b}'), ('\u{6a1e}', '\u{6a1e}'), ('\u{6a22}', '\u{6a23}'), ('\u{6a2e}', '\u{6a2e}'),
+    ('\u{6a36}', '\u{6a36}'), ('\u{6a38}', '\u{6a38}'), ('\u{6a44}', '\u{6a44}'), ('\u{6a47}', '\u{6a48}'),
+    ('\u{6a59}', '\u{6a59}'), ('\u{6a62}', '\u{6a62}'), ('\u{6a66}', '\u{6a66}'), ('\u{6a72}', '\u{6a72}'),
+    ('\u{6a78}', '\u{6a78}'), ('\u{6a84}', '\u{6a84}'), ('\u{6a8d}', '\u{6a8d}'), ('\u{6a90}', '\u{6a90}'),
+    ('\u{6a97}', '\u{6a97}'), ('\u{6a9c}', '\u{6a9c}'), ('\u{6aa0}', '\u{6aa0}'), ('\u{6aa2}', '\u{6aa3}'),
+    ('\u{6aaa}', '\u{6aaa}'), ('\u{6aac}', '\u{6aac}'), ('\u{6aae}', '\u{6aae}'), ('\u{6ab3}', '\u{6ab3}'),
+    ('\u{6ab8}', '\u{6ab8}'), ('\u{6abb}', '\u{6abb}'), ('\u{6ac1}', '\u{6ac3}'), ('\u{6ad1}', '\u{6ad1}'),
+    ('\u{6ada}', '\u{6ada}'), ('\u{6ade}', '\u{6adf}'), ('\u{6aea}', '\u{6aea}'), ('\u{6afa}', '\u{6afb}'),
+    ('\u{6b05}', '\u{6b05}'), ('\u{6b0a}', '\u{6b0a}'), ('\u{6b12}', '\u{6b12}'), ('\u{6b16}', '\u{6b16}'),
+    ('\u{6b1f}', '\u{6b1f}'), ('\u{6b37}', '\u{6b39}'), ('\u{6b43}', '\u{6b43}'), ('\u{6b47}', '\u{6b47}'),
+    ('\u{6b49}', '\u{6b49}'), ('\u{6b50}', '\u{6b50}'), ('\u{6b54}', '\u{6b54}'), ('\u{6b59}', '\u{6b59}'),
+    ('\u{6b5b}', '\u{6b5b}'), ('\u{6b5f}', '\u{6b5f}'), ('\u{6b61}', '\u{6b61}'), ('\u{6b78}', '\u{6b79}'),
+    ('\u{6b7f}', '\u{6b80}'), ('\u{6b83}', '\u{6b84}'), ('\u{6b8d}', '\u{6b8d}'), ('\u{6b95}', '\u{6b95}'),
+    ('\u{6b98}', '\u{6b98}'), ('\u{6b9e}', '\u{6b9e}'), ('\u{6ba4}', '\u{6ba4}'), ('\u{6baa}', '\u{6bab}'),
+    ('\u{6baf}', '\u{6baf}'), ('\u{6bb1}', '\u{6bb3}'), ('\u{6bb7}', '\u{6bb7}'), ('\u{6bbc}', '\u{6bbc}'),
+    ('\u{6bc0}', '\u{6bc0}'), ('\u{6bc6}', '\u{6bc6}'), ('\u{6bcb}', '\u{6bcb}'), ('\u{6bd3}', '\u{6bd3}'),
+    ('\u{6bdf}', '\u{6bdf}'), ('\u{6beb}', '\u{6bec}'), ('\u{6bef}', '\u{6bef}'), ('\u{6bf3}', '\u{6bf3}'),
+    ('\u{6c08}', '\u{6c08}'), ('\u{6c13}', '\u{6c14}'), ('\u{6c1b}', '\u{6c1b}'), ('\u{6c23}', '\u{6c24}'),
+    ('\u{6c55}', '\u{6c55}'), ('\u{6c5e}', '\u{6c5e}'), ('\u{6c62}', '\u{6c62}'), ('\u{6c68}', '\u{6c68}'),
+    ('\u{6c6a}', '\u{6c6a}'), ('\u{6c73}', '\u{6c73}'), ('\u{6c7e}', '\u{6c7e}'), ('\u{6c81}', '\u{6c82}'),
+    ('\u{6c8d}', '\u{6c8d}'), ('\u{6c90}', '\u{6c90}'), ('\u{6c92}', '\u{6c92}'), ('\u{6c9a}', '\u{6c9b}'),
+    ('\u{6cae}', '\u{6cae}'), ('\u{6cb1}', '\u{6cb1}'), ('\u{6cba}', '\u{6cba}'), ('\u{6cbd}', '\u{6cbe}'),
+    ('\u{6cc4}', '\u{6cc5}'), ('\u{6cd3}', '\u{6cd3}'), ('\u{6cd7}', '\u{6cd7}'), ('\u{6cd9}', '\u{6cd9}'),
+    ('\u{6cdb}', '\u{6cdb}'), ('\u{6cdd}', '\u{6cdd}'), ('\u{6cea}', '\u{6cea}'), ('\u{6cef}', '\u{6cef}'),
+    ('\u{6cf1}', '\u{6cf1}'), ('\u{6d0c}', '\u{6d0c}'), ('\u{6d12}', '\u{6d12}'), ('\u{6d19}', '\u{6d19}'),
+    ('\u{6d1f}', '\u{6d1f}'), ('\u{6d2b}', '\u{6d2b}'), ('\u{6d33}', '\u{6d33}'), ('\u{6d35}', '\u{6d36}'),
+    ('\u{6d38}', '\u{6d38}'), ('\u{6d3d}', '\u{6d3d}'), ('\u{6d59}', '\u{6d5a}'), ('\u{6d63}', '\u{6d64}'),
+    ('\u{6d79}', '\u{6d79}'), ('\u{6d85}', '\u{6d85}'), ('\u{6d8e}', '\u{6d8e}'), ('\u{6d93}', '\u{6d93}'),
+    ('\u{6d95}', '\u{6d95}'), ('\u{6db5}', '\u{6db5}'), ('\u{6db8}', '\u{6db8}'), ('\u{6dc5}', '\u{6dc7}'),
+    ('\u{6dcc}', '\u{6dcc}'), ('\u{6dd2}', '\u{6dd2}'), ('\u{6dd5}', '\u{6dd5}'), ('\u{6dd9}', '\u{6dd9}'),
+    ('\u{6dde}', '\u{6dde}'), ('\u{6de4}', '\u{6de4}'), ('\u{6de6}', '\u{6de6}'), ('\u{6de8}', '\u{6de8}'),
+    ('\u{6dea}', '\u{6dea}'), ('\u{6dec}', '\u{6dec}'), ('\u{6dee}', '\u{6dee}'), ('\u{6df9}', '\u{6dfa}'),
+    ('\u{6e0a}', '\u{6e0a}'), ('\u{6e15}', '\u{6e15}'), ('\u{6e19}', '\u{6e19}'), ('\u{6e1d}', '\u{6e1d}'),
+    ('\u{6e1f}', '\u{6e1f}'), ('\u{6e23}', '\u{6e24}'), ('\u{6e2b}', '\u{6e2b}'), ('\u{6e2d}', '\u{6e2e}'),
+    ('\u{6e38}', '\u{6e38}'), ('\u{6e3a}', '\u{6e3a}'), ('\u{6e3e}', '\u{6e3e}'), ('\u{6e43}', '\u{6e43}'),
+    ('\u{6e4d}', '\u{6e4e}'), ('\u{6e5f}', '\u{6e5f}'), ('\u{6e6b}', '\u{6e6b}'), ('\u{6e6e}', '\u{6e6e}'),
+    ('\u{6e72}', '\u{6e72}'), ('\u{6e76}', '\u{6e76}'), ('\u{6e82}', '\u{6e82}'), ('\u{6e8f}', '\u{6e8f}'),
+    ('\u{6e98}', '\u{6e98}'), ('\u{6e9f}', '\u{6e9f}'), ('\u{6ea5}', '\u{6ea5}'), ('\u{6eaa}', '\u{6eaa}'),
+    ('\u{6eaf}', '\u{6eaf}'), ('\u{6eb2}', '\u{6eb2}'), ('\u{6eb7}', '\u{6eb7}'), ('\u{6ebd}', '\u{6ebd}'),
+    ('\u{6ec2}', '\u{6ec2}'), ('\u{6ec4}', '\u{6ec4}'), ('\u{6ec9}', '\u{6ec9}'), ('\u{6ecc}', '\u{6ecc}'),
+    ('\u{6ed3}', '\u{6ed5}'), ('\u{6eec}', '\u{6eec}'), ('\u{6eef}', '\u{6eef}'), ('\u{6ef2}', '\u{6ef2}'),
+    ('\u{6ef7}', '\u{6ef8}'), ('\u{6efe}', '\u{6eff}'), ('\u{6f11}', '\u{6f11}'), ('\u{6f13}', '\u{6f13}'),
+    ('\u{6f31}', '\u{6f32}'), ('\u{6f3e}', '\u{6f3f}'), ('\u{6f41}', '\u{6f41}'), ('\u{6f58}', '\u{6f58}'),
+    ('\u{6f5b}', '\u{6f5b}'), ('\u{6f66}', '\u{6f66}'), ('\u{6f6d}', '\u{6f6d}'), ('\u{6f6f}', '\u{6f6f}'),
+    ('\u{6f74}', '\u{6f74}'), ('\u{6f78}', '\u{6f78}'), ('\u{6f7a}', '\u{6f7a}'), ('\u{6f7c}', '\u{6f7c}'),
+    ('\u{6f80}', '\u{6f82}'), ('\u{6f86}', '\u{6f86}'), ('\u{6f8e}', '\u{6f8e}'), ('\u{6f91}', '\u{6f91}'),
+    ('\u{6fa1}', '\u{6fa1}'), ('\u{6fa3}', '\u{6fa4}'), ('\u{6faa}', '\u{6faa}'), ('\u{6fb3}', '\u{6fb3}'),
+    ('\u{6fb9}', '\u{6fb9}'), ('\u{6fc2}', '\u{6fc2}'), ('\u{6fc6}', '\u{6fc6}'), ('\u{6fd4}', '\u{6fd5}'),
+    ('\u{6fd8}', '\u{6fd8}'), ('\u{6fdb}', '\u{6fdb}'), ('\u{6fdf}', '\u{6fdf}'), ('\u{6fe4}', '\u{6fe4}'),
+    ('\u{6fec}', '\u{6fec}'), ('\u{6fee}', '\u{6fee}'), ('\u{6ff1}', '\u{6ff1}'), ('\u{6ff3}', '\u{6ff3}'),
+    ('\u{6ff6}', '\u{6ff6}'), ('\u{6ffa}', '\u{6ffa}'), ('\u{6ffe}', '\u{6ffe}'), ('\u{7001}', '\u{7001}'),
+    ('\u{7009}', '\u{7009}'), ('\u{700b}', '\u{700b}'), ('\u{700f}', '\u{700f}'), ('\u{7011}', '\u{7011}'),
+    ('\u{7018}', '\u{7018}'), ('\u{701a}', '\u{701b}'), ('\u{701d}', '\u{701d}'), ('\u{701f}', '\u{701f}'),
+    ('\u{7030}', '\u{7030}'), ('\u{7032}', '\u{7032}'), ('\u{703e}', '\u{703e}'), ('\u{704c}', '\u{704c}'),
+    ('\u{7051}', '\u{7051}'), ('\u{7063}', '\u{7063}'), ('\u{7092}', '\u{7092}'), ('\u{7099}', '\u{7099}'),
+    ('\u{70ac}', '\u{70ac}'), ('\u{70ae}', '\u{70af}'), ('\u{70b3}', '\u{70b3}'), ('\u{70b8}', '\u{70b8}'),
+    ('\u{70cb}', '\u{70cb}'), ('\u{70d9}', '\u{70d9}'), ('\u{70dd}', '\u{70dd}'), ('\u{70df}', '\u{70df}'),
+    ('\u{70f1}', '\u{70f1}'), ('\u{70fd}', '\u{70fd}'), ('\u{7109}', '\u{7109}'), ('\u{7119}', '\u{7119}'),
+    ('\u{711c}', '\u{711c}'), ('\u{714c}', '\u{714c}'), ('\u{7155}', '\u{7156}'), ('\u{7162}', '\u{7162}'),
+    ('\u{7165}', '\u{7166}'), ('\u{716c}', '\u{716c}'), ('\u{7184}', '\u{7184}'), ('\u{7188}', '\u{7188}'),
+    ('\u{718f}', '\u{718f}'), ('\u{7195}', '\u{7195}'), ('\u{7199}', '\u{7199}'), ('\u{71a8}', '\u{71a8}'),
+    ('\u{71ac}', '\u{71ac}'), ('\u{71b9}', '\u{71b9}'), ('\u{71be}', '\u{71be}'), ('\u{71c9}', '\u{71c9}'),
+    ('\u{71ce}', '\u{71ce}'), ('\u{71d2}', '\u{71d2}'), ('\u{71d4}', '\u{71d4}'), ('\u{71d7}', '\u{71d7}'),
+    ('\u{71df}', '\u{71e0}'), ('\u{71e7}', '\u{71e7}'), ('\u{71ec}', '\u{71ec}'), ('\u{71ee}', '\u{71ee}'),
+    ('\u{71f5}', '\u{71f5}'), ('\u{71f9}', '\u{71f9}'), ('\u{71fb}', '\u{71fc}'), ('\u{71ff}', '\u{71ff}'),
+    ('\u{720d}', '\u{720d}'), ('\u{7210}', '\u{7210}'), ('\u{721b}', '\u{721b}'), ('\u{7228}', '\u{7228}'),
+    ('\u{722c}', '\u{722d}'), ('\u{7230}', '\u{7230}'), ('\u{7232}', '\u{7232}'), ('\u{723b}', '\u{723c}'),
+    ('\u{723f}', '\u{7240}'), ('\u{7246}', '\u{7246}'), ('\u{724b}', '\u{724b}'), ('\u{7258}', '\u{7258}'),
+    ('\u{7274}', '\u{7274}'), ('\u{727e}', '\u{727e}'), ('\u{7281}', '\u{7282}'), ('\u{7287}', '\u{7287}'),
+    ('\u{7292}', '\u{7292}'), ('\u{7296}', '\u{7296}'), ('\u{72a2}', '\u{72a2}'), ('\u{72a7}', '\u{72a7}'),
+    ('\u{72b2}', '\u{72b2}'), ('\u{72b9}', '\u{72b9}'), ('\u{72c3}', '\u{72c4}'), ('\u{72c6}', '\u{72c6}'),
+    ('\u{72ce}', '\u{72ce}'), ('\u{72d2}', '\u{72d2}'), ('\u{72e0}', '\u{72e2}'), ('\u{72f7}', '\u{72f7}'),
+    ('\u{72f9}', '\u{72f9}'), ('\u{730a}', '\u{730a}'), ('\u{7316}', '\u{7317}'), ('\u{731c}', '\u{731d}'),
+    ('\u{7325}', '\u{7325}'), ('\u{7329}', '\u{7329}'), ('\u{732f}', '\u{732f}'), ('\u{7334}', '\u{7334}'),
+    ('\u{733e}', '\u{733e}'), ('\u{734e}', '\u{734f}'), ('\u{7357}', '\u{7357}'), ('\u{7368}', '\u{7368}'),
+    ('\u{736a}', '\u{736a}'), ('\u{7370}', '\u{7370}'), ('\u{7375}', '\u{7375}'), ('\u{7378}', '\u{7378}'),
+    ('\u{737a}', '\u{737b}'), ('\u{73b3}', '\u{73b3}'), ('\u{73bb}', '\u{73bb}'), ('\u{73c0}', '\u{73c0}'),
+    ('\u{73c8}', '\u{73c8}'), ('\u{73ce}', '\u{73ce}'), ('\u{73de}', '\u{73de}'), ('\u{73e5}', '\u{73e5}'),
+    ('\u{73ee}', '\u{73ee}'), ('\u{73f1}', '\u{73f1}'), ('\u{73f8}', '\u{73f8}'), ('\u{7405}', '\u{7405}'),
+    ('\u{7425}', '\u{7425}'), ('\u{7432}', '\u{7432}'), ('\u{743a}', '\u{743a}'), ('\u{743f}', '\u{743f}'),
+    ('\u{7441}', '\u{7441}'), ('\u{7455}', '\u{7455}'), ('\u{7459}', '\u{7459}'), ('\u{745c}', '\u{745c}'),
+    ('\u{745f}', '\u{745f}'), ('\u{7463}', '\u{7464}'), ('\u{7469}', '\u{746a}'), ('\u{746f}', '\u{7470}'),
+    ('\u{7476}', '\u{7476}'), ('\u{747e}', '\u{747e}'), ('\u{748b}', '\u{748b}'), ('\u{749e}', '\u{749e}'),
+    ('\u{74a2}', '\u{74a2}'), ('\u{74a7}', '\u{74a7}'), ('\u{74ca}', '\u{74ca}'), ('\u{74cf}', '\u{74cf}'),
+    ('\u{74d4}', '\u{74d4}'), ('\u{74e0}', '\u{74e0}'), ('\u{74e3}', '\u{74e3}'), ('\u{74e7}', '\u{74e7}'),
+    ('\u{74e9}', '\u{74e9}'), ('\u{74ee}', '\u{74ee}'), ('\u{74f0}', '\u{74f2}'), ('\u{74f7}', '\u{74f8}'),
+    ('\u{7503}', '\u{7505}'), ('\u{750c}', '\u{750e}'), ('\u{7513}', '\u{7513}'), ('\u{7515}', '\u{7515}'),
+    ('\u{751e}', '\u{751e}'), ('\u{7526}', '\u{7526}'), ('\u{752c}', '\u{752c}'), ('\u{7538}', '\u{7538}'),
+    ('\u{753c}', '\u{753c}'), ('\u{7544}', '\u{7544}'), ('\u{7546}', '\u{7546}'), ('\u{7549}', '\u{754b}'),
+    ('\u{754d}', '\u{754d}'), ('\u{755a}', '\u{755b}'), ('\u{7564}', '\u{7564}'), ('\u{7567}', '\u{7567}'),
+    ('\u{7569}', '\u{7569}'), ('\u{756b}', '\u{756b}'), ('\u{756d}', '\u{756d}'), ('\u{7574}', '\u{7574}'),
+    ('\u{7576}', '\u{7576}'), ('\u{7578}', '\u{7578}'), ('\u{7582}', '\u{7582}'), ('\u{7586}', '\u{7587}'),
+    ('\u{7589}', '\u{758a}'), ('\u{7594}', '\u{7594}'), ('\u{759a}', '\u{759a}'), ('\u{759d}', '\u{759d}'),
+    ('\u{75a3}', '\u{75a3}'), ('\u{75a5}', '\u{75a5}'), ('\u{75b1}', '\u{75b1}'), ('\u{75b3}', '\u{75b3}'),
+    ('\u{75b5}', '\u{75b5}'), ('\u{75b8}', '\u{75b8}'), ('\u{75bc}', '\u{75bd}'), ('\u{75c2}', '\u{75c3}'),
+    ('\u{75ca}', '\u{75ca}'), ('\u{75cd}', '\u{75cd}'), ('\u{75d2}', '\u{75d2}'), ('\u{75d9}', '\u{75d9}'),
+    ('\u{75de}', '\u{75de}'), ('\u{75e3}', '\u{75e3}'), ('\u{75f0}', '\u{75f0}'), ('\u{75f2}', '\u{75f3}'),
+    ('\u{75fa}', '\u{75fa}'), ('\u{75fc}', '\u{75fc}'), ('\u{75fe}', '\u{75ff}'), ('\u{7601}', '\u{7601}'),
+    ('\u{7609}', '\u{7609}'), ('\u{760b}', '\u{760b}'), ('\u{760d}', '\u{760d}'), ('\u{761f}', '\u{7622}'),
+    ('\u{7624}', '\u{7624}'), ('\u{7627}', '\u{7627}'), ('\u{7630}', '\u{7630}'), ('\u{7634}', '\u{7634}'),
+    ('\u{763b}', '\u{763b}'), ('\u{7646}', '\u{7648}'), ('\u{7658}', '\u{7658}'), ('\u{765c}', '\u{765c}'),
+    ('\u{7661}', '\u{7662}'), ('\u{7667}', '\u{766a}'), ('\u{766c}', '\u{766c}'), ('\u{7670}', '\u{7670}'),
+    ('\u{7672}', '\u{7672}'), ('\u{7676}', '\u{7676}'), ('\u{7678}', '\u{7678}'), ('\u{767c}', '\u{767c}'),
+    ('\u{7680}', '\u{7680}'), ('\u{7683}', '\u{7683}'), ('\u{7688}', '\u{7688}'), ('\u{768b}', '\u{768b}'),
+    ('\u{768e}', '\u{768e}'), ('\u{7693}', '\u{7693}'), ('\u{7696}', '\u{7696}'), ('\u{7699}', '\u{769a}'),
+    ('\u{76b0}', '\u{76b0}'), ('\u{76b4}', '\u{76b4}'), ('\u{76b7}', '\u{76ba}'), ('\u{76c2}', '\u{76c2}'),
+    ('\u{76cd}', '\u{76cd}'), ('\u{76d2}', '\u{76d2}'), ('\u{76d6}', '\u{76d6}'), ('\u{76dc}', '\u{76dc}'),
+    ('\u{76de}', '\u{76de}'), ('\u{76e1}', '\u{76e1}'), ('\u{76e5}', '\u{76e5}'), ('\u{76e7}', '\u{76e7}'),
+    ('\u{76ea}', '\u{76ea}'), ('\u{76fb}', '\u{76fb}'), ('\u{7704}', '\u{7704}'), ('\u{7707}', '\u{7708}'),
+    ('\u{771b}', '\u{771b}'), ('\u{771e}', '\u{771e}'), ('\u{7724}', '\u{7726}'), ('\u{7729}', '\u{7729}'),
+    ('\u{7737}', '\u{7738}'), ('\u{7747}', '\u{7747}'), ('\u{775a}', '\u{775b}'), ('\u{7765}', '\u{7765}'),
+    ('\u{7768}', '\u{7768}'), ('\u{776b}', '\u{776b}'), ('\u{7779}', '\u{7779}'), ('\u{777e}', '\u{777f}'),
+    ('\u{778b}', '\u{778b}'), ('\u{778e}', '\u{778e}'), ('\u{7791}', '\u{7791}'), ('\u{779e}', '\u{779e}'),
+    ('\u{77a0}', '\u{77a0}'), ('\u{77b0}', '\u{77b0}'), ('\u{77b6}', '\u{77b6}'), ('\u{77b9}', '\u{77b9}'),
+    ('\u{77bb}', '\u{77bd}'), ('\u{77bf}', '\u{77bf}'), ('\u{77c7}', '\u{77c7}'), ('\u{77cd}', '\u{77cd}'),
+    ('\u{77d7}', '\u{77d7}'), ('\u{77da}', '\u{77da}'), ('\u{77dc}', '\u{77dc}'), ('\u{77e3}', '\u{77e3}'),
+    ('\u{77ee}', '\u{77ee}'), ('\u{77fc}', '\u{77fc}'), ('\u{780c}', '\u{780c}'), ('\u{7812}', '\u{7812}'),
+    ('\u{7820}', '\u{7820}'), ('\u{7845}', '\u{7845}'), ('\u{7874}', '\u{7874}'), ('\u{787c}', '\u{787c}'),
+    ('\u{7886}', '\u{7886}'), ('\u{788c}', '\u{788c}'), ('\u{788e}', '\u{788e}'), ('\u{789a}', '\u{789a}'),
+    ('\u{78a3}', '\u{78a3}'), ('\u{78aa}', '\u{78aa}'), ('\u{78af}', '\u{78af}'), ('\u{78b5}', '\u{78b5}'),
+    ('\u{78bc}', '\u{78bc}'), ('\u{78be}', '\u{78be}'), ('\u{78c5}', '\u{78c6}'), ('\u{78ca}', '\u{78cb}'),
+    ('\u{78d1}', '\u{78d1}'), ('\u{78d4}', '\u{78d4}'), ('\u{78da}', '\u{78da}'), ('\u{78e7}', '\u{78e7}'),
+    ('\u{78ec}', '\u{78ec}'), ('\u{78f4}', '\u{78f4}'), ('\u{78fd}', '\u{78fd}'), ('\u{7907}', '\u{7907}'),
+    ('\u{7911}', '\u{7912}'), ('\u{7919}', '\u{7919}'), ('\u{7926}', '\u{7926}'), ('\u{792a}', '\u{792c}'),
+    ('\u{7940}', '\u{7940}'), ('\u{7953}', '\u{7953}'), ('\u{7955}', '\u{7955}'), ('\u{7957}', '\u{7957}'),
+    ('\u{795a}', '\u{795a}'), ('\u{795f}', '\u{7960}'), ('\u{797a}', '\u{797a}'), ('\u{797f}', '\u{7980}'),
+    ('\u{798a}', '\u{798a}'), ('\u{799d}', '\u{799d}'), ('\u{79a7}', '\u{79a7}'), ('\u{79aa}', '\u{79aa}'),
+    ('\u{79ae}', '\u{79ae}'), ('\u{79b3}', '\u{79b3}'), ('\u{79b9}', '\u{79ba}'), ('\u{79c9}', '\u{79c9}'),
+    ('\u{79d5}', '\u{79d5}'), ('\u{79e1}', '\u{79e1}'), ('\u{79e3}', '\u{79e3}'), ('\u{79e7}', '\u{79e7}'),
+    ('\u{79ec}', '\u{79ec}'), ('\u{7a08}', '\u{7a08}'), ('\u{7a0d}', '\u{7a0d}'), ('\u{7a18}', '\u{7a19}'),
+    ('\u{7a1f}', '\u{7a20}'), ('\u{7a31}', '\u{7a31}'), ('\u{7a37}', '\u{7a37}'), ('\u{7a3b}', '\u{7a3b}'),
+    ('\u{7a3e}', '\u{7a3e}'), ('\u{7a43}', '\u{7a43}'), ('\u{7a49}', '\u{7a49}'), ('\u{7a57}', '\u{7a57}'),
+    ('\u{7a61}', '\u{7a62}'), ('\u{7a69}', '\u{7a69}'), ('\u{7a70}', '\u{7a70}'), ('\u{7a79}', '\u{7a79}'),
+    ('\u{7a7d}', '\u{7a7d}'), ('\u{7a88}', '\u{7a88}'), ('\u{7a95}', '\u{7a98}'), ('\u{7aa9}', '\u{7aa9}'),
+    ('\u{7ab0}', '\u{7ab0}'), ('\u{7ab6}', '\u{7ab6}'), ('\u{7abf}', '\u{7abf}'), ('\u{7ac4}', '\u{7ac5}'),
+    ('\u{7ac7}', '\u{7ac8}'), ('\u{7aca}', '\u{7aca}'), ('\u{7acd}', '\u{7acd}'), ('\u{7acf}', '\u{7acf}'),
+    ('\u{7ad2}', '\u{7ad3}'), ('\u{7ad5}', '\u{7ad5}'), ('\u{7ad9}', '\u{7ada}'), ('\u{7add}', '\u{7add}'),
+    ('\u{7adf}', '\u{7adf}'), ('\u{7ae1}', '\u{7ae2}'), ('\u{7ae6}', '\u{7ae6}'), ('\u{7aed}', '\u{7aed}'),
+    ('\u{7af0}', '\u{7af0}'), ('\u{7af8}', '\u{7af8}'), ('\u{7b02}', '\u{7b02}'), ('\u{7b04}', '\u{7b04}'),
+    ('\u{7b06}', '\u{7b06}'), ('\u{7b0a}', '\u{7b0b}'), ('\u{7b0f}', '\u{7b0f}'), ('\u{7b18}', '\u{7b19}'),
+    ('\u{7b1e}', '\u{7b1e}'), ('\u{7b28}', '\u{7b28}'), ('\u{7b33}', '\u{7b33}'), ('\u{7b35}', '\u{7b36}'),
+    ('\u{7b45}', '\u{7b45}'), ('\u{7b4c}', '\u{7b4d}'), ('\u{7b50}', '\u{7b50}'), ('\u{7b5d}', '\u{7b5d}'),
+    ('\u{7b65}', '\u{7b65}'), ('\u{7b67}', '\u{7b67}'), ('\u{7b6c}', '\u{7b6c}'), ('\u{7b6e}', '\u{7b6e}'),
+    ('\u{7b70}', '\u{7b71}'), ('\u{7b74}', '\u{7b75}'), ('\u{7b7a}', '\u{7b7a}'), ('\u{7b8b}', '\u{7b8b}'),
+    ('\u{7b8d}', '\u{7b8d}'), ('\u{7b8f}', '\u{7b8f}'), ('\u{7b92}', '\u{7b92}'), ('\u{7b98}', '\u{7b9a}'),
+    ('\u{7b9c}', '\u{7b9d}'), ('\u{7b9f}', '\u{7b9f}'), ('\u{7bb4}', '\u{7bb4}'), ('\u{7bc1}', '\u{7bc1}'),
+    ('\u{7bc6}', '\u{7bc6}'), ('\u{7bcb}', '\u{7bcc}'), ('\u{7bcf}', '\u{7bcf}'), ('\u{7bdd}', '\u{7bdd}'),
+    ('\u{7be5}', '\u{7be6}'), ('\u{7be9}', '\u{7be9}'), ('\u{7bf3}', '\u{7bf3}'), ('\u{7bf6}', '\u{7bf7}'),
+    ('\u{7c00}', '\u{7c00}'), ('\u{7c07}', '\u{7c07}'), ('\u{7c0d}', '\u{7c0d}'), ('\u{7c11}', '\u{7c14}'),
+    ('\u{7c17}', '\u{7c17}'), ('\u{7c1f}', '\u{7c1f}'), ('\u{7c23}', '\u{7c23}'), ('\u{7c27}', '\u{7c27}'),
+    ('\u{7c2a}', '\u{7c2b}'), ('\u{7c37}', '\u{7c37}'), ('\u{7c3d}', '\u{7c3d}'), ('\u{7c40}', '\u{7c40}'),
+    ('\u{7c43}', '\u{7c43}'), ('\u{7c4c}', '\u{7c4c}'), ('\u{7c4f}', '\u{7c50}'), ('\u{7c54}', '\u{7c54}'),
+    ('\u{7c56}', '\u{7c56}'), ('\u{7c58}', '\u{7c58}'), ('\u{7c5f}', '\u{7c60}'), ('\u{7c64}', '\u{7c65}'),
+    ('\u{7c6c}', '\u{7c6c}'), ('\u{7c75}', '\u{7c75}'), ('\u{7c83}', '\u{7c83}'), ('\u{7c90}', '\u{7c90}'),
+    ('\u{7ca1}', '\u{7ca2}'), ('\u{7ca4}', '\u{7ca4}'), ('\u{7ca8}', '\u{7ca8}'), ('\u{7cab}', '\u{7cab}'),
+    ('\u{7cad}', '\u{7cae}'), ('\u{7cb1}', '\u{7cb3}'), ('\u{7cb9}', '\u{7cb9}'), ('\u{7cbd}', '\u{7cbd}'),
+    ('\u{7cc0}', '\u{7cc0}'), ('\u{7cc2}', '\u{7cc2}'), ('\u{7cc5}', '\u{7cc5}'), ('\u{7cd2}', '\u{7cd2}'),
+    ('\u{7cd8}', '\u{7cd8}'), ('\u{7cdc}', '\u{7cdc}'), ('\u{7ce2}', '\u{7ce2}'), ('\u{7cef}', '\u{7cef}'),
+    ('\u{7cf2}', '\u{7cf2}'), ('\u{7cf4}', '\u{7cf4}'), ('\u{7cf6}', '\u{7cf6}'), ('\u{7cfa}', '\u{7cfa}'),
+    ('\u{7d02}', '\u{7d02}'), ('\u{7d06}', '\u{7d06}'), ('\u{7d0a}', '\u{7d0a}'), ('\u{7d15}', '\u{7d15}'),
+    ('\u{7d1c}', '\u{7d1c}'), ('\u{7d2e}', '\u{7d2e}'), ('\u{7d32}', '\u{7d32}'), ('\u{7d35}', '\u{7d35}'),
+    ('\u{7d3f}', '\u{7d3f}'), ('\u{7d45}', '\u{7d46}'), ('\u{7d4b}', '\u{7d4b}'), ('\u{7d4e}', '\u{7d4f}'),
+    ('\u{7d56}', '\u{7d56}'), ('\u{7d5b}', '\u{7d5b}'), ('\u{7d63}', '\u{7d63}'), ('\u{7d68}', '\u{7d68}'),
+    ('\u{7d6e}', '\u{7d6e}'), ('\u{7d72}', '\u{7d73}'), ('\u{7d7d}', '\u{7d7d}'), ('\u{7d89}', '\u{7d89}'),
+    ('\u{7d8f}', '\u{7d8f}'), ('\u{7d93}', '\u{7d93}'), ('\u{7d9b}', '\u{7d9b}'), ('\u{7d9f}', '\u{7d9f}'),
+    ('\u{7da2}', '\u{7da3}'), ('\u{7dab}', '\u{7dab}'), ('\u{7dae}', '\u{7db0}'), ('\u{7db5}', '\u{7db5}'),
+    ('\u{7db8}', '\u{7db8}'), ('\u{7dba}', '\u{7dba}'), ('\u{7dbd}', '\u{7dbd}'), ('\u{7dc7}', '\u{7dc7}'),
+    ('\u{7dd5}', '\u{7dd5}'), ('\u{7dd8}', '\u{7dd8}'), ('\u{7ddc}', '\u{7dde}'), ('\u{7de1}', '\u{7de1}'),
+    ('\u{7de4}', '\u{7de4}'), ('\u{7df2}', '\u{7df2}'), ('\u{7dfb}', '\u{7dfb}'), ('\u{7e05}', '\u{7e05}'),
+    ('\u{7e09}', '\u{7e0b}'), ('\u{7e12}', '\u{7e12}'), ('\u{7e1f}', '\u{7e1f}'), ('\u{7e21}', '\u{7e23}'),
+    ('\u{7e31}', '\u{7e32}'), ('\u{7e35}', '\u{7e35}'), ('\u{7e37}', '\u{7e37}'), ('\u{7e39}', '\u{7e3b}'),
+    ('\u{7e3d}', '\u{7e3d}'), ('\u{7e43}', '\u{7e43}'), ('\u{7e46}', '\u{7e46}'), ('\u{7e56}', '\u{7e56}'),
+    ('\u{7e59}', '\u{7e5a}'), ('\u{7e5d}', '\u{7e5e}'), ('\u{7e66}', '\u{7e67}'), ('\u{7e69}', '\u{7e6a}'),
+    ('\u{7e79}', '\u{7e79}'), ('\u{7e7b}', '\u{7e7d}'), ('\u{7e7f}', '\u{7e7f}'), ('\u{7e83}', '\u{7e83}'),
+    ('\u{7e88}', '\u{7e89}'), ('\u{7e8c}', '\u{7e8c}'), ('\u{7e8e}', '\u{7e8e}'), ('\u{7e90}', '\u{7e90}'),
+    ('\u{7e92}', '\u{7e94}'), ('\u{7e96}', '\u{7e96}'), ('\u{7e9b}', '\u{7e9c}'), ('\u{7f38}', '\u{7f38}'),
+    ('\u{7f3a}', '\u{7f3a}'), ('\u{7f45}', '\u{7f45}'), ('\u{7f4c}', '\u{7f4e}'), ('\u{7f50}', '\u{7f51}'),
+    ('\u{7f54}', '\u{7f55}'), ('\u{7f58}', '\u{7f58}'), ('\u{7f5f}', '\u{7f60}'), ('\u{7f67}', '\u{7f69}'),
+    ('\u{7f78}', '\u{7f79}'), ('\u{7f82}', '\u{7f83}'), ('\u{7f86}', '\u{7f88}'), ('\u{7f8c}', '\u{7f8c}'),
+    ('\u{7f94}', '\u{7f94}'), ('\u{7f9a}', '\u{7f9a}'), ('\u{7f9d}', '\u{7f9e}'), ('\u{7fa3}', '\u{7fa3}'),
+    ('\u{7fae}', '\u{7faf}'), ('\u{7fb2}', '\u{7fb2}'), ('\u{7fb6}', '\u{7fb6}'), ('\u{7fb8}', '\u{7fb9}'),
+    ('\u{7fc5}', '\u{7fc6}'), ('\u{7fca}', '\u{7fca}'), ('\u{7fd4}', '\u{7fd5}'), ('\u{7fe1}', '\u{7fe1}'),
+    ('\u{7fe6}', '\u{7fe6}'), ('\u{7fe9}', '\u{7fe9}'), ('\u{7ff3}', '\u{7ff3}'), ('\u{7ff9}', '\u{7ff9}'),
+    ('\u{8004}', '\u{8004}'), ('\u{8006}', '\u{8006}'), ('\u{800b}', '\u{800b}'), ('\u{8012}', '\u{8012}'),
+    ('\u{8018}', '\u{8019}'), ('\u{801c}', '\u{801c}'), ('\u{8021}', '\u{8021}'), ('\u{8028}', '\u{8028}'),
+    ('\u{803b}', '\u{803b}'), ('\u{803f}', '\u{803f}'), ('\u{8046}', '\u{8046}'), ('\u{804a}', '\u{804a}'),
+    ('\u{8052}', '\u{8052}'), ('\u{8058}', '\u{8058}'), ('\u{805a}', '\u{805a}'), ('\u{805f}', '\u{805f}'),
+    ('\u{8062}', '\u{8062}'), ('\u{8068}', '\u{8068}'), ('\u{8070}', '\u{8070}'), ('\u{8072}', '\u{8073}'),
+    ('\u{8076}', '\u{8076}'), ('\u{8079}', '\u{8079}'), ('\u{807d}', '\u{807d}'), ('\u{807f}', '\u{807f}'),
+    ('\u{8084}', '\u{8086}'), ('\u{8093}', '\u{8093}'), ('\u{809a}', '\u{809b}'), ('\u{80ac}', '\u{80ad}'),
+    ('\u{80c4}', '\u{80c4}'), ('\u{80d6}', '\u{80d6}'), ('\u{80d9}', '\u{80db}'), ('\u{80dd}', '\u{80dd}'),
+    ('\u{80e5}', '\u{80e5}'), ('\u{80ef}', '\u{80ef}'), ('\u{80f1}', '\u{80f1}'), ('\u{80fc}', '\u{80fc}'),
+    ('\u{8109}', '\u{8109}'), ('\u{811b}', '\u{811b}'), ('\u{8123}', '\u{8123}'), ('\u{8129}', '\u{8129}'),
+    ('\u{812f}', '\u{812f}'), ('\u{813e}', '\u{813e}'), ('\u{8146}', '\u{8146}'), ('\u{814b}', '\u{814b}'),
+    ('\u{8151}', '\u{8151}'), ('\u{8153}', '\u{8153}'), ('\u{815f}', '\u{815f}'), ('\u{8165}', '\u{8166}'),
+    ('\u{816e}', '\u{816e}'), ('\u{8171}', '\u{8171}'), ('\u{8174}', '\u{8174}'), ('\u{8180}', '\u{8180}'),
+    ('\u{8182}', '\u{8183}'), ('\u{8188}', '\u{8188}'), ('\u{818a}', '\u{818a}'), ('\u{8193}', '\u{8193}'),
+    ('\u{8195}', '\u{8195}'), ('\u{81a0}', '\u{81a0}'), ('\u{81a3}', '\u{81a4}'), ('\u{81a9}', '\u{81a9}'),
+    ('\u{81b0}', '\u{81b0}'), ('\u{81b5}', '\u{81b5}'), ('\u{81b8}', '\u{81b8}'), ('\u{81ba}', '\u{81ba}'),
+    ('\u{81bd}', '\u{81be}'), ('\u{81c0}', '\u{81c0}'), ('\u{81c2}', '\u{81c2}'), ('\u{81c8}', '\u{81c9}'),
+    ('\u{81cd}', '\u{81cd}'), ('\u{81d1}', '\u{81d1}'), ('\u{81d8}', '\u{81da}'), ('\u{81df}', '\u{81e0}'),
+    ('\u{81e7}', '\u{81e7}'), ('\u{81fa}', '\u{81fb}'), ('\u{81fe}', '\u{81fe}'), ('\u{8201}', '\u{8202}'),
+    ('\u{8205}', '\u{8205}'), ('\u{8207}', '\u{8207}'), ('\u{8209}', '\u{820a}'), ('\u{820d}', '\u{820d}'),
+    ('\u{8210}', '\u{8210}'), ('\u{8212}', '\u{8212}'), ('\u{8216}', '\u{8216}'), ('\u{8229}', '\u{8229}'),
+    ('\u{822b}', '\u{822b}'), ('\u{822e}', '\u{822e}'), ('\u{8233}', '\u{8233}'), ('\u{8238}', '\u{8238}'),
+    ('\u{8240}', '\u{8240}'), ('\u{8258}', '\u{825a}'), ('\u{825d}', '\u{825d}'), ('\u{825f}', '\u{825f}'),
+    ('\u{8262}', '\u{8262}'), ('\u{8264}', '\u{8264}'), ('\u{8268}', '\u{8268}'), ('\u{826a}', '\u{826b}'),
+    ('\u{8271}', '\u{8271}'), ('\u{8277}', '\u{8278}'), ('\u{827e}', '\u{827e}'), ('\u{828d}', '\u{828d}'),
+    ('\u{8292}', '\u{8292}'), ('\u{829f}', '\u{829f}'), ('\u{82ab}', '\u{82ac}'), ('\u{82bb}', '\u{82bb}'),
+    ('\u{82d2}', '\u{82d2}'), ('\u{82d9}', '\u{82d9}'), ('\u{82dc}', '\u{82dc}'), ('\u{82de}', '\u{82df}'),
+    ('\u{82e1}', '\u{82e1}'), ('\u{82e3}', '\u{82e3}'), ('\u{82f3}', '\u{82f4}'), ('\u{82f9}', '\u{82fb}'),
+    ('\u{8303}', '\u{8303}'), ('\u{8306}', '\u{8306}'), ('\u{8309}', '\u{8309}'), ('\u{8316}', '\u{8318}'),
+    ('\u{8323}', '\u{8323}'), ('\u{832b}', '\u{832b}'), ('\u{832f}', '\u{832f}'), ('\u{8331}', '\u{8332}'),
+    ('\u{8334}', '\u{8335}'), ('\u{8339}', '\u{8339}'), ('\u{8340}', '\u{8340}'), ('\u{8345}', '\u{8345}'),
+    ('\u{8350}', '\u{8350}'), ('\u{8373}', '\u{8373}'), ('\u{8375}', '\u{8375}'), ('\u{837c}', '\u{837c}'),
+    ('\u{8385}', '\u{8385}'), ('\u{8387}', '\u{8387}'), ('\u{8389}', '\u{838a}'), ('\u{838e}', '\u{838e}'),
+    ('\u{8393}', '\u{8393}'), ('\u{8396}', '\u{8396}'), ('\u{839a}', '\u{839a}'), ('\u{839f}', '\u{83a0}'),
+    ('\u{83a2}', '\u{83a2}'), ('\u{83a8}', '\u{83a8}'), ('\u{83aa}', '\u{83aa}'), ('\u{83b5}', '\u{83b5}'),
+    ('\u{83bd}', '\u{83bd}'), ('\u{83c1}', '\u{83c1}'), ('\u{83ce}', '\u{83ce}'), ('\u{83d8}', '\u{83d8}'),
+    ('\u{83e0}', '\u{83e0}'), ('\u{83eb}', '\u{83eb}'), ('\u{83f2}', '\u{83f2}'), ('\u{83f4}', '\u{83f4}'),
+    ('\u{83f7}', '\u{83f7}'), ('\u{83fb}', '\u{83fb}'), ('\u{83fd}', '\u{83fd}'), ('\u{8403}', '\u{8403}'),
+    ('\u{8407}', '\u{8407}'), ('\u{840b}', '\u{840b}'), ('\u{840d}', '\u{840d}'), ('\u{8413}', '\u{8413}'),
+    ('\u{8420}', '\u{8420}'), ('\u{8422}', '\u{8422}'), ('\u{842a}', '\u{842a}'), ('\u{842c}', '\u{842c}'),
+    ('\u{8435}', '\u{8435}'), ('\u{8438}', '\u{8438}'), ('\u{843c}', '\u{843c}'), ('\u{8446}', '\u{8446}'),
+    ('\u{8462}', '\u{8462}'), ('\u{8469}', '\u{8469}'), ('\u{846b}', '\u{846b}'), ('\u{846d}', '\u{846f}'),
+    ('\u{8477}', '\u{8477}'), ('\u{8479}', '\u{8479}'), ('\u{8482}', '\u{8482}'), ('\u{8484}', '\u{8484}'),
+    ('\u{849f}', '\u{849f}'), ('\u{84a1}', '\u{84a1}'), ('\u{84ad}', '\u{84ad}'), ('\u{84b9}', '\u{84b9}'),
+    ('\u{84bb}', '\u{84bb}'), ('\u{84bf}', '\u{84bf}'), ('\u{84c1}', '\u{84c1}'), ('\u{84c6}', '\u{84c6}'),
+    ('\u{84ca}', '\u{84ca}'), ('\u{84cd}', '\u{84cd}'), ('\u{84d0}', '\u{84d0}'), ('\u{84d6}', '\u{84d6}'),
+    ('\u{84d9}', '\u{84da}'), ('\u{84f4}', '\u{84f4}'), ('\u{84fc}', '\u{84fc}'), ('\u{84ff}', '\u{84ff}'),
+    ('\u{8506}', '\u{8506}'), ('\u{8514}', '\u{8515}'), ('\u{8517}', '\u{8518}'), ('\u{851f}', '\u{851f}'),
+    ('\u{8521}', '\u{8521}'), ('\u{852c}', '\u{852c}'), ('\u{8540}', '\u{8541}'), ('\u{8548}', '\u{8548}'),
+    ('\u{854b}', '\u{854b}'), ('\u{8555}', '\u{8555}'), ('\u{8558}', '\u{8558}'), ('\u{855a}', '\u{855a}'),
+    ('\u{8563}', '\u{8563}'), ('\u{856d}', '\u{856d}'), ('\u{8577}', '\u{8577}'), ('\u{857e}', '\u{857e}'),
+    ('\u{8580}', '\u{8580}'), ('\u{8587}', '\u{8588}'), ('\u{858a}', '\u{858a}'), ('\u{8590}', '\u{8591}'),
+    ('\u{8594}', '\u{8594}'), ('\u{859b}', '\u{859c}'), ('\u{85a4}', '\u{85a4}'), ('\u{85a8}', '\u{85a8}'),
+    ('\u{85b9}', '\u{85ba}'), ('\u{85c9}', '\u{85c9}'), ('\u{85cf}', '\u{85d0}'), ('\u{85d5}', '\u{85d5}'),
+    ('\u{85dc}', '\u{85dd}'), ('\u{85e5}', '\u{85e5}'), ('\u{85ea}', '\u{85ea}'), ('\u{85f9}', '\u{85fa}'),
+    ('\u{85fe}', '\u{85fe}'), ('\u{8602}', '\u{8602}'), ('\u{8606}', '\u{8606}'), ('\u{860a}', '\u{860b}'),
+    ('\u{8613}', '\u{8613}'), ('\u{8616}', '\u{8617}'), ('\u{861a}', '\u{861a}'), ('\u{8622}', '\u{8622}'),
+    ('\u{862f}', '\u{8630}'), ('\u{863f}', '\u{863f}'), ('\u{864d}', '\u{864d}'), ('\u{8654}', '\u{8655}'),
+    ('\u{865f}', '\u{865f}'), ('\u{8667}', '\u{8667}'), ('\u{8671}', '\u{8671}'), ('\u{868b}', '\u{868c}'),
+    ('\u{8693}', '\u{8693}'), ('\u{86a3}', '\u{86a3}'), ('\u{86a9}', '\u{86ab}'), ('\u{86af}', '\u{86b0}'),
+    ('\u{86b6}', '\u{86b6}'), ('\u{86c4}', '\u{86c4}'), ('\u{86c6}', '\u{86c6}'), ('\u{86c9}', '\u{86c9}'),
+    ('\u{86d4}', '\u{86d4}'), ('\u{86db}', '\u{86db}'), ('\u{86de}', '\u{86df}'), ('\u{86e9}', '\u{86e9}'),
+    ('\u{86ec}', '\u{86ec}'), ('\u{86ef}', '\u{86ef}'), ('\u{86f9}', '\u{86f9}'), ('\u{86fb}', '\u{86fb}'),
+    ('\u{8700}', '\u{8700}'), ('\u{8703}', '\u{8703}'), ('\u{8706}', '\u{8706}'), ('\u{8708}', '\u{870a}'),
+    ('\u{870d}', '\u{870d}'), ('\u{8711}', '\u{8712}'), ('\u{871a}', '\u{871a}'), ('\u{8725}', '\u{8725}'),
+    ('\u{8729}', '\u{8729}'), ('\u{8734}', '\u{8734}'), ('\u{8737}', '\u{8737}'), ('\u{873b}', '\u{873b}'),
+    ('\u{873f}', '\u{873f}'), ('\u{874c}', '\u{874c}'), ('\u{874e}', '\u{874e}'), ('\u{8753}', '\u{8753}'),
+    ('\u{8757}', '\u{8757}'), ('\u{8759}', '\u{8759}'), ('\u{875f}', '\u{8760}'), ('\u{8763}', '\u{8763}'),
+    ('\u{8768}', '\u{8768}'), ('\u{876a}', '\u{876a}'), ('\u{876e}', '\u{876e}'), ('\u{8774}', '\u{8774}'),
+    ('\u{8778}', '\u{8778}'), ('\u{8782}', '\u{8782}'), ('\u{879f}', '\u{879f}'), ('\u{87a2}', '\u{87a2}'),
+    ('\u{87ab}', '\u{87ab}'), ('\u{87af}', '\u{87af}'), ('\u{87b3}', '\u{87b3}'), ('\u{87bb}', '\u{87bb}'),
+    ('\u{87bd}', '\u{87bd}'), ('\u{87c0}', '\u{87c0}'), ('\u{87c4}', '\u{87c4}'), ('\u{87c6}', '\u{87c7}'),
+    ('\u{87cb}', '\u{87cb}'), ('\u{87d0}', '\u{87d0}'), ('\u{87d2}', '\u{87d2}'), ('\u{87e0}', '\u{87e0}'),
+    ('\u{87ef}', '\u{87ef}'), ('\u{87f2}', '\u{87f2}'), ('\u{87f6}', '\u{87f7}'), ('\u{87fe}', '\u{87fe}'),
+    ('\u{8805}', '\u{8805}'), ('\u{880d}', '\u{880f}'), ('\u{8811}', '\u{8811}'), ('\u{8815}', '\u{8816}'),
+    ('\u{8821}', '\u{8823}'), ('\u{8827}', '\u{8827}'), ('\u{8831}', '\u{8831}'), ('\u{8836}', '\u{8836}'),
+    ('\u{8839}', '\u{8839}'), ('\u{883b}', '\u{883b}'), ('\u{8842}', '\u{8842}'), ('\u{8844}', '\u{8844}'),
+    ('\u{884d}', '\u{884d}'), ('\u{8852}', '\u{8852}'), ('\u{8859}', '\u{8859}'), ('\u{885e}', '\u{885e}'),
+    ('\u{8862}', '\u{8862}'), ('\u{886b}', '\u{886b}'), ('\u{8872}', '\u{8872}'), ('\u{8875}', '\u{8875}'),
+    ('\u{887d}', '\u{887e}'), ('\u{8881}', '\u{8882}'), ('\u{888d}', '\u{888d}'), ('\u{8892}', '\u{8892}'),
+    ('\u{8897}', '\u{8897}'), ('\u{8899}', '\u{8899}'), ('\u{889e}', '\u{889e}'), ('\u{88a2}', '\u{88a2}'),
+    ('\u{88a4}', '\u{88a4}'), ('\u{88ae}', '\u{88ae}'), ('\u{88b0}', '\u{88b1}'), ('\u{88b5}', '\u{88b5}'),
+    ('\u{88bf}', '\u{88bf}'), ('\u{88c3}', '\u{88c4}'), ('\u{88d4}', '\u{88d4}'), ('\u{88d8}', '\u{88d9}'),
+    ('\u{88dd}', '\u{88dd}'), ('\u{88e8}', '\u{88e8}'), ('\u{88f2}', '\u{88f2}'), ('\u{88f4}', '\u{88f4}'),
+    ('\u{88f9}', '\u{88f9}'), ('\u{88fc}', '\u{88fc}'), ('\u{8902}', '\u{8902}'), ('\u{8904}', '\u{8904}'),
+    ('\u{890a}', '\u{890a}'), ('\u{890c}', '\u{890c}'), ('\u{8913}', '\u{8913}'), ('\u{891d}', '\u{891e}'),
+    ('\u{8925}', '\u{8925}'), ('\u{892a}', '\u{892b}'), ('\u{8936}', '\u{8936}'), ('\u{8938}', '\u{8938}'),
+    ('\u{893b}', '\u{893b}'), ('\u{8941}', '\u{8941}'), ('\u{8943}', '\u{8944}'), ('\u{894c}', '\u{894d}'),
+    ('\u{895e}', '\u{895e}'), ('\u{8960}', '\u{8960}'), ('\u{8964}', '\u{8964}'), ('\u{8966}', '\u{8966}'),
+    ('\u{896a}', '\u{896a}'), ('\u{896d}', '\u{896d}'), ('\u{896f}', '\u{896f}'), ('\u{8974}', '\u{8974}'),
+    ('\u{8977}', '\u{8977}'), ('\u{897e}', '\u{897e}'), ('\u{8983}', '\u{8983}'), ('\u{8988}', '\u{8988}'),
+    ('\u{898a}', '\u{898a}'), ('\u{8993}', '\u{8993}'), ('\u{8998}', '\u{8998}'), ('\u{89a1}', '\u{89a1}'),
+    ('\u{89a6}', '\u{89a6}'), ('\u{89a9}', '\u{89a9}'), ('\u{89ac}', '\u{89ac}'), ('\u{89af}', '\u{89af}'),
+    ('\u{89b2}', '\u{89b2}'), ('\u{89ba}', '\u{89ba}'), ('\u{89bd}', '\u{89bd}'), ('\u{89bf}', '\u{89c0}'),
+    ('\u{89da}', '\u{89da}'), ('\u{89dc}', '\u{89dd}'), ('\u{89e7}', '\u{89e7}'), ('\u{89f4}', '\u{89f4}'),
+    ('\u{89f8}', '\u{89f8}'), ('\u{8a03}', '\u{8a03}'), ('\u{8a0c}', '\u{8a0c}'), ('\u{8a10}', '\u{8a10}'),
+    ('\u{8a16}', '\u{8a16}'), ('\u{8a1b}', '\u{8a1b}'), ('\u{8a1d}', '\u{8a1d}'), ('\u{8a25}', '\u{8a25}'),
+    ('\u{8a36}', '\u{8a36}'), ('\u{8a41}', '\u{8a41}'), ('\u{8a46}', '\u{8a46}'), ('\u{8a48}', '\u{8a48}'),
+    ('\u{8a52}', '\u{8a52}'), ('\u{8a5b}', '\u{8a5b}'), ('\u{8a62}', '\u{8a62}'), ('\u{8a6c}', '\u{8a6d}'),
+    ('\u{8a7c}', '\u{8a7c}'), ('\u{8a82}', '\u{8a82}'), ('\u{8a84}', '\u{8a85}'), ('\u{8a91}', '\u{8a91}'),
+    ('\u{8a9a}', '\u{8a9a}'), ('\u{8aa1}', '\u{8aa1}'), ('\u{8aa3}', '\u{8aa3}'), ('\u{8aa5}', '\u{8aa6}'),
+    ('\u{8aa8}', '\u{8aa8}'), ('\u{8ac2}', '\u{8ac2}'), ('\u{8ac4}', '\u{8ac4}'), ('\u{8acd}', '\u{8acd}'),
+    ('\u{8ada}', '\u{8adb}'), ('\u{8ade}', '\u{8ade}'), ('\u{8ae0}', '\u{8ae2}'), ('\u{8ae4}', '\u{8ae4}'),
+    ('\u{8ae7}', '\u{8ae7}'), ('\u{8aeb}', '\u{8aeb}'), ('\u{8af1}', '\u{8af1}'), ('\u{8af3}', '\u{8af3}'),
+    ('\u{8af7}', '\u{8af7}'), ('\u{8b07}', '\u{8b07}'), ('\u{8b0c}', '\u{8b0c}'), ('\u{8b10}', '\u{8b10}'),
+    ('\u{8b14}', '\u{8b14}'), ('\u{8b16}', '\u{8b17}'), ('\u{8b1a}', '\u{8b1a}'), ('\u{8b20}', '\u{8b20}'),
+    ('\u{8b26}', '\u{8b26}'), ('\u{8b28}', '\u{8b28}'), ('\u{8b2b}', '\u{8b2b}'), ('\u{8b33}', '\u{8b33}'),
+    ('\u{8b3e}', '\u{8b3e}'), ('\u{8b41}', '\u{8b41}'), ('\u{8b49}', '\u{8b49}'), ('\u{8b4c}', '\u{8b4c}'),
+    ('\u{8b4e}', '\u{8b4f}'), ('\u{8b56}', '\u{8b56}'), ('\u{8b5a}', '\u{8b5b}'), ('\u{8b5f}', '\u{8b5f}'),
+    ('\u{8b6b}', '\u{8b6c}'), ('\u{8b6f}', '\u{8b6f}'), ('\u{8b71}', '\u{8b71}'), ('\u{8b74}', '\u{8b74}'),
+    ('\u{8b7d}', '\u{8b7d}'), ('\u{8b80}', '\u{8b80}'), ('\u{8b8a}', '\u{8b8a}'), ('\u{8b8c}', '\u{8b8c}'),
+    ('\u{8b8e}', '\u{8b8e}'), ('\u{8b92}', '\u{8b93}'), ('\u{8b96}', '\u{8b96}'), ('\u{8b99}', '\u{8b9a}'),
+    ('\u{8c3a}', '\u{8c3a}'), ('\u{8c3f}', '\u{8c3f}'), ('\u{8c41}', '\u{8c41}'), ('\u{8c48}', '\u{8c48}'),
+    ('\u{8c4c}', '\u{8c4c}'), ('\u{8c4e}', '\u{8c4e}'), ('\u{8c50}', '\u{8c50}'), ('\u{8c55}', '\u{8c55}'),
+    ('\u{8c62}', '\u{8c62}'), ('\u{8c6b}', '\u{8c6c}'), ('\u{8c78}', '\u{8c78}'), ('\u{8c7a}', '\u{8c7a}'),
+    ('\u{8c7c}', '\u{8c7c}'), ('\u{8c82}', '\u{8c82}'), ('\u{8c85}', '\u{8c85}'), ('\u{8c89}', '\u{8c8a}'),
+    ('\u{8c8d}', '\u{8c8e}'), ('\u{8c94}', '\u{8c94}'), ('\u{8c98}', '\u{8c98}'), ('\u{8caa}', '\u{8caa}'),
+    ('\u{8cad}', '\u{8cae}'), ('\u{8cb2}', '\u{8cb3}'), ('\u{8cb6}', '\u{8cb6}'), ('\u{8cbd}', '\u{8cbd}'),
+    ('\u{8cc1}', '\u{8cc1}'), ('\u{8cc8}', '\u{8cc8}'), ('\u{8ccd}', '\u{8ccd}'), ('\u{8cda}', '\u{8cda}'),
+    ('\u{8ce3}', '\u{8ce4}'), ('\u{8cfa}', '\u{8cfb}'), ('\u{8cfd}', '\u{8cfd}'), ('\u{8d04}', '\u{8d05}'),
+    ('\u{8d07}', '\u{8d07}'), ('\u{8d0a}', '\u{8d0a}'), ('\u{8d0d}', '\u{8d0d}'), ('\u{8d0f}', '\u{8d10}'),
+    ('\u{8d13}', '\u{8d14}'), ('\u{8d16}', '\u{8d16}'), ('\u{8d67}', '\u{8d67}'), ('\u{8d6d}', '\u{8d6d}'),
+    ('\u{8d71}', '\u{8d71}'), ('\u{8d73}', '\u{8d73}'), ('\u{8d81}', '\u{8d81}'), ('\u{8d99}', '\u{8d99}'),
+    ('\u{8dba}', '\u{8dba}'), ('\u{8dbe}', '\u{8dbe}'), ('\u{8dc2}', '\u{8dc2}'), ('\u{8dcb}', '\u{8dcc}'),
+    ('\u{8dcf}', '\u{8dcf}'), ('\u{8dd6}', '\u{8dd6}'), ('\u{8dda}', '\u{8ddb}'), ('\u{8ddf}', '\u{8ddf}'),
+    ('\u{8de3}', '\u{8de3}'), ('\u{8dea}', '\u{8deb}'), ('\u{8dfc}', '\u{8dfc}'), ('\u{8dff}', '\u{8dff}'),
+    ('\u{8e08}', '\u{8e09}'), ('\u{8e10}', '\u{8e10}'), ('\u{8e1d}', '\u{8e1f}'), ('\u{8e2a}', '\u{8e2a}'),
+    ('\u{8e30}', '\u{8e30}'), ('\u{8e34}', '\u{8e35}'), ('\u{8e42}', '\u{8e42}'), ('\u{8e47}', '\u{8e4a}'),
+    ('\u{8e4c}', '\u{8e4c}'), ('\u{8e50}', '\u{8e50}'), ('\u{8e55}', '\u{8e55}'), ('\u{8e59}', '\u{8e59}'),
+    ('\u{8e60}', '\u{8e60}'), ('\u{8e63}', '\u{8e64}'), ('\u{8e72}', '\u{8e72}'), ('\u{8e76}', '\u{8e76}'),
+    ('\u{8e7c}', '\u{8e7c}'), ('\u{8e81}', '\u{8e81}'), ('\u{8e84}', '\u{8e85}'), ('\u{8e87}', '\u{8e87}'),
+    ('\u{8e8a}', '\u{8e8b}'), ('\u{8e91}', '\u{8e91}'), ('\u{8e93}', '\u{8e94}'), ('\u{8e99}', '\u{8e99}'),
+    ('\u{8ea1}', '\u{8ea1}'), ('\u{8eaa}', '\u{8eaa}'), ('\u{8eac}', '\u{8eac}'), ('\u{8eb0}', '\u{8eb1}'),
+    ('\u{8ebe}', '\u{8ebe}'), ('\u{8ec5}', '\u{8ec6}'), ('\u{8ec8}', '\u{8ec8}'), ('\u{8ecb}', '\u{8ecb}'),
+    ('\u{8edb}', '\u{8edb}'), ('\u{8ee3}', '\u{8ee3}'), ('\u{8eeb}', '\u{8eeb}'), ('\u{8efb}', '\u{8efc}'),
+    ('\u{8efe}', '\u{8efe}'), ('\u{8f05}', '\u{8f05}'), ('\u{8f0a}', '\u{8f0a}'), ('\u{8f0c}', '\u{8f0c}'),
+    ('\u{8f12}', '\u{8f13}'), ('\u{8f15}', '\u{8f15}'), ('\u{8f19}', '\u{8f19}'), ('\u{8f1b}', '\u{8f1c}'),
+    ('\u{8f1f}', '\u{8f1f}'), ('\u{8f26}', '\u{8f26}'), ('\u{8f33}', '\u{8f33}'), ('\u{8f39}', '\u{8f39}'),
+    ('\u{8f3b}', '\u{8f3b}'), ('\u{8f3e}', '\u{8f3e}'), ('\u{8f42}', '\u{8f42}'), ('\u{8f45}', '\u{8f46}'),
+    ('\u{8f49}', '\u{8f49}'), ('\u{8f4c}', '\u{8f4c}'), ('\u{8f4e}', '\u{8f4e}'), ('\u{8f57}', '\u{8f57}'),
+    ('\u{8f5c}', '\u{8f5c}'), ('\u{8f62}', '\u{8f64}'), ('\u{8f9c}', '\u{8f9c}'), ('\u{8f9f}', '\u{8f9f}'),
+    ('\u{8fa3}', '\u{8fa3}'), ('\u{8fa7}', '\u{8fa8}'), ('\u{8fad}', '\u{8faf}'), ('\u{8fb7}', '\u{8fb7}'),
+    ('\u{8fda}', '\u{8fda}'), ('\u{8fe2}', '\u{8fe2}'), ('\u{8fe5}', '\u{8fe5}'), ('\u{8fea}', '\u{8fea}'),
+    ('\u{8fef}', '\u{8fef}'), ('\u{8ff4}', '\u{8ff4}'), ('\u{8ff8}', '\u{8ffa}'), ('\u{9005}', '\u{9005}'),
+    ('\u{900b}', '\u{900b}'), ('\u{900d}', '\u{900e}'), ('\u{9011}', '\u{9011}'), ('\u{9015}', '\u{9016}'),
+    ('\u{901e}', '\u{901e}'), ('\u{9021}', '\u{9021}'), ('\u{9027}', '\u{9027}'), ('\u{9035}', '\u{9036}'),
+    ('\u{9039}', '\u{9039}'), ('\u{903e}', '\u{903e}'), ('\u{9049}', '\u{9049}'), ('\u{904f}', '\u{9052}'),
+    ('\u{9056}', '\u{9056}'), ('\u{9058}', '\u{9059}'), ('\u{905e}', '\u{905e}'), ('\u{9068}', '\u{9068}'),
+    ('\u{906f}', '\u{906f}'), ('\u{9072}', '\u{9072}'), ('\u{9076}', '\u{9076}'), ('\u{907d}', '\u{907d}'),
+    ('\u{9080}', '\u{9083}'), ('\u{9087}', '\u{9087}'), ('\u{9089}', '\u{908a}'), ('\u{908f}', '\u{908f}'),
+    ('\u{90a8}', '\u{90a8}'), ('\u{90af}', '\u{90af}'), ('\u{90b1}', '\u{90b1}'), ('\u{90b5}', '\u{90b5}'),
+    ('\u{90db}', '\u{90db}'), ('\u{90e2}', '\u{90e2}'), ('\u{90e4}', '\u{90e4}'), ('\u{9102}', '\u{9102}'),
+    ('\u{9112}', '\u{9112}'), ('\u{9119}', '\u{9119}'), ('\u{9130}', '\u{9130}'), ('\u{9132}', '\u{9132}'),
+    ('\u{914a}', '\u{914a}'), ('\u{9156}', '\u{9156}'), ('\u{9158}', '\u{9158}'), ('\u{9163}', '\u{9163}'),
+    ('\u{9165}', '\u{9165}'), ('\u{9169}', '\u{9169}'), ('\u{9172}', '\u{9173}'), ('\u{9182}', '\u{9182}'),
+    ('\u{9189}', '\u{9189}'), ('\u{918b}', '\u{918b}'), ('\u{91a2}', '\u{91a2}'), ('\u{91aa}', '\u{91ab}'),
+    ('\u{91af}', '\u{91af}'), ('\u{91b4}', '\u{91b5}'), ('\u{91ba}', '\u{91ba}'), ('\u{91c0}', '\u{91c1}'),
+    ('\u{91c9}', '\u{91c9}'), ('\u{91cb}', '\u{91cb}'), ('\u{91d0}', '\u{91d0}'), ('\u{91d6}', '\u{91d6}'),
+    ('\u{91db}', '\u{91db}'), ('\u{91df}', '\u{91df}'), ('\u{91e1}', '\u{91e1}'), ('\u{91f5}', '\u{91f6}'),
+    ('\u{91fc}', '\u{91fc}'), ('\u{91ff}', '\u{91ff}'), ('\u{9211}', '\u{9211}'), ('\u{9214}', '\u{9215}'),
+    ('\u{921e}', '\u{921e}'), ('\u{9229}', '\u{9229}'), ('\u{922c}', '\u{922c}'), ('\u{923f}', '\u{923f}'),
+    ('\u{9245}', '\u{9245}'), ('\u{9248}', '\u{9249}'), ('\u{924b}', '\u{924b}'), ('\u{9250}', '\u{9250}'),
+    ('\u{9257}', '\u{9257}'), ('\u{925a}', '\u{925a}'), ('\u{925e}', '\u{925e}'), ('\u{9264}', '\u{9264}'),
+    ('\u{9293}', '\u{9293}'), ('\u{9295}', '\u{9296}'), ('\u{929b}', '\u{929c}'), ('\u{92b7}', '\u{92b7}'),
+    ('\u{92b9}', '\u{92b9}'), ('\u{92cf}', '\u{92cf}'), ('\u{92e9}', '\u{92e9}'), ('\u{92fa}', '\u{92fa}'),
+    ('\u{930f}', '\u{930f}'), ('\u{9319}', '\u{931a}'), ('\u{9322}', '\u{9323}'), ('\u{932e}', '\u{932e}'),
+    ('\u{9335}', '\u{9335}'), ('\u{933a}', '\u{933b}'), ('\u{9344}', '\u{9344}'), ('\u{9356}', '\u{9356}'),
+    ('\u{935c}', '\u{935c}'), ('\u{9360}', '\u{9360}'), ('\u{936e}', '\u{936e}'), ('\u{937c}', '\u{937c}'),
+    ('\u{9394}', '\u{9394}'), ('\u{93ac}', '\u{93ad}'), ('\u{93b0}', '\u{93b0}'), ('\u{93b9}', '\u{93b9}'),
+    ('\u{93c3}', '\u{93c3}'), ('\u{93c8}', '\u{93c8}'), ('\u{93d0}', '\u{93d0}'), ('\u{93d6}', '\u{93d8}'),
+    ('\u{93dd}', '\u{93dd}'), ('\u{93e4}', '\u{93e5}'), ('\u{93e8}', '\u{93e8}'), ('\u{9403}', '\u{9403}'),
+    ('\u{9407}', '\u{9407}'), ('\u{9410}', '\u{9410}'), ('\u{9413}', '\u{9414}'), ('\u{941a}', '\u{941a}'),
+    ('\u{9421}', '\u{9421}'), ('\u{942b}', '\u{942b}'), ('\u{9435}', '\u{9436}'), ('\u{943a}', '\u{943a}'),
+    ('\u{9441}', '\u{9441}'), ('\u{9444}', '\u{9444}'), ('\u{9452}', '\u{9452}'), ('\u{945a}', '\u{945b}'),
+    ('\u{945e}', '\u{945e}'), ('\u{9460}', '\u{9460}'), ('\u{9462}', '\u{9462}'), ('\u{946a}', '\u{946a}'),
+    ('\u{9470}', '\u{9470}'), ('\u{9475}', '\u{9475}'), ('\u{9477}', '\u{9477}'), ('\u{947c}', '\u{947f}'),
+    ('\u{9481}', '\u{9481}'), ('\u{9582}', '\u{9582}'), ('\u{9587}', '\u{9587}'), ('\u{958a}', '\u{958a}'),
+    ('\u{9594}', '\u{9594}'), ('\u{9596}', '\u{9596}'), ('\u{9598}', '\u{9599}'), ('\u{95a0}', '\u{95a0}'),
+    ('\u{95a7}', '\u{95a8}'), ('\u{95ad}', '\u{95ad}'), ('\u{95b9}', '\u{95b9}'), ('\u{95bb}', '\u{95bc}'),
+    ('\u{95be}', '\u{95be}'), ('\u{95c3}', '\u{95c3}'), ('\u{95ca}', '\u{95ca}'), ('\u{95cc}', '\u{95cd}'),
+    ('\u{95d4}', '\u{95d6}'), ('\u{95dc}', '\u{95dc}'), ('\u{95e1}', '\u{95e2}'), ('\u{95e5}', '\u{95e5}'),
+    ('\u{9621}', '\u{9621}'), ('\u{9628}', '\u{9628}'), ('\u{962e}', '\u{962f}'), ('\u{9642}', '\u{9642}'),
+    ('\u{964b}', '\u{964c}'), ('\u{964f}', '\u{964f}'), ('\u{965c}', '\u{965f}'), ('\u{9666}', '\u{9666}'),
+    ('\u{966c}', '\u{966c}'), ('\u{9672}', '\u{9672}'), ('\u{9677}', '\u{9677}'), ('\u{968b}', '\u{968b}'),
+    ('\u{968d}', '\u{968d}'), ('\u{9695}', '\u{9695}'), ('\u{9697}', '\u{9698}'), ('\u{96a7}', '\u{96a8}'),
+    ('\u{96aa}', '\u{96aa}'), ('\u{96b0}', '\u{96b2}'), ('\u{96b4}', '\u{96b4}'), ('\u{96b6}', '\u{96b6}'),
+    ('\u{96b8}', '\u{96b9}'), ('\u{96c9}', '\u{96c9}'), ('\u{96cb}', '\u{96cb}'), ('\u{96cd}', '\u{96ce}'),
+    ('\u{96d5}', '\u{96d6}'), ('\u{96d9}', '\u{96d9}'), ('\u{96dc}', '\u{96dc}'), ('\u{96f9}', '\u{96f9}'),
+    ('\u{9704}', '\u{9704}'), ('\u{9706}', '\u{9706}'), ('\u{9708}', '\u{9708}'), ('\u{970d}', '\u{970f}'),
+    ('\u{9711}', '\u{9711}'), ('\u{9713}', '\u{9713}'), ('\u{9716}', '\u{9716}'), ('\u{9719}', '\u{9719}'),
+    ('\u{9724}', '\u{9724}'), ('\u{972a}', '\u{972a}'), ('\u{9730}', '\u{9730}'), ('\u{9738}', '\u{9739}'),
+    ('\u{973d}', '\u{973e}'), ('\u{9742}', '\u{9742}'), ('\u{9744}', '\u{9744}'), ('\u{9746}', '\u{9746}'),
+    ('\u{9748}', '\u{9749}'), ('\u{975c}', '\u{975c}'), ('\u{9760}', '\u{9761}'), ('\u{9764}', '\u{9764}'),
+    ('\u{9766}', '\u{9766}'), ('\u{9768}', '\u{9768}'), ('\u{976b}', '\u{976b}'), ('\u{9771}', '\u{9771}'),
+    ('\u{9779}', '\u{977a}'), ('\u{977c}', '\u{977c}'), ('\u{9781}', '\u{9781}'), ('\u{9785}', '\u{9786}'),
+    ('\u{978b}', '\u{978b}'), ('\u{978f}', '\u{9790}'), ('\u{979c}', '\u{979c}'), ('\u{97a3}', '\u{97a3}'),
+    ('\u{97a6}', '\u{97a6}'), ('\u{97a8}', '\u{97a8}'), ('\u{97ab}', '\u{97ab}'), ('\u{97b3}', '\u{97b4}'),
+    ('\u{97c3}', '\u{97c3}'), ('\u{97c6}', '\u{97c6}'), ('\u{97c8}', '\u{97c8}'), ('\u{97cb}', '\u{97cb}'),
+    ('\u{97dc}', '\u{97dc}'), ('\u{97ed}', '\u{97ed}'), ('\u{97f2}', '\u{97f2}'), ('\u{97f5}', '\u{97f6}'),
+    ('\u{980c}', '\u{980c}'), ('\u{980f}', '\u{980f}'), ('\u{9821}', '\u{9821}'), ('\u{9824}', '\u{9824}'),
+    ('\u{9837}', '\u{9838}'), ('\u{983d}', '\u{983d}'), ('\u{9846}', '\u{9846}'), ('\u{984b}', '\u{984b}'),
+    ('\u{984f}', '\u{984f}'), ('\u{986b}', '\u{986b}'), ('\u{986f}', '\u{9871}'), ('\u{9873}', '\u{9874}'),
+    ('\u{98aa}', '\u{98aa}'), ('\u{98af}', '\u{98af}'), ('\u{98b1}', '\u{98b1}'), ('\u{98b6}', '\u{98b6}'),
+    ('\u{98c3}', '\u{98c4}'), ('\u{98c6}', '\u{98c6}'), ('\u{98dc}', '\u{98dc}'), ('\u{98e9}', '\u{98e9}'),
+    ('\u{98eb}', '\u{98eb}'), ('\u{98ed}', '\u{98ee}'), ('\u{9903}', '\u{9903}'), ('\u{9909}', '\u{9909}'),
+    ('\u{9912}', '\u{9912}'), ('\u{9914}', '\u{9914}'), ('\u{9918}', '\u{9918}'), ('\u{991d}', '\u{991e}'),
+    ('\u{9920}', '\u{9921}'), ('\u{9924}', '\u{9924}'), ('\u{992c}', '\u{992c}'), ('\u{992e}', '\u{992e}'),
+    ('\u{993d}', '\u{993e}'), ('\u{9942}', '\u{9942}'), ('\u{9945}', '\u{9945}'), ('\u{9949}', '\u{9949}'),
+    ('\u{994b}', '\u{994c}'), ('\u{9950}', '\u{9952}'), ('\u{9955}', '\u{9955}'), ('\u{9997}', '\u{9998}'),
+    ('\u{99a5}', '\u{99a5}'), ('\u{99ad}', '\u{99ae}'), ('\u{99bc}', '\u{99bc}'), ('\u{99d1}', '\u{99d1}'),
+    ('\u{99d8}', '\u{99d8}'), ('\u{99db}', '\u{99db}'), ('\u{99dd}', '\u{99dd}'), ('\u{99df}', '\u{99df}'),
+    ('\u{99e2}', '\u{99e2}'), ('\u{99ed}', '\u{99ee}'), ('\u{99f1}', '\u{99f2}'), ('\u{99f8}', '\u{99f8}'),
+    ('\u{99fb}', '\u{99fb}'), ('\u{9a01}', '\u{9a01}'), ('\u{9a05}', '\u{9a05}'), ('\u{9a0f}', '\u{9a0f}'),
+    ('\u{9a19}', '\u{9a19}'), ('\u{9a2b}', '\u{9a2b}'), ('\u{9a37}', '\u{9a37}'), ('\u{9a3e}', '\u{9a3e}'),
+    ('\u{9a40}', '\u{9a40}'), ('\u{9a42}', '\u{9a43}'), ('\u{9a45}', '\u{9a45}'), ('\u{9a4d}', '\u{9a4d}'),
+    ('\u{9a55}', '\u{9a55}'), ('\u{9a57}', '\u{9a57}'), ('\u{9a5b}', '\u{9a5b}'), ('\u{9a5f}', '\u{9a5f}'),
+    ('\u{9a62}', '\u{9a62}'), ('\u{9a64}', '\u{9a65}'), ('\u{9a69}', '\u{9a6b}'), ('\u{9aad}', '\u{9aad}'),
+    ('\u{9ab0}', '\u{9ab0}'), ('\u{9abc}', '\u{9abc}'), ('\u{9ac0}', '\u{9ac0}'), ('\u{9acf}', '\u{9acf}'),
+    ('\u{9ad1}', '\u{9ad1}'), ('\u{9ad3}', '\u{9ad4}'), ('\u{9ade}', '\u{9adf}'), ('\u{9ae2}', '\u{9ae3}'),
+    ('\u{9ae6}', '\u{9ae6}'), ('\u{9aeb}', '\u{9aeb}'), ('\u{9aee}', '\u{9aef}'), ('\u{9af1}', '\u{9af1}'),
+    ('\u{9af4}', '\u{9af4}'), ('\u{9af7}', '\u{9af7}'), ('\u{9afb}', '\u{9afb}'), ('\u{9b06}', '\u{9b06}'),
+    ('\u{9b18}', '\u{9b18}'), ('\u{9b1a}', '\u{9b1a}'), ('\u{9b1f}', '\u{9b1f}'), ('\u{9b22}', '\u{9b23}'),
+    ('\u{9b25}', '\u{9b25}'), ('\u{9b27}', '\u{9b2a}'), ('\u{9b2e}', '\u{9b2f}'), ('\u{9b31}', '\u{9b32}'),
+    ('\u{9b3b}', '\u{9b3b}'), ('\u{9b43}', '\u{9b44}'), ('\u{9b4d}', '\u{9b4f}'), ('\u{9b51}', '\u{9b51}'),
+    ('\u{9b58}', '\u{9b58}'), ('\u{9b74}', '\u{9b74}'), ('\u{9b83}', '\u{9b83}'), ('\u{9b91}', '\u{9b91}'),
+    ('\u{9b93}', '\u{9b93}'), ('\u{9b96}', '\u{9b97}'), ('\u{9b9f}', '\u{9ba0}'), ('\u{9ba8}', '\u{9ba8}'),
+    ('\u{9bb4}', '\u{9bb4}'), ('\u{9bb9}', '\u{9bb9}'), ('\u{9bc0}', '\u{9bc0}'), ('\u{9bc6}', '\u{9bc6}'),
+    ('\u{9bca}', '\u{9bca}'), ('\u{9bcf}', '\u{9bcf}'), ('\u{9bd1}', '\u{9bd2}'), ('\u{9bd4}', '\u{9bd4}'),
+    ('\u{9be1}', '\u{9be4}'), ('\u{9bf0}', '\u{9bf2}'), ('\u{9c04}', '\u{9c04}'), ('\u{9c06}', '\u{9c06}'),
+    ('\u{9c08}', '\u{9c0a}'), ('\u{9c0c}', '\u{9c0c}'), ('\u{9c12}', '\u{9c15}'), ('\u{9c1b}', '\u{9c1b}'),
+    ('\u{9c21}', '\u{9c21}'), ('\u{9c24}', '\u{9c25}'), ('\u{9c2e}', '\u{9c2e}'), ('\u{9c30}', '\u{9c30}'),
+    ('\u{9c32}', '\u{9c32}'), ('\u{9c3a}', '\u{9c3a}'), ('\u{9c3e}', '\u{9c3e}'), ('\u{9c46}', '\u{9c47}'),
+    ('\u{9c5a}', '\u{9c5a}'), ('\u{9c60}', '\u{9c60}'), ('\u{9c67}', '\u{9c67}'), ('\u{9c76}', '\u{9c76}'),
+    ('\u{9c78}', '\u{9c78}'), ('\u{9ce7}', '\u{9ce7}'), ('\u{9ceb}', '\u{9cec}'), ('\u{9cf0}', '\u{9cf0}'),
+    ('\u{9d03}', '\u{9d03}'), ('\u{9d06}', '\u{9d06}'), ('\u{9d08}', '\u{9d09}'), ('\u{9d12}', '\u{9d12}'),
+    ('\u{9d15}', '\u{9d15}'), ('\u{9d1f}', '\u{9d1f}'), ('\u{9d23}', '\u{9d23}'), ('\u{9d26}', '\u{9d26}'),
+    ('\u{9d2a}', '\u{9d2a}'), ('\u{9d3e}', '\u{9d3f}'), ('\u{9d41}', '\u{9d41}'), ('\u{9d44}', '\u{9d44}'),
+    ('\u{9d46}', '\u{9d46}'), ('\u{9d48}', '\u{9d48}'), ('\u{9d50}', '\u{9d51}'), ('\u{9d59}', '\u{9d59}'),
+    ('\u{9d5d}', '\u{9d5e}'), ('\u{9d64}', '\u{9d64}'), ('\u{9d6f}', '\u{9d6f}'), ('\u{9d72}', '\u{9d72}'),
+    ('\u{9d7a}', '\u{9d7a}'), ('\u{9d87}', '\u{9d87}'), ('\u{9d89}', '\u{9d89}'), ('\u{9d9a}', '\u{9d9a}'),
+    ('\u{9da4}', '\u{9da4}'), ('\u{9da9}', '\u{9da9}'), ('\u{9dab}', '\u{9dab}'), ('\u{9daf}', '\u{9daf}'),
+    ('\u{9db2}', '\u{9db2}'), ('\u{9db8}', '\u{9db8}'), ('\u{9dba}', '\u{9dbb}'), ('\u{9dc1}', '\u{9dc2}'),
+    ('\u{9dc4}', '\u{9dc4}'), ('\u{9dc6}', '\u{9dc6}'), ('\u{9dcf}', '\u{9dcf}'), ('\u{9dd3}', '\u{9dd3}'),
+    ('\u{9dd9}', '\u{9dd9}'), ('\u{9de6}', '\u{9de6}'), ('\u{9ded}', '\u{9ded}'), ('\u{9def}', '\u{9def}'),
+    ('\u{9df8}', '\u{9df8}'), ('\u{9dfd}', '\u{9dfd}'), ('\u{9e1a}', '\u{9e1b}'), ('\u{9e1e}', '\u{9e1e}'),
+    ('\u{9e75}', '\u{9e75}'), ('\u{9e79}', '\u{9e79}'), ('\u{9e7d}', '\u{9e7d}'), ('\u{9e81}', '\u{9e81}'),
+    ('\u{9e88}', '\u{9e88}'), ('\u{9e8b}', '\u{9e8c}'), ('\u{9e91}', '\u{9e92}'), ('\u{9e95}', '\u{9e95}'),
+    ('\u{9e9d}', '\u{9e9d}'), ('\u{9ea5}', '\u{9ea5}'), ('\u{9ea9}', '\u{9eaa}'), ('\u{9ead}', '\u{9ead}'),
+    ('\u{9eb8}', '\u{9eb8}'), ('\u{9ebc}', '\u{9ebc}'), ('\u{9ebe}', '\u{9ebe}'), ('\u{9ecc}', '\u{9ecc}'),
+    ('\u{9ece}', '\u{9ed0}'), ('\u{9ed4}', '\u{9ed4}'), ('\u{9ed8}', '\u{9ed8}'), ('\u{9edc}', '\u{9ede}'),
+    ('\u{9ee0}', '\u{9ee0}'), ('\u{9ee5}', '\u{9ee5}'), ('\u{9ee8}', '\u{9ee8}'), ('\u{9eef}', '\u{9eef}'),
+    ('\u{9ef4}', '\u{9ef4}'), ('\u{9ef6}', '\u{9ef7}'), ('\u{9ef9}', '\u{9ef9}'), ('\u{9efb}', '\u{9efd}'),
+    ('\u{9f07}', '\u{9f08}'), ('\u{9f15}', '\u{9f15}'), ('\u{9f21}', '\u{9f21}'), ('\u{9f2c}', '\u{9f2c}'),
+    ('\u{9f3e}', '\u{9f3e}'), ('\u{9f4a}', '\u{9f4b}'), ('\u{9f4e}', '\u{9f4f}'), ('\u{9f52}', '\u{9f52}'),
+    ('\u{9f54}', '\u{9f54}'), ('\u{9f5f}', '\u{9f61}'), ('\u{9f63}', '\u{9f63}'), ('\u{9f66}', '\u{9f67}'),
+    ('\u{9f6a}', '\u{9f6a}'), ('\u{9f6c}', '\u{9f6c}'), ('\u{9f72}', '\u{9f72}'), ('\u{9f76}', '\u{9f77}'),
+    ('\u{9f95}', '\u{9f95}'), ('\u{9f9c}', '\u{9f9d}'), ('\u{9fa0}', '\u{9fa0}'),
+];
+
+pub const NFC_QUICK_CHECK: &[(char, char)] = &[
+    ('\u{300}', '\u{304}'), ('\u{306}', '\u{30c}'), ('\u{30f}', '\u{30f}'), ('\u{311}', '\u{311}'),
+    ('\u{313}', '\u{314}'), ('\u{31b}', '\u{31b}'), ('\u{323}', '\u{328}'), ('\u{32d}', '\u{32e}'),
+    ('\u{330}', '\u{331}'), ('\u{338}', '\u{338}'), ('\u{340}', '\u{345}'), ('\u{374}', '\u{374}'),
+    ('\u{37e}', '\u{37e}'), ('\u{387}', '\u{387}'), ('\u{653}', '\u{655}'), ('\u{93c}', '\u{93c}'),
+    ('\u{958}', '\u{95f}'), ('\u{9be}', '\u{9be}'), ('\u{9d7}', '\u{9d7}'), ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9df}'), ('\u{a33}', '\u{a33}'), ('\u{a36}', '\u{a36}'), ('\u{a59}', '\u{a5b}'),
+    ('\u{a5e}', '\u{a5e}'), ('\u{b3e}', '\u{b3e}'), ('\u{b56}', '\u{b57}'), ('\u{b5c}', '\u{b5d}'),
+    ('\u{bbe}', '\u{bbe}'), ('\u{bd7}', '\u{bd7}'), ('\u{c56}', '\u{c56}'), ('\u{cc2}', '\u{cc2}'),
+    ('\u{cd5}', '\u{cd6}'), ('\u{d3e}', '\u{d3e}'), ('\u{d57}', '\u{d57}'), ('\u{dca}', '\u{dca}'),
+    ('\u{dcf}', '\u{dcf}'), ('\u{ddf}', '\u{ddf}'), ('\u{f43}', '\u{f43}'), ('\u{f4d}', '\u{f4d}'),
+    ('\u{f52}', '\u{f52}'), ('\u{f57}', '\u{f57}'), ('\u{f5c}', '\u{f5c}'), ('\u{f69}', '\u{f69}'),
+    ('\u{f73}', '\u{f73}'), ('\u{f75}', '\u{f76}'), ('\u{f78}', '\u{f78}'), ('\u{f81}', '\u{f81}'),
+    ('\u{f93}', '\u{f93}'), ('\u{f9d}', '\u{f9d}'), ('\u{fa2}', '\u{fa2}'), ('\u{fa7}', '\u{fa7}'),
+    ('\u{fac}', '\u{fac}'), ('\u{fb9}', '\u{fb9}'), ('\u{102e}', '\u{102e}'), ('\u{1161}', '\u{1175}'),
+    ('\u{11a8}', '\u{11c2}'), ('\u{1b35}', '\u{1b35}'), ('\u{1f71}', '\u{1f71}'), ('\u{1f73}', '\u{1f73}'),
+    ('\u{1f75}', '\u{1f75}'), ('\u{1f77}', '\u{1f77}'), ('\u{1f79}', '\u{1f79}'), ('\u{1f7b}', '\u{1f7b}'),
+    ('\u{1f7d}', '\u{1f7d}'), ('\u{1fbb}', '\u{1fbb}'), ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc9}', '\u{1fc9}'),
+    ('\u{1fcb}', '\u{1fcb}'), ('\u{1fd3}', '\u{1fd3}'), ('\u{1fdb}', '\u{1fdb}'), ('\u{1fe3}', '\u{1fe3}'),
+    ('\u{1feb}', '\u{1feb}'), ('\u{1fee}', '\u{1fef}'), ('\u{1ff9}', '\u{1ff9}'), ('\u{1ffb}', '\u{1ffb}'),
+    ('\u{1ffd}', '\u{1ffd}'), ('\u{2000}', '\u{2001}'), ('\u{2126}', '\u{2126}'), ('\u{212a}', '\u{212b}'),
+    ('\u{2329}', '\u{232a}'), ('\u{2adc}', '\u{2adc}'), ('\u{3099}', '\u{309a}'), ('\u{f900}', '\u{fa0d}'),
+    ('\u{fa10}', '\u{fa10}'), ('\u{fa12}', '\u{fa12}'), ('\u{fa15}', '\u{fa1e}'), ('\u{fa20}', '\u{fa20}'),
+    ('\u{fa22}', '\u{fa22}'), ('\u{fa25}', '\u{fa26}'), ('\u{fa2a}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb1f}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fb4e}'),
+    ('\u{110ba}', '\u{110ba}'), ('\u{11127}', '\u{11127}'), ('\u{1133e}', '\u{1133e}'), ('\u{11357}', '\u{11357}'),
+    ('\u{114b0}', '\u{114b0}'), ('\u{114ba}', '\u{114ba}'), ('\u{114bd}', '\u{114bd}'), ('\u{115af}', '\u{115af}'),
+    ('\u{11930}', '\u{11930}'), ('\u{1d15e}', '\u{1d164}'), ('\u{1d1bb}', '\u{1d1c0}'), ('\u{2f800}', '\u{2fa1d}'),
+];
+
+pub const NFKC_QUICK_CHECK: &[(char, char)] = &[
+    ('\u{a0}', '\u{a0}'), ('\u{a8}', '\u{a8}'), ('\u{aa}', '\u{aa}'), ('\u{af}', '\u{af}'),
+    ('\u{b2}', '\u{b5}'), ('\u{b8}', '\u{ba}'), ('\u{bc}', '\u{be}'), ('\u{132}', '\u{133}'),
+    ('\u{13f}', '\u{140}'), ('\u{149}', '\u{149}'), ('\u{17f}', '\u{17f}'), ('\u{1c4}', '\u{1cc}'),
+    ('\u{1f1}', '\u{1f3}'), ('\u{2b0}', '\u{2b8}'), ('\u{2d8}', '\u{2dd}'), ('\u{2e0}', '\u{2e4}'),
+    ('\u{300}', '\u{304}'), ('\u{306}', '\u{30c}'), ('\u{30f}', '\u{30f}'), ('\u{311}', '\u{311}'),
+    ('\u{313}', '\u{314}'), ('\u{31b}', '\u{31b}'), ('\u{323}', '\u{328}'), ('\u{32d}', '\u{32e}'),
+    ('\u{330}', '\u{331}'), ('\u{338}', '\u{338}'), ('\u{340}', '\u{345}'), ('\u{374}', '\u{374}'),
+    ('\u{37a}', '\u{37a}'), ('\u{37e}', '\u{37e}'), ('\u{384}', '\u{385}'), ('\u{387}', '\u{387}'),
+    ('\u{3d0}', '\u{3d6}'), ('\u{3f0}', '\u{3f2}'), ('\u{3f4}', '\u{3f5}'), ('\u{3f9}', '\u{3f9}'),
+    ('\u{587}', '\u{587}'), ('\u{653}', '\u{655}'), ('\u{675}', '\u{678}'), ('\u{93c}', '\u{93c}'),
+    ('\u{958}', '\u{95f}'), ('\u{9be}', '\u{9be}'), ('\u{9d7}', '\u{9d7}'), ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9df}'), ('\u{a33}', '\u{a33}'), ('\u{a36}', '\u{a36}'), ('\u{a59}', '\u{a5b}'),
+    ('\u{a5e}', '\u{a5e}'), ('\u{b3e}', '\u{b3e}'), ('\u{b56}', '\u{b57}'), ('\u{b5c}', '\u{b5d}'),
+    ('\u{bbe}', '\u{bbe}'), ('\u{bd7}', '\u{bd7}'), ('\u{c56}', '\u{c56}'), ('\u{cc2}', '\u{cc2}'),
+    ('\u{cd5}', '\u{cd6}'), ('\u{d3e}', '\u{d3e}'), ('\u{d57}', '\u{d57}'), ('\u{dca}', '\u{dca}'),
+    ('\u{dcf}', '\u{dcf}'), ('\u{ddf}', '\u{ddf}'), ('\u{e33}', '\u{e33}'), ('\u{eb3}', '\u{eb3}'),
+    ('\u{edc}', '\u{edd}'), ('\u{f0c}', '\u{f0c}'), ('\u{f43}', '\u{f43}'), ('\u{f4d}', '\u{f4d}'),
+    ('\u{f52}', '\u{f52}'), ('\u{f57}', '\u{f57}'), ('\u{f5c}', '\u{f5c}'), ('\u{f69}', '\u{f69}'),
+    ('\u{f73}', '\u{f73}'), ('\u{f75}', '\u{f79}'), ('\u{f81}', '\u{f81}'), ('\u{f93}', '\u{f93}'),
+    ('\u{f9d}', '\u{f9d}'), ('\u{fa2}', '\u{fa2}'), ('\u{fa7}', '\u{fa7}'), ('\u{fac}', '\u{fac}'),
+    ('\u{fb9}', '\u{fb9}'), ('\u{102e}', '\u{102e}'), ('\u{10fc}', '\u{10fc}'), ('\u{1161}', '\u{1175}'),
+    ('\u{11a8}', '\u{11c2}'), ('\u{1b35}', '\u{1b35}'), ('\u{1d2c}', '\u{1d2e}'), ('\u{1d30}', '\u{1d3a}'),
+    ('\u{1d3c}', '\u{1d4d}'), ('\u{1d4f}', '\u{1d6a}'), ('\u{1d78}', '\u{1d78}'), ('\u{1d9b}', '\u{1dbf}'),
+    ('\u{1e9a}', '\u{1e9b}'), ('\u{1f71}', '\u{1f71}'), ('\u{1f73}', '\u{1f73}'), ('\u{1f75}', '\u{1f75}'),
+    ('\u{1f77}', '\u{1f77}'), ('\u{1f79}', '\u{1f79}'), ('\u{1f7b}', '\u{1f7b}'), ('\u{1f7d}', '\u{1f7d}'),
+    ('\u{1fbb}', '\u{1fbb}'), ('\u{1fbd}', '\u{1fc1}'), ('\u{1fc9}', '\u{1fc9}'), ('\u{1fcb}', '\u{1fcb}'),
+    ('\u{1fcd}', '\u{1fcf}'), ('\u{1fd3}', '\u{1fd3}'), ('\u{1fdb}', '\u{1fdb}'), ('\u{1fdd}', '\u{1fdf}'),
+    ('\u{1fe3}', '\u{1fe3}'), ('\u{1feb}', '\u{1feb}'), ('\u{1fed}', '\u{1fef}'), ('\u{1ff9}', '\u{1ff9}'),
+    ('\u{1ffb}', '\u{1ffb}'), ('\u{1ffd}', '\u{1ffe}'), ('\u{2000}', '\u{200a}'), ('\u{2011}', '\u{2011}'),
+    ('\u{2017}', '\u{2017}'), ('\u{2024}', '\u{2026}'), ('\u{202f}', '\u{202f}'), ('\u{2033}', '\u{2034}'),
+    ('\u{2036}', '\u{2037}'), ('\u{203c}', '\u{203c}'), ('\u{203e}', '\u{203e}'), ('\u{2047}', '\u{2049}'),
+    ('\u{2057}', '\u{2057}'), ('\u{205f}', '\u{205f}'), ('\u{2070}', '\u{2071}'), ('\u{2074}', '\u{208e}'),
+    ('\u{2090}', '\u{209c}'), ('\u{20a8}', '\u{20a8}'), ('\u{2100}', '\u{2103}'), ('\u{2105}', '\u{2107}'),
+    ('\u{2109}', '\u{2113}'), ('\u{2115}', '\u{2116}'), ('\u{2119}', '\u{211d}'), ('\u{2120}', '\u{2122}'),
+    ('\u{2124}', '\u{2124}'), ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'),
+    ('\u{212f}', '\u{2131}'), ('\u{2133}', '\u{2139}'), ('\u{213b}', '\u{2140}'), ('\u{2145}', '\u{2149}'),
+    ('\u{2150}', '\u{217f}'), ('\u{2189}', '\u{2189}'), ('\u{222c}', '\u{222d}'), ('\u{222f}', '\u{2230}'),
+    ('\u{2329}', '\u{232a}'), ('\u{2460}', '\u{24ea}'), ('\u{2a0c}', '\u{2a0c}'), ('\u{2a74}', '\u{2a76}'),
+    ('\u{2adc}', '\u{2adc}'), ('\u{2c7c}', '\u{2c7d}'), ('\u{2d6f}', '\u{2d6f}'), ('\u{2e9f}', '\u{2e9f}'),
+    ('\u{2ef3}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{3000}', '\u{3000}'), ('\u{3036}', '\u{3036}'),
+    ('\u{3038}', '\u{303a}'), ('\u{3099}', '\u{309c}'), ('\u{309f}', '\u{309f}'), ('\u{30ff}', '\u{30ff}'),
+    ('\u{3131}', '\u{318e}'), ('\u{3192}', '\u{319f}'), ('\u{3200}', '\u{321e}'), ('\u{3220}', '\u{3247}'),
+    ('\u{3250}', '\u{327e}'), ('\u{3280}', '\u{33ff}'), ('\u{a69c}', '\u{a69d}'), ('\u{a770}', '\u{a770}'),
+    ('\u{a7f2}', '\u{a7f4}'), ('\u{a7f8}', '\u{a7f9}'), ('\u{ab5c}', '\u{ab5f}'), ('\u{ab69}', '\u{ab69}'),
+    ('\u{f900}', '\u{fa0d}'), ('\u{fa10}', '\u{fa10}'), ('\u{fa12}', '\u{fa12}'), ('\u{fa15}', '\u{fa1e}'),
+    ('\u{fa20}', '\u{fa20}'), ('\u{fa22}', '\u{fa22}'), ('\u{fa25}', '\u{fa26}'), ('\u{fa2a}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdfc}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe44}'),
+    ('\u{fe47}', '\u{fe52}'), ('\u{fe54}', '\u{fe66}'), ('\u{fe68}', '\u{fe6b}'), ('\u{fe70}', '\u{fe72}'),
+    ('\u{fe74}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'), ('\u{ff01}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'), ('\u{ffe0}', '\u{ffe6}'),
+    ('\u{ffe8}', '\u{ffee}'), ('\u{10781}', '\u{10785}'), ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'),
+    ('\u{110ba}', '\u{110ba}'), ('\u{11127}', '\u{11127}'), ('\u{1133e}', '\u{1133e}'), ('\u{11357}', '\u{11357}'),
+    ('\u{114b0}', '\u{114b0}'), ('\u{114ba}', '\u{114ba}'), ('\u{114bd}', '\u{114bd}'), ('\u{115af}', '\u{115af}'),
+    ('\u{11930}', '\u{11930}'), ('\u{1d15e}', '\u{1d164}'), ('\u{1d1bb}', '\u{1d1c0}'), ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'), ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d7cb}'), ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'), ('\u{1f100}', '\u{1f10a}'), ('\u{1f110}', '\u{1f12e}'), ('\u{1f130}', '\u{1f14f}'),
+    ('\u{1f16a}', '\u{1f16c}'), ('\u{1f190}', '\u{1f190}'), ('\u{1f200}', '\u{1f202}'), ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'), ('\u{1fbf0}', '\u{1fbf9}'), ('\u{2f800}', '\u{2fa1d}'),
+];
+
+pub const COMBINING_CLASS: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230), ('\u{315}', '\u{315}', 232), ('\u{316}', '\u{319}', 220),
+    ('\u{31a}', '\u{31a}', 232), ('\u{31b}', '\u{31b}', 216), ('\u{31c}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202), ('\u{323}', '\u{326}', 220), ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220), ('\u{334}', '\u{338}', 1), ('\u{339}', '\u{33c}', 220),
+    ('\u{33d}', '\u{344}', 230), ('\u{345}', '\u{345}', 240), ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220), ('\u{34a}', '\u{34c}', 230), ('\u{34d}', '\u{34e}', 220),
+    ('\u{350}', '\u{352}', 230), ('\u{353}', '\u{356}', 220), ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232), ('\u{359}', '\u{35a}', 220), ('\u{35b}', '\u{35b}', 230),
+    ('\u{35c}', '\u{35c}', 233), ('\u{35d}', '\u{35e}', 234), ('\u{35f}', '\u{35f}', 233),
+    ('\u{360}', '\u{361}', 234), ('\u{362}', '\u{362}', 233), ('\u{363}', '\u{36f}', 230),
+    ('\u{483}', '\u{487}', 230), ('\u{591}', '\u{591}', 220), ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220), ('\u{597}', '\u{599}', 230), ('\u{59a}', '\u{59a}', 222),
+    ('\u{59b}', '\u{59b}', 220), ('\u{59c}', '\u{5a1}', 230), ('\u{5a2}', '\u{5a7}', 220),
+    ('\u{5a8}', '\u{5a9}', 230), ('\u{5aa}', '\u{5aa}', 220), ('\u{5ab}', '\u{5ac}', 230),
+    ('\u{5ad}', '\u{5ad}', 222), ('\u{5ae}', '\u{5ae}', 228), ('\u{5af}', '\u{5af}', 230),
+    ('\u{5b0}', '\u{5b0}', 10), ('\u{5b1}', '\u{5b1}', 11), ('\u{5b2}', '\u{5b2}', 12),
+    ('\u{5b3}', '\u{5b3}', 13), ('\u{5b4}', '\u{5b4}', 14), ('\u{5b5}', '\u{5b5}', 15),
+    ('\u{5b6}', '\u{5b6}', 16), ('\u{5b7}', '\u{5b7}', 17), ('\u{5b8}', '\u{5b8}', 18),
+    ('\u{5b9}', '\u{5ba}', 19), ('\u{5bb}', '\u{5bb}', 20), ('\u{5bc}', '\u{5bc}', 21),
+    ('\u{5bd}', '\u{5bd}', 22), ('\u{5bf}', '\u{5bf}', 23), ('\u{5c1}', '\u{5c1}', 24),
+    ('\u{5c2}', '\u{5c2}', 25), ('\u{5c4}', '\u{5c4}', 230), ('\u{5c5}', '\u{5c5}', 220),
+    ('\u{5c7}', '\u{5c7}', 18), ('\u{610}', '\u{617}', 230), ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31), ('\u{61a}', '\u{61a}', 32), ('\u{64b}', '\u{64b}', 27),
+    ('\u{64c}', '\u{64c}', 28), ('\u{64d}', '\u{64d}', 29), ('\u{64e}', '\u{64e}', 30),
+    ('\u{64f}', '\u{64f}', 31), ('\u{650}', '\u{650}', 32), ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34), ('\u{653}', '\u{654}', 230), ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65b}', 230), ('\u{65c}', '\u{65c}', 220), ('\u{65d}', '\u{65e}', 230),
+    ('\u{65f}', '\u{65f}', 220), ('\u{670}', '\u{670}', 35), ('\u{6d6}', '\u{6dc}', 230),
+    ('\u{6df}', '\u{6e2}', 230), ('\u{6e3}', '\u{6e3}', 220), ('\u{6e4}', '\u{6e4}', 230),
+    ('\u{6e7}', '\u{6e8}', 230), ('\u{6ea}', '\u{6ea}', 220), ('\u{6eb}', '\u{6ec}', 230),
+    ('\u{6ed}', '\u{6ed}', 220), ('\u{711}', '\u{711}', 36), ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220), ('\u{732}', '\u{733}', 230), ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230), ('\u{737}', '\u{739}', 220), ('\u{73a}', '\u{73a}', 230),
+    ('\u{73b}', '\u{73c}', 220), ('\u{73d}', '\u{73d}', 230), ('\u{73e}', '\u{73e}', 220),
+    ('\u{73f}', '\u{741}', 230), ('\u{742}', '\u{742}', 220), ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220), ('\u{745}', '\u{745}', 230), ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230), ('\u{748}', '\u{748}', 220), ('\u{749}', '\u{74a}', 230),
+    ('\u{7eb}', '\u{7f1}', 230), ('\u{7f2}', '\u{7f2}', 220), ('\u{7f3}', '\u{7f3}', 230),
+    ('\u{7fd}', '\u{7fd}', 220), ('\u{816}', '\u{819}', 230), ('\u{81b}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230), ('\u{829}', '\u{82d}', 230), ('\u{859}', '\u{85b}', 220),
+    ('\u{898}', '\u{898}', 230), ('\u{899}', '\u{89b}', 220), ('\u{89c}', '\u{89f}', 230),
+    ('\u{8ca}', '\u{8ce}', 230), ('\u{8cf}', '\u{8d3}', 220), ('\u{8d4}', '\u{8e1}', 230),
+    ('\u{8e3}', '\u{8e3}', 220), ('\u{8e4}', '\u{8e5}', 230), ('\u{8e6}', '\u{8e6}', 220),
+    ('\u{8e7}', '\u{8e8}', 230), ('\u{8e9}', '\u{8e9}', 220), ('\u{8ea}', '\u{8ec}', 230),
+    ('\u{8ed}', '\u{8ef}', 220), ('\u{8f0}', '\u{8f0}', 27), ('\u{8f1}', '\u{8f1}', 28),
+    ('\u{8f2}', '\u{8f2}', 29), ('\u{8f3}', '\u{8f5}', 230), ('\u{8f6}', '\u{8f6}', 220),
+    ('\u{8f7}', '\u{8f8}', 230), ('\u{8f9}', '\u{8fa}', 220), ('\u{8fb}', '\u{8ff}', 230),
+    ('\u{93c}', '\u{93c}', 7), ('\u{94d}', '\u{94d}', 9), ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220), ('\u{953}', '\u{954}', 230), ('\u{9bc}', '\u{9bc}', 7),
+    ('\u{9cd}', '\u{9cd}', 9), ('\u{9fe}', '\u{9fe}', 230), ('\u{a3c}', '\u{a3c}', 7),
+    ('\u{a4d}', '\u{a4d}', 9), ('\u{abc}', '\u{abc}', 7), ('\u{acd}', '\u{acd}', 9),
+    ('\u{b3c}', '\u{b3c}', 7), ('\u{b4d}', '\u{b4d}', 9), ('\u{bcd}', '\u{bcd}', 9),
+    ('\u{c3c}', '\u{c3c}', 7), ('\u{c4d}', '\u{c4d}', 9), ('\u{c55}', '\u{c55}', 84),
+    ('\u{c56}', '\u{c56}', 91), ('\u{cbc}', '\u{cbc}', 7), ('\u{ccd}', '\u{ccd}', 9),
+    ('\u{d3b}', '\u{d3c}', 9), ('\u{d4d}', '\u{d4d}', 9), ('\u{dca}', '\u{dca}', 9),
+    ('\u{e38}', '\u{e39}', 103), ('\u{e3a}', '\u{e3a}', 9), ('\u{e48}', '\u{e4b}', 107),
+    ('\u{eb8}', '\u{eb9}', 118), ('\u{eba}', '\u{eba}', 9), ('\u{ec8}', '\u{ecb}', 122),
+    ('\u{f18}', '\u{f19}', 220), ('\u{f35}', '\u{f35}', 220), ('\u{f37}', '\u{f37}', 220),
+    ('\u{f39}', '\u{f39}', 216), ('\u{f71}', '\u{f71}', 129), ('\u{f72}', '\u{f72}', 130),
+    ('\u{f74}', '\u{f74}', 132), ('\u{f7a}', '\u{f7d}', 130), ('\u{f80}', '\u{f80}', 130),
+    ('\u{f82}', '\u{f83}', 230), ('\u{f84}', '\u{f84}', 9), ('\u{f86}', '\u{f87}', 230),
+    ('\u{fc6}', '\u{fc6}', 220), ('\u{1037}', '\u{1037}', 7), ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220), ('\u{135d}', '\u{135f}', 230), ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9), ('\u{17d2}', '\u{17d2}', 9), ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228), ('\u{1939}', '\u{1939}', 222), ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220), ('\u{1a17}', '\u{1a17}', 230), ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9), ('\u{1a75}', '\u{1a7c}', 230), ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230), ('\u{1ab5}', '\u{1aba}', 220), ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220), ('\u{1abf}', '\u{1ac0}', 220), ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220), ('\u{1ac5}', '\u{1ac9}', 230), ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230), ('\u{1b34}', '\u{1b34}', 7), ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230), ('\u{1b6c}', '\u{1b6c}', 220), ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9), ('\u{1be6}', '\u{1be6}', 7), ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7), ('\u{1cd0}', '\u{1cd2}', 230), ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220), ('\u{1cda}', '\u{1cdb}', 230), ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230), ('\u{1ce2}', '\u{1ce8}', 1), ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230), ('\u{1cf8}', '\u{1cf9}', 230), ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220), ('\u{1dc3}', '\u{1dc9}', 230), ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230), ('\u{1dcd}', '\u{1dcd}', 234), ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220), ('\u{1dd0}', '\u{1dd0}', 202), ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232), ('\u{1df7}', '\u{1df8}', 228), ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218), ('\u{1dfb}', '\u{1dfb}', 230), ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220), ('\u{1dfe}', '\u{1dfe}', 230), ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230), ('\u{20d2}', '\u{20d3}', 1), ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1), ('\u{20db}', '\u{20dc}', 230), ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1), ('\u{20e7}', '\u{20e7}', 230), ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230), ('\u{20ea}', '\u{20eb}', 1), ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230), ('\u{2cef}', '\u{2cf1}', 230), ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230), ('\u{302a}', '\u{302a}', 218), ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232), ('\u{302d}', '\u{302d}', 222), ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8), ('\u{a66f}', '\u{a66f}', 230), ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230), ('\u{a6f0}', '\u{a6f1}', 230), ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9), ('\u{a8c4}', '\u{a8c4}', 9), ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220), ('\u{a953}', '\u{a953}', 9), ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9), ('\u{aab0}', '\u{aab0}', 230), ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220), ('\u{aab7}', '\u{aab8}', 230), ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230), ('\u{aaf6}', '\u{aaf6}', 9), ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26), ('\u{fe20}', '\u{fe26}', 230), ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230), ('\u{101fd}', '\u{101fd}', 220), ('\u{102e0}', '\u{102e0}', 220),
+    ('\u{10376}', '\u{1037a}', 230), ('\u{10a0d}', '\u{10a0d}', 220), ('\u{10a0f}', '\u{10a0f}', 230),
+    ('\u{10a38}', '\u{10a38}', 230), ('\u{10a39}', '\u{10a39}', 1), ('\u{10a3a}', '\u{10a3a}', 220),
+    ('\u{10a3f}', '\u{10a3f}', 9), ('\u{10ae5}', '\u{10ae5}', 230), ('\u{10ae6}', '\u{10ae6}', 220),
+    ('\u{10d24}', '\u{10d27}', 230), ('\u{10eab}', '\u{10eac}', 230), ('\u{10f46}', '\u{10f47}', 220),
+    ('\u{10f48}', '\u{10f4a}', 230), ('\u{10f4b}', '\u{10f4b}', 220), ('\u{10f4c}', '\u{10f4c}', 230),
+    ('\u{10f4d}', '\u{10f50}', 220), ('\u{10f82}', '\u{10f82}', 230), ('\u{10f83}', '\u{10f83}', 220),
+    ('\u{10f84}', '\u{10f84}', 230), ('\u{10f85}', '\u{10f85}', 220), ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9), ('\u{1107f}', '\u{1107f}', 9), ('\u{110b9}', '\u{110b9}', 9),
+    ('\u{110ba}', '\u{110ba}', 7), ('\u{11100}', '\u{11102}', 230), ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7), ('\u{111c0}', '\u{111c0}', 9), ('\u{111ca}', '\u{111ca}', 7),
+    ('\u{11235}', '\u{11235}', 9), ('\u{11236}', '\u{11236}', 7), ('\u{112e9}', '\u{112e9}', 7),
+    ('\u{112ea}', '\u{112ea}', 9), ('\u{1133b}', '\u{1133c}', 7), ('\u{1134d}', '\u{1134d}', 9),
+    ('\u{11366}', '\u{1136c}', 230), ('\u{11370}', '\u{11374}', 230), ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7), ('\u{1145e}', '\u{1145e}', 230), ('\u{114c2}', '\u{114c2}', 9),
+    ('\u{114c3}', '\u{114c3}', 7), ('\u{115bf}', '\u{115bf}', 9), ('\u{115c0}', '\u{115c0}', 7),
+    ('\u{1163f}', '\u{1163f}', 9), ('\u{116b6}', '\u{116b6}', 9), ('\u{116b7}', '\u{116b7}', 7),
+    ('\u{1172b}', '\u{1172b}', 9), ('\u{11839}', '\u{11839}', 9), ('\u{1183a}', '\u{1183a}', 7),
+    ('\u{1193d}', '\u{1193e}', 9), ('\u{11943}', '\u{11943}', 7), ('\u{119e0}', '\u{119e0}', 9),
+    ('\u{11a34}', '\u{11a34}', 9), ('\u{11a47}', '\u{11a47}', 9), ('\u{11a99}', '\u{11a99}', 9),
+    ('\u{11c3f}', '\u{11c3f}', 9), ('\u{11d42}', '\u{11d42}', 7), ('\u{11d44}', '\u{11d45}', 9),
+    ('\u{11d97}', '\u{11d97}', 9), ('\u{16af0}', '\u{16af4}', 1), ('\u{16b30}', '\u{16b36}', 230),
+    ('\u{16ff0}', '\u{16ff1}', 6), ('\u{1bc9e}', '\u{1bc9e}', 1), ('\u{1d165}', '\u{1d166}', 216),
+    ('\u{1d167}', '\u{1d169}', 1), ('\u{1d16d}', '\u{1d16d}', 226), ('\u{1d16e}', '\u{1d172}', 216),
+    ('\u{1d17b}', '\u{1d182}', 220), ('\u{1d185}', '\u{1d189}', 230), ('\u{1d18a}', '\u{1d18b}', 220),
+    ('\u{1d1aa}', '\u{1d1ad}', 230), ('\u{1d242}', '\u{1d244}', 230), ('\u{1e000}', '\u{1e006}', 230),
+    ('\u{1e008}', '\u{1e018}', 230), ('\u{1e01b}', '\u{1e021}', 230), ('\u{1e023}', '\u{1e024}', 230),
+    ('\u{1e026}', '\u{1e02a}', 230), ('\u{1e130}', '\u{1e136}', 230), ('\u{1e2ae}', '\u{1e2ae}', 230),
+    ('\u{1e2ec}', '\u{1e2ef}', 230), ('\u{1e8d0}', '\u{1e8d6}', 220), ('\u{1e944}', '\u{1e949}', 230),
+    ('\u{1e94a}', '\u{1e94a}', 7),
+];
+
+pub const CANONICAL_DECOMPOSITION: &[(char, &str)] = &[
+    ('\u{c0}', "\u{41}\u{300}"), ('\u{c1}', "\u{41}\u{301}"), ('\u{c2}', "\u{41}\u{302}"),
+    ('\u{c3}', "\u{41}\u{303}"), ('\u{c4}', "\u{41}\u{308}"), ('\u{c5}', "\u{41}\u{30a}"),
+    ('\u{c7}', "\u{43}\u{327}"), ('\u{c8}', "\u{45}\u{300}"), ('\u{c9}', "\u{45}\u{301}"),
+    ('\u{ca}', "\u{45}\u{302}"), ('\u{cb}', "\u{45}\u{308}"), ('\u{cc}', "\u{49}\u{300}"),
+    ('\u{cd}', "\u{49}\u{301}"), ('\u{ce}', "\u{49}\u{302}"), ('\u{cf}', "\u{49}\u{308}"),
+    ('\u{d1}', "\u{4e}\u{303}"), ('\u{d2}', "\u{4f}\u{300}"), ('\u{d3}', "\u{4f}\u{301}"),
+    ('\u{d4}', "\u{4f}\u{302}"), ('\u{d5}', "\u{4f}\u{303}"), ('\u{d6}', "\u{4f}\u{308}"),
+    ('\u{d9}', "\u{55}\u{300}"), ('\u{da}', "\u{55}\u{301}"), ('\u{db}', "\u{55}\u{302}"),
+    ('\u{dc}', "\u{55}\u{308}"), ('\u{dd}', "\u{59}\u{301}"), ('\u{e0}', "\u{61}\u{300}"),
+    ('\u{e1}', "\u{61}\u{301}"), ('\u{e2}', "\u{61}\u{302}"), ('\u{e3}', "\u{61}\u{303}"),
+    ('\u{e4}', "\u{61}\u{308}"), ('\u{e5}', "\u{61}\u{30a}"), ('\u{e7}', "\u{63}\u{327}"),
+    ('\u{e8}', "\u{65}\u{300}"), ('\u{e9}', "\u{65}\u{301}"), ('\u{ea}', "\u{65}\u{302}"),
+    ('\u{eb}', "\u{65}\u{308}"), ('\u{ec}', "\u{69}\u{300}"), ('\u{ed}', "\u{69}\u{301}"),
+    ('\u{ee}', "\u{69}\u{302}"), ('\u{ef}', "\u{69}\u{308}"), ('\u{f1}', "\u{6e}\u{303}"),
+    ('\u{f2}', "\u{6f}\u{300}"), ('\u{f3}', "\u{6f}\u{301}"), ('\u{f4}', "\u{6f}\u{302}"),
+    ('\u{f5}', "\u{6f}\u{303}"), ('\u{f6}', "\u{6f}\u{308}"), ('\u{f9}', "\u{75}\u{300}"),
+    ('\u{fa}', "\u{75}\u{301}"), ('\u{fb}', "\u{75}\u{302}"), ('\u{fc}', "\u{75}\u{308}"),
+    ('\u{fd}', "\u{79}\u{301}"), ('\u{ff}', "\u{79}\u{308}"), ('\u{100}', "\u{41}\u{304}"),
+    ('\u{101}', "\u{61}\u{304}"), ('\u{102}', "\u{41}\u{306}"), ('\u{103}', "\u{61}\u{306}"),
+    ('\u{104}', "\u{41}\u{328}"), ('\u{105}', "\u{61}\u{328}"), ('\u{106}', "\u{43}\u{301}"),
+    ('\u{107}', "\u{63}\u{301}"), ('\u{108}', "\u{43}\u{302}"), ('\u{109}', "\u{63}\u{302}"),
+    ('\u{10a}', "\u{43}\u{307}"), ('\u{10b}', "\u{63}\u{307}"), ('\u{10c}', "\u{43}\u{30c}"),
+    ('\u{10d}', "\u{63}\u{30c}"), ('\u{10e}', "\u{44}\u{30c}"), ('\u{10f}', "\u{64}\u{30c}"),
+    ('\u{112}', "\u{45}\u{304}"), ('\u{113}', "\u{65}\u{304}"), ('\u{114}', "\u{45}\u{306}"),
+    ('\u{115}', "\u{65}\u{306}"), ('\u{116}', "\u{45}\u{307}"), ('\u{117}', "\u{65}\u{307}"),
+    ('\u{118}', "\u{45}\u{328}"), ('\u{119}', "\u{65}\u{328}"), ('\u{11a}', "\u{45}\u{30c}"),
+    ('\u{11b}', "\u{65}\u{30c}"), ('\u{11c}', "\u{47}\u{302}"), ('\u{11d}', "\u{67}\u{302}"),
+    ('\u{11e}', "\u{47}\u{306}"), ('\u{11f}', "\u{67}\u{306}"), ('\u{120}', "\u{47}\u{307}"),
+    ('\u{121}', "\u{67}\u{307}"), ('\u{122}', "\u{47}\u{327}"), ('\u{123}', "\u{67}\u{327}"),
+    ('\u{124}', "\u{48}\u{302}"), ('\u{125}', "\u{68}\u{302}"), ('\u{128}', "\u{49}\u{303}"),
+    ('\u{129}', "\u{69}\u{303}"), ('\u{12a}', "\u{49}\u{304}"), ('\u{12b}', "\u{69}\u{304}"),
+    ('\u{12c}', "\u{49}\u{306}"), ('\u{12d}', "\u{69}\u{306}"), ('\u{12e}', "\u{49}\u{328}"),
+    ('\u{12f}', "\u{69}\u{328}"), ('\u{130}', "\u{49}\u{307}"), ('\u{134}', "\u{4a}\u{302}"),
+    ('\u{135}', "\u{6a}\u{302}"), ('\u{136}', "\u{4b}\u{327}"), ('\u{137}', "\u{6b}\u{327}"),
+    ('\u{139}', "\u{4c}\u{301}"), ('\u{13a}', "\u{6c}\u{301}"), ('\u{13b}', "\u{4c}\u{327}"),
+    ('\u{13c}', "\u{6c}\u{327}"), ('\u{13d}', "\u{4c}\u{30c}"), ('\u{13e}', "\u{6c}\u{30c}"),
+    ('\u{143}', "\u{4e}\u{301}"), ('\u{144}', "\u{6e}\u{301}"), ('\u{145}', "\u{4e}\u{327}"),
+    ('\u{146}', "\u{6e}\u{327}"), ('\u{147}', "\u{4e}\u{30c}"), ('\u{148}', "\u{6e}\u{30c}"),
+    ('\u{14c}', "\u{4f}\u{304}"), ('\u{14d}', "\u{6f}\u{304}"), ('\u{14e}', "\u{4f}\u{306}"),
+    ('\u{14f}', "\u{6f}\u{306}"), ('\u{150}', "\u{4f}\u{30b}"), ('\u{151}', "\u{6f}\u{30b}"),
+    ('\u{154}', "\u{52}\u{301}"), ('\u{155}', "\u{72}\u{301}"), ('\u{156}', "\u{52}\u{327}"),
+    ('\u{157}', "\u{72}\u{327}"), ('\u{158}', "\u{52}\u{30c}"), ('\u{159}', "\u{72}\u{30c}"),
+    ('\u{15a}', "\u{53}\u{301}"), ('\u{15b}', "\u{73}\u{301}"), ('\u{15c}', "\u{53}\u{302}"),
+    ('\u{15d}', "\u{73}\u{302}"), ('\u{15e}', "\u{53}\u{327}"), ('\u{15f}', "\u{73}\u{327}"),
+    ('\u{160}', "\u{53}\u{30c}"), ('\u{161}', "\u{73}\u{30c}"), ('\u{162}', "\u{54}\u{327}"),
+    ('\u{163}', "\u{74}\u{327}"), ('\u{164}', "\u{54}\u{30c}"), ('\u{165}', "\u{74}\u{30c}"),
+    ('\u{168}', "\u{55}\u{303}"), ('\u{169}', "\u{75}\u{303}"), ('\u{16a}', "\u{55}\u{304}"),
+    ('\u{16b}', "\u{75}\u{304}"), ('\u{16c}', "\u{55}\u{306}"), ('\u{16d}', "\u{75}\u{306}"),
+    ('\u{16e}', "\u{55}\u{30a}"), ('\u{16f}', "\u{75}\u{30a}"), ('\u{170}', "\u{55}\u{30b}"),
+    ('\u{171}', "\u{75}\u{30b}"), ('\u{172}', "\u{55}\u{328}"), ('\u{173}', "\u{75}\u{328}"),
+    ('\u{174}', "\u{57}\u{302}"), ('\u{175}', "\u{77}\u{302}"), ('\u{176}', "\u{59}\u{302}"),
+    ('\u{177}', "\u{79}\u{302}"), ('\u{178}', "\u{59}\u{308}"), ('\u{179}', "\u{5a}\u{301}"),
+    ('\u{17a}', "\u{7a}\u{301}"), ('\u{17b}', "\u{5a}\u{307}"), ('\u{17c}', "\u{7a}\u{307}"),
+    ('\u{17d}', "\u{5a}\u{30c}"), ('\u{17e}', "\u{7a}\u{30c}"), ('\u{1a0}', "\u{4f}\u{31b}"),
+    ('\u{1a1}', "\u{6f}\u{31b}"), ('\u{1af}', "\u{55}\u{31b}"), ('\u{1b0}', "\u{75}\u{31b}"),
+    ('\u{1cd}', "\u{41}\u{30c}"), ('\u{1ce}', "\u{61}\u{30c}"), ('\u{1cf}', "\u{49}\u{30c}"),
+    ('\u{1d0}', "\u{69}\u{30c}"), ('\u{1d1}', "\u{4f}\u{30c}"), ('\u{1d2}', "\u{6f}\u{30c}"),
+    ('\u{1d3}', "\u{55}\u{30c}"), ('\u{1d4}', "\u{75}\u{30c}"), ('\u{1d5}', "\u{55}\u{308}\u{304}"),
+    ('\u{1d6}', "\u{75}\u{308}\u{304}"), ('\u{1d7}', "\u{55}\u{308}\u{301}"), ('\u{1d8}', "\u{75}\u{308}\u{301}"),
+    ('\u{1d9}', "\u{55}\u{308}\u{30c}"), ('\u{1da}', "\u{75}\u{308}\u{30c}"), ('\u{1db}', "\u{55}\u{308}\u{300}"),
+    ('\u{1dc}', "\u{75}\u{308}\u{300}"), ('\u{1de}', "\u{41}\u{308}\u{304}"), ('\u{1df}', "\u{61}\u{308}\u{304}"),
+    ('\u{1e0}', "\u{41}\u{307}\u{304}"), ('\u{1e1}', "\u{61}\u{307}\u{304}"), ('\u{1e2}', "\u{c6}\u{304}"),
+    ('\u{1e3}', "\u{e6}\u{304}"), ('\u{1e6}', "\u{47}\u{30c}"), ('\u{1e7}', "\u{67}\u{30c}"),
+    ('\u{1e8}', "\u{4b}\u{30c}"), ('\u{1e9}', "\u{6b}\u{30c}"), ('\u{1ea}', "\u{4f}\u{328}"),
+    ('\u{1eb}', "\u{6f}\u{328}"), ('\u{1ec}', "\u{4f}\u{328}\u{304}"), ('\u{1ed}', "\u{6f}\u{328}\u{304}"),
+    ('\u{1ee}', "\u{1b7}\u{30c}"), ('\u{1ef}', "\u{292}\u{30c}"), ('\u{1f0}', "\u{6a}\u{30c}"),
+    ('\u{1f4}', "\u{47}\u{301}"), ('\u{1f5}', "\u{67}\u{301}"), ('\u{1f8}', "\u{4e}\u{300}"),
+    ('\u{1f9}', "\u{6e}\u{300}"), ('\u{1fa}', "\u{41}\u{30a}\u{301}"), ('\u{1fb}', "\u{61}\u{30a}\u{301}"),
+    ('\u{1fc}', "\u{c6}\u{301}"), ('\u{1fd}', "\u{e6}\u{301}"), ('\u{1fe}', "\u{d8}\u{301}"),
+    ('\u{1ff}', "\u{f8}\u{301}"), ('\u{200}', "\u{41}\u{30f}"), ('\u{201}', "\u{61}\u{30f}"),
+    ('\u{202}', "\u{41}\u{311}"), ('\u{203}', "\u{61}\u{311}"), ('\u{204}', "\u{45}\u{30f}"),
+    ('\u{205}', "\u{65}\u{30f}"), ('\u{206}', "\u{45}\u{311}"), ('\u{207}', "\u{65}\u{311}"),
+    ('\u{208}', "\u{49}\u{30f}"), ('\u{209}', "\u{69}\u{30f}"), ('\u{20a}', "\u{49}\u{311}"),
+    ('\u{20b}', "\u{69}\u{311}"), ('\u{20c}', "\u{4f}\u{30f}"), ('\u{20d}', "\u{6f}\u{30f}"),
+    ('\u{20e}', "\u{4f}\u{311}"), ('\u{20f}', "\u{6f}\u{311}"), ('\u{210}', "\u{52}\u{30f}"),
+    ('\u{211}', "\u{72}\u{30f}"), ('\u{212}', "\u{52}\u{311}"), ('\u{213}', "\u{72}\u{311}"),
+    ('\u{214}', "\u{55}\u{30f}"), ('\u{215}', "\u{75}\u{30f}"), ('\u{216}', "\u{55}\u{311}"),
+    ('\u{217}', "\u{75}\u{311}"), ('\u{218}', "\u{53}\u{326}"), ('\u{219}', "\u{73}\u{326}"),
+    ('\u{21a}', "\u{54}\u{326}"), ('\u{21b}', "\u{74}\u{326}"), ('\u{21e}', "\u{48}\u{30c}"),
+    ('\u{21f}', "\u{68}\u{30c}"), ('\u{226}', "\u{41}\u{307}"), ('\u{227}', "\u{61}\u{307}"),
+    ('\u{228}', "\u{45}\u{327}"), ('\u{229}', "\u{65}\u{327}"), ('\u{22a}', "\u{4f}\u{308}\u{304}"),
+    ('\u{22b}', "\u{6f}\u{308}\u{304}"), ('\u{22c}', "\u{4f}\u{303}\u{304}"), ('\u{22d}', "\u{6f}\u{303}\u{304}"),
+    ('\u{22e}', "\u{4f}\u{307}"), ('\u{22f}', "\u{6f}\u{307}"), ('\u{230}', "\u{4f}\u{307}\u{304}"),
+    ('\u{231}', "\u{6f}\u{307}\u{304}"), ('\u{232}', "\u{59}\u{304}"), ('\u{233}', "\u{79}\u{304}"),
+    ('\u{340}', "\u{300}"), ('\u{341}', "\u{301}"), ('\u{343}', "\u{313}"),
+    ('\u{344}', "\u{308}\u{301}"), ('\u{374}', "\u{2b9}"), ('\u{37e}', "\u{3b}"),
+    ('\u{385}', "\u{a8}\u{301}"), ('\u{386}', "\u{391}\u{301}"), ('\u{387}', "\u{b7}"),
+    ('\u{388}', "\u{395}\u{301}"), ('\u{389}', "\u{397}\u{301}"), ('\u{38a}', "\u{399}\u{301}"),
+    ('\u{38c}', "\u{39f}\u{301}"), ('\u{38e}', "\u{3a5}\u{301}"), ('\u{38f}', "\u{3a9}\u{301}"),
+    ('\u{390}', "\u{3b9}\u{308}\u{301}"), ('\u{3aa}', "\u{399}\u{308}"), ('\u{3ab}', "\u{3a5}\u{308}"),
+    ('\u{3ac}', "\u{3b1}\u{301}"), ('\u{3ad}', "\u{3b5}\u{301}"), ('\u{3ae}', "\u{3b7}\u{301}"),
+    ('\u{3af}', "\u{3b9}\u{301}"), ('\u{3b0}', "\u{3c5}\u{308}\u{301}"), ('\u{3ca}', "\u{3b9}\u{308}"),
+    ('\u{3cb}', "\u{3c5}\u{308}"), ('\u{3cc}', "\u{3bf}\u{301}"), ('\u{3cd}', "\u{3c5}\u{301}"),
+    ('\u{3ce}', "\u{3c9}\u{301}"), ('\u{3d3}', "\u{3d2}\u{301}"), ('\u{3d4}', "\u{3d2}\u{308}"),
+    ('\u{400}', "\u{415}\u{300}"), ('\u{401}', "\u{415}\u{308}"), ('\u{403}', "\u{413}\u{301}"),
+    ('\u{407}', "\u{406}\u{308}"), ('\u{40c}', "\u{41a}\u{301}"), ('\u{40d}', "\u{418}\u{300}"),
+    ('\u{40e}', "\u{423}\u{306}"), ('\u{419}', "\u{418}\u{306}"), ('\u{439}', "\u{438}\u{306}"),
+    ('\u{450}', "\u{435}\u{300}"), ('\u{451}', "\u{435}\u{308}"), ('\u{453}', "\u{433}\u{301}"),
+    ('\u{457}', "\u{456}\u{308}"), ('\u{45c}', "\u{43a}\u{301}"), ('\u{45d}', "\u{438}\u{300}"),
+    ('\u{45e}', "\u{443}\u{306}"), ('\u{476}', "\u{474}\u{30f}"), ('\u{477}', "\u{475}\u{30f}"),
+    ('\u{4c1}', "\u{416}\u{306}"), ('\u{4c2}', "\u{436}\u{306}"), ('\u{4d0}', "\u{410}\u{306}"),
+    ('\u{4d1}', "\u{430}\u{306}"), ('\u{4d2}', "\u{410}\u{308}"), ('\u{4d3}', "\u{430}\u{308}"),
+    ('\u{4d6}', "\u{415}\u{306}"), ('\u{4d7}', "\u{435}\u{306}"), ('\u{4da}', "\u{4d8}\u{308}"),
+    ('\u{4db}', "\u{4d9}\u{308}"), ('\u{4dc}', "\u{416}\u{308}"), ('\u{4dd}', "\u{436}\u{308}"),
+    ('\u{4de}', "\u{417}\u{308}"), ('\u{4df}', "\u{437}\u{308}"), ('\u{4e2}', "\u{418}\u{304}"),
+    ('\u{4e3}', "\u{438}\u{304}"), ('\u{4e4}', "\u{418}\u{308}"), ('\u{4e5}', "\u{438}\u{308}"),
+    ('\u{4e6}', "\u{41e}\u{308}"), ('\u{4e7}', "\u{43e}\u{308}"), ('\u{4ea}', "\u{4e8}\u{308}"),
+    ('\u{4eb}', "\u{4e9}\u{308}"), ('\u{4ec}', "\u{42d}\u{308}"), ('\u{4ed}', "\u{44d}\u{308}"),
+    ('\u{4ee}', "\u{423}\u{304}"), ('\u{4ef}', "\u{443}\u{304}"), ('\u{4f0}', "\u{423}\u{308}"),
+    ('\u{4f1}', "\u{443}\u{308}"), ('\u{4f2}', "\u{423}\u{30b}"), ('\u{4f3}', "\u{443}\u{30b}"),
+    ('\u{4f4}', "\u{427}\u{308}"), ('\u{4f5}', "\u{447}\u{308}"), ('\u{4f8}', "\u{42b}\u{308}"),
+    ('\u{4f9}', "\u{44b}\u{308}"), ('\u{622}', "\u{627}\u{653}"), ('\u{623}', "\u{627}\u{654}"),
+    ('\u{624}', "\u{648}\u{654}"), ('\u{625}', "\u{627}\u{655}"), ('\u{626}', "\u{64a}\u{654}"),
+    ('\u{6c0}', "\u{6d5}\u{654}"), ('\u{6c2}', "\u{6c1}\u{654}"), ('\u{6d3}', "\u{6d2}\u{654}"),
+    ('\u{929}', "\u{928}\u{93c}"), ('\u{931}', "\u{930}\u{93c}"), ('\u{934}', "\u{933}\u{93c}"),
+    ('\u{958}', "\u{915}\u{93c}"), ('\u{959}', "\u{916}\u{93c}"), ('\u{95a}', "\u{917}\u{93c}"),
+    ('\u{95b}', "\u{91c}\u{93c}"), ('\u{95c}', "\u{921}\u{93c}"), ('\u{95d}', "\u{922}\u{93c}"),
+    ('\u{95e}', "\u{92b}\u{93c}"), ('\u{95f}', "\u{92f}\u{93c}"), ('\u{9cb}', "\u{9c7}\u{9be}"),
+    ('\u{9cc}', "\u{9c7}\u{9d7}"), ('\u{9dc}', "\u{9a1}\u{9bc}"), ('\u{9dd}', "\u{9a2}\u{9bc}"),
+    ('\u{9df}', "\u{9af}\u{9bc}"), ('\u{a33}', "\u{a32}\u{a3c}"), ('\u{a36}', "\u{a38}\u{a3c}"),
+    ('\u{a59}', "\u{a16}\u{a3c}"), ('\u{a5a}', "\u{a17}\u{a3c}"), ('\u{a5b}', "\u{a1c}\u{a3c}"),
+    ('\u{a5e}', "\u{a2b}\u{a3c}"), ('\u{b48}', "\u{b47}\u{b56}"), ('\u{b4b}', "\u{b47}\u{b3e}"),
+    ('\u{b4c}', "\u{b47}\u{b57}"), ('\u{b5c}', "\u{b21}\u{b3c}"), ('\u{b5d}', "\u{b22}\u{b3c}"),
+    ('\u{b94}', "\u{b92}\u{bd7}"), ('\u{bca}', "\u{bc6}\u{bbe}"), ('\u{bcb}', "\u{bc7}\u{bbe}"),
+    ('\u{bcc}', "\u{bc6}\u{bd7}"), ('\u{c48}', "\u{c46}\u{c56}"), ('\u{cc0}', "\u{cbf}\u{cd5}"),
+    ('\u{cc7}', "\u{cc6}\u{cd5}"), ('\u{cc8}', "\u{cc6}\u{cd6}"), ('\u{cca}', "\u{cc6}\u{cc2}"),
+    ('\u{ccb}', "\u{cc6}\u{cc2}\u{cd5}"), ('\u{d4a}', "\u{d46}\u{d3e}"), ('\u{d4b}', "\u{d47}\u{d3e}"),
+    ('\u{d4c}', "\u{d46}\u{d57}"), ('\u{dda}', "\u{dd9}\u{dca}"), ('\u{ddc}', "\u{dd9}\u{dcf}"),
+    ('\u{ddd}', "\u{dd9}\u{dcf}\u{dca}"), ('\u{dde}', "\u{dd9}\u{ddf}"), ('\u{f43}', "\u{f42}\u{fb7}"),
+    ('\u{f4d}', "\u{f4c}\u{fb7}"), ('\u{f52}', "\u{f51}\u{fb7}"), ('\u{f57}', "\u{f56}\u{fb7}"),
+    ('\u{f5c}', "\u{f5b}\u{fb7}"), ('\u{f69}', "\u{f40}\u{fb5}"), ('\u{f73}', "\u{f71}\u{f72}"),
+    ('\u{f75}', "\u{f71}\u{f74}"), ('\u{f76}', "\u{fb2}\u{f80}"), ('\u{f78}', "\u{fb3}\u{f80}"),
+    ('\u{f81}', "\u{f71}\u{f80}"), ('\u{f93}', "\u{f92}\u{fb7}"), ('\u{f9d}', "\u{f9c}\u{fb7}"),
+    ('\u{fa2}', "\u{fa1}\u{fb7}"), ('\u{fa7}', "\u{fa6}\u{fb7}"), ('\u{fac}', "\u{fab}\u{fb7}"),
+    ('\u{fb9}', "\u{f90}\u{fb5}"), ('\u{1026}', "\u{1025}\u{102e}"), ('\u{1b06}', "\u{1b05}\u{1b35}"),
+    ('\u{1b08}', "\u{1b07}\u{1b35}"), ('\u{1b0a}', "\u{1b09}\u{1b35}"), ('\u{1b0c}', "\u{1b0b}\u{1b35}"),
+    ('\u{1b0e}', "\u{1b0d}\u{1b35}"), ('\u{1b12}', "\u{1b11}\u{1b35}"), ('\u{1b3b}', "\u{1b3a}\u{1b35}"),
+    ('\u{1b3d}', "\u{1b3c}\u{1b35}"), ('\u{1b40}', "\u{1b3e}\u{1b35}"), ('\u{1b41}', "\u{1b3f}\u{1b35}"),
+    ('\u{1b43}', "\u{1b42}\u{1b35}"), ('\u{1e00}', "\u{41}\u{325}"), ('\u{1e01}', "\u{61}\u{325}"),
+    ('\u{1e02}', "\u{42}\u{307}"), ('\u{1e03}', "\u{62}\u{307}"), ('\u{1e04}', "\u{42}\u{323}"),
+    ('\u{1e05}', "\u{62}\u{323}"), ('\u{1e06}', "\u{42}\u{331}"), ('\u{1e07}', "\u{62}\u{331}"),
+    ('\u{1e08}', "\u{43}\u{327}\u{301}"), ('\u{1e09}', "\u{63}\u{327}\u{301}"), ('\u{1e0a}', "\u{44}\u{307}"),
+    ('\u{1e0b}', "\u{64}\u{307}"), ('\u{1e0c}', "\u{44}\u{323}"), ('\u{1e0d}', "\u{64}\u{323}"),
+    ('\u{1e0e}', "\u{44}\u{331}"), ('\u{1e0f}', "\u{64}\u{331}"), ('\u{1e10}', "\u{44}\u{327}"),
+    ('\u{1e11}', "\u{64}\u{327}"), ('\u{1e12}', "\u{44}\u{32d}"), ('\u{1e13}', "\u{64}\u{32d}"),
+    ('\u{1e14}', "\u{45}\u{304}\u{300}"), ('\u{1e15}', "\u{65}\u{304}\u{300}"), ('\u{1e16}', "\u{45}\u{304}\u{301}"),
+    ('\u{1e17}', "\u{65}\u{304}\u{301}"), ('\u{1e18}', "\u{45}\u{32d}"), ('\u{1e19}', "\u{65}\u{32d}"),
+    ('\u{1e1a}', "\u{45}\u{330}"), ('\u{1e1b}', "\u{65}\u{330}"), ('\u{1e1c}', "\u{45}\u{327}\u{306}"),
+    ('\u{1e1d}', "\u{65}\u{327}\u{306}"), ('\u{1e1e}', "\u{46}\u{307}"), ('\u{1e1f}', "\u{66}\u{307}"),
+    ('\u{1e20}', "\u{47}\u{304}"), ('\u{1e21}', "\u{67}\u{304}"), ('\u{1e22}', "\u{48}\u{307}"),
+    ('\u{1e23}', "\u{68}\u{307}"), ('\u{1e24}', "\u{48}\u{323}"), ('\u{1e25}', "\u{68}\u{323}"),
+    ('\u{1e26}', "\u{48}\u{308}"), ('\u{1e27}', "\u{68}\u{308}"), ('\u{1e28}', "\u{48}\u{327}"),
+    ('\u{1e29}', "\u{68}\u{327}"), ('\u{1e2a}', "\u{48}\u{32e}"), ('\u{1e2b}', "\u{68}\u{32e}"),
+    ('\u{1e2c}', "\u{49}\u{330}"), ('\u{1e2d}', "\u{69}\u{330}"), ('\u{1e2e}', "\u{49}\u{308}\u{301}"),
+    ('\u{1e2f}', "\u{69}\u{308}\u{301}"), ('\u{1e30}', "\u{4b}\u{301}"), ('\u{1e31}', "\u{6b}\u{301}"),
+    ('\u{1e32}', "\u{4b}\u{323}"), ('\u{1e33}', "\u{6b}\u{323}"), ('\u{1e34}', "\u{4b}\u{331}"),
+    ('\u{1e35}', "\u{6b}\u{331}"), ('\u{1e36}', "\u{4c}\u{323}"), ('\u{1e37}', "\u{6c}\u{323}"),
+    ('\u{1e38}', "\u{4c}\u{323}\u{304}"), ('\u{1e39}', "\u{6c}\u{323}\u{304}"), ('\u{1e3a}', "\u{4c}\u{331}"),
+    ('\u{1e3b}', "\u{6c}\u{331}"), ('\u{1e3c}', "\u{4c}\u{32d}"), ('\u{1e3d}', "\u{6c}\u{32d}"),
+    ('\u{1e3e}', "\u{4d}\u{301}"), ('\u{1e3f}', "\u{6d}\u{301}"), ('\u{1e40}', "\u{4d}\u{307}"),
+    ('\u{1e41}', "\u{6d}\u{307}"), ('\u{1e42}', "\u{4d}\u{323}"), ('\u{1e43}', "\u{6d}\u{323}"),
+    ('\u{1e44}', "\u{4e}\u{307}"), ('\u{1e45}', "\u{6e}\u{307}"), ('\u{1e46}', "\u{4e}\u{323}"),
+    ('\u{1e47}', "\u{6e}\u{323}"), ('\u{1e48}', "\u{4e}\u{331}"), ('\u{1e49}', "\u{6e}\u{331}"),
+    ('\u{1e4a}', "\u{4e}\u{32d}"), ('\u{1e4b}', "\u{6e}\u{32d}"), ('\u{1e4c}', "\u{4f}\u{303}\u{301}"),
+    ('\u{1e4d}', "\u{6f}\u{303}\u{301}"), ('\u{1e4e}', "\u{4f}\u{303}\u{308}"), ('\u{1e4f}', "\u{6f}\u{303}\u{308}"),
+    ('\u{1e50}', "\u{4f}\u{304}\u{300}"), ('\u{1e51}', "\u{6f}\u{304}\u{300}"), ('\u{1e52}', "\u{4f}\u{304}\u{301}"),
+    ('\u{1e53}', "\u{6f}\u{304}\u{301}"), ('\u{1e54}', "\u{50}\u{301}"), ('\u{1e55}', "\u{70}\u{301}"),
+    ('\u{1e56}', "\u{50}\u{307}"), ('\u{1e57}', "\u{70}\u{307}"), ('\u{1e58}', "\u{52}\u{307}"),
+    ('\u{1e59}', "\u{72}\u{307}"), ('\u{1e5a}', "\u{52}\u{323}"), ('\u{1e5b}', "\u{72}\u{323}"),
+    ('\u{1e5c}', "\u{52}\u{323}\u{304}"), ('\u{1e5d}', "\u{72}\u{323}\u{304}"), ('\u{1e5e}', "\u{52}\u{331}"),
+    ('\u{1e5f}', "\u{72}\u{331}"), ('\u{1e60}', "\u{53}\u{307}"), ('\u{1e61}', "\u{73}\u{307}"),
+    ('\u{1e62}', "\u{53}\u{323}"), ('\u{1e63}', "\u{73}\u{323}"), ('\u{1e64}', "\u{53}\u{301}\u{307}"),
+    ('\u{1e65}', "\u{73}\u{301}\u{307}"), ('\u{1e66}', "\u{53}\u{30c}\u{307}"), ('\u{1e67}', "\u{73}\u{30c}\u{307}"),
+    ('\u{1e68}', "\u{53}\u{323}\u{307}"), ('\u{1e69}', "\u{73}\u{323}\u{307}"), ('\u{1e6a}', "\u{54}\u{307}"),
+    ('\u{1e6b}', "\u{74}\u{307}"), ('\u{1e6c}', "\u{54}\u{323}"), ('\u{1e6d}', "\u{74}\u{323}"),
+    ('\u{1e6e}', "\u{54}\u{331}"), ('\u{1e6f}', "\u{74}\u{331}"), ('\u{1e70}', "\u{54}\u{32d}"),
+    ('\u{1e71}', "\u{74}\u{32d}"), ('\u{1e72}', "\u{55}\u{324}"), ('\u{1e73}', "\u{75}\u{324}"),
+    ('\u{1e74}', "\u{55}\u{330}"), ('\u{1e75}', "\u{75}\u{330}"), ('\u{1e76}', "\u{55}\u{32d}"),
+    ('\u{1e77}', "\u{75}\u{32d}"), ('\u{1e78}', "\u{55}\u{303}\u{301}"), ('\u{1e79}', "\u{75}\u{303}\u{301}"),
+    ('\u{1e7a}', "\u{55}\u{304}\u{308}"), ('\u{1e7b}', "\u{75}\u{304}\u{308}"), ('\u{1e7c}', "\u{56}\u{303}"),
+    ('\u{1e7d}', "\u{76}\u{303}"), ('\u{1e7e}', "\u{56}\u{323}"), ('\u{1e7f}', "\u{76}\u{323}"),
+    ('\u{1e80}', "\u{57}\u{300}"), ('\u{1e81}', "\u{77}\u{300}"), ('\u{1e82}', "\u{57}\u{301}"),
+    ('\u{1e83}', "\u{77}\u{301}"), ('\u{1e84}', "\u{57}\u{308}"), ('\u{1e85}', "\u{77}\u{308}"),
+    ('\u{1e86}', "\u{57}\u{307}"), ('\u{1e87}', "\u{77}\u{307}"), ('\u{1e88}', "\u{57}\u{323}"),
+    ('\u{1e89}', "\u{77}\u{323}"), ('\u{1e8a}', "\u{58}\u{307}"), ('\u{1e8b}', "\u{78}\u{307}"),
+    ('\u{1e8c}', "\u{58}\u{308}"), ('\u{1e8d}', "\u{78}\u{308}"), ('\u{1e8e}', "\u{59}\u{307}"),
+    ('\u{1e8f}', "\u{79}\u{307}"), ('\u{1e90}', "\u{5a}\u{302}"), ('\u{1e91}', "\u{7a}\u{302}"),
+    ('\u{1e92}', "\u{5a}\u{323}"), ('\u{1e93}', "\u{7a}\u{323}"), ('\u{1e94}', "\u{5a}\u{331}"),
+    ('\u{1e95}', "\u{7a}\u{331}"), ('\u{1e96}', "\u{68}\u{331}"), ('\u{1e97}', "\u{74}\u{308}"),
+    ('\u{1e98}', "\u{77}\u{30a}"), ('\u{1e99}', "\u{79}\u{30a}"), ('\u{1e9b}', "\u{17f}\u{307}"),
+    ('\u{1ea0}', "\u{41}\u{323}"), ('\u{1ea1}', "\u{61}\u{323}"), ('\u{1ea2}', "\u{41}\u{309}"),
+    ('\u{1ea3}', "\u{61}\u{309}"), ('\u{1ea4}', "\u{41}\u{302}\u{301}"), ('\u{1ea5}', "\u{61}\u{302}\u{301}"),
+    ('\u{1ea6}', "\u{41}\u{302}\u{300}"), ('\u{1ea7}', "\u{61}\u{302}\u{300}"), ('\u{1ea8}', "\u{41}\u{302}\u{309}"),
+    ('\u{1ea9}', "\u{61}\u{302}\u{309}"), ('\u{1eaa}', "\u{41}\u{302}\u{303}"), ('\u{1eab}', "\u{61}\u{302}\u{303}"),
+    ('\u{1eac}', "\u{41}\u{323}\u{302}"), ('\u{1ead}', "\u{61}\u{323}\u{302}"), ('\u{1eae}', "\u{41}\u{306}\u{301}"),
+    ('\u{1eaf}', "\u{61}\u{306}\u{301}"), ('\u{1eb0}', "\u{41}\u{306}\u{300}"), ('\u{1eb1}', "\u{61}\u{306}\u{300}"),
+    ('\u{1eb2}', "\u{41}\u{306}\u{309}"), ('\u{1eb3}', "\u{61}\u{306}\u{309}"), ('\u{1eb4}', "\u{41}\u{306}\u{303}"),
+    ('\u{1eb5}', "\u{61}\u{306}\u{303}"), ('\u{1eb6}', "\u{41}\u{323}\u{306}"), ('\u{1eb7}', "\u{61}\u{323}\u{306}"),
+    ('\u{1eb8}', "\u{45}\u{323}"), ('\u{1eb9}', "\u{65}\u{323}"), ('\u{1eba}', "\u{45}\u{309}"),
+    ('\u{1ebb}', "\u{65}\u{309}"), ('\u{1ebc}', "\u{45}\u{303}"), ('\u{1ebd}', "\u{65}\u{303}"),
+    ('\u{1ebe}', "\u{45}\u{302}\u{301}"), ('\u{1ebf}', "\u{65}\u{302}\u{301}"), ('\u{1ec0}', "\u{45}\u{302}\u{300}"),
+    ('\u{1ec1}', "\u{65}\u{302}\u{300}"), ('\u{1ec2}', "\u{45}\u{302}\u{309}"), ('\u{1ec3}', "\u{65}\u{302}\u{309}"),
+    ('\u{1ec4}', "\u{45}\u{302}\u{303}"), ('\u{1ec5}', "\u{65}\u{302}\u{303}"), ('\u{1ec6}', "\u{45}\u{323}\u{302}"),
+    ('\u{1ec7}', "\u{65}\u{323}\u{302}"), ('\u{1ec8}', "\u{49}\u{309}"), ('\u{1ec9}', "\u{69}\u{309}"),
+    ('\u{1eca}', "\u{49}\u{323}"), ('\u{1ecb}', "\u{69}\u{323}"), ('\u{1ecc}', "\u{4f}\u{323}"),
+    ('\u{1ecd}', "\u{6f}\u{323}"), ('\u{1ece}', "\u{4f}\u{309}"), ('\u{1ecf}', "\u{6f}\u{309}"),
+    ('\u{1ed0}', "\u{4f}\u{302}\u{301}"), ('\u{1ed1}', "\u{6f}\u{302}\u{301}"), ('\u{1ed2}', "\u{4f}\u{302}\u{300}"),
+    ('\u{1ed3}', "\u{6f}\u{302}\u{300}"), ('\u{1ed4}', "\u{4f}\u{302}\u{309}"), ('\u{1ed5}', "\u{6f}\u{302}\u{309}"),
+    ('\u{1ed6}', "\u{4f}\u{302}\u{303}"), ('\u{1ed7}', "\u{6f}\u{302}\u{303}"), ('\u{1ed8}', "\u{4f}\u{323}\u{302}"),
+    ('\u{1ed9}', "\u{6f}\u{323}\u{302}"), ('\u{1eda}', "\u{4f}\u{31b}\u{301}"), ('\u{1edb}', "\u{6f}\u{31b}\u{301}"),
+    ('\u{1edc}', "\u{4f}\u{31b}\u{300}"), ('\u{1edd}', "\u{6f}\u{31b}\u{300}"), ('\u{1ede}', "\u{4f}\u{31b}\u{309}"),
+    ('\u{1edf}', "\u{6f}\u{31b}\u{309}"), ('\u{1ee0}', "\u{4f}\u{31b}\u{303}"), ('\u{1ee1}', "\u{6f}\u{31b}\u{303}"),
+    ('\u{1ee2}', "\u{4f}\u{31b}\u{323}"), ('\u{1ee3}', "\u{6f}\u{31b}\u{323}"), ('\u{1ee4}', "\u{55}\u{323}"),
+    ('\u{1ee5}', "\u{75}\u{323}"), ('\u{1ee6}', "\u{55}\u{309}"), ('\u{1ee7}', "\u{75}\u{309}"),
+    ('\u{1ee8}', "\u{55}\u{31b}\u{301}"), ('\u{1ee9}', "\u{75}\u{31b}\u{301}"), ('\u{1eea}', "\u{55}\u{31b}\u{300}"),
+    ('\u{1eeb}', "\u{75}\u{31b}\u{300}"), ('\u{1eec}', "\u{55}\u{31b}\u{309}"), ('\u{1eed}', "\u{75}\u{31b}\u{309}"),
+    ('\u{1eee}', "\u{55}\u{31b}\u{303}"), ('\u{1eef}', "\u{75}\u{31b}\u{303}"), ('\u{1ef0}', "\u{55}\u{31b}\u{323}"),
+    ('\u{1ef1}', "\u{75}\u{31b}\u{323}"), ('\u{1ef2}', "\u{59}\u{300}"), ('\u{1ef3}', "\u{79}\u{300}"),
+    ('\u{1ef4}', "\u{59}\u{323}"), ('\u{1ef5}', "\u{79}\u{323}"), ('\u{1ef6}', "\u{59}\u{309}"),
+    ('\u{1ef7}', "\u{79}\u{309}"), ('\u{1ef8}', "\u{59}\u{303}"), ('\u{1ef9}', "\u{79}\u{303}"),
+    ('\u{1f00}', "\u{3b1}\u{313}"), ('\u{1f01}', "\u{3b1}\u{314}"), ('\u{1f02}', "\u{3b1}\u{313}\u{300}"),
+    ('\u{1f03}', "\u{3b1}\u{314}\u{300}"), ('\u{1f04}', "\u{3b1}\u{313}\u{301}"), ('\u{1f05}', "\u{3b1}\u{314}\u{301}"),
+    ('\u{1f06}', "\u{3b1}\u{313}\u{342}"), ('\u{1f07}', "\u{3b1}\u{314}\u{342}"), ('\u{1f08}', "\u{391}\u{313}"),
+    ('\u{1f09}', "\u{391}\u{314}"), ('\u{1f0a}', "\u{391}\u{313}\u{300}"), ('\u{1f0b}', "\u{391}\u{314}\u{300}"),
+    ('\u{1f0c}', "\u{391}\u{313}\u{301}"), ('\u{1f0d}', "\u{391}\u{314}\u{301}"), ('\u{1f0e}', "\u{391}\u{313}\u{342}"),
+    ('\u{1f0f}', "\u{391}\u{314}\u{342}"), ('\u{1f10}', "\u{3b5}\u{313}"), ('\u{1f11}', "\u{3b5}\u{314}"),
+    ('\u{1f12}', "\u{3b5}\u{313}\u{300}"), ('\u{1f13}', "\u{3b5}\u{314}\u{300}"), ('\u{1f14}', "\u{3b5}\u{313}\u{301}"),
+    ('\u{1f15}', "\u{3b5}\u{314}\u{301}"), ('\u{1f18}', "\u{395}\u{313}"), ('\u{1f19}', "\u{395}\u{314}"),
+    ('\u{1f1a}', "\u{395}\u{313}\u{300}"), ('\u{1f1b}', "\u{395}\u{314}\u{300}"), ('\u{1f1c}', "\u{395}\u{313}\u{301}"),
+    ('\u{1f1d}', "\u{395}\u{314}\u{301}"), ('\u{1f20}', "\u{3b7}\u{313}"), ('\u{1f21}', "\u{3b7}\u{314}"),
+    ('\u{1f22}', "\u{3b7}\u{313}\u{300}"), ('\u{1f23}', "\u{3b7}\u{314}\u{300}"), ('\u{1f24}', "\u{3b7}\u{313}\u{301}"),
+    ('\u{1f25}', "\u{3b7}\u{314}\u{301}"), ('\u{1f26}', "\u{3b7}\u{313}\u{342}"), ('\u{1f27}', "\u{3b7}\u{314}\u{342}"),
+    ('\u{1f28}', "\u{397}\u{313}"), ('\u{1f29}', "\u{397}\u{314}"), ('\u{1f2a}', "\u{397}\u{313}\u{300}"),
+    ('\u{1f2b}', "\u{397}\u{314}\u{300}"), ('\u{1f2c}', "\u{397}\u{313}\u{301}"), ('\u{1f2d}', "\u{397}\u{314}\u{301}"),
+    ('\u{1f2e}', "\u{397}\u{313}\u{342}"), ('\u{1f2f}', "\u{397}\u{314}\u{342}"), ('\u{1f30}', "\u{3b9}\u{313}"),
+    ('\u{1f31}', "\u{3b9}\u{314}"), ('\u{1f32}', "\u{3b9}\u{313}\u{300}"), ('\u{1f33}', "\u{3b9}\u{314}\u{300}"),
+    ('\u{1f34}', "\u{3b9}\u{313}\u{301}"), ('\u{1f35}', "\u{3b9}\u{314}\u{301}"), ('\u{1f36}', "\u{3b9}\u{313}\u{342}"),
+    ('\u{1f37}', "\u{3b9}\u{314}\u{342}"), ('\u{1f38}', "\u{399}\u{313}"), ('\u{1f39}', "\u{399}\u{314}"),
+    ('\u{1f3a}', "\u{399}\u{313}\u{300}"), ('\u{1f3b}', "\u{399}\u{314}\u{300}"), ('\u{1f3c}', "\u{399}\u{313}\u{301}"),
+    ('\u{1f3d}', "\u{399}\u{314}\u{301}"), ('\u{1f3e}', "\u{399}\u{313}\u{342}"), ('\u{1f3f}', "\u{399}\u{314}\u{342}"),
+    ('\u{1f40}', "\u{3bf}\u{313}"), ('\u{1f41}', "\u{3bf}\u{314}"), ('\u{1f42}', "\u{3bf}\u{313}\u{300}"),
+    ('\u{1f43}', "\u{3bf}\u{314}\u{300}"), ('\u{1f44}', "\u{3bf}\u{313}\u{301}"), ('\u{1f45}', "\u{3bf}\u{314}\u{301}"),
+    ('\u{1f48}', "\u{39f}\u{313}"), ('\u{1f49}', "\u{39f}\u{314}"), ('\u{1f4a}', "\u{39f}\u{313}\u{300}"),
+    ('\u{1f4b}', "\u{39f}\u{314}\u{300}"), ('\u{1f4c}', "\u{39f}\u{313}\u{301}"), ('\u{1f4d}', "\u{39f}\u{314}\u{301}"),
+    ('\u{1f50}', "\u{3c5}\u{313}"), ('\u{1f51}', "\u{3c5}\u{314}"), ('\u{1f52}', "\u{3c5}\u{313}\u{300}"),
+    ('\u{1f53}', "\u{3c5}\u{314}\u{300}"), ('\u{1f54}', "\u{3c5}\u{313}\u{301}"), ('\u{1f55}', "\u{3c5}\u{314}\u{301}"),
+    ('\u{1f56}', "\u{3c5}\u{313}\u{342}"), ('\u{1f57}', "\u{3c5}\u{314}\u{342}"), ('\u{1f59}', "\u{3a5}\u{314}"),
+    ('\u{1f5b}', "\u{3a5}\u{314}\u{300}"), ('\u{1f5d}', "\u{3a5}\u{314}\u{301}"), ('\u{1f5f}', "\u{3a5}\u{314}\u{342}"),
+    ('\u{1f60}', "\u{3c9}\u{313}"), ('\u{1f61}', "\u{3c9}\u{314}"), ('\u{1f62}', "\u{3c9}\u{313}\u{300}"),
+    ('\u{1f63}', "\u{3c9}\u{314}\u{300}"), ('\u{1f64}', "\u{3c9}\u{313}\u{301}"), ('\u{1f65}', "\u{3c9}\u{314}\u{301}"),
+    ('\u{1f66}', "\u{3c9}\u{313}\u{342}"), ('\u{1f67}', "\u{3c9}\u{314}\u{342}"), ('\u{1f68}', "\u{3a9}\u{313}"),
+    ('\u{1f69}', "\u{3a9}\u{314}"), ('\u{1f6a}', "\u{3a9}\u{313}\u{300}"), ('\u{1f6b}', "\u{3a9}\u{314}\u{300}"),
+    ('\u{1f6c}', "\u{3a9}\u{313}\u{301}"), ('\u{1f6d}', "\u{3a9}\u{314}\u{301}"), ('\u{1f6e}', "\u{3a9}\u{313}\u{342}"),
+    ('\u{1f6f}', "\u{3a9}\u{314}\u{342}"), ('\u{1f70}', "\u{3b1}\u{300}"), ('\u{1f71}', "\u{3b1}\u{301}"),
+    ('\u{1f72}', "\u{3b5}\u{300}"), ('\u{1f73}', "\u{3b5}\u{301}"), ('\u{1f74}', "\u{3b7}\u{300}"),
+    ('\u{1f75}', "\u{3b7}\u{301}"), ('\u{1f76}', "\u{3b9}\u{300}"), ('\u{1f77}', "\u{3b9}\u{301}"),
+    ('\u{1f78}', "\u{3bf}\u{300}"), ('\u{1f79}', "\u{3bf}\u{301}"), ('\u{1f7a}', "\u{3c5}\u{300}"),
+    ('\u{1f7b}', "\u{3c5}\u{301}"), ('\u{1f7c}', "\u{3c9}\u{300}"), ('\u{1f7d}', "\u{3c9}\u{301}"),
+    ('\u{1f80}', "\u{3b1}\u{313}\u{345}"), ('\u{1f81}', "\u{3b1}\u{314}\u{345}"), ('\u{1f82}', "\u{3b1}\u{313}\u{300}\u{345}"),
+    ('\u{1f83}', "\u{3b1}\u{314}\u{300}\u{345}"), ('\u{1f84}', "\u{3b1}\u{313}\u{301}\u{345}"), ('\u{1f85}', "\u{3b1}\u{314}\u{301}\u{345}"),
+    ('\u{1f86}', "\u{3b1}\u{313}\u{342}\u{345}"), ('\u{1f87}', "\u{3b1}\u{314}\u{342}\u{345}"), ('\u{1f88}', "\u{391}\u{313}\u{345}"),
+    ('\u{1f89}', "\u{391}\u{314}\u{345}"), ('\u{1f8a}', "\u{391}\u{313}\u{300}\u{345}"), ('\u{1f8b}', "\u{391}\u{314}\u{300}\u{345}"),
+    ('\u{1f8c}', "\u{391}\u{313}\u{301}\u{345}"), ('\u{1f8d}', "\u{391}\u{314}\u{301}\u{345}"), ('\u{1f8e}', "\u{391}\u{313}\u{342}\u{345}"),
+    ('\u{1f8f}', "\u{391}\u{314}\u{342}\u{345}"), ('\u{1f90}', "\u{3b7}\u{313}\u{345}"), ('\u{1f91}', "\u{3b7}\u{314}\u{345}"),
+    ('\u{1f92}', "\u{3b7}\u{313}\u{300}\u{345}"), ('\u{1f93}', "\u{3b7}\u{314}\u{300}\u{345}"), ('\u{1f94}', "\u{3b7}\u{313}\u{301}\u{345}"),
+    ('\u{1f95}', "\u{3b7}\u{314}\u{301}\u{345}"), ('\u{1f96}', "\u{3b7}\u{313}\u{342}\u{345}"), ('\u{1f97}', "\u{3b7}\u{314}\u{342}\u{345}"),
+    ('\u{1f98}', "\u{397}\u{313}\u{345}"), ('\u{1f99}', "\u{397}\u{314}\u{345}"), ('\u{1f9a}', "\u{397}\u{313}\u{300}\u{345}"),
+    ('\u{1f9b}', "\u{397}\u{314}\u{300}\u{345}"), ('\u{1f9c}', "\u{397}\u{313}\u{301}\u{345}"), ('\u{1f9d}', "\u{397}\u{314}\u{301}\u{345}"),
+    ('\u{1f9e}', "\u{397}\u{313}\u{342}\u{345}"), ('\u{1f9f}', "\u{397}\u{314}\u{342}\u{345}"), ('\u{1fa0}', "\u{3c9}\u{313}\u{345}"),
+    ('\u{1fa1}', "\u{3c9}\u{314}\u{345}"), ('\u{1fa2}', "\u{3c9}\u{313}\u{300}\u{345}"), ('\u{1fa3}', "\u{3c9}\u{314}\u{300}\u{345}"),
+    ('\u{1fa4}', "\u{3c9}\u{313}\u{301}\u{345}"), ('\u{1fa5}', "\u{3c9}\u{314}\u{301}\u{345}"), ('\u{1fa6}', "\u{3c9}\u{313}\u{342}\u{345}"),
+    ('\u{1fa7}', "\u{3c9}\u{314}\u{342}\u{345}"), ('\u{1fa8}', "\u{3a9}\u{313}\u{345}"), ('\u{1fa9}', "\u{3a9}\u{314}\u{345}"),
+    ('\u{1faa}', "\u{3a9}\u{313}\u{300}\u{345}"), ('\u{1fab}', "\u{3a9}\u{314}\u{300}\u{345}"), ('\u{1fac}', "\u{3a9}\u{313}\u{301}\u{345}"),
+    ('\u{1fad}', "\u{3a9}\u{314}\u{301}\u{345}"), ('\u{1fae}', "\u{3a9}\u{313}\u{342}\u{345}"), ('\u{1faf}', "\u{3a9}\u{314}\u{342}\u{345}"),
+    ('\u{1fb0}', "\u{3b1}\u{306}"), ('\u{1fb1}', "\u{3b1}\u{304}"), ('\u{1fb2}', "\u{3b1}\u{300}\u{345}"),
+    ('\u{1fb3}', "\u{3b1}\u{345}"), ('\u{1fb4}', "\u{3b1}\u{301}\u{345}"), ('\u{1fb6}', "\u{3b1}\u{342}"),
+    ('\u{1fb7}', "\u{3b1}\u{342}\u{345}"), ('\u{1fb8}', "\u{391}\u{306}"), ('\u{1fb9}', "\u{391}\u{304}"),
+    ('\u{1fba}', "\u{391}\u{300}"), ('\u{1fbb}', "\u{391}\u{301}"), ('\u{1fbc}', "\u{391}\u{345}"),
+    ('\u{1fbe}', "\u{3b9}"), ('\u{1fc1}', "\u{a8}\u{342}"), ('\u{1fc2}', "\u{3b7}\u{300}\u{345}"),
+    ('\u{1fc3}', "\u{3b7}\u{345}"), ('\u{1fc4}', "\u{3b7}\u{301}\u{345}"), ('\u{1fc6}', "\u{3b7}\u{342}"),
+    ('\u{1fc7}', "\u{3b7}\u{342}\u{345}"), ('\u{1fc8}', "\u{395}\u{300}"), ('\u{1fc9}', "\u{395}\u{301}"),
+    ('\u{1fca}', "\u{397}\u{300}"), ('\u{1fcb}', "\u{397}\u{301}"), ('\u{1fcc}', "\u{397}\u{345}"),
+    ('\u{1fcd}', "\u{1fbf}\u{300}"), ('\u{1fce}', "\u{1fbf}\u{301}"), ('\u{1fcf}', "\u{1fbf}\u{342}"),
+    ('\u{1fd0}', "\u{3b9}\u{306}"), ('\u{1fd1}', "\u{3b9}\u{304}"), ('\u{1fd2}', "\u{3b9}\u{308}\u{300}"),
+    ('\u{1fd3}', "\u{3b9}\u{308}\u{301}"), ('\u{1fd6}', "\u{3b9}\u{342}"), ('\u{1fd7}', "\u{3b9}\u{308}\u{342}"),
+    ('\u{1fd8}', "\u{399}\u{306}"), ('\u{1fd9}', "\u{399}\u{304}"), ('\u{1fda}', "\u{399}\u{300}"),
+    ('\u{1fdb}', "\u{399}\u{301}"), ('\u{1fdd}', "\u{1ffe}\u{300}"), ('\u{1fde}', "\u{1ffe}\u{301}"),
+    ('\u{1fdf}', "\u{1ffe}\u{342}"), ('\u{1fe0}', "\u{3c5}\u{306}"), ('\u{1fe1}', "\u{3c5}\u{304}"),
+    ('\u{1fe2}', "\u{3c5}\u{308}\u{300}"), ('\u{1fe3}', "\u{3c5}\u{308}\u{301}"), ('\u{1fe4}', "\u{3c1}\u{313}"),
+    ('\u{1fe5}', "\u{3c1}\u{314}"), ('\u{1fe6}', "\u{3c5}\u{342}"), ('\u{1fe7}', "\u{3c5}\u{308}\u{342}"),
+    ('\u{1fe8}', "\u{3a5}\u{306}"), ('\u{1fe9}', "\u{3a5}\u{304}"), ('\u{1fea}', "\u{3a5}\u{300}"),
+    ('\u{1feb}', "\u{3a5}\u{301}"), ('\u{1fec}', "\u{3a1}\u{314}"), ('\u{1fed}', "\u{a8}\u{300}"),
+    ('\u{1fee}', "\u{a8}\u{301}"), ('\u{1fef}', "\u{60}"), ('\u{1ff2}', "\u{3c9}\u{300}\u{345}"),
+    ('\u{1ff3}', "\u{3c9}\u{345}"), ('\u{1ff4}', "\u{3c9}\u{301}\u{345}"), ('\u{1ff6}', "\u{3c9}\u{342}"),
+    ('\u{1ff7}', "\u{3c9}\u{342}\u{345}"), ('\u{1ff8}', "\u{39f}\u{300}"), ('\u{1ff9}', "\u{39f}\u{301}"),
+    ('\u{1ffa}', "\u{3a9}\u{300}"), ('\u{1ffb}', "\u{3a9}\u{301}"), ('\u{1ffc}', "\u{3a9}\u{345}"),
+    ('\u{1ffd}', "\u{b4}"), ('\u{2000}', "\u{2002}"), ('\u{2001}', "\u{2003}"),
+    ('\u{2126}', "\u{3a9}"), ('\u{212a}', "\u{4b}"), ('\u{212b}', "\u{41}\u{30a}"),
+    ('\u{219a}', "\u{2190}\u{338}"), ('\u{219b}', "\u{2192}\u{338}"), ('\u{21ae}', "\u{2194}\u{338}"),
+    ('\u{21cd}', "\u{21d0}\u{338}"), ('\u{21ce}', "\u{21d4}\u{338}"), ('\u{21cf}', "\u{21d2}\u{338}"),
+    ('\u{2204}', "\u{2203}\u{338}"), ('\u{2209}', "\u{2208}\u{338}"), ('\u{220c}', "\u{220b}\u{338}"),
+    ('\u{2224}', "\u{2223}\u{338}"), ('\u{2226}', "\u{2225}\u{338}"), ('\u{2241}', "\u{223c}\u{338}"),
+    ('\u{2244}', "\u{2243}\u{338}"), ('\u{2247}', "\u{2245}\u{338}"), ('\u{2249}', "\u{2248}\u{338}"),
+    ('\u{2260}', "\u{3d}\u{338}"), ('\u{2262}', "\u{2261}\u{338}"), ('\u{226d}', "\u{224d}\u{338}"),
+    ('\u{226e}', "\u{3c}\u{338}"), ('\u{226f}', "\u{3e}\u{338}"), ('\u{2270}', "\u{2264}\u{338}"),
+    ('\u{2271}', "\u{2265}\u{338}"), ('\u{2274}', "\u{2272}\u{338}"), ('\u{2275}', "\u{2273}\u{338}"),
+    ('\u{2278}', "\u{2276}\u{338}"), ('\u{2279}', "\u{2277}\u{338}"), ('\u{2280}', "\u{227a}\u{338}"),
+    ('\u{2281}', "\u{227b}\u{338}"), ('\u{2284}', "\u{2282}\u{338}"), ('\u{2285}', "\u{2283}\u{338}"),
+    ('\u{2288}', "\u{2286}\u{338}"), ('\u{2289}', "\u{2287}\u{338}"), ('\u{22ac}', "\u{22a2}\u{338}"),
+    ('\u{22ad}', "\u{22a8}\u{338}"), ('\u{22ae}', "\u{22a9}\u{338}"), ('\u{22af}', "\u{22ab}\u{338}"),
+    ('\u{22e0}', "\u{227c}\u{338}"), ('\u{22e1}', "\u{227d}\u{338}"), ('\u{22e2}', "\u{2291}\u{338}"),
+    ('\u{22e3}', "\u{2292}\u{338}"), ('\u{22ea}', "\u{22b2}\u{338}"), ('\u{22eb}', "\u{22b3}\u{338}"),
+    ('\u{22ec}', "\u{22b4}\u{338}"), ('\u{22ed}', "\u{22b5}\u{338}"), ('\u{2329}', "\u{3008}"),
+    ('\u{232a}', "\u{3009}"), ('\u{2adc}', "\u{2add}\u{338}"), ('\u{304c}', "\u{304b}\u{3099}"),
+    ('\u{304e}', "\u{304d}\u{3099}"), ('\u{3050}', "\u{304f}\u{3099}"), ('\u{3052}', "\u{3051}\u{3099}"),
+    ('\u{3054}', "\u{3053}\u{3099}"), ('\u{3056}', "\u{3055}\u{3099}"), ('\u{3058}', "\u{3057}\u{3099}"),
+    ('\u{305a}', "\u{3059}\u{3099}"), ('\u{305c}', "\u{305b}\u{3099}"), ('\u{305e}', "\u{305d}\u{3099}"),
+    ('\u{3060}', "\u{305f}\u{3099}"), ('\u{3062}', "\u{3061}\u{3099}"), ('\u{3065}', "\u{3064}\u{3099}"),
+    ('\u{3067}', "\u{3066}\u{3099}"), ('\u{3069}', "\u{3068}\u{3099}"), ('\u{3070}', "\u{306f}\u{3099}"),
+    ('\u{3071}', "\u{306f}\u{309a}"), ('\u{3073}', "\u{3072}\u{3099}"), ('\u{3074}', "\u{3072}\u{309a}"),
+    ('\u{3076}', "\u{3075}\u{3099}"), ('\u{3077}', "\u{3075}\u{309a}"), ('\u{3079}', "\u{3078}\u{3099}"),
+    ('\u{307a}', "\u{3078}\u{309a}"), ('\u{307c}', "\u{307b}\u{3099}"), ('\u{307d}', "\u{307b}\u{309a}"),
+    ('\u{3094}', "\u{3046}\u{3099}"), ('\u{309e}', "\u{309d}\u{3099}"), ('\u{30ac}', "\u{30ab}\u{3099}"),
+    ('\u{30ae}', "\u{30ad}\u{3099}"), ('\u{30b0}', "\u{30af}\u{3099}"), ('\u{30b2}', "\u{30b1}\u{3099}"),
+    ('\u{30b4}', "\u{30b3}\u{3099}"), ('\u{30b6}', "\u{30b5}\u{3099}"), ('\u{30b8}', "\u{30b7}\u{3099}"),
+    ('\u{30ba}', "\u{30b9}\u{3099}"), ('\u{30bc}', "\u{30bb}\u{3099}"), ('\u{30be}', "\u{30bd}\u{3099}"),
+    ('\u{30c0}', "\u{30bf}\u{3099}"), ('\u{30c2}', "\u{30c1}\u{3099}"), ('\u{30c5}', "\u{30c4}\u{3099}"),
+    ('\u{30c7}', "\u{30c6}\u{3099}"), ('\u{30c9}', "\u{30c8}\u{3099}"), ('\u{30d0}', "\u{30cf}\u{3099}"),
+    ('\u{30d1}', "\u{30cf}\u{309a}"), ('\u{30d3}', "\u{30d2}\u{3099}"), ('\u{30d4}', "\u{30d2}\u{309a}"),
+    ('\u{30d6}', "\u{30d5}\u{3099}"), ('\u{30d7}', "\u{30d5}\u{309a}"), ('\u{30d9}', "\u{30d8}\u{3099}"),
+    ('\u{30da}', "\u{30d8}\u{309a}"), ('\u{30dc}', "\u{30db}\u{3099}"), ('\u{30dd}', "\u{30db}\u{309a}"),
+    ('\u{30f4}', "\u{30a6}\u{3099}"), ('\u{30f7}', "\u{30ef}\u{3099}"), ('\u{30f8}', "\u{30f0}\u{3099}"),
+    ('\u{30f9}', "\u{30f1}\u{3099}"), ('\u{30fa}', "\u{30f2}\u{3099}"), ('\u{30fe}', "\u{30fd}\u{3099}"),
+    ('\u{f900}', "\u{8c48}"), ('\u{f901}', "\u{66f4}"), ('\u{f902}', "\u{8eca}"),
+    ('\u{f903}', "\u{8cc8}"), ('\u{f904}', "\u{6ed1}"), ('\u{f905}', "\u{4e32}"),
+    ('\u{f906}', "\u{53e5}"), ('\u{f907}', "\u{9f9c}"), ('\u{f908}', "\u{9f9c}"),
+    ('\u{f909}', "\u{5951}"), ('\u{f90a}', "\u{91d1}"), ('\u{f90b}', "\u{5587}"),
+    ('\u{f90c}', "\u{5948}"), ('\u{f90d}', "\u{61f6}"), ('\u{f90e}', "\u{7669}"),
+    ('\u{f90f}', "\u{7f85}"), ('\u{f910}', "\u{863f}"), ('\u{f911}', "\u{87ba}"),
+    ('\u{f912}', "\u{88f8}"), ('\u{f913}', "\u{908f}"), ('\u{f914}', "\u{6a02}"),
+    ('\u{f915}', "\u{6d1b}"), ('\u{f916}', "\u{70d9}"), ('\u{f917}', "\u{73de}"),
+    ('\u{f918}', "\u{843d}"), ('\u{f919}', "\u{916a}"), ('\u{f91a}', "\u{99f1}"),
+    ('\u{f91b}', "\u{4e82}"), ('\u{f91c}', "\u{5375}"), ('\u{f91d}', "\u{6b04}"),
+    ('\u{f91e}', "\u{721b}"), ('\u{f91f}', "\u{862d}"), ('\u{f920}', "\u{9e1e}"),
+    ('\u{f921}', "\u{5d50}"), ('\u{f922}', "\u{6feb}"), ('\u{f923}', "\u{85cd}"),
+    ('\u{f924}', "\u{8964}"), ('\u{f925}', "\u{62c9}"), ('\u{f926}', "\u{81d8}"),
+    ('\u{f927}', "\u{881f}"), ('\u{f928}', "\u{5eca}"), ('\u{f929}', "\u{6717}"),
+    ('\u{f92a}', "\u{6d6a}"), ('\u{f92b}', "\u{72fc}"), ('\u{f92c}', "\u{90ce}"),
+    ('\u{f92d}', "\u{4f86}"), ('\u{f92e}', "\u{51b7}"), ('\u{f92f}', "\u{52de}"),
+    ('\u{f930}', "\u{64c4}"), ('\u{f931}', "\u{6ad3}"), ('\u{f932}', "\u{7210}"),
+    ('\u{f933}', "\u{76e7}"), ('\u{f934}', "\u{8001}"), ('\u{f935}', "\u{8606}"),
+    ('\u{f936}', "\u{865c}"), ('\u{f937}', "\u{8def}"), ('\u{f938}', "\u{9732}"),
+    ('\u{f939}', "\u{9b6f}"), ('\u{f93a}', "\u{9dfa}"), ('\u{f93b}', "\u{788c}"),
+    ('\u{f93c}', "\u{797f}"), ('\u{f93d}', "\u{7da0}"), ('\u{f93e}', "\u{83c9}"),
+    ('\u{f93f}', "\u{9304}"), ('\u{f940}', "\u{9e7f}"), ('\u{f941}', "\u{8ad6}"),
+    ('\u{f942}', "\u{58df}"), ('\u{f943}', "\u{5f04}"), ('\u{f944}', "\u{7c60}"),
+    ('\u{f945}', "\u{807e}"), ('\u{f946}', "\u{7262}"), ('\u{f947}', "\u{78ca}"),
+    ('\u{f948}', "\u{8cc2}"), ('\u{f949}', "\u{96f7}"), ('\u{f94a}', "\u{58d8}"),
+    ('\u{f94b}', "\u{5c62}"), ('\u{f94c}', "\u{6a13}"), ('\u{f94d}', "\u{6dda}"),
+    ('\u{f94e}', "\u{6f0f}"), ('\u{f94f}', "\u{7d2f}"), ('\u{f950}', "\u{7e37}"),
+    ('\u{f951}', "\u{964b}"), ('\u{f952}', "\u{52d2}"), ('\u{f953}', "\u{808b}"),
+    ('\u{f954}', "\u{51dc}"), ('\u{f955}', "\u{51cc}"), ('\u{f956}', "\u{7a1c}"),
+    ('\u{f957}', "\u{7dbe}"), ('\u{f958}', "\u{83f1}"), ('\u{f959}', "\u{9675}"),
+    ('\u{f95a}', "\u{8b80}"), ('\u{f95b}', "\u{62cf}"), ('\u{f95c}', "\u{6a02}"),
+    ('\u{f95d}', "\u{8afe}"), ('\u{f95e}', "\u{4e39}"), ('\u{f95f}', "\u{5be7}"),
+    ('\u{f960}', "\u{6012}"), ('\u{f961}', "\u{7387}"), ('\u{f962}', "\u{7570}"),
+    ('\u{f963}', "\u{5317}"), ('\u{f964}', "\u{78fb}"), ('\u{f965}', "\u{4fbf}"),
+    ('\u{f966}', "\u{5fa9}"), ('\u{f967}', "\u{4e0d}"), ('\u{f968}', "\u{6ccc}"),
+    ('\u{f969}', "\u{6578}"), ('\u{f96a}', "\u{7d22}"), ('\u{f96b}', "\u{53c3}"),
+    ('\u{f96c}', "\u{585e}"), ('\u{f96d}', "\u{7701}"), ('\u{f96e}', "\u{8449}"),
+    ('\u{f96f}', "\u{8aaa}"), ('\u{f970}', "\u{6bba}"), ('\u{f971}', "\u{8fb0}"),
+    ('\u{f972}', "\u{6c88}"), ('\u{f973}', "\u{62fe}"), ('\u{f974}', "\u{82e5}"),
+    ('\u{f975}', "\u{63a0}"), ('\u{f976}', "\u{7565}"), ('\u{f977}', "\u{4eae}"),
+    ('\u{f978}', "\u{5169}"), ('\u{f979}', "\u{51c9}"), ('\u{f97a}', "\u{6881}"),
+    ('\u{f97b}', "\u{7ce7}"), ('\u{f97c}', "\u{826f}"), ('\u{f97d}', "\u{8ad2}"),
+    ('\u{f97e}', "\u{91cf}"), ('\u{f97f}', "\u{52f5}"), ('\u{f980}', "\u{5442}"),
+    ('\u{f981}', "\u{5973}"), ('\u{f982}', "\u{5eec}"), ('\u{f983}', "\u{65c5}"),
+    ('\u{f984}', "\u{6ffe}"), ('\u{f985}', "\u{792a}"), ('\u{f986}', "\u{95ad}"),
+    ('\u{f987}', "\u{9a6a}"), ('\u{f988}', "\u{9e97}"), ('\u{f989}', "\u{9ece}"),
+    ('\u{f98a}', "\u{529b}"), ('\u{f98b}', "\u{66c6}"), ('\u{f98c}', "\u{6b77}"),
+    ('\u{f98d}', "\u{8f62}"), ('\u{f98e}', "\u{5e74}"), ('\u{f98f}', "\u{6190}"),
+    ('\u{f990}', "\u{6200}"), ('\u{f991}', "\u{649a}"), ('\u{f992}', "\u{6f23}"),
+    ('\u{f993}', "\u{7149}"), ('\u{f994}', "\u{7489}"), ('\u{f995}', "\u{79ca}"),
+    ('\u{f996}', "\u{7df4}"), ('\u{f997}', "\u{806f}"), ('\u{f998}', "\u{8f26}"),
+    ('\u{f999}', "\u{84ee}"), ('\u{f99a}', "\u{9023}"), ('\u{f99b}', "\u{934a}"),
+    ('\u{f99c}', "\u{5217}"), ('\u{f99d}', "\u{52a3}"), ('\u{f99e}', "\u{54bd}"),
+    ('\u{f99f}', "\u{70c8}"), ('\u{f9a0}', "\u{88c2}"), ('\u{f9a1}', "\u{8aaa}"),
+    ('\u{f9a2}', "\u{5ec9}"), ('\u{f9a3}', "\u{5ff5}"), ('\u{f9a4}', "\u{637b}"),
+    ('\u{f9a5}', "\u{6bae}"), ('\u{f9a6}', "\u{7c3e}"), ('\u{f9a7}', "\u{7375}"),
+    ('\u{f9a8}', "\u{4ee4}"), ('\u{f9a9}', "\u{56f9}"), ('\u{f9aa}', "\u{5be7}"),
+    ('\u{f9ab}', "\u{5dba}"), ('\u{f9ac}', "\u{601c}"), ('\u{f9ad}', "\u{73b2}"),
+    ('\u{f9ae}', "\u{7469}"), ('\u{f9af}', "\u{7f9a}"), ('\u{f9b0}', "\u{8046}"),
+    ('\u{f9b1}', "\u{9234}"), ('\u{f9b2}', "\u{96f6}"), ('\u{f9b3}', "\u{9748}"),
+    ('\u{f9b4}', "\u{9818}"), ('\u{f9b5}', "\u{4f8b}"), ('\u{f9b6}', "\u{79ae}"),
+    ('\u{f9b7}', "\u{91b4}"), ('\u{f9b8}', "\u{96b8}"), ('\u{f9b9}', "\u{60e1}"),
+    ('\u{f9ba}', "\u{4e86}"), ('\u{f9bb}', "\u{50da}"), ('\u{f9bc}', "\u{5bee}"),
+    ('\u{f9bd}', "\u{5c3f}"), ('\u{f9be}', "\u{6599}"), ('\u{f9bf}', "\u{6a02}"),
+    ('\u{f9c0}', "\u{71ce}"), ('\u{f9c1}', "\u{7642}"), ('\u{f9c2}', "\u{84fc}"),
+    ('\u{f9c3}', "\u{907c}"), ('\u{f9c4}', "\u{9f8d}"), ('\u{f9c5}', "\u{6688}"),
+    ('\u{f9c6}', "\u{962e}"), ('\u{f9c7}', "\u{5289}"), ('\u{f9c8}', "\u{677b}"),
+    ('\u{f9c9}', "\u{67f3}"), ('\u{f9ca}', "\u{6d41}"), ('\u{f9cb}', "\u{6e9c}"),
+    ('\u{f9cc}', "\u{7409}"), ('\u{f9cd}', "\u{7559}"), ('\u{f9ce}', "\u{786b}"),
+    ('\u{f9cf}', "\u{7d10}"), ('\u{f9d0}', "\u{985e}"), ('\u{f9d1}', "\u{516d}"),
+    ('\u{f9d2}', "\u{622e}"), ('\u{f9d3}', "\u{9678}"), ('\u{f9d4}', "\u{502b}"),
+    ('\u{f9d5}', "\u{5d19}"), ('\u{f9d6}', "\u{6dea}"), ('\u{f9d7}', "\u{8f2a}"),
+    ('\u{f9d8}', "\u{5f8b}"), ('\u{f9d9}', "\u{6144}"), ('\u{f9da}', "\u{6817}"),
+    ('\u{f9db}', "\u{7387}"), ('\u{f9dc}', "\u{9686}"), ('\u{f9dd}', "\u{5229}"),
+    ('\u{f9de}', "\u{540f}"), ('\u{f9df}', "\u{5c65}"), ('\u{f9e0}', "\u{6613}"),
+    ('\u{f9e1}', "\u{674e}"), ('\u{f9e2}', "\u{68a8}"), ('\u{f9e3}', "\u{6ce5}"),
+    ('\u{f9e4}', "\u{7406}"), ('\u{f9e5}', "\u{75e2}"), ('\u{f9e6}', "\u{7f79}"),
+    ('\u{f9e7}', "\u{88cf}"), ('\u{f9e8}', "\u{88e1}"), ('\u{f9e9}', "\u{91cc}"),
+    ('\u{f9ea}', "\u{96e2}"), ('\u{f9eb}', "\u{533f}"), ('\u{f9ec}', "\u{6eba}"),
+    ('\u{f9ed}', "\u{541d}"), ('\u{f9ee}', "\u{71d0}"), ('\u{f9ef}', "\u{7498}"),
+    ('\u{f9f0}', "\u{85fa}"), ('\u{f9f1}', "\u{96a3}"), ('\u{f9f2}', "\u{9c57}"),
+    ('\u{f9f3}', "\u{9e9f}"), ('\u{f9f4}', "\u{6797}"), ('\u{f9f5}', "\u{6dcb}"),
+    ('\u{f9f6}', "\u{81e8}"), ('\u{f9f7}', "\u{7acb}"), ('\u{f9f8}', "\u{7b20}"),
+    ('\u{f9f9}', "\u{7c92}"), ('\u{f9fa}', "\u{72c0}"), ('\u{f9fb}', "\u{7099}"),
+    ('\u{f9fc}', "\u{8b58}"), ('\u{f9fd}', "\u{4ec0}"), ('\u{f9fe}', "\u{8336}"),
+    ('\u{f9ff}', "\u{523a}"), ('\u{fa00}', "\u{5207}"), ('\u{fa01}', "\u{5ea6}"),
+    ('\u{fa02}', "\u{62d3}"), ('\u{fa03}', "\u{7cd6}"), ('\u{fa04}', "\u{5b85}"),
+    ('\u{fa05}', "\u{6d1e}"), ('\u{fa06}', "\u{66b4}"), ('\u{fa07}', "\u{8f3b}"),
+    ('\u{fa08}', "\u{884c}"), ('\u{fa09}', "\u{964d}"), ('\u{fa0a}', "\u{898b}"),
+    ('\u{fa0b}', "\u{5ed3}"), ('\u{fa0c}', "\u{5140}"), ('\u{fa0d}', "\u{55c0}"),
+    ('\u{fa10}', "\u{585a}"), ('\u{fa12}', "\u{6674}"), ('\u{fa15}', "\u{51de}"),
+    ('\u{fa16}', "\u{732a}"), ('\u{fa17}', "\u{76ca}"), ('\u{fa18}', "\u{793c}"),
+    ('\u{fa19}', "\u{795e}"), ('\u{fa1a}', "\u{7965}"), ('\u{fa1b}', "\u{798f}"),
+    ('\u{fa1c}', "\u{9756}"), ('\u{fa1d}', "\u{7cbe}"), ('\u{fa1e}', "\u{7fbd}"),
+    ('\u{fa20}', "\u{8612}"), ('\u{fa22}', "\u{8af8}"), ('\u{fa25}', "\u{9038}"),
+    ('\u{fa26}', "\u{90fd}"), ('\u{fa2a}', "\u{98ef}"), ('\u{fa2b}', "\u{98fc}"),
+    ('\u{fa2c}', "\u{9928}"), ('\u{fa2d}', "\u{9db4}"), ('\u{fa2e}', "\u{90de}"),
+    ('\u{fa2f}', "\u{96b7}"), ('\u{fa30}', "\u{4fae}"), ('\u{fa31}', "\u{50e7}"),
+    ('\u{fa32}', "\u{514d}"), ('\u{fa33}', "\u{52c9}"), ('\u{fa34}', "\u{52e4}"),
+    ('\u{fa35}', "\u{5351}"), ('\u{fa36}', "\u{559d}"), ('\u{fa37}', "\u{5606}"),
+    ('\u{fa38}', "\u{5668}"), ('\u{fa39}', "\u{5840}"), ('\u{fa3a}', "\u{58a8}"),
+    ('\u{fa3b}', "\u{5c64}"), ('\u{fa3c}', "\u{5c6e}"), ('\u{fa3d}', "\u{6094}"),
+    ('\u{fa3e}', "\u{6168}"), ('\u{fa3f}', "\u{618e}"), ('\u{fa40}', "\u{61f2}"),
+    ('\u{fa41}', "\u{654f}"), ('\u{fa42}', "\u{65e2}"), ('\u{fa43}', "\u{6691}"),
+    ('\u{fa44}', "\u{6885}"), ('\u{fa45}', "\u{6d77}"), ('\u{fa46}', "\u{6e1a}"),
+    ('\u{fa47}', "\u{6f22}"), ('\u{fa48}', "\u{716e}"), ('\u{fa49}', "\u{722b}"),
+    ('\u{fa4a}', "\u{7422}"), ('\u{fa4b}', "\u{7891}"), ('\u{fa4c}', "\u{793e}"),
+    ('\u{fa4d}', "\u{7949}"), ('\u{fa4e}', "\u{7948}"), ('\u{fa4f}', "\u{7950}"),
+    ('\u{fa50}', "\u{7956}"), ('\u{fa51}', "\u{795d}"), ('\u{fa52}', "\u{798d}"),
+    ('\u{fa53}', "\u{798e}"), ('\u{fa54}', "\u{7a40}"), ('\u{fa55}', "\u{7a81}"),
+    ('\u{fa56}', "\u{7bc0}"), ('\u{fa57}', "\u{7df4}"), ('\u{fa58}', "\u{7e09}"),
+    ('\u{fa59}', "\u{7e41}"), ('\u{fa5a}', "\u{7f72}"), ('\u{fa5b}', "\u{8005}"),
+    ('\u{fa5c}', "\u{81ed}"), ('\u{fa5d}', "\u{8279}"), ('\u{fa5e}', "\u{8279}"),
+    ('\u{fa5f}', "\u{8457}"), ('\u{fa60}', "\u{8910}"), ('\u{fa61}', "\u{8996}"),
+    ('\u{fa62}', "\u{8b01}"), ('\u{fa63}', "\u{8b39}"), ('\u{fa64}', "\u{8cd3}"),
+    ('\u{fa65}', "\u{8d08}"), ('\u{fa66}', "\u{8fb6}"), ('\u{fa67}', "\u{9038}"),
+    ('\u{fa68}', "\u{96e3}"), ('\u{fa69}', "\u{97ff}"), ('\u{fa6a}', "\u{983b}"),
+    ('\u{fa6b}', "\u{6075}"), ('\u{fa6c}', "\u{242ee}"), ('\u{fa6d}', "\u{8218}"),
+    ('\u{fa70}', "\u{4e26}"), ('\u{fa71}', "\u{51b5}"), ('\u{fa72}', "\u{5168}"),
+    ('\u{fa73}', "\u{4f80}"), ('\u{fa74}', "\u{5145}"), ('\u{fa75}', "\u{5180}"),
+    ('\u{fa76}', "\u{52c7}"), ('\u{fa77}', "\u{52fa}"), ('\u{fa78}', "\u{559d}"),
+    ('\u{fa79}', "\u{5555}"), ('\u{fa7a}', "\u{5599}"), ('\u{fa7b}', "\u{55e2}"),
+    ('\u{fa7c}', "\u{585a}"), ('\u{fa7d}', "\u{58b3}"), ('\u{fa7e}', "\u{5944}"),
+    ('\u{fa7f}', "\u{5954}"), ('\u{fa80}', "\u{5a62}"), ('\u{fa81}', "\u{5b28}"),
+    ('\u{fa82}', "\u{5ed2}"), ('\u{fa83}', "\u{5ed9}"), ('\u{fa84}', "\u{5f69}"),
+    ('\u{fa85}', "\u{5fad}"), ('\u{fa86}', "\u{60d8}"), ('\u{fa87}', "\u{614e}"),
+    ('\u{fa88}', "\u{6108}"), ('\u{fa89}', "\u{618e}"), ('\u{fa8a}', "\u{6160}"),
+    ('\u{fa8b}', "\u{61f2}"), ('\u{fa8c}', "\u{6234}"), ('\u{fa8d}', "\u{63c4}"),
+    ('\u{fa8e}', "\u{641c}"), ('\u{fa8f}', "\u{6452}"), ('\u{fa90}', "\u{6556}"),
+    ('\u{fa91}', "\u{6674}"), ('\u{fa92}', "\u{6717}"), ('\u{fa93}', "\u{671b}"),
+    ('\u{fa94}', "\u{6756}"), ('\u{fa95}', "\u{6b79}"), ('\u{fa96}', "\u{6bba}"),
+    ('\u{fa97}', "\u{6d41}"), ('\u{fa98}', "\u{6edb}"), ('\u{fa99}', "\u{6ecb}"),
+    ('\u{fa9a}', "\u{6f22}"), ('\u{fa9b}', "\u{701e}"), ('\u{fa9c}', "\u{716e}"),
+    ('\u{fa9d}', "\u{77a7}"), ('\u{fa9e}', "\u{7235}"), ('\u{fa9f}', "\u{72af}"),
+    ('\u{faa0}', "\u{732a}"), ('\u{faa1}', "\u{7471}"), ('\u{faa2}', "\u{7506}"),
+    ('\u{faa3}', "\u{753b}"), ('\u{faa4}', "\u{761d}"), ('\u{faa5}', "\u{761f}"),
+    ('\u{faa6}', "\u{76ca}"), ('\u{faa7}', "\u{76db}"), ('\u{faa8}', "\u{76f4}"),
+    ('\u{faa9}', "\u{774a}"), ('\u{faaa}', "\u{7740}"), ('\u{faab}', "\u{78cc}"),
+    ('\u{faac}', "\u{7ab1}"), ('\u{faad}', "\u{7bc0}"), ('\u{faae}', "\u{7c7b}"),
+    ('\u{faaf}', "\u{7d5b}"), ('\u{fab0}', "\u{7df4}"), ('\u{fab1}', "\u{7f3e}"),
+    ('\u{fab2}', "\u{8005}"), ('\u{fab3}', "\u{8352}"), ('\u{fab4}', "\u{83ef}"),
+    ('\u{fab5}', "\u{8779}"), ('\u{fab6}', "\u{8941}"), ('\u{fab7}', "\u{8986}"),
+    ('\u{fab8}', "\u{8996}"), ('\u{fab9}', "\u{8abf}"), ('\u{faba}', "\u{8af8}"),
+    ('\u{fabb}', "\u{8acb}"), ('\u{fabc}', "\u{8b01}"), ('\u{fabd}', "\u{8afe}"),
+    ('\u{fabe}', "\u{8aed}"), ('\u{fabf}', "\u{8b39}"), ('\u{fac0}', "\u{8b8a}"),
+    ('\u{fac1}', "\u{8d08}"), ('\u{fac2}', "\u{8f38}"), ('\u{fac3}', "\u{9072}"),
+    ('\u{fac4}', "\u{9199}"), ('\u{fac5}', "\u{9276}"), ('\u{fac6}', "\u{967c}"),
+    ('\u{fac7}', "\u{96e3}"), ('\u{fac8}', "\u{9756}"), ('\u{fac9}', "\u{97db}"),
+    ('\u{faca}', "\u{97ff}"), ('\u{facb}', "\u{980b}"), ('\u{facc}', "\u{983b}"),
+    ('\u{facd}', "\u{9b12}"), ('\u{face}', "\u{9f9c}"), ('\u{facf}', "\u{2284a}"),
+    ('\u{fad0}', "\u{22844}"), ('\u{fad1}', "\u{233d5}"), ('\u{fad2}', "\u{3b9d}"),
+    ('\u{fad3}', "\u{4018}"), ('\u{fad4}', "\u{4039}"), ('\u{fad5}', "\u{25249}"),
+    ('\u{fad6}', "\u{25cd0}"), ('\u{fad7}', "\u{27ed3}"), ('\u{fad8}', "\u{9f43}"),
+    ('\u{fad9}', "\u{9f8e}"), ('\u{fb1d}', "\u{5d9}\u{5b4}"), ('\u{fb1f}', "\u{5f2}\u{5b7}"),
+    ('\u{fb2a}', "\u{5e9}\u{5c1}"), ('\u{fb2b}', "\u{5e9}\u{5c2}"), ('\u{fb2c}', "\u{5e9}\u{5bc}\u{5c1}"),
+    ('\u{fb2d}', "\u{5e9}\u{5bc}\u{5c2}"), ('\u{fb2e}', "\u{5d0}\u{5b7}"), ('\u{fb2f}', "\u{5d0}\u{5b8}"),
+    ('\u{fb30}', "\u{5d0}\u{5bc}"), ('\u{fb31}', "\u{5d1}\u{5bc}"), ('\u{fb32}', "\u{5d2}\u{5bc}"),
+    ('\u{fb33}', "\u{5d3}\u{5bc}"), ('\u{fb34}', "\u{5d4}\u{5bc}"), ('\u{fb35}', "\u{5d5}\u{5bc}"),
+    ('\u{fb36}', "\u{5d6}\u{5bc}"), ('\u{fb38}', "\u{5d8}\u{5bc}"), ('\u{fb39}', "\u{5d9}\u{5bc}"),
+    ('\u{fb3a}', "\u{5da}\u{5bc}"), ('\u{fb3b}', "\u{5db}\u{5bc}"), ('\u{fb3c}', "\u{5dc}\u{5bc}"),
+    ('\u{fb3e}', "\u{5de}\u{5bc}"), ('\u{fb40}', "\u{5e0}\u{5bc}"), ('\u{fb41}', "\u{5e1}\u{5bc}"),
+    ('\u{fb43}', "\u{5e3}\u{5bc}"), ('\u{fb44}', "\u{5e4}\u{5bc}"), ('\u{fb46}', "\u{5e6}\u{5bc}"),
+    ('\u{fb47}', "\u{5e7}\u{5bc}"), ('\u{fb48}', "\u{5e8}\u{5bc}"), ('\u{fb49}', "\u{5e9}\u{5bc}"),
+    ('\u{fb4a}', "\u{5ea}\u{5bc}"), ('\u{fb4b}', "\u{5d5}\u{5b9}"), ('\u{fb4c}', "\u{5d1}\u{5bf}"),
+    ('\u{fb4d}', "\u{5db}\u{5bf}"), ('\u{fb4e}', "\u{5e4}\u{5bf}"), ('\u{1109a}', "\u{11099}\u{110ba}"),
+    ('\u{1109c}', "\u{1109b}\u{110ba}"), ('\u{110ab}', "\u{110a5}\u{110ba}"), ('\u{1112e}', "\u{11131}\u{11127}"),
+    ('\u{1112f}', "\u{11132}\u{11127}"), ('\u{1134b}', "\u{11347}\u{1133e}"), ('\u{1134c}', "\u{11347}\u{11357}"),
+    ('\u{114bb}', "\u{114b9}\u{114ba}"), ('\u{114bc}', "\u{114b9}\u{114b0}"), ('\u{114be}', "\u{114b9}\u{114bd}"),
+    ('\u{115ba}', "\u{115b8}\u{115af}"), ('\u{115bb}', "\u{115b9}\u{115af}"), ('\u{11938}', "\u{11935}\u{11930}"),
+    ('\u{1d15e}', "\u{1d157}\u{1d165}"), ('\u{1d15f}', "\u{1d158}\u{1d165}"), ('\u{1d160}', "\u{1d158}\u{1d165}\u{1d16e}"),
+    ('\u{1d161}', "\u{1d158}\u{1d165}\u{1d16f}"), ('\u{1d162}', "\u{1d158}\u{1d165}\u{1d170}"), ('\u{1d163}', "\u{1d158}\u{1d165}\u{1d171}"),
+    ('\u{1d164}', "\u{1d158}\u{1d165}\u{1d172}"), ('\u{1d1bb}', "\u{1d1b9}\u{1d165}"), ('\u{1d1bc}', "\u{1d1ba}\u{1d165}"),
+    ('\u{1d1bd}', "\u{1d1b9}\u{1d165}\u{1d16e}"), ('\u{1d1be}', "\u{1d1ba}\u{1d165}\u{1d16e}"), ('\u{1d1bf}', "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    ('\u{1d1c0}', "\u{1d1ba}\u{1d165}\u{1d16f}"), ('\u{2f800}', "\u{4e3d}"), ('\u{2f801}', "\u{4e38}"),
+    ('\u{2f802}', "\u{4e41}"), ('\u{2f803}', "\u{20122}"), ('\u{2f804}', "\u{4f60}"),
+    ('\u{2f805}', "\u{4fae}"), ('\u{2f806}', "\u{4fbb}"), ('\u{2f807}', "\u{5002}"),
+    ('\u{2f808}', "\u{507a}"), ('\u{2f809}', "\u{5099}"), ('\u{2f80a}', "\u{50e7}"),
+    ('\u{2f80b}', "\u{50cf}"), ('\u{2f80c}', "\u{349e}"), ('\u{2f80d}', "\u{2063a}"),
+    ('\u{2f80e}', "\u{514d}"), ('\u{2f80f}', "\u{5154}"), ('\u{2f810}', "\u{5164}"),
+    ('\u{2f811}', "\u{5177}"), ('\u{2f812}', "\u{2051c}"), ('\u{2f813}', "\u{34b9}"),
+    ('\u{2f814}', "\u{5167}"), ('\u{2f815}', "\u{518d}"), ('\u{2f816}', "\u{2054b}"),
+    ('\u{2f817}', "\u{5197}"), ('\u{2f818}', "\u{51a4}"), ('\u{2f819}', "\u{4ecc}"),
+    ('\u{2f81a}', "\u{51ac}"), ('\u{2f81b}', "\u{51b5}"), ('\u{2f81c}', "\u{291df}"),
+    ('\u{2f81d}', "\u{51f5}"), ('\u{2f81e}', "\u{5203}"), ('\u{2f81f}', "\u{34df}"),
+    ('\u{2f820}', "\u{523b}"), ('\u{2f821}', "\u{5246}"), ('\u{2f822}', "\u{5272}"),
+    ('\u{2f823}', "\u{5277}"), ('\u{2f824}', "\u{3515}"), ('\u{2f825}', "\u{52c7}"),
+    ('\u{2f826}', "\u{52c9}"), ('\u{2f827}', "\u{52e4}"), ('\u{2f828}', "\u{52fa}"),
+    ('\u{2f829}', "\u{5305}"), ('\u{2f82a}', "\u{5306}"), ('\u{2f82b}', "\u{5317}"),
+    ('\u{2f82c}', "\u{5349}"), ('\u{2f82d}', "\u{5351}"), ('\u{2f82e}', "\u{535a}"),
+    ('\u{2f82f}', "\u{5373}"), ('\u{2f830}', "\u{537d}"), ('\u{2f831}', "\u{537f}"),
+    ('\u{2f832}', "\u{537f}"), ('\u{2f833}', "\u{537f}"), ('\u{2f834}', "\u{20a2c}"),
+    ('\u{2f835}', "\u{7070}"), ('\u{2f836}', "\u{53ca}"), ('\u{2f837}', "\u{53df}"),
+    ('\u{2f838}', "\u{20b63}"), ('\u{2f839}', "\u{53eb}"), ('\u{2f83a}', "\u{53f1}"),
+    ('\u{2f83b}', "\u{5406}"), ('\u{2f83c}', "\u{549e}"), ('\u{2f83d}', "\u{5438}"),
+    ('\u{2f83e}', "\u{5448}"), ('\u{2f83f}', "\u{5468}"), ('\u{2f840}', "\u{54a2}"),
+    ('\u{2f841}', "\u{54f6}"), ('\u{2f842}', "\u{5510}"), ('\u{2f843}', "\u{5553}"),
+    ('\u{2f844}', "\u{5563}"), ('\u{2f845}', "\u{5584}"), ('\u{2f846}', "\u{5584}"),
+    ('\u{2f847}', "\u{5599}"), ('\u{2f848}', "\u{55ab}"), ('\u{2f849}', "\u{55b3}"),
+    ('\u{2f84a}', "\u{55c2}"), ('\u{2f84b}', "\u{5716}"), ('\u{2f84c}', "\u{5606}"),
+    ('\u{2f84d}', "\u{5717}"), ('\u{2f84e}', "\u{5651}"), ('\u{2f84f}', "\u{5674}"),
+    ('\u{2f850}', "\u{5207}"), ('\u{2f851}', "\u{58ee}"), ('\u{2f852}', "\u{57ce}"),
+    ('\u{2f853}', "\u{57f4}"), ('\u{2f854}', "\u{580d}"), ('\u{2f855}', "\u{578b}"),
+    ('\u{2f856}', "\u{5832}"), ('\u{2f857}', "\u{5831}"), ('\u{2f858}', "\u{58ac}"),
+    ('\u{2f859}', "\u{214e4}"), ('\u{2f85a}', "\u{58f2}"), ('\u{2f85b}', "\u{58f7}"),
+    ('\u{2f85c}', "\u{5906}"), ('\u{2f85d}', "\u{591a}"), ('\u{2f85e}', "\u{5922}"),
+    ('\u{2f85f}', "\u{5962}"), ('\u{2f860}', "\u{216a8}"), ('\u{2f861}', "\u{216ea}"),
+    ('\u{2f862}', "\u{59ec}"), ('\u{2f863}', "\u{5a1b}"), ('\u{2f864}', "\u{5a27}"),
+    ('\u{2f865}', "\u{59d8}"), ('\u{2f866}', "\u{5a66}"), ('\u{2f867}', "\u{36ee}"),
+    ('\u{2f868}', "\u{36fc}"), ('\u{2f869}', "\u{5b08}"), ('\u{2f86a}', "\u{5b3e}"),
+    ('\u{2f86b}', "\u{5b3e}"), ('\u{2f86c}', "\u{219c8}"), ('\u{2f86d}', "\u{5bc3}"),
+    ('\u{2f86e}', "\u{5bd8}"), ('\u{2f86f}', "\u{5be7}"), ('\u{2f870}', "\u{5bf3}"),
+    ('\u{2f871}', "\u{21b18}"), ('\u{2f872}', "\u{5bff}"), ('\u{2f873}', "\u{5c06}"),
+    ('\u{2f874}', "\u{5f53}"), ('\u{2f875}', "\u{5c22}"), ('\u{2f876}', "\u{3781}"),
+    ('\u{2f877}', "\u{5c60}"), ('\u{2f878}', "\u{5c6e}"), ('\u{2f879}', "\u{5cc0}"),
+    ('\u{2f87a}', "\u{5c8d}"), ('\u{2f87b}', "\u{21de4}"), ('\u{2f87c}', "\u{5d43}"),
+    ('\u{2f87d}', "\u{21de6}"), ('\u{2f87e}', "\u{5d6e}"), ('\u{2f87f}', "\u{5d6b}"),
+    ('\u{2f880}', "\u{5d7c}"), ('\u{2f881}', "\u{5de1}"), ('\u{2f882}', "\u{5de2}"),
+    ('\u{2f883}', "\u{382f}"), ('\u{2f884}', "\u{5dfd}"), ('\u{2f885}', "\u{5e28}"),
+    ('\u{2f886}', "\u{5e3d}"), ('\u{2f887}', "\u{5e69}"), ('\u{2f888}', "\u{3862}"),
+    ('\u{2f889}', "\u{22183}"), ('\u{2f88a}', "\u{387c}"), ('\u{2f88b}', "\u{5eb0}"),
+    ('\u{2f88c}', "\u{5eb3}"), ('\u{2f88d}', "\u{5eb6}"), ('\u{2f88e}', "\u{5eca}"),
+    ('\u{2f88f}', "\u{2a392}"), ('\u{2f890}', "\u{5efe}"), ('\u{2f891}', "\u{22331}"),
+    ('\u{2f892}', "\u{22331}"), ('\u{2f893}', "\u{8201}"), ('\u{2f894}', "\u{5f22}"),
+    ('\u{2f895}', "\u{5f22}"), ('\u{2f896}', "\u{38c7}"), ('\u{2f897}', "\u{232b8}"),
+    ('\u{2f898}', "\u{261da}"), ('\u{2f899}', "\u{5f62}"), ('\u{2f89a}', "\u{5f6b}"),
+    ('\u{2f89b}', "\u{38e3}"), ('\u{2f89c}', "\u{5f9a}"), ('\u{2f89d}', "\u{5fcd}"),
+    ('\u{2f89e}', "\u{5fd7}"), ('\u{2f89f}', "\u{5ff9}"), ('\u{2f8a0}', "\u{6081}"),
+    ('\u{2f8a1}', "\u{393a}"), ('\u{2f8a2}', "\u{391c}"), ('\u{2f8a3}', "\u{6094}"),
+    ('\u{2f8a4}', "\u{226d4}"), ('\u{2f8a5}', "\u{60c7}"), ('\u{2f8a6}', "\u{6148}"),
+    ('\u{2f8a7}', "\u{614c}"), ('\u{2f8a8}', "\u{614e}"), ('\u{2f8a9}', "\u{614c}"),
+    ('\u{2f8aa}', "\u{617a}"), ('\u{2f8ab}', "\u{618e}"), ('\u{2f8ac}', "\u{61b2}"),
+    ('\u{2f8ad}', "\u{61a4}"), ('\u{2f8ae}', "\u{61af}"), ('\u{2f8af}', "\u{61de}"),
+    ('\u{2f8b0}', "\u{61f2}"), ('\u{2f8b1}', "\u{61f6}"), ('\u{2f8b2}', "\u{6210}"),
+    ('\u{2f8b3}', "\u{621b}"), ('\u{2f8b4}', "\u{625d}"), ('\u{2f8b5}', "\u{62b1}"),
+    ('\u{2f8b6}', "\u{62d4}"), ('\u{2f8b7}', "\u{6350}"), ('\u{2f8b8}', "\u{22b0c}"),
+    ('\u{2f8b9}', "\u{633d}"), ('\u{2f8ba}', "\u{62fc}"), ('\u{2f8bb}', "\u{6368}"),
+    ('\u{2f8bc}', "\u{6383}"), ('\u{2f8bd}', "\u{63e4}"), ('\u{2f8be}', "\u{22bf1}"),
+    ('\u{2f8bf}', "\u{6422}"), ('\u{2f8c0}', "\u{63c5}"), ('\u{2f8c1}', "\u{63a9}"),
+    ('\u{2f8c2}', "\u{3a2e}"), ('\u{2f8c3}', "\u{6469}"), ('\u{2f8c4}', "\u{647e}"),
+    ('\u{2f8c5}', "\u{649d}"), ('\u{2f8c6}', "\u{6477}"), ('\u{2f8c7}', "\u{3a6c}"),
+    ('\u{2f8c8}', "\u{654f}"), ('\u{2f8c9}', "\u{656c}"), ('\u{2f8ca}', "\u{2300a}"),
+    ('\u{2f8cb}', "\u{65e3}"), ('\u{2f8cc}', "\u{66f8}"), ('\u{2f8cd}', "\u{6649}"),
+    ('\u{2f8ce}', "\u{3b19}"), ('\u{2f8cf}', "\u{6691}"), ('\u{2f8d0}', "\u{3b08}"),
+    ('\u{2f8d1}', "\u{3ae4}"), ('\u{2f8d2}', "\u{5192}"), ('\u{2f8d3}', "\u{5195}"),
+    ('\u{2f8d4}', "\u{6700}"), ('\u{2f8d5}', "\u{669c}"), ('\u{2f8d6}', "\u{80ad}"),
+    ('\u{2f8d7}', "\u{43d9}"), ('\u{2f8d8}', "\u{6717}"), ('\u{2f8d9}', "\u{671b}"),
+    ('\u{2f8da}', "\u{6721}"), ('\u{2f8db}', "\u{675e}"), ('\u{2f8dc}', "\u{6753}"),
+    ('\u{2f8dd}', "\u{233c3}"), ('\u{2f8de}', "\u{3b49}"), ('\u{2f8df}', "\u{67fa}"),
+    ('\u{2f8e0}', "\u{6785}"), ('\u{2f8e1}', "\u{6852}"), ('\u{2f8e2}', "\u{6885}"),
+    ('\u{2f8e3}', "\u{2346d}"), ('\u{2f8e4}', "\u{688e}"), ('\u{2f8e5}', "\u{681f}"),
+    ('\u{2f8e6}', "\u{6914}"), ('\u{2f8e7}', "\u{3b9d}"), ('\u{2f8e8}', "\u{6942}"),
+    ('\u{2f8e9}', "\u{69a3}"), ('\u{2f8ea}', "\u{69ea}"), ('\u{2f8eb}', "\u{6aa8}"),
+    ('\u{2f8ec}', "\u{236a3}"), ('\u{2f8ed}', "\u{6adb}"), ('\u{2f8ee}', "\u{3c18}"),
+    ('\u{2f8ef}', "\u{6b21}"), ('\u{2f8f0}', "\u{238a7}"), ('\u{2f8f1}', "\u{6b54}"),
+    ('\u{2f8f2}', "\u{3c4e}"), ('\u{2f8f3}', "\u{6b72}"), ('\u{2f8f4}', "\u{6b9f}"),
+    ('\u{2f8f5}', "\u{6bba}"), ('\u{2f8f6}', "\u{6bbb}"), ('\u{2f8f7}', "\u{23a8d}"),
+    ('\u{2f8f8}', "\u{21d0b}"), ('\u{2f8f9}', "\u{23afa}"), ('\u{2f8fa}', "\u{6c4e}"),
+    ('\u{2f8fb}', "\u{23cbc}"), ('\u{2f8fc}', "\u{6cbf}"), ('\u{2f8fd}', "\u{6ccd}"),
+    ('\u{2f8fe}', "\u{6c67}"), ('\u{2f8ff}', "\u{6d16}"), ('\u{2f900}', "\u{6d3e}"),
+    ('\u{2f901}', "\u{6d77}"), ('\u{2f902}', "\u{6d41}"), ('\u{2f903}', "\u{6d69}"),
+    ('\u{2f904}', "\u{6d78}"), ('\u{2f905}', "\u{6d85}"), ('\u{2f906}', "\u{23d1e}"),
+    ('\u{2f907}', "\u{6d34}"), ('\u{2f908}', "\u{6e2f}"), ('\u{2f909}', "\u{6e6e}"),
+    ('\u{2f90a}', "\u{3d33}"), ('\u{2f90b}', "\u{6ecb}"), ('\u{2f90c}', "\u{6ec7}"),
+    ('\u{2f90d}', "\u{23ed1}"), ('\u{2f90e}', "\u{6df9}"), ('\u{2f90f}', "\u{6f6e}"),
+    ('\u{2f910}', "\u{23f5e}"), ('\u{2f911}', "\u{23f8e}"), ('\u{2f912}', "\u{6fc6}"),
+    ('\u{2f913}', "\u{7039}"), ('\u{2f914}', "\u{701e}"), ('\u{2f915}', "\u{701b}"),
+    ('\u{2f916}', "\u{3d96}"), ('\u{2f917}', "\u{704a}"), ('\u{2f918}', "\u{707d}"),
+    ('\u{2f919}', "\u{7077}"), ('\u{2f91a}', "\u{70ad}"), ('\u{2f91b}', "\u{20525}"),
+    ('\u{2f91c}', "\u{7145}"), ('\u{2f91d}', "\u{24263}"), ('\u{2f91e}', "\u{719c}"),
+    ('\u{2f91f}', "\u{243ab}"), ('\u{2f920}', "\u{7228}"), ('\u{2f921}', "\u{7235}"),
+    ('\u{2f922}', "\u{7250}"), ('\u{2f923}', "\u{24608}"), ('\u{2f924}', "\u{7280}"),
+    ('\u{2f925}', "\u{7295}"), ('\u{2f926}', "\u{24735}"), ('\u{2f927}', "\u{24814}"),
+    ('\u{2f928}', "\u{737a}"), ('\u{2f929}', "\u{738b}"), ('\u{2f92a}', "\u{3eac}"),
+    ('\u{2f92b}', "\u{73a5}"), ('\u{2f92c}', "\u{3eb8}"), ('\u{2f92d}', "\u{3eb8}"),
+    ('\u{2f92e}', "\u{7447}"), ('\u{2f92f}', "\u{745c}"), ('\u{2f930}', "\u{7471}"),
+    ('\u{2f931}', "\u{7485}"), ('\u{2f932}', "\u{74ca}"), ('\u{2f933}', "\u{3f1b}"),
+    ('\u{2f934}', "\u{7524}"), ('\u{2f935}', "\u{24c36}"), ('\u{2f936}', "\u{753e}"),
+    ('\u{2f937}', "\u{24c92}"), ('\u{2f938}', "\u{7570}"), ('\u{2f939}', "\u{2219f}"),
+    ('\u{2f93a}', "\u{7610}"), ('\u{2f93b}', "\u{24fa1}"), ('\u{2f93c}', "\u{24fb8}"),
+    ('\u{2f93d}', "\u{25044}"), ('\u{2f93e}', "\u{3ffc}"), ('\u{2f93f}', "\u{4008}"),
+    ('\u{2f940}', "\u{76f4}"), ('\u{2f941}', "\u{250f3}"), ('\u{2f942}', "\u{250f2}"),
+    ('\u{2f943}', "\u{25119}"), ('\u{2f944}', "\u{25133}"), ('\u{2f945}', "\u{771e}"),
+    ('\u{2f946}', "\u{771f}"), ('\u{2f947}', "\u{771f}"), ('\u{2f948}', "\u{774a}"),
+    ('\u{2f949}', "\u{4039}"), ('\u{2f94a}', "\u{778b}"), ('\u{2f94b}', "\u{4046}"),
+    ('\u{2f94c}', "\u{4096}"), ('\u{2f94d}', "\u{2541d}"), ('\u{2f94e}', "\u{784e}"),
+    ('\u{2f94f}', "\u{788c}"), ('\u{2f950}', "\u{78cc}"), ('\u{2f951}', "\u{40e3}"),
+    ('\u{2f952}', "\u{25626}"), ('\u{2f953}', "\u{7956}"), ('\u{2f954}', "\u{2569a}"),
+    ('\u{2f955}', "\u{256c5}"), ('\u{2f956}', "\u{798f}"), ('\u{2f957}', "\u{79eb}"),
+    ('\u{2f958}', "\u{412f}"), ('\u{2f959}', "\u{7a40}"), ('\u{2f95a}', "\u{7a4a}"),
+    ('\u{2f95b}', "\u{7a4f}"), ('\u{2f95c}', "\u{2597c}"), ('\u{2f95d}', "\u{25aa7}"),
+    ('\u{2f95e}', "\u{25aa7}"), ('\u{2f95f}', "\u{7aee}"), ('\u{2f960}', "\u{4202}"),
+    ('\u{2f961}', "\u{25bab}"), ('\u{2f962}', "\u{7bc6}"), ('\u{2f963}', "\u{7bc9}"),
+    ('\u{2f964}', "\u{4227}"), ('\u{2f965}', "\u{25c80}"), ('\u{2f966}', "\u{7cd2}"),
+    ('\u{2f967}', "\u{42a0}"), ('\u{2f968}', "\u{7ce8}"), ('\u{2f969}', "\u{7ce3}"),
+    ('\u{2f96a}', "\u{7d00}"), ('\u{2f96b}', "\u{25f86}"), ('\u{2f96c}', "\u{7d63}"),
+    ('\u{2f96d}', "\u{4301}"), ('\u{2f96e}', "\u{7dc7}"), ('\u{2f96f}', "\u{7e02}"),
+    ('\u{2f970}', "\u{7e45}"), ('\u{2f971}', "\u{4334}"), ('\u{2f972}', "\u{26228}"),
+    ('\u{2f973}', "\u{26247}"), ('\u{2f974}', "\u{4359}"), ('\u{2f975}', "\u{262d9}"),
+    ('\u{2f976}', "\u{7f7a}"), ('\u{2f977}', "\u{2633e}"), ('\u{2f978}', "\u{7f95}"),
+    ('\u{2f979}', "\u{7ffa}"), ('\u{2f97a}', "\u{8005}"), ('\u{2f97b}', "\u{264da}"),
+    ('\u{2f97c}', "\u{26523}"), ('\u{2f97d}', "\u{8060}"), ('\u{2f97e}', "\u{265a8}"),
+    ('\u{2f97f}', "\u{8070}"), ('\u{2f980}', "\u{2335f}"), ('\u{2f981}', "\u{43d5}"),
+    ('\u{2f982}', "\u{80b2}"), ('\u{2f983}', "\u{8103}"), ('\u{2f984}', "\u{440b}"),
+    ('\u{2f985}', "\u{813e}"), ('\u{2f986}', "\u{5ab5}"), ('\u{2f987}', "\u{267a7}"),
+    ('\u{2f988}', "\u{267b5}"), ('\u{2f989}', "\u{23393}"), ('\u{2f98a}', "\u{2339c}"),
+    ('\u{2f98b}', "\u{8201}"), ('\u{2f98c}', "\u{8204}"), ('\u{2f98d}', "\u{8f9e}"),
+    ('\u{2f98e}', "\u{446b}"), ('\u{2f98f}', "\u{8291}"), ('\u{2f990}', "\u{828b}"),
+    ('\u{2f991}', "\u{829d}"), ('\u{2f992}', "\u{52b3}"), ('\u{2f993}', "\u{82b1}"),
+    ('\u{2f994}', "\u{82b3}"), ('\u{2f995}', "\u{82bd}"), ('\u{2f996}', "\u{82e6}"),
+    ('\u{2f997}', "\u{26b3c}"), ('\u{2f998}', "\u{82e5}"), ('\u{2f999}', "\u{831d}"),
+    ('\u{2f99a}', "\u{8363}"), ('\u{2f99b}', "\u{83ad}"), ('\u{2f99c}', "\u{8323}"),
+    ('\u{2f99d}', "\u{83bd}"), ('\u{2f99e}', "\u{83e7}"), ('\u{2f99f}', "\u{8457}"),
+    ('\u{2f9a0}', "\u{8353}"), ('\u{2f9a1}', "\u{83ca}"), ('\u{2f9a2}', "\u{83cc}"),
+    ('\u{2f9a3}', "\u{83dc}"), ('\u{2f9a4}', "\u{26c36}"), ('\u{2f9a5}', "\u{26d6b}"),
+    ('\u{2f9a6}', "\u{26cd5}"), ('\u{2f9a7}', "\u{452b}"), ('\u{2f9a8}', "\u{84f1}"),
+    ('\u{2f9a9}', "\u{84f3}"), ('\u{2f9aa}', "\u{8516}"), ('\u{2f9ab}', "\u{273ca}"),
+    ('\u{2f9ac}', "\u{8564}"), ('\u{2f9ad}', "\u{26f2c}"), ('\u{2f9ae}', "\u{455d}"),
+    ('\u{2f9af}', "\u{4561}"), ('\u{2f9b0}', "\u{26fb1}"), ('\u{2f9b1}', "\u{270d2}"),
+    ('\u{2f9b2}', "\u{456b}"), ('\u{2f9b3}', "\u{8650}"), ('\u{2f9b4}', "\u{865c}"),
+    ('\u{2f9b5}', "\u{8667}"), ('\u{2f9b6}', "\u{8669}"), ('\u{2f9b7}', "\u{86a9}"),
+    ('\u{2f9b8}', "\u{8688}"), ('\u{2f9b9}', "\u{870e}"), ('\u{2f9ba}', "\u{86e2}"),
+    ('\u{2f9bb}', "\u{8779}"), ('\u{2f9bc}', "\u{8728}"), ('\u{2f9bd}', "\u{876b}"),
+    ('\u{2f9be}', "\u{8786}"), ('\u{2f9bf}', "\u{45d7}"), ('\u{2f9c0}', "\u{87e1}"),
+    ('\u{2f9c1}', "\u{8801}"), ('\u{2f9c2}', "\u{45f9}"), ('\u{2f9c3}', "\u{8860}"),
+    ('\u{2f9c4}', "\u{8863}"), ('\u{2f9c5}', "\u{27667}"), ('\u{2f9c6}', "\u{88d7}"),
+    ('\u{2f9c7}', "\u{88de}"), ('\u{2f9c8}', "\u{4635}"), ('\u{2f9c9}', "\u{88fa}"),
+    ('\u{2f9ca}', "\u{34bb}"), ('\u{2f9cb}', "\u{278ae}"), ('\u{2f9cc}', "\u{27966}"),
+    ('\u{2f9cd}', "\u{46be}"), ('\u{2f9ce}', "\u{46c7}"), ('\u{2f9cf}', "\u{8aa0}"),
+    ('\u{2f9d0}', "\u{8aed}"), ('\u{2f9d1}', "\u{8b8a}"), ('\u{2f9d2}', "\u{8c55}"),
+    ('\u{2f9d3}', "\u{27ca8}"), ('\u{2f9d4}', "\u{8cab}"), ('\u{2f9d5}', "\u{8cc1}"),
+    ('\u{2f9d6}', "\u{8d1b}"), ('\u{2f9d7}', "\u{8d77}"), ('\u{2f9d8}', "\u{27f2f}"),
+    ('\u{2f9d9}', "\u{20804}"), ('\u{2f9da}', "\u{8dcb}"), ('\u{2f9db}', "\u{8dbc}"),
+    ('\u{2f9dc}', "\u{8df0}"), ('\u{2f9dd}', "\u{208de}"), ('\u{2f9de}', "\u{8ed4}"),
+    ('\u{2f9df}', "\u{8f38}"), ('\u{2f9e0}', "\u{285d2}"), ('\u{2f9e1}', "\u{285ed}"),
+    ('\u{2f9e2}', "\u{9094}"), ('\u{2f9e3}', "\u{90f1}"), ('\u{2f9e4}', "\u{9111}"),
+    ('\u{2f9e5}', "\u{2872e}"), ('\u{2f9e6}', "\u{911b}"), ('\u{2f9e7}', "\u{9238}"),
+    ('\u{2f9e8}', "\u{92d7}"), ('\u{2f9e9}', "\u{92d8}"), ('\u{2f9ea}', "\u{927c}"),
+    ('\u{2f9eb}', "\u{93f9}"), ('\u{2f9ec}', "\u{9415}"), ('\u{2f9ed}', "\u{28bfa}"),
+    ('\u{2f9ee}', "\u{958b}"), ('\u{2f9ef}', "\u{4995}"), ('\u{2f9f0}', "\u{95b7}"),
+    ('\u{2f9f1}', "\u{28d77}"), ('\u{2f9f2}', "\u{49e6}"), ('\u{2f9f3}', "\u{96c3}"),
+    ('\u{2f9f4}', "\u{5db2}"), ('\u{2f9f5}', "\u{9723}"), ('\u{2f9f6}', "\u{29145}"),
+    ('\u{2f9f7}', "\u{2921a}"), ('\u{2f9f8}', "\u{4a6e}"), ('\u{2f9f9}', "\u{4a76}"),
+    ('\u{2f9fa}', "\u{97e0}"), ('\u{2f9fb}', "\u{2940a}"), ('\u{2f9fc}', "\u{4ab2}"),
+    ('\u{2f9fd}', "\u{29496}"), ('\u{2f9fe}', "\u{980b}"), ('\u{2f9ff}', "\u{980b}"),
+    ('\u{2fa00}', "\u{9829}"), ('\u{2fa01}', "\u{295b6}"), ('\u{2fa02}', "\u{98e2}"),
+    ('\u{2fa03}', "\u{4b33}"), ('\u{2fa04}', "\u{9929}"), ('\u{2fa05}', "\u{99a7}"),
+    ('\u{2fa06}', "\u{99c2}"), ('\u{2fa07}', "\u{99fe}"), ('\u{2fa08}', "\u{4bce}"),
+    ('\u{2fa09}', "\u{29b30}"), ('\u{2fa0a}', "\u{9b12}"), ('\u{2fa0b}', "\u{9c40}"),
+    ('\u{2fa0c}', "\u{9cfd}"), ('\u{2fa0d}', "\u{4cce}"), ('\u{2fa0e}', "\u{4ced}"),
+    ('\u{2fa0f}', "\u{9d67}"), ('\u{2fa10}', "\u{2a0ce}"), ('\u{2fa11}', "\u{4cf8}"),
+    ('\u{2fa12}', "\u{2a105}"), ('\u{2fa13}', "\u{2a20e}"), ('\u{2fa14}', "\u{2a291}"),
+    ('\u{2fa15}', "\u{9ebb}"), ('\u{2fa16}', "\u{4d56}"), ('\u{2fa17}', "\u{9ef9}"),
+    ('\u{2fa18}', "\u{9efe}"), ('\u{2fa19}', "\u{9f05}"), ('\u{2fa1a}', "\u{9f0f}"),
+    ('\u{2fa1b}', "\u{9f16}"), ('\u{2fa1c}', "\u{9f3b}"), ('\u{2fa1d}', "\u{2a600}"),
+];
+
+pub const COMPATIBILITY_DECOMPOSITION: &[(char, &str)] = &[
+    ('\u{a0}', "\u{20}"), ('\u{a8}', "\u{20}\u{308}"), ('\u{aa}', "\u{61}"),
+    ('\u{af}', "\u{20}\u{304}"), ('\u{b2}', "\u{32}"), ('\u{b3}', "\u{33}"),
+    ('\u{b4}', "\u{20}\u{301}"), ('\u{b5}', "\u{3bc}"), ('\u{b8}', "\u{20}\u{327}"),
+    ('\u{b9}', "\u{31}"), ('\u{ba}', "\u{6f}"), ('\u{bc}', "\u{31}\u{2044}\u{34}"),
+    ('\u{bd}', "\u{31}\u{2044}\u{32}"), ('\u{be}', "\u{33}\u{2044}\u{34}"), ('\u{132}', "\u{49}\u{4a}"),
+    ('\u{133}', "\u{69}\u{6a}"), ('\u{13f}', "\u{4c}\u{b7}"), ('\u{140}', "\u{6c}\u{b7}"),
+    ('\u{149}', "\u{2bc}\u{6e}"), ('\u{17f}', "\u{73}"), ('\u{1c4}', "\u{44}\u{5a}\u{30c}"),
+    ('\u{1c5}', "\u{44}\u{7a}\u{30c}"), ('\u{1c6}', "\u{64}\u{7a}\u{30c}"), ('\u{1c7}', "\u{4c}\u{4a}"),
+    ('\u{1c8}', "\u{4c}\u{6a}"), ('\u{1c9}', "\u{6c}\u{6a}"), ('\u{1ca}', "\u{4e}\u{4a}"),
+    ('\u{1cb}', "\u{4e}\u{6a}"), ('\u{1cc}', "\u{6e}\u{6a}"), ('\u{1f1}', "\u{44}\u{5a}"),
+    ('\u{1f2}', "\u{44}\u{7a}"), ('\u{1f3}', "\u{64}\u{7a}"), ('\u{2b0}', "\u{68}"),
+    ('\u{2b1}', "\u{266}"), ('\u{2b2}', "\u{6a}"), ('\u{2b3}', "\u{72}"),
+    ('\u{2b4}', "\u{279}"), ('\u{2b5}', "\u{27b}"), ('\u{2b6}', "\u{281}"),
+    ('\u{2b7}', "\u{77}"), ('\u{2b8}', "\u{79}"), ('\u{2d8}', "\u{20}\u{306}"),
+    ('\u{2d9}', "\u{20}\u{307}"), ('\u{2da}', "\u{20}\u{30a}"), ('\u{2db}', "\u{20}\u{328}"),
+    ('\u{2dc}', "\u{20}\u{303}"), ('\u{2dd}', "\u{20}\u{30b}"), ('\u{2e0}', "\u{263}"),
+    ('\u{2e1}', "\u{6c}"), ('\u{2e2}', "\u{73}"), ('\u{2e3}', "\u{78}"),
+    ('\u{2e4}', "\u{295}"), ('\u{37a}', "\u{20}\u{345}"), ('\u{384}', "\u{20}\u{301}"),
+    ('\u{385}', "\u{20}\u{308}\u{301}"), ('\u{3d0}', "\u{3b2}"), ('\u{3d1}', "\u{3b8}"),
+    ('\u{3d2}', "\u{3a5}"), ('\u{3d3}', "\u{3a5}\u{301}"), ('\u{3d4}', "\u{3a5}\u{308}"),
+    ('\u{3d5}', "\u{3c6}"), ('\u{3d6}', "\u{3c0}"), ('\u{3f0}', "\u{3ba}"),
+    ('\u{3f1}', "\u{3c1}"), ('\u{3f2}', "\u{3c2}"), ('\u{3f4}', "\u{398}"),
+    ('\u{3f5}', "\u{3b5}"), ('\u{3f9}', "\u{3a3}"), ('\u{587}', "\u{565}\u{582}"),
+    ('\u{675}', "\u{627}\u{674}"), ('\u{676}', "\u{648}\u{674}"), ('\u{677}', "\u{6c7}\u{674}"),
+    ('\u{678}', "\u{64a}\u{674}"), ('\u{e33}', "\u{e4d}\u{e32}"), ('\u{eb3}', "\u{ecd}\u{eb2}"),
+    ('\u{edc}', "\u{eab}\u{e99}"), ('\u{edd}', "\u{eab}\u{ea1}"), ('\u{f0c}', "\u{f0b}"),
+    ('\u{f77}', "\u{fb2}\u{f71}\u{f80}"), ('\u{f79}', "\u{fb3}\u{f71}\u{f80}"), ('\u{10fc}', "\u{10dc}"),
+    ('\u{1d2c}', "\u{41}"), ('\u{1d2d}', "\u{c6}"), ('\u{1d2e}', "\u{42}"),
+    ('\u{1d30}', "\u{44}"), ('\u{1d31}', "\u{45}"), ('\u{1d32}', "\u{18e}"),
+    ('\u{1d33}', "\u{47}"), ('\u{1d34}', "\u{48}"), ('\u{1d35}', "\u{49}"),
+    ('\u{1d36}', "\u{4a}"), ('\u{1d37}', "\u{4b}"), ('\u{1d38}', "\u{4c}"),
+    ('\u{1d39}', "\u{4d}"), ('\u{1d3a}', "\u{4e}"), ('\u{1d3c}', "\u{4f}"),
+    ('\u{1d3d}', "\u{222}"), ('\u{1d3e}', "\u{50}"), ('\u{1d3f}', "\u{52}"),
+    ('\u{1d40}', "\u{54}"), ('\u{1d41}', "\u{55}"), ('\u{1d42}', "\u{57}"),
+    ('\u{1d43}', "\u{61}"), ('\u{1d44}', "\u{250}"), ('\u{1d45}', "\u{251}"),
+    ('\u{1d46}', "\u{1d02}"), ('\u{1d47}', "\u{62}"), ('\u{1d48}', "\u{64}"),
+    ('\u{1d49}', "\u{65}"), ('\u{1d4a}', "\u{259}"), ('\u{1d4b}', "\u{25b}"),
+    ('\u{1d4c}', "\u{25c}"), ('\u{1d4d}', "\u{67}"), ('\u{1d4f}', "\u{6b}"),
+    ('\u{1d50}', "\u{6d}"), ('\u{1d51}', "\u{14b}"), ('\u{1d52}', "\u{6f}"),
+    ('\u{1d53}', "\u{254}"), ('\u{1d54}', "\u{1d16}"), ('\u{1d55}', "\u{1d17}"),
+    ('\u{1d56}', "\u{70}"), ('\u{1d57}', "\u{74}"), ('\u{1d58}', "\u{75}"),
+    ('\u{1d59}', "\u{1d1d}"), ('\u{1d5a}', "\u{26f}"), ('\u{1d5b}', "\u{76}"),
+    ('\u{1d5c}', "\u{1d25}"), ('\u{1d5d}', "\u{3b2}"), ('\u{1d5e}', "\u{3b3}"),
+    ('\u{1d5f}', "\u{3b4}"), ('\u{1d60}', "\u{3c6}"), ('\u{1d61}', "\u{3c7}"),
+    ('\u{1d62}', "\u{69}"), ('\u{1d63}', "\u{72}"), ('\u{1d64}', "\u{75}"),
+    ('\u{1d65}', "\u{76}"), ('\u{1d66}', "\u{3b2}"), ('\u{1d67}', "\u{3b3}"),
+    ('\u{1d68}', "\u{3c1}"), ('\u{1d69}', "\u{3c6}"), ('\u{1d6a}', "\u{3c7}"),
+    ('\u{1d78}', "\u{43d}"), ('\u{1d9b}', "\u{252}"), ('\u{1d9c}', "\u{63}"),
+    ('\u{1d9d}', "\u{255}"), ('\u{1d9e}', "\u{f0}"), ('\u{1d9f}', "\u{25c}"),
+    ('\u{1da0}', "\u{66}"), ('\u{1da1}', "\u{25f}"), ('\u{1da2}', "\u{261}"),
+    ('\u{1da3}', "\u{265}"), ('\u{1da4}', "\u{268}"), ('\u{1da5}', "\u{269}"),
+    ('\u{1da6}', "\u{26a}"), ('\u{1da7}', "\u{1d7b}"), ('\u{1da8}', "\u{29d}"),
+    ('\u{1da9}', "\u{26d}"), ('\u{1daa}', "\u{1d85}"), ('\u{1dab}', "\u{29f}"),
+    ('\u{1dac}', "\u{271}"), ('\u{1dad}', "\u{270}"), ('\u{1dae}', "\u{272}"),
+    ('\u{1daf}', "\u{273}"), ('\u{1db0}', "\u{274}"), ('\u{1db1}', "\u{275}"),
+    ('\u{1db2}', "\u{278}"), ('\u{1db3}', "\u{282}"), ('\u{1db4}', "\u{283}"),
+    ('\u{1db5}', "\u{1ab}"), ('\u{1db6}', "\u{289}"), ('\u{1db7}', "\u{28a}"),
+    ('\u{1db8}', "\u{1d1c}"), ('\u{1db9}', "\u{28b}"), ('\u{1dba}', "\u{28c}"),
+    ('\u{1dbb}', "\u{7a}"), ('\u{1dbc}', "\u{290}"), ('\u{1dbd}', "\u{291}"),
+    ('\u{1dbe}', "\u{292}"), ('\u{1dbf}', "\u{3b8}"), ('\u{1e9a}', "\u{61}\u{2be}"),
+    ('\u{1e9b}', "\u{73}\u{307}"), ('\u{1fbd}', "\u{20}\u{313}"), ('\u{1fbf}', "\u{20}\u{313}"),
+    ('\u{1fc0}', "\u{20}\u{342}"), ('\u{1fc1}', "\u{20}\u{308}\u{342}"), ('\u{1fcd}', "\u{20}\u{313}\u{300}"),
+    ('\u{1fce}', "\u{20}\u{313}\u{301}"), ('\u{1fcf}', "\u{20}\u{313}\u{342}"), ('\u{1fdd}', "\u{20}\u{314}\u{300}"),
+    ('\u{1fde}', "\u{20}\u{314}\u{301}"), ('\u{1fdf}', "\u{20}\u{314}\u{342}"), ('\u{1fed}', "\u{20}\u{308}\u{300}"),
+    ('\u{1fee}', "\u{20}\u{308}\u{301}"), ('\u{1ffd}', "\u{20}\u{301}"), ('\u{1ffe}', "\u{20}\u{314}"),
+    ('\u{2000}', "\u{20}"), ('\u{2001}', "\u{20}"), ('\u{2002}', "\u{20}"),
+    ('\u{2003}', "\u{20}"), ('\u{2004}', "\u{20}"), ('\u{2005}', "\u{20}"),
+    ('\u{2006}', "\u{20}"), ('\u{2007}', "\u{20}"), ('\u{2008}', "\u{20}"),
+    ('\u{2009}', "\u{20}"), ('\u{200a}', "\u{20}"), ('\u{2011}', "\u{2010}"),
+    ('\u{2017}', "\u{20}\u{333}"), ('\u{2024}', "\u{2e}"), ('\u{2025}', "\u{2e}\u{2e}"),
+    ('\u{2026}', "\u{2e}\u{2e}\u{2e}"), ('\u{202f}', "\u{20}"), ('\u{2033}', "\u{2032}\u{2032}"),
+    ('\u{2034}', "\u{2032}\u{2032}\u{2032}"), ('\u{2036}', "\u{2035}\u{2035}"), ('\u{2037}', "\u{2035}\u{2035}\u{2035}"),
+    ('\u{203c}', "\u{21}\u{21}"), ('\u{203e}', "\u{20}\u{305}"), ('\u{2047}', "\u{3f}\u{3f}"),
+    ('\u{2048}', "\u{3f}\u{21}"), ('\u{2049}', "\u{21}\u{3f}"), ('\u{2057}', "\u{2032}\u{2032}\u{2032}\u{2032}"),
+    ('\u{205f}', "\u{20}"), ('\u{2070}', "\u{30}"), ('\u{2071}', "\u{69}"),
+    ('\u{2074}', "\u{34}"), ('\u{2075}', "\u{35}"), ('\u{2076}', "\u{36}"),
+    ('\u{2077}', "\u{37}"), ('\u{2078}', "\u{38}"), ('\u{2079}', "\u{39}"),
+    ('\u{207a}', "\u{2b}"), ('\u{207b}', "\u{2212}"), ('\u{207c}', "\u{3d}"),
+    ('\u{207d}', "\u{28}"), ('\u{207e}', "\u{29}"), ('\u{207f}', "\u{6e}"),
+    ('\u{2080}', "\u{30}"), ('\u{2081}', "\u{31}"), ('\u{2082}', "\u{32}"),
+    ('\u{2083}', "\u{33}"), ('\u{2084}', "\u{34}"), ('\u{2085}', "\u{35}"),
+    ('\u{2086}', "\u{36}"), ('\u{2087}', "\u{37}"), ('\u{2088}', "\u{38}"),
+    ('\u{2089}', "\u{39}"), ('\u{208a}', "\u{2b}"), ('\u{208b}', "\u{2212}"),
+    ('\u{208c}', "\u{3d}"), ('\u{208d}', "\u{28}"), ('\u{208e}', "\u{29}"),
+    ('\u{2090}', "\u{61}"), ('\u{2091}', "\u{65}"), ('\u{2092}', "\u{6f}"),
+    ('\u{2093}', "\u{78}"), ('\u{2094}', "\u{259}"), ('\u{2095}', "\u{68}"),
+    ('\u{2096}', "\u{6b}"), ('\u{2097}', "\u{6c}"), ('\u{2098}', "\u{6d}"),
+    ('\u{2099}', "\u{6e}"), ('\u{209a}', "\u{70}"), ('\u{209b}', "\u{73}"),
+    ('\u{209c}', "\u{74}"), ('\u{20a8}', "\u{52}\u{73}"), ('\u{2100}', "\u{61}\u{2f}\u{63}"),
+    ('\u{2101}', "\u{61}\u{2f}\u{73}"), ('\u{2102}', "\u{43}"), ('\u{2103}', "\u{b0}\u{43}"),
+    ('\u{2105}', "\u{63}\u{2f}\u{6f}"), ('\u{2106}', "\u{63}\u{2f}\u{75}"), ('\u{2107}', "\u{190}"),
+    ('\u{2109}', "\u{b0}\u{46}"), ('\u{210a}', "\u{67}"), ('\u{210b}', "\u{48}"),
+    ('\u{210c}', "\u{48}"), ('\u{210d}', "\u{48}"), ('\u{210e}', "\u{68}"),
+    ('\u{210f}', "\u{127}"), ('\u{2110}', "\u{49}"), ('\u{2111}', "\u{49}"),
+    ('\u{2112}', "\u{4c}"), ('\u{2113}', "\u{6c}"), ('\u{2115}', "\u{4e}"),
+    ('\u{2116}', "\u{4e}\u{6f}"), ('\u{2119}', "\u{50}"), ('\u{211a}', "\u{51}"),
+    ('\u{211b}', "\u{52}"), ('\u{211c}', "\u{52}"), ('\u{211d}', "\u{52}"),
+    ('\u{2120}', "\u{53}\u{4d}"), ('\u{2121}', "\u{54}\u{45}\u{4c}"), ('\u{2122}', "\u{54}\u{4d}"),
+    ('\u{2124}', "\u{5a}"), ('\u{2128}', "\u{5a}"), ('\u{212c}', "\u{42}"),
+    ('\u{212d}', "\u{43}"), ('\u{212f}', "\u{65}"), ('\u{2130}', "\u{45}"),
+    ('\u{2131}', "\u{46}"), ('\u{2133}', "\u{4d}"), ('\u{2134}', "\u{6f}"),
+    ('\u{2135}', "\u{5d0}"), ('\u{2136}', "\u{5d1}"), ('\u{2137}', "\u{5d2}"),
+    ('\u{2138}', "\u{5d3}"), ('\u{2139}', "\u{69}"), ('\u{213b}', "\u{46}\u{41}\u{58}"),
+    ('\u{213c}', "\u{3c0}"), ('\u{213d}', "\u{3b3}"), ('\u{213e}', "\u{393}"),
+    ('\u{213f}', "\u{3a0}"), ('\u{2140}', "\u{2211}"), ('\u{2145}', "\u{44}"),
+    ('\u{2146}', "\u{64}"), ('\u{2147}', "\u{65}"), ('\u{2148}', "\u{69}"),
+    ('\u{2149}', "\u{6a}"), ('\u{2150}', "\u{31}\u{2044}\u{37}"), ('\u{2151}', "\u{31}\u{2044}\u{39}"),
+    ('\u{2152}', "\u{31}\u{2044}\u{31}\u{30}"), ('\u{2153}', "\u{31}\u{2044}\u{33}"), ('\u{2154}', "\u{32}\u{2044}\u{33}"),
+    ('\u{2155}', "\u{31}\u{2044}\u{35}"), ('\u{2156}', "\u{32}\u{2044}\u{35}"), ('\u{2157}', "\u{33}\u{2044}\u{35}"),
+    ('\u{2158}', "\u{34}\u{2044}\u{35}"), ('\u{2159}', "\u{31}\u{2044}\u{36}"), ('\u{215a}', "\u{35}\u{2044}\u{36}"),
+    ('\u{215b}', "\u{31}\u{2044}\u{38}"), ('\u{215c}', "\u{33}\u{2044}\u{38}"), ('\u{215d}', "\u{35}\u{2044}\u{38}"),
+    ('\u{215e}', "\u{37}\u{2044}\u{38}"), ('\u{215f}', "\u{31}\u{2044}"), ('\u{2160}', "\u{49}"),
+    ('\u{2161}', "\u{49}\u{49}"), ('\u{2162}', "\u{49}\u{49}\u{49}"), ('\u{2163}', "\u{49}\u{56}"),
+    ('\u{2164}', "\u{56}"), ('\u{2165}', "\u{56}\u{49}"), ('\u{2166}', "\u{56}\u{49}\u{49}"),
+    ('\u{2167}', "\u{56}\u{49}\u{49}\u{49}"), ('\u{2168}', "\u{49}\u{58}"), ('\u{2169}', "\u{58}"),
+    ('\u{216a}', "\u{58}\u{49}"), ('\u{216b}', "\u{58}\u{49}\u{49}"), ('\u{216c}', "\u{4c}"),
+    ('\u{216d}', "\u{43}"), ('\u{216e}', "\u{44}"), ('\u{216f}', "\u{4d}"),
+    ('\u{2170}', "\u{69}"), ('\u{2171}', "\u{69}\u{69}"), ('\u{2172}', "\u{69}\u{69}\u{69}"),
+    ('\u{2173}', "\u{69}\u{76}"), ('\u{2174}', "\u{76}"), ('\u{2175}', "\u{76}\u{69}"),
+    ('\u{2176}', "\u{76}\u{69}\u{69}"), ('\u{2177}', "\u{76}\u{69}\u{69}\u{69}"), ('\u{2178}', "\u{69}\u{78}"),
+    ('\u{2179}', "\u{78}"), ('\u{217a}', "\u{78}\u{69}"), ('\u{217b}', "\u{78}\u{69}\u{69}"),
+    ('\u{217c}', "\u{6c}"), ('\u{217d}', "\u{63}"), ('\u{217e}', "\u{64}"),
+    ('\u{217f}', "\u{6d}"), ('\u{2189}', "\u{30}\u{2044}\u{33}"), ('\u{222c}', "\u{222b}\u{222b}"),
+    ('\u{222d}', "\u{222b}\u{222b}\u{222b}"), ('\u{222f}', "\u{222e}\u{222e}"), ('\u{2230}', "\u{222e}\u{222e}\u{222e}"),
+    ('\u{2460}', "\u{31}"), ('\u{2461}', "\u{32}"), ('\u{2462}', "\u{33}"),
+    ('\u{2463}', "\u{34}"), ('\u{2464}', "\u{35}"), ('\u{2465}', "\u{36}"),
+    ('\u{2466}', "\u{37}"), ('\u{2467}', "\u{38}"), ('\u{2468}', "\u{39}"),
+    ('\u{2469}', "\u{31}\u{30}"), ('\u{246a}', "\u{31}\u{31}"), ('\u{246b}', "\u{31}\u{32}"),
+    ('\u{246c}', "\u{31}\u{33}"), ('\u{246d}', "\u{31}\u{34}"), ('\u{246e}', "\u{31}\u{35}"),
+    ('\u{246f}', "\u{31}\u{36}"), ('\u{2470}', "\u{31}\u{37}"), ('\u{2471}', "\u{31}\u{38}"),
+    ('\u{2472}', "\u{31}\u{39}"), ('\u{2473}', "\u{32}\u{30}"), ('\u{2474}', "\u{28}\u{31}\u{29}"),
+    ('\u{2475}', "\u{28}\u{32}\u{29}"), ('\u{2476}', "\u{28}\u{33}\u{29}"), ('\u{2477}', "\u{28}\u{34}\u{29}"),
+    ('\u{2478}', "\u{28}\u{35}\u{29}"), ('\u{2479}', "\u{28}\u{36}\u{29}"), ('\u{247a}', "\u{28}\u{37}\u{29}"),
+    ('\u{247b}', "\u{28}\u{38}\u{29}"), ('\u{247c}', "\u{28}\u{39}\u{29}"), ('\u{247d}', "\u{28}\u{31}\u{30}\u{29}"),
+    ('\u{247e}', "\u{28}\u{31}\u{31}\u{29}"), ('\u{247f}', "\u{28}\u{31}\u{32}\u{29}"), ('\u{2480}', "\u{28}\u{31}\u{33}\u{29}"),
+    ('\u{2481}', "\u{28}\u{31}\u{34}\u{29}"), ('\u{2482}', "\u{28}\u{31}\u{35}\u{29}"), ('\u{2483}', "\u{28}\u{31}\u{36}\u{29}"),
+    ('\u{2484}', "\u{28}\u{31}\u{37}\u{29}"), ('\u{2485}', "\u{28}\u{31}\u{38}\u{29}"), ('\u{2486}', "\u{28}\u{31}\u{39}\u{29}"),
+    ('\u{2487}', "\u{28}\u{32}\u{30}\u{29}"), ('\u{2488}', "\u{31}\u{2e}"), ('\u{2489}', "\u{32}\u{2e}"),
+    ('\u{248a}', "\u{33}\u{2e}"), ('\u{248b}', "\u{34}\u{2e}"), ('\u{248c}', "\u{35}\u{2e}"),
+    ('\u{248d}', "\u{36}\u{2e}"), ('\u{248e}', "\u{37}\u{2e}"), ('\u{248f}', "\u{38}\u{2e}"),
+    ('\u{2490}', "\u{39}\u{2e}"), ('\u{2491}', "\u{31}\u{30}\u{2e}"), ('\u{2492}', "\u{31}\u{31}\u{2e}"),
+    ('\u{2493}', "\u{31}\u{32}\u{2e}"), ('\u{2494}', "\u{31}\u{33}\u{2e}"), ('\u{2495}', "\u{31}\u{34}\u{2e}"),
+    ('\u{2496}', "\u{31}\u{35}\u{2e}"), ('\u{2497}', "\u{31}\u{36}\u{2e}"), ('\u{2498}', "\u{31}\u{37}\u{2e}"),
+    ('\u{2499}', "\u{31}\u{38}\u{2e}"), ('\u{249a}', "\u{31}\u{39}\u{2e}"), ('\u{249b}', "\u{32}\u{30}\u{2e}"),
+    ('\u{249c}', "\u{28}\u{61}\u{29}"), ('\u{249d}', "\u{28}\u{62}\u{29}"), ('\u{249e}', "\u{28}\u{63}\u{29}"),
+    ('\u{249f}', "\u{28}\u{64}\u{29}"), ('\u{24a0}', "\u{28}\u{65}\u{29}"), ('\u{24a1}', "\u{28}\u{66}\u{29}"),
+    ('\u{24a2}', "\u{28}\u{67}\u{29}"), ('\u{24a3}', "\u{28}\u{68}\u{29}"), ('\u{24a4}', "\u{28}\u{69}\u{29}"),
+    ('\u{24a5}', "\u{28}\u{6a}\u{29}"), ('\u{24a6}', "\u{28}\u{6b}\u{29}"), ('\u{24a7}', "\u{28}\u{6c}\u{29}"),
+    ('\u{24a8}', "\u{28}\u{6d}\u{29}"), ('\u{24a9}', "\u{28}\u{6e}\u{29}"), ('\u{24aa}', "\u{28}\u{6f}\u{29}"),
+    ('\u{24ab}', "\u{28}\u{70}\u{29}"), ('\u{24ac}', "\u{28}\u{71}\u{29}"), ('\u{24ad}', "\u{28}\u{72}\u{29}"),
+    ('\u{24ae}', "\u{28}\u{73}\u{29}"), ('\u{24af}', "\u{28}\u{74}\u{29}"), ('\u{24b0}', "\u{28}\u{75}\u{29}"),
+    ('\u{24b1}', "\u{28}\u{76}\u{29}"), ('\u{24b2}', "\u{28}\u{77}\u{29}"), ('\u{24b3}', "\u{28}\u{78}\u{29}"),
+    ('\u{24b4}', "\u{28}\u{79}\u{29}"), ('\u{24b5}', "\u{28}\u{7a}\u{29}"), ('\u{24b6}', "\u{41}"),
+    ('\u{24b7}', "\u{42}"), ('\u{24b8}', "\u{43}"), ('\u{24b9}', "\u{44}"),
+    ('\u{24ba}', "\u{45}"), ('\u{24bb}', "\u{46}"), ('\u{24bc}', "\u{47}"),
+    ('\u{24bd}', "\u{48}"), ('\u{24be}', "\u{49}"), ('\u{24bf}', "\u{4a}"),
+    ('\u{24c0}', "\u{4b}"), ('\u{24c1}', "\u{4c}"), ('\u{24c2}', "\u{4d}"),
+    ('\u{24c3}', "\u{4e}"), ('\u{24c4}', "\u{4f}"), ('\u{24c5}', "\u{50}"),
+    ('\u{24c6}', "\u{51}"), ('\u{24c7}', "\u{52}"), ('\u{24c8}', "\u{53}"),
+    ('\u{24c9}', "\u{54}"), ('\u{24ca}', "\u{55}"), ('\u{24cb}', "\u{56}"),
+    ('\u{24cc}', "\u{57}"), ('\u{24cd}', "\u{58}"), ('\u{24ce}', "\u{59}"),
+    ('\u{24cf}', "\u{5a}"), ('\u{24d0}', "\u{61}"), ('\u{24d1}', "\u{62}"),
+    ('\u{24d2}', "\u{63}"), ('\u{24d3}', "\u{64}"), ('\u{24d4}', "\u{65}"),
+    ('\u{24d5}', "\u{66}"), ('\u{24d6}', "\u{67}"), ('\u{24d7}', "\u{68}"),
+    ('\u{24d8}', "\u{69}"), ('\u{24d9}', "\u{6a}"), ('\u{24da}', "\u{6b}"),
+    ('\u{24db}', "\u{6c}"), ('\u{24dc}', "\u{6d}"), ('\u{24dd}', "\u{6e}"),
+    ('\u{24de}', "\u{6f}"), ('\u{24df}', "\u{70}"), ('\u{24e0}', "\u{71}"),
+    ('\u{24e1}', "\u{72}"), ('\u{24e2}', "\u{73}"), ('\u{24e3}', "\u{74}"),
+    ('\u{24e4}', "\u{75}"), ('\u{24e5}', "\u{76}"), ('\u{24e6}', "\u{77}"),
+    ('\u{24e7}', "\u{78}"), ('\u{24e8}', "\u{79}"), ('\u{24e9}', "\u{7a}"),
+    ('\u{24ea}', "\u{30}"), ('\u{2a0c}', "\u{222b}\u{222b}\u{222b}\u{222b}"), ('\u{2a74}', "\u{3a}\u{3a}\u{3d}"),
+    ('\u{2a75}', "\u{3d}\u{3d}"), ('\u{2a76}', "\u{3d}\u{3d}\u{3d}"), ('\u{2c7c}', "\u{6a}"),
+    ('\u{2c7d}', "\u{56}"), ('\u{2d6f}', "\u{2d61}"), ('\u{2e9f}', "\u{6bcd}"),
+    ('\u{2ef3}', "\u{9f9f}"), ('\u{2f00}', "\u{4e00}"), ('\u{2f01}', "\u{4e28}"),
+    ('\u{2f02}', "\u{4e36}"), ('\u{2f03}', "\u{4e3f}"), ('\u{2f04}', "\u{4e59}"),
+    ('\u{2f05}', "\u{4e85}"), ('\u{2f06}', "\u{4e8c}"), ('\u{2f07}', "\u{4ea0}"),
+    ('\u{2f08}', "\u{4eba}"), ('\u{2f09}', "\u{513f}"), ('\u{2f0a}', "\u{5165}"),
+    ('\u{2f0b}', "\u{516b}"), ('\u{2f0c}', "\u{5182}"), ('\u{2f0d}', "\u{5196}"),
+    ('\u{2f0e}', "\u{51ab}"), ('\u{2f0f}', "\u{51e0}"), ('\u{2f10}', "\u{51f5}"),
+    ('\u{2f11}', "\u{5200}"), ('\u{2f12}', "\u{529b}"), ('\u{2f13}', "\u{52f9}"),
+    ('\u{2f14}', "\u{5315}"), ('\u{2f15}', "\u{531a}"), ('\u{2f16}', "\u{5338}"),
+    ('\u{2f17}', "\u{5341}"), ('\u{2f18}', "\u{535c}"), ('\u{2f19}', "\u{5369}"),
+    ('\u{2f1a}', "\u{5382}"), ('\u{2f1b}', "\u{53b6}"), ('\u{2f1c}', "\u{53c8}"),
+    ('\u{2f1d}', "\u{53e3}"), ('\u{2f1e}', "\u{56d7}"), ('\u{2f1f}', "\u{571f}"),
+    ('\u{2f20}', "\u{58eb}"), ('\u{2f21}', "\u{5902}"), ('\u{2f22}', "\u{590a}"),
+    ('\u{2f23}', "\u{5915}"), ('\u{2f24}', "\u{5927}"), ('\u{2f25}', "\u{5973}"),
+    ('\u{2f26}', "\u{5b50}"), ('\u{2f27}', "\u{5b80}"), ('\u{2f28}', "\u{5bf8}"),
+    ('\u{2f29}', "\u{5c0f}"), ('\u{2f2a}', "\u{5c22}"), ('\u{2f2b}', "\u{5c38}"),
+    ('\u{2f2c}', "\u{5c6e}"), ('\u{2f2d}', "\u{5c71}"), ('\u{2f2e}', "\u{5ddb}"),
+    ('\u{2f2f}', "\u{5de5}"), ('\u{2f30}', "\u{5df1}"), ('\u{2f31}', "\u{5dfe}"),
+    ('\u{2f32}', "\u{5e72}"), ('\u{2f33}', "\u{5e7a}"), ('\u{2f34}', "\u{5e7f}"),
+    ('\u{2f35}', "\u{5ef4}"), ('\u{2f36}', "\u{5efe}"), ('\u{2f37}', "\u{5f0b}"),
+    ('\u{2f38}', "\u{5f13}"), ('\u{2f39}', "\u{5f50}"), ('\u{2f3a}', "\u{5f61}"),
+    ('\u{2f3b}', "\u{5f73}"), ('\u{2f3c}', "\u{5fc3}"), ('\u{2f3d}', "\u{6208}"),
+    ('\u{2f3e}', "\u{6236}"), ('\u{2f3f}', "\u{624b}"), ('\u{2f40}', "\u{652f}"),
+    ('\u{2f41}', "\u{6534}"), ('\u{2f42}', "\u{6587}"), ('\u{2f43}', "\u{6597}"),
+    ('\u{2f44}', "\u{65a4}"), ('\u{2f45}', "\u{65b9}"), ('\u{2f46}', "\u{65e0}"),
+    ('\u{2f47}', "\u{65e5}"), ('\u{2f48}', "\u{66f0}"), ('\u{2f49}', "\u{6708}"),
+    ('\u{2f4a}', "\u{6728}"), ('\u{2f4b}', "\u{6b20}"), ('\u{2f4c}', "\u{6b62}"),
+    ('\u{2f4d}', "\u{6b79}"), ('\u{2f4e}', "\u{6bb3}"), ('\u{2f4f}', "\u{6bcb}"),
+    ('\u{2f50}', "\u{6bd4}"), ('\u{2f51}', "\u{6bdb}"), ('\u{2f52}', "\u{6c0f}"),
+    ('\u{2f53}', "\u{6c14}"), ('\u{2f54}', "\u{6c34}"), ('\u{2f55}', "\u{706b}"),
+    ('\u{2f56}', "\u{722a}"), ('\u{2f57}', "\u{7236}"), ('\u{2f58}', "\u{723b}"),
+    ('\u{2f59}', "\u{723f}"), ('\u{2f5a}', "\u{7247}"), ('\u{2f5b}', "\u{7259}"),
+    ('\u{2f5c}', "\u{725b}"), ('\u{2f5d}', "\u{72ac}"), ('\u{2f5e}', "\u{7384}"),
+    ('\u{2f5f}', "\u{7389}"), ('\u{2f60}', "\u{74dc}"), ('\u{2f61}', "\u{74e6}"),
+    ('\u{2f62}', "\u{7518}"), ('\u{2f63}', "\u{751f}"), ('\u{2f64}', "\u{7528}"),
+    ('\u{2f65}', "\u{7530}"), ('\u{2f66}', "\u{758b}"), ('\u{2f67}', "\u{7592}"),
+    ('\u{2f68}', "\u{7676}"), ('\u{2f69}', "\u{767d}"), ('\u{2f6a}', "\u{76ae}"),
+    ('\u{2f6b}', "\u{76bf}"), ('\u{2f6c}', "\u{76ee}"), ('\u{2f6d}', "\u{77db}"),
+    ('\u{2f6e}', "\u{77e2}"), ('\u{2f6f}', "\u{77f3}"), ('\u{2f70}', "\u{793a}"),
+    ('\u{2f71}', "\u{79b8}"), ('\u{2f72}', "\u{79be}"), ('\u{2f73}', "\u{7a74}"),
+    ('\u{2f74}', "\u{7acb}"), ('\u{2f75}', "\u{7af9}"), ('\u{2f76}', "\u{7c73}"),
+    ('\u{2f77}', "\u{7cf8}"), ('\u{2f78}', "\u{7f36}"), ('\u{2f79}', "\u{7f51}"),
+    ('\u{2f7a}', "\u{7f8a}"), ('\u{2f7b}', "\u{7fbd}"), ('\u{2f7c}', "\u{8001}"),
+    ('\u{2f7d}', "\u{800c}"), ('\u{2f7e}', "\u{8012}"), ('\u{2f7f}', "\u{8033}"),
+    ('\u{2f80}', "\u{807f}"), ('\u{2f81}', "\u{8089}"), ('\u{2f82}', "\u{81e3}"),
+    ('\u{2f83}', "\u{81ea}"), ('\u{2f84}', "\u{81f3}"), ('\u{2f85}', "\u{81fc}"),
+    ('\u{2f86}', "\u{820c}"), ('\u{2f87}', "\u{821b}"), ('\u{2f88}', "\u{821f}"),
+    ('\u{2f89}', "\u{826e}"), ('\u{2f8a}', "\u{8272}"), ('\u{2f8b}', "\u{8278}"),
+    ('\u{2f8c}', "\u{864d}"), ('\u{2f8d}', "\u{866b}"), ('\u{2f8e}', "\u{8840}"),
+    ('\u{2f8f}', "\u{884c}"), ('\u{2f90}', "\u{8863}"), ('\u{2f91}', "\u{897e}"),
+    ('\u{2f92}', "\u{898b}"), ('\u{2f93}', "\u{89d2}"), ('\u{2f94}', "\u{8a00}"),
+    ('\u{2f95}', "\u{8c37}"), ('\u{2f96}', "\u{8c46}"), ('\u{2f97}', "\u{8c55}"),
+    ('\u{2f98}', "\u{8c78}"), ('\u{2f99}', "\u{8c9d}"), ('\u{2f9a}', "\u{8d64}"),
+    ('\u{2f9b}', "\u{8d70}"), ('\u{2f9c}', "\u{8db3}"), ('\u{2f9d}', "\u{8eab}"),
+    ('\u{2f9e}', "\u{8eca}"), ('\u{2f9f}', "\u{8f9b}"), ('\u{2fa0}', "\u{8fb0}"),
+    ('\u{2fa1}', "\u{8fb5}"), ('\u{2fa2}', "\u{9091}"), ('\u{2fa3}', "\u{9149}"),
+    ('\u{2fa4}', "\u{91c6}"), ('\u{2fa5}', "\u{91cc}"), ('\u{2fa6}', "\u{91d1}"),
+    ('\u{2fa7}', "\u{9577}"), ('\u{2fa8}', "\u{9580}"), ('\u{2fa9}', "\u{961c}"),
+    ('\u{2faa}', "\u{96b6}"), ('\u{2fab}', "\u{96b9}"), ('\u{2fac}', "\u{96e8}"),
+    ('\u{2fad}', "\u{9751}"), ('\u{2fae}', "\u{975e}"), ('\u{2faf}', "\u{9762}"),
+    ('\u{2fb0}', "\u{9769}"), ('\u{2fb1}', "\u{97cb}"), ('\u{2fb2}', "\u{97ed}"),
+    ('\u{2fb3}', "\u{97f3}"), ('\u{2fb4}', "\u{9801}"), ('\u{2fb5}', "\u{98a8}"),
+    ('\u{2fb6}', "\u{98db}"), ('\u{2fb7}', "\u{98df}"), ('\u{2fb8}', "\u{9996}"),
+    ('\u{2fb9}', "\u{9999}"), ('\u{2fba}', "\u{99ac}"), ('\u{2fbb}', "\u{9aa8}"),
+    ('\u{2fbc}', "\u{9ad8}"), ('\u{2fbd}', "\u{9adf}"), ('\u{2fbe}', "\u{9b25}"),
+    ('\u{2fbf}', "\u{9b2f}"), ('\u{2fc0}', "\u{9b32}"), ('\u{2fc1}', "\u{9b3c}"),
+    ('\u{2fc2}', "\u{9b5a}"), ('\u{2fc3}', "\u{9ce5}"), ('\u{2fc4}', "\u{9e75}"),
+    ('\u{2fc5}', "\u{9e7f}"), ('\u{2fc6}', "\u{9ea5}"), ('\u{2fc7}', "\u{9ebb}"),
+    ('\u{2fc8}', "\u{9ec3}"), ('\u{2fc9}', "\u{9ecd}"), ('\u{2fca}', "\u{9ed1}"),
+    ('\u{2fcb}', "\u{9ef9}"), ('\u{2fcc}', "\u{9efd}"), ('\u{2fcd}', "\u{9f0e}"),
+    ('\u{2fce}', "\u{9f13}"), ('\u{2fcf}', "\u{9f20}"), ('\u{2fd0}', "\u{9f3b}"),
+    ('\u{2fd1}', "\u{9f4a}"), ('\u{2fd2}', "\u{9f52}"), ('\u{2fd3}', "\u{9f8d}"),
+    ('\u{2fd4}', "\u{9f9c}"), ('\u{2fd5}', "\u{9fa0}"), ('\u{3000}', "\u{20}"),
+    ('\u{3036}', "\u{3012}"), ('\u{3038}', "\u{5341}"), ('\u{3039}', "\u{5344}"),
+    ('\u{303a}', "\u{5345}"), ('\u{309b}', "\u{20}\u{3099}"), ('\u{309c}', "\u{20}\u{309a}"),
+    ('\u{309f}', "\u{3088}\u{308a}"), ('\u{30ff}', "\u{30b3}\u{30c8}"), ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1101}"), ('\u{3133}', "\u{11aa}"), ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{11ac}"), ('\u{3136}', "\u{11ad}"), ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1104}"), ('\u{3139}', "\u{1105}"), ('\u{313a}', "\u{11b0}"),
+    ('\u{313b}', "\u{11b1}"), ('\u{313c}', "\u{11b2}"), ('\u{313d}', "\u{11b3}"),
+    ('\u{313e}', "\u{11b4}"), ('\u{313f}', "\u{11b5}"), ('\u{3140}', "\u{111a}"),
+    ('\u{3141}', "\u{1106}"), ('\u{3142}', "\u{1107}"), ('\u{3143}', "\u{1108}"),
+    ('\u{3144}', "\u{1121}"), ('\u{3145}', "\u{1109}"), ('\u{3146}', "\u{110a}"),
+    ('\u{3147}', "\u{110b}"), ('\u{3148}', "\u{110c}"), ('\u{3149}', "\u{110d}"),
+    ('\u{314a}', "\u{110e}"), ('\u{314b}', "\u{110f}"), ('\u{314c}', "\u{1110}"),
+    ('\u{314d}', "\u{1111}"), ('\u{314e}', "\u{1112}"), ('\u{314f}', "\u{1161}"),
+    ('\u{3150}', "\u{1162}"), ('\u{3151}', "\u{1163}"), ('\u{3152}', "\u{1164}"),
+    ('\u{3153}', "\u{1165}"), ('\u{3154}', "\u{1166}"), ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1168}"), ('\u{3157}', "\u{1169}"), ('\u{3158}', "\u{116a}"),
+    ('\u{3159}', "\u{116b}"), ('\u{315a}', "\u{116c}"), ('\u{315b}', "\u{116d}"),
+    ('\u{315c}', "\u{116e}"), ('\u{315d}', "\u{116f}"), ('\u{315e}', "\u{1170}"),
+    ('\u{315f}', "\u{1171}"), ('\u{3160}', "\u{1172}"), ('\u{3161}', "\u{1173}"),
+    ('\u{3162}', "\u{1174}"), ('\u{3163}', "\u{1175}"), ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1114}"), ('\u{3166}', "\u{1115}"), ('\u{3167}', "\u{11c7}"),
+    ('\u{3168}', "\u{11c8}"), ('\u{3169}', "\u{11cc}"), ('\u{316a}', "\u{11ce}"),
+    ('\u{316b}', "\u{11d3}"), ('\u{316c}', "\u{11d7}"), ('\u{316d}', "\u{11d9}"),
+    ('\u{316e}', "\u{111c}"), ('\u{316f}', "\u{11dd}"), ('\u{3170}', "\u{11df}"),
+    ('\u{3171}', "\u{111d}"), ('\u{3172}', "\u{111e}"), ('\u{3173}', "\u{1120}"),
+    ('\u{3174}', "\u{1122}"), ('\u{3175}', "\u{1123}"), ('\u{3176}', "\u{1127}"),
+    ('\u{3177}', "\u{1129}"), ('\u{3178}', "\u{112b}"), ('\u{3179}', "\u{112c}"),
+    ('\u{317a}', "\u{112d}"), ('\u{317b}', "\u{112e}"), ('\u{317c}', "\u{112f}"),
+    ('\u{317d}', "\u{1132}"), ('\u{317e}', "\u{1136}"), ('\u{317f}', "\u{1140}"),
+    ('\u{3180}', "\u{1147}"), ('\u{3181}', "\u{114c}"), ('\u{3182}', "\u{11f1}"),
+    ('\u{3183}', "\u{11f2}"), ('\u{3184}', "\u{1157}"), ('\u{3185}', "\u{1158}"),
+    ('\u{3186}', "\u{1159}"), ('\u{3187}', "\u{1184}"), ('\u{3188}', "\u{1185}"),
+    ('\u{3189}', "\u{1188}"), ('\u{318a}', "\u{1191}"), ('\u{318b}', "\u{1192}"),
+    ('\u{318c}', "\u{1194}"), ('\u{318d}', "\u{119e}"), ('\u{318e}', "\u{11a1}"),
+    ('\u{3192}', "\u{4e00}"), ('\u{3193}', "\u{4e8c}"), ('\u{3194}', "\u{4e09}"),
+    ('\u{3195}', "\u{56db}"), ('\u{3196}', "\u{4e0a}"), ('\u{3197}', "\u{4e2d}"),
+    ('\u{3198}', "\u{4e0b}"), ('\u{3199}', "\u{7532}"), ('\u{319a}', "\u{4e59}"),
+    ('\u{319b}', "\u{4e19}"), ('\u{319c}', "\u{4e01}"), ('\u{319d}', "\u{5929}"),
+    ('\u{319e}', "\u{5730}"), ('\u{319f}', "\u{4eba}"), ('\u{3200}', "\u{28}\u{1100}\u{29}"),
+    ('\u{3201}', "\u{28}\u{1102}\u{29}"), ('\u{3202}', "\u{28}\u{1103}\u{29}"), ('\u{3203}', "\u{28}\u{1105}\u{29}"),
+    ('\u{3204}', "\u{28}\u{1106}\u{29}"), ('\u{3205}', "\u{28}\u{1107}\u{29}"), ('\u{3206}', "\u{28}\u{1109}\u{29}"),
+    ('\u{3207}', "\u{28}\u{110b}\u{29}"), ('\u{3208}', "\u{28}\u{110c}\u{29}"), ('\u{3209}', "\u{28}\u{110e}\u{29}"),
+    ('\u{320a}', "\u{28}\u{110f}\u{29}"), ('\u{320b}', "\u{28}\u{1110}\u{29}"), ('\u{320c}', "\u{28}\u{1111}\u{29}"),
+    ('\u{320d}', "\u{28}\u{1112}\u{29}"), ('\u{320e}', "\u{28}\u{1100}\u{1161}\u{29}"), ('\u{320f}', "\u{28}\u{1102}\u{1161}\u{29}"),
+    ('\u{3210}', "\u{28}\u{1103}\u{1161}\u{29}"), ('\u{3211}', "\u{28}\u{1105}\u{1161}\u{29}"), ('\u{3212}', "\u{28}\u{1106}\u{1161}\u{29}"),
+    ('\u{3213}', "\u{28}\u{1107}\u{1161}\u{29}"), ('\u{3214}', "\u{28}\u{1109}\u{1161}\u{29}"), ('\u{3215}', "\u{28}\u{110b}\u{1161}\u{29}"),
+    ('\u{3216}', "\u{28}\u{110c}\u{1161}\u{29}"), ('\u{3217}', "\u{28}\u{110e}\u{1161}\u{29}"), ('\u{3218}', "\u{28}\u{110f}\u{1161}\u{29}"),
+    ('\u{3219}', "\u{28}\u{1110}\u{1161}\u{29}"), ('\u{321a}', "\u{28}\u{1111}\u{1161}\u{29}"), ('\u{321b}', "\u{28}\u{1112}\u{1161}\u{29}"),
+    ('\u{321c}', "\u{28}\u{110c}\u{116e}\u{29}"), ('\u{321d}', "\u{28}\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab}\u{29}"), ('\u{321e}', "\u{28}\u{110b}\u{1169}\u{1112}\u{116e}\u{29}"),
+    ('\u{3220}', "\u{28}\u{4e00}\u{29}"), ('\u{3221}', "\u{28}\u{4e8c}\u{29}"), ('\u{3222}', "\u{28}\u{4e09}\u{29}"),
+    ('\u{3223}', "\u{28}\u{56db}\u{29}"), ('\u{3224}', "\u{28}\u{4e94}\u{29}"), ('\u{3225}', "\u{28}\u{516d}\u{29}"),
+    ('\u{3226}', "\u{28}\u{4e03}\u{29}"), ('\u{3227}', "\u{28}\u{516b}\u{29}"), ('\u{3228}', "\u{28}\u{4e5d}\u{29}"),
+    ('\u{3229}', "\u{28}\u{5341}\u{29}"), ('\u{322a}', "\u{28}\u{6708}\u{29}"), ('\u{322b}', "\u{28}\u{706b}\u{29}"),
+    ('\u{322c}', "\u{28}\u{6c34}\u{29}"), ('\u{322d}', "\u{28}\u{6728}\u{29}"), ('\u{322e}', "\u{28}\u{91d1}\u{29}"),
+    ('\u{322f}', "\u{28}\u{571f}\u{29}"), ('\u{3230}', "\u{28}\u{65e5}\u{29}"), ('\u{3231}', "\u{28}\u{682a}\u{29}"),
+    ('\u{3232}', "\u{28}\u{6709}\u{29}"), ('\u{3233}', "\u{28}\u{793e}\u{29}"), ('\u{3234}', "\u{28}\u{540d}\u{29}"),
+    ('\u{3235}', "\u{28}\u{7279}\u{29}"), ('\u{3236}', "\u{28}\u{8ca1}\u{29}"), ('\u{3237}', "\u{28}\u{795d}\u{29}"),
+    ('\u{3238}', "\u{28}\u{52b4}\u{29}"), ('\u{3239}', "\u{28}\u{4ee3}\u{29}"), ('\u{323a}', "\u{28}\u{547c}\u{29}"),
+    ('\u{323b}', "\u{28}\u{5b66}\u{29}"), ('\u{323c}', "\u{28}\u{76e3}\u{29}"), ('\u{323d}', "\u{28}\u{4f01}\u{29}"),
+    ('\u{323e}', "\u{28}\u{8cc7}\u{29}"), ('\u{323f}', "\u{28}\u{5354}\u{29}"), ('\u{3240}', "\u{28}\u{796d}\u{29}"),
+    ('\u{3241}', "\u{28}\u{4f11}\u{29}"), ('\u{3242}', "\u{28}\u{81ea}\u{29}"), ('\u{3243}', "\u{28}\u{81f3}\u{29}"),
+    ('\u{3244}', "\u{554f}"), ('\u{3245}', "\u{5e7c}"), ('\u{3246}', "\u{6587}"),
+    ('\u{3247}', "\u{7b8f}"), ('\u{3250}', "\u{50}\u{54}\u{45}"), ('\u{3251}', "\u{32}\u{31}"),
+    ('\u{3252}', "\u{32}\u{32}"), ('\u{3253}', "\u{32}\u{33}"), ('\u{3254}', "\u{32}\u{34}"),
+    ('\u{3255}', "\u{32}\u{35}"), ('\u{3256}', "\u{32}\u{36}"), ('\u{3257}', "\u{32}\u{37}"),
+    ('\u{3258}', "\u{32}\u{38}"), ('\u{3259}', "\u{32}\u{39}"), ('\u{325a}', "\u{33}\u{30}"),
+    ('\u{325b}', "\u{33}\u{31}"), ('\u{325c}', "\u{33}\u{32}"), ('\u{325d}', "\u{33}\u{33}"),
+    ('\u{325e}', "\u{33}\u{34}"), ('\u{325f}', "\u{33}\u{35}"), ('\u{3260}', "\u{1100}"),
+    ('\u{3261}', "\u{1102}"), ('\u{3262}', "\u{1103}"), ('\u{3263}', "\u{1105}"),
+    ('\u{3264}', "\u{1106}"), ('\u{3265}', "\u{1107}"), ('\u{3266}', "\u{1109}"),
+    ('\u{3267}', "\u{110b}"), ('\u{3268}', "\u{110c}"), ('\u{3269}', "\u{110e}"),
+    ('\u{326a}', "\u{110f}"), ('\u{326b}', "\u{1110}"), ('\u{326c}', "\u{1111}"),
+    ('\u{326d}', "\u{1112}"), ('\u{326e}', "\u{1100}\u{1161}"), ('\u{326f}', "\u{1102}\u{1161}"),
+    ('\u{3270}', "\u{1103}\u{1161}"), ('\u{3271}', "\u{1105}\u{1161}"), ('\u{3272}', "\u{1106}\u{1161}"),
+    ('\u{3273}', "\u{1107}\u{1161}"), ('\u{3274}', "\u{1109}\u{1161}"), ('\u{3275}', "\u{110b}\u{1161}"),
+    ('\u{3276}', "\u{110c}\u{1161}"), ('\u{3277}', "\u{110e}\u{1161}"), ('\u{3278}', "\u{110f}\u{1161}"),
+    ('\u{3279}', "\u{1110}\u{1161}"), ('\u{327a}', "\u{1111}\u{1161}"), ('\u{327b}', "\u{1112}\u{1161}"),
+    ('\u{327c}', "\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}"), ('\u{327d}', "\u{110c}\u{116e}\u{110b}\u{1174}"), ('\u{327e}', "\u{110b}\u{116e}"),
+    ('\u{3280}', "\u{4e00}"), ('\u{3281}', "\u{4e8c}"), ('\u{3282}', "\u{4e09}"),
+    ('\u{3283}', "\u{56db}"), ('\u{3284}', "\u{4e94}"), ('\u{3285}', "\u{516d}"),
+    ('\u{3286}', "\u{4e03}"), ('\u{3287}', "\u{516b}"), ('\u{3288}', "\u{4e5d}"),
+    ('\u{3289}', "\u{5341}"), ('\u{328a}', "\u{6708}"), ('\u{328b}', "\u{706b}"),
+    ('\u{328c}', "\u{6c34}"), ('\u{328d}', "\u{6728}"), ('\u{328e}', "\u{91d1}"),
+    ('\u{328f}', "\u{571f}"), ('\u{3290}', "\u{65e5}"), ('\u{3291}', "\u{682a}"),
+    ('\u{3292}', "\u{6709}"), ('\u{3293}', "\u{793e}"), ('\u{3294}', "\u{540d}"),
+    ('\u{3295}', "\u{7279}"), ('\u{3296}', "\u{8ca1}"), ('\u{3297}', "\u{795d}"),
+    ('\u{3298}', "\u{52b4}"), ('\u{3299}', "\u{79d8}"), ('\u{329a}', "\u{7537}"),
+    ('\u{329b}', "\u{5973}"), ('\u{329c}', "\u{9069}"), ('\u{329d}', "\u{512a}"),
+    ('\u{329e}', "\u{5370}"), ('\u{329f}', "\u{6ce8}"), ('\u{32a0}', "\u{9805}"),
+    ('\u{32a1}', "\u{4f11}"), ('\u{32a2}', "\u{5199}"), ('\u{32a3}', "\u{6b63}"),
+    ('\u{32a4}', "\u{4e0a}"), ('\u{32a5}', "\u{4e2d}"), ('\u{32a6}', "\u{4e0b}"),
+    ('\u{32a7}', "\u{5de6}"), ('\u{32a8}', "\u{53f3}"), ('\u{32a9}', "\u{533b}"),
+    ('\u{32aa}', "\u{5b97}"), ('\u{32ab}', "\u{5b66}"), ('\u{32ac}', "\u{76e3}"),
+    ('\u{32ad}', "\u{4f01}"), ('\u{32ae}', "\u{8cc7}"), ('\u{32af}', "\u{5354}"),
+    ('\u{32b0}', "\u{591c}"), ('\u{32b1}', "\u{33}\u{36}"), ('\u{32b2}', "\u{33}\u{37}"),
+    ('\u{32b3}', "\u{33}\u{38}"), ('\u{32b4}', "\u{33}\u{39}"), ('\u{32b5}', "\u{34}\u{30}"),
+    ('\u{32b6}', "\u{34}\u{31}"), ('\u{32b7}', "\u{34}\u{32}"), ('\u{32b8}', "\u{34}\u{33}"),
+    ('\u{32b9}', "\u{34}\u{34}"), ('\u{32ba}', "\u{34}\u{35}"), ('\u{32bb}', "\u{34}\u{36}"),
+    ('\u{32bc}', "\u{34}\u{37}"), ('\u{32bd}', "\u{34}\u{38}"), ('\u{32be}', "\u{34}\u{39}"),
+    ('\u{32bf}', "\u{35}\u{30}"), ('\u{32c0}', "\u{31}\u{6708}"), ('\u{32c1}', "\u{32}\u{6708}"),
+    ('\u{32c2}', "\u{33}\u{6708}"), ('\u{32c3}', "\u{34}\u{6708}"), ('\u{32c4}', "\u{35}\u{6708}"),
+    ('\u{32c5}', "\u{36}\u{6708}"), ('\u{32c6}', "\u{37}\u{6708}"), ('\u{32c7}', "\u{38}\u{6708}"),
+    ('\u{32c8}', "\u{39}\u{6708}"), ('\u{32c9}', "\u{31}\u{30}\u{6708}"), ('\u{32ca}', "\u{31}\u{31}\u{6708}"),
+    ('\u{32cb}', "\u{31}\u{32}\u{6708}"), ('\u{32cc}', "\u{48}\u{67}"), ('\u{32cd}', "\u{65}\u{72}\u{67}"),
+    ('\u{32ce}', "\u{65}\u{56}"), ('\u{32cf}', "\u{4c}\u{54}\u{44}"), ('\u{32d0}', "\u{30a2}"),
+    ('\u{32d1}', "\u{30a4}"), ('\u{32d2}', "\u{30a6}"), ('\u{32d3}', "\u{30a8}"),
+    ('\u{32d4}', "\u{30aa}"), ('\u{32d5}', "\u{30ab}"), ('\u{32d6}', "\u{30ad}"),
+    ('\u{32d7}', "\u{30af}"), ('\u{32d8}', "\u{30b1}"), ('\u{32d9}', "\u{30b3}"),
+    ('\u{32da}', "\u{30b5}"), ('\u{32db}', "\u{30b7}"), ('\u{32dc}', "\u{30b9}"),
+    ('\u{32dd}', "\u{30bb}"), ('\u{32de}', "\u{30bd}"), ('\u{32df}', "\u{30bf}"),
+    ('\u{32e0}', "\u{30c1}"), ('\u{32e1}', "\u{30c4}"), ('\u{32e2}', "\u{30c6}"),
+    ('\u{32e3}', "\u{30c8}"), ('\u{32e4}', "\u{30ca}"), ('\u{32e5}', "\u{30cb}"),
+    ('\u{32e6}', "\u{30cc}"), ('\u{32e7}', "\u{30cd}"), ('\u{32e8}', "\u{30ce}"),
+    ('\u{32e9}', "\u{30cf}"), ('\u{32ea}', "\u{30d2}"), ('\u{32eb}', "\u{30d5}"),
+    ('\u{32ec}', "\u{30d8}"), ('\u{32ed}', "\u{30db}"), ('\u{32ee}', "\u{30de}"),
+    ('\u{32ef}', "\u{30df}"), ('\u{32f0}', "\u{30e0}"), ('\u{32f1}', "\u{30e1}"),
+    ('\u{32f2}', "\u{30e2}"), ('\u{32f3}', "\u{30e4}"), ('\u{32f4}', "\u{30e6}"),
+    ('\u{32f5}', "\u{30e8}"), ('\u{32f6}', "\u{30e9}"), ('\u{32f7}', "\u{30ea}"),
+    ('\u{32f8}', "\u{30eb}"), ('\u{32f9}', "\u{30ec}"), ('\u{32fa}', "\u{30ed}"),
+    ('\u{32fb}', "\u{30ef}"), ('\u{32fc}', "\u{30f0}"), ('\u{32fd}', "\u{30f1}"),
+    ('\u{32fe}', "\u{30f2}"), ('\u{32ff}', "\u{4ee4}\u{548c}"), ('\u{3300}', "\u{30a2}\u{30cf}\u{309a}\u{30fc}\u{30c8}"),
+    ('\u{3301}', "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"), ('\u{3302}', "\u{30a2}\u{30f3}\u{30d8}\u{309a}\u{30a2}"), ('\u{3303}', "\u{30a2}\u{30fc}\u{30eb}"),
+    ('\u{3304}', "\u{30a4}\u{30cb}\u{30f3}\u{30af}\u{3099}"), ('\u{3305}', "\u{30a4}\u{30f3}\u{30c1}"), ('\u{3306}', "\u{30a6}\u{30a9}\u{30f3}"),
+    ('\u{3307}', "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c8}\u{3099}"), ('\u{3308}', "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"), ('\u{3309}', "\u{30aa}\u{30f3}\u{30b9}"),
+    ('\u{330a}', "\u{30aa}\u{30fc}\u{30e0}"), ('\u{330b}', "\u{30ab}\u{30a4}\u{30ea}"), ('\u{330c}', "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"),
+    ('\u{330d}', "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"), ('\u{330e}', "\u{30ab}\u{3099}\u{30ed}\u{30f3}"), ('\u{330f}', "\u{30ab}\u{3099}\u{30f3}\u{30de}"),
+    ('\u{3310}', "\u{30ad}\u{3099}\u{30ab}\u{3099}"), ('\u{3311}', "\u{30ad}\u{3099}\u{30cb}\u{30fc}"), ('\u{3312}', "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    ('\u{3313}', "\u{30ad}\u{3099}\u{30eb}\u{30bf}\u{3099}\u{30fc}"), ('\u{3314}', "\u{30ad}\u{30ed}"), ('\u{3315}', "\u{30ad}\u{30ed}\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    ('\u{3316}', "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"), ('\u{3317}', "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"), ('\u{3318}', "\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    ('\u{3319}', "\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"), ('\u{331a}', "\u{30af}\u{30eb}\u{30bb}\u{3099}\u{30a4}\u{30ed}"), ('\u{331b}', "\u{30af}\u{30ed}\u{30fc}\u{30cd}"),
+    ('\u{331c}', "\u{30b1}\u{30fc}\u{30b9}"), ('\u{331d}', "\u{30b3}\u{30eb}\u{30ca}"), ('\u{331e}', "\u{30b3}\u{30fc}\u{30db}\u{309a}"),
+    ('\u{331f}', "\u{30b5}\u{30a4}\u{30af}\u{30eb}"), ('\u{3320}', "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"), ('\u{3321}', "\u{30b7}\u{30ea}\u{30f3}\u{30af}\u{3099}"),
+    ('\u{3322}', "\u{30bb}\u{30f3}\u{30c1}"), ('\u{3323}', "\u{30bb}\u{30f3}\u{30c8}"), ('\u{3324}', "\u{30bf}\u{3099}\u{30fc}\u{30b9}"),
+    ('\u{3325}', "\u{30c6}\u{3099}\u{30b7}"), ('\u{3326}', "\u{30c8}\u{3099}\u{30eb}"), ('\u{3327}', "\u{30c8}\u{30f3}"),
+    ('\u{3328}', "\u{30ca}\u{30ce}"), ('\u{3329}', "\u{30ce}\u{30c3}\u{30c8}"), ('\u{332a}', "\u{30cf}\u{30a4}\u{30c4}"),
+    ('\u{332b}', "\u{30cf}\u{309a}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"), ('\u{332c}', "\u{30cf}\u{309a}\u{30fc}\u{30c4}"), ('\u{332d}', "\u{30cf}\u{3099}\u{30fc}\u{30ec}\u{30eb}"),
+    ('\u{332e}', "\u{30d2}\u{309a}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"), ('\u{332f}', "\u{30d2}\u{309a}\u{30af}\u{30eb}"), ('\u{3330}', "\u{30d2}\u{309a}\u{30b3}"),
+    ('\u{3331}', "\u{30d2}\u{3099}\u{30eb}"), ('\u{3332}', "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c8}\u{3099}"), ('\u{3333}', "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    ('\u{3334}', "\u{30d5}\u{3099}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"), ('\u{3335}', "\u{30d5}\u{30e9}\u{30f3}"), ('\u{3336}', "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"),
+    ('\u{3337}', "\u{30d8}\u{309a}\u{30bd}"), ('\u{3338}', "\u{30d8}\u{309a}\u{30cb}\u{30d2}"), ('\u{3339}', "\u{30d8}\u{30eb}\u{30c4}"),
+    ('\u{333a}', "\u{30d8}\u{309a}\u{30f3}\u{30b9}"), ('\u{333b}', "\u{30d8}\u{309a}\u{30fc}\u{30b7}\u{3099}"), ('\u{333c}', "\u{30d8}\u{3099}\u{30fc}\u{30bf}"),
+    ('\u{333d}', "\u{30db}\u{309a}\u{30a4}\u{30f3}\u{30c8}"), ('\u{333e}', "\u{30db}\u{3099}\u{30eb}\u{30c8}"), ('\u{333f}', "\u{30db}\u{30f3}"),
+    ('\u{3340}', "\u{30db}\u{309a}\u{30f3}\u{30c8}\u{3099}"), ('\u{3341}', "\u{30db}\u{30fc}\u{30eb}"), ('\u{3342}', "\u{30db}\u{30fc}\u{30f3}"),
+    ('\u{3343}', "\u{30de}\u{30a4}\u{30af}\u{30ed}"), ('\u{3344}', "\u{30de}\u{30a4}\u{30eb}"), ('\u{3345}', "\u{30de}\u{30c3}\u{30cf}"),
+    ('\u{3346}', "\u{30de}\u{30eb}\u{30af}"), ('\u{3347}', "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"), ('\u{3348}', "\u{30df}\u{30af}\u{30ed}\u{30f3}"),
+    ('\u{3349}', "\u{30df}\u{30ea}"), ('\u{334a}', "\u{30df}\u{30ea}\u{30cf}\u{3099}\u{30fc}\u{30eb}"), ('\u{334b}', "\u{30e1}\u{30ab}\u{3099}"),
+    ('\u{334c}', "\u{30e1}\u{30ab}\u{3099}\u{30c8}\u{30f3}"), ('\u{334d}', "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"), ('\u{334e}', "\u{30e4}\u{30fc}\u{30c8}\u{3099}"),
+    ('\u{334f}', "\u{30e4}\u{30fc}\u{30eb}"), ('\u{3350}', "\u{30e6}\u{30a2}\u{30f3}"), ('\u{3351}', "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"),
+    ('\u{3352}', "\u{30ea}\u{30e9}"), ('\u{3353}', "\u{30eb}\u{30d2}\u{309a}\u{30fc}"), ('\u{3354}', "\u{30eb}\u{30fc}\u{30d5}\u{3099}\u{30eb}"),
+    ('\u{3355}', "\u{30ec}\u{30e0}"), ('\u{3356}', "\u{30ec}\u{30f3}\u{30c8}\u{30b1}\u{3099}\u{30f3}"), ('\u{3357}', "\u{30ef}\u{30c3}\u{30c8}"),
+    ('\u{3358}', "\u{30}\u{70b9}"), ('\u{3359}', "\u{31}\u{70b9}"), ('\u{335a}', "\u{32}\u{70b9}"),
+    ('\u{335b}', "\u{33}\u{70b9}"), ('\u{335c}', "\u{34}\u{70b9}"), ('\u{335d}', "\u{35}\u{70b9}"),
+    ('\u{335e}', "\u{36}\u{70b9}"), ('\u{335f}', "\u{37}\u{70b9}"), ('\u{3360}', "\u{38}\u{70b9}"),
+    ('\u{3361}', "\u{39}\u{70b9}"), ('\u{3362}', "\u{31}\u{30}\u{70b9}"), ('\u{3363}', "\u{31}\u{31}\u{70b9}"),
+    ('\u{3364}', "\u{31}\u{32}\u{70b9}"), ('\u{3365}', "\u{31}\u{33}\u{70b9}"), ('\u{3366}', "\u{31}\u{34}\u{70b9}"),
+    ('\u{3367}', "\u{31}\u{35}\u{70b9}"), ('\u{3368}', "\u{31}\u{36}\u{70b9}"), ('\u{3369}', "\u{31}\u{37}\u{70b9}"),
+    ('\u{336a}', "\u{31}\u{38}\u{70b9}"), ('\u{336b}', "\u{31}\u{39}\u{70b9}"), ('\u{336c}', "\u{32}\u{30}\u{70b9}"),
+    ('\u{336d}', "\u{32}\u{31}\u{70b9}"), ('\u{336e}', "\u{32}\u{32}\u{70b9}"), ('\u{336f}', "\u{32}\u{33}\u{70b9}"),
+    ('\u{3370}', "\u{32}\u{34}\u{70b9}"), ('\u{3371}', "\u{68}\u{50}\u{61}"), ('\u{3372}', "\u{64}\u{61}"),
+    ('\u{3373}', "\u{41}\u{55}"), ('\u{3374}', "\u{62}\u{61}\u{72}"), ('\u{3375}', "\u{6f}\u{56}"),
+    ('\u{3376}', "\u{70}\u{63}"), ('\u{3377}', "\u{64}\u{6d}"), ('\u{3378}', "\u{64}\u{6d}\u{32}"),
+    ('\u{3379}', "\u{64}\u{6d}\u{33}"), ('\u{337a}', "\u{49}\u{55}"), ('\u{337b}', "\u{5e73}\u{6210}"),
+    ('\u{337c}', "\u{662d}\u{548c}"), ('\u{337d}', "\u{5927}\u{6b63}"), ('\u{337e}', "\u{660e}\u{6cbb}"),
+    ('\u{337f}', "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"), ('\u{3380}', "\u{70}\u{41}"), ('\u{3381}', "\u{6e}\u{41}"),
+    ('\u{3382}', "\u{3bc}\u{41}"), ('\u{3383}', "\u{6d}\u{41}"), ('\u{3384}', "\u{6b}\u{41}"),
+    ('\u{3385}', "\u{4b}\u{42}"), ('\u{3386}', "\u{4d}\u{42}"), ('\u{3387}', "\u{47}\u{42}"),
+    ('\u{3388}', "\u{63}\u{61}\u{6c}"), ('\u{3389}', "\u{6b}\u{63}\u{61}\u{6c}"), ('\u{338a}', "\u{70}\u{46}"),
+    ('\u{338b}', "\u{6e}\u{46}"), ('\u{338c}', "\u{3bc}\u{46}"), ('\u{338d}', "\u{3bc}\u{67}"),
+    ('\u{338e}', "\u{6d}\u{67}"), ('\u{338f}', "\u{6b}\u{67}"), ('\u{3390}', "\u{48}\u{7a}"),
+    ('\u{3391}', "\u{6b}\u{48}\u{7a}"), ('\u{3392}', "\u{4d}\u{48}\u{7a}"), ('\u{3393}', "\u{47}\u{48}\u{7a}"),
+    ('\u{3394}', "\u{54}\u{48}\u{7a}"), ('\u{3395}', "\u{3bc}\u{6c}"), ('\u{3396}', "\u{6d}\u{6c}"),
+    ('\u{3397}', "\u{64}\u{6c}"), ('\u{3398}', "\u{6b}\u{6c}"), ('\u{3399}', "\u{66}\u{6d}"),
+    ('\u{339a}', "\u{6e}\u{6d}"), ('\u{339b}', "\u{3bc}\u{6d}"), ('\u{339c}', "\u{6d}\u{6d}"),
+    ('\u{339d}', "\u{63}\u{6d}"), ('\u{339e}', "\u{6b}\u{6d}"), ('\u{339f}', "\u{6d}\u{6d}\u{32}"),
+    ('\u{33a0}', "\u{63}\u{6d}\u{32}"), ('\u{33a1}', "\u{6d}\u{32}"), ('\u{33a2}', "\u{6b}\u{6d}\u{32}"),
+    ('\u{33a3}', "\u{6d}\u{6d}\u{33}"), ('\u{33a4}', "\u{63}\u{6d}\u{33}"), ('\u{33a5}', "\u{6d}\u{33}"),
+    ('\u{33a6}', "\u{6b}\u{6d}\u{33}"), ('\u{33a7}', "\u{6d}\u{2215}\u{73}"), ('\u{33a8}', "\u{6d}\u{2215}\u{73}\u{32}"),
+    ('\u{33a9}', "\u{50}\u{61}"), ('\u{33aa}', "\u{6b}\u{50}\u{61}"), ('\u{33ab}', "\u{4d}\u{50}\u{61}"),
+    ('\u{33ac}', "\u{47}\u{50}\u{61}"), ('\u{33ad}', "\u{72}\u{61}\u{64}"), ('\u{33ae}', "\u{72}\u{61}\u{64}\u{2215}\u{73}"),
+    ('\u{33af}', "\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}"), ('\u{33b0}', "\u{70}\u{73}"), ('\u{33b1}', "\u{6e}\u{73}"),
+    ('\u{33b2}', "\u{3bc}\u{73}"), ('\u{33b3}', "\u{6d}\u{73}"), ('\u{33b4}', "\u{70}\u{56}"),
+    ('\u{33b5}', "\u{6e}\u{56}"), ('\u{33b6}', "\u{3bc}\u{56}"), ('\u{33b7}', "\u{6d}\u{56}"),
+    ('\u{33b8}', "\u{6b}\u{56}"), ('\u{33b9}', "\u{4d}\u{56}"), ('\u{33ba}', "\u{70}\u{57}"),
+    ('\u{33bb}', "\u{6e}\u{57}"), ('\u{33bc}', "\u{3bc}\u{57}"), ('\u{33bd}', "\u{6d}\u{57}"),
+    ('\u{33be}', "\u{6b}\u{57}"), ('\u{33bf}', "\u{4d}\u{57}"), ('\u{33c0}', "\u{6b}\u{3a9}"),
+    ('\u{33c1}', "\u{4d}\u{3a9}"), ('\u{33c2}', "\u{61}\u{2e}\u{6d}\u{2e}"), ('\u{33c3}', "\u{42}\u{71}"),
+    ('\u{33c4}', "\u{63}\u{63}"), ('\u{33c5}', "\u{63}\u{64}"), ('\u{33c6}', "\u{43}\u{2215}\u{6b}\u{67}"),
+    ('\u{33c7}', "\u{43}\u{6f}\u{2e}"), ('\u{33c8}', "\u{64}\u{42}"), ('\u{33c9}', "\u{47}\u{79}"),
+    ('\u{33ca}', "\u{68}\u{61}"), ('\u{33cb}', "\u{48}\u{50}"), ('\u{33cc}', "\u{69}\u{6e}"),
+    ('\u{33cd}', "\u{4b}\u{4b}"), ('\u{33ce}', "\u{4b}\u{4d}"), ('\u{33cf}', "\u{6b}\u{74}"),
+    ('\u{33d0}', "\u{6c}\u{6d}"), ('\u{33d1}', "\u{6c}\u{6e}"), ('\u{33d2}', "\u{6c}\u{6f}\u{67}"),
+    ('\u{33d3}', "\u{6c}\u{78}"), ('\u{33d4}', "\u{6d}\u{62}"), ('\u{33d5}', "\u{6d}\u{69}\u{6c}"),
+    ('\u{33d6}', "\u{6d}\u{6f}\u{6c}"), ('\u{33d7}', "\u{50}\u{48}"), ('\u{33d8}', "\u{70}\u{2e}\u{6d}\u{2e}"),
+    ('\u{33d9}', "\u{50}\u{50}\u{4d}"), ('\u{33da}', "\u{50}\u{52}"), ('\u{33db}', "\u{73}\u{72}"),
+    ('\u{33dc}', "\u{53}\u{76}"), ('\u{33dd}', "\u{57}\u{62}"), ('\u{33de}', "\u{56}\u{2215}\u{6d}"),
+    ('\u{33df}', "\u{41}\u{2215}\u{6d}"), ('\u{33e0}', "\u{31}\u{65e5}"), ('\u{33e1}', "\u{32}\u{65e5}"),
+    ('\u{33e2}', "\u{33}\u{65e5}"), ('\u{33e3}', "\u{34}\u{65e5}"), ('\u{33e4}', "\u{35}\u{65e5}"),
+    ('\u{33e5}', "\u{36}\u{65e5}"), ('\u{33e6}', "\u{37}\u{65e5}"), ('\u{33e7}', "\u{38}\u{65e5}"),
+    ('\u{33e8}', "\u{39}\u{65e5}"), ('\u{33e9}', "\u{31}\u{30}\u{65e5}"), ('\u{33ea}', "\u{31}\u{31}\u{65e5}"),
+    ('\u{33eb}', "\u{31}\u{32}\u{65e5}"), ('\u{33ec}', "\u{31}\u{33}\u{65e5}"), ('\u{33ed}', "\u{31}\u{34}\u{65e5}"),
+    ('\u{33ee}', "\u{31}\u{35}\u{65e5}"), ('\u{33ef}', "\u{31}\u{36}\u{65e5}"), ('\u{33f0}', "\u{31}\u{37}\u{65e5}"),
+    ('\u{33f1}', "\u{31}\u{38}\u{65e5}"), ('\u{33f2}', "\u{31}\u{39}\u{65e5}"), ('\u{33f3}', "\u{32}\u{30}\u{65e5}"),
+    ('\u{33f4}', "\u{32}\u{31}\u{65e5}"), ('\u{33f5}', "\u{32}\u{32}\u{65e5}"), ('\u{33f6}', "\u{32}\u{33}\u{65e5}"),
+    ('\u{33f7}', "\u{32}\u{34}\u{65e5}"), ('\u{33f8}', "\u{32}\u{35}\u{65e5}"), ('\u{33f9}', "\u{32}\u{36}\u{65e5}"),
+    ('\u{33fa}', "\u{32}\u{37}\u{65e5}"), ('\u{33fb}', "\u{32}\u{38}\u{65e5}"), ('\u{33fc}', "\u{32}\u{39}\u{65e5}"),
+    ('\u{33fd}', "\u{33}\u{30}\u{65e5}"), ('\u{33fe}', "\u{33}\u{31}\u{65e5}"), ('\u{33ff}', "\u{67}\u{61}\u{6c}"),
+    ('\u{a69c}', "\u{44a}"), ('\u{a69d}', "\u{44c}"), ('\u{a770}', "\u{a76f}"),
+    ('\u{a7f2}', "\u{43}"), ('\u{a7f3}', "\u{46}"), ('\u{a7f4}', "\u{51}"),
+    ('\u{a7f8}', "\u{126}"), ('\u{a7f9}', "\u{153}"), ('\u{ab5c}', "\u{a727}"),
+    ('\u{ab5d}', "\u{ab37}"), ('\u{ab5e}', "\u{26b}"), ('\u{ab5f}', "\u{ab52}"),
+    ('\u{ab69}', "\u{28d}"), ('\u{fb00}', "\u{66}\u{66}"), ('\u{fb01}', "\u{66}\u{69}"),
+    ('\u{fb02}', "\u{66}\u{6c}"), ('\u{fb03}', "\u{66}\u{66}\u{69}"), ('\u{fb04}', "\u{66}\u{66}\u{6c}"),
+    ('\u{fb05}', "\u{73}\u{74}"), ('\u{fb06}', "\u{73}\u{74}"), ('\u{fb13}', "\u{574}\u{576}"),
+    ('\u{fb14}', "\u{574}\u{565}"), ('\u{fb15}', "\u{574}\u{56b}"), ('\u{fb16}', "\u{57e}\u{576}"),
+    ('\u{fb17}', "\u{574}\u{56d}"), ('\u{fb20}', "\u{5e2}"), ('\u{fb21}', "\u{5d0}"),
+    ('\u{fb22}', "\u{5d3}"), ('\u{fb23}', "\u{5d4}"), ('\u{fb24}', "\u{5db}"),
+    ('\u{fb25}', "\u{5dc}"), ('\u{fb26}', "\u{5dd}"), ('\u{fb27}', "\u{5e8}"),
+    ('\u{fb28}', "\u{5ea}"), ('\u{fb29}', "\u{2b}"), ('\u{fb4f}', "\u{5d0}\u{5dc}"),
+    ('\u{fb50}', "\u{671}"), ('\u{fb51}', "\u{671}"), ('\u{fb52}', "\u{67b}"),
+    ('\u{fb53}', "\u{67b}"), ('\u{fb54}', "\u{67b}"), ('\u{fb55}', "\u{67b}"),
+    ('\u{fb56}', "\u{67e}"), ('\u{fb57}', "\u{67e}"), ('\u{fb58}', "\u{67e}"),
+    ('\u{fb59}', "\u{67e}"), ('\u{fb5a}', "\u{680}"), ('\u{fb5b}', "\u{680}"),
+    ('\u{fb5c}', "\u{680}"), ('\u{fb5d}', "\u{680}"), ('\u{fb5e}', "\u{67a}"),
+    ('\u{fb5f}', "\u{67a}"), ('\u{fb60}', "\u{67a}"), ('\u{fb61}', "\u{67a}"),
+    ('\u{fb62}', "\u{67f}"), ('\u{fb63}', "\u{67f}"), ('\u{fb64}', "\u{67f}"),
+    ('\u{fb65}', "\u{67f}"), ('\u{fb66}', "\u{679}"), ('\u{fb67}', "\u{679}"),
+    ('\u{fb68}', "\u{679}"), ('\u{fb69}', "\u{679}"), ('\u{fb6a}', "\u{6a4}"),
+    ('\u{fb6b}', "\u{6a4}"), ('\u{fb6c}', "\u{6a4}"), ('\u{fb6d}', "\u{6a4}"),
+    ('\u{fb6e}', "\u{6a6}"), ('\u{fb6f}', "\u{6a6}"), ('\u{fb70}', "\u{6a6}"),
+    ('\u{fb71}', "\u{6a6}"), ('\u{fb72}', "\u{684}"), ('\u{fb73}', "\u{684}"),
+    ('\u{fb74}', "\u{684}"), ('\u{fb75}', "\u{684}"), ('\u{fb76}', "\u{683}"),
+    ('\u{fb77}', "\u{683}"), ('\u{fb78}', "\u{683}"), ('\u{fb79}', "\u{683}"),
+    ('\u{fb7a}', "\u{686}"), ('\u{fb7b}', "\u{686}"), ('\u{fb7c}', "\u{686}"),
+    ('\u{fb7d}', "\u{686}"), ('\u{fb7e}', "\u{687}"), ('\u{fb7f}', "\u{687}"),
+    ('\u{fb80}', "\u{687}"), ('\u{fb81}', "\u{687}"), ('\u{fb82}', "\u{68d}"),
+    ('\u{fb83}', "\u{68d}"), ('\u{fb84}', "\u{68c}"), ('\u{fb85}', "\u{68c}"),
+    ('\u{fb86}', "\u{68e}"), ('\u{fb87}', "\u{68e}"), ('\u{fb88}', "\u{688}"),
+    ('\u{fb89}', "\u{688}"), ('\u{fb8a}', "\u{698}"), ('\u{fb8b}', "\u{698}"),
+    ('\u{fb8c}', "\u{691}"), ('\u{fb8d}', "\u{691}"), ('\u{fb8e}', "\u{6a9}"),
+    ('\u{fb8f}', "\u{6a9}"), ('\u{fb90}', "\u{6a9}"), ('\u{fb91}', "\u{6a9}"),
+    ('\u{fb92}', "\u{6af}"), ('\u{fb93}', "\u{6af}"), ('\u{fb94}', "\u{6af}"),
+    ('\u{fb95}', "\u{6af}"), ('\u{fb96}', "\u{6b3}"), ('\u{fb97}', "\u{6b3}"),
+    ('\u{fb98}', "\u{6b3}"), ('\u{fb99}', "\u{6b3}"), ('\u{fb9a}', "\u{6b1}"),
+    ('\u{fb9b}', "\u{6b1}"), ('\u{fb9c}', "\u{6b1}"), ('\u{fb9d}', "\u{6b1}"),
+    ('\u{fb9e}', "\u{6ba}"), ('\u{fb9f}', "\u{6ba}"), ('\u{fba0}', "\u{6bb}"),
+    ('\u{fba1}', "\u{6bb}"), ('\u{fba2}', "\u{6bb}"), ('\u{fba3}', "\u{6bb}"),
+    ('\u{fba4}', "\u{6d5}\u{654}"), ('\u{fba5}', "\u{6d5}\u{654}"), ('\u{fba6}', "\u{6c1}"),
+    ('\u{fba7}', "\u{6c1}"), ('\u{fba8}', "\u{6c1}"), ('\u{fba9}', "\u{6c1}"),
+    ('\u{fbaa}', "\u{6be}"), ('\u{fbab}', "\u{6be}"), ('\u{fbac}', "\u{6be}"),
+    ('\u{fbad}', "\u{6be}"), ('\u{fbae}', "\u{6d2}"), ('\u{fbaf}', "\u{6d2}"),
+    ('\u{fbb0}', "\u{6d2}\u{654}"), ('\u{fbb1}', "\u{6d2}\u{654}"), ('\u{fbd3}', "\u{6ad}"),
+    ('\u{fbd4}', "\u{6ad}"), ('\u{fbd5}', "\u{6ad}"), ('\u{fbd6}', "\u{6ad}"),
+    ('\u{fbd7}', "\u{6c7}"), ('\u{fbd8}', "\u{6c7}"), ('\u{fbd9}', "\u{6c6}"),
+    ('\u{fbda}', "\u{6c6}"), ('\u{fbdb}', "\u{6c8}"), ('\u{fbdc}', "\u{6c8}"),
+    ('\u{fbdd}', "\u{6c7}\u{674}"), ('\u{fbde}', "\u{6cb}"), ('\u{fbdf}', "\u{6cb}"),
+    ('\u{fbe0}', "\u{6c5}"), ('\u{fbe1}', "\u{6c5}"), ('\u{fbe2}', "\u{6c9}"),
+    ('\u{fbe3}', "\u{6c9}"), ('\u{fbe4}', "\u{6d0}"), ('\u{fbe5}', "\u{6d0}"),
+    ('\u{fbe6}', "\u{6d0}"), ('\u{fbe7}', "\u{6d0}"), ('\u{fbe8}', "\u{649}"),
+    ('\u{fbe9}', "\u{649}"), ('\u{fbea}', "\u{64a}\u{654}\u{627}"), ('\u{fbeb}', "\u{64a}\u{654}\u{627}"),
+    ('\u{fbec}', "\u{64a}\u{654}\u{6d5}"), ('\u{fbed}', "\u{64a}\u{654}\u{6d5}"), ('\u{fbee}', "\u{64a}\u{654}\u{648}"),
+    ('\u{fbef}', "\u{64a}\u{654}\u{648}"), ('\u{fbf0}', "\u{64a}\u{654}\u{6c7}"), ('\u{fbf1}', "\u{64a}\u{654}\u{6c7}"),
+    ('\u{fbf2}', "\u{64a}\u{654}\u{6c6}"), ('\u{fbf3}', "\u{64a}\u{654}\u{6c6}"), ('\u{fbf4}', "\u{64a}\u{654}\u{6c8}"),
+    ('\u{fbf5}', "\u{64a}\u{654}\u{6c8}"), ('\u{fbf6}', "\u{64a}\u{654}\u{6d0}"), ('\u{fbf7}', "\u{64a}\u{654}\u{6d0}"),
+    ('\u{fbf8}', "\u{64a}\u{654}\u{6d0}"), ('\u{fbf9}', "\u{64a}\u{654}\u{649}"), ('\u{fbfa}', "\u{64a}\u{654}\u{649}"),
+    ('\u{fbfb}', "\u{64a}\u{654}\u{649}"), ('\u{fbfc}', "\u{6cc}"), ('\u{fbfd}', "\u{6cc}"),
+    ('\u{fbfe}', "\u{6cc}"), ('\u{fbff}', "\u{6cc}"), ('\u{fc00}', "\u{64a}\u{654}\u{62c}"),
+    ('\u{fc01}', "\u{64a}\u{654}\u{62d}"), ('\u{fc02}', "\u{64a}\u{654}\u{645}"), ('\u{fc03}', "\u{64a}\u{654}\u{649}"),
+    ('\u{fc04}', "\u{64a}\u{654}\u{64a}"), ('\u{fc05}', "\u{628}\u{62c}"), ('\u{fc06}', "\u{628}\u{62d}"),
+    ('\u{fc07}', "\u{628}\u{62e}"), ('\u{fc08}', "\u{628}\u{645}"), ('\u{fc09}', "\u{628}\u{649}"),
+    ('\u{fc0a}', "\u{628}\u{64a}"), ('\u{fc0b}', "\u{62a}\u{62c}"), ('\u{fc0c}', "\u{62a}\u{62d}"),
+    ('\u{fc0d}', "\u{62a}\u{62e}"), ('\u{fc0e}', "\u{62a}\u{645}"), ('\u{fc0f}', "\u{62a}\u{649}"),
+    ('\u{fc10}', "\u{62a}\u{64a}"), ('\u{fc11}', "\u{62b}\u{62c}"), ('\u{fc12}', "\u{62b}\u{645}"),
+    ('\u{fc13}', "\u{62b}\u{649}"), ('\u{fc14}', "\u{62b}\u{64a}"), ('\u{fc15}', "\u{62c}\u{62d}"),
+    ('\u{fc16}', "\u{62c}\u{645}"), ('\u{fc17}', "\u{62d}\u{62c}"), ('\u{fc18}', "\u{62d}\u{645}"),
+    ('\u{fc19}', "\u{62e}\u{62c}"), ('\u{fc1a}', "\u{62e}\u{62d}"), ('\u{fc1b}', "\u{62e}\u{645}"),
+    ('\u{fc1c}', "\u{633}\u{62c}"), ('\u{fc1d}', "\u{633}\u{62d}"), ('\u{fc1e}', "\u{633}\u{62e}"),
+    ('\u{fc1f}', "\u{633}\u{645}"), ('\u{fc20}', "\u{635}\u{62d}"), ('\u{fc21}', "\u{635}\u{645}"),
+    ('\u{fc22}', "\u{636}\u{62c}"), ('\u{fc23}', "\u{636}\u{62d}"), ('\u{fc24}', "\u{636}\u{62e}"),
+    ('\u{fc25}', "\u{636}\u{645}"), ('\u{fc26}', "\u{637}\u{62d}"), ('\u{fc27}', "\u{637}\u{645}"),
+    ('\u{fc28}', "\u{638}\u{645}"), ('\u{fc29}', "\u{639}\u{62c}"), ('\u{fc2a}', "\u{639}\u{645}"),
+    ('\u{fc2b}', "\u{63a}\u{62c}"), ('\u{fc2c}', "\u{63a}\u{645}"), ('\u{fc2d}', "\u{641}\u{62c}"),
+    ('\u{fc2e}', "\u{641}\u{62d}"), ('\u{fc2f}', "\u{641}\u{62e}"), ('\u{fc30}', "\u{641}\u{645}"),
+    ('\u{fc31}', "\u{641}\u{649}"), ('\u{fc32}', "\u{641}\u{64a}"), ('\u{fc33}', "\u{642}\u{62d}"),
+    ('\u{fc34}', "\u{642}\u{645}"), ('\u{fc35}', "\u{642}\u{649}"), ('\u{fc36}', "\u{642}\u{64a}"),
+    ('\u{fc37}', "\u{643}\u{627}"), ('\u{fc38}', "\u{643}\u{62c}"), ('\u{fc39}', "\u{643}\u{62d}"),
+    ('\u{fc3a}', "\u{643}\u{62e}"), ('\u{fc3b}', "\u{643}\u{644}"), ('\u{fc3c}', "\u{643}\u{645}"),
+    ('\u{fc3d}', "\u{643}\u{649}"), ('\u{fc3e}', "\u{643}\u{64a}"), ('\u{fc3f}', "\u{644}\u{62c}"),
+    ('\u{fc40}', "\u{644}\u{62d}"), ('\u{fc41}', "\u{644}\u{62e}"), ('\u{fc42}', "\u{644}\u{645}"),
+    ('\u{fc43}', "\u{644}\u{649}"), ('\u{fc44}', "\u{644}\u{64a}"), ('\u{fc45}', "\u{645}\u{62c}"),
+    ('\u{fc46}', "\u{645}\u{62d}"), ('\u{fc47}', "\u{645}\u{62e}"), ('\u{fc48}', "\u{645}\u{645}"),
+    ('\u{fc49}', "\u{645}\u{649}"), ('\u{fc4a}', "\u{645}\u{64a}"), ('\u{fc4b}', "\u{646}\u{62c}"),
+    ('\u{fc4c}', "\u{646}\u{62d}"), ('\u{fc4d}', "\u{646}\u{62e}"), ('\u{fc4e}', "\u{646}\u{645}"),
+    ('\u{fc4f}', "\u{646}\u{649}"), ('\u{fc50}', "\u{646}\u{64a}"), ('\u{fc51}', "\u{647}\u{62c}"),
+    ('\u{fc52}', "\u{647}\u{645}"), ('\u{fc53}', "\u{647}\u{649}"), ('\u{fc54}', "\u{647}\u{64a}"),
+    ('\u{fc55}', "\u{64a}\u{62c}"), ('\u{fc56}', "\u{64a}\u{62d}"), ('\u{fc57}', "\u{64a}\u{62e}"),
+    ('\u{fc58}', "\u{64a}\u{645}"), ('\u{fc59}', "\u{64a}\u{649}"), ('\u{fc5a}', "\u{64a}\u{64a}"),
+    ('\u{fc5b}', "\u{630}\u{670}"), ('\u{fc5c}', "\u{631}\u{670}"), ('\u{fc5d}', "\u{649}\u{670}"),
+    ('\u{fc5e}', "\u{20}\u{64c}\u{651}"), ('\u{fc5f}', "\u{20}\u{64d}\u{651}"), ('\u{fc60}', "\u{20}\u{64e}\u{651}"),
+    ('\u{fc61}', "\u{20}\u{64f}\u{651}"), ('\u{fc62}', "\u{20}\u{650}\u{651}"), ('\u{fc63}', "\u{20}\u{651}\u{670}"),
+    ('\u{fc64}', "\u{64a}\u{654}\u{631}"), ('\u{fc65}', "\u{64a}\u{654}\u{632}"), ('\u{fc66}', "\u{64a}\u{654}\u{645}"),
+    ('\u{fc67}', "\u{64a}\u{654}\u{646}"), ('\u{fc68}', "\u{64a}\u{654}\u{649}"), ('\u{fc69}', "\u{64a}\u{654}\u{64a}"),
+    ('\u{fc6a}', "\u{628}\u{631}"), ('\u{fc6b}', "\u{628}\u{632}"), ('\u{fc6c}', "\u{628}\u{645}"),
+    ('\u{fc6d}', "\u{628}\u{646}"), ('\u{fc6e}', "\u{628}\u{649}"), ('\u{fc6f}', "\u{628}\u{64a}"),
+    ('\u{fc70}', "\u{62a}\u{631}"), ('\u{fc71}', "\u{62a}\u{632}"), ('\u{fc72}', "\u{62a}\u{645}"),
+    ('\u{fc73}', "\u{62a}\u{646}"), ('\u{fc74}', "\u{62a}\u{649}"), ('\u{fc75}', "\u{62a}\u{64a}"),
+    ('\u{fc76}', "\u{62b}\u{631}"), ('\u{fc77}', "\u{62b}\u{632}"), ('\u{fc78}', "\u{62b}\u{645}"),
+    ('\u{fc79}', "\u{62b}\u{646}"), ('\u{fc7a}', "\u{62b}\u{649}"), ('\u{fc7b}', "\u{62b}\u{64a}"),
+    ('\u{fc7c}', "\u{641}\u{649}"), ('\u{fc7d}', "\u{641}\u{64a}"), ('\u{fc7e}', "\u{642}\u{649}"),
+    ('\u{fc7f}', "\u{642}\u{64a}"), ('\u{fc80}', "\u{643}\u{627}"), ('\u{fc81}', "\u{643}\u{644}"),
+    ('\u{fc82}', "\u{643}\u{645}"), ('\u{fc83}', "\u{643}\u{649}"), ('\u{fc84}', "\u{643}\u{64a}"),
+    ('\u{fc85}', "\u{644}\u{645}"), ('\u{fc86}', "\u{644}\u{649}"), ('\u{fc87}', "\u{644}\u{64a}"),
+    ('\u{fc88}', "\u{645}\u{627}"), ('\u{fc89}', "\u{645}\u{645}"), ('\u{fc8a}', "\u{646}\u{631}"),
+    ('\u{fc8b}', "\u{646}\u{632}"), ('\u{fc8c}', "\u{646}\u{645}"), ('\u{fc8d}', "\u{646}\u{646}"),
+    ('\u{fc8e}', "\u{646}\u{649}"), ('\u{fc8f}', "\u{646}\u{64a}"), ('\u{fc90}', "\u{649}\u{670}"),
+    ('\u{fc91}', "\u{64a}\u{631}"), ('\u{fc92}', "\u{64a}\u{632}"), ('\u{fc93}', "\u{64a}\u{645}"),
+    ('\u{fc94}', "\u{64a}\u{646}"), ('\u{fc95}', "\u{64a}\u{649}"), ('\u{fc96}', "\u{64a}\u{64a}"),
+    ('\u{fc97}', "\u{64a}\u{654}\u{62c}"), ('\u{fc98}', "\u{64a}\u{654}\u{62d}"), ('\u{fc99}', "\u{64a}\u{654}\u{62e}"),
+    ('\u{fc9a}', "\u{64a}\u{654}\u{645}"), ('\u{fc9b}', "\u{64a}\u{654}\u{647}"), ('\u{fc9c}', "\u{628}\u{62c}"),
+    ('\u{fc9d}', "\u{628}\u{62d}"), ('\u{fc9e}', "\u{628}\u{62e}"), ('\u{fc9f}', "\u{628}\u{645}"),
+    ('\u{fca0}', "\u{628}\u{647}"), ('\u{fca1}', "\u{62a}\u{62c}"), ('\u{fca2}', "\u{62a}\u{62d}"),
+    ('\u{fca3}', "\u{62a}\u{62e}"), ('\u{fca4}', "\u{62a}\u{645}"), ('\u{fca5}', "\u{62a}\u{647}"),
+    ('\u{fca6}', "\u{62b}\u{645}"), ('\u{fca7}', "\u{62c}\u{62d}"), ('\u{fca8}', "\u{62c}\u{645}"),
+    ('\u{fca9}', "\u{62d}\u{62c}"), ('\u{fcaa}', "\u{62d}\u{645}"), ('\u{fcab}', "\u{62e}\u{62c}"),
+    ('\u{fcac}', "\u{62e}\u{645}"), ('\u{fcad}', "\u{633}\u{62c}"), ('\u{fcae}', "\u{633}\u{62d}"),
+    ('\u{fcaf}', "\u{633}\u{62e}"), ('\u{fcb0}', "\u{633}\u{645}"), ('\u{fcb1}', "\u{635}\u{62d}"),
+    ('\u{fcb2}', "\u{635}\u{62e}"), ('\u{fcb3}', "\u{635}\u{645}"), ('\u{fcb4}', "\u{636}\u{62c}"),
+    ('\u{fcb5}', "\u{636}\u{62d}"), ('\u{fcb6}', "\u{636}\u{62e}"), ('\u{fcb7}', "\u{636}\u{645}"),
+    ('\u{fcb8}', "\u{637}\u{62d}"), ('\u{fcb9}', "\u{638}\u{645}"), ('\u{fcba}', "\u{639}\u{62c}"),
+    ('\u{fcbb}', "\u{639}\u{645}"), ('\u{fcbc}', "\u{63a}\u{62c}"), ('\u{fcbd}', "\u{63a}\u{645}"),
+    ('\u{fcbe}', "\u{641}\u{62c}"), ('\u{fcbf}', "\u{641}\u{62d}"), ('\u{fcc0}', "\u{641}\u{62e}"),
+    ('\u{fcc1}', "\u{641}\u{645}"), ('\u{fcc2}', "\u{642}\u{62d}"), ('\u{fcc3}', "\u{642}\u{645}"),
+    ('\u{fcc4}', "\u{643}\u{62c}"), ('\u{fcc5}', "\u{643}\u{62d}"), ('\u{fcc6}', "\u{643}\u{62e}"),
+    ('\u{fcc7}', "\u{643}\u{644}"), ('\u{fcc8}', "\u{643}\u{645}"), ('\u{fcc9}', "\u{644}\u{62c}"),
+    ('\u{fcca}', "\u{644}\u{62d}"), ('\u{fccb}', "\u{644}\u{62e}"), ('\u{fccc}', "\u{644}\u{645}"),
+    ('\u{fccd}', "\u{644}\u{647}"), ('\u{fcce}', "\u{645}\u{62c}"), ('\u{fccf}', "\u{645}\u{62d}"),
+    ('\u{fcd0}', "\u{645}\u{62e}"), ('\u{fcd1}', "\u{645}\u{645}"), ('\u{fcd2}', "\u{646}\u{62c}"),
+    ('\u{fcd3}', "\u{646}\u{62d}"), ('\u{fcd4}', "\u{646}\u{62e}"), ('\u{fcd5}', "\u{646}\u{645}"),
+    ('\u{fcd6}', "\u{646}\u{647}"), ('\u{fcd7}', "\u{647}\u{62c}"), ('\u{fcd8}', "\u{647}\u{645}"),
+    ('\u{fcd9}', "\u{647}\u{670}"), ('\u{fcda}', "\u{64a}\u{62c}"), ('\u{fcdb}', "\u{64a}\u{62d}"),
+    ('\u{fcdc}', "\u{64a}\u{62e}"), ('\u{fcdd}', "\u{64a}\u{645}"), ('\u{fcde}', "\u{64a}\u{647}"),
+    ('\u{fcdf}', "\u{64a}\u{654}\u{645}"), ('\u{fce0}', "\u{64a}\u{654}\u{647}"), ('\u{fce1}', "\u{628}\u{645}"),
+    ('\u{fce2}', "\u{628}\u{647}"), ('\u{fce3}', "\u{62a}\u{645}"), ('\u{fce4}', "\u{62a}\u{647}"),
+    ('\u{fce5}', "\u{62b}\u{645}"), ('\u{fce6}', "\u{62b}\u{647}"), ('\u{fce7}', "\u{633}\u{645}"),
+    ('\u{fce8}', "\u{633}\u{647}"), ('\u{fce9}', "\u{634}\u{645}"), ('\u{fcea}', "\u{634}\u{647}"),
+    ('\u{fceb}', "\u{643}\u{644}"), ('\u{fcec}', "\u{643}\u{645}"), ('\u{fced}', "\u{644}\u{645}"),
+    ('\u{fcee}', "\u{646}\u{645}"), ('\u{fcef}', "\u{646}\u{647}"), ('\u{fcf0}', "\u{64a}\u{645}"),
+    ('\u{fcf1}', "\u{64a}\u{647}"), ('\u{fcf2}', "\u{640}\u{64e}\u{651}"), ('\u{fcf3}', "\u{640}\u{64f}\u{651}"),
+    ('\u{fcf4}', "\u{640}\u{650}\u{651}"), ('\u{fcf5}', "\u{637}\u{649}"), ('\u{fcf6}', "\u{637}\u{64a}"),
+    ('\u{fcf7}', "\u{639}\u{649}"), ('\u{fcf8}', "\u{639}\u{64a}"), ('\u{fcf9}', "\u{63a}\u{649}"),
+    ('\u{fcfa}', "\u{63a}\u{64a}"), ('\u{fcfb}', "\u{633}\u{649}"), ('\u{fcfc}', "\u{633}\u{64a}"),
+    ('\u{fcfd}', "\u{634}\u{649}"), ('\u{fcfe}', "\u{634}\u{64a}"), ('\u{fcff}', "\u{62d}\u{649}"),
+    ('\u{fd00}', "\u{62d}\u{64a}"), ('\u{fd01}', "\u{62c}\u{649}"), ('\u{fd02}', "\u{62c}\u{64a}"),
+    ('\u{fd03}', "\u{62e}\u{649}"), ('\u{fd04}', "\u{62e}\u{64a}"), ('\u{fd05}', "\u{635}\u{649}"),
+    ('\u{fd06}', "\u{635}\u{64a}"), ('\u{fd07}', "\u{636}\u{649}"), ('\u{fd08}', "\u{636}\u{64a}"),
+    ('\u{fd09}', "\u{634}\u{62c}"), ('\u{fd0a}', "\u{634}\u{62d}"), ('\u{fd0b}', "\u{634}\u{62e}"),
+    ('\u{fd0c}', "\u{634}\u{645}"), ('\u{fd0d}', "\u{634}\u{631}"), ('\u{fd0e}', "\u{633}\u{631}"),
+    ('\u{fd0f}', "\u{635}\u{631}"), ('\u{fd10}', "\u{636}\u{631}"), ('\u{fd11}', "\u{637}\u{649}"),
+    ('\u{fd12}', "\u{637}\u{64a}"), ('\u{fd13}', "\u{639}\u{649}"), ('\u{fd14}', "\u{639}\u{64a}"),
+    ('\u{fd15}', "\u{63a}\u{649}"), ('\u{fd16}', "\u{63a}\u{64a}"), ('\u{fd17}', "\u{633}\u{649}"),
+    ('\u{fd18}', "\u{633}\u{64a}"), ('\u{fd19}', "\u{634}\u{649}"), ('\u{fd1a}', "\u{634}\u{64a}"),
+    ('\u{fd1b}', "\u{62d}\u{649}"), ('\u{fd1c}', "\u{62d}\u{64a}"), ('\u{fd1d}', "\u{62c}\u{649}"),
+    ('\u{fd1e}', "\u{62c}\u{64a}"), ('\u{fd1f}', "\u{62e}\u{649}"), ('\u{fd20}', "\u{62e}\u{64a}"),
+    ('\u{fd21}', "\u{635}\u{649}"), ('\u{fd22}', "\u{635}\u{64a}"), ('\u{fd23}', "\u{636}\u{649}"),
+    ('\u{fd24}', "\u{636}\u{64a}"), ('\u{fd25}', "\u{634}\u{62c}"), ('\u{fd26}', "\u{634}\u{62d}"),
+    ('\u{fd27}', "\u{634}\u{62e}"), ('\u{fd28}', "\u{634}\u{645}"), ('\u{fd29}', "\u{634}\u{631}"),
+    ('\u{fd2a}', "\u{633}\u{631}"), ('\u{fd2b}', "\u{635}\u{631}"), ('\u{fd2c}', "\u{636}\u{631}"),
+    ('\u{fd2d}', "\u{634}\u{62c}"), ('\u{fd2e}', "\u{634}\u{62d}"), ('\u{fd2f}', "\u{634}\u{62e}"),
+    ('\u{fd30}', "\u{634}\u{645}"), ('\u{fd31}', "\u{633}\u{647}"), ('\u{fd32}', "\u{634}\u{647}"),
+    ('\u{fd33}', "\u{637}\u{645}"), ('\u{fd34}', "\u{633}\u{62c}"), ('\u{fd35}', "\u{633}\u{62d}"),
+    ('\u{fd36}', "\u{633}\u{62e}"), ('\u{fd37}', "\u{634}\u{62c}"), ('\u{fd38}', "\u{634}\u{62d}"),
+    ('\u{fd39}', "\u{634}\u{62e}"), ('\u{fd3a}', "\u{637}\u{645}"), ('\u{fd3b}', "\u{638}\u{645}"),
+    ('\u{fd3c}', "\u{627}\u{64b}"), ('\u{fd3d}', "\u{627}\u{64b}"), ('\u{fd50}', "\u{62a}\u{62c}\u{645}"),
+    ('\u{fd51}', "\u{62a}\u{62d}\u{62c}"), ('\u{fd52}', "\u{62a}\u{62d}\u{62c}"), ('\u{fd53}', "\u{62a}\u{62d}\u{645}"),
+    ('\u{fd54}', "\u{62a}\u{62e}\u{645}"), ('\u{fd55}', "\u{62a}\u{645}\u{62c}"), ('\u{fd56}', "\u{62a}\u{645}\u{62d}"),
+    ('\u{fd57}', "\u{62a}\u{645}\u{62e}"), ('\u{fd58}', "\u{62c}\u{645}\u{62d}"), ('\u{fd59}', "\u{62c}\u{645}\u{62d}"),
+    ('\u{fd5a}', "\u{62d}\u{645}\u{64a}"), ('\u{fd5b}', "\u{62d}\u{645}\u{649}"), ('\u{fd5c}', "\u{633}\u{62d}\u{62c}"),
+    ('\u{fd5d}', "\u{633}\u{62c}\u{62d}"), ('\u{fd5e}', "\u{633}\u{62c}\u{649}"), ('\u{fd5f}', "\u{633}\u{645}\u{62d}"),
+    ('\u{fd60}', "\u{633}\u{645}\u{62d}"), ('\u{fd61}', "\u{633}\u{645}\u{62c}"), ('\u{fd62}', "\u{633}\u{645}\u{645}"),
+    ('\u{fd63}', "\u{633}\u{645}\u{645}"), ('\u{fd64}', "\u{635}\u{62d}\u{62d}"), ('\u{fd65}', "\u{635}\u{62d}\u{62d}"),
+    ('\u{fd66}', "\u{635}\u{645}\u{645}"), ('\u{fd67}', "\u{634}\u{62d}\u{645}"), ('\u{fd68}', "\u{634}\u{62d}\u{645}"),
+    ('\u{fd69}', "\u{634}\u{62c}\u{64a}"), ('\u{fd6a}', "\u{634}\u{645}\u{62e}"), ('\u{fd6b}', "\u{634}\u{645}\u{62e}"),
+    ('\u{fd6c}', "\u{634}\u{645}\u{645}"), ('\u{fd6d}', "\u{634}\u{645}\u{645}"), ('\u{fd6e}', "\u{636}\u{62d}\u{649}"),
+    ('\u{fd6f}', "\u{636}\u{62e}\u{645}"), ('\u{fd70}', "\u{636}\u{62e}\u{645}"), ('\u{fd71}', "\u{637}\u{645}\u{62d}"),
+    ('\u{fd72}', "\u{637}\u{645}\u{62d}"), ('\u{fd73}', "\u{637}\u{645}\u{645}"), ('\u{fd74}', "\u{637}\u{645}\u{64a}"),
+    ('\u{fd75}', "\u{639}\u{62c}\u{645}"), ('\u{fd76}', "\u{639}\u{645}\u{645}"), ('\u{fd77}', "\u{639}\u{645}\u{645}"),
+    ('\u{fd78}', "\u{639}\u{645}\u{649}"), ('\u{fd79}', "\u{63a}\u{645}\u{645}"), ('\u{fd7a}', "\u{63a}\u{645}\u{64a}"),
+    ('\u{fd7b}', "\u{63a}\u{645}\u{649}"), ('\u{fd7c}', "\u{641}\u{62e}\u{645}"), ('\u{fd7d}', "\u{641}\u{62e}\u{645}"),
+    ('\u{fd7e}', "\u{642}\u{645}\u{62d}"), ('\u{fd7f}', "\u{642}\u{645}\u{645}"), ('\u{fd80}', "\u{644}\u{62d}\u{645}"),
+    ('\u{fd81}', "\u{644}\u{62d}\u{64a}"), ('\u{fd82}', "\u{644}\u{62d}\u{649}"), ('\u{fd83}', "\u{644}\u{62c}\u{62c}"),
+    ('\u{fd84}', "\u{644}\u{62c}\u{62c}"), ('\u{fd85}', "\u{644}\u{62e}\u{645}"), ('\u{fd86}', "\u{644}\u{62e}\u{645}"),
+    ('\u{fd87}', "\u{644}\u{645}\u{62d}"), ('\u{fd88}', "\u{644}\u{645}\u{62d}"), ('\u{fd89}', "\u{645}\u{62d}\u{62c}"),
+    ('\u{fd8a}', "\u{645}\u{62d}\u{645}"), ('\u{fd8b}', "\u{645}\u{62d}\u{64a}"), ('\u{fd8c}', "\u{645}\u{62c}\u{62d}"),
+    ('\u{fd8d}', "\u{645}\u{62c}\u{645}"), ('\u{fd8e}', "\u{645}\u{62e}\u{62c}"), ('\u{fd8f}', "\u{645}\u{62e}\u{645}"),
+    ('\u{fd92}', "\u{645}\u{62c}\u{62e}"), ('\u{fd93}', "\u{647}\u{645}\u{62c}"), ('\u{fd94}', "\u{647}\u{645}\u{645}"),
+    ('\u{fd95}', "\u{646}\u{62d}\u{645}"), ('\u{fd96}', "\u{646}\u{62d}\u{649}"), ('\u{fd97}', "\u{646}\u{62c}\u{645}"),
+    ('\u{fd98}', "\u{646}\u{62c}\u{645}"), ('\u{fd99}', "\u{646}\u{62c}\u{649}"), ('\u{fd9a}', "\u{646}\u{645}\u{64a}"),
+    ('\u{fd9b}', "\u{646}\u{645}\u{649}"), ('\u{fd9c}', "\u{64a}\u{645}\u{645}"), ('\u{fd9d}', "\u{64a}\u{645}\u{645}"),
+    ('\u{fd9e}', "\u{628}\u{62e}\u{64a}"), ('\u{fd9f}', "\u{62a}\u{62c}\u{64a}"), ('\u{fda0}', "\u{62a}\u{62c}\u{649}"),
+    ('\u{fda1}', "\u{62a}\u{62e}\u{64a}"), ('\u{fda2}', "\u{62a}\u{62e}\u{649}"), ('\u{fda3}', "\u{62a}\u{645}\u{64a}"),
+    ('\u{fda4}', "\u{62a}\u{645}\u{649}"), ('\u{fda5}', "\u{62c}\u{645}\u{64a}"), ('\u{fda6}', "\u{62c}\u{62d}\u{649}"),
+    ('\u{fda7}', "\u{62c}\u{645}\u{649}"), ('\u{fda8}', "\u{633}\u{62e}\u{649}"), ('\u{fda9}', "\u{635}\u{62d}\u{64a}"),
+    ('\u{fdaa}', "\u{634}\u{62d}\u{64a}"), ('\u{fdab}', "\u{636}\u{62d}\u{64a}"), ('\u{fdac}', "\u{644}\u{62c}\u{64a}"),
+    ('\u{fdad}', "\u{644}\u{645}\u{64a}"), ('\u{fdae}', "\u{64a}\u{62d}\u{64a}"), ('\u{fdaf}', "\u{64a}\u{62c}\u{64a}"),
+    ('\u{fdb0}', "\u{64a}\u{645}\u{64a}"), ('\u{fdb1}', "\u{645}\u{645}\u{64a}"), ('\u{fdb2}', "\u{642}\u{645}\u{64a}"),
+    ('\u{fdb3}', "\u{646}\u{62d}\u{64a}"), ('\u{fdb4}', "\u{642}\u{645}\u{62d}"), ('\u{fdb5}', "\u{644}\u{62d}\u{645}"),
+    ('\u{fdb6}', "\u{639}\u{645}\u{64a}"), ('\u{fdb7}', "\u{643}\u{645}\u{64a}"), ('\u{fdb8}', "\u{646}\u{62c}\u{62d}"),
+    ('\u{fdb9}', "\u{645}\u{62e}\u{64a}"), ('\u{fdba}', "\u{644}\u{62c}\u{645}"), ('\u{fdbb}', "\u{643}\u{645}\u{645}"),
+    ('\u{fdbc}', "\u{644}\u{62c}\u{645}"), ('\u{fdbd}', "\u{646}\u{62c}\u{62d}"), ('\u{fdbe}', "\u{62c}\u{62d}\u{64a}"),
+    ('\u{fdbf}', "\u{62d}\u{62c}\u{64a}"), ('\u{fdc0}', "\u{645}\u{62c}\u{64a}"), ('\u{fdc1}', "\u{641}\u{645}\u{64a}"),
+    ('\u{fdc2}', "\u{628}\u{62d}\u{64a}"), ('\u{fdc3}', "\u{643}\u{645}\u{645}"), ('\u{fdc4}', "\u{639}\u{62c}\u{645}"),
+    ('\u{fdc5}', "\u{635}\u{645}\u{645}"), ('\u{fdc6}', "\u{633}\u{62e}\u{64a}"), ('\u{fdc7}', "\u{646}\u{62c}\u{64a}"),
+    ('\u{fdf0}', "\u{635}\u{644}\u{6d2}"), ('\u{fdf1}', "\u{642}\u{644}\u{6d2}"), ('\u{fdf2}', "\u{627}\u{644}\u{644}\u{647}"),
+    ('\u{fdf3}', "\u{627}\u{643}\u{628}\u{631}"), ('\u{fdf4}', "\u{645}\u{62d}\u{645}\u{62f}"), ('\u{fdf5}', "\u{635}\u{644}\u{639}\u{645}"),
+    ('\u{fdf6}', "\u{631}\u{633}\u{648}\u{644}"), ('\u{fdf7}', "\u{639}\u{644}\u{64a}\u{647}"), ('\u{fdf8}', "\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{fdf9}', "\u{635}\u{644}\u{649}"), ('\u{fdfa}', "\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64a}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}"), ('\u{fdfb}', "\u{62c}\u{644}\u{20}\u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    ('\u{fdfc}', "\u{631}\u{6cc}\u{627}\u{644}"), ('\u{fe10}', "\u{2c}"), ('\u{fe11}', "\u{3001}"),
+    ('\u{fe12}', "\u{3002}"), ('\u{fe13}', "\u{3a}"), ('\u{fe14}', "\u{3b}"),
+    ('\u{fe15}', "\u{21}"), ('\u{fe16}', "\u{3f}"), ('\u{fe17}', "\u{3016}"),
+    ('\u{fe18}', "\u{3017}"), ('\u{fe19}', "\u{2e}\u{2e}\u{2e}"), ('\u{fe30}', "\u{2e}\u{2e}"),
+    ('\u{fe31}', "\u{2014}"), ('\u{fe32}', "\u{2013}"), ('\u{fe33}', "\u{5f}"),
+    ('\u{fe34}', "\u{5f}"), ('\u{fe35}', "\u{28}"), ('\u{fe36}', "\u{29}"),
+    ('\u{fe37}', "\u{7b}"), ('\u{fe38}', "\u{7d}"), ('\u{fe39}', "\u{3014}"),
+    ('\u{fe3a}', "\u{3015}"), ('\u{fe3b}', "\u{3010}"), ('\u{fe3c}', "\u{3011}"),
+    ('\u{fe3d}', "\u{300a}"), ('\u{fe3e}', "\u{300b}"), ('\u{fe3f}', "\u{3008}"),
+    ('\u{fe40}', "\u{3009}"), ('\u{fe41}', "\u{300c}"), ('\u{fe42}', "\u{300d}"),
+    ('\u{fe43}', "\u{300e}"), ('\u{fe44}', "\u{300f}"), ('\u{fe47}', "\u{5b}"),
+    ('\u{fe48}', "\u{5d}"), ('\u{fe49}', "\u{20}\u{305}"), ('\u{fe4a}', "\u{20}\u{305}"),
+    ('\u{fe4b}', "\u{20}\u{305}"), ('\u{fe4c}', "\u{20}\u{305}"), ('\u{fe4d}', "\u{5f}"),
+    ('\u{fe4e}', "\u{5f}"), ('\u{fe4f}', "\u{5f}"), ('\u{fe50}', "\u{2c}"),
+    ('\u{fe51}', "\u{3001}"), ('\u{fe52}', "\u{2e}"), ('\u{fe54}', "\u{3b}"),
+    ('\u{fe55}', "\u{3a}"), ('\u{fe56}', "\u{3f}"), ('\u{fe57}', "\u{21}"),
+    ('\u{fe58}', "\u{2014}"), ('\u{fe59}', "\u{28}"), ('\u{fe5a}', "\u{29}"),
+    ('\u{fe5b}', "\u{7b}"), ('\u{fe5c}', "\u{7d}"), ('\u{fe5d}', "\u{3014}"),
+    ('\u{fe5e}', "\u{3015}"), ('\u{fe5f}', "\u{23}"), ('\u{fe60}', "\u{26}"),
+    ('\u{fe61}', "\u{2a}"), ('\u{fe62}', "\u{2b}"), ('\u{fe63}', "\u{2d}"),
+    ('\u{fe64}', "\u{3c}"), ('\u{fe65}', "\u{3e}"), ('\u{fe66}', "\u{3d}"),
+    ('\u{fe68}', "\u{5c}"), ('\u{fe69}', "\u{24}"), ('\u{fe6a}', "\u{25}"),
+    ('\u{fe6b}', "\u{40}"), ('\u{fe70}', "\u{20}\u{64b}"), ('\u{fe71}', "\u{640}\u{64b}"),
+    ('\u{fe72}', "\u{20}\u{64c}"), ('\u{fe74}', "\u{20}\u{64d}"), ('\u{fe76}', "\u{20}\u{64e}"),
+    ('\u{fe77}', "\u{640}\u{64e}"), ('\u{fe78}', "\u{20}\u{64f}"), ('\u{fe79}', "\u{640}\u{64f}"),
+    ('\u{fe7a}', "\u{20}\u{650}"), ('\u{fe7b}', "\u{640}\u{650}"), ('\u{fe7c}', "\u{20}\u{651}"),
+    ('\u{fe7d}', "\u{640}\u{651}"), ('\u{fe7e}', "\u{20}\u{652}"), ('\u{fe7f}', "\u{640}\u{652}"),
+    ('\u{fe80}', "\u{621}"), ('\u{fe81}', "\u{627}\u{653}"), ('\u{fe82}', "\u{627}\u{653}"),
+    ('\u{fe83}', "\u{627}\u{654}"), ('\u{fe84}', "\u{627}\u{654}"), ('\u{fe85}', "\u{648}\u{654}"),
+    ('\u{fe86}', "\u{648}\u{654}"), ('\u{fe87}', "\u{627}\u{655}"), ('\u{fe88}', "\u{627}\u{655}"),
+    ('\u{fe89}', "\u{64a}\u{654}"), ('\u{fe8a}', "\u{64a}\u{654}"), ('\u{fe8b}', "\u{64a}\u{654}"),
+    ('\u{fe8c}', "\u{64a}\u{654}"), ('\u{fe8d}', "\u{627}"), ('\u{fe8e}', "\u{627}"),
+    ('\u{fe8f}', "\u{628}"), ('\u{fe90}', "\u{628}"), ('\u{fe91}', "\u{628}"),
+    ('\u{fe92}', "\u{628}"), ('\u{fe93}', "\u{629}"), ('\u{fe94}', "\u{629}"),
+    ('\u{fe95}', "\u{62a}"), ('\u{fe96}', "\u{62a}"), ('\u{fe97}', "\u{62a}"),
+    ('\u{fe98}', "\u{62a}"), ('\u{fe99}', "\u{62b}"), ('\u{fe9a}', "\u{62b}"),
+    ('\u{fe9b}', "\u{62b}"), ('\u{fe9c}', "\u{62b}"), ('\u{fe9d}', "\u{62c}"),
+    ('\u{fe9e}', "\u{62c}"), ('\u{fe9f}', "\u{62c}"), ('\u{fea0}', "\u{62c}"),
+    ('\u{fea1}', "\u{62d}"), ('\u{fea2}', "\u{62d}"), ('\u{fea3}', "\u{62d}"),
+    ('\u{fea4}', "\u{62d}"), ('\u{fea5}', "\u{62e}"), ('\u{fea6}', "\u{62e}"),
+    ('\u{fea7}', "\u{62e}"), ('\u{fea8}', "\u{62e}"), ('\u{fea9}', "\u{62f}"),
+    ('\u{feaa}', "\u{62f}"), ('\u{feab}', "\u{630}"), ('\u{feac}', "\u{630}"),
+    ('\u{fead}', "\u{631}"), ('\u{feae}', "\u{631}"), ('\u{feaf}', "\u{632}"),
+    ('\u{feb0}', "\u{632}"), ('\u{feb1}', "\u{633}"), ('\u{feb2}', "\u{633}"),
+    ('\u{feb3}', "\u{633}"), ('\u{feb4}', "\u{633}"), ('\u{feb5}', "\u{634}"),
+    ('\u{feb6}', "\u{634}"), ('\u{feb7}', "\u{634}"), ('\u{feb8}', "\u{634}"),
+    ('\u{feb9}', "\u{635}"), ('\u{feba}', "\u{635}"), ('\u{febb}', "\u{635}"),
+    ('\u{febc}', "\u{635}"), ('\u{febd}', "\u{636}"), ('\u{febe}', "\u{636}"),
+    ('\u{febf}', "\u{636}"), ('\u{fec0}', "\u{636}"), ('\u{fec1}', "\u{637}"),
+    ('\u{fec2}', "\u{637}"), ('\u{fec3}', "\u{637}"), ('\u{fec4}', "\u{637}"),
+    ('\u{fec5}', "\u{638}"), ('\u{fec6}', "\u{638}"), ('\u{fec7}', "\u{638}"),
+    ('\u{fec8}', "\u{638}"), ('\u{fec9}', "\u{639}"), ('\u{feca}', "\u{639}"),
+    ('\u{fecb}', "\u{639}"), ('\u{fecc}', "\u{639}"), ('\u{fecd}', "\u{63a}"),
+    ('\u{fece}', "\u{63a}"), ('\u{fecf}', "\u{63a}"), ('\u{fed0}', "\u{63a}"),
+    ('\u{fed1}', "\u{641}"), ('\u{fed2}', "\u{641}"), ('\u{fed3}', "\u{641}"),
+    ('\u{fed4}', "\u{641}"), ('\u{fed5}', "\u{642}"), ('\u{fed6}', "\u{642}"),
+    ('\u{fed7}', "\u{642}"), ('\u{fed8}', "\u{642}"), ('\u{fed9}', "\u{643}"),
+    ('\u{feda}', "\u{643}"), ('\u{fedb}', "\u{643}"), ('\u{fedc}', "\u{643}"),
+    ('\u{fedd}', "\u{644}"), ('\u{fede}', "\u{644}"), ('\u{fedf}', "\u{644}"),
+    ('\u{fee0}', "\u{644}"), ('\u{fee1}', "\u{645}"), ('\u{fee2}', "\u{645}"),
+    ('\u{fee3}', "\u{645}"), ('\u{fee4}', "\u{645}"), ('\u{fee5}', "\u{646}"),
+    ('\u{fee6}', "\u{646}"), ('\u{fee7}', "\u{646}"), ('\u{fee8}', "\u{646}"),
+    ('\u{fee9}', "\u{647}"), ('\u{feea}', "\u{647}"), ('\u{feeb}', "\u{647}"),
+    ('\u{feec}', "\u{647}"), ('\u{feed}', "\u{648}"), ('\u{feee}', "\u{648}"),
+    ('\u{feef}', "\u{649}"), ('\u{fef0}', "\u{649}"), ('\u{fef1}', "\u{64a}"),
+    ('\u{fef2}', "\u{64a}"), ('\u{fef3}', "\u{64a}"), ('\u{fef4}', "\u{64a}"),
+    ('\u{fef5}', "\u{644}\u{627}\u{653}"), ('\u{fef6}', "\u{644}\u{627}\u{653}"), ('\u{fef7}', "\u{644}\u{627}\u{654}"),
+    ('\u{fef8}', "\u{644}\u{627}\u{654}"), ('\u{fef9}', "\u{644}\u{627}\u{655}"), ('\u{fefa}', "\u{644}\u{627}\u{655}"),
+    ('\u{fefb}', "\u{644}\u{627}"), ('\u{fefc}', "\u{644}\u{627}"), ('\u{ff01}', "\u{21}"),
+    ('\u{ff02}', "\u{22}"), ('\u{ff03}', "\u{23}"), ('\u{ff04}', "\u{24}"),
+    ('\u{ff05}', "\u{25}"), ('\u{ff06}', "\u{26}"), ('\u{ff07}', "\u{27}"),
+    ('\u{ff08}', "\u{28}"), ('\u{ff09}', "\u{29}"), ('\u{ff0a}', "\u{2a}"),
+    ('\u{ff0b}', "\u{2b}"), ('\u{ff0c}', "\u{2c}"), ('\u{ff0d}', "\u{2d}"),
+    ('\u{ff0e}', "\u{2e}"), ('\u{ff0f}', "\u{2f}"), ('\u{ff10}', "\u{30}"),
+    ('\u{ff11}', "\u{31}"), ('\u{ff12}', "\u{32}"), ('\u{ff13}', "\u{33}"),
+    ('\u{ff14}', "\u{34}"), ('\u{ff15}', "\u{35}"), ('\u{ff16}', "\u{36}"),
+    ('\u{ff17}', "\u{37}"), ('\u{ff18}', "\u{38}"), ('\u{ff19}', "\u{39}"),
+    ('\u{ff1a}', "\u{3a}"), ('\u{ff1b}', "\u{3b}"), ('\u{ff1c}', "\u{3c}"),
+    ('\u{ff1d}', "\u{3d}"), ('\u{ff1e}', "\u{3e}"), ('\u{ff1f}', "\u{3f}"),
+    ('\u{ff20}', "\u{40}"), ('\u{ff21}', "\u{41}"), ('\u{ff22}', "\u{42}"),
+    ('\u{ff23}', "\u{43}"), ('\u{ff24}', "\u{44}"), ('\u{ff25}', "\u{45}"),
+    ('\u{ff26}', "\u{46}"), ('\u{ff27}', "\u{47}"), ('\u{ff28}', "\u{48}"),
+    ('\u{ff29}', "\u{49}"), ('\u{ff2a}', "\u{4a}"), ('\u{ff2b}', "\u{4b}"),
+    ('\u{ff2c}', "\u{4c}"), ('\u{ff2d}', "\u{4d}"), ('\u{ff2e}', "\u{4e}"),
+    ('\u{ff2f}', "\u{4f}"), ('\u{ff30}', "\u{50}"), ('\u{ff31}', "\u{51}"),
+    ('\u{ff32}', "\u{52}"), ('\u{ff33}', "\u{53}"), ('\u{ff34}', "\u{54}"),
+    ('\u{ff35}', "\u{55}"), ('\u{ff36}', "\u{56}"), ('\u{ff37}', "\u{57}"),
+    ('\u{ff38}', "\u{58}"), ('\u{ff39}', "\u{59}"), ('\u{ff3a}', "\u{5a}"),
+    ('\u{ff3b}', "\u{5b}"), ('\u{ff3c}', "\u{5c}"), ('\u{ff3d}', "\u{5d}"),
+    ('\u{ff3e}', "\u{5e}"), ('\u{ff3f}', "\u{5f}"), ('\u{ff40}', "\u{60}"),
+    ('\u{ff41}', "\u{61}"), ('\u{ff42}', "\u{62}"), ('\u{ff43}', "\u{63}"),
+    ('\u{ff44}', "\u{64}"), ('\u{ff45}', "\u{65}"), ('\u{ff46}', "\u{66}"),
+    ('\u{ff47}', "\u{67}"), ('\u{ff48}', "\u{68}"), ('\u{ff49}', "\u{69}"),
+    ('\u{ff4a}', "\u{6a}"), ('\u{ff4b}', "\u{6b}"), ('\u{ff4c}', "\u{6c}"),
+    ('\u{ff4d}', "\u{6d}"), ('\u{ff4e}', "\u{6e}"), ('\u{ff4f}', "\u{6f}"),
+    ('\u{ff50}', "\u{70}"), ('\u{ff51}', "\u{71}"), ('\u{ff52}', "\u{72}"),
+    ('\u{ff53}', "\u{73}"), ('\u{ff54}', "\u{74}"), ('\u{ff55}', "\u{75}"),
+    ('\u{ff56}', "\u{76}"), ('\u{ff57}', "\u{77}"), ('\u{ff58}', "\u{78}"),
+    ('\u{ff59}', "\u{79}"), ('\u{ff5a}', "\u{7a}"), ('\u{ff5b}', "\u{7b}"),
+    ('\u{ff5c}', "\u{7c}"), ('\u{ff5d}', "\u{7d}"), ('\u{ff5e}', "\u{7e}"),
+    ('\u{ff5f}', "\u{2985}"), ('\u{ff60}', "\u{2986}"), ('\u{ff61}', "\u{3002}"),
+    ('\u{ff62}', "\u{300c}"), ('\u{ff63}', "\u{300d}"), ('\u{ff64}', "\u{3001}"),
+    ('\u{ff65}', "\u{30fb}"), ('\u{ff66}', "\u{30f2}"), ('\u{ff67}', "\u{30a1}"),
+    ('\u{ff68}', "\u{30a3}"), ('\u{ff69}', "\u{30a5}"), ('\u{ff6a}', "\u{30a7}"),
+    ('\u{ff6b}', "\u{30a9}"), ('\u{ff6c}', "\u{30e3}"), ('\u{ff6d}', "\u{30e5}"),
+    ('\u{ff6e}', "\u{30e7}"), ('\u{ff6f}', "\u{30c3}"), ('\u{ff70}', "\u{30fc}"),
+    ('\u{ff71}', "\u{30a2}"), ('\u{ff72}', "\u{30a4}"), ('\u{ff73}', "\u{30a6}"),
+    ('\u{ff74}', "\u{30a8}"), ('\u{ff75}', "\u{30aa}"), ('\u{ff76}', "\u{30ab}"),
+    ('\u{ff77}', "\u{30ad}"), ('\u{ff78}', "\u{30af}"), ('\u{ff79}', "\u{30b1}"),
+    ('\u{ff7a}', "\u{30b3}"), ('\u{ff7b}', "\u{30b5}"), ('\u{ff7c}', "\u{30b7}"),
+    ('\u{ff7d}', "\u{30b9}"), ('\u{ff7e}', "\u{30bb}"), ('\u{ff7f}', "\u{30bd}"),
+    ('\u{ff80}', "\u{30bf}"), ('\u{ff81}', "\u{30c1}"), ('\u{ff82}', "\u{30c4}"),
+    ('\u{ff83}', "\u{30c6}"), ('\u{ff84}', "\u{30c8}"), ('\u{ff85}', "\u{30ca}"),
+    ('\u{ff86}', "\u{30cb}"), ('\u{ff87}', "\u{30cc}"), ('\u{ff88}', "\u{30cd}"),
+    ('\u{ff89}', "\u{30ce}"), ('\u{ff8a}', "\u{30cf}"), ('\u{ff8b}', "\u{30d2}"),
+    ('\u{ff8c}', "\u{30d5}"), ('\u{ff8d}', "\u{30d8}"), ('\u{ff8e}', "\u{30db}"),
+    ('\u{ff8f}', "\u{30de}"), ('\u{ff90}', "\u{30df}"), ('\u{ff91}', "\u{30e0}"),
+    ('\u{ff92}', "\u{30e1}"), ('\u{ff93}', "\u{30e2}"), ('\u{ff94}', "\u{30e4}"),
+    ('\u{ff95}', "\u{30e6}"), ('\u{ff96}', "\u{30e8}"), ('\u{ff97}', "\u{30e9}"),
+    ('\u{ff98}', "\u{30ea}"), ('\u{ff99}', "\u{30eb}"), ('\u{ff9a}', "\u{30ec}"),
+    ('\u{ff9b}', "\u{30ed}"), ('\u{ff9c}', "\u{30ef}"), ('\u{ff9d}', "\u{30f3}"),
+    ('\u{ff9e}', "\u{3099}"), ('\u{ff9f}', "\u{309a}"), ('\u{ffa0}', "\u{1160}"),
+    ('\u{ffa1}', "\u{1100}"), ('\u{ffa2}', "\u{1101}"), ('\u{ffa3}', "\u{11aa}"),
+    ('\u{ffa4}', "\u{1102}"), ('\u{ffa5}', "\u{11ac}"), ('\u{ffa6}', "\u{11ad}"),
+    ('\u{ffa7}', "\u{1103}"), ('\u{ffa8}', "\u{1104}"), ('\u{ffa9}', "\u{1105}"),
+    ('\u{ffaa}', "\u{11b0}"), ('\u{ffab}', "\u{11b1}"), ('\u{ffac}', "\u{11b2}"),
+    ('\u{ffad}', "\u{11b3}"), ('\u{ffae}', "\u{11b4}"), ('\u{ffaf}', "\u{11b5}"),
+    ('\u{ffb0}', "\u{111a}"), ('\u{ffb1}', "\u{1106}"), ('\u{ffb2}', "\u{1107}"),
+    ('\u{ffb3}', "\u{1108}"), ('\u{ffb4}', "\u{1121}"), ('\u{ffb5}', "\u{1109}"),
+    ('\u{ffb6}', "\u{110a}"), ('\u{ffb7}', "\u{110b}"), ('\u{ffb8}', "\u{110c}"),
+    ('\u{ffb9}', "\u{110d}"), ('\u{ffba}', "\u{110e}"), ('\u{ffbb}', "\u{110f}"),
+    ('\u{ffbc}', "\u{1110}"), ('\u{ffbd}', "\u{1111}"), ('\u{ffbe}', "\u{1112}"),
+    ('\u{ffc2}', "\u{1161}"), ('\u{ffc3}', "\u{1162}"), ('\u{ffc4}', "\u{1163}"),
+    ('\u{ffc5}', "\u{1164}"), ('\u{ffc6}', "\u{1165}"), ('\u{ffc7}', "\u{1166}"),
+    ('\u{ffca}', "\u{1167}"), ('\u{ffcb}', "\u{1168}"), ('\u{ffcc}', "\u{1169}"),
+    ('\u{ffcd}', "\u{116a}"), ('\u{ffce}', "\u{116b}"), ('\u{ffcf}', "\u{116c}"),
+    ('\u{ffd2}', "\u{116d}"), ('\u{ffd3}', "\u{116e}"), ('\u{ffd4}', "\u{116f}"),
+    ('\u{ffd5}', "\u{1170}"), ('\u{ffd6}', "\u{1171}"), ('\u{ffd7}', "\u{1172}"),
+    ('\u{ffda}', "\u{1173}"), ('\u{ffdb}', "\u{1174}"), ('\u{ffdc}', "\u{1175}"),
+    ('\u{ffe0}', "\u{a2}"), ('\u{ffe1}', "\u{a3}"), ('\u{ffe2}', "\u{ac}"),
+    ('\u{ffe3}', "\u{20}\u{304}"), ('\u{ffe4}', "\u{a6}"), ('\u{ffe5}', "\u{a5}"),
+    ('\u{ffe6}', "\u{20a9}"), ('\u{ffe8}', "\u{2502}"), ('\u{ffe9}', "\u{2190}"),
+    ('\u{ffea}', "\u{2191}"), ('\u{ffeb}', "\u{2192}"), ('\u{ffec}', "\u{2193}"),
+    ('\u{ffed}', "\u{25a0}"), ('\u{ffee}', "\u{25cb}"), ('\u{10781}', "\u{2d0}"),
+    ('\u{10782}', "\u{2d1}"), ('\u{10783}', "\u{e6}"), ('\u{10784}', "\u{299}"),
+    ('\u{10785}', "\u{253}"), ('\u{10787}', "\u{2a3}"), ('\u{10788}', "\u{ab66}"),
+    ('\u{10789}', "\u{2a5}"), ('\u{1078a}', "\u{2a4}"), ('\u{1078b}', "\u{256}"),
+    ('\u{1078c}', "\u{257}"), ('\u{1078d}', "\u{1d91}"), ('\u{1078e}', "\u{258}"),
+    ('\u{1078f}', "\u{25e}"), ('\u{10790}', "\u{2a9}"), ('\u{10791}', "\u{264}"),
+    ('\u{10792}', "\u{262}"), ('\u{10793}', "\u{260}"), ('\u{10794}', "\u{29b}"),
+    ('\u{10795}', "\u{127}"), ('\u{10796}', "\u{29c}"), ('\u{10797}', "\u{267}"),
+    ('\u{10798}', "\u{284}"), ('\u{10799}', "\u{2aa}"), ('\u{1079a}', "\u{2ab}"),
+    ('\u{1079b}', "\u{26c}"), ('\u{1079c}', "\u{1df04}"), ('\u{1079d}', "\u{a78e}"),
+    ('\u{1079e}', "\u{26e}"), ('\u{1079f}', "\u{1df05}"), ('\u{107a0}', "\u{28e}"),
+    ('\u{107a1}', "\u{1df06}"), ('\u{107a2}', "\u{f8}"), ('\u{107a3}', "\u{276}"),
+    ('\u{107a4}', "\u{277}"), ('\u{107a5}', "\u{71}"), ('\u{107a6}', "\u{27a}"),
+    ('\u{107a7}', "\u{1df08}"), ('\u{107a8}', "\u{27d}"), ('\u{107a9}', "\u{27e}"),
+    ('\u{107aa}', "\u{280}"), ('\u{107ab}', "\u{2a8}"), ('\u{107ac}', "\u{2a6}"),
+    ('\u{107ad}', "\u{ab67}"), ('\u{107ae}', "\u{2a7}"), ('\u{107af}', "\u{288}"),
+    ('\u{107b0}', "\u{2c71}"), ('\u{107b2}', "\u{28f}"), ('\u{107b3}', "\u{2a1}"),
+    ('\u{107b4}', "\u{2a2}"), ('\u{107b5}', "\u{298}"), ('\u{107b6}', "\u{1c0}"),
+    ('\u{107b7}', "\u{1c1}"), ('\u{107b8}', "\u{1c2}"), ('\u{107b9}', "\u{1df0a}"),
+    ('\u{107ba}', "\u{1df1e}"), ('\u{1d400}', "\u{41}"), ('\u{1d401}', "\u{42}"),
+    ('\u{1d402}', "\u{43}"), ('\u{1d403}', "\u{44}"), ('\u{1d404}', "\u{45}"),
+    ('\u{1d405}', "\u{46}"), ('\u{1d406}', "\u{47}"), ('\u{1d407}', "\u{48}"),
+    ('\u{1d408}', "\u{49}"), ('\u{1d409}', "\u{4a}"), ('\u{1d40a}', "\u{4b}"),
+    ('\u{1d40b}', "\u{4c}"), ('\u{1d40c}', "\u{4d}"), ('\u{1d40d}', "\u{4e}"),
+    ('\u{1d40e}', "\u{4f}"), ('\u{1d40f}', "\u{50}"), ('\u{1d410}', "\u{51}"),
+    ('\u{1d411}', "\u{52}"), ('\u{1d412}', "\u{53}"), ('\u{1d413}', "\u{54}"),
+    ('\u{1d414}', "\u{55}"), ('\u{1d415}', "\u{56}"), ('\u{1d416}', "\u{57}"),
+    ('\u{1d417}', "\u{58}"), ('\u{1d418}', "\u{59}"), ('\u{1d419}', "\u{5a}"),
+    ('\u{1d41a}', "\u{61}"), ('\u{1d41b}', "\u{62}"), ('\u{1d41c}', "\u{63}"),
+    ('\u{1d41d}', "\u{64}"), ('\u{1d41e}', "\u{65}"), ('\u{1d41f}', "\u{66}"),
+    ('\u{1d420}', "\u{67}"), ('\u{1d421}', "\u{68}"), ('\u{1d422}', "\u{69}"),
+    ('\u{1d423}', "\u{6a}"), ('\u{1d424}', "\u{6b}"), ('\u{1d425}', "\u{6c}"),
+    ('\u{1d426}', "\u{6d}"), ('\u{1d427}', "\u{6e}"), ('\u{1d428}', "\u{6f}"),
+    ('\u{1d429}', "\u{70}"), ('\u{1d42a}', "\u{71}"), ('\u{1d42b}', "\u{72}"),
+    ('\u{1d42c}', "\u{73}"), ('\u{1d42d}', "\u{74}"), ('\u{1d42e}', "\u{75}"),
+    ('\u{1d42f}', "\u{76}"), ('\u{1d430}', "\u{77}"), ('\u{1d431}', "\u{78}"),
+    ('\u{1d432}', "\u{79}"), ('\u{1d433}', "\u{7a}"), ('\u{1d434}', "\u{41}"),
+    ('\u{1d435}', "\u{42}"), ('\u{1d436}', "\u{43}"), ('\u{1d437}', "\u{44}"),
+    ('\u{1d438}', "\u{45}"), ('\u{1d439}', "\u{46}"), ('\u{1d43a}', "\u{47}"),
+    ('\u{1d43b}', "\u{48}"), ('\u{1d43c}', "\u{49}"), ('\u{1d43d}', "\u{4a}"),
+    ('\u{1d43e}', "\u{4b}"), ('\u{1d43f}', "\u{4c}"), ('\u{1d440}', "\u{4d}"),
+    ('\u{1d441}', "\u{4e}"), ('\u{1d442}', "\u{4f}"), ('\u{1d443}', "\u{50}"),
+    ('\u{1d444}', "\u{51}"), ('\u{1d445}', "\u{52}"), ('\u{1d446}', "\u{53}"),
+    ('\u{1d447}', "\u{54}"), ('\u{1d448}', "\u{55}"), ('\u{1d449}', "\u{56}"),
+    ('\u{1d44a}', "\u{57}"), ('\u{1d44b}', "\u{58}"), ('\u{1d44c}', "\u{59}"),
+    ('\u{1d44d}', "\u{5a}"), ('\u{1d44e}', "\u{61}"), ('\u{1d44f}', "\u{62}"),
+    ('\u{1d450}', "\u{63}"), ('\u{1d451}', "\u{64}"), ('\u{1d452}', "\u{65}"),
+    ('\u{1d453}', "\u{66}"), ('\u{1d454}', "\u{67}"), ('\u{1d456}', "\u{69}"),
+    ('\u{1d457}', "\u{6a}"), ('\u{1d458}', "\u{6b}"), ('\u{1d459}', "\u{6c}"),
+    ('\u{1d45a}', "\u{6d}"), ('\u{1d45b}', "\u{6e}"), ('\u{1d45c}', "\u{6f}"),
+    ('\u{1d45d}', "\u{70}"), ('\u{1d45e}', "\u{71}"), ('\u{1d45f}', "\u{72}"),
+    ('\u{1d460}', "\u{73}"), ('\u{1d461}', "\u{74}"), ('\u{1d462}', "\u{75}"),
+    ('\u{1d463}', "\u{76}"), ('\u{1d464}', "\u{77}"), ('\u{1d465}', "\u{78}"),
+    ('\u{1d466}', "\u{79}"), ('\u{1d467}', "\u{7a}"), ('\u{1d468}', "\u{41}"),
+    ('\u{1d469}', "\u{42}"), ('\u{1d46a}', "\u{43}"), ('\u{1d46b}', "\u{44}"),
+    ('\u{1d46c}', "\u{45}"), ('\u{1d46d}', "\u{46}"), ('\u{1d46e}', "\u{47}"),
+    ('\u{1d46f}', "\u{48}"), ('\u{1d470}', "\u{49}"), ('\u{1d471}', "\u{4a}"),
+    ('\u{1d472}', "\u{4b}"), ('\u{1d473}', "\u{4c}"), ('\u{1d474}', "\u{4d}"),
+    ('\u{1d475}', "\u{4e}"), ('\u{1d476}', "\u{4f}"), ('\u{1d477}', "\u{50}"),
+    ('\u{1d478}', "\u{51}"), ('\u{1d479}', "\u{52}"), ('\u{1d47a}', "\u{53}"),
+    ('\u{1d47b}', "\u{54}"), ('\u{1d47c}', "\u{55}"), ('\u{1d47d}', "\u{56}"),
+    ('\u{1d47e}', "\u{57}"), ('\u{1d47f}', "\u{58}"), ('\u{1d480}', "\u{59}"),
+    ('\u{1d481}', "\u{5a}"), ('\u{1d482}', "\u{61}"), ('\u{1d483}', "\u{62}"),
+    ('\u{1d484}', "\u{63}"), ('\u{1d485}', "\u{64}"), ('\u{1d486}', "\u{65}"),
+    ('\u{1d487}', "\u{66}"), ('\u{1d488}', "\u{67}"), ('\u{1d489}', "\u{68}"),
+    ('\u{1d48a}', "\u{69}"), ('\u{1d48b}', "\u{6a}"), ('\u{1d48c}', "\u{6b}"),
+    ('\u{1d48d}', "\u{6c}"), ('\u{1d48e}', "\u{6d}"), ('\u{1d48f}', "\u{6e}"),
+    ('\u{1d490}', "\u{6f}"), ('\u{1d491}', "\u{70}"), ('\u{1d492}', "\u{71}"),
+    ('\u{1d493}', "\u{72}"), ('\u{1d494}', "\u{73}"), ('\u{1d495}', "\u{74}"),
+    ('\u{1d496}', "\u{75}"), ('\u{1d497}', "\u{76}"), ('\u{1d498}', "\u{77}"),
+    ('\u{1d499}', "\u{78}"), ('\u{1d49a}', "\u{79}"), ('\u{1d49b}', "\u{7a}"),
+    ('\u{1d49c}', "\u{41}"), ('\u{1d49e}', "\u{43}"), ('\u{1d49f}', "\u{44}"),
+    ('\u{1d4a2}', "\u{47}"), ('\u{1d4a5}', "\u{4a}"), ('\u{1d4a6}', "\u{4b}"),
+    ('\u{1d4a9}', "\u{4e}"), ('\u{1d4aa}', "\u{4f}"), ('\u{1d4ab}', "\u{50}"),
+    ('\u{1d4ac}', "\u{51}"), ('\u{1d4ae}', "\u{53}"), ('\u{1d4af}', "\u{54}"),
+    ('\u{1d4b0}', "\u{55}"), ('\u{1d4b1}', "\u{56}"), ('\u{1d4b2}', "\u{57}"),
+    ('\u{1d4b3}', "\u{58}"), ('\u{1d4b4}', "\u{59}"), ('\u{1d4b5}', "\u{5a}"),
+    ('\u{1d4b6}', "\u{61}"), ('\u{1d4b7}', "\u{62}"), ('\u{1d4b8}', "\u{63}"),
+    ('\u{1d4b9}', "\u{64}"), ('\u{1d4bb}', "\u{66}"), ('\u{1d4bd}', "\u{68}"),
+    ('\u{1d4be}', "\u{69}"), ('\u{1d4bf}', "\u{6a}"), ('\u{1d4c0}', "\u{6b}"),
+    ('\u{1d4c1}', "\u{6c}"), ('\u{1d4c2}', "\u{6d}"), ('\u{1d4c3}', "\u{6e}"),
+    ('\u{1d4c5}', "\u{70}"), ('\u{1d4c6}', "\u{71}"), ('\u{1d4c7}', "\u{72}"),
+    ('\u{1d4c8}', "\u{73}"), ('\u{1d4c9}', "\u{74}"), ('\u{1d4ca}', "\u{75}"),
+    ('\u{1d4cb}', "\u{76}"), ('\u{1d4cc}', "\u{77}"), ('\u{1d4cd}', "\u{78}"),
+    ('\u{1d4ce}', "\u{79}"), ('\u{1d4cf}', "\u{7a}"), ('\u{1d4d0}', "\u{41}"),
+    ('\u{1d4d1}', "\u{42}"), ('\u{1d4d2}', "\u{43}"), ('\u{1d4d3}', "\u{44}"),
+    ('\u{1d4d4}', "\u{45}"), ('\u{1d4d5}', "\u{46}"), ('\u{1d4d6}', "\u{47}"),
+    ('\u{1d4d7}', "\u{48}"), ('\u{1d4d8}', "\u{49}"), ('\u{1d4d9}', "\u{4a}"),
+    ('\u{1d4da}', "\u{4b}"), ('\u{1d4db}', "\u{4c}"), ('\u{1d4dc}', "\u{4d}"),
+    ('\u{1d4dd}', "\u{4e}"), ('\u{1d4de}', "\u{4f}"), ('\u{1d4df}', "\u{50}"),
+    ('\u{1d4e0}', "\u{51}"), ('\u{1d4e1}', "\u{52}"), ('\u{1d4e2}', "\u{53}"),
+    ('\u{1d4e3}', "\u{54}"), ('\u{1d4e4}', "\u{55}"), ('\u{1d4e5}', "\u{56}"),
+    ('\u{1d4e6}', "\u{57}"), ('\u{1d4e7}', "\u{58}"), ('\u{1d4e8}', "\u{59}"),
+    ('\u{1d4e9}', "\u{5a}"), ('\u{1d4ea}', "\u{61}"), ('\u{1d4eb}', "\u{62}"),
+    ('\u{1d4ec}', "\u{63}"), ('\u{1d4ed}', "\u{64}"), ('\u{1d4ee}', "\u{65}"),
+    ('\u{1d4ef}', "\u{66}"), ('\u{1d4f0}', "\u{67}"), ('\u{1d4f1}', "\u{68}"),
+    ('\u{1d4f2}', "\u{69}"), ('\u{1d4f3}', "\u{6a}"), ('\u{1d4f4}', "\u{6b}"),
+    ('\u{1d4f5}', "\u{6c}"), ('\u{1d4f6}', "\u{6d}"), ('\u{1d4f7}', "\u{6e}"),
+    ('\u{1d4f8}', "\u{6f}"), ('\u{1d4f9}', "\u{70}"), ('\u{1d4fa}', "\u{71}"),
+    ('\u{1d4fb}', "\u{72}"), ('\u{1d4fc}', "\u{73}"), ('\u{1d4fd}', "\u{74}"),
+    ('\u{1d4fe}', "\u{75}"), ('\u{1d4ff}', "\u{76}"), ('\u{1d500}', "\u{77}"),
+    ('\u{1d501}', "\u{78}"), ('\u{1d502}', "\u{79}"), ('\u{1d503}', "\u{7a}"),
+    ('\u{1d504}', "\u{41}"), ('\u{1d505}', "\u{42}"), ('\u{1d507}', "\u{44}"),
+    ('\u{1d508}', "\u{45}"), ('\u{1d509}', "\u{46}"), ('\u{1d50a}', "\u{47}"),
+    ('\u{1d50d}', "\u{4a}"), ('\u{1d50e}', "\u{4b}"), ('\u{1d50f}', "\u{4c}"),
+    ('\u{1d510}', "\u{4d}"), ('\u{1d511}', "\u{4e}"), ('\u{1d512}', "\u{4f}"),
+    ('\u{1d513}', "\u{50}"), ('\u{1d514}', "\u{51}"), ('\u{1d516}', "\u{53}"),
+    ('\u{1d517}', "\u{54}"), ('\u{1d518}', "\u{55}"), ('\u{1d519}', "\u{56}"),
+    ('\u{1d51a}', "\u{57}"), ('\u{1d51b}', "\u{58}"), ('\u{1d51c}', "\u{59}"),
+    ('\u{1d51e}', "\u{61}"), ('\u{1d51f}', "\u{62}"), ('\u{1d520}', "\u{63}"),
+    ('\u{1d521}', "\u{64}"), ('\u{1d522}', "\u{65}"), ('\u{1d523}', "\u{66}"),
+    ('\u{1d524}', "\u{67}"), ('\u{1d525}', "\u{68}"), ('\u{1d526}', "\u{69}"),
+    ('\u{1d527}', "\u{6a}"), ('\u{1d528}', "\u{6b}"), ('\u{1d529}', "\u{6c}"),
+    ('\u{1d52a}', "\u{6d}"), ('\u{1d52b}', "\u{6e}"), ('\u{1d52c}', "\u{6f}"),
+    ('\u{1d52d}', "\u{70}"), ('\u{1d52e}', "\u{71}"), ('\u{1d52f}', "\u{72}"),
+    ('\u{1d530}', "\u{73}"), ('\u{1d531}', "\u{74}"), ('\u{1d532}', "\u{75}"),
+    ('\u{1d533}', "\u{76}"), ('\u{1d534}', "\u{77}"), ('\u{1d535}', "\u{78}"),
+    ('\u{1d536}', "\u{79}"), ('\u{1d537}', "\u{7a}"), ('\u{1d538}', "\u{41}"),
+    ('\u{1d539}', "\u{42}"), ('\u{1d53b}', "\u{44}"), ('\u{1d53c}', "\u{45}"),
+    ('\u{1d53d}', "\u{46}"), ('\u{1d53e}', "\u{47}"), ('\u{1d540}', "\u{49}"),
+    ('\u{1d541}', "\u{4a}"), ('\u{1d542}', "\u{4b}"), ('\u{1d543}', "\u{4c}"),
+    ('\u{1d544}', "\u{4d}"), ('\u{1d546}', "\u{4f}"), ('\u{1d54a}', "\u{53}"),
+    ('\u{1d54b}', "\u{54}"), ('\u{1d54c}', "\u{55}"), ('\u{1d54d}', "\u{56}"),
+    ('\u{1d54e}', "\u{57}"), ('\u{1d54f}', "\u{58}"), ('\u{1d550}', "\u{59}"),
+    ('\u{1d552}', "\u{61}"), ('\u{1d553}', "\u{62}"), ('\u{1d554}', "\u{63}"),
+    ('\u{1d555}', "\u{64}"), ('\u{1d556}', "\u{65}"), ('\u{1d557}', "\u{66}"),
+    ('\u{1d558}', "\u{67}"), ('\u{1d559}', "\u{68}"), ('\u{1d55a}', "\u{69}"),
+    ('\u{1d55b}', "\u{6a}"), ('\u{1d55c}', "\u{6b}"), ('\u{1d55d}', "\u{6c}"),
+    ('\u{1d55e}', "\u{6d}"), ('\u{1d55f}', "\u{6e}"), ('\u{1d560}', "\u{6f}"),
+    ('\u{1d561}', "\u{70}"), ('\u{1d562}', "\u{71}"), ('\u{1d563}', "\u{72}"),
+    ('\u{1d564}', "\u{73}"), ('\u{1d565}', "\u{74}"), ('\u{1d566}', "\u{75}"),
+    ('\u{1d567}', "\u{76}"), ('\u{1d568}', "\u{77}"), ('\u{1d569}', "\u{78}"),
+    ('\u{1d56a}', "\u{79}"), ('\u{1d56b}', "\u{7a}"), ('\u{1d56c}', "\u{41}"),
+    ('\u{1d56d}', "\u{42}"), ('\u{1d56e}', "\u{43}"), ('\u{1d56f}', "\u{44}"),
+    ('\u{1d570}', "\u{45}"), ('\u{1d571}', "\u{46}"), ('\u{1d572}', "\u{47}"),
+    ('\u{1d573}', "\u{48}"), ('\u{1d574}', "\u{49}"), ('\u{1d575}', "\u{4a}"),
+    ('\u{1d576}', "\u{4b}"), ('\u{1d577}', "\u{4c}"), ('\u{1d578}', "\u{4d}"),
+    ('\u{1d579}', "\u{4e}"), ('\u{1d57a}', "\u{4f}"), ('\u{1d57b}', "\u{50}"),
+    ('\u{1d57c}', "\u{51}"), ('\u{1d57d}', "\u{52}"), ('\u{1d57e}', "\u{53}"),
+    ('\u{1d57f}', "\u{54}"), ('\u{1d580}', "\u{55}"), ('\u{1d581}', "\u{56}"),
+    ('\u{1d582}', "\u{57}"), ('\u{1d583}', "\u{58}"), ('\u{1d584}', "\u{59}"),
+    ('\u{1d585}', "\u{5a}"), ('\u{1d586}', "\u{61}"), ('\u{1d587}', "\u{62}"),
+    ('\u{1d588}', "\u{63}"), ('\u{1d589}', "\u{64}"), ('\u{1d58a}', "\u{65}"),
+    ('\u{1d58b}', "\u{66}"), ('\u{1d58c}', "\u{67}"), ('\u{1d58d}', "\u{68}"),
+    ('\u{1d58e}', "\u{69}"), ('\u{1d58f}', "\u{6a}"), ('\u{1d590}', "\u{6b}"),
+    ('\u{1d591}', "\u{6c}"), ('\u{1d592}', "\u{6d}"), ('\u{1d593}', "\u{6e}"),
+    ('\u{1d594}', "\u{6f}"), ('\u{1d595}', "\u{70}"), ('\u{1d596}', "\u{71}"),
+    ('\u{1d597}', "\u{72}"), ('\u{1d598}', "\u{73}"), ('\u{1d599}', "\u{74}"),
+    ('\u{1d59a}', "\u{75}"), ('\u{1d59b}', "\u{76}"), ('\u{1d59c}', "\u{77}"),
+    ('\u{1d59d}', "\u{78}"), ('\u{1d59e}', "\u{79}"), ('\u{1d59f}', "\u{7a}"),
+    ('\u{1d5a0}', "\u{41}"), ('\u{1d5a1}', "\u{42}"), ('\u{1d5a2}', "\u{43}"),
+    ('\u{1d5a3}', "\u{44}"), ('\u{1d5a4}', "\u{45}"), ('\u{1d5a5}', "\u{46}"),
+    ('\u{1d5a6}', "\u{47}"), ('\u{1d5a7}', "\u{48}"), ('\u{1d5a8}', "\u{49}"),
+    ('\u{1d5a9}', "\u{4a}"), ('\u{1d5aa}', "\u{4b}"), ('\u{1d5ab}', "\u{4c}"),
+    ('\u{1d5ac}', "\u{4d}"), ('\u{1d5ad}', "\u{4e}"), ('\u{1d5ae}', "\u{4f}"),
+    ('\u{1d5af}', "\u{50}"), ('\u{1d5b0}', "\u{51}"), ('\u{1d5b1}', "\u{52}"),
+    ('\u{1d5b2}', "\u{53}"), ('\u{1d5b3}', "\u{54}"), ('\u{1d5b4}', "\u{55}"),
+    ('\u{1d5b5}', "\u{56}"), ('\u{1d5b6}', "\u{57}"), ('\u{1d5b7}', "\u{58}"),
+    ('\u{1d5b8}', "\u{59}"), ('\u{1d5b9}', "\u{5a}"), ('\u{1d5ba}', "\u{61}"),
+    ('\u{1d5bb}', "\u{62}"), ('\u{1d5bc}', "\u{63}"), ('\u{1d5bd}', "\u{64}"),
+    ('\u{1d5be}', "\u{65}"), ('\u{1d5bf}', "\u{66}"), ('\u{1d5c0}', "\u{67}"),
+    ('\u{1d5c1}', "\u{68}"), ('\u{1d5c2}', "\u{69}"), ('\u{1d5c3}', "\u{6a}"),
+    ('\u{1d5c4}', "\u{6b}"), ('\u{1d5c5}', "\u{6c}"), ('\u{1d5c6}', "\u{6d}"),
+    ('\u{1d5c7}', "\u{6e}"), ('\u{1d5c8}', "\u{6f}"), ('\u{1d5c9}', "\u{70}"),
+    ('\u{1d5ca}', "\u{71}"), ('\u{1d5cb}', "\u{72}"), ('\u{1d5cc}', "\u{73}"),
+    ('\u{1d5cd}', "\u{74}"), ('\u{1d5ce}', "\u{75}"), ('\u{1d5cf}', "\u{76}"),
+    ('\u{1d5d0}', "\u{77}"), ('\u{1d5d1}', "\u{78}"), ('\u{1d5d2}', "\u{79}"),
+    ('\u{1d5d3}', "\u{7a}"), ('\u{1d5d4}', "\u{41}"), ('\u{1d5d5}', "\u{42}"),
+    ('\u{1d5d6}', "\u{43}"), ('\u{1d5d7}', "\u{44}"), ('\u{1d5d8}', "\u{45}"),
+    ('\u{1d5d9}', "\u{46}"), ('\u{1d5da}', "\u{47}"), ('\u{1d5db}', "\u{48}"),
+    ('\u{1d5dc}', "\u{49}"), ('\u{1d5dd}', "\u{4a}"), ('\u{1d5de}', "\u{4b}"),
+    ('\u{1d5df}', "\u{4c}"), ('\u{1d5e0}', "\u{4d}"), ('\u{1d5e1}', "\u{4e}"),
+    ('\u{1d5e2}', "\u{4f}"), ('\u{1d5e3}', "\u{50}"), ('\u{1d5e4}', "\u{51}"),
+    ('\u{1d5e5}', "\u{52}"), ('\u{1d5e6}', "\u{53}"), ('\u{1d5e7}', "\u{54}"),
+    ('\u{1d5e8}', "\u{55}"), ('\u{1d5e9}', "\u{56}"), ('\u{1d5ea}', "\u{57}"),
+    ('\u{1d5eb}', "\u{58}"), ('\u{1d5ec}', "\u{59}"), ('\u{1d5ed}', "\u{5a}"),
+    ('\u{1d5ee}', "\u{61}"), ('\u{1d5ef}', "\u{62}"), ('\u{1d5f0}', "\u{63}"),
+    ('\u{1d5f1}', "\u{64}"), ('\u{1d5f2}', "\u{65}"), ('\u{1d5f3}', "\u{66}"),
+    ('\u{1d5f4}', "\u{67}"), ('\u{1d5f5}', "\u{68}"), ('\u{1d5f6}', "\u{69}"),
+    ('\u{1d5f7}', "\u{6a}"), ('\u{1d5f8}', "\u{6b}"), ('\u{1d5f9}', "\u{6c}"),
+    ('\u{1d5fa}', "\u{6d}"), ('\u{1d5fb}', "\u{6e}"), ('\u{1d5fc}', "\u{6f}"),
+    ('\u{1d5fd}', "\u{70}"), ('\u{1d5fe}', "\u{71}"), ('\u{1d5ff}', "\u{72}"),
+    ('\u{1d600}', "\u{73}"), ('\u{1d601}', "\u{74}"), ('\u{1d602}', "\u{75}"),
+    ('\u{1d603}', "\u{76}"), ('\u{1d604}', "\u{77}"), ('\u{1d605}', "\u{78}"),
+    ('\u{1d606}', "\u{79}"), ('\u{1d607}', "\u{7a}"), ('\u{1d608}', "\u{41}"),
+    ('\u{1d609}', "\u{42}"), ('\u{1d60a}', "\u{43}"), ('\u{1d60b}', "\u{44}"),
+    ('\u{1d60c}', "\u{45}"), ('\u{1d60d}', "\u{46}"), ('\u{1d60e}', "\u{47}"),
+    ('\u{1d60f}', "\u{48}"), ('\u{1d610}', "\u{49}"), ('\u{1d611}', "\u{4a}"),
+    ('\u{1d612}', "\u{4b}"), ('\u{1d613}', "\u{4c}"), ('\u{1d614}', "\u{4d}"),
+    ('\u{1d615}', "\u{4e}"), ('\u{1d616}', "\u{4f}"), ('\u{1d617}', "\u{50}"),
+    ('\u{1d618}', "\u{51}"), ('\u{1d619}', "\u{52}"), ('\u{1d61a}', "\u{53}"),
+    ('\u{1d61b}', "\u{54}"), ('\u{1d61c}', "\u{55}"), ('\u{1d61d}', "\u{56}"),
+    ('\u{1d61e}', "\u{57}"), ('\u{1d61f}', "\u{58}"), ('\u{1d620}', "\u{59}"),
+    ('\u{1d621}', "\u{5a}"), ('\u{1d622}', "\u{61}"), ('\u{1d623}', "\u{62}"),
+    ('\u{1d624}', "\u{63}"), ('\u{1d625}', "\u{64}"), ('\u{1d626}', "\u{65}"),
+    ('\u{1d627}', "\u{66}"), ('\u{1d628}', "\u{67}"), ('\u{1d629}', "\u{68}"),
+    ('\u{1d62a}', "\u{69}"), ('\u{1d62b}', "\u{6a}"), ('\u{1d62c}', "\u{6b}"),
+    ('\u{1d62d}', "\u{6c}"), ('\u{1d62e}', "\u{6d}"), ('\u{1d62f}', "\u{6e}"),
+    ('\u{1d630}', "\u{6f}"), ('\u{1d631}', "\u{70}"), ('\u{1d632}', "\u{71}"),
+    ('\u{1d633}', "\u{72}"), ('\u{1d634}', "\u{73}"), ('\u{1d635}', "\u{74}"),
+    ('\u{1d636}', "\u{75}"), ('\u{1d637}', "\u{76}"), ('\u{1d638}', "\u{77}"),
+    ('\u{1d639}', "\u{78}"), ('\u{1d63a}', "\u{79}"), ('\u{1d63b}', "\u{7a}"),
+    ('\u{1d63c}', "\u{41}"), ('\u{1d63d}', "\u{42}"), ('\u{1d63e}', "\u{43}"),
+    ('\u{1d63f}', "\u{44}"), ('\u{1d640}', "\u{45}"), ('\u{1d641}', "\u{46}"),
+    ('\u{1d642}', "\u{47}"), ('\u{1d643}', "\u{48}"), ('\u{1d644}', "\u{49}"),
+    ('\u{1d645}', "\u{4a}"), ('\u{1d646}', "\u{4b}"), ('\u{1d647}', "\u{4c}"),
+    ('\u{1d648}', "\u{4d}"), ('\u{1d649}', "\u{4e}"), ('\u{1d64a}', "\u{4f}"),
+    ('\u{1d64b}', "\u{50}"), ('\u{1d64c}', "\u{51}"), ('\u{1d64d}', "\u{52}"),
+    ('\u{1d64e}', "\u{53}"), ('\u{1d64f}', "\u{54}"), ('\u{1d650}', "\u{55}"),
+    ('\u{1d651}', "\u{56}"), ('\u{1d652}', "\u{57}"), ('\u{1d653}', "\u{58}"),
+    ('\u{1d654}', "\u{59}"), ('\u{1d655}', "\u{5a}"), ('\u{1d656}', "\u{61}"),
+    ('\u{1d657}', "\u{62}"), ('\u{1d658}', "\u{63}"), ('\u{1d659}', "\u{64}"),
+    ('\u{1d65a}', "\u{65}"), ('\u{1d65b}', "\u{66}"), ('\u{1d65c}', "\u{67}"),
+    ('\u{1d65d}', "\u{68}"), ('\u{1d65e}', "\u{69}"), ('\u{1d65f}', "\u{6a}"),
+    ('\u{1d660}', "\u{6b}"), ('\u{1d661}', "\u{6c}"), ('\u{1d662}', "\u{6d}"),
+    ('\u{1d663}', "\u{6e}"), ('\u{1d664}', "\u{6f}"), ('\u{1d665}', "\u{70}"),
+    ('\u{1d666}', "\u{71}"), ('\u{1d667}', "\u{72}"), ('\u{1d668}', "\u{73}"),
+    ('\u{1d669}', "\u{74}"), ('\u{1d66a}', "\u{75}"), ('\u{1d66b}', "\u{76}"),
+    ('\u{1d66c}', "\u{77}"), ('\u{1d66d}', "\u{78}"), ('\u{1d66e}', "\u{79}"),
+    ('\u{1d66f}', "\u{7a}"), ('\u{1d670}', "\u{41}"), ('\u{1d671}', "\u{42}"),
+    ('\u{1d672}', "\u{43}"), ('\u{1d673}', "\u{44}"), ('\u{1d674}', "\u{45}"),
+    ('\u{1d675}', "\u{46}"), ('\u{1d676}', "\u{47}"), ('\u{1d677}', "\u{48}"),
+    ('\u{1d678}', "\u{49}"), ('\u{1d679}', "\u{4a}"), ('\u{1d67a}', "\u{4b}"),
+    ('\u{1d67b}', "\u{4c}"), ('\u{1d67c}', "\u{4d}"), ('\u{1d67d}', "\u{4e}"),
+    ('\u{1d67e}', "\u{4f}"), ('\u{1d67f}', "\u{50}"), ('\u{1d680}', "\u{51}"),
+    ('\u{1d681}', "\u{52}"), ('\u{1d682}', "\u{53}"), ('\u{1d683}', "\u{54}"),
+    ('\u{1d684}', "\u{55}"), ('\u{1d685}', "\u{56}"), ('\u{1d686}', "\u{57}"),
+    ('\u{1d687}', "\u{58}"), ('\u{1d688}', "\u{59}"), ('\u{1d689}', "\u{5a}"),
+    ('\u{1d68a}', "\u{61}"), ('\u{1d68b}', "\u{62}"), ('\u{1d68c}', "\u{63}"),
+    ('\u{1d68d}', "\u{64}"), ('\u{1d68e}', "\u{65}"), ('\u{1d68f}', "\u{66}"),
+    ('\u{1d690}', "\u{67}"), ('\u{1d691}', "\u{68}"), ('\u{1d692}', "\u{69}"),
+    ('\u{1d693}', "\u{6a}"), ('\u{1d694}', "\u{6b}"), ('\u{1d695}', "\u{6c}"),
+    ('\u{1d696}', "\u{6d}"), ('\u{1d697}', "\u{6e}"), ('\u{1d698}', "\u{6f}"),
+    ('\u{1d699}', "\u{70}"), ('\u{1d69a}', "\u{71}"), ('\u{1d69b}', "\u{72}"),
+    ('\u{1d69c}', "\u{73}"), ('\u{1d69d}', "\u{74}"), ('\u{1d69e}', "\u{75}"),
+    ('\u{1d69f}', "\u{76}"), ('\u{1d6a0}', "\u{77}"), ('\u{1d6a1}', "\u{78}"),
+    ('\u{1d6a2}', "\u{79}"), ('\u{1d6a3}', "\u{7a}"), ('\u{1d6a4}', "\u{131}"),
+    ('\u{1d6a5}', "\u{237}"), ('\u{1d6a8}', "\u{391}"), ('\u{1d6a9}', "\u{392}"),
+    ('\u{1d6aa}', "\u{393}"), ('\u{1d6ab}', "\u{394}"), ('\u{1d6ac}', "\u{395}"),
+    ('\u{1d6ad}', "\u{396}"), ('\u{1d6ae}', "\u{397}"), ('\u{1d6af}', "\u{398}"),
+    ('\u{1d6b0}', "\u{399}"), ('\u{1d6b1}', "\u{39a}"), ('\u{1d6b2}', "\u{39b}"),
+    ('\u{1d6b3}', "\u{39c}"), ('\u{1d6b4}', "\u{39d}"), ('\u{1d6b5}', "\u{39e}"),
+    ('\u{1d6b6}', "\u{39f}"), ('\u{1d6b7}', "\u{3a0}"), ('\u{1d6b8}', "\u{3a1}"),
+    ('\u{1d6b9}', "\u{398}"), ('\u{1d6ba}', "\u{3a3}"), ('\u{1d6bb}', "\u{3a4}"),
+    ('\u{1d6bc}', "\u{3a5}"), ('\u{1d6bd}', "\u{3a6}"), ('\u{1d6be}', "\u{3a7}"),
+    ('\u{1d6bf}', "\u{3a8}"), ('\u{1d6c0}', "\u{3a9}"), ('\u{1d6c1}', "\u{2207}"),
+    ('\u{1d6c2}', "\u{3b1}"), ('\u{1d6c3}', "\u{3b2}"), ('\u{1d6c4}', "\u{3b3}"),
+    ('\u{1d6c5}', "\u{3b4}"), ('\u{1d6c6}', "\u{3b5}"), ('\u{1d6c7}', "\u{3b6}"),
+    ('\u{1d6c8}', "\u{3b7}"), ('\u{1d6c9}', "\u{3b8}"), ('\u{1d6ca}', "\u{3b9}"),
+    ('\u{1d6cb}', "\u{3ba}"), ('\u{1d6cc}', "\u{3bb}"), ('\u{1d6cd}', "\u{3bc}"),
+    ('\u{1d6ce}', "\u{3bd}"), ('\u{1d6cf}', "\u{3be}"), ('\u{1d6d0}', "\u{3bf}"),
+    ('\u{1d6d1}', "\u{3c0}"), ('\u{1d6d2}', "\u{3c1}"), ('\u{1d6d3}', "\u{3c2}"),
+    ('\u{1d6d4}', "\u{3c3}"), ('\u{1d6d5}', "\u{3c4}"), ('\u{1d6d6}', "\u{3c5}"),
+    ('\u{1d6d7}', "\u{3c6}"), ('\u{1d6d8}', "\u{3c7}"), ('\u{1d6d9}', "\u{3c8}"),
+    ('\u{1d6da}', "\u{3c9}"), ('\u{1d6db}', "\u{2202}"), ('\u{1d6dc}', "\u{3b5}"),
+    ('\u{1d6dd}', "\u{3b8}"), ('\u{1d6de}', "\u{3ba}"), ('\u{1d6df}', "\u{3c6}"),
+    ('\u{1d6e0}', "\u{3c1}"), ('\u{1d6e1}', "\u{3c0}"), ('\u{1d6e2}', "\u{391}"),
+    ('\u{1d6e3}', "\u{392}"), ('\u{1d6e4}', "\u{393}"), ('\u{1d6e5}', "\u{394}"),
+    ('\u{1d6e6}', "\u{395}"), ('\u{1d6e7}', "\u{396}"), ('\u{1d6e8}', "\u{397}"),
+    ('\u{1d6e9}', "\u{398}"), ('\u{1d6ea}', "\u{399}"), ('\u{1d6eb}', "\u{39a}"),
+    ('\u{1d6ec}', "\u{39b}"), ('\u{1d6ed}', "\u{39c}"), ('\u{1d6ee}', "\u{39d}"),
+    ('\u{1d6ef}', "\u{39e}"), ('\u{1d6f0}', "\u{39f}"), ('\u{1d6f1}', "\u{3a0}"),
+    ('\u{1d6f2}', "\u{3a1}"), ('\u{1d6f3}', "\u{398}"), ('\u{1d6f4}', "\u{3a3}"),
+    ('\u{1d6f5}', "\u{3a4}"), ('\u{1d6f6}', "\u{3a5}"), ('\u{1d6f7}', "\u{3a6}"),
+    ('\u{1d6f8}', "\u{3a7}"), ('\u{1d6f9}', "\u{3a8}"), ('\u{1d6fa}', "\u{3a9}"),
+    ('\u{1d6fb}', "\u{2207}"), ('\u{1d6fc}', "\u{3b1}"), ('\u{1d6fd}', "\u{3b2}"),
+    ('\u{1d6fe}', "\u{3b3}"), ('\u{1d6ff}', "\u{3b4}"), ('\u{1d700}', "\u{3b5}"),
+    ('\u{1d701}', "\u{3b6}"), ('\u{1d702}', "\u{3b7}"), ('\u{1d703}', "\u{3b8}"),
+    ('\u{1d704}', "\u{3b9}"), ('\u{1d705}', "\u{3ba}"), ('\u{1d706}', "\u{3bb}"),
+    ('\u{1d707}', "\u{3bc}"), ('\u{1d708}', "\u{3bd}"), ('\u{1d709}', "\u{3be}"),
+    ('\u{1d70a}', "\u{3bf}"), ('\u{1d70b}', "\u{3c0}"), ('\u{1d70c}', "\u{3c1}"),
+    ('\u{1d70d}', "\u{3c2}"), ('\u{1d70e}', "\u{3c3}"), ('\u{1d70f}', "\u{3c4}"),
+    ('\u{1d710}', "\u{3c5}"), ('\u{1d711}', "\u{3c6}"), ('\u{1d712}', "\u{3c7}"),
+    ('\u{1d713}', "\u{3c8}"), ('\u{1d714}', "\u{3c9}"), ('\u{1d715}', "\u{2202}"),
+    ('\u{1d716}', "\u{3b5}"), ('\u{1d717}', "\u{3b8}"), ('\u{1d718}', "\u{3ba}"),
+    ('\u{1d719}', "\u{3c6}"), ('\u{1d71a}', "\u{3c1}"), ('\u{1d71b}', "\u{3c0}"),
+    ('\u{1d71c}', "\u{391}"), ('\u{1d71d}', "\u{392}"), ('\u{1d71e}', "\u{393}"),
+    ('\u{1d71f}', "\u{394}"), ('\u{1d720}', "\u{395}"), ('\u{1d721}', "\u{396}"),
+    ('\u{1d722}', "\u{397}"), ('\u{1d723}', "\u{398}"), ('\u{1d724}', "\u{399}"),
+    ('\u{1d725}', "\u{39a}"), ('\u{1d726}', "\u{39b}"), ('\u{1d727}', "\u{39c}"),
+    ('\u{1d728}', "\u{39d}"), ('\u{1d729}', "\u{39e}"), ('\u{1d72a}', "\u{39f}"),
+    ('\u{1d72b}', "\u{3a0}"), ('\u{1d72c}', "\u{3a1}"), ('\u{1d72d}', "\u{398}"),
+    ('\u{1d72e}', "\u{3a3}"), ('\u{1d72f}', "\u{3a4}"), ('\u{1d730}', "\u{3a5}"),
+    ('\u{1d731}', "\u{3a6}"), ('\u{1d732}', "\u{3a7}"), ('\u{1d733}', "\u{3a8}"),
+    ('\u{1d734}', "\u{3a9}"), ('\u{1d735}', "\u{2207}"), ('\u{1d736}', "\u{3b1}"),
+    ('\u{1d737}', "\u{3b2}"), ('\u{1d738}', "\u{3b3}"), ('\u{1d739}', "\u{3b4}"),
+    ('\u{1d73a}', "\u{3b5}"), ('\u{1d73b}', "\u{3b6}"), ('\u{1d73c}', "\u{3b7}"),
+    ('\u{1d73d}', "\u{3b8}"), ('\u{1d73e}', "\u{3b9}"), ('\u{1d73f}', "\u{3ba}"),
+    ('\u{1d740}', "\u{3bb}"), ('\u{1d741}', "\u{3bc}"), ('\u{1d742}', "\u{3bd}"),
+    ('\u{1d743}', "\u{3be}"), ('\u{1d744}', "\u{3bf}"), ('\u{1d745}', "\u{3c0}"),
+    ('\u{1d746}', "\u{3c1}"), ('\u{1d747}', "\u{3c2}"), ('\u{1d748}', "\u{3c3}"),
+    ('\u{1d749}', "\u{3c4}"), ('\u{1d74a}', "\u{3c5}"), ('\u{1d74b}', "\u{3c6}"),
+    ('\u{1d74c}', "\u{3c7}"), ('\u{1d74d}', "\u{3c8}"), ('\u{1d74e}', "\u{3c9}"),
+    ('\u{1d74f}', "\u{2202}"), ('\u{1d750}', "\u{3b5}"), ('\u{1d751}', "\u{3b8}"),
+    ('\u{1d752}', "\u{3ba}"), ('\u{1d753}', "\u{3c6}"), ('\u{1d754}', "\u{3c1}"),
+    ('\u{1d755}', "\u{3c0}"), ('\u{1d756}', "\u{391}"), ('\u{1d757}', "\u{392}"),
+    ('\u{1d758}', "\u{393}"), ('\u{1d759}', "\u{394}"), ('\u{1d75a}', "\u{395}"),
+    ('\u{1d75b}', "\u{396}"), ('\u{1d75c}', "\u{397}"), ('\u{1d75d}', "\u{398}"),
+    ('\u{1d75e}', "\u{399}"), ('\u{1d75f}', "\u{39a}"), ('\u{1d760}', "\u{39b}"),
+    ('\u{1d761}', "\u{39c}"), ('\u{1d762}', "\u{39d}"), ('\u{1d763}', "\u{39e}"),
+    ('\u{1d764}', "\u{39f}"), ('\u{1d765}', "\u{3a0}"), ('\u{1d766}', "\u{3a1}"),
+    ('\u{1d767}', "\u{398}"), ('\u{1d768}', "\u{3a3}"), ('\u{1d769}', "\u{3a4}"),
+    ('\u{1d76a}', "\u{3a5}"), ('\u{1d76b}', "\u{3a6}"), ('\u{1d76c}', "\u{3a7}"),
+    ('\u{1d76d}', "\u{3a8}"), ('\u{1d76e}', "\u{3a9}"), ('\u{1d76f}', "\u{2207}"),
+    ('\u{1d770}', "\u{3b1}"), ('\u{1d771}', "\u{3b2}"), ('\u{1d772}', "\u{3b3}"),
+    ('\u{1d773}', "\u{3b4}"), ('\u{1d774}', "\u{3b5}"), ('\u{1d775}', "\u{3b6}"),
+    ('\u{1d776}', "\u{3b7}"), ('\u{1d777}', "\u{3b8}"), ('\u{1d778}', "\u{3b9}"),
+    ('\u{1d779}', "\u{3ba}"), ('\u{1d77a}', "\u{3bb}"), ('\u{1d77b}', "\u{3bc}"),
+    ('\u{1d77c}', "\u{3bd}"), ('\u{1d77d}', "\u{3be}"), ('\u{1d77e}', "\u{3bf}"),
+    ('\u{1d77f}', "\u{3c0}"), ('\u{1d780}', "\u{3c1}"), ('\u{1d781}', "\u{3c2}"),
+    ('\u{1d782}', "\u{3c3}"), ('\u{1d783}', "\u{3c4}"), ('\u{1d784}', "\u{3c5}"),
+    ('\u{1d785}', "\u{3c6}"), ('\u{1d786}', "\u{3c7}"), ('\u{1d787}', "\u{3c8}"),
+    ('\u{1d788}', "\u{3c9}"), ('\u{1d789}', "\u{2202}"), ('\u{1d78a}', "\u{3b5}"),
+    ('\u{1d78b}', "\u{3b8}"), ('\u{1d78c}', "\u{3ba}"), ('\u{1d78d}', "\u{3c6}"),
+    ('\u{1d78e}', "\u{3c1}"), ('\u{1d78f}', "\u{3c0}"), ('\u{1d790}', "\u{391}"),
+    ('\u{1d791}', "\u{392}"), ('\u{1d792}', "\u{393}"), ('\u{1d793}', "\u{394}"),
+    ('\u{1d794}', "\u{395}"), ('\u{1d795}', "\u{396}"), ('\u{1d796}', "\u{397}"),
+    ('\u{1d797}', "\u{398}"), ('\u{1d798}', "\u{399}"), ('\u{1d799}', "\u{39a}"),
+    ('\u{1d79a}', "\u{39b}"), ('\u{1d79b}', "\u{39c}"), ('\u{1d79c}', "\u{39d}"),
+    ('\u{1d79d}', "\u{39e}"), ('\u{1d79e}', "\u{39f}"), ('\u{1d79f}', "\u{3a0}"),
+    ('\u{1d7a0}', "\u{3a1}"), ('\u{1d7a1}', "\u{398}"), ('\u{1d7a2}', "\u{3a3}"),
+    ('\u{1d7a3}', "\u{3a4}"), ('\u{1d7a4}', "\u{3a5}"), ('\u{1d7a5}', "\u{3a6}"),
+    ('\u{1d7a6}', "\u{3a7}"), ('\u{1d7a7}', "\u{3a8}"), ('\u{1d7a8}', "\u{3a9}"),
+    ('\u{1d7a9}', "\u{2207}"), ('\u{1d7aa}', "\u{3b1}"), ('\u{1d7ab}', "\u{3b2}"),
+    ('\u{1d7ac}', "\u{3b3}"), ('\u{1d7ad}', "\u{3b4}"), ('\u{1d7ae}', "\u{3b5}"),
+    ('\u{1d7af}', "\u{3b6}"), ('\u{1d7b0}', "\u{3b7}"), ('\u{1d7b1}', "\u{3b8}"),
+    ('\u{1d7b2}', "\u{3b9}"), ('\u{1d7b3}', "\u{3ba}"), ('\u{1d7b4}', "\u{3bb}"),
+    ('\u{1d7b5}', "\u{3bc}"), ('\u{1d7b6}', "\u{3bd}"), ('\u{1d7b7}', "\u{3be}"),
+    ('\u{1d7b8}', "\u{3bf}"), ('\u{1d7b9}', "\u{3c0}"), ('\u{1d7ba}', "\u{3c1}"),
+    ('\u{1d7bb}', "\u{3c2}"), ('\u{1d7bc}', "\u{3c3}"), ('\u{1d7bd}', "\u{3c4}"),
+    ('\u{1d7be}', "\u{3c5}"), ('\u{1d7bf}', "\u{3c6}"), ('\u{1d7c0}', "\u{3c7}"),
+    ('\u{1d7c1}', "\u{3c8}"), ('\u{1d7c2}', "\u{3c9}"), ('\u{1d7c3}', "\u{2202}"),
+    ('\u{1d7c4}', "\u{3b5}"), ('\u{1d7c5}', "\u{3b8}"), ('\u{1d7c6}', "\u{3ba}"),
+    ('\u{1d7c7}', "\u{3c6}"), ('\u{1d7c8}', "\u{3c1}"), ('\u{1d7c9}', "\u{3c0}"),
+    ('\u{1d7ca}', "\u{3dc}"), ('\u{1d7cb}', "\u{3dd}"), ('\u{1d7ce}', "\u{30}"),
+    ('\u{1d7cf}', "\u{31}"), ('\u{1d7d0}', "\u{32}"), ('\u{1d7d1}', "\u{33}"),
+    ('\u{1d7d2}', "\u{34}"), ('\u{1d7d3}', "\u{35}"), ('\u{1d7d4}', "\u{36}"),
+    ('\u{1d7d5}', "\u{37}"), ('\u{1d7d6}', "\u{38}"), ('\u{1d7d7}', "\u{39}"),
+    ('\u{1d7d8}', "\u{30}"), ('\u{1d7d9}', "\u{31}"), ('\u{1d7da}', "\u{32}"),
+    ('\u{1d7db}', "\u{33}"), ('\u{1d7dc}', "\u{34}"), ('\u{1d7dd}', "\u{35}"),
+    ('\u{1d7de}', "\u{36}"), ('\u{1d7df}', "\u{37}"), ('\u{1d7e0}', "\u{38}"),
+    ('\u{1d7e1}', "\u{39}"), ('\u{1d7e2}', "\u{30}"), ('\u{1d7e3}', "\u{31}"),
+    ('\u{1d7e4}', "\u{32}"), ('\u{1d7e5}', "\u{33}"), ('\u{1d7e6}', "\u{34}"),
+    ('\u{1d7e7}', "\u{35}"), ('\u{1d7e8}', "\u{36}"), ('\u{1d7e9}', "\u{37}"),
+    ('\u{1d7ea}', "\u{38}"), ('\u{1d7eb}', "\u{39}"), ('\u{1d7ec}', "\u{30}"),
+    ('\u{1d7ed}', "\u{31}"), ('\u{1d7ee}', "\u{32}"), ('\u{1d7ef}', "\u{33}"),
+    ('\u{1d7f0}', "\u{34}"), ('\u{1d7f1}', "\u{35}"), ('\u{1d7f2}', "\u{36}"),
+    ('\u{1d7f3}', "\u{37}"), ('\u{1d7f4}', "\u{38}"), ('\u{1d7f5}', "\u{39}"),
+    ('\u{1d7f6}', "\u{30}"), ('\u{1d7f7}', "\u{31}"), ('\u{1d7f8}', "\u{32}"),
+    ('\u{1d7f9}', "\u{33}"), ('\u{1d7fa}', "\u{34}"), ('\u{1d7fb}', "\u{35}"),
+    ('\u{1d7fc}', "\u{36}"), ('\u{1d7fd}', "\u{37}"), ('\u{1d7fe}', "\u{38}"),
+    ('\u{1d7ff}', "\u{39}"), ('\u{1ee00}', "\u{627}"), ('\u{1ee01}', "\u{628}"),
+    ('\u{1ee02}', "\u{62c}"), ('\u{1ee03}', "\u{62f}"), ('\u{1ee05}', "\u{648}"),
+    ('\u{1ee06}', "\u{632}"), ('\u{1ee07}', "\u{62d}"), ('\u{1ee08}', "\u{637}"),
+    ('\u{1ee09}', "\u{64a}"), ('\u{1ee0a}', "\u{643}"), ('\u{1ee0b}', "\u{644}"),
+    ('\u{1ee0c}', "\u{645}"), ('\u{1ee0d}', "\u{646}"), ('\u{1ee0e}', "\u{633}"),
+    ('\u{1ee0f}', "\u{639}"), ('\u{1ee10}', "\u{641}"), ('\u{1ee11}', "\u{635}"),
+    ('\u{1ee12}', "\u{642}"), ('\u{1ee13}', "\u{631}"), ('\u{1ee14}', "\u{634}"),
+    ('\u{1ee15}', "\u{62a}"), ('\u{1ee16}', "\u{62b}"), ('\u{1ee17}', "\u{62e}"),
+    ('\u{1ee18}', "\u{630}"), ('\u{1ee19}', "\u{636}"), ('\u{1ee1a}', "\u{638}"),
+    ('\u{1ee1b}', "\u{63a}"), ('\u{1ee1c}', "\u{66e}"), ('\u{1ee1d}', "\u{6ba}"),
+    ('\u{1ee1e}', "\u{6a1}"), ('\u{1ee1f}', "\u{66f}"), ('\u{1ee21}', "\u{628}"),
+    ('\u{1ee22}', "\u{62c}"), ('\u{1ee24}', "\u{647}"), ('\u{1ee27}', "\u{62d}"),
+    ('\u{1ee29}', "\u{64a}"), ('\u{1ee2a}', "\u{643}"), ('\u{1ee2b}', "\u{644}"),
+    ('\u{1ee2c}', "\u{645}"), ('\u{1ee2d}', "\u{646}"), ('\u{1ee2e}', "\u{633}"),
+    ('\u{1ee2f}', "\u{639}"), ('\u{1ee30}', "\u{641}"), ('\u{1ee31}', "\u{635}"),
+    ('\u{1ee32}', "\u{642}"), ('\u{1ee34}', "\u{634}"), ('\u{1ee35}', "\u{62a}"),
+    ('\u{1ee36}', "\u{62b}"), ('\u{1ee37}', "\u{62e}"), ('\u{1ee39}', "\u{636}"),
+    ('\u{1ee3b}', "\u{63a}"), ('\u{1ee42}', "\u{62c}"), ('\u{1ee47}', "\u{62d}"),
+    ('\u{1ee49}', "\u{64a}"), ('\u{1ee4b}', "\u{644}"), ('\u{1ee4d}', "\u{646}"),
+    ('\u{1ee4e}', "\u{633}"), ('\u{1ee4f}', "\u{639}"), ('\u{1ee51}', "\u{635}"),
+    ('\u{1ee52}', "\u{642}"), ('\u{1ee54}', "\u{634}"), ('\u{1ee57}', "\u{62e}"),
+    ('\u{1ee59}', "\u{636}"), ('\u{1ee5b}', "\u{63a}"), ('\u{1ee5d}', "\u{6ba}"),
+    ('\u{1ee5f}', "\u{66f}"), ('\u{1ee61}', "\u{628}"), ('\u{1ee62}', "\u{62c}"),
+    ('\u{1ee64}', "\u{647}"), ('\u{1ee67}', "\u{62d}"), ('\u{1ee68}', "\u{637}"),
+    ('\u{1ee69}', "\u{64a}"), ('\u{1ee6a}', "\u{643}"), ('\u{1ee6c}', "\u{645}"),
+    ('\u{1ee6d}', "\u{646}"), ('\u{1ee6e}', "\u{633}"), ('\u{1ee6f}', "\u{639}"),
+    ('\u{1ee70}', "\u{641}"), ('\u{1ee71}', "\u{635}"), ('\u{1ee72}', "\u{642}"),
+    ('\u{1ee74}', "\u{634}"), ('\u{1ee75}', "\u{62a}"), ('\u{1ee76}', "\u{62b}"),
+    ('\u{1ee77}', "\u{62e}"), ('\u{1ee79}', "\u{636}"), ('\u{1ee7a}', "\u{638}"),
+    ('\u{1ee7b}', "\u{63a}"), ('\u{1ee7c}', "\u{66e}"), ('\u{1ee7e}', "\u{6a1}"),
+    ('\u{1ee80}', "\u{627}"), ('\u{1ee81}', "\u{628}"), ('\u{1ee82}', "\u{62c}"),
+    ('\u{1ee83}', "\u{62f}"), ('\u{1ee84}', "\u{647}"), ('\u{1ee85}', "\u{648}"),
+    ('\u{1ee86}', "\u{632}"), ('\u{1ee87}', "\u{62d}"), ('\u{1ee88}', "\u{637}"),
+    ('\u{1ee89}', "\u{64a}"), ('\u{1ee8b}', "\u{644}"), ('\u{1ee8c}', "\u{645}"),
+    ('\u{1ee8d}', "\u{646}"), ('\u{1ee8e}', "\u{633}"), ('\u{1ee8f}', "\u{639}"),
+    ('\u{1ee90}', "\u{641}"), ('\u{1ee91}', "\u{635}"), ('\u{1ee92}', "\u{642}"),
+    ('\u{1ee93}', "\u{631}"), ('\u{1ee94}', "\u{634}"), ('\u{1ee95}', "\u{62a}"),
+    ('\u{1ee96}', "\u{62b}"), ('\u{1ee97}', "\u{62e}"), ('\u{1ee98}', "\u{630}"),
+    ('\u{1ee99}', "\u{636}"), ('\u{1ee9a}', "\u{638}"), ('\u{1ee9b}', "\u{63a}"),
+    ('\u{1eea1}', "\u{628}"), ('\u{1eea2}', "\u{62c}"), ('\u{1eea3}', "\u{62f}"),
+    ('\u{1eea5}', "\u{648}"), ('\u{1eea6}', "\u{632}"), ('\u{1eea7}', "\u{62d}"),
+    ('\u{1eea8}', "\u{637}"), ('\u{1eea9}', "\u{64a}"), ('\u{1eeab}', "\u{644}"),
+    ('\u{1eeac}', "\u{645}"), ('\u{1eead}', "\u{646}"), ('\u{1eeae}', "\u{633}"),
+    ('\u{1eeaf}', "\u{639}"), ('\u{1eeb0}', "\u{641}"), ('\u{1eeb1}', "\u{635}"),
+    ('\u{1eeb2}', "\u{642}"), ('\u{1eeb3}', "\u{631}"), ('\u{1eeb4}', "\u{634}"),
+    ('\u{1eeb5}', "\u{62a}"), ('\u{1eeb6}', "\u{62b}"), ('\u{1eeb7}', "\u{62e}"),
+    ('\u{1eeb8}', "\u{630}"), ('\u{1eeb9}', "\u{636}"), ('\u{1eeba}', "\u{638}"),
+    ('\u{1eebb}', "\u{63a}"), ('\u{1f100}', "\u{30}\u{2e}"), ('\u{1f101}', "\u{30}\u{2c}"),
+    ('\u{1f102}', "\u{31}\u{2c}"), ('\u{1f103}', "\u{32}\u{2c}"), ('\u{1f104}', "\u{33}\u{2c}"),
+    ('\u{1f105}', "\u{34}\u{2c}"), ('\u{1f106}', "\u{35}\u{2c}"), ('\u{1f107}', "\u{36}\u{2c}"),
+    ('\u{1f108}', "\u{37}\u{2c}"), ('\u{1f109}', "\u{38}\u{2c}"), ('\u{1f10a}', "\u{39}\u{2c}"),
+    ('\u{1f110}', "\u{28}\u{41}\u{29}"), ('\u{1f111}', "\u{28}\u{42}\u{29}"), ('\u{1f112}', "\u{28}\u{43}\u{29}"),
+    ('\u{1f113}', "\u{28}\u{44}\u{29}"), ('\u{1f114}', "\u{28}\u{45}\u{29}"), ('\u{1f115}', "\u{28}\u{46}\u{29}"),
+    ('\u{1f116}', "\u{28}\u{47}\u{29}"), ('\u{1f117}', "\u{28}\u{48}\u{29}"), ('\u{1f118}', "\u{28}\u{49}\u{29}"),
+    ('\u{1f119}', "\u{28}\u{4a}\u{29}"), ('\u{1f11a}', "\u{28}\u{4b}\u{29}"), ('\u{1f11b}', "\u{28}\u{4c}\u{29}"),
+    ('\u{1f11c}', "\u{28}\u{4d}\u{29}"), ('\u{1f11d}', "\u{28}\u{4e}\u{29}"), ('\u{1f11e}', "\u{28}\u{4f}\u{29}"),
+    ('\u{1f11f}', "\u{28}\u{50}\u{29}"), ('\u{1f120}', "\u{28}\u{51}\u{29}"), ('\u{1f121}', "\u{28}\u{52}\u{29}"),
+    ('\u{1f122}', "\u{28}\u{53}\u{29}"), ('\u{1f123}', "\u{28}\u{54}\u{29}"), ('\u{1f124}', "\u{28}\u{55}\u{29}"),
+    ('\u{1f125}', "\u{28}\u{56}\u{29}"), ('\u{1f126}', "\u{28}\u{57}\u{29}"), ('\u{1f127}', "\u{28}\u{58}\u{29}"),
+    ('\u{1f128}', "\u{28}\u{59}\u{29}"), ('\u{1f129}', "\u{28}\u{5a}\u{29}"), ('\u{1f12a}', "\u{3014}\u{53}\u{3015}"),
+    ('\u{1f12b}', "\u{43}"), ('\u{1f12c}', "\u{52}"), ('\u{1f12d}', "\u{43}\u{44}"),
+    ('\u{1f12e}', "\u{57}\u{5a}"), ('\u{1f130}', "\u{41}"), ('\u{1f131}', "\u{42}"),
+    ('\u{1f132}', "\u{43}"), ('\u{1f133}', "\u{44}"), ('\u{1f134}', "\u{45}"),
+    ('\u{1f135}', "\u{46}"), ('\u{1f136}', "\u{47}"), ('\u{1f137}', "\u{48}"),
+    ('\u{1f138}', "\u{49}"), ('\u{1f139}', "\u{4a}"), ('\u{1f13a}', "\u{4b}"),
+    ('\u{1f13b}', "\u{4c}"), ('\u{1f13c}', "\u{4d}"), ('\u{1f13d}', "\u{4e}"),
+    ('\u{1f13e}', "\u{4f}"), ('\u{1f13f}', "\u{50}"), ('\u{1f140}', "\u{51}"),
+    ('\u{1f141}', "\u{52}"), ('\u{1f142}', "\u{53}"), ('\u{1f143}', "\u{54}"),
+    ('\u{1f144}', "\u{55}"), ('\u{1f145}', "\u{56}"), ('\u{1f146}', "\u{57}"),
+    ('\u{1f147}', "\u{58}"), ('\u{1f148}', "\u{59}"), ('\u{1f149}', "\u{5a}"),
+    ('\u{1f14a}', "\u{48}\u{56}"), ('\u{1f14b}', "\u{4d}\u{56}"), ('\u{1f14c}', "\u{53}\u{44}"),
+    ('\u{1f14d}', "\u{53}\u{53}"), ('\u{1f14e}', "\u{50}\u{50}\u{56}"), ('\u{1f14f}', "\u{57}\u{43}"),
+    ('\u{1f16a}', "\u{4d}\u{43}"), ('\u{1f16b}', "\u{4d}\u{44}"), ('\u{1f16c}', "\u{4d}\u{52}"),
+    ('\u{1f190}', "\u{44}\u{4a}"), ('\u{1f200}', "\u{307b}\u{304b}"), ('\u{1f201}', "\u{30b3}\u{30b3}"),
+    ('\u{1f202}', "\u{30b5}"), ('\u{1f210}', "\u{624b}"), ('\u{1f211}', "\u{5b57}"),
+    ('\u{1f212}', "\u{53cc}"), ('\u{1f213}', "\u{30c6}\u{3099}"), ('\u{1f214}', "\u{4e8c}"),
+    ('\u{1f215}', "\u{591a}"), ('\u{1f216}', "\u{89e3}"), ('\u{1f217}', "\u{5929}"),
+    ('\u{1f218}', "\u{4ea4}"), ('\u{1f219}', "\u{6620}"), ('\u{1f21a}', "\u{7121}"),
+    ('\u{1f21b}', "\u{6599}"), ('\u{1f21c}', "\u{524d}"), ('\u{1f21d}', "\u{5f8c}"),
+    ('\u{1f21e}', "\u{518d}"), ('\u{1f21f}', "\u{65b0}"), ('\u{1f220}', "\u{521d}"),
+    ('\u{1f221}', "\u{7d42}"), ('\u{1f222}', "\u{751f}"), ('\u{1f223}', "\u{8ca9}"),
+    ('\u{1f224}', "\u{58f0}"), ('\u{1f225}', "\u{5439}"), ('\u{1f226}', "\u{6f14}"),
+    ('\u{1f227}', "\u{6295}"), ('\u{1f228}', "\u{6355}"), ('\u{1f229}', "\u{4e00}"),
+    ('\u{1f22a}', "\u{4e09}"), ('\u{1f22b}', "\u{904a}"), ('\u{1f22c}', "\u{5de6}"),
+    ('\u{1f22d}', "\u{4e2d}"), ('\u{1f22e}', "\u{53f3}"), ('\u{1f22f}', "\u{6307}"),
+    ('\u{1f230}', "\u{8d70}"), ('\u{1f231}', "\u{6253}"), ('\u{1f232}', "\u{7981}"),
+    ('\u{1f233}', "\u{7a7a}"), ('\u{1f234}', "\u{5408}"), ('\u{1f235}', "\u{6e80}"),
+    ('\u{1f236}', "\u{6709}"), ('\u{1f237}', "\u{6708}"), ('\u{1f238}', "\u{7533}"),
+    ('\u{1f239}', "\u{5272}"), ('\u{1f23a}', "\u{55b6}"), ('\u{1f23b}', "\u{914d}"),
+    ('\u{1f240}', "\u{3014}\u{672c}\u{3015}"), ('\u{1f241}', "\u{3014}\u{4e09}\u{3015}"), ('\u{1f242}', "\u{3014}\u{4e8c}\u{3015}"),
+    ('\u{1f243}', "\u{3014}\u{5b89}\u{3015}"), ('\u{1f244}', "\u{3014}\u{70b9}\u{3015}"), ('\u{1f245}', "\u{3014}\u{6253}\u{3015}"),
+    ('\u{1f246}', "\u{3014}\u{76d7}\u{3015}"), ('\u{1f247}', "\u{3014}\u{52dd}\u{3015}"), ('\u{1f248}', "\u{3014}\u{6557}\u{3015}"),
+    ('\u{1f250}', "\u{5f97}"), ('\u{1f251}', "\u{53ef}"), ('\u{1fbf0}', "\u{30}"),
+    ('\u{1fbf1}', "\u{31}"), ('\u{1fbf2}', "\u{32}"), ('\u{1fbf3}', "\u{33}"),
+    ('\u{1fbf4}', "\u{34}"), ('\u{1fbf5}', "\u{35}"), ('\u{1fbf6}', "\u{36}"),
+    ('\u{1fbf7}', "\u{37}"), ('\u{1fbf8}', "\u{38}"), ('\u{1fbf9}', "\u{39}"),
+];
+
+pub const COMPOSITION: &[(char, char, char)] = &[
+    ('\u{3c}', '\u{338}', '\u{226e}'), ('\u{3d}', '\u{338}', '\u{2260}'), ('\u{3e}', '\u{338}', '\u{226f}'),
+    ('\u{41}', '\u{300}', '\u{c0}'), ('\u{41}', '\u{301}', '\u{c1}'), ('\u{41}', '\u{302}', '\u{c2}'),
+    ('\u{41}', '\u{303}', '\u{c3}'), ('\u{41}', '\u{304}', '\u{100}'), ('\u{41}', '\u{306}', '\u{102}'),
+    ('\u{41}', '\u{307}', '\u{226}'), ('\u{41}', '\u{308}', '\u{c4}'), ('\u{41}', '\u{309}', '\u{1ea2}'),
+    ('\u{41}', '\u{30a}', '\u{c5}'), ('\u{41}', '\u{30c}', '\u{1cd}'), ('\u{41}', '\u{30f}', '\u{200}'),
+    ('\u{41}', '\u{311}', '\u{202}'), ('\u{41}', '\u{323}', '\u{1ea0}'), ('\u{41}', '\u{325}', '\u{1e00}'),
+    ('\u{41}', '\u{328}', '\u{104}'), ('\u{42}', '\u{307}', '\u{1e02}'), ('\u{42}', '\u{323}', '\u{1e04}'),
+    ('\u{42}', '\u{331}', '\u{1e06}'), ('\u{43}', '\u{301}', '\u{106}'), ('\u{43}', '\u{302}', '\u{108}'),
+    ('\u{43}', '\u{307}', '\u{10a}'), ('\u{43}', '\u{30c}', '\u{10c}'), ('\u{43}', '\u{327}', '\u{c7}'),
+    ('\u{44}', '\u{307}', '\u{1e0a}'), ('\u{44}', '\u{30c}', '\u{10e}'), ('\u{44}', '\u{323}', '\u{1e0c}'),
+    ('\u{44}', '\u{327}', '\u{1e10}'), ('\u{44}', '\u{32d}', '\u{1e12}'), ('\u{44}', '\u{331}', '\u{1e0e}'),
+    ('\u{45}', '\u{300}', '\u{c8}'), ('\u{45}', '\u{301}', '\u{c9}'), ('\u{45}', '\u{302}', '\u{ca}'),
+    ('\u{45}', '\u{303}', '\u{1ebc}'), ('\u{45}', '\u{304}', '\u{112}'), ('\u{45}', '\u{306}', '\u{114}'),
+    ('\u{45}', '\u{307}', '\u{116}'), ('\u{45}', '\u{308}', '\u{cb}'), ('\u{45}', '\u{309}', '\u{1eba}'),
+    ('\u{45}', '\u{30c}', '\u{11a}'), ('\u{45}', '\u{30f}', '\u{204}'), ('\u{45}', '\u{311}', '\u{206}'),
+    ('\u{45}', '\u{323}', '\u{1eb8}'), ('\u{45}', '\u{327}', '\u{228}'), ('\u{45}', '\u{328}', '\u{118}'),
+    ('\u{45}', '\u{32d}', '\u{1e18}'), ('\u{45}', '\u{330}', '\u{1e1a}'), ('\u{46}', '\u{307}', '\u{1e1e}'),
+    ('\u{47}', '\u{301}', '\u{1f4}'), ('\u{47}', '\u{302}', '\u{11c}'), ('\u{47}', '\u{304}', '\u{1e20}'),
+    ('\u{47}', '\u{306}', '\u{11e}'), ('\u{47}', '\u{307}', '\u{120}'), ('\u{47}', '\u{30c}', '\u{1e6}'),
+    ('\u{47}', '\u{327}', '\u{122}'), ('\u{48}', '\u{302}', '\u{124}'), ('\u{48}', '\u{307}', '\u{1e22}'),
+    ('\u{48}', '\u{308}', '\u{1e26}'), ('\u{48}', '\u{30c}', '\u{21e}'), ('\u{48}', '\u{323}', '\u{1e24}'),
+    ('\u{48}', '\u{327}', '\u{1e28}'), ('\u{48}', '\u{32e}', '\u{1e2a}'), ('\u{49}', '\u{300}', '\u{cc}'),
+    ('\u{49}', '\u{301}', '\u{cd}'), ('\u{49}', '\u{302}', '\u{ce}'), ('\u{49}', '\u{303}', '\u{128}'),
+    ('\u{49}', '\u{304}', '\u{12a}'), ('\u{49}', '\u{306}', '\u{12c}'), ('\u{49}', '\u{307}', '\u{130}'),
+    ('\u{49}', '\u{308}', '\u{cf}'), ('\u{49}', '\u{309}', '\u{1ec8}'), ('\u{49}', '\u{30c}', '\u{1cf}'),
+    ('\u{49}', '\u{30f}', '\u{208}'), ('\u{49}', '\u{311}', '\u{20a}'), ('\u{49}', '\u{323}', '\u{1eca}'),
+    ('\u{49}', '\u{328}', '\u{12e}'), ('\u{49}', '\u{330}', '\u{1e2c}'), ('\u{4a}', '\u{302}', '\u{134}'),
+    ('\u{4b}', '\u{301}', '\u{1e30}'), ('\u{4b}', '\u{30c}', '\u{1e8}'), ('\u{4b}', '\u{323}', '\u{1e32}'),
+    ('\u{4b}', '\u{327}', '\u{136}'), ('\u{4b}', '\u{331}', '\u{1e34}'), ('\u{4c}', '\u{301}', '\u{139}'),
+    ('\u{4c}', '\u{30c}', '\u{13d}'), ('\u{4c}', '\u{323}', '\u{1e36}'), ('\u{4c}', '\u{327}', '\u{13b}'),
+    ('\u{4c}', '\u{32d}', '\u{1e3c}'), ('\u{4c}', '\u{331}', '\u{1e3a}'), ('\u{4d}', '\u{301}', '\u{1e3e}'),
+    ('\u{4d}', '\u{307}', '\u{1e40}'), ('\u{4d}', '\u{323}', '\u{1e42}'), ('\u{4e}', '\u{300}', '\u{1f8}'),
+    ('\u{4e}', '\u{301}', '\u{143}'), ('\u{4e}', '\u{303}', '\u{d1}'), ('\u{4e}', '\u{307}', '\u{1e44}'),
+    ('\u{4e}', '\u{30c}', '\u{147}'), ('\u{4e}', '\u{323}', '\u{1e46}'), ('\u{4e}', '\u{327}', '\u{145}'),
+    ('\u{4e}', '\u{32d}', '\u{1e4a}'), ('\u{4e}', '\u{331}', '\u{1e48}'), ('\u{4f}', '\u{300}', '\u{d2}'),
+    ('\u{4f}', '\u{301}', '\u{d3}'), ('\u{4f}', '\u{302}', '\u{d4}'), ('\u{4f}', '\u{303}', '\u{d5}'),
+    ('\u{4f}', '\u{304}', '\u{14c}'), ('\u{4f}', '\u{306}', '\u{14e}'), ('\u{4f}', '\u{307}', '\u{22e}'),
+    ('\u{4f}', '\u{308}', '\u{d6}'), ('\u{4f}', '\u{309}', '\u{1ece}'), ('\u{4f}', '\u{30b}', '\u{150}'),
+    ('\u{4f}', '\u{30c}', '\u{1d1}'), ('\u{4f}', '\u{30f}', '\u{20c}'), ('\u{4f}', '\u{311}', '\u{20e}'),
+    ('\u{4f}', '\u{31b}', '\u{1a0}'), ('\u{4f}', '\u{323}', '\u{1ecc}'), ('\u{4f}', '\u{328}', '\u{1ea}'),
+    ('\u{50}', '\u{301}', '\u{1e54}'), ('\u{50}', '\u{307}', '\u{1e56}'), ('\u{52}', '\u{301}', '\u{154}'),
+    ('\u{52}', '\u{307}', '\u{1e58}'), ('\u{52}', '\u{30c}', '\u{158}'), ('\u{52}', '\u{30f}', '\u{210}'),
+    ('\u{52}', '\u{311}', '\u{212}'), ('\u{52}', '\u{323}', '\u{1e5a}'), ('\u{52}', '\u{327}', '\u{156}'),
+    ('\u{52}', '\u{331}', '\u{1e5e}'), ('\u{53}', '\u{301}', '\u{15a}'), ('\u{53}', '\u{302}', '\u{15c}'),
+    ('\u{53}', '\u{307}', '\u{1e60}'), ('\u{53}', '\u{30c}', '\u{160}'), ('\u{53}', '\u{323}', '\u{1e62}'),
+    ('\u{53}', '\u{326}', '\u{218}'), ('\u{53}', '\u{327}', '\u{15e}'), ('\u{54}', '\u{307}', '\u{1e6a}'),
+    ('\u{54}', '\u{30c}', '\u{164}'), ('\u{54}', '\u{323}', '\u{1e6c}'), ('\u{54}', '\u{326}', '\u{21a}'),
+    ('\u{54}', '\u{327}', '\u{162}'), ('\u{54}', '\u{32d}', '\u{1e70}'), ('\u{54}', '\u{331}', '\u{1e6e}'),
+    ('\u{55}', '\u{300}', '\u{d9}'), ('\u{55}', '\u{301}', '\u{da}'), ('\u{55}', '\u{302}', '\u{db}'),
+    ('\u{55}', '\u{303}', '\u{168}'), ('\u{55}', '\u{304}', '\u{16a}'), ('\u{55}', '\u{306}', '\u{16c}'),
+    ('\u{55}', '\u{308}', '\u{dc}'), ('\u{55}', '\u{309}', '\u{1ee6}'), ('\u{55}', '\u{30a}', '\u{16e}'),
+    ('\u{55}', '\u{30b}', '\u{170}'), ('\u{55}', '\u{30c}', '\u{1d3}'), ('\u{55}', '\u{30f}', '\u{214}'),
+    ('\u{55}', '\u{311}', '\u{216}'), ('\u{55}', '\u{31b}', '\u{1af}'), ('\u{55}', '\u{323}', '\u{1ee4}'),
+    ('\u{55}', '\u{324}', '\u{1e72}'), ('\u{55}', '\u{328}', '\u{172}'), ('\u{55}', '\u{32d}', '\u{1e76}'),
+    ('\u{55}', '\u{330}', '\u{1e74}'), ('\u{56}', '\u{303}', '\u{1e7c}'), ('\u{56}', '\u{323}', '\u{1e7e}'),
+    ('\u{57}', '\u{300}', '\u{1e80}'), ('\u{57}', '\u{301}', '\u{1e82}'), ('\u{57}', '\u{302}', '\u{174}'),
+    ('\u{57}', '\u{307}', '\u{1e86}'), ('\u{57}', '\u{308}', '\u{1e84}'), ('\u{57}', '\u{323}', '\u{1e88}'),
+    ('\u{58}', '\u{307}', '\u{1e8a}'), ('\u{58}', '\u{308}', '\u{1e8c}'), ('\u{59}', '\u{300}', '\u{1ef2}'),
+    ('\u{59}', '\u{301}', '\u{dd}'), ('\u{59}', '\u{302}', '\u{176}'), ('\u{59}', '\u{303}', '\u{1ef8}'),
+    ('\u{59}', '\u{304}', '\u{232}'), ('\u{59}', '\u{307}', '\u{1e8e}'), ('\u{59}', '\u{308}', '\u{178}'),
+    ('\u{59}', '\u{309}', '\u{1ef6}'), ('\u{59}', '\u{323}', '\u{1ef4}'), ('\u{5a}', '\u{301}', '\u{179}'),
+    ('\u{5a}', '\u{302}', '\u{1e90}'), ('\u{5a}', '\u{307}', '\u{17b}'), ('\u{5a}', '\u{30c}', '\u{17d}'),
+    ('\u{5a}', '\u{323}', '\u{1e92}'), ('\u{5a}', '\u{331}', '\u{1e94}'), ('\u{61}', '\u{300}', '\u{e0}'),
+    ('\u{61}', '\u{301}', '\u{e1}'), ('\u{61}', '\u{302}', '\u{e2}'), ('\u{61}', '\u{303}', '\u{e3}'),
+    ('\u{61}', '\u{304}', '\u{101}'), ('\u{61}', '\u{306}', '\u{103}'), ('\u{61}', '\u{307}', '\u{227}'),
+    ('\u{61}', '\u{308}', '\u{e4}'), ('\u{61}', '\u{309}', '\u{1ea3}'), ('\u{61}', '\u{30a}', '\u{e5}'),
+    ('\u{61}', '\u{30c}', '\u{1ce}'), ('\u{61}', '\u{30f}', '\u{201}'), ('\u{61}', '\u{311}', '\u{203}'),
+    ('\u{61}', '\u{323}', '\u{1ea1}'), ('\u{61}', '\u{325}', '\u{1e01}'), ('\u{61}', '\u{328}', '\u{105}'),
+    ('\u{62}', '\u{307}', '\u{1e03}'), ('\u{62}', '\u{323}', '\u{1e05}'), ('\u{62}', '\u{331}', '\u{1e07}'),
+    ('\u{63}', '\u{301}', '\u{107}'), ('\u{63}', '\u{302}', '\u{109}'), ('\u{63}', '\u{307}', '\u{10b}'),
+    ('\u{63}', '\u{30c}', '\u{10d}'), ('\u{63}', '\u{327}', '\u{e7}'), ('\u{64}', '\u{307}', '\u{1e0b}'),
+    ('\u{64}', '\u{30c}', '\u{10f}'), ('\u{64}', '\u{323}', '\u{1e0d}'), ('\u{64}', '\u{327}', '\u{1e11}'),
+    ('\u{64}', '\u{32d}', '\u{1e13}'), ('\u{64}', '\u{331}', '\u{1e0f}'), ('\u{65}', '\u{300}', '\u{e8}'),
+    ('\u{65}', '\u{301}', '\u{e9}'), ('\u{65}', '\u{302}', '\u{ea}'), ('\u{65}', '\u{303}', '\u{1ebd}'),
+    ('\u{65}', '\u{304}', '\u{113}'), ('\u{65}', '\u{306}', '\u{115}'), ('\u{65}', '\u{307}', '\u{117}'),
+    ('\u{65}', '\u{308}', '\u{eb}'), ('\u{65}', '\u{309}', '\u{1ebb}'), ('\u{65}', '\u{30c}', '\u{11b}'),
+    ('\u{65}', '\u{30f}', '\u{205}'), ('\u{65}', '\u{311}', '\u{207}'), ('\u{65}', '\u{323}', '\u{1eb9}'),
+    ('\u{65}', '\u{327}', '\u{229}'), ('\u{65}', '\u{328}', '\u{119}'), ('\u{65}', '\u{32d}', '\u{1e19}'),
+    ('\u{65}', '\u{330}', '\u{1e1b}'), ('\u{66}', '\u{307}', '\u{1e1f}'), ('\u{67}', '\u{301}', '\u{1f5}'),
+    ('\u{67}', '\u{302}', '\u{11d}'), ('\u{67}', '\u{304}', '\u{1e21}'), ('\u{67}', '\u{306}', '\u{11f}'),
+    ('\u{67}', '\u{307}', '\u{121}'), ('\u{67}', '\u{30c}', '\u{1e7}'), ('\u{67}', '\u{327}', '\u{123}'),
+    ('\u{68}', '\u{302}', '\u{125}'), ('\u{68}', '\u{307}', '\u{1e23}'), ('\u{68}', '\u{308}', '\u{1e27}'),
+    ('\u{68}', '\u{30c}', '\u{21f}'), ('\u{68}', '\u{323}', '\u{1e25}'), ('\u{68}', '\u{327}', '\u{1e29}'),
+    ('\u{68}', '\u{32e}', '\u{1e2b}'), ('\u{68}', '\u{331}', '\u{1e96}'), ('\u{69}', '\u{300}', '\u{ec}'),
+    ('\u{69}', '\u{301}', '\u{ed}'), ('\u{69}', '\u{302}', '\u{ee}'), ('\u{69}', '\u{303}', '\u{129}'),
+    ('\u{69}', '\u{304}', '\u{12b}'), ('\u{69}', '\u{306}', '\u{12d}'), ('\u{69}', '\u{308}', '\u{ef}'),
+    ('\u{69}', '\u{309}', '\u{1ec9}'), ('\u{69}', '\u{30c}', '\u{1d0}'), ('\u{69}', '\u{30f}', '\u{209}'),
+    ('\u{69}', '\u{311}', '\u{20b}'), ('\u{69}', '\u{323}', '\u{1ecb}'), ('\u{69}', '\u{328}', '\u{12f}'),
+    ('\u{69}', '\u{330}', '\u{1e2d}'), ('\u{6a}', '\u{302}', '\u{135}'), ('\u{6a}', '\u{30c}', '\u{1f0}'),
+    ('\u{6b}', '\u{301}', '\u{1e31}'), ('\u{6b}', '\u{30c}', '\u{1e9}'), ('\u{6b}', '\u{323}', '\u{1e33}'),
+    ('\u{6b}', '\u{327}', '\u{137}'), ('\u{6b}', '\u{331}', '\u{1e35}'), ('\u{6c}', '\u{301}', '\u{13a}'),
+    ('\u{6c}', '\u{30c}', '\u{13e}'), ('\u{6c}', '\u{323}', '\u{1e37}'), ('\u{6c}', '\u{327}', '\u{13c}'),
+    ('\u{6c}', '\u{32d}', '\u{1e3d}'), ('\u{6c}', '\u{331}', '\u{1e3b}'), ('\u{6d}', '\u{301}', '\u{1e3f}'),
+    ('\u{6d}', '\u{307}', '\u{1e41}'), ('\u{6d}', '\u{323}', '\u{1e43}'), ('\u{6e}', '\u{300}', '\u{1f9}'),
+    ('\u{6e}', '\u{301}', '\u{144}'), ('\u{6e}', '\u{303}', '\u{f1}'), ('\u{6e}', '\u{307}', '\u{1e45}'),
+    ('\u{6e}', '\u{30c}', '\u{148}'), ('\u{6e}', '\u{323}', '\u{1e47}'), ('\u{6e}', '\u{327}', '\u{146}'),
+    ('\u{6e}', '\u{32d}', '\u{1e4b}'), ('\u{6e}', '\u{331}', '\u{1e49}'), ('\u{6f}', '\u{300}', '\u{f2}'),
+    ('\u{6f}', '\u{301}', '\u{f3}'), ('\u{6f}', '\u{302}', '\u{f4}'), ('\u{6f}', '\u{303}', '\u{f5}'),
+    ('\u{6f}', '\u{304}', '\u{14d}'), ('\u{6f}', '\u{306}', '\u{14f}'), ('\u{6f}', '\u{307}', '\u{22f}'),
+    ('\u{6f}', '\u{308}', '\u{f6}'), ('\u{6f}', '\u{309}', '\u{1ecf}'), ('\u{6f}', '\u{30b}', '\u{151}'),
+    ('\u{6f}', '\u{30c}', '\u{1d2}'), ('\u{6f}', '\u{30f}', '\u{20d}'), ('\u{6f}', '\u{311}', '\u{20f}'),
+    ('\u{6f}', '\u{31b}', '\u{1a1}'), ('\u{6f}', '\u{323}', '\u{1ecd}'), ('\u{6f}', '\u{328}', '\u{1eb}'),
+    ('\u{70}', '\u{301}', '\u{1e55}'), ('\u{70}', '\u{307}', '\u{1e57}'), ('\u{72}', '\u{301}', '\u{155}'),
+    ('\u{72}', '\u{307}', '\u{1e59}'), ('\u{72}', '\u{30c}', '\u{159}'), ('\u{72}', '\u{30f}', '\u{211}'),
+    ('\u{72}', '\u{311}', '\u{213}'), ('\u{72}', '\u{323}', '\u{1e5b}'), ('\u{72}', '\u{327}', '\u{157}'),
+    ('\u{72}', '\u{331}', '\u{1e5f}'), ('\u{73}', '\u{301}', '\u{15b}'), ('\u{73}', '\u{302}', '\u{15d}'),
+    ('\u{73}', '\u{307}', '\u{1e61}'), ('\u{73}', '\u{30c}', '\u{161}'), ('\u{73}', '\u{323}', '\u{1e63}'),
+    ('\u{73}', '\u{326}', '\u{219}'), ('\u{73}', '\u{327}', '\u{15f}'), ('\u{74}', '\u{307}', '\u{1e6b}'),
+    ('\u{74}', '\u{308}', '\u{1e97}'), ('\u{74}', '\u{30c}', '\u{165}'), ('\u{74}', '\u{323}', '\u{1e6d}'),
+    ('\u{74}', '\u{326}', '\u{21b}'), ('\u{74}', '\u{327}', '\u{163}'), ('\u{74}', '\u{32d}', '\u{1e71}'),
+    ('\u{74}', '\u{331}', '\u{1e6f}'), ('\u{75}', '\u{300}', '\u{f9}'), ('\u{75}', '\u{301}', '\u{fa}'),
+    ('\u{75}', '\u{302}', '\u{fb}'), ('\u{75}', '\u{303}', '\u{169}'), ('\u{75}', '\u{304}', '\u{16b}'),
+    ('\u{75}', '\u{306}', '\u{16d}'), ('\u{75}', '\u{308}', '\u{fc}'), ('\u{75}', '\u{309}', '\u{1ee7}'),
+    ('\u{75}', '\u{30a}', '\u{16f}'), ('\u{75}', '\u{30b}', '\u{171}'), ('\u{75}', '\u{30c}', '\u{1d4}'),
+    ('\u{75}', '\u{30f}', '\u{215}'), ('\u{75}', '\u{311}', '\u{217}'), ('\u{75}', '\u{31b}', '\u{1b0}'),
+    ('\u{75}', '\u{323}', '\u{1ee5}'), ('\u{75}', '\u{324}', '\u{1e73}'), ('\u{75}', '\u{328}', '\u{173}'),
+    ('\u{75}', '\u{32d}', '\u{1e77}'), ('\u{75}', '\u{330}', '\u{1e75}'), ('\u{76}', '\u{303}', '\u{1e7d}'),
+    ('\u{76}', '\u{323}', '\u{1e7f}'), ('\u{77}', '\u{300}', '\u{1e81}'), ('\u{77}', '\u{301}', '\u{1e83}'),
+    ('\u{77}', '\u{302}', '\u{175}'), ('\u{77}', '\u{307}', '\u{1e87}'), ('\u{77}', '\u{308}', '\u{1e85}'),
+    ('\u{77}', '\u{30a}', '\u{1e98}'), ('\u{77}', '\u{323}', '\u{1e89}'), ('\u{78}', '\u{307}', '\u{1e8b}'),
+    ('\u{78}', '\u{308}', '\u{1e8d}'), ('\u{79}', '\u{300}', '\u{1ef3}'), ('\u{79}', '\u{301}', '\u{fd}'),
+    ('\u{79}', '\u{302}', '\u{177}'), ('\u{79}', '\u{303}', '\u{1ef9}'), ('\u{79}', '\u{304}', '\u{233}'),
+    ('\u{79}', '\u{307}', '\u{1e8f}'), ('\u{79}', '\u{308}', '\u{ff}'), ('\u{79}', '\u{309}', '\u{1ef7}'),
+    ('\u{79}', '\u{30a}', '\u{1e99}'), ('\u{79}', '\u{323}', '\u{1ef5}'), ('\u{7a}', '\u{301}', '\u{17a}'),
+    ('\u{7a}', '\u{302}', '\u{1e91}'), ('\u{7a}', '\u{307}', '\u{17c}'), ('\u{7a}', '\u{30c}', '\u{17e}'),
+    ('\u{7a}', '\u{323}', '\u{1e93}'), ('\u{7a}', '\u{331}', '\u{1e95}'), ('\u{a8}', '\u{300}', '\u{1fed}'),
+    ('\u{a8}', '\u{301}', '\u{385}'), ('\u{a8}', '\u{342}', '\u{1fc1}'), ('\u{c2}', '\u{300}', '\u{1ea6}'),
+    ('\u{c2}', '\u{301}', '\u{1ea4}'), ('\u{c2}', '\u{303}', '\u{1eaa}'), ('\u{c2}', '\u{309}', '\u{1ea8}'),
+    ('\u{c4}', '\u{304}', '\u{1de}'), ('\u{c5}', '\u{301}', '\u{1fa}'), ('\u{c6}', '\u{301}', '\u{1fc}'),
+    ('\u{c6}', '\u{304}', '\u{1e2}'), ('\u{c7}', '\u{301}', '\u{1e08}'), ('\u{ca}', '\u{300}', '\u{1ec0}'),
+    ('\u{ca}', '\u{301}', '\u{1ebe}'), ('\u{ca}', '\u{303}', '\u{1ec4}'), ('\u{ca}', '\u{309}', '\u{1ec2}'),
+    ('\u{cf}', '\u{301}', '\u{1e2e}'), ('\u{d4}', '\u{300}', '\u{1ed2}'), ('\u{d4}', '\u{301}', '\u{1ed0}'),
+    ('\u{d4}', '\u{303}', '\u{1ed6}'), ('\u{d4}', '\u{309}', '\u{1ed4}'), ('\u{d5}', '\u{301}', '\u{1e4c}'),
+    ('\u{d5}', '\u{304}', '\u{22c}'), ('\u{d5}', '\u{308}', '\u{1e4e}'), ('\u{d6}', '\u{304}', '\u{22a}'),
+    ('\u{d8}', '\u{301}', '\u{1fe}'), ('\u{dc}', '\u{300}', '\u{1db}'), ('\u{dc}', '\u{301}', '\u{1d7}'),
+    ('\u{dc}', '\u{304}', '\u{1d5}'), ('\u{dc}', '\u{30c}', '\u{1d9}'), ('\u{e2}', '\u{300}', '\u{1ea7}'),
+    ('\u{e2}', '\u{301}', '\u{1ea5}'), ('\u{e2}', '\u{303}', '\u{1eab}'), ('\u{e2}', '\u{309}', '\u{1ea9}'),
+    ('\u{e4}', '\u{304}', '\u{1df}'), ('\u{e5}', '\u{301}', '\u{1fb}'), ('\u{e6}', '\u{301}', '\u{1fd}'),
+    ('\u{e6}', '\u{304}', '\u{1e3}'), ('\u{e7}', '\u{301}', '\u{1e09}'), ('\u{ea}', '\u{300}', '\u{1ec1}'),
+    ('\u{ea}', '\u{301}', '\u{1ebf}'), ('\u{ea}', '\u{303}', '\u{1ec5}'), ('\u{ea}', '\u{309}', '\u{1ec3}'),
+    ('\u{ef}', '\u{301}', '\u{1e2f}'), ('\u{f4}', '\u{300}', '\u{1ed3}'), ('\u{f4}', '\u{301}', '\u{1ed1}'),
+    ('\u{f4}', '\u{303}', '\u{1ed7}'), ('\u{f4}', '\u{309}', '\u{1ed5}'), ('\u{f5}', '\u{301}', '\u{1e4d}'),
+    ('\u{f5}', '\u{304}', '\u{22d}'), ('\u{f5}', '\u{308}', '\u{1e4f}'), ('\u{f6}', '\u{304}', '\u{22b}'),
+    ('\u{f8}', '\u{301}', '\u{1ff}'), ('\u{fc}', '\u{300}', '\u{1dc}'), ('\u{fc}', '\u{301}', '\u{1d8}'),
+    ('\u{fc}', '\u{304}', '\u{1d6}'), ('\u{fc}', '\u{30c}', '\u{1da}'), ('\u{102}', '\u{300}', '\u{1eb0}'),
+    ('\u{102}', '\u{301}', '\u{1eae}'), ('\u{102}', '\u{303}', '\u{1eb4}'), ('\u{102}', '\u{309}', '\u{1eb2}'),
+    ('\u{103}', '\u{300}', '\u{1eb1}'), ('\u{103}', '\u{301}', '\u{1eaf}'), ('\u{103}', '\u{303}', '\u{1eb5}'),
+    ('\u{103}', '\u{309}', '\u{1eb3}'), ('\u{112}', '\u{300}', '\u{1e14}'), ('\u{112}', '\u{301}', '\u{1e16}'),
+    ('\u{113}', '\u{300}', '\u{1e15}'), ('\u{113}', '\u{301}', '\u{1e17}'), ('\u{14c}', '\u{300}', '\u{1e50}'),
+    ('\u{14c}', '\u{301}', '\u{1e52}'), ('\u{14d}', '\u{300}', '\u{1e51}'), ('\u{14d}', '\u{301}', '\u{1e53}'),
+    ('\u{15a}', '\u{307}', '\u{1e64}'), ('\u{15b}', '\u{307}', '\u{1e65}'), ('\u{160}', '\u{307}', '\u{1e66}'),
+    ('\u{161}', '\u{307}', '\u{1e67}'), ('\u{168}', '\u{301}', '\u{1e78}'), ('\u{169}', '\u{301}', '\u{1e79}'),
+    ('\u{16a}', '\u{308}', '\u{1e7a}'), ('\u{16b}', '\u{308}', '\u{1e7b}'), ('\u{17f}', '\u{307}', '\u{1e9b}'),
+    ('\u{1a0}', '\u{300}', '\u{1edc}'), ('\u{1a0}', '\u{301}', '\u{1eda}'), ('\u{1a0}', '\u{303}', '\u{1ee0}'),
+    ('\u{1a0}', '\u{309}', '\u{1ede}'), ('\u{1a0}', '\u{323}', '\u{1ee2}'), ('\u{1a1}', '\u{300}', '\u{1edd}'),
+    ('\u{1a1}', '\u{301}', '\u{1edb}'), ('\u{1a1}', '\u{303}', '\u{1ee1}'), ('\u{1a1}', '\u{309}', '\u{1edf}'),
+    ('\u{1a1}', '\u{323}', '\u{1ee3}'), ('\u{1af}', '\u{300}', '\u{1eea}'), ('\u{1af}', '\u{301}', '\u{1ee8}'),
+    ('\u{1af}', '\u{303}', '\u{1eee}'), ('\u{1af}', '\u{309}', '\u{1eec}'), ('\u{1af}', '\u{323}', '\u{1ef0}'),
+    ('\u{1b0}', '\u{300}', '\u{1eeb}'), ('\u{1b0}', '\u{301}', '\u{1ee9}'), ('\u{1b0}', '\u{303}', '\u{1eef}'),
+    ('\u{1b0}', '\u{309}', '\u{1eed}'), ('\u{1b0}', '\u{323}', '\u{1ef1}'), ('\u{1b7}', '\u{30c}', '\u{1ee}'),
+    ('\u{1ea}', '\u{304}', '\u{1ec}'), ('\u{1eb}', '\u{304}', '\u{1ed}'), ('\u{226}', '\u{304}', '\u{1e0}'),
+    ('\u{227}', '\u{304}', '\u{1e1}'), ('\u{228}', '\u{306}', '\u{1e1c}'), ('\u{229}', '\u{306}', '\u{1e1d}'),
+    ('\u{22e}', '\u{304}', '\u{230}'), ('\u{22f}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30c}', '\u{1ef}'),
+    ('\u{391}', '\u{300}', '\u{1fba}'), ('\u{391}', '\u{301}', '\u{386}'), ('\u{391}', '\u{304}', '\u{1fb9}'),
+    ('\u{391}', '\u{306}', '\u{1fb8}'), ('\u{391}', '\u{313}', '\u{1f08}'), ('\u{391}', '\u{314}', '\u{1f09}'),
+    ('\u{391}', '\u{345}', '\u{1fbc}'), ('\u{395}', '\u{300}', '\u{1fc8}'), ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1f18}'), ('\u{395}', '\u{314}', '\u{1f19}'), ('\u{397}', '\u{300}', '\u{1fca}'),
+    ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1f28}'), ('\u{397}', '\u{314}', '\u{1f29}'),
+    ('\u{397}', '\u{345}', '\u{1fcc}'), ('\u{399}', '\u{300}', '\u{1fda}'), ('\u{399}', '\u{301}', '\u{38a}'),
+    ('\u{399}', '\u{304}', '\u{1fd9}'), ('\u{399}', '\u{306}', '\u{1fd8}'), ('\u{399}', '\u{308}', '\u{3aa}'),
+    ('\u{399}', '\u{313}', '\u{1f38}'), ('\u{399}', '\u{314}', '\u{1f39}'), ('\u{39f}', '\u{300}', '\u{1ff8}'),
+    ('\u{39f}', '\u{301}', '\u{38c}'), ('\u{39f}', '\u{313}', '\u{1f48}'), ('\u{39f}', '\u{314}', '\u{1f49}'),
+    ('\u{3a1}', '\u{314}', '\u{1fec}'), ('\u{3a5}', '\u{300}', '\u{1fea}'), ('\u{3a5}', '\u{301}', '\u{38e}'),
+    ('\u{3a5}', '\u{304}', '\u{1fe9}'), ('\u{3a5}', '\u{306}', '\u{1fe8}'), ('\u{3a5}', '\u{308}', '\u{3ab}'),
+    ('\u{3a5}', '\u{314}', '\u{1f59}'), ('\u{3a9}', '\u{300}', '\u{1ffa}'), ('\u{3a9}', '\u{301}', '\u{38f}'),
+    ('\u{3a9}', '\u{313}', '\u{1f68}'), ('\u{3a9}', '\u{314}', '\u{1f69}'), ('\u{3a9}', '\u{345}', '\u{1ffc}'),
+    ('\u{3ac}', '\u{345}', '\u{1fb4}'), ('\u{3ae}', '\u{345}', '\u{1fc4}'), ('\u{3b1}', '\u{300}', '\u{1f70}'),
+    ('\u{3b1}', '\u{301}', '\u{3ac}'), ('\u{3b1}', '\u{304}', '\u{1fb1}'), ('\u{3b1}', '\u{306}', '\u{1fb0}'),
+    ('\u{3b1}', '\u{313}', '\u{1f00}'), ('\u{3b1}', '\u{314}', '\u{1f01}'), ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+    ('\u{3b1}', '\u{345}', '\u{1fb3}'), ('\u{3b5}', '\u{300}', '\u{1f72}'), ('\u{3b5}', '\u{301}', '\u{3ad}'),
+    ('\u{3b5}', '\u{313}', '\u{1f10}'), ('\u{3b5}', '\u{314}', '\u{1f11}'), ('\u{3b7}', '\u{300}', '\u{1f74}'),
+    ('\u{3b7}', '\u{301}', '\u{3ae}'), ('\u{3b7}', '\u{313}', '\u{1f20}'), ('\u{3b7}', '\u{314}', '\u{1f21}'),
+    ('\u{3b7}', '\u{342}', '\u{1fc6}'), ('\u{3b7}', '\u{345}', '\u{1fc3}'), ('\u{3b9}', '\u{300}', '\u{1f76}'),
+    ('\u{3b9}', '\u{301}', '\u{3af}'), ('\u{3b9}', '\u{304}', '\u{1fd1}'), ('\u{3b9}', '\u{306}', '\u{1fd0}'),
+    ('\u{3b9}', '\u{308}', '\u{3ca}'), ('\u{3b9}', '\u{313}', '\u{1f30}'), ('\u{3b9}', '\u{314}', '\u{1f31}'),
+    ('\u{3b9}', '\u{342}', '\u{1fd6}'), ('\u{3bf}', '\u{300}', '\u{1f78}'), ('\u{3bf}', '\u{301}', '\u{3cc}'),
+    ('\u{3bf}', '\u{313}', '\u{1f40}'), ('\u{3bf}', '\u{314}', '\u{1f41}'), ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+    ('\u{3c1}', '\u{314}', '\u{1fe5}'), ('\u{3c5}', '\u{300}', '\u{1f7a}'), ('\u{3c5}', '\u{301}', '\u{3cd}'),
+    ('\u{3c5}', '\u{304}', '\u{1fe1}'), ('\u{3c5}', '\u{306}', '\u{1fe0}'), ('\u{3c5}', '\u{308}', '\u{3cb}'),
+    ('\u{3c5}', '\u{313}', '\u{1f50}'), ('\u{3c5}', '\u{314}', '\u{1f51}'), ('\u{3c5}', '\u{342}', '\u{1fe6}'),
+    ('\u{3c9}', '\u{300}', '\u{1f7c}'), ('\u{3c9}', '\u{301}', '\u{3ce}'), ('\u{3c9}', '\u{313}', '\u{1f60}'),
+    ('\u{3c9}', '\u{314}', '\u{1f61}'), ('\u{3c9}', '\u{342}', '\u{1ff6}'), ('\u{3c9}', '\u{345}', '\u{1ff3}'),
+    ('\u{3ca}', '\u{300}', '\u{1fd2}'), ('\u{3ca}', '\u{301}', '\u{390}'), ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+    ('\u{3cb}', '\u{300}', '\u{1fe2}'), ('\u{3cb}', '\u{301}', '\u{3b0}'), ('\u{3cb}', '\u{342}', '\u{1fe7}'),
+    ('\u{3ce}', '\u{345}', '\u{1ff4}'), ('\u{3d2}', '\u{301}', '\u{3d3}'), ('\u{3d2}', '\u{308}', '\u{3d4}'),
+    ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4d0}'), ('\u{410}', '\u{308}', '\u{4d2}'),
+    ('\u{413}', '\u{301}', '\u{403}'), ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4d6}'),
+    ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4c1}'), ('\u{416}', '\u{308}', '\u{4dc}'),
+    ('\u{417}', '\u{308}', '\u{4de}'), ('\u{418}', '\u{300}', '\u{40d}'), ('\u{418}', '\u{304}', '\u{4e2}'),
+    ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4e4}'), ('\u{41a}', '\u{301}', '\u{40c}'),
+    ('\u{41e}', '\u{308}', '\u{4e6}'), ('\u{423}', '\u{304}', '\u{4ee}'), ('\u{423}', '\u{306}', '\u{40e}'),
+    ('\u{423}', '\u{308}', '\u{4f0}'), ('\u{423}', '\u{30b}', '\u{4f2}'), ('\u{427}', '\u{308}', '\u{4f4}'),
+    ('\u{42b}', '\u{308}', '\u{4f8}'), ('\u{42d}', '\u{308}', '\u{4ec}'), ('\u{430}', '\u{306}', '\u{4d1}'),
+    ('\u{430}', '\u{308}', '\u{4d3}'), ('\u{433}', '\u{301}', '\u{453}'), ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4d7}'), ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4c2}'),
+    ('\u{436}', '\u{308}', '\u{4dd}'), ('\u{437}', '\u{308}', '\u{4df}'), ('\u{438}', '\u{300}', '\u{45d}'),
+    ('\u{438}', '\u{304}', '\u{4e3}'), ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4e5}'),
+    ('\u{43a}', '\u{301}', '\u{45c}'), ('\u{43e}', '\u{308}', '\u{4e7}'), ('\u{443}', '\u{304}', '\u{4ef}'),
+    ('\u{443}', '\u{306}', '\u{45e}'), ('\u{443}', '\u{308}', '\u{4f1}'), ('\u{443}', '\u{30b}', '\u{4f3}'),
+    ('\u{447}', '\u{308}', '\u{4f5}'), ('\u{44b}', '\u{308}', '\u{4f9}'), ('\u{44d}', '\u{308}', '\u{4ed}'),
+    ('\u{456}', '\u{308}', '\u{457}'), ('\u{474}', '\u{30f}', '\u{476}'), ('\u{475}', '\u{30f}', '\u{477}'),
+    ('\u{4d8}', '\u{308}', '\u{4da}'), ('\u{4d9}', '\u{308}', '\u{4db}'), ('\u{4e8}', '\u{308}', '\u{4ea}'),
+    ('\u{4e9}', '\u{308}', '\u{4eb}'), ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'), ('\u{64a}', '\u{654}', '\u{626}'),
+    ('\u{6c1}', '\u{654}', '\u{6c2}'), ('\u{6d2}', '\u{654}', '\u{6d3}'), ('\u{6d5}', '\u{654}', '\u{6c0}'),
+    ('\u{928}', '\u{93c}', '\u{929}'), ('\u{930}', '\u{93c}', '\u{931}'), ('\u{933}', '\u{93c}', '\u{934}'),
+    ('\u{9c7}', '\u{9be}', '\u{9cb}'), ('\u{9c7}', '\u{9d7}', '\u{9cc}'), ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+    ('\u{b47}', '\u{b56}', '\u{b48}'), ('\u{b47}', '\u{b57}', '\u{b4c}'), ('\u{b92}', '\u{bd7}', '\u{b94}'),
+    ('\u{bc6}', '\u{bbe}', '\u{bca}'), ('\u{bc6}', '\u{bd7}', '\u{bcc}'), ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+    ('\u{c46}', '\u{c56}', '\u{c48}'), ('\u{cbf}', '\u{cd5}', '\u{cc0}'), ('\u{cc6}', '\u{cc2}', '\u{cca}'),
+    ('\u{cc6}', '\u{cd5}', '\u{cc7}'), ('\u{cc6}', '\u{cd6}', '\u{cc8}'), ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+    ('\u{d46}', '\u{d3e}', '\u{d4a}'), ('\u{d46}', '\u{d57}', '\u{d4c}'), ('\u{d47}', '\u{d3e}', '\u{d4b}'),
+    ('\u{dd9}', '\u{dca}', '\u{dda}'), ('\u{dd9}', '\u{dcf}', '\u{ddc}'), ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+    ('\u{ddc}', '\u{dca}', '\u{ddd}'), ('\u{1025}', '\u{102e}', '\u{1026}'), ('\u{1b05}', '\u{1b35}', '\u{1b06}'),
+    ('\u{1b07}', '\u{1b35}', '\u{1b08}'), ('\u{1b09}', '\u{1b35}', '\u{1b0a}'), ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+    ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'), ('\u{1b11}', '\u{1b35}', '\u{1b12}'), ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'),
+    ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'), ('\u{1b3e}', '\u{1b35}', '\u{1b40}'), ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+    ('\u{1b42}', '\u{1b35}', '\u{1b43}'), ('\u{1e36}', '\u{304}', '\u{1e38}'), ('\u{1e37}', '\u{304}', '\u{1e39}'),
+    ('\u{1e5a}', '\u{304}', '\u{1e5c}'), ('\u{1e5b}', '\u{304}', '\u{1e5d}'), ('\u{1e62}', '\u{307}', '\u{1e68}'),
+    ('\u{1e63}', '\u{307}', '\u{1e69}'), ('\u{1ea0}', '\u{302}', '\u{1eac}'), ('\u{1ea0}', '\u{306}', '\u{1eb6}'),
+    ('\u{1ea1}', '\u{302}', '\u{1ead}'), ('\u{1ea1}', '\u{306}', '\u{1eb7}'), ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+    ('\u{1eb9}', '\u{302}', '\u{1ec7}'), ('\u{1ecc}', '\u{302}', '\u{1ed8}'), ('\u{1ecd}', '\u{302}', '\u{1ed9}'),
+    ('\u{1f00}', '\u{300}', '\u{1f02}'), ('\u{1f00}', '\u{301}', '\u{1f04}'), ('\u{1f00}', '\u{342}', '\u{1f06}'),
+    ('\u{1f00}', '\u{345}', '\u{1f80}'), ('\u{1f01}', '\u{300}', '\u{1f03}'), ('\u{1f01}', '\u{301}', '\u{1f05}'),
+    ('\u{1f01}', '\u{342}', '\u{1f07}'), ('\u{1f01}', '\u{345}', '\u{1f81}'), ('\u{1f02}', '\u{345}', '\u{1f82}'),
+    ('\u{1f03}', '\u{345}', '\u{1f83}'), ('\u{1f04}', '\u{345}', '\u{1f84}'), ('\u{1f05}', '\u{345}', '\u{1f85}'),
+    ('\u{1f06}', '\u{345}', '\u{1f86}'), ('\u{1f07}', '\u{345}', '\u{1f87}'), ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+    ('\u{1f08}', '\u{301}', '\u{1f0c}'), ('\u{1f08}', '\u{342}', '\u{1f0e}'), ('\u{1f08}', '\u{345}', '\u{1f88}'),
+    ('\u{1f09}', '\u{300}', '\u{1f0b}'), ('\u{1f09}', '\u{301}', '\u{1f0d}'), ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+    ('\u{1f09}', '\u{345}', '\u{1f89}'), ('\u{1f0a}', '\u{345}', '\u{1f8a}'), ('\u{1f0b}', '\u{345}', '\u{1f8b}'),
+    ('\u{1f0c}', '\u{345}', '\u{1f8c}'), ('\u{1f0d}', '\u{345}', '\u{1f8d}'), ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+    ('\u{1f0f}', '\u{345}', '\u{1f8f}'), ('\u{1f10}', '\u{300}', '\u{1f12}'), ('\u{1f10}', '\u{301}', '\u{1f14}'),
+    ('\u{1f11}', '\u{300}', '\u{1f13}'), ('\u{1f11}', '\u{301}', '\u{1f15}'), ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+    ('\u{1f18}', '\u{301}', '\u{1f1c}'), ('\u{1f19}', '\u{300}', '\u{1f1b}'), ('\u{1f19}', '\u{301}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{300}', '\u{1f22}'), ('\u{1f20}', '\u{301}', '\u{1f24}'), ('\u{1f20}', '\u{342}', '\u{1f26}'),
+    ('\u{1f20}', '\u{345}', '\u{1f90}'), ('\u{1f21}', '\u{300}', '\u{1f23}'), ('\u{1f21}', '\u{301}', '\u{1f25}'),
+    ('\u{1f21}', '\u{342}', '\u{1f27}'), ('\u{1f21}', '\u{345}', '\u{1f91}'), ('\u{1f22}', '\u{345}', '\u{1f92}'),
+    ('\u{1f23}', '\u{345}', '\u{1f93}'), ('\u{1f24}', '\u{345}', '\u{1f94}'), ('\u{1f25}', '\u{345}', '\u{1f95}'),
+    ('\u{1f26}', '\u{345}', '\u{1f96}'), ('\u{1f27}', '\u{345}', '\u{1f97}'), ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+    ('\u{1f28}', '\u{301}', '\u{1f2c}'), ('\u{1f28}', '\u{342}', '\u{1f2e}'), ('\u{1f28}', '\u{345}', '\u{1f98}'),
+    ('\u{1f29}', '\u{300}', '\u{1f2b}'), ('\u{1f29}', '\u{301}', '\u{1f2d}'), ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+    ('\u{1f29}', '\u{345}', '\u{1f99}'), ('\u{1f2a}', '\u{345}', '\u{1f9a}'), ('\u{1f2b}', '\u{345}', '\u{1f9b}'),
+    ('\u{1f2c}', '\u{345}', '\u{1f9c}'), ('\u{1f2d}', '\u{345}', '\u{1f9d}'), ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+    ('\u{1f2f}', '\u{345}', '\u{1f9f}'), ('\u{1f30}', '\u{300}', '\u{1f32}'), ('\u{1f30}', '\u{301}', '\u{1f34}'),
+    ('\u{1f30}', '\u{342}', '\u{1f36}'), ('\u{1f31}', '\u{300}', '\u{1f33}'), ('\u{1f31}', '\u{301}', '\u{1f35}'),
+    ('\u{1f31}', '\u{342}', '\u{1f37}'), ('\u{1f38}', '\u{300}', '\u{1f3a}'), ('\u{1f38}', '\u{301}', '\u{1f3c}'),
+    ('\u{1f38}', '\u{342}', '\u{1f3e}'), ('\u{1f39}', '\u{300}', '\u{1f3b}'), ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+    ('\u{1f39}', '\u{342}', '\u{1f3f}'), ('\u{1f40}', '\u{300}', '\u{1f42}'), ('\u{1f40}', '\u{301}', '\u{1f44}'),
+    ('\u{1f41}', '\u{300}', '\u{1f43}'), ('\u{1f41}', '\u{301}', '\u{1f45}'), ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+    ('\u{1f48}', '\u{301}', '\u{1f4c}'), ('\u{1f49}', '\u{300}', '\u{1f4b}'), ('\u{1f49}', '\u{301}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{300}', '\u{1f52}'), ('\u{1f50}', '\u{301}', '\u{1f54}'), ('\u{1f50}', '\u{342}', '\u{1f56}'),
+    ('\u{1f51}', '\u{300}', '\u{1f53}'), ('\u{1f51}', '\u{301}', '\u{1f55}'), ('\u{1f51}', '\u{342}', '\u{1f57}'),
+    ('\u{1f59}', '\u{300}', '\u{1f5b}'), ('\u{1f59}', '\u{301}', '\u{1f5d}'), ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+    ('\u{1f60}', '\u{300}', '\u{1f62}'), ('\u{1f60}', '\u{301}', '\u{1f64}'), ('\u{1f60}', '\u{342}', '\u{1f66}'),
+    ('\u{1f60}', '\u{345}', '\u{1fa0}'), ('\u{1f61}', '\u{300}', '\u{1f63}'), ('\u{1f61}', '\u{301}', '\u{1f65}'),
+    ('\u{1f61}', '\u{342}', '\u{1f67}'), ('\u{1f61}', '\u{345}', '\u{1fa1}'), ('\u{1f62}', '\u{345}', '\u{1fa2}'),
+    ('\u{1f63}', '\u{345}', '\u{1fa3}'), ('\u{1f64}', '\u{345}', '\u{1fa4}'), ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+    ('\u{1f66}', '\u{345}', '\u{1fa6}'), ('\u{1f67}', '\u{345}', '\u{1fa7}'), ('\u{1f68}', '\u{300}', '\u{1f6a}'),
+    ('\u{1f68}', '\u{301}', '\u{1f6c}'), ('\u{1f68}', '\u{342}', '\u{1f6e}'), ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+    ('\u{1f69}', '\u{300}', '\u{1f6b}'), ('\u{1f69}', '\u{301}', '\u{1f6d}'), ('\u{1f69}', '\u{342}', '\u{1f6f}'),
+    ('\u{1f69}', '\u{345}', '\u{1fa9}'), ('\u{1f6a}', '\u{345}', '\u{1faa}'), ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+    ('\u{1f6c}', '\u{345}', '\u{1fac}'), ('\u{1f6d}', '\u{345}', '\u{1fad}'), ('\u{1f6e}', '\u{345}', '\u{1fae}'),
+    ('\u{1f6f}', '\u{345}', '\u{1faf}'), ('\u{1f70}', '\u{345}', '\u{1fb2}'), ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+    ('\u{1f7c}', '\u{345}', '\u{1ff2}'), ('\u{1fb6}', '\u{345}', '\u{1fb7}'), ('\u{1fbf}', '\u{300}', '\u{1fcd}'),
+    ('\u{1fbf}', '\u{301}', '\u{1fce}'), ('\u{1fbf}', '\u{342}', '\u{1fcf}'), ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+    ('\u{1ff6}', '\u{345}', '\u{1ff7}'), ('\u{1ffe}', '\u{300}', '\u{1fdd}'), ('\u{1ffe}', '\u{301}', '\u{1fde}'),
+    ('\u{1ffe}', '\u{342}', '\u{1fdf}'), ('\u{2190}', '\u{338}', '\u{219a}'), ('\u{2192}', '\u{338}', '\u{219b}'),
+    ('\u{2194}', '\u{338}', '\u{21ae}'), ('\u{21d0}', '\u{338}', '\u{21cd}'), ('\u{21d2}', '\u{338}', '\u{21cf}'),
+    ('\u{21d4}', '\u{338}', '\u{21ce}'), ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220b}', '\u{338}', '\u{220c}'), ('\u{2223}', '\u{338}', '\u{2224}'), ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223c}', '\u{338}', '\u{2241}'), ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224d}', '\u{338}', '\u{226d}'), ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'), ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'), ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227a}', '\u{338}', '\u{2280}'), ('\u{227b}', '\u{338}', '\u{2281}'), ('\u{227c}', '\u{338}', '\u{22e0}'),
+    ('\u{227d}', '\u{338}', '\u{22e1}'), ('\u{2282}', '\u{338}', '\u{2284}'), ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'), ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22e2}'),
+    ('\u{2292}', '\u{338}', '\u{22e3}'), ('\u{22a2}', '\u{338}', '\u{22ac}'), ('\u{22a8}', '\u{338}', '\u{22ad}'),
+    ('\u{22a9}', '\u{338}', '\u{22ae}'), ('\u{22ab}', '\u{338}', '\u{22af}'), ('\u{22b2}', '\u{338}', '\u{22ea}'),
+    ('\u{22b3}', '\u{338}', '\u{22eb}'), ('\u{22b4}', '\u{338}', '\u{22ec}'), ('\u{22b5}', '\u{338}', '\u{22ed}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'), ('\u{304b}', '\u{3099}', '\u{304c}'), ('\u{304d}', '\u{3099}', '\u{304e}'),
+    ('\u{304f}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'), ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'), ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305a}'),
+    ('\u{305b}', '\u{3099}', '\u{305c}'), ('\u{305d}', '\u{3099}', '\u{305e}'), ('\u{305f}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'), ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306f}', '\u{3099}', '\u{3070}'), ('\u{306f}', '\u{309a}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'), ('\u{3072}', '\u{309a}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309a}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'), ('\u{3078}', '\u{309a}', '\u{307a}'),
+    ('\u{307b}', '\u{3099}', '\u{307c}'), ('\u{307b}', '\u{309a}', '\u{307d}'), ('\u{309d}', '\u{3099}', '\u{309e}'),
+    ('\u{30a6}', '\u{3099}', '\u{30f4}'), ('\u{30ab}', '\u{3099}', '\u{30ac}'), ('\u{30ad}', '\u{3099}', '\u{30ae}'),
+    ('\u{30af}', '\u{3099}', '\u{30b0}'), ('\u{30b1}', '\u{3099}', '\u{30b2}'), ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+    ('\u{30b5}', '\u{3099}', '\u{30b6}'), ('\u{30b7}', '\u{3099}', '\u{30b8}'), ('\u{30b9}', '\u{3099}', '\u{30ba}'),
+    ('\u{30bb}', '\u{3099}', '\u{30bc}'), ('\u{30bd}', '\u{3099}', '\u{30be}'), ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+    ('\u{30c1}', '\u{3099}', '\u{30c2}'), ('\u{30c4}', '\u{3099}', '\u{30c5}'), ('\u{30c6}', '\u{3099}', '\u{30c7}'),
+    ('\u{30c8}', '\u{3099}', '\u{30c9}'), ('\u{30cf}', '\u{3099}', '\u{30d0}'), ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+    ('\u{30d2}', '\u{3099}', '\u{30d3}'), ('\u{30d2}', '\u{309a}', '\u{30d4}'), ('\u{30d5}', '\u{3099}', '\u{30d6}'),
+    ('\u{30d5}', '\u{309a}', '\u{30d7}'), ('\u{30d8}', '\u{3099}', '\u{30d9}'), ('\u{30d8}', '\u{309a}', '\u{30da}'),
+    ('\u{30db}', '\u{3099}', '\u{30dc}'), ('\u{30db}', '\u{309a}', '\u{30dd}'), ('\u{30ef}', '\u{3099}', '\u{30f7}'),
+    ('\u{30f0}', '\u{3099}', '\u{30f8}'), ('\u{30f1}', '\u{3099}', '\u{30f9}'), ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+    ('\u{30fd}', '\u{3099}', '\u{30fe}'), ('\u{11099}', '\u{110ba}', '\u{1109a}'), ('\u{1109b}', '\u{110ba}', '\u{1109c}'),
+    ('\u{110a5}', '\u{110ba}', '\u{110ab}'), ('\u{11131}', '\u{11127}', '\u{1112e}'), ('\u{11132}', '\u{11127}', '\u{1112f}'),
+    ('\u{11347}', '\u{1133e}', '\u{1134b}'), ('\u{11347}', '\u{11357}', '\u{1134c}'), ('\u{114b9}', '\u{114b0}', '\u{114bc}'),
+    ('\u{114b9}', '\u{114ba}', '\u{114bb}'), ('\u{114b9}', '\u{114bd}', '\u{114be}'), ('\u{115b8}', '\u{115af}', '\u{115ba}'),
+    ('\u{115b9}', '\u{115af}', '\u{115bb}'), ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
+
+pub const GRAPHEME_BREAK: &[(char, char, GraphemeBreak)] = &[
+    ('\u{0}', '\u{9}', GraphemeBreak::Control), ('\u{a}', '\u{a}', GraphemeBreak::Lf),
+    ('\u{b}', '\u{c}', GraphemeBreak::Control), ('\u{d}', '\u{d}', GraphemeBreak::Cr),
+    ('\u{e}', '\u{1f}', GraphemeBreak::Control), ('\u{7f}', '\u{9f}', GraphemeBreak::Control),
+    ('\u{a9}', '\u{a9}', GraphemeBreak::ExtendedPictographic), ('\u{ad}', '\u{ad}', GraphemeBreak::Control),
+    ('\u{ae}', '\u{ae}', GraphemeBreak::ExtendedPictographic), ('\u{300}', '\u{36f}', GraphemeBreak::Extend),
+    ('\u{483}', '\u{489}', GraphemeBreak::Extend), ('\u{591}', '\u{5bd}', GraphemeBreak::Extend),
+    ('\u{5bf}', '\u{5bf}', GraphemeBreak::Extend), ('\u{5c1}', '\u{5c2}', GraphemeBreak::Extend),
+    ('\u{5c4}', '\u{5c5}', GraphemeBreak::Extend), ('\u{5c7}', '\u{5c7}', GraphemeBreak::Extend),
+    ('\u{600}', '\u{605}', GraphemeBreak::Prepend), ('\u{610}', '\u{61a}', GraphemeBreak::Extend),
+    ('\u{61c}', '\u{61c}', GraphemeBreak::Control), ('\u{64b}', '\u{65f}', GraphemeBreak::Extend),
+    ('\u{670}', '\u{670}', GraphemeBreak::Extend), ('\u{6d6}', '\u{6dc}', GraphemeBreak::Extend),
+    ('\u{6dd}', '\u{6dd}', GraphemeBreak::Prepend), ('\u{6df}', '\u{6e4}', GraphemeBreak::Extend),
+    ('\u{6e7}', '\u{6e8}', GraphemeBreak::Extend), ('\u{6ea}', '\u{6ed}', GraphemeBreak::Extend),
+    ('\u{70f}', '\u{70f}', GraphemeBreak::Prepend), ('\u{711}', '\u{711}', GraphemeBreak::Extend),
+    ('\u{730}', '\u{74a}', GraphemeBreak::Extend), ('\u{7a6}', '\u{7b0}', GraphemeBreak::Extend),
+    ('\u{7eb}', '\u{7f3}', GraphemeBreak::Extend), ('\u{7fd}', '\u{7fd}', GraphemeBreak::Extend),
+    ('\u{816}', '\u{819}', GraphemeBreak::Extend), ('\u{81b}', '\u{823}', GraphemeBreak::Extend),
+    ('\u{825}', '\u{827}', GraphemeBreak::Extend), ('\u{829}', '\u{82d}', GraphemeBreak::Extend),
+    ('\u{859}', '\u{85b}', GraphemeBreak::Extend), ('\u{890}', '\u{891}', GraphemeBreak::Prepend),
+    ('\u{898}', '\u{89f}', GraphemeBreak::Extend), ('\u{8ca}', '\u{8e1}', GraphemeBreak::Extend),
+    ('\u{8e2}', '\u{8e2}', GraphemeBreak::Prepend), ('\u{8e3}', '\u{902}', GraphemeBreak::Extend),
+    ('\u{903}', '\u{903}', GraphemeBreak::SpacingMark), ('\u{93a}', '\u{93a}', GraphemeBreak::Extend),
+    ('\u{93b}', '\u{93b}', GraphemeBreak::SpacingMark), ('\u{93c}', '\u{93c}', GraphemeBreak::Extend),
+    ('\u{93e}', '\u{940}', GraphemeBreak::SpacingMark), ('\u{941}', '\u{948}', GraphemeBreak::Extend),
+    ('\u{949}', '\u{94c}', GraphemeBreak::SpacingMark), ('\u{94d}', '\u{94d}', GraphemeBreak::Extend),
+    ('\u{94e}', '\u{94f}', GraphemeBreak::SpacingMark), ('\u{951}', '\u{957}', GraphemeBreak::Extend),
+    ('\u{962}', '\u{963}', GraphemeBreak::Extend), ('\u{981}', '\u{981}', GraphemeBreak::Extend),
+    ('\u{982}', '\u{983}', GraphemeBreak::SpacingMark), ('\u{9bc}', '\u{9bc}', GraphemeBreak::Extend),
+    ('\u{9be}', '\u{9be}', GraphemeBreak::Extend), ('\u{9bf}', '\u{9c0}', GraphemeBreak::SpacingMark),
+    ('\u{9c1}', '\u{9c4}', GraphemeBreak::Extend), ('\u{9c7}', '\u{9c8}', GraphemeBreak::SpacingMark),
+    ('\u{9cb}', '\u{9cc}', GraphemeBreak::SpacingMark), ('\u{9cd}', '\u{9cd}', GraphemeBreak::Extend),
+    ('\u{9d7}', '\u{9d7}', GraphemeBreak::Extend), ('\u{9e2}', '\u{9e3}', GraphemeBreak::Extend),
+    ('\u{9fe}', '\u{9fe}', GraphemeBreak::Extend), ('\u{a01}', '\u{a02}', GraphemeBreak::Extend),
+    ('\u{a03}', '\u{a03}', GraphemeBreak::SpacingMark), ('\u{a3c}', '\u{a3c}', GraphemeBreak::Extend),
+    ('\u{a3e}', '\u{a40}', GraphemeBreak::SpacingMark), ('\u{a41}', '\u{a42}', GraphemeBreak::Extend),
+    ('\u{a47}', '\u{a48}', GraphemeBreak::Extend), ('\u{a4b}', '\u{a4d}', GraphemeBreak::Extend),
+    ('\u{a51}', '\u{a51}', GraphemeBreak::Extend), ('\u{a70}', '\u{a71}', GraphemeBreak::Extend),
+    ('\u{a75}', '\u{a75}', GraphemeBreak::Extend), ('\u{a81}', '\u{a82}', GraphemeBreak::Extend),
+    ('\u{a83}', '\u{a83}', GraphemeBreak::SpacingMark), ('\u{abc}', '\u{abc}', GraphemeBreak::Extend),
+    ('\u{abe}', '\u{ac0}', GraphemeBreak::SpacingMark), ('\u{ac1}', '\u{ac5}', GraphemeBreak::Extend),
+    ('\u{ac7}', '\u{ac8}', GraphemeBreak::Extend), ('\u{ac9}', '\u{ac9}', GraphemeBreak::SpacingMark),
+    ('\u{acb}', '\u{acc}', GraphemeBreak::SpacingMark), ('\u{acd}', '\u{acd}', GraphemeBreak::Extend),
+    ('\u{ae2}', '\u{ae3}', GraphemeBreak::Extend), ('\u{afa}', '\u{aff}', GraphemeBreak::Extend),
+    ('\u{b01}', '\u{b01}', GraphemeBreak::Extend), ('\u{b02}', '\u{b03}', GraphemeBreak::SpacingMark),
+    ('\u{b3c}', '\u{b3c}', GraphemeBreak::Extend), ('\u{b3e}', '\u{b3f}', GraphemeBreak::Extend),
+    ('\u{b40}', '\u{b40}', GraphemeBreak::SpacingMark), ('\u{b41}', '\u{b44}', GraphemeBreak::Extend),
+    ('\u{b47}', '\u{b48}', GraphemeBreak::SpacingMark), ('\u{b4b}', '\u{b4c}', GraphemeBreak::SpacingMark),
+    ('\u{b4d}', '\u{b4d}', GraphemeBreak::Extend), ('\u{b55}', '\u{b57}', GraphemeBreak::Extend),
+    ('\u{b62}', '\u{b63}', GraphemeBreak::Extend), ('\u{b82}', '\u{b82}', GraphemeBreak::Extend),
+    ('\u{bbe}', '\u{bbe}', GraphemeBreak::Extend), ('\u{bbf}', '\u{bbf}', GraphemeBreak::SpacingMark),
+    ('\u{bc0}', '\u{bc0}', GraphemeBreak::Extend), ('\u{bc1}', '\u{bc2}', GraphemeBreak::SpacingMark),
+    ('\u{bc6}', '\u{bc8}', GraphemeBreak::SpacingMark), ('\u{bca}', '\u{bcc}', GraphemeBreak::SpacingMark),
+    ('\u{bcd}', '\u{bcd}', GraphemeBreak::Extend), ('\u{bd7}', '\u{bd7}', GraphemeBreak::Extend),
+    ('\u{c00}', '\u{c00}', GraphemeBreak::Extend), ('\u{c01}', '\u{c03}', GraphemeBreak::SpacingMark),
+    ('\u{c04}', '\u{c04}', GraphemeBreak::Extend), ('\u{c3c}', '\u{c3c}', GraphemeBreak::Extend),
+    ('\u{c3e}', '\u{c40}', GraphemeBreak::Extend), ('\u{c41}', '\u{c44}', GraphemeBreak::SpacingMark),
+    ('\u{c46}', '\u{c48}', GraphemeBreak::Extend), ('\u{c4a}', '\u{c4d}', GraphemeBreak::Extend),
+    ('\u{c55}', '\u{c56}', GraphemeBreak::Extend), ('\u{c62}', '\u{c63}', GraphemeBreak::Extend),
+    ('\u{c81}', '\u{c81}', GraphemeBreak::Extend), ('\u{c82}', '\u{c83}', GraphemeBreak::SpacingMark),
+    ('\u{cbc}', '\u{cbc}', GraphemeBreak::Extend), ('\u{cbe}', '\u{cbe}', GraphemeBreak::SpacingMark),
+    ('\u{cbf}', '\u{cbf}', GraphemeBreak::Extend), ('\u{cc0}', '\u{cc1}', GraphemeBreak::SpacingMark),
+    ('\u{cc2}', '\u{cc2}', GraphemeBreak::Extend), ('\u{cc3}', '\u{cc4}', GraphemeBreak::SpacingMark),
+    ('\u{cc6}', '\u{cc6}', GraphemeBreak::Extend), ('\u{cc7}', '\u{cc8}', GraphemeBreak::SpacingMark),
+    ('\u{cca}', '\u{ccb}', GraphemeBreak::SpacingMark), ('\u{ccc}', '\u{ccd}', GraphemeBreak::Extend),
+    ('\u{cd5}', '\u{cd6}', GraphemeBreak::Extend), ('\u{ce2}', '\u{ce3}', GraphemeBreak::Extend),
+    ('\u{d00}', '\u{d01}', GraphemeBreak::Extend), ('\u{d02}', '\u{d03}', GraphemeBreak::SpacingMark),
+    ('\u{d3b}', '\u{d3c}', GraphemeBreak::Extend), ('\u{d3e}', '\u{d3e}', GraphemeBreak::Extend),
+    ('\u{d3f}', '\u{d40}', GraphemeBreak::SpacingMark), ('\u{d41}', '\u{d44}', GraphemeBreak::Extend),
+    ('\u{d46}', '\u{d48}', GraphemeBreak::SpacingMark), ('\u{d4a}', '\u{d4c}', GraphemeBreak::SpacingMark),
+    ('\u{d4d}', '\u{d4d}', GraphemeBreak::Extend), ('\u{d4e}', '\u{d4e}', GraphemeBreak::Prepend),
+    ('\u{d57}', '\u{d57}', GraphemeBreak::Extend), ('\u{d62}', '\u{d63}', GraphemeBreak::Extend),
+    ('\u{d81}', '\u{d81}', GraphemeBreak::Extend), ('\u{d82}', '\u{d83}', GraphemeBreak::SpacingMark),
+    ('\u{dca}', '\u{dca}', GraphemeBreak::Extend), ('\u{dcf}', '\u{dcf}', GraphemeBreak::Extend),
+    ('\u{dd0}', '\u{dd1}', GraphemeBreak::SpacingMark), ('\u{dd2}', '\u{dd4}', GraphemeBreak::Extend),
+    ('\u{dd6}', '\u{dd6}', GraphemeBreak::Extend), ('\u{dd8}', '\u{dde}', GraphemeBreak::SpacingMark),
+    ('\u{ddf}', '\u{ddf}', GraphemeBreak::Extend), ('\u{df2}', '\u{df3}', GraphemeBreak::SpacingMark),
+    ('\u{e31}', '\u{e31}', GraphemeBreak::Extend), ('\u{e33}', '\u{e33}', GraphemeBreak::SpacingMark),
+    ('\u{e34}', '\u{e3a}', GraphemeBreak::Extend), ('\u{e47}', '\u{e4e}', GraphemeBreak::Extend),
+    ('\u{eb1}', '\u{eb1}', GraphemeBreak::Extend), ('\u{eb3}', '\u{eb3}', GraphemeBreak::SpacingMark),
+    ('\u{eb4}', '\u{ebc}', GraphemeBreak::Extend), ('\u{ec8}', '\u{ecd}', GraphemeBreak::Extend),
+    ('\u{f18}', '\u{f19}', GraphemeBreak::Extend), ('\u{f35}', '\u{f35}', GraphemeBreak::Extend),
+    ('\u{f37}', '\u{f37}', GraphemeBreak::Extend), ('\u{f39}', '\u{f39}', GraphemeBreak::Extend),
+    ('\u{f3e}', '\u{f3f}', GraphemeBreak::SpacingMark), ('\u{f71}', '\u{f7e}', GraphemeBreak::Extend),
+    ('\u{f7f}', '\u{f7f}', GraphemeBreak::SpacingMark), ('\u{f80}', '\u{f84}', GraphemeBreak::Extend),
+    ('\u{f86}', '\u{f87}', GraphemeBreak::Extend), ('\u{f8d}', '\u{f97}', GraphemeBreak::Extend),
+    ('\u{f99}', '\u{fbc}', GraphemeBreak::Extend), ('\u{fc6}', '\u{fc6}', GraphemeBreak::Extend),
+    ('\u{102d}', '\u{1030}', GraphemeBreak::Extend), ('\u{1031}', '\u{1031}', GraphemeBreak::SpacingMark),
+    ('\u{1032}', '\u{1037}', GraphemeBreak::Extend), ('\u{1039}', '\u{103a}', GraphemeBreak::Extend),
+    ('\u{103b}', '\u{103c}', GraphemeBreak::SpacingMark), ('\u{103d}', '\u{103e}', GraphemeBreak::Extend),
+    ('\u{1056}', '\u{1057}', GraphemeBreak::SpacingMark), ('\u{1058}', '\u{1059}', GraphemeBreak::Extend),
+    ('\u{105e}', '\u{1060}', GraphemeBreak::Extend), ('\u{1071}', '\u{1074}', GraphemeBreak::Extend),
+    ('\u{1082}', '\u{1082}', GraphemeBreak::Extend), ('\u{1084}', '\u{1084}', GraphemeBreak::SpacingMark),
+    ('\u{1085}', '\u{1086}', GraphemeBreak::Extend), ('\u{108d}', '\u{108d}', GraphemeBreak::Extend),
+    ('\u{109d}', '\u{109d}', GraphemeBreak::Extend), ('\u{1100}', '\u{115f}', GraphemeBreak::L),
+    ('\u{1160}', '\u{11a7}', GraphemeBreak::V), ('\u{11a8}', '\u{11ff}', GraphemeBreak::T),
+    ('\u{135d}', '\u{135f}', GraphemeBreak::Extend), ('\u{1712}', '\u{1714}', GraphemeBreak::Extend),
+    ('\u{1715}', '\u{1715}', GraphemeBreak::SpacingMark), ('\u{1732}', '\u{1733}', GraphemeBreak::Extend),
+    ('\u{1734}', '\u{1734}', GraphemeBreak::SpacingMark), ('\u{1752}', '\u{1753}', GraphemeBreak::Extend),
+    ('\u{1772}', '\u{1773}', GraphemeBreak::Extend), ('\u{17b4}', '\u{17b5}', GraphemeBreak::Extend),
+    ('\u{17b6}', '\u{17b6}', GraphemeBreak::SpacingMark), ('\u{17b7}', '\u{17bd}', GraphemeBreak::Extend),
+    ('\u{17be}', '\u{17c5}', GraphemeBreak::SpacingMark), ('\u{17c6}', '\u{17c6}', GraphemeBreak::Extend),
+    ('\u{17c7}', '\u{17c8}', GraphemeBreak::SpacingMark), ('\u{17c9}', '\u{17d3}', GraphemeBreak::Extend),
+    ('\u{17dd}', '\u{17dd}', GraphemeBreak::Extend), ('\u{180b}', '\u{180d}', GraphemeBreak::Extend),
+    ('\u{180e}', '\u{180e}', GraphemeBreak::Control), ('\u{180f}', '\u{180f}', GraphemeBreak::Extend),
+    ('\u{1885}', '\u{1886}', GraphemeBreak::Extend), ('\u{18a9}', '\u{18a9}', GraphemeBreak::Extend),
+    ('\u{1920}', '\u{1922}', GraphemeBreak::Extend), ('\u{1923}', '\u{1926}', GraphemeBreak::SpacingMark),
+    ('\u{1927}', '\u{1928}', GraphemeBreak::Extend), ('\u{1929}', '\u{192b}', GraphemeBreak::SpacingMark),
+    ('\u{1930}', '\u{1931}', GraphemeBreak::SpacingMark), ('\u{1932}', '\u{1932}', GraphemeBreak::Extend),
+    ('\u{1933}', '\u{1938}', GraphemeBreak::SpacingMark), ('\u{1939}', '\u{193b}', GraphemeBreak::Extend),
+    ('\u{1a17}', '\u{1a18}', GraphemeBreak::Extend), ('\u{1a19}', '\u{1a1a}', GraphemeBreak::SpacingMark),
+    ('\u{1a1b}', '\u{1a1b}', GraphemeBreak::Extend), ('\u{1a55}', '\u{1a55}', GraphemeBreak::SpacingMark),
+    ('\u{1a56}', '\u{1a56}', GraphemeBreak::Extend), ('\u{1a57}', '\u{1a57}', GraphemeBreak::SpacingMark),
+    ('\u{1a58}', '\u{1a5e}', GraphemeBreak::Extend), ('\u{1a60}', '\u{1a60}', GraphemeBreak::Extend),
+    ('\u{1a62}', '\u{1a62}', GraphemeBreak::Extend), ('\u{1a65}', '\u{1a6c}', GraphemeBreak::Extend),
+    ('\u{1a6d}', '\u{1a72}', GraphemeBreak::SpacingMark), ('\u{1a73}', '\u{1a7c}', GraphemeBreak::Extend),
+    ('\u{1a7f}', '\u{1a7f}', GraphemeBreak::Extend), ('\u{1ab0}', '\u{1ace}', GraphemeBreak::Extend),
+    ('\u{1b00}', '\u{1b03}', GraphemeBreak::Extend), ('\u{1b04}', '\u{1b04}', GraphemeBreak::SpacingMark),
+    ('\u{1b34}', '\u{1b3a}', GraphemeBreak::Extend), ('\u{1b3b}', '\u{1b3b}', GraphemeBreak::SpacingMark),
+    ('\u{1b3c}', '\u{1b3c}', GraphemeBreak::Extend), ('\u{1b3d}', '\u{1b41}', GraphemeBreak::SpacingMark),
+    ('\u{1b42}', '\u{1b42}', GraphemeBreak::Extend), ('\u{1b43}', '\u{1b44}', GraphemeBreak::SpacingMark),
+    ('\u{1b6b}', '\u{1b73}', GraphemeBreak::Extend), ('\u{1b80}', '\u{1b81}', GraphemeBreak::Extend),
+    ('\u{1b82}', '\u{1b82}', GraphemeBreak::SpacingMark), ('\u{1ba1}', '\u{1ba1}', GraphemeBreak::SpacingMark),
+    ('\u{1ba2}', '\u{1ba5}', GraphemeBreak::Extend), ('\u{1ba6}', '\u{1ba7}', GraphemeBreak::SpacingMark),
+    ('\u{1ba8}', '\u{1ba9}', GraphemeBreak::Extend), ('\u{1baa}', '\u{1baa}', GraphemeBreak::SpacingMark),
+    ('\u{1bab}', '\u{1bad}', GraphemeBreak::Extend), ('\u{1be6}', '\u{1be6}', GraphemeBreak::Extend),
+    ('\u{1be7}', '\u{1be7}', GraphemeBreak::SpacingMark), ('\u{1be8}', '\u{1be9}', GraphemeBreak::Extend),
+    ('\u{1bea}', '\u{1bec}', GraphemeBreak::SpacingMark), ('\u{1bed}', '\u{1bed}', GraphemeBreak::Extend),
+    ('\u{1bee}', '\u{1bee}', GraphemeBreak::SpacingMark), ('\u{1bef}', '\u{1bf1}', GraphemeBreak::Extend),
+    ('\u{1bf2}', '\u{1bf3}', GraphemeBreak::SpacingMark), ('\u{1c24}', '\u{1c2b}', GraphemeBreak::SpacingMark),
+    ('\u{1c2c}', '\u{1c33}', GraphemeBreak::Extend), ('\u{1c34}', '\u{1c35}', GraphemeBreak::SpacingMark),
+    ('\u{1c36}', '\u{1c37}', GraphemeBreak::Extend), ('\u{1cd0}', '\u{1cd2}', GraphemeBreak::Extend),
+    ('\u{1cd4}', '\u{1ce0}', GraphemeBreak::Extend), ('\u{1ce1}', '\u{1ce1}', GraphemeBreak::SpacingMark),
+    ('\u{1ce2}', '\u{1ce8}', GraphemeBreak::Extend), ('\u{1ced}', '\u{1ced}', GraphemeBreak::Extend),
+    ('\u{1cf4}', '\u{1cf4}', GraphemeBreak::Extend), ('\u{1cf7}', '\u{1cf7}', GraphemeBreak::SpacingMark),
+    ('\u{1cf8}', '\u{1cf9}', GraphemeBreak::Extend), ('\u{1dc0}', '\u{1dff}', GraphemeBreak::Extend),
+    ('\u{200b}', '\u{200b}', GraphemeBreak::Control), ('\u{200c}', '\u{200c}', GraphemeBreak::Extend),
+    ('\u{200d}', '\u{200d}', GraphemeBreak::Zwj), ('\u{200e}', '\u{200f}', GraphemeBreak::Control),
+    ('\u{2028}', '\u{202e}', GraphemeBreak::Control), ('\u{203c}', '\u{203c}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2049}', '\u{2049}', GraphemeBreak::ExtendedPictographic), ('\u{2060}', '\u{206f}', GraphemeBreak::Control),
+    ('\u{20d0}', '\u{20f0}', GraphemeBreak::Extend), ('\u{2122}', '\u{2122}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2139}', '\u{2139}', GraphemeBreak::ExtendedPictographic), ('\u{2194}', '\u{2199}', GraphemeBreak::ExtendedPictographic),
+    ('\u{21a9}', '\u{21aa}', GraphemeBreak::ExtendedPictographic), ('\u{231a}', '\u{231b}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2328}', '\u{2328}', GraphemeBreak::ExtendedPictographic), ('\u{2388}', '\u{2388}', GraphemeBreak::ExtendedPictographic),
+    ('\u{23cf}', '\u{23cf}', GraphemeBreak::ExtendedPictographic), ('\u{23e9}', '\u{23f3}', GraphemeBreak::ExtendedPictographic),
+    ('\u{23f8}', '\u{23fa}', GraphemeBreak::ExtendedPictographic), ('\u{24c2}', '\u{24c2}', GraphemeBreak::ExtendedPictographic),
+    ('\u{25aa}', '\u{25ab}', GraphemeBreak::ExtendedPictographic), ('\u{25b6}', '\u{25b6}', GraphemeBreak::ExtendedPictographic),
+    ('\u{25c0}', '\u{25c0}', GraphemeBreak::ExtendedPictographic), ('\u{25fb}', '\u{25fe}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2600}', '\u{2605}', GraphemeBreak::ExtendedPictographic), ('\u{2607}', '\u{2612}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2614}', '\u{2685}', GraphemeBreak::ExtendedPictographic), ('\u{2690}', '\u{2705}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2708}', '\u{2712}', GraphemeBreak::ExtendedPictographic), ('\u{2714}', '\u{2714}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2716}', '\u{2716}', GraphemeBreak::ExtendedPictographic), ('\u{271d}', '\u{271d}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2721}', '\u{2721}', GraphemeBreak::ExtendedPictographic), ('\u{2728}', '\u{2728}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2733}', '\u{2734}', GraphemeBreak::ExtendedPictographic), ('\u{2744}', '\u{2744}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2747}', '\u{2747}', GraphemeBreak::ExtendedPictographic), ('\u{274c}', '\u{274c}', GraphemeBreak::ExtendedPictographic),
+    ('\u{274e}', '\u{274e}', GraphemeBreak::ExtendedPictographic), ('\u{2753}', '\u{2755}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2757}', '\u{2757}', GraphemeBreak::ExtendedPictographic), ('\u{2763}', '\u{2767}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2795}', '\u{2797}', GraphemeBreak::ExtendedPictographic), ('\u{27a1}', '\u{27a1}', GraphemeBreak::ExtendedPictographic),
+    ('\u{27b0}', '\u{27b0}', GraphemeBreak::ExtendedPictographic), ('\u{27bf}', '\u{27bf}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2934}', '\u{2935}', GraphemeBreak::ExtendedPictographic), ('\u{2b05}', '\u{2b07}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2b1b}', '\u{2b1c}', GraphemeBreak::ExtendedPictographic), ('\u{2b50}', '\u{2b50}', GraphemeBreak::ExtendedPictographic),
+    ('\u{2b55}', '\u{2b55}', GraphemeBreak::ExtendedPictographic), ('\u{2cef}', '\u{2cf1}', GraphemeBreak::Extend),
+    ('\u{2d7f}', '\u{2d7f}', GraphemeBreak::Extend), ('\u{2de0}', '\u{2dff}', GraphemeBreak::Extend),
+    ('\u{302a}', '\u{302f}', GraphemeBreak::Extend), ('\u{3030}', '\u{3030}', GraphemeBreak::ExtendedPictographic),
+    ('\u{303d}', '\u{303d}', GraphemeBreak::ExtendedPictographic), ('\u{3099}', '\u{309a}', GraphemeBreak::Extend),
+    ('\u{3297}', '\u{3297}', GraphemeBreak::ExtendedPictographic), ('\u{3299}', '\u{3299}', GraphemeBreak::ExtendedPictographic),
+    ('\u{a66f}', '\u{a672}', GraphemeBreak::Extend), ('\u{a674}', '\u{a67d}', GraphemeBreak::Extend),
+    ('\u{a69e}', '\u{a69f}', GraphemeBreak::Extend), ('\u{a6f0}', '\u{a6f1}', GraphemeBreak::Extend),
+    ('\u{a802}', '\u{a802}', GraphemeBreak::Extend), ('\u{a806}', '\u{a806}', GraphemeBreak::Extend),
+    ('\u{a80b}', '\u{a80b}', GraphemeBreak::Extend), ('\u{a823}', '\u{a824}', GraphemeBreak::SpacingMark),
+    ('\u{a825}', '\u{a826}', GraphemeBreak::Extend), ('\u{a827}', '\u{a827}', GraphemeBreak::SpacingMark),
+    ('\u{a82c}', '\u{a82c}', GraphemeBreak::Extend), ('\u{a880}', '\u{a881}', GraphemeBreak::SpacingMark),
+    ('\u{a8b4}', '\u{a8c3}', GraphemeBreak::SpacingMark), ('\u{a8c4}', '\u{a8c5}', GraphemeBreak::Extend),
+    ('\u{a8e0}', '\u{a8f1}', GraphemeBreak::Extend), ('\u{a8ff}', '\u{a8ff}', GraphemeBreak::Extend),
+    ('\u{a926}', '\u{a92d}', GraphemeBreak::Extend), ('\u{a947}', '\u{a951}', GraphemeBreak::Extend),
+    ('\u{a952}', '\u{a953}', GraphemeBreak::SpacingMark), ('\u{a960}', '\u{a97c}', GraphemeBreak::L),
+    ('\u{a980}', '\u{a982}', GraphemeBreak::Extend), ('\u{a983}', '\u{a983}', GraphemeBreak::SpacingMark),
+    ('\u{a9b3}', '\u{a9b3}', GraphemeBreak::Extend), ('\u{a9b4}', '\u{a9b5}', GraphemeBreak::SpacingMark),
+    ('\u{a9b6}', '\u{a9b9}', GraphemeBreak::Extend), ('\u{a9ba}', '\u{a9bb}', GraphemeBreak::SpacingMark),
+    ('\u{a9bc}', '\u{a9bd}', GraphemeBreak::Extend), ('\u{a9be}', '\u{a9c0}', GraphemeBreak::SpacingMark),
+    ('\u{a9e5}', '\u{a9e5}', GraphemeBreak::Extend), ('\u{aa29}', '\u{aa2e}', GraphemeBreak::Extend),
+    ('\u{aa2f}', '\u{aa30}', GraphemeBreak::SpacingMark), ('\u{aa31}', '\u{aa32}', GraphemeBreak::Extend),
+    ('\u{aa33}', '\u{aa34}', GraphemeBreak::SpacingMark), ('\u{aa35}', '\u{aa36}', GraphemeBreak::Extend),
+    ('\u{aa43}', '\u{aa43}', GraphemeBreak::Extend), ('\u{aa4c}', '\u{aa4c}', GraphemeBreak::Extend),
+    ('\u{aa4d}', '\u{aa4d}', GraphemeBreak::SpacingMark), ('\u{aa7c}', '\u{aa7c}', GraphemeBreak::Extend),
+    ('\u{aab0}', '\u{aab0}', GraphemeBreak::Extend), ('\u{aab2}', '\u{aab4}', GraphemeBreak::Extend),
+    ('\u{aab7}', '\u{aab8}', GraphemeBreak::Extend), ('\u{aabe}', '\u{aabf}', GraphemeBreak::Extend),
+    ('\u{aac1}', '\u{aac1}', GraphemeBreak::Extend), ('\u{aaeb}', '\u{aaeb}', GraphemeBreak::SpacingMark),
+    ('\u{aaec}', '\u{aaed}', GraphemeBreak::Extend), ('\u{aaee}', '\u{aaef}', GraphemeBreak::SpacingMark),
+    ('\u{aaf5}', '\u{aaf5}', GraphemeBreak::SpacingMark), ('\u{aaf6}', '\u{aaf6}', GraphemeBreak::Extend),
+    ('\u{abe3}', '\u{abe4}', GraphemeBreak::SpacingMark), ('\u{abe5}', '\u{abe5}', GraphemeBreak::Extend),
+    ('\u{abe6}', '\u{abe7}', GraphemeBreak::SpacingMark), ('\u{abe8}', '\u{abe8}', GraphemeBreak::Extend),
+    ('\u{abe9}', '\u{abea}', GraphemeBreak::SpacingMark), ('\u{abec}', '\u{abec}', GraphemeBreak::SpacingMark),
+    ('\u{abed}', '\u{abed}', GraphemeBreak::Extend), ('\u{ac00}', '\u{ac00}', GraphemeBreak::Lv),
+    ('\u{ac01}', '\u{ac1b}', GraphemeBreak::Lvt), ('\u{ac1c}', '\u{ac1c}', GraphemeBreak::Lv),
+    ('\u{ac1d}', '\u{ac37}', GraphemeBreak::Lvt), ('\u{ac38}', '\u{ac38}', GraphemeBreak::Lv),
+    ('\u{ac39}', '\u{ac53}', GraphemeBreak::Lvt), ('\u{ac54}', '\u{ac54}', GraphemeBreak::Lv),
+    ('\u{ac55}', '\u{ac6f}', GraphemeBreak::Lvt), ('\u{ac70}', '\u{ac70}', GraphemeBreak::Lv),
+    ('\u{ac71}', '\u{ac8b}', GraphemeBreak::Lvt), ('\u{ac8c}', '\u{ac8c}', GraphemeBreak::Lv),
+    ('\u{ac8d}', '\u{aca7}', GraphemeBreak::Lvt), ('\u{aca8}', '\u{aca8}', GraphemeBreak::Lv),
+    ('\u{aca9}', '\u{acc3}', GraphemeBreak::Lvt), ('\u{acc4}', '\u{acc4}', GraphemeBreak::Lv),
+    ('\u{acc5}', '\u{acdf}', GraphemeBreak::Lvt), ('\u{ace0}', '\u{ace0}', GraphemeBreak::Lv),
+    ('\u{ace1}', '\u{acfb}', GraphemeBreak::Lvt), ('\u{acfc}', '\u{acfc}', GraphemeBreak::Lv),
+    ('\u{acfd}', '\u{ad17}', GraphemeBreak::Lvt), ('\u{ad18}', '\u{ad18}', GraphemeBreak::Lv),
+    ('\u{ad19}', '\u{ad33}', GraphemeBreak::Lvt), ('\u{ad34}', '\u{ad34}', GraphemeBreak::Lv),
+    ('\u{ad35}', '\u{ad4f}', GraphemeBreak::Lvt), ('\u{ad50}', '\u{ad50}', GraphemeBreak::Lv),
+    ('\u{ad51}', '\u{ad6b}', GraphemeBreak::Lvt), ('\u{ad6c}', '\u{ad6c}', GraphemeBreak::Lv),
+    ('\u{ad6d}', '\u{ad87}', GraphemeBreak::Lvt), ('\u{ad88}', '\u{ad88}', GraphemeBreak::Lv),
+    ('\u{ad89}', '\u{ada3}', GraphemeBreak::Lvt), ('\u{ada4}', '\u{ada4}', GraphemeBreak::Lv),
+    ('\u{ada5}', '\u{adbf}', GraphemeBreak::Lvt), ('\u{adc0}', '\u{adc0}', GraphemeBreak::Lv),
+    ('\u{adc1}', '\u{addb}', GraphemeBreak::Lvt), ('\u{addc}', '\u{addc}', GraphemeBreak::Lv),
+    ('\u{addd}', '\u{adf7}', GraphemeBreak::Lvt), ('\u{adf8}', '\u{adf8}', GraphemeBreak::Lv),
+    ('\u{adf9}', '\u{ae13}', GraphemeBreak::Lvt), ('\u{ae14}', '\u{ae14}', GraphemeBreak::Lv),
+    ('\u{ae15}', '\u{ae2f}', GraphemeBreak::Lvt), ('\u{ae30}', '\u{ae30}', GraphemeBreak::Lv),
+    ('\u{ae31}', '\u{ae4b}', GraphemeBreak::Lvt), ('\u{ae4c}', '\u{ae4c}', GraphemeBreak::Lv),
+    ('\u{ae4d}', '\u{ae67}', GraphemeBreak::Lvt), ('\u{ae68}', '\u{ae68}', GraphemeBreak::Lv),
+    ('\u{ae69}', '\u{ae83}', GraphemeBreak::Lvt), ('\u{ae84}', '\u{ae84}', GraphemeBreak::Lv),
+    ('\u{ae85}', '\u{ae9f}', GraphemeBreak::Lvt), ('\u{aea0}', '\u{aea0}', GraphemeBreak::Lv),
+    ('\u{aea1}', '\u{aebb}', GraphemeBreak::Lvt), ('\u{aebc}', '\u{aebc}', GraphemeBreak::Lv),
+    ('\u{aebd}', '\u{aed7}', GraphemeBreak::Lvt), ('\u{aed8}', '\u{aed8}', GraphemeBreak::Lv),
+    ('\u{aed9}', '\u{aef3}', GraphemeBreak::Lvt), ('\u{aef4}', '\u{aef4}', GraphemeBreak::Lv),
+    ('\u{aef5}', '\u{af0f}', GraphemeBreak::Lvt), ('\u{af10}', '\u{af10}', GraphemeBreak::Lv),
+    ('\u{af11}', '\u{af2b}', GraphemeBreak::Lvt), ('\u{af2c}', '\u{af2c}', GraphemeBreak::Lv),
+    ('\u{af2d}', '\u{af47}', GraphemeBreak::Lvt), ('\u{af48}', '\u{af48}', GraphemeBreak::Lv),
+    ('\u{af49}', '\u{af63}', GraphemeBreak::Lvt), ('\u{af64}', '\u{af64}', GraphemeBreak::Lv),
+    ('\u{af65}', '\u{af7f}', GraphemeBreak::Lvt), ('\u{af80}', '\u{af80}', GraphemeBreak::Lv),
+    ('\u{af81}', '\u{af9b}', GraphemeBreak::Lvt), ('\u{af9c}', '\u{af9c}', GraphemeBreak::Lv),
+    ('\u{af9d}', '\u{afb7}', GraphemeBreak::Lvt), ('\u{afb8}', '\u{afb8}', GraphemeBreak::Lv),
+    ('\u{afb9}', '\u{afd3}', GraphemeBreak::Lvt), ('\u{afd4}', '\u{afd4}', GraphemeBreak::Lv),
+    ('\u{afd5}', '\u{afef}', GraphemeBreak::Lvt), ('\u{aff0}', '\u{aff0}', GraphemeBreak::Lv),
+    ('\u{aff1}', '\u{b00b}', GraphemeBreak::Lvt), ('\u{b00c}', '\u{b00c}', GraphemeBreak::Lv),
+    ('\u{b00d}', '\u{b027}', GraphemeBreak::Lvt), ('\u{b028}', '\u{b028}', GraphemeBreak::Lv),
+    ('\u{b029}', '\u{b043}', GraphemeBreak::Lvt), ('\u{b044}', '\u{b044}', GraphemeBreak::Lv),
+    ('\u{b045}', '\u{b05f}', GraphemeBreak::Lvt), ('\u{b060}', '\u{b060}', GraphemeBreak::Lv),
+    ('\u{b061}', '\u{b07b}', GraphemeBreak::Lvt), ('\u{b07c}', '\u{b07c}', GraphemeBreak::Lv),
+    ('\u{b07d}', '\u{b097}', GraphemeBreak::Lvt), ('\u{b098}', '\u{b098}', GraphemeBreak::Lv),
+    ('\u{b099}', '\u{b0b3}', GraphemeBreak::Lvt), ('\u{b0b4}', '\u{b0b4}', GraphemeBreak::Lv),
+    ('\u{b0b5}', '\u{b0cf}', GraphemeBreak::Lvt), ('\u{b0d0}', '\u{b0d0}', GraphemeBreak::Lv),
+    ('\u{b0d1}', '\u{b0eb}', GraphemeBreak::Lvt), ('\u{b0ec}', '\u{b0ec}', GraphemeBreak::Lv),
+    ('\u{b0ed}', '\u{b107}', GraphemeBreak::Lvt), ('\u{b108}', '\u{b108}', GraphemeBreak::Lv),
+    ('\u{b109}', '\u{b123}', GraphemeBreak::Lvt), ('\u{b124}', '\u{b124}', GraphemeBreak::Lv),
+    ('\u{b125}', '\u{b13f}', GraphemeBreak::Lvt), ('\u{b140}', '\u{b140}', GraphemeBreak::Lv),
+    ('\u{b141}', '\u{b15b}', GraphemeBreak::Lvt), ('\u{b15c}', '\u{b15c}', GraphemeBreak::Lv),
+    ('\u{b15d}', '\u{b177}', GraphemeBreak::Lvt), ('\u{b178}', '\u{b178}', GraphemeBreak::Lv),
+    ('\u{b179}', '\u{b193}', GraphemeBreak::Lvt), ('\u{b194}', '\u{b194}', GraphemeBreak::Lv),
+    ('\u{b195}', '\u{b1af}', GraphemeBreak::Lvt), ('\u{b1b0}', '\u{b1b0}', GraphemeBreak::Lv),
+    ('\u{b1b1}', '\u{b1cb}', GraphemeBreak::Lvt), ('\u{b1cc}', '\u{b1cc}', GraphemeBreak::Lv),
+    ('\u{b1cd}', '\u{b1e7}', GraphemeBreak::Lvt), ('\u{b1e8}', '\u{b1e8}', GraphemeBreak::Lv),
+    ('\u{b1e9}', '\u{b203}', GraphemeBreak::Lvt), ('\u{b204}', '\u{b204}', GraphemeBreak::Lv),
+    ('\u{b205}', '\u{b21f}', GraphemeBreak::Lvt), ('\u{b220}', '\u{b220}', GraphemeBreak::Lv),
+    ('\u{b221}', '\u{b23b}', GraphemeBreak::Lvt), ('\u{b23c}', '\u{b23c}', GraphemeBreak::Lv),
+    ('\u{b23d}', '\u{b257}', GraphemeBreak::Lvt), ('\u{b258}', '\u{b258}', GraphemeBreak::Lv),
+    ('\u{b259}', '\u{b273}', GraphemeBreak::Lvt), ('\u{b274}', '\u{b274}', GraphemeBreak::Lv),
+    ('\u{b275}', '\u{b28f}', GraphemeBreak::Lvt), ('\u{b290}', '\u{b290}', GraphemeBreak::Lv),
+    ('\u{b291}', '\u{b2ab}', GraphemeBreak::Lvt), ('\u{b2ac}', '\u{b2ac}', GraphemeBreak::Lv),
+    ('\u{b2ad}', '\u{b2c7}', GraphemeBreak::Lvt), ('\u{b2c8}', '\u{b2c8}', GraphemeBreak::Lv),
+    ('\u{b2c9}', '\u{b2e3}', GraphemeBreak::Lvt), ('\u{b2e4}', '\u{b2e4}', GraphemeBreak::Lv),
+    ('\u{b2e5}', '\u{b2ff}', GraphemeBreak::Lvt), ('\u{b300}', '\u{b300}', GraphemeBreak::Lv),
+    ('\u{b301}', '\u{b31b}', GraphemeBreak::Lvt), ('\u{b31c}', '\u{b31c}', GraphemeBreak::Lv),
+    ('\u{b31d}', '\u{b337}', GraphemeBreak::Lvt), ('\u{b338}', '\u{b338}', GraphemeBreak::Lv),
+    ('\u{b339}', '\u{b353}', GraphemeBreak::Lvt), ('\u{b354}', '\u{b354}', GraphemeBreak::Lv),
+    ('\u{b355}', '\u{b36f}', GraphemeBreak::Lvt), ('\u{b370}', '\u{b370}', GraphemeBreak::Lv),
+    ('\u{b371}', '\u{b38b}', GraphemeBreak::Lvt), ('\u{b38c}', '\u{b38c}', GraphemeBreak::Lv),
+    ('\u{b38d}', '\u{b3a7}', GraphemeBreak::Lvt), ('\u{b3a8}', '\u{b3a8}', GraphemeBreak::Lv),
+    ('\u{b3a9}', '\u{b3c3}', GraphemeBreak::Lvt), ('\u{b3c4}', '\u{b3c4}', GraphemeBreak::Lv),
+    ('\u{b3c5}', '\u{b3df}', GraphemeBreak::Lvt), ('\u{b3e0}', '\u{b3e0}', GraphemeBreak::Lv),
+    ('\u{b3e1}', '\u{b3fb}', GraphemeBreak::Lvt), ('\u{b3fc}', '\u{b3fc}', GraphemeBreak::Lv),
+    ('\u{b3fd}', '\u{b417}', GraphemeBreak::Lvt), ('\u{b418}', '\u{b418}', GraphemeBreak::Lv),
+    ('\u{b419}', '\u{b433}', GraphemeBreak::Lvt), ('\u{b434}', '\u{b434}', GraphemeBreak::Lv),
+    ('\u{b435}', '\u{b44f}', GraphemeBreak::Lvt), ('\u{b450}', '\u{b450}', GraphemeBreak::Lv),
+    ('\u{b451}', '\u{b46b}', GraphemeBreak::Lvt), ('\u{b46c}', '\u{b46c}', GraphemeBreak::Lv),
+    ('\u{b46d}', '\u{b487}', GraphemeBreak::Lvt), ('\u{b488}', '\u{b488}', GraphemeBreak::Lv),
+    ('\u{b489}', '\u{b4a3}', GraphemeBreak::Lvt), ('\u{b4a4}', '\u{b4a4}', GraphemeBreak::Lv),
+    ('\u{b4a5}', '\u{b4bf}', GraphemeBreak::Lvt), ('\u{b4c0}', '\u{b4c0}', GraphemeBreak::Lv),
+    ('\u{b4c1}', '\u{b4db}', GraphemeBreak::Lvt), ('\u{b4dc}', '\u{b4dc}', GraphemeBreak::Lv),
+    ('\u{b4dd}', '\u{b4f7}', GraphemeBreak::Lvt), ('\u{b4f8}', '\u{b4f8}', GraphemeBreak::Lv),
+    ('\u{b4f9}', '\u{b513}', GraphemeBreak::Lvt), ('\u{b514}', '\u{b514}', GraphemeBreak::Lv),
+    ('\u{b515}', '\u{b52f}', GraphemeBreak::Lvt), ('\u{b530}', '\u{b530}', GraphemeBreak::Lv),
+    ('\u{b531}', '\u{b54b}', GraphemeBreak::Lvt), ('\u{b54c}', '\u{b54c}', GraphemeBreak::Lv),
+    ('\u{b54d}', '\u{b567}', GraphemeBreak::Lvt), ('\u{b568}', '\u{b568}', GraphemeBreak::Lv),
+    ('\u{b569}', '\u{b583}', GraphemeBreak::Lvt), ('\u{b584}', '\u{b584}', GraphemeBreak::Lv),
+    ('\u{b585}', '\u{b59f}', GraphemeBreak::Lvt), ('\u{b5a0}', '\u{b5a0}', GraphemeBreak::Lv),
+    ('\u{b5a1}', '\u{b5bb}', GraphemeBreak::Lvt), ('\u{b5bc}', '\u{b5bc}', GraphemeBreak::Lv),
+    ('\u{b5bd}', '\u{b5d7}', GraphemeBreak::Lvt), ('\u{b5d8}', '\u{b5d8}', GraphemeBreak::Lv),
+    ('\u{b5d9}', '\u{b5f3}', GraphemeBreak::Lvt), ('\u{b5f4}', '\u{b5f4}', GraphemeBreak::Lv),
+    ('\u{b5f5}', '\u{b60f}', GraphemeBreak::Lvt), ('\u{b610}', '\u{b610}', GraphemeBreak::Lv),
+    ('\u{b611}', '\u{b62b}', GraphemeBreak::Lvt), ('\u{b62c}', '\u{b62c}', GraphemeBreak::Lv),
+    ('\u{b62d}', '\u{b647}', GraphemeBreak::Lvt), ('\u{b648}', '\u{b648}', GraphemeBreak::Lv),
+    ('\u{b649}', '\u{b663}', GraphemeBreak::Lvt), ('\u{b664}', '\u{b664}', GraphemeBreak::Lv),
+    ('\u{b665}', '\u{b67f}', GraphemeBreak::Lvt), ('\u{b680}', '\u{b680}', GraphemeBreak::Lv),
+    ('\u{b681}', '\u{b69b}', GraphemeBreak::Lvt), ('\u{b69c}', '\u{b69c}', GraphemeBreak::Lv),
+    ('\u{b69d}', '\u{b6b7}', GraphemeBreak::Lvt), ('\u{b6b8}', '\u{b6b8}', GraphemeBreak::Lv),
+    ('\u{b6b9}', '\u{b6d3}', GraphemeBreak::Lvt), ('\u{b6d4}', '\u{b6d4}', GraphemeBreak::Lv),
+    ('\u{b6d5}', '\u{b6ef}', GraphemeBreak::Lvt), ('\u{b6f0}', '\u{b6f0}', GraphemeBreak::Lv),
+    ('\u{b6f1}', '\u{b70b}', GraphemeBreak::Lvt), ('\u{b70c}', '\u{b70c}', GraphemeBreak::Lv),
+    ('\u{b70d}', '\u{b727}', GraphemeBreak::Lvt), ('\u{b728}', '\u{b728}', GraphemeBreak::Lv),
+    ('\u{b729}', '\u{b743}', GraphemeBreak::Lvt), ('\u{b744}', '\u{b744}', GraphemeBreak::Lv),
+    ('\u{b745}', '\u{b75f}', GraphemeBreak::Lvt), ('\u{b760}', '\u{b760}', GraphemeBreak::Lv),
+    ('\u{b761}', '\u{b77b}', GraphemeBreak::Lvt), ('\u{b77c}', '\u{b77c}', GraphemeBreak::Lv),
+    ('\u{b77d}', '\u{b797}', GraphemeBreak::Lvt), ('\u{b798}', '\u{b798}', GraphemeBreak::Lv),
+    ('\u{b799}', '\u{b7b3}', GraphemeBreak::Lvt), ('\u{b7b4}', '\u{b7b4}', GraphemeBreak::Lv),
+    ('\u{b7b5}', '\u{b7cf}', GraphemeBreak::Lvt), ('\u{b7d0}', '\u{b7d0}', GraphemeBreak::Lv),
+    ('\u{b7d1}', '\u{b7eb}', GraphemeBreak::Lvt), ('\u{b7ec}', '\u{b7ec}', GraphemeBreak::Lv),
+    ('\u{b7ed}', '\u{b807}', GraphemeBreak::Lvt), ('\u{b808}', '\u{b808}', GraphemeBreak::Lv),
+    ('\u{b809}', '\u{b823}', GraphemeBreak::Lvt), ('\u{b824}', '\u{b824}', GraphemeBreak::Lv),
+    ('\u{b825}', '\u{b83f}', GraphemeBreak::Lvt), ('\u{b840}', '\u{b840}', GraphemeBreak::Lv),
+    ('\u{b841}', '\u{b85b}', GraphemeBreak::Lvt), ('\u{b85c}', '\u{b85c}', GraphemeBreak::Lv),
+    ('\u{b85d}', '\u{b877}', GraphemeBreak::Lvt), ('\u{b878}', '\u{b878}', GraphemeBreak::Lv),
+    ('\u{b879}', '\u{b893}', GraphemeBreak::Lvt), ('\u{b894}', '\u{b894}', GraphemeBreak::Lv),
+    ('\u{b895}', '\u{b8af}', GraphemeBreak::Lvt), ('\u{b8b0}', '\u{b8b0}', GraphemeBreak::Lv),
+    ('\u{b8b1}', '\u{b8cb}', GraphemeBreak::Lvt), ('\u{b8cc}', '\u{b8cc}', GraphemeBreak::Lv),
+    ('\u{b8cd}', '\u{b8e7}', GraphemeBreak::Lvt), ('\u{b8e8}', '\u{b8e8}', GraphemeBreak::Lv),
+    ('\u{b8e9}', '\u{b903}', GraphemeBreak::Lvt), ('\u{b904}', '\u{b904}', GraphemeBreak::Lv),
+    ('\u{b905}', '\u{b91f}', GraphemeBreak::Lvt), ('\u{b920}', '\u{b920}', GraphemeBreak::Lv),
+    ('\u{b921}', '\u{b93b}', GraphemeBreak::Lvt), ('\u{b93c}', '\u{b93c}', GraphemeBreak::Lv),
+    ('\u{b93d}', '\u{b957}', GraphemeBreak::Lvt), ('\u{b958}', '\u{b958}', GraphemeBreak::Lv),
+    ('\u{b959}', '\u{b973}', GraphemeBreak::Lvt), ('\u{b974}', '\u{b974}', GraphemeBreak::Lv),
+    ('\u{b975}', '\u{b98f}', GraphemeBreak::Lvt), ('\u{b990}', '\u{b990}', GraphemeBreak::Lv),
+    ('\u{b991}', '\u{b9ab}', GraphemeBreak::Lvt), ('\u{b9ac}', '\u{b9ac}', GraphemeBreak::Lv),
+    ('\u{b9ad}', '\u{b9c7}', GraphemeBreak::Lvt), ('\u{b9c8}', '\u{b9c8}', GraphemeBreak::Lv),
+    ('\u{b9c9}', '\u{b9e3}', GraphemeBreak::Lvt), ('\u{b9e4}', '\u{b9e4}', GraphemeBreak::Lv),
+    ('\u{b9e5}', '\u{b9ff}', GraphemeBreak::Lvt), ('\u{ba00}', '\u{ba00}', GraphemeBreak::Lv),
+    ('\u{ba01}', '\u{ba1b}', GraphemeBreak::Lvt), ('\u{ba1c}', '\u{ba1c}', GraphemeBreak::Lv),
+    ('\u{ba1d}', '\u{ba37}', GraphemeBreak::Lvt), ('\u{ba38}', '\u{ba38}', GraphemeBreak::Lv),
+    ('\u{ba39}', '\u{ba53}', GraphemeBreak::Lvt), ('\u{ba54}', '\u{ba54}', GraphemeBreak::Lv),
+    ('\u{ba55}', '\u{ba6f}', GraphemeBreak::Lvt), ('\u{ba70}', '\u{ba70}', GraphemeBreak::Lv),
+    ('\u{ba71}', '\u{ba8b}', GraphemeBreak::Lvt), ('\u{ba8c}', '\u{ba8c}', GraphemeBreak::Lv),
+    ('\u{ba8d}', '\u{baa7}', GraphemeBreak::Lvt), ('\u{baa8}', '\u{baa8}', GraphemeBreak::Lv),
+    ('\u{baa9}', '\u{bac3}', GraphemeBreak::Lvt), ('\u{bac4}', '\u{bac4}', GraphemeBreak::Lv),
+    ('\u{bac5}', '\u{badf}', GraphemeBreak::Lvt), ('\u{bae0}', '\u{bae0}', GraphemeBreak::Lv),
+    ('\u{bae1}', '\u{bafb}', GraphemeBreak::Lvt), ('\u{bafc}', '\u{bafc}', GraphemeBreak::Lv),
+    ('\u{bafd}', '\u{bb17}', GraphemeBreak::Lvt), ('\u{bb18}', '\u{bb18}', GraphemeBreak::Lv),
+    ('\u{bb19}', '\u{bb33}', GraphemeBreak::Lvt), ('\u{bb34}', '\u{bb34}', GraphemeBreak::Lv),
+    ('\u{bb35}', '\u{bb4f}', GraphemeBreak::Lvt), ('\u{bb50}', '\u{bb50}', GraphemeBreak::Lv),
+    ('\u{bb51}', '\u{bb6b}', GraphemeBreak::Lvt), ('\u{bb6c}', '\u{bb6c}', GraphemeBreak::Lv),
+    ('\u{bb6d}', '\u{bb87}', GraphemeBreak::Lvt), ('\u{bb88}', '\u{bb88}', GraphemeBreak::Lv),
+    ('\u{bb89}', '\u{bba3}', GraphemeBreak::Lvt), ('\u{bba4}', '\u{bba4}', GraphemeBreak::Lv),
+    ('\u{bba5}', '\u{bbbf}', GraphemeBreak::Lvt), ('\u{bbc0}', '\u{bbc0}', GraphemeBreak::Lv),
+    ('\u{bbc1}', '\u{bbdb}', GraphemeBreak::Lvt), ('\u{bbdc}', '\u{bbdc}', GraphemeBreak::Lv),
+    ('\u{bbdd}', '\u{bbf7}', GraphemeBreak::Lvt), ('\u{bbf8}', '\u{bbf8}', GraphemeBreak::Lv),
+    ('\u{bbf9}', '\u{bc13}', GraphemeBreak::Lvt), ('\u{bc14}', '\u{bc14}', GraphemeBreak::Lv),
+    ('\u{bc15}', '\u{bc2f}', GraphemeBreak::Lvt), ('\u{bc30}', '\u{bc30}', GraphemeBreak::Lv),
+    ('\u{bc31}', '\u{bc4b}', GraphemeBreak::Lvt), ('\u{bc4c}', '\u{bc4c}', GraphemeBreak::Lv),
+    ('\u{bc4d}', '\u{bc67}', GraphemeBreak::Lvt), ('\u{bc68}', '\u{bc68}', GraphemeBreak::Lv),
+    ('\u{bc69}', '\u{bc83}', GraphemeBreak::Lvt), ('\u{bc84}', '\u{bc84}', GraphemeBreak::Lv),
+    ('\u{bc85}', '\u{bc9f}', GraphemeBreak::Lvt), ('\u{bca0}', '\u{bca0}', GraphemeBreak::Lv),
+    ('\u{bca1}', '\u{bcbb}', GraphemeBreak::Lvt), ('\u{bcbc}', '\u{bcbc}', GraphemeBreak::Lv),
+    ('\u{bcbd}', '\u{bcd7}', GraphemeBreak::Lvt), ('\u{bcd8}', '\u{bcd8}', GraphemeBreak::Lv),
+    ('\u{bcd9}', '\u{bcf3}', GraphemeBreak::Lvt), ('\u{bcf4}', '\u{bcf4}', GraphemeBreak::Lv),
+    ('\u{bcf5}', '\u{bd0f}', GraphemeBreak::Lvt), ('\u{bd10}', '\u{bd10}', GraphemeBreak::Lv),
+    ('\u{bd11}', '\u{bd2b}', GraphemeBreak::Lvt), ('\u{bd2c}', '\u{bd2c}', GraphemeBreak::Lv),
+    ('\u{bd2d}', '\u{bd47}', GraphemeBreak::Lvt), ('\u{bd48}', '\u{bd48}', GraphemeBreak::Lv),
+    ('\u{bd49}', '\u{bd63}', GraphemeBreak::Lvt), ('\u{bd64}', '\u{bd64}', GraphemeBreak::Lv),
+    ('\u{bd65}', '\u{bd7f}', GraphemeBreak::Lvt), ('\u{bd80}', '\u{bd80}', GraphemeBreak::Lv),
+    ('\u{bd81}', '\u{bd9b}', GraphemeBreak::Lvt), ('\u{bd9c}', '\u{bd9c}', GraphemeBreak::Lv),
+    ('\u{bd9d}', '\u{bdb7}', GraphemeBreak::Lvt), ('\u{bdb8}', '\u{bdb8}', GraphemeBreak::Lv),
+    ('\u{bdb9}', '\u{bdd3}', GraphemeBreak::Lvt), ('\u{bdd4}', '\u{bdd4}', GraphemeBreak::Lv),
+    ('\u{bdd5}', '\u{bdef}', GraphemeBreak::Lvt), ('\u{bdf0}', '\u{bdf0}', GraphemeBreak::Lv),
+    ('\u{bdf1}', '\u{be0b}', GraphemeBreak::Lvt), ('\u{be0c}', '\u{be0c}', GraphemeBreak::Lv),
+    ('\u{be0d}', '\u{be27}', GraphemeBreak::Lvt), ('\u{be28}', '\u{be28}', GraphemeBreak::Lv),
+    ('\u{be29}', '\u{be43}', GraphemeBreak::Lvt), ('\u{be44}', '\u{be44}', GraphemeBreak::Lv),
+    ('\u{be45}', '\u{be5f}', GraphemeBreak::Lvt), ('\u{be60}', '\u{be60}', GraphemeBreak::Lv),
+    ('\u{be61}', '\u{be7b}', GraphemeBreak::Lvt), ('\u{be7c}', '\u{be7c}', GraphemeBreak::Lv),
+    ('\u{be7d}', '\u{be97}', GraphemeBreak::Lvt), ('\u{be98}', '\u{be98}', GraphemeBreak::Lv),
+    ('\u{be99}', '\u{beb3}', GraphemeBreak::Lvt), ('\u{beb4}', '\u{beb4}', GraphemeBreak::Lv),
+    ('\u{beb5}', '\u{becf}', GraphemeBreak::Lvt), ('\u{bed0}', '\u{bed0}', GraphemeBreak::Lv),
+    ('\u{bed1}', '\u{beeb}', GraphemeBreak::Lvt), ('\u{beec}', '\u{beec}', GraphemeBreak::Lv),
+    ('\u{beed}', '\u{bf07}', GraphemeBreak::Lvt), ('\u{bf08}', '\u{bf08}', GraphemeBreak::Lv),
+    ('\u{bf09}', '\u{bf23}', GraphemeBreak::Lvt), ('\u{bf24}', '\u{bf24}', GraphemeBreak::Lv),
+    ('\u{bf25}', '\u{bf3f}', GraphemeBreak::Lvt), ('\u{bf40}', '\u{bf40}', GraphemeBreak::Lv),
+    ('\u{bf41}', '\u{bf5b}', GraphemeBreak::Lvt), ('\u{bf5c}', '\u{bf5c}', GraphemeBreak::Lv),
+    ('\u{bf5d}', '\u{bf77}', GraphemeBreak::Lvt), ('\u{bf78}', '\u{bf78}', GraphemeBreak::Lv),
+    ('\u{bf79}', '\u{bf93}', GraphemeBreak::Lvt), ('\u{bf94}', '\u{bf94}', GraphemeBreak::Lv),
+    ('\u{bf95}', '\u{bfaf}', GraphemeBreak::Lvt), ('\u{bfb0}', '\u{bfb0}', GraphemeBreak::Lv),
+    ('\u{bfb1}', '\u{bfcb}', GraphemeBreak::Lvt), ('\u{bfcc}', '\u{bfcc}', GraphemeBreak::Lv),
+    ('\u{bfcd}', '\u{bfe7}', GraphemeBreak::Lvt), ('\u{bfe8}', '\u{bfe8}', GraphemeBreak::Lv),
+    ('\u{bfe9}', '\u{c003}', GraphemeBreak::Lvt), ('\u{c004}', '\u{c004}', GraphemeBreak::Lv),
+    ('\u{c005}', '\u{c01f}', GraphemeBreak::Lvt), ('\u{c020}', '\u{c020}', GraphemeBreak::Lv),
+    ('\u{c021}', '\u{c03b}', GraphemeBreak::Lvt), ('\u{c03c}', '\u{c03c}', GraphemeBreak::Lv),
+    ('\u{c03d}', '\u{c057}', GraphemeBreak::Lvt), ('\u{c058}', '\u{c058}', GraphemeBreak::Lv),
+    ('\u{c059}', '\u{c073}', GraphemeBreak::Lvt), ('\u{c074}', '\u{c074}', GraphemeBreak::Lv),
+    ('\u{c075}', '\u{c08f}', GraphemeBreak::Lvt), ('\u{c090}', '\u{c090}', GraphemeBreak::Lv),
+    ('\u{c091}', '\u{c0ab}', GraphemeBreak::Lvt), ('\u{c0ac}', '\u{c0ac}', GraphemeBreak::Lv),
+    ('\u{c0ad}', '\u{c0c7}', GraphemeBreak::Lvt), ('\u{c0c8}', '\u{c0c8}', GraphemeBreak::Lv),
+    ('\u{c0c9}', '\u{c0e3}', GraphemeBreak::Lvt), ('\u{c0e4}', '\u{c0e4}', GraphemeBreak::Lv),
+    ('\u{c0e5}', '\u{c0ff}', GraphemeBreak::Lvt), ('\u{c100}', '\u{c100}', GraphemeBreak::Lv),
+    ('\u{c101}', '\u{c11b}', GraphemeBreak::Lvt), ('\u{c11c}', '\u{c11c}', GraphemeBreak::Lv),
+    ('\u{c11d}', '\u{c137}', GraphemeBreak::Lvt), ('\u{c138}', '\u{c138}', GraphemeBreak::Lv),
+    ('\u{c139}', '\u{c153}', GraphemeBreak::Lvt), ('\u{c154}', '\u{c154}', GraphemeBreak::Lv),
+    ('\u{c155}', '\u{c16f}', GraphemeBreak::Lvt), ('\u{c170}', '\u{c170}', GraphemeBreak::Lv),
+    ('\u{c171}', '\u{c18b}', GraphemeBreak::Lvt), ('\u{c18c}', '\u{c18c}', GraphemeBreak::Lv),
+    ('\u{c18d}', '\u{c1a7}', GraphemeBreak::Lvt), ('\u{c1a8}', '\u{c1a8}', GraphemeBreak::Lv),
+    ('\u{c1a9}', '\u{c1c3}', GraphemeBreak::Lvt), ('\u{c1c4}', '\u{c1c4}', GraphemeBreak::Lv),
+    ('\u{c1c5}', '\u{c1df}', GraphemeBreak::Lvt), ('\u{c1e0}', '\u{c1e0}', GraphemeBreak::Lv),
+    ('\u{c1e1}', '\u{c1fb}', GraphemeBreak::Lvt), ('\u{c1fc}', '\u{c1fc}', GraphemeBreak::Lv),
+    ('\u{c1fd}', '\u{c217}', GraphemeBreak::Lvt), ('\u{c218}', '\u{c218}', GraphemeBreak::Lv),
+    ('\u{c219}', '\u{c233}', GraphemeBreak::Lvt), ('\u{c234}', '\u{c234}', GraphemeBreak::Lv),
+    ('\u{c235}', '\u{c24f}', GraphemeBreak::Lvt), ('\u{c250}', '\u{c250}', GraphemeBreak::Lv),
+    ('\u{c251}', '\u{c26b}', GraphemeBreak::Lvt), ('\u{c26c}', '\u{c26c}', GraphemeBreak::Lv),
+    ('\u{c26d}', '\u{c287}', GraphemeBreak::Lvt), ('\u{c288}', '\u{c288}', GraphemeBreak::Lv),
+    ('\u{c289}', '\u{c2a3}', GraphemeBreak::Lvt), ('\u{c2a4}', '\u{c2a4}', GraphemeBreak::Lv),
+    ('\u{c2a5}', '\u{c2bf}', GraphemeBreak::Lvt), ('\u{c2c0}', '\u{c2c0}', GraphemeBreak::Lv),
+    ('\u{c2c1}', '\u{c2db}', GraphemeBreak::Lvt), ('\u{c2dc}', '\u{c2dc}', GraphemeBreak::Lv),
+    ('\u{c2dd}', '\u{c2f7}', GraphemeBreak::Lvt), ('\u{c2f8}', '\u{c2f8}', GraphemeBreak::Lv),
+    ('\u{c2f9}', '\u{c313}', GraphemeBreak::Lvt), ('\u{c314}', '\u{c314}', GraphemeBreak::Lv),
+    ('\u{c315}', '\u{c32f}', GraphemeBreak::Lvt), ('\u{c330}', '\u{c330}', GraphemeBreak::Lv),
+    ('\u{c331}', '\u{c34b}', GraphemeBreak::Lvt), ('\u{c34c}', '\u{c34c}', GraphemeBreak::Lv),
+    ('\u{c34d}', '\u{c367}', GraphemeBreak::Lvt), ('\u{c368}', '\u{c368}', GraphemeBreak::Lv),
+    ('\u{c369}', '\u{c383}', GraphemeBreak::Lvt), ('\u{c384}', '\u{c384}', GraphemeBreak::Lv),
+    ('\u{c385}', '\u{c39f}', GraphemeBreak::Lvt), ('\u{c3a0}', '\u{c3a0}', GraphemeBreak::Lv),
+    ('\u{c3a1}', '\u{c3bb}', GraphemeBreak::Lvt), ('\u{c3bc}', '\u{c3bc}', GraphemeBreak::Lv),
+    ('\u{c3bd}', '\u{c3d7}', GraphemeBreak::Lvt), ('\u{c3d8}', '\u{c3d8}', GraphemeBreak::Lv),
+    ('\u{c3d9}', '\u{c3f3}', GraphemeBreak::Lvt), ('\u{c3f4}', '\u{c3f4}', GraphemeBreak::Lv),
+    ('\u{c3f5}', '\u{c40f}', GraphemeBreak::Lvt), ('\u{c410}', '\u{c410}', GraphemeBreak::Lv),
+    ('\u{c411}', '\u{c42b}', GraphemeBreak::Lvt), ('\u{c42c}', '\u{c42c}', GraphemeBreak::Lv),
+    ('\u{c42d}', '\u{c447}', GraphemeBreak::Lvt), ('\u{c448}', '\u{c448}', GraphemeBreak::Lv),
+    ('\u{c449}', '\u{c463}', GraphemeBreak::Lvt), ('\u{c464}', '\u{c464}', GraphemeBreak::Lv),
+    ('\u{c465}', '\u{c47f}', GraphemeBreak::Lvt), ('\u{c480}', '\u{c480}', GraphemeBreak::Lv),
+    ('\u{c481}', '\u{c49b}', GraphemeBreak::Lvt), ('\u{c49c}', '\u{c49c}', GraphemeBreak::Lv),
+    ('\u{c49d}', '\u{c4b7}', GraphemeBreak::Lvt), ('\u{c4b8}', '\u{c4b8}', GraphemeBreak::Lv),
+    ('\u{c4b9}', '\u{c4d3}', GraphemeBreak::Lvt), ('\u{c4d4}', '\u{c4d4}', GraphemeBreak::Lv),
+    ('\u{c4d5}', '\u{c4ef}', GraphemeBreak::Lvt), ('\u{c4f0}', '\u{c4f0}', GraphemeBreak::Lv),
+    ('\u{c4f1}', '\u{c50b}', GraphemeBreak::Lvt), ('\u{c50c}', '\u{c50c}', GraphemeBreak::Lv),
+    ('\u{c50d}', '\u{c527}', GraphemeBreak::Lvt), ('\u{c528}', '\u{c528}', GraphemeBreak::Lv),
+    ('\u{c529}', '\u{c543}', GraphemeBreak::Lvt), ('\u{c544}', '\u{c544}', GraphemeBreak::Lv),
+    ('\u{c545}', '\u{c55f}', GraphemeBreak::Lvt), ('\u{c560}', '\u{c560}', GraphemeBreak::Lv),
+    ('\u{c561}', '\u{c57b}', GraphemeBreak::Lvt), ('\u{c57c}', '\u{c57c}', GraphemeBreak::Lv),
+    ('\u{c57d}', '\u{c597}', GraphemeBreak::Lvt), ('\u{c598}', '\u{c598}', GraphemeBreak::Lv),
+    ('\u{c599}', '\u{c5b3}', GraphemeBreak::Lvt), ('\u{c5b4}', '\u{c5b4}', GraphemeBreak::Lv),
+    ('\u{c5b5}', '\u{c5cf}', GraphemeBreak::Lvt), ('\u{c5d0}', '\u{c5d0}', GraphemeBreak::Lv),
+    ('\u{c5d1}', '\u{c5eb}', GraphemeBreak::Lvt), ('\u{c5ec}', '\u{c5ec}', GraphemeBreak::Lv),
+    ('\u{c5ed}', '\u{c607}', GraphemeBreak::Lvt), ('\u{c608}', '\u{c608}', GraphemeBreak::Lv),
+    ('\u{c609}', '\u{c623}', GraphemeBreak::Lvt), ('\u{c624}', '\u{c624}', GraphemeBreak::Lv),
+    ('\u{c625}', '\u{c63f}', GraphemeBreak::Lvt), ('\u{c640}', '\u{c640}', GraphemeBreak::Lv),
+    ('\u{c641}', '\u{c65b}', GraphemeBreak::Lvt), ('\u{c65c}', '\u{c65c}', GraphemeBreak::Lv),
+    ('\u{c65d}', '\u{c677}', GraphemeBreak::Lvt), ('\u{c678}', '\u{c678}', GraphemeBreak::Lv),
+    ('\u{c679}', '\u{c693}', GraphemeBreak::Lvt), ('\u{c694}', '\u{c694}', GraphemeBreak::Lv),
+    ('\u{c695}', '\u{c6af}', GraphemeBreak::Lvt), ('\u{c6b0}', '\u{c6b0}', GraphemeBreak::Lv),
+    ('\u{c6b1}', '\u{c6cb}', GraphemeBreak::Lvt), ('\u{c6cc}', '\u{c6cc}', GraphemeBreak::Lv),
+    ('\u{c6cd}', '\u{c6e7}', GraphemeBreak::Lvt), ('\u{c6e8}', '\u{c6e8}', GraphemeBreak::Lv),
+    ('\u{c6e9}', '\u{c703}', GraphemeBreak::Lvt), ('\u{c704}', '\u{c704}', GraphemeBreak::Lv),
+    ('\u{c705}', '\u{c71f}', GraphemeBreak::Lvt), ('\u{c720}', '\u{c720}', GraphemeBreak::Lv),
+    ('\u{c721}', '\u{c73b}', GraphemeBreak::Lvt), ('\u{c73c}', '\u{c73c}', GraphemeBreak::Lv),
+    ('\u{c73d}', '\u{c757}', GraphemeBreak::Lvt), ('\u{c758}', '\u{c758}', GraphemeBreak::Lv),
+    ('\u{c759}', '\u{c773}', GraphemeBreak::Lvt), ('\u{c774}', '\u{c774}', GraphemeBreak::Lv),
+    ('\u{c775}', '\u{c78f}', GraphemeBreak::Lvt), ('\u{c790}', '\u{c790}', GraphemeBreak::Lv),
+    ('\u{c791}', '\u{c7ab}', GraphemeBreak::Lvt), ('\u{c7ac}', '\u{c7ac}', GraphemeBreak::Lv),
+    ('\u{c7ad}', '\u{c7c7}', GraphemeBreak::Lvt), ('\u{c7c8}', '\u{c7c8}', GraphemeBreak::Lv),
+    ('\u{c7c9}', '\u{c7e3}', GraphemeBreak::Lvt), ('\u{c7e4}', '\u{c7e4}', GraphemeBreak::Lv),
+    ('\u{c7e5}', '\u{c7ff}', GraphemeBreak::Lvt), ('\u{c800}', '\u{c800}', GraphemeBreak::Lv),
+    ('\u{c801}', '\u{c81b}', GraphemeBreak::Lvt), ('\u{c81c}', '\u{c81c}', GraphemeBreak::Lv),
+    ('\u{c81d}', '\u{c837}', GraphemeBreak::Lvt), ('\u{c838}', '\u{c838}', GraphemeBreak::Lv),
+    ('\u{c839}', '\u{c853}', GraphemeBreak::Lvt), ('\u{c854}', '\u{c854}', GraphemeBreak::Lv),
+    ('\u{c855}', '\u{c86f}', GraphemeBreak::Lvt), ('\u{c870}', '\u{c870}', GraphemeBreak::Lv),
+    ('\u{c871}', '\u{c88b}', GraphemeBreak::Lvt), ('\u{c88c}', '\u{c88c}', GraphemeBreak::Lv),
+    ('\u{c88d}', '\u{c8a7}', GraphemeBreak::Lvt), ('\u{c8a8}', '\u{c8a8}', GraphemeBreak::Lv),
+    ('\u{c8a9}', '\u{c8c3}', GraphemeBreak::Lvt), ('\u{c8c4}', '\u{c8c4}', GraphemeBreak::Lv),
+    ('\u{c8c5}', '\u{c8df}', GraphemeBreak::Lvt), ('\u{c8e0}', '\u{c8e0}', GraphemeBreak::Lv),
+    ('\u{c8e1}', '\u{c8fb}', GraphemeBreak::Lvt), ('\u{c8fc}', '\u{c8fc}', GraphemeBreak::Lv),
+    ('\u{c8fd}', '\u{c917}', GraphemeBreak::Lvt), ('\u{c918}', '\u{c918}', GraphemeBreak::Lv),
+    ('\u{c919}', '\u{c933}', GraphemeBreak::Lvt), ('\u{c934}', '\u{c934}', GraphemeBreak::Lv),
+    ('\u{c935}', '\u{c94f}', GraphemeBreak::Lvt), ('\u{c950}', '\u{c950}', GraphemeBreak::Lv),
+    ('\u{c951}', '\u{c96b}', GraphemeBreak::Lvt), ('\u{c96c}', '\u{c96c}', GraphemeBreak::Lv),
+    ('\u{c96d}', '\u{c987}', GraphemeBreak::Lvt), ('\u{c988}', '\u{c988}', GraphemeBreak::Lv),
+    ('\u{c989}', '\u{c9a3}', GraphemeBreak::Lvt), ('\u{c9a4}', '\u{c9a4}', GraphemeBreak::Lv),
+    ('\u{c9a5}', '\u{c9bf}', GraphemeBreak::Lvt), ('\u{c9c0}', '\u{c9c0}', GraphemeBreak::Lv),
+    ('\u{c9c1}', '\u{c9db}', GraphemeBreak::Lvt), ('\u{c9dc}', '\u{c9dc}', GraphemeBreak::Lv),
+    ('\u{c9dd}', '\u{c9f7}', GraphemeBreak::Lvt), ('\u{c9f8}', '\u{c9f8}', GraphemeBreak::Lv),
+    ('\u{c9f9}', '\u{ca13}', GraphemeBreak::Lvt), ('\u{ca14}', '\u{ca14}', GraphemeBreak::Lv),
+    ('\u{ca15}', '\u{ca2f}', GraphemeBreak::Lvt), ('\u{ca30}', '\u{ca30}', GraphemeBreak::Lv),
+    ('\u{ca31}', '\u{ca4b}', GraphemeBreak::Lvt), ('\u{ca4c}', '\u{ca4c}', GraphemeBreak::Lv),
+    ('\u{ca4d}', '\u{ca67}', GraphemeBreak::Lvt), ('\u{ca68}', '\u{ca68}', GraphemeBreak::Lv),
+    ('\u{ca69}', '\u{ca83}', GraphemeBreak::Lvt), ('\u{ca84}', '\u{ca84}', GraphemeBreak::Lv),
+    ('\u{ca85}', '\u{ca9f}', GraphemeBreak::Lvt), ('\u{caa0}', '\u{caa0}', GraphemeBreak::Lv),
+    ('\u{caa1}', '\u{cabb}', GraphemeBreak::Lvt), ('\u{cabc}', '\u{cabc}', GraphemeBreak::Lv),
+    ('\u{cabd}', '\u{cad7}', GraphemeBreak::Lvt), ('\u{cad8}', '\u{cad8}', GraphemeBreak::Lv),
+    ('\u{cad9}', '\u{caf3}', GraphemeBreak::Lvt), ('\u{caf4}', '\u{caf4}', GraphemeBreak::Lv),
+    ('\u{caf5}', '\u{cb0f}', GraphemeBreak::Lvt), ('\u{cb10}', '\u{cb10}', GraphemeBreak::Lv),
+    ('\u{cb11}', '\u{cb2b}', GraphemeBreak::Lvt), ('\u{cb2c}', '\u{cb2c}', GraphemeBreak::Lv),
+    ('\u{cb2d}', '\u{cb47}', GraphemeBreak::Lvt), ('\u{cb48}', '\u{cb48}', GraphemeBreak::Lv),
+    ('\u{cb49}', '\u{cb63}', GraphemeBreak::Lvt), ('\u{cb64}', '\u{cb64}', GraphemeBreak::Lv),
+    ('\u{cb65}', '\u{cb7f}', GraphemeBreak::Lvt), ('\u{cb80}', '\u{cb80}', GraphemeBreak::Lv),
+    ('\u{cb81}', '\u{cb9b}', GraphemeBreak::Lvt), ('\u{cb9c}', '\u{cb9c}', GraphemeBreak::Lv),
+    ('\u{cb9d}', '\u{cbb7}', GraphemeBreak::Lvt), ('\u{cbb8}', '\u{cbb8}', GraphemeBreak::Lv),
+    ('\u{cbb9}', '\u{cbd3}', GraphemeBreak::Lvt), ('\u{cbd4}', '\u{cbd4}', GraphemeBreak::Lv),
+    ('\u{cbd5}', '\u{cbef}', GraphemeBreak::Lvt), ('\u{cbf0}', '\u{cbf0}', GraphemeBreak::Lv),
+    ('\u{cbf1}', '\u{cc0b}', GraphemeBreak::Lvt), ('\u{cc0c}', '\u{cc0c}', GraphemeBreak::Lv),
+    ('\u{cc0d}', '\u{cc27}', GraphemeBreak::Lvt), ('\u{cc28}', '\u{cc28}', GraphemeBreak::Lv),
+    ('\u{cc29}', '\u{cc43}', GraphemeBreak::Lvt), ('\u{cc44}', '\u{cc44}', GraphemeBreak::Lv),
+    ('\u{cc45}', '\u{cc5f}', GraphemeBreak::Lvt), ('\u{cc60}', '\u{cc60}', GraphemeBreak::Lv),
+    ('\u{cc61}', '\u{cc7b}', GraphemeBreak::Lvt), ('\u{cc7c}', '\u{cc7c}', GraphemeBreak::Lv),
+    ('\u{cc7d}', '\u{cc97}', GraphemeBreak::Lvt), ('\u{cc98}', '\u{cc98}', GraphemeBreak::Lv),
+    ('\u{cc99}', '\u{ccb3}', GraphemeBreak::Lvt), ('\u{ccb4}', '\u{ccb4}', GraphemeBreak::Lv),
+    ('\u{ccb5}', '\u{cccf}', GraphemeBreak::Lvt), ('\u{ccd0}', '\u{ccd0}', GraphemeBreak::Lv),
+    ('\u{ccd1}', '\u{cceb}', GraphemeBreak::Lvt), ('\u{ccec}', '\u{ccec}', GraphemeBreak::Lv),
+    ('\u{cced}', '\u{cd07}', GraphemeBreak::Lvt), ('\u{cd08}', '\u{cd08}', GraphemeBreak::Lv),
+    ('\u{cd09}', '\u{cd23}', GraphemeBreak::Lvt), ('\u{cd24}', '\u{cd24}', GraphemeBreak::Lv),
+    ('\u{cd25}', '\u{cd3f}', GraphemeBreak::Lvt), ('\u{cd40}', '\u{cd40}', GraphemeBreak::Lv),
+    ('\u{cd41}', '\u{cd5b}', GraphemeBreak::Lvt), ('\u{cd5c}', '\u{cd5c}', GraphemeBreak::Lv),
+    ('\u{cd5d}', '\u{cd77}', GraphemeBreak::Lvt), ('\u{cd78}', '\u{cd78}', GraphemeBreak::Lv),
+    ('\u{cd79}', '\u{cd93}', GraphemeBreak::Lvt), ('\u{cd94}', '\u{cd94}', GraphemeBreak::Lv),
+    ('\u{cd95}', '\u{cdaf}', GraphemeBreak::Lvt), ('\u{cdb0}', '\u{cdb0}', GraphemeBreak::Lv),
+    ('\u{cdb1}', '\u{cdcb}', GraphemeBreak::Lvt), ('\u{cdcc}', '\u{cdcc}', GraphemeBreak::Lv),
+    ('\u{cdcd}', '\u{cde7}', GraphemeBreak::Lvt), ('\u{cde8}', '\u{cde8}', GraphemeBreak::Lv),
+    ('\u{cde9}', '\u{ce03}', GraphemeBreak::Lvt), ('\u{ce04}', '\u{ce04}', GraphemeBreak::Lv),
+    ('\u{ce05}', '\u{ce1f}', GraphemeBreak::Lvt), ('\u{ce20}', '\u{ce20}', GraphemeBreak::Lv),
+    ('\u{ce21}', '\u{ce3b}', GraphemeBreak::Lvt), ('\u{ce3c}', '\u{ce3c}', GraphemeBreak::Lv),
+    ('\u{ce3d}', '\u{ce57}', GraphemeBreak::Lvt), ('\u{ce58}', '\u{ce58}', GraphemeBreak::Lv),
+    ('\u{ce59}', '\u{ce73}', GraphemeBreak::Lvt), ('\u{ce74}', '\u{ce74}', GraphemeBreak::Lv),
+    ('\u{ce75}', '\u{ce8f}', GraphemeBreak::Lvt), ('\u{ce90}', '\u{ce90}', GraphemeBreak::Lv),
+    ('\u{ce91}', '\u{ceab}', GraphemeBreak::Lvt), ('\u{ceac}', '\u{ceac}', GraphemeBreak::Lv),
+    ('\u{cead}', '\u{cec7}', GraphemeBreak::Lvt), ('\u{cec8}', '\u{cec8}', GraphemeBreak::Lv),
+    ('\u{cec9}', '\u{cee3}', GraphemeBreak::Lvt), ('\u{cee4}', '\u{cee4}', GraphemeBreak::Lv),
+    ('\u{cee5}', '\u{ceff}', GraphemeBreak::Lvt), ('\u{cf00}', '\u{cf00}', GraphemeBreak::Lv),
+    ('\u{cf01}', '\u{cf1b}', GraphemeBreak::Lvt), ('\u{cf1c}', '\u{cf1c}', GraphemeBreak::Lv),
+    ('\u{cf1d}', '\u{cf37}', GraphemeBreak::Lvt), ('\u{cf38}', '\u{cf38}', GraphemeBreak::Lv),
+    ('\u{cf39}', '\u{cf53}', GraphemeBreak::Lvt), ('\u{cf54}', '\u{cf54}', GraphemeBreak::Lv),
+    ('\u{cf55}', '\u{cf6f}', GraphemeBreak::Lvt), ('\u{cf70}', '\u{cf70}', GraphemeBreak::Lv),
+    ('\u{cf71}', '\u{cf8b}', GraphemeBreak::Lvt), ('\u{cf8c}', '\u{cf8c}', GraphemeBreak::Lv),
+    ('\u{cf8d}', '\u{cfa7}', GraphemeBreak::Lvt), ('\u{cfa8}', '\u{cfa8}', GraphemeBreak::Lv),
+    ('\u{cfa9}', '\u{cfc3}', GraphemeBreak::Lvt), ('\u{cfc4}', '\u{cfc4}', GraphemeBreak::Lv),
+    ('\u{cfc5}', '\u{cfdf}', GraphemeBreak::Lvt), ('\u{cfe0}', '\u{cfe0}', GraphemeBreak::Lv),
+    ('\u{cfe1}', '\u{cffb}', GraphemeBreak::Lvt), ('\u{cffc}', '\u{cffc}', GraphemeBreak::Lv),
+    ('\u{cffd}', '\u{d017}', GraphemeBreak::Lvt), ('\u{d018}', '\u{d018}', GraphemeBreak::Lv),
+    ('\u{d019}', '\u{d033}', GraphemeBreak::Lvt), ('\u{d034}', '\u{d034}', GraphemeBreak::Lv),
+    ('\u{d035}', '\u{d04f}', GraphemeBreak::Lvt), ('\u{d050}', '\u{d050}', GraphemeBreak::Lv),
+    ('\u{d051}', '\u{d06b}', GraphemeBreak::Lvt), ('\u{d06c}', '\u{d06c}', GraphemeBreak::Lv),
+    ('\u{d06d}', '\u{d087}', GraphemeBreak::Lvt), ('\u{d088}', '\u{d088}', GraphemeBreak::Lv),
+    ('\u{d089}', '\u{d0a3}', GraphemeBreak::Lvt), ('\u{d0a4}', '\u{d0a4}', GraphemeBreak::Lv),
+    ('\u{d0a5}', '\u{d0bf}', GraphemeBreak::Lvt), ('\u{d0c0}', '\u{d0c0}', GraphemeBreak::Lv),
+    ('\u{d0c1}', '\u{d0db}', GraphemeBreak::Lvt), ('\u{d0dc}', '\u{d0dc}', GraphemeBreak::Lv),
+    ('\u{d0dd}', '\u{d0f7}', GraphemeBreak::Lvt), ('\u{d0f8}', '\u{d0f8}', GraphemeBreak::Lv),
+    ('\u{d0f9}', '\u{d113}', GraphemeBreak::Lvt), ('\u{d114}', '\u{d114}', GraphemeBreak::Lv),
+    ('\u{d115}', '\u{d12f}', GraphemeBreak::Lvt), ('\u{d130}', '\u{d130}', GraphemeBreak::Lv),
+    ('\u{d131}', '\u{d14b}', GraphemeBreak::Lvt), ('\u{d14c}', '\u{d14c}', GraphemeBreak::Lv),
+    ('\u{d14d}', '\u{d167}', GraphemeBreak::Lvt), ('\u{d168}', '\u{d168}', GraphemeBreak::Lv),
+    ('\u{d169}', '\u{d183}', GraphemeBreak::Lvt), ('\u{d184}', '\u{d184}', GraphemeBreak::Lv),
+    ('\u{d185}', '\u{d19f}', GraphemeBreak::Lvt), ('\u{d1a0}', '\u{d1a0}', GraphemeBreak::Lv),
+    ('\u{d1a1}', '\u{d1bb}', GraphemeBreak::Lvt), ('\u{d1bc}', '\u{d1bc}', GraphemeBreak::Lv),
+    ('\u{d1bd}', '\u{d1d7}', GraphemeBreak::Lvt), ('\u{d1d8}', '\u{d1d8}', GraphemeBreak::Lv),
+    ('\u{d1d9}', '\u{d1f3}', GraphemeBreak::Lvt), ('\u{d1f4}', '\u{d1f4}', GraphemeBreak::Lv),
+    ('\u{d1f5}', '\u{d20f}', GraphemeBreak::Lvt), ('\u{d210}', '\u{d210}', GraphemeBreak::Lv),
+    ('\u{d211}', '\u{d22b}', GraphemeBreak::Lvt), ('\u{d22c}', '\u{d22c}', GraphemeBreak::Lv),
+    ('\u{d22d}', '\u{d247}', GraphemeBreak::Lvt), ('\u{d248}', '\u{d248}', GraphemeBreak::Lv),
+    ('\u{d249}', '\u{d263}', GraphemeBreak::Lvt), ('\u{d264}', '\u{d264}', GraphemeBreak::Lv),
+    ('\u{d265}', '\u{d27f}', GraphemeBreak::Lvt), ('\u{d280}', '\u{d280}', GraphemeBreak::Lv),
+    ('\u{d281}', '\u{d29b}', GraphemeBreak::Lvt), ('\u{d29c}', '\u{d29c}', GraphemeBreak::Lv),
+    ('\u{d29d}', '\u{d2b7}', GraphemeBreak::Lvt), ('\u{d2b8}', '\u{d2b8}', GraphemeBreak::Lv),
+    ('\u{d2b9}', '\u{d2d3}', GraphemeBreak::Lvt), ('\u{d2d4}', '\u{d2d4}', GraphemeBreak::Lv),
+    ('\u{d2d5}', '\u{d2ef}', GraphemeBreak::Lvt), ('\u{d2f0}', '\u{d2f0}', GraphemeBreak::Lv),
+    ('\u{d2f1}', '\u{d30b}', GraphemeBreak::Lvt), ('\u{d30c}', '\u{d30c}', GraphemeBreak::Lv),
+    ('\u{d30d}', '\u{d327}', GraphemeBreak::Lvt), ('\u{d328}', '\u{d328}', GraphemeBreak::Lv),
+    ('\u{d329}', '\u{d343}', GraphemeBreak::Lvt), ('\u{d344}', '\u{d344}', GraphemeBreak::Lv),
+    ('\u{d345}', '\u{d35f}', GraphemeBreak::Lvt), ('\u{d360}', '\u{d360}', GraphemeBreak::Lv),
+    ('\u{d361}', '\u{d37b}', GraphemeBreak::Lvt), ('\u{d37c}', '\u{d37c}', GraphemeBreak::Lv),
+    ('\u{d37d}', '\u{d397}', GraphemeBreak::Lvt), ('\u{d398}', '\u{d398}', GraphemeBreak::Lv),
+    ('\u{d399}', '\u{d3b3}', GraphemeBreak::Lvt), ('\u{d3b4}', '\u{d3b4}', GraphemeBreak::Lv),
+    ('\u{d3b5}', '\u{d3cf}', GraphemeBreak::Lvt), ('\u{d3d0}', '\u{d3d0}', GraphemeBreak::Lv),
+    ('\u{d3d1}', '\u{d3eb}', GraphemeBreak::Lvt), ('\u{d3ec}', '\u{d3ec}', GraphemeBreak::Lv),
+    ('\u{d3ed}', '\u{d407}', GraphemeBreak::Lvt), ('\u{d408}', '\u{d408}', GraphemeBreak::Lv),
+    ('\u{d409}', '\u{d423}', GraphemeBreak::Lvt), ('\u{d424}', '\u{d424}', GraphemeBreak::Lv),
+    ('\u{d425}', '\u{d43f}', GraphemeBreak::Lvt), ('\u{d440}', '\u{d440}', GraphemeBreak::Lv),
+    ('\u{d441}', '\u{d45b}', GraphemeBreak::Lvt), ('\u{d45c}', '\u{d45c}', GraphemeBreak::Lv),
+    ('\u{d45d}', '\u{d477}', GraphemeBreak::Lvt), ('\u{d478}', '\u{d478}', GraphemeBreak::Lv),
+    ('\u{d479}', '\u{d493}', GraphemeBreak::Lvt), ('\u{d494}', '\u{d494}', GraphemeBreak::Lv),
+    ('\u{d495}', '\u{d4af}', GraphemeBreak::Lvt), ('\u{d4b0}', '\u{d4b0}', GraphemeBreak::Lv),
+    ('\u{d4b1}', '\u{d4cb}', GraphemeBreak::Lvt), ('\u{d4cc}', '\u{d4cc}', GraphemeBreak::Lv),
+    ('\u{d4cd}', '\u{d4e7}', GraphemeBreak::Lvt), ('\u{d4e8}', '\u{d4e8}', GraphemeBreak::Lv),
+    ('\u{d4e9}', '\u{d503}', GraphemeBreak::Lvt), ('\u{d504}', '\u{d504}', GraphemeBreak::Lv),
+    ('\u{d505}', '\u{d51f}', GraphemeBreak::Lvt), ('\u{d520}', '\u{d520}', GraphemeBreak::Lv),
+    ('\u{d521}', '\u{d53b}', GraphemeBreak::Lvt), ('\u{d53c}', '\u{d53c}', GraphemeBreak::Lv),
+    ('\u{d53d}', '\u{d557}', GraphemeBreak::Lvt), ('\u{d558}', '\u{d558}', GraphemeBreak::Lv),
+    ('\u{d559}', '\u{d573}', GraphemeBreak::Lvt), ('\u{d574}', '\u{d574}', GraphemeBreak::Lv),
+    ('\u{d575}', '\u{d58f}', GraphemeBreak::Lvt), ('\u{d590}', '\u{d590}', GraphemeBreak::Lv),
+    ('\u{d591}', '\u{d5ab}', GraphemeBreak::Lvt), ('\u{d5ac}', '\u{d5ac}', GraphemeBreak::Lv),
+    ('\u{d5ad}', '\u{d5c7}', GraphemeBreak::Lvt), ('\u{d5c8}', '\u{d5c8}', GraphemeBreak::Lv),
+    ('\u{d5c9}', '\u{d5e3}', GraphemeBreak::Lvt), ('\u{d5e4}', '\u{d5e4}', GraphemeBreak::Lv),
+    ('\u{d5e5}', '\u{d5ff}', GraphemeBreak::Lvt), ('\u{d600}', '\u{d600}', GraphemeBreak::Lv),
+    ('\u{d601}', '\u{d61b}', GraphemeBreak::Lvt), ('\u{d61c}', '\u{d61c}', GraphemeBreak::Lv),
+    ('\u{d61d}', '\u{d637}', GraphemeBreak::Lvt), ('\u{d638}', '\u{d638}', GraphemeBreak::Lv),
+    ('\u{d639}', '\u{d653}', GraphemeBreak::Lvt), ('\u{d654}', '\u{d654}', GraphemeBreak::Lv),
+    ('\u{d655}', '\u{d66f}', GraphemeBreak::Lvt), ('\u{d670}', '\u{d670}', GraphemeBreak::Lv),
+    ('\u{d671}', '\u{d68b}', GraphemeBreak::Lvt), ('\u{d68c}', '\u{d68c}', GraphemeBreak::Lv),
+    ('\u{d68d}', '\u{d6a7}', GraphemeBreak::Lvt), ('\u{d6a8}', '\u{d6a8}', GraphemeBreak::Lv),
+    ('\u{d6a9}', '\u{d6c3}', GraphemeBreak::Lvt), ('\u{d6c4}', '\u{d6c4}', GraphemeBreak::Lv),
+    ('\u{d6c5}', '\u{d6df}', GraphemeBreak::Lvt), ('\u{d6e0}', '\u{d6e0}', GraphemeBreak::Lv),
+    ('\u{d6e1}', '\u{d6fb}', GraphemeBreak::Lvt), ('\u{d6fc}', '\u{d6fc}', GraphemeBreak::Lv),
+    ('\u{d6fd}', '\u{d717}', GraphemeBreak::Lvt), ('\u{d718}', '\u{d718}', GraphemeBreak::Lv),
+    ('\u{d719}', '\u{d733}', GraphemeBreak::Lvt), ('\u{d734}', '\u{d734}', GraphemeBreak::Lv),
+    ('\u{d735}', '\u{d74f}', GraphemeBreak::Lvt), ('\u{d750}', '\u{d750}', GraphemeBreak::Lv),
+    ('\u{d751}', '\u{d76b}', GraphemeBreak::Lvt), ('\u{d76c}', '\u{d76c}', GraphemeBreak::Lv),
+    ('\u{d76d}', '\u{d787}', GraphemeBreak::Lvt), ('\u{d788}', '\u{d788}', GraphemeBreak::Lv),
+    ('\u{d789}', '\u{d7a3}', GraphemeBreak::Lvt), ('\u{d7b0}', '\u{d7c6}', GraphemeBreak::V),
+    ('\u{d7cb}', '\u{d7fb}', GraphemeBreak::T), ('\u{fb1e}', '\u{fb1e}', GraphemeBreak::Extend),
+    ('\u{fe00}', '\u{fe0f}', GraphemeBreak::Extend), ('\u{fe20}', '\u{fe2f}', GraphemeBreak::Extend),
+    ('\u{feff}', '\u{feff}', GraphemeBreak::Control), ('\u{ff9e}', '\u{ff9f}', GraphemeBreak::Extend),
+    ('\u{fff0}', '\u{fffb}', GraphemeBreak::Control), ('\u{101fd}', '\u{101fd}', GraphemeBreak::Extend),
+    ('\u{102e0}', '\u{102e0}', GraphemeBreak::Extend), ('\u{10376}', '\u{1037a}', GraphemeBreak::Extend),
+    ('\u{10a01}', '\u{10a03}', GraphemeBreak::Extend), ('\u{10a05}', '\u{10a06}', GraphemeBreak::Extend),
+    ('\u{10a0c}', '\u{10a0f}', GraphemeBreak::Extend), ('\u{10a38}', '\u{10a3a}', GraphemeBreak::Extend),
+    ('\u{10a3f}', '\u{10a3f}', GraphemeBreak::Extend), ('\u{10ae5}', '\u{10ae6}', GraphemeBreak::Extend),
+    ('\u{10d24}', '\u{10d27}', GraphemeBreak::Extend), ('\u{10eab}', '\u{10eac}', GraphemeBreak::Extend),
+    ('\u{10f46}', '\u{10f50}', GraphemeBreak::Extend), ('\u{10f82}', '\u{10f85}', GraphemeBreak::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeBreak::SpacingMark), ('\u{11001}', '\u{11001}', GraphemeBreak::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeBreak::SpacingMark), ('\u{11038}', '\u{11046}', GraphemeBreak::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeBreak::Extend), ('\u{11073}', '\u{11074}', GraphemeBreak::Extend),
+    ('\u{1107f}', '\u{11081}', GraphemeBreak::Extend), ('\u{11082}', '\u{11082}', GraphemeBreak::SpacingMark),
+    ('\u{110b0}', '\u{110b2}', GraphemeBreak::SpacingMark), ('\u{110b3}', '\u{110b6}', GraphemeBreak::Extend),
+    ('\u{110b7}', '\u{110b8}', GraphemeBreak::SpacingMark), ('\u{110b9}', '\u{110ba}', GraphemeBreak::Extend),
+    ('\u{110bd}', '\u{110bd}', GraphemeBreak::Prepend), ('\u{110c2}', '\u{110c2}', GraphemeBreak::Extend),
+    ('\u{110cd}', '\u{110cd}', GraphemeBreak::Prepend), ('\u{11100}', '\u{11102}', GraphemeBreak::Extend),
+    ('\u{11127}', '\u{1112b}', GraphemeBreak::Extend), ('\u{1112c}', '\u{1112c}', GraphemeBreak::SpacingMark),
+    ('\u{1112d}', '\u{11134}', GraphemeBreak::Extend), ('\u{11145}', '\u{11146}', GraphemeBreak::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeBreak::Extend), ('\u{11180}', '\u{11181}', GraphemeBreak::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeBreak::SpacingMark), ('\u{111b3}', '\u{111b5}', GraphemeBreak::SpacingMark),
+    ('\u{111b6}', '\u{111be}', GraphemeBreak::Extend), ('\u{111bf}', '\u{111c0}', GraphemeBreak::SpacingMark),
+    ('\u{111c2}', '\u{111c3}', GraphemeBreak::Prepend), ('\u{111c9}', '\u{111cc}', GraphemeBreak::Extend),
+    ('\u{111ce}', '\u{111ce}', GraphemeBreak::SpacingMark), ('\u{111cf}', '\u{111cf}', GraphemeBreak::Extend),
+    ('\u{1122c}', '\u{1122e}', GraphemeBreak::SpacingMark), ('\u{1122f}', '\u{11231}', GraphemeBreak::Extend),
+    ('\u{11232}', '\u{11233}', GraphemeBreak::SpacingMark), ('\u{11234}', '\u{11234}', GraphemeBreak::Extend),
+    ('\u{11235}', '\u{11235}', GraphemeBreak::SpacingMark), ('\u{11236}', '\u{11237}', GraphemeBreak::Extend),
+    ('\u{1123e}', '\u{1123e}', GraphemeBreak::Extend), ('\u{112df}', '\u{112df}', GraphemeBreak::Extend),
+    ('\u{112e0}', '\u{112e2}', GraphemeBreak::SpacingMark), ('\u{112e3}', '\u{112ea}', GraphemeBreak::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeBreak::Extend), ('\u{11302}', '\u{11303}', GraphemeBreak::SpacingMark),
+    ('\u{1133b}', '\u{1133c}', GraphemeBreak::Extend), ('\u{1133e}', '\u{1133e}', GraphemeBreak::Extend),
+    ('\u{1133f}', '\u{1133f}', GraphemeBreak::SpacingMark), ('\u{11340}', '\u{11340}', GraphemeBreak::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeBreak::SpacingMark), ('\u{11347}', '\u{11348}', GraphemeBreak::SpacingMark),
+    ('\u{1134b}', '\u{1134d}', GraphemeBreak::SpacingMark), ('\u{11357}', '\u{11357}', GraphemeBreak::Extend),
+    ('\u{11362}', '\u{11363}', GraphemeBreak::SpacingMark), ('\u{11366}', '\u{1136c}', GraphemeBreak::Extend),
+    ('\u{11370}', '\u{11374}', GraphemeBreak::Extend), ('\u{11435}', '\u{11437}', GraphemeBreak::SpacingMark),
+    ('\u{11438}', '\u{1143f}', GraphemeBreak::Extend), ('\u{11440}', '\u{11441}', GraphemeBreak::SpacingMark),
+    ('\u{11442}', '\u{11444}', GraphemeBreak::Extend), ('\u{11445}', '\u{11445}', GraphemeBreak::SpacingMark),
+    ('\u{11446}', '\u{11446}', GraphemeBreak::Extend), ('\u{1145e}', '\u{1145e}', GraphemeBreak::Extend),
+    ('\u{114b0}', '\u{114b0}', GraphemeBreak::Extend), ('\u{114b1}', '\u{114b2}', GraphemeBreak::SpacingMark),
+    ('\u{114b3}', '\u{114b8}', GraphemeBreak::Extend), ('\u{114b9}', '\u{114b9}', GraphemeBreak::SpacingMark),
+    ('\u{114ba}', '\u{114ba}', GraphemeBreak::Extend), ('\u{114bb}', '\u{114bc}', GraphemeBreak::SpacingMark),
+    ('\u{114bd}', '\u{114bd}', GraphemeBreak::Extend), ('\u{114be}', '\u{114be}', GraphemeBreak::SpacingMark),
+    ('\u{114bf}', '\u{114c0}', GraphemeBreak::Extend), ('\u{114c1}', '\u{114c1}', GraphemeBreak::SpacingMark),
+    ('\u{114c2}', '\u{114c3}', GraphemeBreak::Extend), ('\u{115af}', '\u{115af}', GraphemeBreak::Extend),
+    ('\u{115b0}', '\u{115b1}', GraphemeBreak::SpacingMark), ('\u{115b2}', '\u{115b5}', GraphemeBreak::Extend),
+    ('\u{115b8}', '\u{115bb}', GraphemeBreak::SpacingMark), ('\u{115bc}', '\u{115bd}', GraphemeBreak::Extend),
+    ('\u{115be}', '\u{115be}', GraphemeBreak::SpacingMark), ('\u{115bf}', '\u{115c0}', GraphemeBreak::Extend),
+    ('\u{115dc}', '\u{115dd}', GraphemeBreak::Extend), ('\u{11630}', '\u{11632}', GraphemeBreak::SpacingMark),
+    ('\u{11633}', '\u{1163a}', GraphemeBreak::Extend), ('\u{1163b}', '\u{1163c}', GraphemeBreak::SpacingMark),
+    ('\u{1163d}', '\u{1163d}', GraphemeBreak::Extend), ('\u{1163e}', '\u{1163e}', GraphemeBreak::SpacingMark),
+    ('\u{1163f}', '\u{11640}', GraphemeBreak::Extend), ('\u{116ab}', '\u{116ab}', GraphemeBreak::Extend),
+    ('\u{116ac}', '\u{116ac}', GraphemeBreak::SpacingMark), ('\u{116ad}', '\u{116ad}', GraphemeBreak::Extend),
+    ('\u{116ae}', '\u{116af}', GraphemeBreak::SpacingMark), ('\u{116b0}', '\u{116b5}', GraphemeBreak::Extend),
+    ('\u{116b6}', '\u{116b6}', GraphemeBreak::SpacingMark), ('\u{116b7}', '\u{116b7}', GraphemeBreak::Extend),
+    ('\u{1171d}', '\u{1171f}', GraphemeBreak::Extend), ('\u{11722}', '\u{11725}', GraphemeBreak::Extend),
+    ('\u{11726}', '\u{11726}', GraphemeBreak::SpacingMark), ('\u{11727}', '\u{1172b}', GraphemeBreak::Extend),
+    ('\u{1182c}', '\u{1182e}', GraphemeBreak::SpacingMark), ('\u{1182f}', '\u{11837}', GraphemeBreak::Extend),
+    ('\u{11838}', '\u{11838}', GraphemeBreak::SpacingMark), ('\u{11839}', '\u{1183a}', GraphemeBreak::Extend),
+    ('\u{11930}', '\u{11930}', GraphemeBreak::Extend), ('\u{11931}', '\u{11935}', GraphemeBreak::SpacingMark),
+    ('\u{11937}', '\u{11938}', GraphemeBreak::SpacingMark), ('\u{1193b}', '\u{1193c}', GraphemeBreak::Extend),
+    ('\u{1193d}', '\u{1193d}', GraphemeBreak::SpacingMark), ('\u{1193e}', '\u{1193e}', GraphemeBreak::Extend),
+    ('\u{1193f}', '\u{1193f}', GraphemeBreak::Prepend), ('\u{11940}', '\u{11940}', GraphemeBreak::SpacingMark),
+    ('\u{11941}', '\u{11941}', GraphemeBreak::Prepend), ('\u{11942}', '\u{11942}', GraphemeBreak::SpacingMark),
+    ('\u{11943}', '\u{11943}', GraphemeBreak::Extend), ('\u{119d1}', '\u{119d3}', GraphemeBreak::SpacingMark),
+    ('\u{119d4}', '\u{119d7}', GraphemeBreak::Extend), ('\u{119da}', '\u{119db}', GraphemeBreak::Extend),
+    ('\u{119dc}', '\u{119df}', GraphemeBreak::SpacingMark), ('\u{119e0}', '\u{119e0}', GraphemeBreak::Extend),
+    ('\u{119e4}', '\u{119e4}', GraphemeBreak::SpacingMark), ('\u{11a01}', '\u{11a0a}', GraphemeBreak::Extend),
+    ('\u{11a33}', '\u{11a38}', GraphemeBreak::Extend), ('\u{11a39}', '\u{11a39}', GraphemeBreak::SpacingMark),
+    ('\u{11a3a}', '\u{11a3a}', GraphemeBreak::Prepend), ('\u{11a3b}', '\u{11a3e}', GraphemeBreak::Extend),
+    ('\u{11a47}', '\u{11a47}', GraphemeBreak::Extend), ('\u{11a51}', '\u{11a56}', GraphemeBreak::Extend),
+    ('\u{11a57}', '\u{11a58}', GraphemeBreak::SpacingMark), ('\u{11a59}', '\u{11a5b}', GraphemeBreak::Extend),
+    ('\u{11a84}', '\u{11a89}', GraphemeBreak::Prepend), ('\u{11a8a}', '\u{11a96}', GraphemeBreak::Extend),
+    ('\u{11a97}', '\u{11a97}', GraphemeBreak::SpacingMark), ('\u{11a98}', '\u{11a99}', GraphemeBreak::Extend),
+    ('\u{11c2f}', '\u{11c2f}', GraphemeBreak::SpacingMark), ('\u{11c30}', '\u{11c36}', GraphemeBreak::Extend),
+    ('\u{11c38}', '\u{11c3d}', GraphemeBreak::Extend), ('\u{11c3e}', '\u{11c3e}', GraphemeBreak::SpacingMark),
+    ('\u{11c3f}', '\u{11c3f}', GraphemeBreak::Extend), ('\u{11c92}', '\u{11ca7}', GraphemeBreak::Extend),
+    ('\u{11ca9}', '\u{11ca9}', GraphemeBreak::SpacingMark), ('\u{11caa}', '\u{11cb0}', GraphemeBreak::Extend),
+    ('\u{11cb1}', '\u{11cb1}', GraphemeBreak::SpacingMark), ('\u{11cb2}', '\u{11cb3}', GraphemeBreak::Extend),
+    ('\u{11cb4}', '\u{11cb4}', GraphemeBreak::SpacingMark), ('\u{11cb5}', '\u{11cb6}', GraphemeBreak::Extend),
+    ('\u{11d31}', '\u{11d36}', GraphemeBreak::Extend), ('\u{11d3a}', '\u{11d3a}', GraphemeBreak::Extend),
+    ('\u{11d3c}', '\u{11d3d}', GraphemeBreak::Extend), ('\u{11d3f}', '\u{11d45}', GraphemeBreak::Extend),
+    ('\u{11d46}', '\u{11d46}', GraphemeBreak::Prepend), ('\u{11d47}', '\u{11d47}', GraphemeBreak::Extend),
+    ('\u{11d8a}', '\u{11d8e}', GraphemeBreak::SpacingMark), ('\u{11d90}', '\u{11d91}', GraphemeBreak::Extend),
+    ('\u{11d93}', '\u{11d94}', GraphemeBreak::SpacingMark), ('\u{11d95}', '\u{11d95}', GraphemeBreak::Extend),
+    ('\u{11d96}', '\u{11d96}', GraphemeBreak::SpacingMark), ('\u{11d97}', '\u{11d97}', GraphemeBreak::Extend),
+    ('\u{11ef3}', '\u{11ef4}', GraphemeBreak::Extend), ('\u{11ef5}', '\u{11ef6}', GraphemeBreak::SpacingMark),
+    ('\u{13430}', '\u{13438}', GraphemeBreak::Control), ('\u{16af0}', '\u{16af4}', GraphemeBreak::Extend),
+    ('\u{16b30}', '\u{16b36}', GraphemeBreak::Extend), ('\u{16f4f}', '\u{16f4f}', GraphemeBreak::Extend),
+    ('\u{16f51}', '\u{16f87}', GraphemeBreak::SpacingMark), ('\u{16f8f}', '\u{16f92}', GraphemeBreak::Extend),
+    ('\u{16fe4}', '\u{16fe4}', GraphemeBreak::Extend), ('\u{16ff0}', '\u{16ff1}', GraphemeBreak::SpacingMark),
+    ('\u{1bc9d}', '\u{1bc9e}', GraphemeBreak::Extend), ('\u{1bca0}', '\u{1bca3}', GraphemeBreak::Control),
+    ('\u{1cf00}', '\u{1cf2d}', GraphemeBreak::Extend), ('\u{1cf30}', '\u{1cf46}', GraphemeBreak::Extend),
+    ('\u{1d165}', '\u{1d165}', GraphemeBreak::Extend), ('\u{1d166}', '\u{1d166}', GraphemeBreak::SpacingMark),
+    ('\u{1d167}', '\u{1d169}', GraphemeBreak::Extend), ('\u{1d16d}', '\u{1d16d}', GraphemeBreak::SpacingMark),
+    ('\u{1d16e}', '\u{1d172}', GraphemeBreak::Extend), ('\u{1d173}', '\u{1d17a}', GraphemeBreak::Control),
+    ('\u{1d17b}', '\u{1d182}', GraphemeBreak::Extend), ('\u{1d185}', '\u{1d18b}', GraphemeBreak::Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', GraphemeBreak::Extend), ('\u{1d242}', '\u{1d244}', GraphemeBreak::Extend),
+    ('\u{1da00}', '\u{1da36}', GraphemeBreak::Extend), ('\u{1da3b}', '\u{1da6c}', GraphemeBreak::Extend),
+    ('\u{1da75}', '\u{1da75}', GraphemeBreak::Extend), ('\u{1da84}', '\u{1da84}', GraphemeBreak::Extend),
+    ('\u{1da9b}', '\u{1da9f}', GraphemeBreak::Extend), ('\u{1daa1}', '\u{1daaf}', GraphemeBreak::Extend),
+    ('\u{1e000}', '\u{1e006}', GraphemeBreak::Extend), ('\u{1e008}', '\u{1e018}', GraphemeBreak::Extend),
+    ('\u{1e01b}', '\u{1e021}', GraphemeBreak::Extend), ('\u{1e023}', '\u{1e024}', GraphemeBreak::Extend),
+    ('\u{1e026}', '\u{1e02a}', GraphemeBreak::Extend), ('\u{1e130}', '\u{1e136}', GraphemeBreak::Extend),
+    ('\u{1e2ae}', '\u{1e2ae}', GraphemeBreak::Extend), ('\u{1e2ec}', '\u{1e2ef}', GraphemeBreak::Extend),
+    ('\u{1e8d0}', '\u{1e8d6}', GraphemeBreak::Extend), ('\u{1e944}', '\u{1e94a}', GraphemeBreak::Extend),
+    ('\u{1f000}', '\u{1f0ff}', GraphemeBreak::ExtendedPictographic), ('\u{1f10d}', '\u{1f10f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f12f}', '\u{1f12f}', GraphemeBreak::ExtendedPictographic), ('\u{1f16c}', '\u{1f171}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f17e}', '\u{1f17f}', GraphemeBreak::ExtendedPictographic), ('\u{1f18e}', '\u{1f18e}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f191}', '\u{1f19a}', GraphemeBreak::ExtendedPictographic), ('\u{1f1ad}', '\u{1f1e5}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f1e6}', '\u{1f1ff}', GraphemeBreak::RegionalIndicator), ('\u{1f201}', '\u{1f20f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f21a}', '\u{1f21a}', GraphemeBreak::ExtendedPictographic), ('\u{1f22f}', '\u{1f22f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f232}', '\u{1f23a}', GraphemeBreak::ExtendedPictographic), ('\u{1f23c}', '\u{1f23f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f249}', '\u{1f3fa}', GraphemeBreak::ExtendedPictographic), ('\u{1f3fb}', '\u{1f3ff}', GraphemeBreak::Extend),
+    ('\u{1f400}', '\u{1f53d}', GraphemeBreak::ExtendedPictographic), ('\u{1f546}', '\u{1f64f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f680}', '\u{1f6ff}', GraphemeBreak::ExtendedPictographic), ('\u{1f774}', '\u{1f77f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f7d5}', '\u{1f7ff}', GraphemeBreak::ExtendedPictographic), ('\u{1f80c}', '\u{1f80f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f848}', '\u{1f84f}', GraphemeBreak::ExtendedPictographic), ('\u{1f85a}', '\u{1f85f}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f888}', '\u{1f88f}', GraphemeBreak::ExtendedPictographic), ('\u{1f8ae}', '\u{1f8ff}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f90c}', '\u{1f93a}', GraphemeBreak::ExtendedPictographic), ('\u{1f93c}', '\u{1f945}', GraphemeBreak::ExtendedPictographic),
+    ('\u{1f947}', '\u{1faff}', GraphemeBreak::ExtendedPictographic), ('\u{1fc00}', '\u{1fffd}', GraphemeBreak::ExtendedPictographic),
+    ('\u{e0000}', '\u{e001f}', GraphemeBreak::Control), ('\u{e0020}', '\u{e007f}', GraphemeBreak::Extend),
+    ('\u{e0080}', '\u{e00ff}', GraphemeBreak::Control), ('\u{e0100}', '\u{e01ef}', GraphemeBreak::Extend),
+    ('\u{e01f0}', '\u{e0fff}', GraphemeBreak::Control),
+];
+
+/// (display name, aliases, table)
+pub type PropertyEntry = (&'static str, &'static [&'static str], &'static [(char, char)]);
+
+pub const GC_C: &[(char, char)] = &[
+    ('\u{0}', '\u{1f}'), ('\u{7f}', '\u{9f}'), ('\u{ad}', '\u{ad}'), ('\u{378}', '\u{379}'),
+    ('\u{380}', '\u{383}'), ('\u{38b}', '\u{38b}'), ('\u{38d}', '\u{38d}'), ('\u{3a2}', '\u{3a2}'),
+    ('\u{530}', '\u{530}'), ('\u{557}', '\u{558}'), ('\u{58b}', '\u{58c}'), ('\u{590}', '\u{590}'),
+    ('\u{5c8}', '\u{5cf}'), ('\u{5eb}', '\u{5ee}'), ('\u{5f5}', '\u{605}'), ('\u{61c}', '\u{61c}'),
+    ('\u{6dd}', '\u{6dd}'), ('\u{70e}', '\u{70f}'), ('\u{74b}', '\u{74c}'), ('\u{7b2}', '\u{7bf}'),
+    ('\u{7fb}', '\u{7fc}'), ('\u{82e}', '\u{82f}'), ('\u{83f}', '\u{83f}'), ('\u{85c}', '\u{85d}'),
+    ('\u{85f}', '\u{85f}'), ('\u{86b}', '\u{86f}'), ('\u{88f}', '\u{897}'), ('\u{8e2}', '\u{8e2}'),
+    ('\u{984}', '\u{984}'), ('\u{98d}', '\u{98e}'), ('\u{991}', '\u{992}'), ('\u{9a9}', '\u{9a9}'),
+    ('\u{9b1}', '\u{9b1}'), ('\u{9b3}', '\u{9b5}'), ('\u{9ba}', '\u{9bb}'), ('\u{9c5}', '\u{9c6}'),
+    ('\u{9c9}', '\u{9ca}'), ('\u{9cf}', '\u{9d6}'), ('\u{9d8}', '\u{9db}'), ('\u{9de}', '\u{9de}'),
+    ('\u{9e4}', '\u{9e5}'), ('\u{9ff}', '\u{a00}'), ('\u{a04}', '\u{a04}'), ('\u{a0b}', '\u{a0e}'),
+    ('\u{a11}', '\u{a12}'), ('\u{a29}', '\u{a29}'), ('\u{a31}', '\u{a31}'), ('\u{a34}', '\u{a34}'),
+    ('\u{a37}', '\u{a37}'), ('\u{a3a}', '\u{a3b}'), ('\u{a3d}', '\u{a3d}'), ('\u{a43}', '\u{a46}'),
+    ('\u{a49}', '\u{a4a}'), ('\u{a4e}', '\u{a50}'), ('\u{a52}', '\u{a58}'), ('\u{a5d}', '\u{a5d}'),
+    ('\u{a5f}', '\u{a65}'), ('\u{a77}', '\u{a80}'), ('\u{a84}', '\u{a84}'), ('\u{a8e}', '\u{a8e}'),
+    ('\u{a92}', '\u{a92}'), ('\u{aa9}', '\u{aa9}'), ('\u{ab1}', '\u{ab1}'), ('\u{ab4}', '\u{ab4}'),
+    ('\u{aba}', '\u{abb}'), ('\u{ac6}', '\u{ac6}'), ('\u{aca}', '\u{aca}'), ('\u{ace}', '\u{acf}'),
+    ('\u{ad1}', '\u{adf}'), ('\u{ae4}', '\u{ae5}'), ('\u{af2}', '\u{af8}'), ('\u{b00}', '\u{b00}'),
+    ('\u{b04}', '\u{b04}'), ('\u{b0d}', '\u{b0e}'), ('\u{b11}', '\u{b12}'), ('\u{b29}', '\u{b29}'),
+    ('\u{b31}', '\u{b31}'), ('\u{b34}', '\u{b34}'), ('\u{b3a}', '\u{b3b}'), ('\u{b45}', '\u{b46}'),
+    ('\u{b49}', '\u{b4a}'), ('\u{b4e}', '\u{b54}'), ('\u{b58}', '\u{b5b}'), ('\u{b5e}', '\u{b5e}'),
+    ('\u{b64}', '\u{b65}'), ('\u{b78}', '\u{b81}'), ('\u{b84}', '\u{b84}'), ('\u{b8b}', '\u{b8d}'),
+    ('\u{b91}', '\u{b91}'), ('\u{b96}', '\u{b98}'), ('\u{b9b}', '\u{b9b}'), ('\u{b9d}', '\u{b9d}'),
+    ('\u{ba0}', '\u{ba2}'), ('\u{ba5}', '\u{ba7}'), ('\u{bab}', '\u{bad}'), ('\u{bba}', '\u{bbd}'),
+    ('\u{bc3}', '\u{bc5}'), ('\u{bc9}', '\u{bc9}'), ('\u{bce}', '\u{bcf}'), ('\u{bd1}', '\u{bd6}'),
+    ('\u{bd8}', '\u{be5}'), ('\u{bfb}', '\u{bff}'), ('\u{c0d}', '\u{c0d}'), ('\u{c11}', '\u{c11}'),
+    ('\u{c29}', '\u{c29}'), ('\u{c3a}', '\u{c3b}'), ('\u{c45}', '\u{c45}'), ('\u{c49}', '\u{c49}'),
+    ('\u{c4e}', '\u{c54}'), ('\u{c57}', '\u{c57}'), ('\u{c5b}', '\u{c5c}'), ('\u{c5e}', '\u{c5f}'),
+    ('\u{c64}', '\u{c65}'), ('\u{c70}', '\u{c76}'), ('\u{c8d}', '\u{c8d}'), ('\u{c91}', '\u{c91}'),
+    ('\u{ca9}', '\u{ca9}'), ('\u{cb4}', '\u{cb4}'), ('\u{cba}', '\u{cbb}'), ('\u{cc5}', '\u{cc5}'),
+    ('\u{cc9}', '\u{cc9}'), ('\u{cce}', '\u{cd4}'), ('\u{cd7}', '\u{cdc}'), ('\u{cdf}', '\u{cdf}'),
+    ('\u{ce4}', '\u{ce5}'), ('\u{cf0}', '\u{cf0}'), ('\u{cf3}', '\u{cff}'), ('\u{d0d}', '\u{d0d}'),
+    ('\u{d11}', '\u{d11}'), ('\u{d45}', '\u{d45}'), ('\u{d49}', '\u{d49}'), ('\u{d50}', '\u{d53}'),
+    ('\u{d64}', '\u{d65}'), ('\u{d80}', '\u{d80}'), ('\u{d84}', '\u{d84}'), ('\u{d97}', '\u{d99}'),
+    ('\u{db2}', '\u{db2}'), ('\u{dbc}', '\u{dbc}'), ('\u{dbe}', '\u{dbf}'), ('\u{dc7}', '\u{dc9}'),
+    ('\u{dcb}', '\u{dce}'), ('\u{dd5}', '\u{dd5}'), ('\u{dd7}', '\u{dd7}'), ('\u{de0}', '\u{de5}'),
+    ('\u{df0}', '\u{df1}'), ('\u{df5}', '\u{e00}'), ('\u{e3b}', '\u{e3e}'), ('\u{e5c}', '\u{e80}'),
+    ('\u{e83}', '\u{e83}'), ('\u{e85}', '\u{e85}'), ('\u{e8b}', '\u{e8b}'), ('\u{ea4}', '\u{ea4}'),
+    ('\u{ea6}', '\u{ea6}'), ('\u{ebe}', '\u{ebf}'), ('\u{ec5}', '\u{ec5}'), ('\u{ec7}', '\u{ec7}'),
+    ('\u{ece}', '\u{ecf}'), ('\u{eda}', '\u{edb}'), ('\u{ee0}', '\u{eff}'), ('\u{f48}', '\u{f48}'),
+    ('\u{f6d}', '\u{f70}'), ('\u{f98}', '\u{f98}'), ('\u{fbd}', '\u{fbd}'), ('\u{fcd}', '\u{fcd}'),
+    ('\u{fdb}', '\u{fff}'), ('\u{10c6}', '\u{10c6}'), ('\u{10c8}', '\u{10cc}'), ('\u{10ce}', '\u{10cf}'),
+    ('\u{1249}', '\u{1249}'), ('\u{124e}', '\u{124f}'), ('\u{1257}', '\u{1257}'), ('\u{1259}', '\u{1259}'),
+    ('\u{125e}', '\u{125f}'), ('\u{1289}', '\u{1289}'), ('\u{128e}', '\u{128f}'), ('\u{12b1}', '\u{12b1}'),
+    ('\u{12b6}', '\u{12b7}'), ('\u{12bf}', '\u{12bf}'), ('\u{12c1}', '\u{12c1}'), ('\u{12c6}', '\u{12c7}'),
+    ('\u{12d7}', '\u{12d7}'), ('\u{1311}', '\u{1311}'), ('\u{1316}', '\u{1317}'), ('\u{135b}', '\u{135c}'),
+    ('\u{137d}', '\u{137f}'), ('\u{139a}', '\u{139f}'), ('\u{13f6}', '\u{13f7}'), ('\u{13fe}', '\u{13ff}'),
+    ('\u{169d}', '\u{169f}'), ('\u{16f9}', '\u{16ff}'), ('\u{1716}', '\u{171e}'), ('\u{1737}', '\u{173f}'),
+    ('\u{1754}', '\u{175f}'), ('\u{176d}', '\u{176d}'), ('\u{1771}', '\u{1771}'), ('\u{1774}', '\u{177f}'),
+    ('\u{17de}', '\u{17df}'), ('\u{17ea}', '\u{17ef}'), ('\u{17fa}', '\u{17ff}'), ('\u{180e}', '\u{180e}'),
+    ('\u{181a}', '\u{181f}'), ('\u{1879}', '\u{187f}'), ('\u{18ab}', '\u{18af}'), ('\u{18f6}', '\u{18ff}'),
+    ('\u{191f}', '\u{191f}'), ('\u{192c}', '\u{192f}'), ('\u{193c}', '\u{193f}'), ('\u{1941}', '\u{1943}'),
+    ('\u{196e}', '\u{196f}'), ('\u{1975}', '\u{197f}'), ('\u{19ac}', '\u{19af}'), ('\u{19ca}', '\u{19cf}'),
+    ('\u{19db}', '\u{19dd}'), ('\u{1a1c}', '\u{1a1d}'), ('\u{1a5f}', '\u{1a5f}'), ('\u{1a7d}', '\u{1a7e}'),
+    ('\u{1a8a}', '\u{1a8f}'), ('\u{1a9a}', '\u{1a9f}'), ('\u{1aae}', '\u{1aaf}'), ('\u{1acf}', '\u{1aff}'),
+    ('\u{1b4d}', '\u{1b4f}'), ('\u{1b7f}', '\u{1b7f}'), ('\u{1bf4}', '\u{1bfb}'), ('\u{1c38}', '\u{1c3a}'),
+    ('\u{1c4a}', '\u{1c4c}'), ('\u{1c89}', '\u{1c8f}'), ('\u{1cbb}', '\u{1cbc}'), ('\u{1cc8}', '\u{1ccf}'),
+    ('\u{1cfb}', '\u{1cff}'), ('\u{1f16}', '\u{1f17}'), ('\u{1f1e}', '\u{1f1f}'), ('\u{1f46}', '\u{1f47}'),
+    ('\u{1f4e}', '\u{1f4f}'), ('\u{1f58}', '\u{1f58}'), ('\u{1f5a}', '\u{1f5a}'), ('\u{1f5c}', '\u{1f5c}'),
+    ('\u{1f5e}', '\u{1f5e}'), ('\u{1f7e}', '\u{1f7f}'), ('\u{1fb5}', '\u{1fb5}'), ('\u{1fc5}', '\u{1fc5}'),
+    ('\u{1fd4}', '\u{1fd5}'), ('\u{1fdc}', '\u{1fdc}'), ('\u{1ff0}', '\u{1ff1}'), ('\u{1ff5}', '\u{1ff5}'),
+    ('\u{1fff}', '\u{1fff}'), ('\u{200b}', '\u{200f}'), ('\u{202a}', '\u{202e}'), ('\u{2060}', '\u{206f}'),
+    ('\u{2072}', '\u{2073}'), ('\u{208f}', '\u{208f}'), ('\u{209d}', '\u{209f}'), ('\u{20c1}', '\u{20cf}'),
+    ('\u{20f1}', '\u{20ff}'), ('\u{218c}', '\u{218f}'), ('\u{2427}', '\u{243f}'), ('\u{244b}', '\u{245f}'),
+    ('\u{2b74}', '\u{2b75}'), ('\u{2b96}', '\u{2b96}'), ('\u{2cf4}', '\u{2cf8}'), ('\u{2d26}', '\u{2d26}'),
+    ('\u{2d28}', '\u{2d2c}'), ('\u{2d2e}', '\u{2d2f}'), ('\u{2d68}', '\u{2d6e}'), ('\u{2d71}', '\u{2d7e}'),
+    ('\u{2d97}', '\u{2d9f}'), ('\u{2da7}', '\u{2da7}'), ('\u{2daf}', '\u{2daf}'), ('\u{2db7}', '\u{2db7}'),
+    ('\u{2dbf}', '\u{2dbf}'), ('\u{2dc7}', '\u{2dc7}'), ('\u{2dcf}', '\u{2dcf}'), ('\u{2dd7}', '\u{2dd7}'),
+    ('\u{2ddf}', '\u{2ddf}'), ('\u{2e5e}', '\u{2e7f}'), ('\u{2e9a}', '\u{2e9a}'), ('\u{2ef4}', '\u{2eff}'),
+    ('\u{2fd6}', '\u{2fef}'), ('\u{2ffc}', '\u{2fff}'), ('\u{3040}', '\u{3040}'), ('\u{3097}', '\u{3098}'),
+    ('\u{3100}', '\u{3104}'), ('\u{3130}', '\u{3130}'), ('\u{318f}', '\u{318f}'), ('\u{31e4}', '\u{31ef}'),
+    ('\u{321f}', '\u{321f}'), ('\u{a48d}', '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}', '\u{a63f}'),
+    ('\u{a6f8}', '\u{a6ff}'), ('\u{a7cb}', '\u{a7cf}'), ('\u{a7d2}', '\u{a7d2}'), ('\u{a7d4}', '\u{a7d4}'),
+    ('\u{a7da}', '\u{a7f1}'), ('\u{a82d}', '\u{a82f}'), ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'),
+    ('\u{a8c6}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'), ('\u{a954}', '\u{a95e}'), ('\u{a97d}', '\u{a97f}'),
+    ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'), ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'),
+    ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}', '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}', '\u{ab00}'),
+    ('\u{ab07}', '\u{ab08}'), ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'),
+    ('\u{ab2f}', '\u{ab2f}'), ('\u{ab6c}', '\u{ab6f}'), ('\u{abee}', '\u{abef}'), ('\u{abfa}', '\u{abff}'),
+    ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'), ('\u{e000}', '\u{f8ff}'),
+    ('\u{fa6e}', '\u{fa6f}'), ('\u{fada}', '\u{faff}'), ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'),
+    ('\u{fb37}', '\u{fb37}'), ('\u{fb3d}', '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'),
+    ('\u{fb45}', '\u{fb45}'), ('\u{fbc3}', '\u{fbd2}'), ('\u{fd90}', '\u{fd91}'), ('\u{fdc8}', '\u{fdce}'),
+    ('\u{fdd0}', '\u{fdef}'), ('\u{fe1a}', '\u{fe1f}'), ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'),
+    ('\u{fe6c}', '\u{fe6f}'), ('\u{fe75}', '\u{fe75}'), ('\u{fefd}', '\u{ff00}'), ('\u{ffbf}', '\u{ffc1}'),
+    ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'), ('\u{ffdd}', '\u{ffdf}'),
+    ('\u{ffe7}', '\u{ffe7}'), ('\u{ffef}', '\u{fffb}'), ('\u{fffe}', '\u{ffff}'), ('\u{1000c}', '\u{1000c}'),
+    ('\u{10027}', '\u{10027}'), ('\u{1003b}', '\u{1003b}'), ('\u{1003e}', '\u{1003e}'), ('\u{1004e}', '\u{1004f}'),
+    ('\u{1005e}', '\u{1007f}'), ('\u{100fb}', '\u{100ff}'), ('\u{10103}', '\u{10106}'), ('\u{10134}', '\u{10136}'),
+    ('\u{1018f}', '\u{1018f}'), ('\u{1019d}', '\u{1019f}'), ('\u{101a1}', '\u{101cf}'), ('\u{101fe}', '\u{1027f}'),
+    ('\u{1029d}', '\u{1029f}'), ('\u{102d1}', '\u{102df}'), ('\u{102fc}', '\u{102ff}'), ('\u{10324}', '\u{1032c}'),
+    ('\u{1034b}', '\u{1034f}'), ('\u{1037b}', '\u{1037f}'), ('\u{1039e}', '\u{1039e}'), ('\u{103c4}', '\u{103c7}'),
+    ('\u{103d6}', '\u{103ff}'), ('\u{1049e}', '\u{1049f}'), ('\u{104aa}', '\u{104af}'), ('\u{104d4}', '\u{104d7}'),
+    ('\u{104fc}', '\u{104ff}'), ('\u{10528}', '\u{1052f}'), ('\u{10564}', '\u{1056e}'), ('\u{1057b}', '\u{1057b}'),
+    ('\u{1058b}', '\u{1058b}'), ('\u{10593}', '\u{10593}'), ('\u{10596}', '\u{10596}'), ('\u{105a2}', '\u{105a2}'),
+    ('\u{105b2}', '\u{105b2}'), ('\u{105ba}', '\u{105ba}'), ('\u{105bd}', '\u{105ff}'), ('\u{10737}', '\u{1073f}'),
+    ('\u{10756}', '\u{1075f}'), ('\u{10768}', '\u{1077f}'), ('\u{10786}', '\u{10786}'), ('\u{107b1}', '\u{107b1}'),
+    ('\u{107bb}', '\u{107ff}'), ('\u{10806}', '\u{10807}'), ('\u{10809}', '\u{10809}'), ('\u{10836}', '\u{10836}'),
+    ('\u{10839}', '\u{1083b}'), ('\u{1083d}', '\u{1083e}'), ('\u{10856}', '\u{10856}'), ('\u{1089f}', '\u{108a6}'),
+    ('\u{108b0}', '\u{108df}'), ('\u{108f3}', '\u{108f3}'), ('\u{108f6}', '\u{108fa}'), ('\u{1091c}', '\u{1091e}'),
+    ('\u{1093a}', '\u{1093e}'), ('\u{10940}', '\u{1097f}'), ('\u{109b8}', '\u{109bb}'), ('\u{109d0}', '\u{109d1}'),
+    ('\u{10a04}', '\u{10a04}'), ('\u{10a07}', '\u{10a0b}'), ('\u{10a14}', '\u{10a14}'), ('\u{10a18}', '\u{10a18}'),
+    ('\u{10a36}', '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'), ('\u{10a49}', '\u{10a4f}'), ('\u{10a59}', '\u{10a5f}'),
+    ('\u{10aa0}', '\u{10abf}'), ('\u{10ae7}', '\u{10aea}'), ('\u{10af7}', '\u{10aff}'), ('\u{10b36}', '\u{10b38}'),
+    ('\u{10b56}', '\u{10b57}'), ('\u{10b73}', '\u{10b77}'), ('\u{10b92}', '\u{10b98}'), ('\u{10b9d}', '\u{10ba8}'),
+    ('\u{10bb0}', '\u{10bff}'), ('\u{10c49}', '\u{10c7f}'), ('\u{10cb3}', '\u{10cbf}'), ('\u{10cf3}', '\u{10cf9}'),
+    ('\u{10d28}', '\u{10d2f}'), ('\u{10d3a}', '\u{10e5f}'), ('\u{10e7f}', '\u{10e7f}'), ('\u{10eaa}', '\u{10eaa}'),
+    ('\u{10eae}', '\u{10eaf}'), ('\u{10eb2}', '\u{10eff}'), ('\u{10f28}', '\u{10f2f}'), ('\u{10f5a}', '\u{10f6f}'),
+    ('\u{10f8a}', '\u{10faf}'), ('\u{10fcc}', '\u{10fdf}'), ('\u{10ff7}', '\u{10fff}'), ('\u{1104e}', '\u{11051}'),
+    ('\u{11076}', '\u{1107e}'), ('\u{110bd}', '\u{110bd}'), ('\u{110c3}', '\u{110cf}'), ('\u{110e9}', '\u{110ef}'),
+    ('\u{110fa}', '\u{110ff}'), ('\u{11135}', '\u{11135}'), ('\u{11148}', '\u{1114f}'), ('\u{11177}', '\u{1117f}'),
+    ('\u{111e0}', '\u{111e0}'), ('\u{111f5}', '\u{111ff}'), ('\u{11212}', '\u{11212}'), ('\u{1123f}', '\u{1127f}'),
+    ('\u{11287}', '\u{11287}'), ('\u{11289}', '\u{11289}'), ('\u{1128e}', '\u{1128e}'), ('\u{1129e}', '\u{1129e}'),
+    ('\u{112aa}', '\u{112af}'), ('\u{112eb}', '\u{112ef}'), ('\u{112fa}', '\u{112ff}'), ('\u{11304}', '\u{11304}'),
+    ('\u{1130d}', '\u{1130e}'), ('\u{11311}', '\u{11312}'), ('\u{11329}', '\u{11329}'), ('\u{11331}', '\u{11331}'),
+    ('\u{11334}', '\u{11334}'), ('\u{1133a}', '\u{1133a}'), ('\u{11345}', '\u{11346}'), ('\u{11349}', '\u{1134a}'),
+    ('\u{1134e}', '\u{1134f}'), ('\u{11351}', '\u{11356}'), ('\u{11358}', '\u{1135c}'), ('\u{11364}', '\u{11365}'),
+    ('\u{1136d}', '\u{1136f}'), ('\u{11375}', '\u{113ff}'), ('\u{1145c}', '\u{1145c}'), ('\u{11462}', '\u{1147f}'),
+    ('\u{114c8}', '\u{114cf}'), ('\u{114da}', '\u{1157f}'), ('\u{115b6}', '\u{115b7}'), ('\u{115de}', '\u{115ff}'),
+    ('\u{11645}', '\u{1164f}'), ('\u{1165a}', '\u{1165f}'), ('\u{1166d}', '\u{1167f}'), ('\u{116ba}', '\u{116bf}'),
+    ('\u{116ca}', '\u{116ff}'), ('\u{1171b}', '\u{1171c}'), ('\u{1172c}', '\u{1172f}'), ('\u{11747}', '\u{117ff}'),
+    ('\u{1183c}', '\u{1189f}'), ('\u{118f3}', '\u{118fe}'), ('\u{11907}', '\u{11908}'), ('\u{1190a}', '\u{1190b}'),
+    ('\u{11914}', '\u{11914}'), ('\u{11917}', '\u{11917}'), ('\u{11936}', '\u{11936}'), ('\u{11939}', '\u{1193a}'),
+    ('\u{11947}', '\u{1194f}'), ('\u{1195a}', '\u{1199f}'), ('\u{119a8}', '\u{119a9}'), ('\u{119d8}', '\u{119d9}'),
+    ('\u{119e5}', '\u{119ff}'), ('\u{11a48}', '\u{11a4f}'), ('\u{11aa3}', '\u{11aaf}'), ('\u{11af9}', '\u{11bff}'),
+    ('\u{11c09}', '\u{11c09}'), ('\u{11c37}', '\u{11c37}'), ('\u{11c46}', '\u{11c4f}'), ('\u{11c6d}', '\u{11c6f}'),
+    ('\u{11c90}', '\u{11c91}'), ('\u{11ca8}', '\u{11ca8}'), ('\u{11cb7}', '\u{11cff}'), ('\u{11d07}', '\u{11d07}'),
+    ('\u{11d0a}', '\u{11d0a}'), ('\u{11d37}', '\u{11d39}'), ('\u{11d3b}', '\u{11d3b}'), ('\u{11d3e}', '\u{11d3e}'),
+    ('\u{11d48}', '\u{11d4f}'), ('\u{11d5a}', '\u{11d5f}'), ('\u{11d66}', '\u{11d66}'), ('\u{11d69}', '\u{11d69}'),
+    ('\u{11d8f}', '\u{11d8f}'), ('\u{11d92}', '\u{11d92}'), ('\u{11d99}', '\u{11d9f}'), ('\u{11daa}', '\u{11edf}'),
+    ('\u{11ef9}', '\u{11faf}'), ('\u{11fb1}', '\u{11fbf}'), ('\u{11ff2}', '\u{11ffe}'), ('\u{1239a}', '\u{123ff}'),
+    ('\u{1246f}', '\u{1246f}'), ('\u{12475}', '\u{1247f}'), ('\u{12544}', '\u{12f8f}'), ('\u{12ff3}', '\u{12fff}'),
+    ('\u{1342f}', '\u{143ff}'), ('\u{14647}', '\u{167ff}'), ('\u{16a39}', '\u{16a3f}'), ('\u{16a5f}', '\u{16a5f}'),
+    ('\u{16a6a}', '\u{16a6d}'), ('\u{16abf}', '\u{16abf}'), ('\u{16aca}', '\u{16acf}'), ('\u{16aee}', '\u{16aef}'),
+    ('\u{16af6}', '\u{16aff}'), ('\u{16b46}', '\u{16b4f}'), ('\u{16b5a}', '\u{16b5a}'), ('\u{16b62}', '\u{16b62}'),
+    ('\u{16b78}', '\u{16b7c}'), ('\u{16b90}', '\u{16e3f}'), ('\u{16e9b}', '\u{16eff}'), ('\u{16f4b}', '\u{16f4e}'),
+    ('\u{16f88}', '\u{16f8e}'), ('\u{16fa0}', '\u{16fdf}'), ('\u{16fe5}', '\u{16fef}'), ('\u{16ff2}', '\u{16fff}'),
+    ('\u{187f8}', '\u{187ff}'), ('\u{18cd6}', '\u{18cff}'), ('\u{18d09}', '\u{1afef}'), ('\u{1aff4}', '\u{1aff4}'),
+    ('\u{1affc}', '\u{1affc}'), ('\u{1afff}', '\u{1afff}'), ('\u{1b123}', '\u{1b14f}'), ('\u{1b153}', '\u{1b163}'),
+    ('\u{1b168}', '\u{1b16f}'), ('\u{1b2fc}', '\u{1bbff}'), ('\u{1bc6b}', '\u{1bc6f}'), ('\u{1bc7d}', '\u{1bc7f}'),
+    ('\u{1bc89}', '\u{1bc8f}'), ('\u{1bc9a}', '\u{1bc9b}'), ('\u{1bca0}', '\u{1ceff}'), ('\u{1cf2e}', '\u{1cf2f}'),
+    ('\u{1cf47}', '\u{1cf4f}'), ('\u{1cfc4}', '\u{1cfff}'), ('\u{1d0f6}', '\u{1d0ff}'), ('\u{1d127}', '\u{1d128}'),
+    ('\u{1d173}', '\u{1d17a}'), ('\u{1d1eb}', '\u{1d1ff}'), ('\u{1d246}', '\u{1d2df}'), ('\u{1d2f4}', '\u{1d2ff}'),
+    ('\u{1d357}', '\u{1d35f}'), ('\u{1d379}', '\u{1d3ff}'), ('\u{1d455}', '\u{1d455}'), ('\u{1d49d}', '\u{1d49d}'),
+    ('\u{1d4a0}', '\u{1d4a1}'), ('\u{1d4a3}', '\u{1d4a4}'), ('\u{1d4a7}', '\u{1d4a8}'), ('\u{1d4ad}', '\u{1d4ad}'),
+    ('\u{1d4ba}', '\u{1d4ba}'), ('\u{1d4bc}', '\u{1d4bc}'), ('\u{1d4c4}', '\u{1d4c4}'), ('\u{1d506}', '\u{1d506}'),
+    ('\u{1d50b}', '\u{1d50c}'), ('\u{1d515}', '\u{1d515}'), ('\u{1d51d}', '\u{1d51d}'), ('\u{1d53a}', '\u{1d53a}'),
+    ('\u{1d53f}', '\u{1d53f}'), ('\u{1d545}', '\u{1d545}'), ('\u{1d547}', '\u{1d549}'), ('\u{1d551}', '\u{1d551}'),
+    ('\u{1d6a6}', '\u{1d6a7}'), ('\u{1d7cc}', '\u{1d7cd}'), ('\u{1da8c}', '\u{1da9a}'), ('\u{1daa0}', '\u{1daa0}'),
+    ('\u{1dab0}', '\u{1deff}'), ('\u{1df1f}', '\u{1dfff}'), ('\u{1e007}', '\u{1e007}'), ('\u{1e019}', '\u{1e01a}'),
+    ('\u{1e022}', '\u{1e022}'), ('\u{1e025}', '\u{1e025}'), ('\u{1e02b}', '\u{1e0ff}'), ('\u{1e12d}', '\u{1e12f}'),
+    ('\u{1e13e}', '\u{1e13f}'), ('\u{1e14a}', '\u{1e14d}'), ('\u{1e150}', '\u{1e28f}'), ('\u{1e2af}', '\u{1e2bf}'),
+    ('\u{1e2fa}', '\u{1e2fe}'), ('\u{1e300}', '\u{1e7df}'), ('\u{1e7e7}', '\u{1e7e7}'), ('\u{1e7ec}', '\u{1e7ec}'),
+    ('\u{1e7ef}', '\u{1e7ef}'), ('\u{1e7ff}', '\u{1e7ff}'), ('\u{1e8c5}', '\u{1e8c6}'), ('\u{1e8d7}', '\u{1e8ff}'),
+    ('\u{1e94c}', '\u{1e94f}'), ('\u{1e95a}', '\u{1e95d}'), ('\u{1e960}', '\u{1ec70}'), ('\u{1ecb5}', '\u{1ed00}'),
+    ('\u{1ed3e}', '\u{1edff}'), ('\u{1ee04}', '\u{1ee04}'), ('\u{1ee20}', '\u{1ee20}'), ('\u{1ee23}', '\u{1ee23}'),
+    ('\u{1ee25}', '\u{1ee26}'), ('\u{1ee28}', '\u{1ee28}'), ('\u{1ee33}', '\u{1ee33}'), ('\u{1ee38}', '\u{1ee38}'),
+    ('\u{1ee3a}', '\u{1ee3a}'), ('\u{1ee3c}', '\u{1ee41}'), ('\u{1ee43}', '\u{1ee46}'), ('\u{1ee48}', '\u{1ee48}'),
+    ('\u{1ee4a}', '\u{1ee4a}'), ('\u{1ee4c}', '\u{1ee4c}'), ('\u{1ee50}', '\u{1ee50}'), ('\u{1ee53}', '\u{1ee53}'),
+    ('\u{1ee55}', '\u{1ee56}'), ('\u{1ee58}', '\u{1ee58}'), ('\u{1ee5a}', '\u{1ee5a}'), ('\u{1ee5c}', '\u{1ee5c}'),
+    ('\u{1ee5e}', '\u{1ee5e}'), ('\u{1ee60}', '\u{1ee60}'), ('\u{1ee63}', '\u{1ee63}'), ('\u{1ee65}', '\u{1ee66}'),
+    ('\u{1ee6b}', '\u{1ee6b}'), ('\u{1ee73}', '\u{1ee73}'), ('\u{1ee78}', '\u{1ee78}'), ('\u{1ee7d}', '\u{1ee7d}'),
+    ('\u{1ee7f}', '\u{1ee7f}'), ('\u{1ee8a}', '\u{1ee8a}'), ('\u{1ee9c}', '\u{1eea0}'), ('\u{1eea4}', '\u{1eea4}'),
+    ('\u{1eeaa}', '\u{1eeaa}'), ('\u{1eebc}', '\u{1eeef}'), ('\u{1eef2}', '\u{1efff}'), ('\u{1f02c}', '\u{1f02f}'),
+    ('\u{1f094}', '\u{1f09f}'), ('\u{1f0af}', '\u{1f0b0}'), ('\u{1f0c0}', '\u{1f0c0}'), ('\u{1f0d0}', '\u{1f0d0}'),
+    ('\u{1f0f6}', '\u{1f0ff}'), ('\u{1f1ae}', '\u{1f1e5}'), ('\u{1f203}', '\u{1f20f}'), ('\u{1f23c}', '\u{1f23f}'),
+    ('\u{1f249}', '\u{1f24f}'), ('\u{1f252}', '\u{1f25f}'), ('\u{1f266}', '\u{1f2ff}'), ('\u{1f6d8}', '\u{1f6dc}'),
+    ('\u{1f6ed}', '\u{1f6ef}'), ('\u{1f6fd}', '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d9}', '\u{1f7df}'),
+    ('\u{1f7ec}', '\u{1f7ef}'), ('\u{1f7f1}', '\u{1f7ff}'), ('\u{1f80c}', '\u{1f80f}'), ('\u{1f848}', '\u{1f84f}'),
+    ('\u{1f85a}', '\u{1f85f}'), ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1f8af}'), ('\u{1f8b2}', '\u{1f8ff}'),
+    ('\u{1fa54}', '\u{1fa5f}'), ('\u{1fa6e}', '\u{1fa6f}'), ('\u{1fa75}', '\u{1fa77}'), ('\u{1fa7d}', '\u{1fa7f}'),
+    ('\u{1fa87}', '\u{1fa8f}'), ('\u{1faad}', '\u{1faaf}'), ('\u{1fabb}', '\u{1fabf}'), ('\u{1fac6}', '\u{1facf}'),
+    ('\u{1fada}', '\u{1fadf}'), ('\u{1fae8}', '\u{1faef}'), ('\u{1faf7}', '\u{1faff}'), ('\u{1fb93}', '\u{1fb93}'),
+    ('\u{1fbcb}', '\u{1fbef}'), ('\u{1fbfa}', '\u{1ffff}'), ('\u{2a6e0}', '\u{2a6ff}'), ('\u{2b739}', '\u{2b73f}'),
+    ('\u{2b81e}', '\u{2b81f}'), ('\u{2cea2}', '\u{2ceaf}'), ('\u{2ebe1}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{2ffff}'),
+    ('\u{3134b}', '\u{e00ff}'), ('\u{e01f0}', '\u{10ffff}'),
+];
+
+pub const GC_CC: &[(char, char)] = &[
+    ('\u{0}', '\u{1f}'), ('\u{7f}', '\u{9f}'),
+];
+
+pub const GC_CF: &[(char, char)] = &[
+    ('\u{ad}', '\u{ad}'), ('\u{600}', '\u{605}'), ('\u{61c}', '\u{61c}'), ('\u{6dd}', '\u{6dd}'),
+    ('\u{70f}', '\u{70f}'), ('\u{890}', '\u{891}'), ('\u{8e2}', '\u{8e2}'), ('\u{180e}', '\u{180e}'),
+    ('\u{200b}', '\u{200f}'), ('\u{202a}', '\u{202e}'), ('\u{2060}', '\u{2064}'), ('\u{2066}', '\u{206f}'),
+    ('\u{feff}', '\u{feff}'), ('\u{fff9}', '\u{fffb}'), ('\u{110bd}', '\u{110bd}'), ('\u{110cd}', '\u{110cd}'),
+    ('\u{13430}', '\u{13438}'), ('\u{1bca0}', '\u{1bca3}'), ('\u{1d173}', '\u{1d17a}'), ('\u{e0001}', '\u{e0001}'),
+    ('\u{e0020}', '\u{e007f}'),
+];
+
+pub const GC_CN: &[(char, char)] = &[
+    ('\u{378}', '\u{379}'), ('\u{380}', '\u{383}'), ('\u{38b}', '\u{38b}'), ('\u{38d}', '\u{38d}'),
+    ('\u{3a2}', '\u{3a2}'), ('\u{530}', '\u{530}'), ('\u{557}', '\u{558}'), ('\u{58b}', '\u{58c}'),
+    ('\u{590}', '\u{590}'), ('\u{5c8}', '\u{5cf}'), ('\u{5eb}', '\u{5ee}'), ('\u{5f5}', '\u{5ff}'),
+    ('\u{70e}', '\u{70e}'), ('\u{74b}', '\u{74c}'), ('\u{7b2}', '\u{7bf}'), ('\u{7fb}', '\u{7fc}'),
+    ('\u{82e}', '\u{82f}'), ('\u{83f}', '\u{83f}'), ('\u{85c}', '\u{85d}'), ('\u{85f}', '\u{85f}'),
+    ('\u{86b}', '\u{86f}'), ('\u{88f}', '\u{88f}'), ('\u{892}', '\u{897}'), ('\u{984}', '\u{984}'),
+    ('\u{98d}', '\u{98e}'), ('\u{991}', '\u{992}'), ('\u{9a9}', '\u{9a9}'), ('\u{9b1}', '\u{9b1}'),
+    ('\u{9b3}', '\u{9b5}'), ('\u{9ba}', '\u{9bb}'), ('\u{9c5}', '\u{9c6}'), ('\u{9c9}', '\u{9ca}'),
+    ('\u{9cf}', '\u{9d6}'), ('\u{9d8}', '\u{9db}'), ('\u{9de}', '\u{9de}'), ('\u{9e4}', '\u{9e5}'),
+    ('\u{9ff}', '\u{a00}'), ('\u{a04}', '\u{a04}'), ('\u{a0b}', '\u{a0e}'), ('\u{a11}', '\u{a12}'),
+    ('\u{a29}', '\u{a29}'), ('\u{a31}', '\u{a31}'), ('\u{a34}', '\u{a34}'), ('\u{a37}', '\u{a37}'),
+    ('\u{a3a}', '\u{a3b}'), ('\u{a3d}', '\u{a3d}'), ('\u{a43}', '\u{a46}'), ('\u{a49}', '\u{a4a}'),
+    ('\u{a4e}', '\u{a50}'), ('\u{a52}', '\u{a58}'), ('\u{a5d}', '\u{a5d}'), ('\u{a5f}', '\u{a65}'),
+    ('\u{a77}', '\u{a80}'), ('\u{a84}', '\u{a84}'), ('\u{a8e}', '\u{a8e}'), ('\u{a92}', '\u{a92}'),
+    ('\u{aa9}', '\u{aa9}'), ('\u{ab1}', '\u{ab1}'), ('\u{ab4}', '\u{ab4}'), ('\u{aba}', '\u{abb}'),
+    ('\u{ac6}', '\u{ac6}'), ('\u{aca}', '\u{aca}'), ('\u{ace}', '\u{acf}'), ('\u{ad1}', '\u{adf}'),
+    ('\u{ae4}', '\u{ae5}'), ('\u{af2}', '\u{af8}'), ('\u{b00}', '\u{b00}'), ('\u{b04}', '\u{b04}'),
+    ('\u{b0d}', '\u{b0e}'), ('\u{b11}', '\u{b12}'), ('\u{b29}', '\u{b29}'), ('\u{b31}', '\u{b31}'),
+    ('\u{b34}', '\u{b34}'), ('\u{b3a}', '\u{b3b}'), ('\u{b45}', '\u{b46}'), ('\u{b49}', '\u{b4a}'),
+    ('\u{b4e}', '\u{b54}'), ('\u{b58}', '\u{b5b}'), ('\u{b5e}', '\u{b5e}'), ('\u{b64}', '\u{b65}'),
+    ('\u{b78}', '\u{b81}'), ('\u{b84}', '\u{b84}'), ('\u{b8b}', '\u{b8d}'), ('\u{b91}', '\u{b91}'),
+    ('\u{b96}', '\u{b98}'), ('\u{b9b}', '\u{b9b}'), ('\u{b9d}', '\u{b9d}'), ('\u{ba0}', '\u{ba2}'),
+    ('\u{ba5}', '\u{ba7}'), ('\u{bab}', '\u{bad}'), ('\u{bba}', '\u{bbd}'), ('\u{bc3}', '\u{bc5}'),
+    ('\u{bc9}', '\u{bc9}'), ('\u{bce}', '\u{bcf}'), ('\u{bd1}', '\u{bd6}'), ('\u{bd8}', '\u{be5}'),
+    ('\u{bfb}', '\u{bff}'), ('\u{c0d}', '\u{c0d}'), ('\u{c11}', '\u{c11}'), ('\u{c29}', '\u{c29}'),
+    ('\u{c3a}', '\u{c3b}'), ('\u{c45}', '\u{c45}'), ('\u{c49}', '\u{c49}'), ('\u{c4e}', '\u{c54}'),
+    ('\u{c57}', '\u{c57}'), ('\u{c5b}', '\u{c5c}'), ('\u{c5e}', '\u{c5f}'), ('\u{c64}', '\u{c65}'),
+    ('\u{c70}', '\u{c76}'), ('\u{c8d}', '\u{c8d}'), ('\u{c91}', '\u{c91}'), ('\u{ca9}', '\u{ca9}'),
+    ('\u{cb4}', '\u{cb4}'), ('\u{cba}', '\u{cbb}'), ('\u{cc5}', '\u{cc5}'), ('\u{cc9}', '\u{cc9}'),
+    ('\u{cce}', '\u{cd4}'), ('\u{cd7}', '\u{cdc}'), ('\u{cdf}', '\u{cdf}'), ('\u{ce4}', '\u{ce5}'),
+    ('\u{cf0}', '\u{cf0}'), ('\u{cf3}', '\u{cff}'), ('\u{d0d}', '\u{d0d}'), ('\u{d11}', '\u{d11}'),
+    ('\u{d45}', '\u{d45}'), ('\u{d49}', '\u{d49}'), ('\u{d50}', '\u{d53}'), ('\u{d64}', '\u{d65}'),
+    ('\u{d80}', '\u{d80}'), ('\u{d84}', '\u{d84}'), ('\u{d97}', '\u{d99}'), ('\u{db2}', '\u{db2}'),
+    ('\u{dbc}', '\u{dbc}'), ('\u{dbe}', '\u{dbf}'), ('\u{dc7}', '\u{dc9}'), ('\u{dcb}', '\u{dce}'),
+    ('\u{dd5}', '\u{dd5}'), ('\u{dd7}', '\u{dd7}'), ('\u{de0}', '\u{de5}'), ('\u{df0}', '\u{df1}'),
+    ('\u{df5}', '\u{e00}'), ('\u{e3b}', '\u{e3e}'), ('\u{e5c}', '\u{e80}'), ('\u{e83}', '\u{e83}'),
+    ('\u{e85}', '\u{e85}'), ('\u{e8b}', '\u{e8b}'), ('\u{ea4}', '\u{ea4}'), ('\u{ea6}', '\u{ea6}'),
+    ('\u{ebe}', '\u{ebf}'), ('\u{ec5}', '\u{ec5}'), ('\u{ec7}', '\u{ec7}'), ('\u{ece}', '\u{ecf}'),
+    ('\u{eda}', '\u{edb}'), ('\u{ee0}', '\u{eff}'), ('\u{f48}', '\u{f48}'), ('\u{f6d}', '\u{f70}'),
+    ('\u{f98}', '\u{f98}'), ('\u{fbd}', '\u{fbd}'), ('\u{fcd}', '\u{fcd}'), ('\u{fdb}', '\u{fff}'),
+    ('\u{10c6}', '\u{10c6}'), ('\u{10c8}', '\u{10cc}'), ('\u{10ce}', '\u{10cf}'), ('\u{1249}', '\u{1249}'),
+    ('\u{124e}', '\u{124f}'), ('\u{1257}', '\u{1257}'), ('\u{1259}', '\u{1259}'), ('\u{125e}', '\u{125f}'),
+    ('\u{1289}', '\u{1289}'), ('\u{128e}', '\u{128f}'), ('\u{12b1}', '\u{12b1}'), ('\u{12b6}', '\u{12b7}'),
+    ('\u{12bf}', '\u{12bf}'), ('\u{12c1}', '\u{12c1}'), ('\u{12c6}', '\u{12c7}'), ('\u{12d7}', '\u{12d7}'),
+    ('\u{1311}', '\u{1311}'), ('\u{1316}', '\u{1317}'), ('\u{135b}', '\u{135c}'), ('\u{137d}', '\u{137f}'),
+    ('\u{139a}', '\u{139f}'), ('\u{13f6}', '\u{13f7}'), ('\u{13fe}', '\u{13ff}'), ('\u{169d}', '\u{169f}'),
+    ('\u{16f9}', '\u{16ff}'), ('\u{1716}', '\u{171e}'), ('\u{1737}', '\u{173f}'), ('\u{1754}', '\u{175f}'),
+    ('\u{176d}', '\u{176d}'), ('\u{1771}', '\u{1771}'), ('\u{1774}', '\u{177f}'), ('\u{17de}', '\u{17df}'),
+    ('\u{17ea}', '\u{17ef}'), ('\u{17fa}', '\u{17ff}'), ('\u{181a}', '\u{181f}'), ('\u{1879}', '\u{187f}'),
+    ('\u{18ab}', '\u{18af}'), ('\u{18f6}', '\u{18ff}'), ('\u{191f}', '\u{191f}'), ('\u{192c}', '\u{192f}'),
+    ('\u{193c}', '\u{193f}'), ('\u{1941}', '\u{1943}'), ('\u{196e}', '\u{196f}'), ('\u{1975}', '\u{197f}'),
+    ('\u{19ac}', '\u{19af}'), ('\u{19ca}', '\u{19cf}'), ('\u{19db}', '\u{19dd}'), ('\u{1a1c}', '\u{1a1d}'),
+    ('\u{1a5f}', '\u{1a5f}'), ('\u{1a7d}', '\u{1a7e}'), ('\u{1a8a}', '\u{1a8f}'), ('\u{1a9a}', '\u{1a9f}'),
+    ('\u{1aae}', '\u{1aaf}'), ('\u{1acf}', '\u{1aff}'), ('\u{1b4d}', '\u{1b4f}'), ('\u{1b7f}', '\u{1b7f}'),
+    ('\u{1bf4}', '\u{1bfb}'), ('\u{1c38}', '\u{1c3a}'), ('\u{1c4a}', '\u{1c4c}'), ('\u{1c89}', '\u{1c8f}'),
+    ('\u{1cbb}', '\u{1cbc}'), ('\u{1cc8}', '\u{1ccf}'), ('\u{1cfb}', '\u{1cff}'), ('\u{1f16}', '\u{1f17}'),
+    ('\u{1f1e}', '\u{1f1f}'), ('\u{1f46}', '\u{1f47}'), ('\u{1f4e}', '\u{1f4f}'), ('\u{1f58}', '\u{1f58}'),
+    ('\u{1f5a}', '\u{1f5a}'), ('\u{1f5c}', '\u{1f5c}'), ('\u{1f5e}', '\u{1f5e}'), ('\u{1f7e}', '\u{1f7f}'),
+    ('\u{1fb5}', '\u{1fb5}'), ('\u{1fc5}', '\u{1fc5}'), ('\u{1fd4}', '\u{1fd5}'), ('\u{1fdc}', '\u{1fdc}'),
+    ('\u{1ff0}', '\u{1ff1}'), ('\u{1ff5}', '\u{1ff5}'), ('\u{1fff}', '\u{1fff}'), ('\u{2065}', '\u{2065}'),
+    ('\u{2072}', '\u{2073}'), ('\u{208f}', '\u{208f}'), ('\u{209d}', '\u{209f}'), ('\u{20c1}', '\u{20cf}'),
+    ('\u{20f1}', '\u{20ff}'), ('\u{218c}', '\u{218f}'), ('\u{2427}', '\u{243f}'), ('\u{244b}', '\u{245f}'),
+    ('\u{2b74}', '\u{2b75}'), ('\u{2b96}', '\u{2b96}'), ('\u{2cf4}', '\u{2cf8}'), ('\u{2d26}', '\u{2d26}'),
+    ('\u{2d28}', '\u{2d2c}'), ('\u{2d2e}', '\u{2d2f}'), ('\u{2d68}', '\u{2d6e}'), ('\u{2d71}', '\u{2d7e}'),
+    ('\u{2d97}', '\u{2d9f}'), ('\u{2da7}', '\u{2da7}'), ('\u{2daf}', '\u{2daf}'), ('\u{2db7}', '\u{2db7}'),
+    ('\u{2dbf}', '\u{2dbf}'), ('\u{2dc7}', '\u{2dc7}'), ('\u{2dcf}', '\u{2dcf}'), ('\u{2dd7}', '\u{2dd7}'),
+    ('\u{2ddf}', '\u{2ddf}'), ('\u{2e5e}', '\u{2e7f}'), ('\u{2e9a}', '\u{2e9a}'), ('\u{2ef4}', '\u{2eff}'),
+    ('\u{2fd6}', '\u{2fef}'), ('\u{2ffc}', '\u{2fff}'), ('\u{3040}', '\u{3040}'), ('\u{3097}', '\u{3098}'),
+    ('\u{3100}', '\u{3104}'), ('\u{3130}', '\u{3130}'), ('\u{318f}', '\u{318f}'), ('\u{31e4}', '\u{31ef}'),
+    ('\u{321f}', '\u{321f}'), ('\u{a48d}', '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}', '\u{a63f}'),
+    ('\u{a6f8}', '\u{a6ff}'), ('\u{a7cb}', '\u{a7cf}'), ('\u{a7d2}', '\u{a7d2}'), ('\u{a7d4}', '\u{a7d4}'),
+    ('\u{a7da}', '\u{a7f1}'), ('\u{a82d}', '\u{a82f}'), ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'),
+    ('\u{a8c6}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'), ('\u{a954}', '\u{a95e}'), ('\u{a97d}', '\u{a97f}'),
+    ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'), ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'),
+    ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}', '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}', '\u{ab00}'),
+    ('\u{ab07}', '\u{ab08}'), ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'),
+    ('\u{ab2f}', '\u{ab2f}'), ('\u{ab6c}', '\u{ab6f}'), ('\u{abee}', '\u{abef}'), ('\u{abfa}', '\u{abff}'),
+    ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'), ('\u{fa6e}', '\u{fa6f}'),
+    ('\u{fada}', '\u{faff}'), ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'), ('\u{fb37}', '\u{fb37}'),
+    ('\u{fb3d}', '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'), ('\u{fb45}', '\u{fb45}'),
+    ('\u{fbc3}', '\u{fbd2}'), ('\u{fd90}', '\u{fd91}'), ('\u{fdc8}', '\u{fdce}'), ('\u{fdd0}', '\u{fdef}'),
+    ('\u{fe1a}', '\u{fe1f}'), ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'), ('\u{fe6c}', '\u{fe6f}'),
+    ('\u{fe75}', '\u{fe75}'), ('\u{fefd}', '\u{fefe}'), ('\u{ff00}', '\u{ff00}'), ('\u{ffbf}', '\u{ffc1}'),
+    ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'), ('\u{ffdd}', '\u{ffdf}'),
+    ('\u{ffe7}', '\u{ffe7}'), ('\u{ffef}', '\u{fff8}'), ('\u{fffe}', '\u{ffff}'), ('\u{1000c}', '\u{1000c}'),
+    ('\u{10027}', '\u{10027}'), ('\u{1003b}', '\u{1003b}'), ('\u{1003e}', '\u{1003e}'), ('\u{1004e}', '\u{1004f}'),
+    ('\u{1005e}', '\u{1007f}'), ('\u{100fb}', '\u{100ff}'), ('\u{10103}', '\u{10106}'), ('\u{10134}', '\u{10136}'),
+    ('\u{1018f}', '\u{1018f}'), ('\u{1019d}', '\u{1019f}'), ('\u{101a1}', '\u{101cf}'), ('\u{101fe}', '\u{1027f}'),
+    ('\u{1029d}', '\u{1029f}'), ('\u{102d1}', '\u{102df}'), ('\u{102fc}', '\u{102ff}'), ('\u{10324}', '\u{1032c}'),
+    ('\u{1034b}', '\u{1034f}'), ('\u{1037b}', '\u{1037f}'), ('\u{1039e}', '\u{1039e}'), ('\u{103c4}', '\u{103c7}'),
+    ('\u{103d6}', '\u{103ff}'), ('\u{1049e}', '\u{1049f}'), ('\u{104aa}', '\u{104af}'), ('\u{104d4}', '\u{104d7}'),
+    ('\u{104fc}', '\u{104ff}'), ('\u{10528}', '\u{1052f}'), ('\u{10564}', '\u{1056e}'), ('\u{1057b}', '\u{1057b}'),
+    ('\u{1058b}', '\u{1058b}'), ('\u{10593}', '\u{10593}'), ('\u{10596}', '\u{10596}'), ('\u{105a2}', '\u{105a2}'),
+    ('\u{105b2}', '\u{105b2}'), ('\u{105ba}', '\u{105ba}'), ('\u{105bd}', '\u{105ff}'), ('\u{10737}', '\u{1073f}'),
+    ('\u{10756}', '\u{1075f}'), ('\u{10768}', '\u{1077f}'), ('\u{10786}', '\u{10786}'), ('\u{107b1}', '\u{107b1}'),
+    ('\u{107bb}', '\u{107ff}'), ('\u{10806}', '\u{10807}'), ('\u{10809}', '\u{10809}'), ('\u{10836}', '\u{10836}'),
+    ('\u{10839}', '\u{1083b}'), ('\u{1083d}', '\u{1083e}'), ('\u{10856}', '\u{10856}'), ('\u{1089f}', '\u{108a6}'),
+    ('\u{108b0}', '\u{108df}'), ('\u{108f3}', '\u{108f3}'), ('\u{108f6}', '\u{108fa}'), ('\u{1091c}', '\u{1091e}'),
+    ('\u{1093a}', '\u{1093e}'), ('\u{10940}', '\u{1097f}'), ('\u{109b8}', '\u{109bb}'), ('\u{109d0}', '\u{109d1}'),
+    ('\u{10a04}', '\u{10a04}'), ('\u{10a07}', '\u{10a0b}'), ('\u{10a14}', '\u{10a14}'), ('\u{10a18}', '\u{10a18}'),
+    ('\u{10a36}', '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'), ('\u{10a49}', '\u{10a4f}'), ('\u{10a59}', '\u{10a5f}'),
+    ('\u{10aa0}', '\u{10abf}'), ('\u{10ae7}', '\u{10aea}'), ('\u{10af7}', '\u{10aff}'), ('\u{10b36}', '\u{10b38}'),
+    ('\u{10b56}', '\u{10b57}'), ('\u{10b73}', '\u{10b77}'), ('\u{10b92}', '\u{10b98}'), ('\u{10b9d}', '\u{10ba8}'),
+    ('\u{10bb0}', '\u{10bff}'), ('\u{10c49}', '\u{10c7f}'), ('\u{10cb3}', '\u{10cbf}'), ('\u{10cf3}', '\u{10cf9}'),
+    ('\u{10d28}', '\u{10d2f}'), ('\u{10d3a}', '\u{10e5f}'), ('\u{10e7f}', '\u{10e7f}'), ('\u{10eaa}', '\u{10eaa}'),
+    ('\u{10eae}', '\u{10eaf}'), ('\u{10eb2}', '\u{10eff}'), ('\u{10f28}', '\u{10f2f}'), ('\u{10f5a}', '\u{10f6f}'),
+    ('\u{10f8a}', '\u{10faf}'), ('\u{10fcc}', '\u{10fdf}'), ('\u{10ff7}', '\u{10fff}'), ('\u{1104e}', '\u{11051}'),
+    ('\u{11076}', '\u{1107e}'), ('\u{110c3}', '\u{110cc}'), ('\u{110ce}', '\u{110cf}'), ('\u{110e9}', '\u{110ef}'),
+    ('\u{110fa}', '\u{110ff}'), ('\u{11135}', '\u{11135}'), ('\u{11148}', '\u{1114f}'), ('\u{11177}', '\u{1117f}'),
+    ('\u{111e0}', '\u{111e0}'), ('\u{111f5}', '\u{111ff}'), ('\u{11212}', '\u{11212}'), ('\u{1123f}', '\u{1127f}'),
+    ('\u{11287}', '\u{11287}'), ('\u{11289}', '\u{11289}'), ('\u{1128e}', '\u{1128e}'), ('\u{1129e}', '\u{1129e}'),
+    ('\u{112aa}', '\u{112af}'), ('\u{112eb}', '\u{112ef}'), ('\u{112fa}', '\u{112ff}'), ('\u{11304}', '\u{11304}'),
+    ('\u{1130d}', '\u{1130e}'), ('\u{11311}', '\u{11312}'), ('\u{11329}', '\u{11329}'), ('\u{11331}', '\u{11331}'),
+    ('\u{11334}', '\u{11334}'), ('\u{1133a}', '\u{1133a}'), ('\u{11345}', '\u{11346}'), ('\u{11349}', '\u{1134a}'),
+    ('\u{1134e}', '\u{1134f}'), ('\u{11351}', '\u{11356}'), ('\u{11358}', '\u{1135c}'), ('\u{11364}', '\u{11365}'),
+    ('\u{1136d}', '\u{1136f}'), ('\u{11375}', '\u{113ff}'), ('\u{1145c}', '\u{1145c}'), ('\u{11462}', '\u{1147f}'),
+    ('\u{114c8}', '\u{114cf}'), ('\u{114da}', '\u{1157f}'), ('\u{115b6}', '\u{115b7}'), ('\u{115de}', '\u{115ff}'),
+    ('\u{11645}', '\u{1164f}'), ('\u{1165a}', '\u{1165f}'), ('\u{1166d}', '\u{1167f}'), ('\u{116ba}', '\u{116bf}'),
+    ('\u{116ca}', '\u{116ff}'), ('\u{1171b}', '\u{1171c}'), ('\u{1172c}', '\u{1172f}'), ('\u{11747}', '\u{117ff}'),
+    ('\u{1183c}', '\u{1189f}'), ('\u{118f3}', '\u{118fe}'), ('\u{11907}', '\u{11908}'), ('\u{1190a}', '\u{1190b}'),
+    ('\u{11914}', '\u{11914}'), ('\u{11917}', '\u{11917}'), ('\u{11936}', '\u{11936}'), ('\u{11939}', '\u{1193a}'),
+    ('\u{11947}', '\u{1194f}'), ('\u{1195a}', '\u{1199f}'), ('\u{119a8}', '\u{119a9}'), ('\u{119d8}', '\u{119d9}'),
+    ('\u{119e5}', '\u{119ff}'), ('\u{11a48}', '\u{11a4f}'), ('\u{11aa3}', '\u{11aaf}'), ('\u{11af9}', '\u{11bff}'),
+    ('\u{11c09}', '\u{11c09}'), ('\u{11c37}', '\u{11c37}'), ('\u{11c46}', '\u{11c4f}'), ('\u{11c6d}', '\u{11c6f}'),
+    ('\u{11c90}', '\u{11c91}'), ('\u{11ca8}', '\u{11ca8}'), ('\u{11cb7}', '\u{11cff}'), ('\u{11d07}', '\u{11d07}'),
+    ('\u{11d0a}', '\u{11d0a}'), ('\u{11d37}', '\u{11d39}'), ('\u{11d3b}', '\u{11d3b}'), ('\u{11d3e}', '\u{11d3e}'),
+    ('\u{11d48}', '\u{11d4f}'), ('\u{11d5a}', '\u{11d5f}'), ('\u{11d66}', '\u{11d66}'), ('\u{11d69}', '\u{11d69}'),
+    ('\u{11d8f}', '\u{11d8f}'), ('\u{11d92}', '\u{11d92}'), ('\u{11d99}', '\u{11d9f}'), ('\u{11daa}', '\u{11edf}'),
+    ('\u{11ef9}', '\u{11faf}'), ('\u{11fb1}', '\u{11fbf}'), ('\u{11ff2}', '\u{11ffe}'), ('\u{1239a}', '\u{123ff}'),
+    ('\u{1246f}', '\u{1246f}'), ('\u{12475}', '\u{1247f}'), ('\u{12544}', '\u{12f8f}'), ('\u{12ff3}', '\u{12fff}'),
+    ('\u{1342f}', '\u{1342f}'), ('\u{13439}', '\u{143ff}'), ('\u{14647}', '\u{167ff}'), ('\u{16a39}', '\u{16a3f}'),
+    ('\u{16a5f}', '\u{16a5f}'), ('\u{16a6a}', '\u{16a6d}'), ('\u{16abf}', '\u{16abf}'), ('\u{16aca}', '\u{16acf}'),
+    ('\u{16aee}', '\u{16aef}'), ('\u{16af6}', '\u{16aff}'), ('\u{16b46}', '\u{16b4f}'), ('\u{16b5a}', '\u{16b5a}'),
+    ('\u{16b62}', '\u{16b62}'), ('\u{16b78}', '\u{16b7c}'), ('\u{16b90}', '\u{16e3f}'), ('\u{16e9b}', '\u{16eff}'),
+    ('\u{16f4b}', '\u{16f4e}'), ('\u{16f88}', '\u{16f8e}'), ('\u{16fa0}', '\u{16fdf}'), ('\u{16fe5}', '\u{16fef}'),
+    ('\u{16ff2}', '\u{16fff}'), ('\u{187f8}', '\u{187ff}'), ('\u{18cd6}', '\u{18cff}'), ('\u{18d09}', '\u{1afef}'),
+    ('\u{1aff4}', '\u{1aff4}'), ('\u{1affc}', '\u{1affc}'), ('\u{1afff}', '\u{1afff}'), ('\u{1b123}', '\u{1b14f}'),
+    ('\u{1b153}', '\u{1b163}'), ('\u{1b168}', '\u{1b16f}'), ('\u{1b2fc}', '\u{1bbff}'), ('\u{1bc6b}', '\u{1bc6f}'),
+    ('\u{1bc7d}', '\u{1bc7f}'), ('\u{1bc89}', '\u{1bc8f}'), ('\u{1bc9a}', '\u{1bc9b}'), ('\u{1bca4}', '\u{1ceff}'),
+    ('\u{1cf2e}', '\u{1cf2f}'), ('\u{1cf47}', '\u{1cf4f}'), ('\u{1cfc4}', '\u{1cfff}'), ('\u{1d0f6}', '\u{1d0ff}'),
+    ('\u{1d127}', '\u{1d128}'), ('\u{1d1eb}', '\u{1d1ff}'), ('\u{1d246}', '\u{1d2df}'), ('\u{1d2f4}', '\u{1d2ff}'),
+    ('\u{1d357}', '\u{1d35f}'), ('\u{1d379}', '\u{1d3ff}'), ('\u{1d455}', '\u{1d455}'), ('\u{1d49d}', '\u{1d49d}'),
+    ('\u{1d4a0}', '\u{1d4a1}'), ('\u{1d4a3}', '\u{1d4a4}'), ('\u{1d4a7}', '\u{1d4a8}'), ('\u{1d4ad}', '\u{1d4ad}'),
+    ('\u{1d4ba}', '\u{1d4ba}'), ('\u{1d4bc}', '\u{1d4bc}'), ('\u{1d4c4}', '\u{1d4c4}'), ('\u{1d506}', '\u{1d506}'),
+    ('\u{1d50b}', '\u{1d50c}'), ('\u{1d515}', '\u{1d515}'), ('\u{1d51d}', '\u{1d51d}'), ('\u{1d53a}', '\u{1d53a}'),
+    ('\u{1d53f}', '\u{1d53f}'), ('\u{1d545}', '\u{1d545}'), ('\u{1d547}', '\u{1d549}'), ('\u{1d551}', '\u{1d551}'),
+    ('\u{1d6a6}', '\u{1d6a7}'), ('\u{1d7cc}', '\u{1d7cd}'), ('\u{1da8c}', '\u{1da9a}'), ('\u{1daa0}', '\u{1daa0}'),
+    ('\u{1dab0}', '\u{1deff}'), ('\u{1df1f}', '\u{1dfff}'), ('\u{1e007}', '\u{1e007}'), ('\u{1e019}', '\u{1e01a}'),
+    ('\u{1e022}', '\u{1e022}'), ('\u{1e025}', '\u{1e025}'), ('\u{1e02b}', '\u{1e0ff}'), ('\u{1e12d}', '\u{1e12f}'),
+    ('\u{1e13e}', '\u{1e13f}'), ('\u{1e14a}', '\u{1e14d}'), ('\u{1e150}', '\u{1e28f}'), ('\u{1e2af}', '\u{1e2bf}'),
+    ('\u{1e2fa}', '\u{1e2fe}'), ('\u{1e300}', '\u{1e7df}'), ('\u{1e7e7}', '\u{1e7e7}'), ('\u{1e7ec}', '\u{1e7ec}'),
+    ('\u{1e7ef}', '\u{1e7ef}'), ('\u{1e7ff}', '\u{1e7ff}'), ('\u{1e8c5}', '\u{1e8c6}'), ('\u{1e8d7}', '\u{1e8ff}'),
+    ('\u{1e94c}', '\u{1e94f}'), ('\u{1e95a}', '\u{1e95d}'), ('\u{1e960}', '\u{1ec70}'), ('\u{1ecb5}', '\u{1ed00}'),
+    ('\u{1ed3e}', '\u{1edff}'), ('\u{1ee04}', '\u{1ee04}'), ('\u{1ee20}', '\u{1ee20}'), ('\u{1ee23}', '\u{1ee23}'),
+    ('\u{1ee25}', '\u{1ee26}'), ('\u{1ee28}', '\u{1ee28}'), ('\u{1ee33}', '\u{1ee33}'), ('\u{1ee38}', '\u{1ee38}'),
+    ('\u{1ee3a}', '\u{1ee3a}'), ('\u{1ee3c}', '\u{1ee41}'), ('\u{1ee43}', '\u{1ee46}'), ('\u{1ee48}', '\u{1ee48}'),
+    ('\u{1ee4a}', '\u{1ee4a}'), ('\u{1ee4c}', '\u{1ee4c}'), ('\u{1ee50}', '\u{1ee50}'), ('\u{1ee53}', '\u{1ee53}'),
+    ('\u{1ee55}', '\u{1ee56}'), ('\u{1ee58}', '\u{1ee58}'), ('\u{1ee5a}', '\u{1ee5a}'), ('\u{1ee5c}', '\u{1ee5c}'),
+    ('\u{1ee5e}', '\u{1ee5e}'), ('\u{1ee60}', '\u{1ee60}'), ('\u{1ee63}', '\u{1ee63}'), ('\u{1ee65}', '\u{1ee66}'),
+    ('\u{1ee6b}', '\u{1ee6b}'), ('\u{1ee73}', '\u{1ee73}'), ('\u{1ee78}', '\u{1ee78}'), ('\u{1ee7d}', '\u{1ee7d}'),
+    ('\u{1ee7f}', '\u{1ee7f}'), ('\u{1ee8a}', '\u{1ee8a}'), ('\u{1ee9c}', '\u{1eea0}'), ('\u{1eea4}', '\u{1eea4}'),
+    ('\u{1eeaa}', '\u{1eeaa}'), ('\u{1eebc}', '\u{1eeef}'), ('\u{1eef2}', '\u{1efff}'), ('\u{1f02c}', '\u{1f02f}'),
+    ('\u{1f094}', '\u{1f09f}'), ('\u{1f0af}', '\u{1f0b0}'), ('\u{1f0c0}', '\u{1f0c0}'), ('\u{1f0d0}', '\u{1f0d0}'),
+    ('\u{1f0f6}', '\u{1f0ff}'), ('\u{1f1ae}', '\u{1f1e5}'), ('\u{1f203}', '\u{1f20f}'), ('\u{1f23c}', '\u{1f23f}'),
+    ('\u{1f249}', '\u{1f24f}'), ('\u{1f252}', '\u{1f25f}'), ('\u{1f266}', '\u{1f2ff}'), ('\u{1f6d8}', '\u{1f6dc}'),
+    ('\u{1f6ed}', '\u{1f6ef}'), ('\u{1f6fd}', '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d9}', '\u{1f7df}'),
+    ('\u{1f7ec}', '\u{1f7ef}'), ('\u{1f7f1}', '\u{1f7ff}'), ('\u{1f80c}', '\u{1f80f}'), ('\u{1f848}', '\u{1f84f}'),
+    ('\u{1f85a}', '\u{1f85f}'), ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1f8af}'), ('\u{1f8b2}', '\u{1f8ff}'),
+    ('\u{1fa54}', '\u{1fa5f}'), ('\u{1fa6e}', '\u{1fa6f}'), ('\u{1fa75}', '\u{1fa77}'), ('\u{1fa7d}', '\u{1fa7f}'),
+    ('\u{1fa87}', '\u{1fa8f}'), ('\u{1faad}', '\u{1faaf}'), ('\u{1fabb}', '\u{1fabf}'), ('\u{1fac6}', '\u{1facf}'),
+    ('\u{1fada}', '\u{1fadf}'), ('\u{1fae8}', '\u{1faef}'), ('\u{1faf7}', '\u{1faff}'), ('\u{1fb93}', '\u{1fb93}'),
+    ('\u{1fbcb}', '\u{1fbef}'), ('\u{1fbfa}', '\u{1ffff}'), ('\u{2a6e0}', '\u{2a6ff}'), ('\u{2b739}', '\u{2b73f}'),
+    ('\u{2b81e}', '\u{2b81f}'), ('\u{2cea2}', '\u{2ceaf}'), ('\u{2ebe1}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{2ffff}'),
+    ('\u{3134b}', '\u{e0000}'), ('\u{e0002}', '\u{e001f}'), ('\u{e0080}', '\u{e00ff}'), ('\u{e01f0}', '\u{effff}'),
+    ('\u{ffffe}', '\u{fffff}'), ('\u{10fffe}', '\u{10ffff}'),
+];
+
+pub const GC_CO: &[(char, char)] = &[
+    ('\u{e000}', '\u{f8ff}'), ('\u{f0000}', '\u{ffffd}'), ('\u{100000}', '\u{10fffd}'),
+];
+
+pub const GC_CS: &[(char, char)] = &[
+];
+
+pub const GC_L: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{61}', '\u{7a}'), ('\u{aa}', '\u{aa}'), ('\u{b5}', '\u{b5}'),
+    ('\u{ba}', '\u{ba}'), ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'), ('\u{2e0}', '\u{2e4}'), ('\u{2ec}', '\u{2ec}'), ('\u{2ee}', '\u{2ee}'),
+    ('\u{370}', '\u{374}'), ('\u{376}', '\u{377}'), ('\u{37a}', '\u{37d}'), ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'), ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3f5}'), ('\u{3f7}', '\u{481}'), ('\u{48a}', '\u{52f}'), ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'), ('\u{560}', '\u{588}'), ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f2}'),
+    ('\u{620}', '\u{64a}'), ('\u{66e}', '\u{66f}'), ('\u{671}', '\u{6d3}'), ('\u{6d5}', '\u{6d5}'),
+    ('\u{6e5}', '\u{6e6}'), ('\u{6ee}', '\u{6ef}'), ('\u{6fa}', '\u{6fc}'), ('\u{6ff}', '\u{6ff}'),
+    ('\u{710}', '\u{710}'), ('\u{712}', '\u{72f}'), ('\u{74d}', '\u{7a5}'), ('\u{7b1}', '\u{7b1}'),
+    ('\u{7ca}', '\u{7ea}'), ('\u{7f4}', '\u{7f5}'), ('\u{7fa}', '\u{7fa}'), ('\u{800}', '\u{815}'),
+    ('\u{81a}', '\u{81a}'), ('\u{824}', '\u{824}'), ('\u{828}', '\u{828}'), ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86a}'), ('\u{870}', '\u{887}'), ('\u{889}', '\u{88e}'), ('\u{8a0}', '\u{8c9}'),
+    ('\u{904}', '\u{939}'), ('\u{93d}', '\u{93d}'), ('\u{950}', '\u{950}'), ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'), ('\u{985}', '\u{98c}'), ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'), ('\u{9b6}', '\u{9b9}'), ('\u{9bd}', '\u{9bd}'),
+    ('\u{9ce}', '\u{9ce}'), ('\u{9dc}', '\u{9dd}'), ('\u{9df}', '\u{9e1}'), ('\u{9f0}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'), ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'), ('\u{a35}', '\u{a36}'), ('\u{a38}', '\u{a39}'),
+    ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'), ('\u{a72}', '\u{a74}'), ('\u{a85}', '\u{a8d}'),
+    ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'), ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'),
+    ('\u{ab5}', '\u{ab9}'), ('\u{abd}', '\u{abd}'), ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae1}'),
+    ('\u{af9}', '\u{af9}'), ('\u{b05}', '\u{b0c}'), ('\u{b0f}', '\u{b10}'), ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'), ('\u{b35}', '\u{b39}'), ('\u{b3d}', '\u{b3d}'),
+    ('\u{b5c}', '\u{b5d}'), ('\u{b5f}', '\u{b61}'), ('\u{b71}', '\u{b71}'), ('\u{b83}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'), ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'), ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'), ('\u{bd0}', '\u{bd0}'), ('\u{c05}', '\u{c0c}'), ('\u{c0e}', '\u{c10}'),
+    ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c39}'), ('\u{c3d}', '\u{c3d}'), ('\u{c58}', '\u{c5a}'),
+    ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c61}'), ('\u{c80}', '\u{c80}'), ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'), ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb3}'), ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbd}', '\u{cbd}'), ('\u{cdd}', '\u{cde}'), ('\u{ce0}', '\u{ce1}'), ('\u{cf1}', '\u{cf2}'),
+    ('\u{d04}', '\u{d0c}'), ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d3a}'), ('\u{d3d}', '\u{d3d}'),
+    ('\u{d4e}', '\u{d4e}'), ('\u{d54}', '\u{d56}'), ('\u{d5f}', '\u{d61}'), ('\u{d7a}', '\u{d7f}'),
+    ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'), ('\u{db3}', '\u{dbb}'), ('\u{dbd}', '\u{dbd}'),
+    ('\u{dc0}', '\u{dc6}'), ('\u{e01}', '\u{e30}'), ('\u{e32}', '\u{e33}'), ('\u{e40}', '\u{e46}'),
+    ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'), ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'), ('\u{ea7}', '\u{eb0}'), ('\u{eb2}', '\u{eb3}'), ('\u{ebd}', '\u{ebd}'),
+    ('\u{ec0}', '\u{ec4}'), ('\u{ec6}', '\u{ec6}'), ('\u{edc}', '\u{edf}'), ('\u{f00}', '\u{f00}'),
+    ('\u{f40}', '\u{f47}'), ('\u{f49}', '\u{f6c}'), ('\u{f88}', '\u{f8c}'), ('\u{1000}', '\u{102a}'),
+    ('\u{103f}', '\u{103f}'), ('\u{1050}', '\u{1055}'), ('\u{105a}', '\u{105d}'), ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'), ('\u{106e}', '\u{1070}'), ('\u{1075}', '\u{1081}'), ('\u{108e}', '\u{108e}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'), ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'), ('\u{13a0}', '\u{13f5}'), ('\u{13f8}', '\u{13fd}'), ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'), ('\u{1681}', '\u{169a}'), ('\u{16a0}', '\u{16ea}'), ('\u{16f1}', '\u{16f8}'),
+    ('\u{1700}', '\u{1711}'), ('\u{171f}', '\u{1731}'), ('\u{1740}', '\u{1751}'), ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'), ('\u{1780}', '\u{17b3}'), ('\u{17d7}', '\u{17d7}'), ('\u{17dc}', '\u{17dc}'),
+    ('\u{1820}', '\u{1878}'), ('\u{1880}', '\u{1884}'), ('\u{1887}', '\u{18a8}'), ('\u{18aa}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'), ('\u{1900}', '\u{191e}'), ('\u{1950}', '\u{196d}'), ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'), ('\u{19b0}', '\u{19c9}'), ('\u{1a00}', '\u{1a16}'), ('\u{1a20}', '\u{1a54}'),
+    ('\u{1aa7}', '\u{1aa7}'), ('\u{1b05}', '\u{1b33}'), ('\u{1b45}', '\u{1b4c}'), ('\u{1b83}', '\u{1ba0}'),
+    ('\u{1bae}', '\u{1baf}'), ('\u{1bba}', '\u{1be5}'), ('\u{1c00}', '\u{1c23}'), ('\u{1c4d}', '\u{1c4f}'),
+    ('\u{1c5a}', '\u{1c7d}'), ('\u{1c80}', '\u{1c88}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1ce9}', '\u{1cec}'), ('\u{1cee}', '\u{1cf3}'), ('\u{1cf5}', '\u{1cf6}'), ('\u{1cfa}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1dbf}'), ('\u{1e00}', '\u{1f15}'), ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'), ('\u{1f50}', '\u{1f57}'), ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f7d}'), ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fcc}'), ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'), ('\u{1fe0}', '\u{1fec}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{2071}', '\u{2071}'), ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'), ('\u{210a}', '\u{2113}'), ('\u{2115}', '\u{2115}'), ('\u{2119}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'), ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'),
+    ('\u{212f}', '\u{2139}'), ('\u{213c}', '\u{213f}'), ('\u{2145}', '\u{2149}'), ('\u{214e}', '\u{214e}'),
+    ('\u{2183}', '\u{2184}'), ('\u{2c00}', '\u{2ce4}'), ('\u{2ceb}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'), ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'), ('\u{2d80}', '\u{2d96}'), ('\u{2da0}', '\u{2da6}'), ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'), ('\u{2dc0}', '\u{2dc6}'), ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{2e2f}', '\u{2e2f}'), ('\u{3005}', '\u{3006}'),
+    ('\u{3031}', '\u{3035}'), ('\u{303b}', '\u{303c}'), ('\u{3041}', '\u{3096}'), ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30fa}'), ('\u{30fc}', '\u{30ff}'), ('\u{3105}', '\u{312f}'), ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'), ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{4dbf}'), ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'), ('\u{a500}', '\u{a60c}'), ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66e}'), ('\u{a67f}', '\u{a69d}'), ('\u{a6a0}', '\u{a6e5}'), ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'), ('\u{a78b}', '\u{a7ca}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'), ('\u{a7f2}', '\u{a801}'), ('\u{a803}', '\u{a805}'), ('\u{a807}', '\u{a80a}'),
+    ('\u{a80c}', '\u{a822}'), ('\u{a840}', '\u{a873}'), ('\u{a882}', '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'), ('\u{a8fd}', '\u{a8fe}'), ('\u{a90a}', '\u{a925}'), ('\u{a930}', '\u{a946}'),
+    ('\u{a960}', '\u{a97c}'), ('\u{a984}', '\u{a9b2}'), ('\u{a9cf}', '\u{a9cf}'), ('\u{a9e0}', '\u{a9e4}'),
+    ('\u{a9e6}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}', '\u{aa28}'), ('\u{aa40}', '\u{aa42}'),
+    ('\u{aa44}', '\u{aa4b}'), ('\u{aa60}', '\u{aa76}'), ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'),
+    ('\u{aab1}', '\u{aab1}'), ('\u{aab5}', '\u{aab6}'), ('\u{aab9}', '\u{aabd}'), ('\u{aac0}', '\u{aac0}'),
+    ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadd}'), ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf4}'),
+    ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'), ('\u{ab5c}', '\u{ab69}'), ('\u{ab70}', '\u{abe2}'),
+    ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'),
+    ('\u{fe76}', '\u{fefc}'), ('\u{ff21}', '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{ff66}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'), ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'), ('\u{10300}', '\u{1031f}'), ('\u{1032d}', '\u{10340}'), ('\u{10342}', '\u{10349}'),
+    ('\u{10350}', '\u{10375}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'), ('\u{103c8}', '\u{103cf}'),
+    ('\u{10400}', '\u{1049d}'), ('\u{104b0}', '\u{104d3}'), ('\u{104d8}', '\u{104fb}'), ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'), ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'), ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'), ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'), ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'), ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'), ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'), ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'), ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae4}'), ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'), ('\u{10c00}', '\u{10c48}'), ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d23}'), ('\u{10e80}', '\u{10ea9}'), ('\u{10eb0}', '\u{10eb1}'), ('\u{10f00}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'), ('\u{10f30}', '\u{10f45}'), ('\u{10f70}', '\u{10f81}'), ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'), ('\u{11003}', '\u{11037}'), ('\u{11071}', '\u{11072}'), ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110af}'), ('\u{110d0}', '\u{110e8}'), ('\u{11103}', '\u{11126}'), ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'), ('\u{11150}', '\u{11172}'), ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'),
+    ('\u{111c1}', '\u{111c4}'), ('\u{111da}', '\u{111da}'), ('\u{111dc}', '\u{111dc}'), ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122b}'), ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'), ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'), ('\u{1129f}', '\u{112a8}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'), ('\u{1133d}', '\u{1133d}'), ('\u{11350}', '\u{11350}'), ('\u{1135d}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'), ('\u{11447}', '\u{1144a}'), ('\u{1145f}', '\u{11461}'), ('\u{11480}', '\u{114af}'),
+    ('\u{114c4}', '\u{114c5}'), ('\u{114c7}', '\u{114c7}'), ('\u{11580}', '\u{115ae}'), ('\u{115d8}', '\u{115db}'),
+    ('\u{11600}', '\u{1162f}'), ('\u{11644}', '\u{11644}'), ('\u{11680}', '\u{116aa}'), ('\u{116b8}', '\u{116b8}'),
+    ('\u{11700}', '\u{1171a}'), ('\u{11740}', '\u{11746}'), ('\u{11800}', '\u{1182b}'), ('\u{118a0}', '\u{118df}'),
+    ('\u{118ff}', '\u{11906}'), ('\u{11909}', '\u{11909}'), ('\u{1190c}', '\u{11913}'), ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192f}'), ('\u{1193f}', '\u{1193f}'), ('\u{11941}', '\u{11941}'), ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d0}'), ('\u{119e1}', '\u{119e1}'), ('\u{119e3}', '\u{119e3}'), ('\u{11a00}', '\u{11a00}'),
+    ('\u{11a0b}', '\u{11a32}'), ('\u{11a3a}', '\u{11a3a}'), ('\u{11a50}', '\u{11a50}'), ('\u{11a5c}', '\u{11a89}'),
+    ('\u{11a9d}', '\u{11a9d}'), ('\u{11ab0}', '\u{11af8}'), ('\u{11c00}', '\u{11c08}'), ('\u{11c0a}', '\u{11c2e}'),
+    ('\u{11c40}', '\u{11c40}'), ('\u{11c72}', '\u{11c8f}'), ('\u{11d00}', '\u{11d06}'), ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d30}'), ('\u{11d46}', '\u{11d46}'), ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d89}'), ('\u{11d98}', '\u{11d98}'), ('\u{11ee0}', '\u{11ef2}'), ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'), ('\u{12480}', '\u{12543}'), ('\u{12f90}', '\u{12ff0}'), ('\u{13000}', '\u{1342e}'),
+    ('\u{14400}', '\u{14646}'), ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ad0}', '\u{16aed}'), ('\u{16b00}', '\u{16b2f}'), ('\u{16b40}', '\u{16b43}'), ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'), ('\u{16e40}', '\u{16e7f}'), ('\u{16f00}', '\u{16f4a}'), ('\u{16f50}', '\u{16f50}'),
+    ('\u{16f93}', '\u{16f9f}'), ('\u{16fe0}', '\u{16fe1}'), ('\u{16fe3}', '\u{16fe3}'), ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'), ('\u{18d00}', '\u{18d08}'), ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b122}'), ('\u{1b150}', '\u{1b152}'), ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'), ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'), ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'), ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'), ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'), ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1e100}', '\u{1e12c}'), ('\u{1e137}', '\u{1e13d}'), ('\u{1e14e}', '\u{1e14e}'), ('\u{1e290}', '\u{1e2ad}'),
+    ('\u{1e2c0}', '\u{1e2eb}'), ('\u{1e7e0}', '\u{1e7e6}'), ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'), ('\u{1e800}', '\u{1e8c4}'), ('\u{1e900}', '\u{1e943}'), ('\u{1e94b}', '\u{1e94b}'),
+    ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{2a6df}'), ('\u{2a700}', '\u{2b738}'), ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'), ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'),
+];
+
+pub const GC_LC: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{61}', '\u{7a}'), ('\u{b5}', '\u{b5}'), ('\u{c0}', '\u{d6}'),
+    ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{1ba}'), ('\u{1bc}', '\u{1bf}'), ('\u{1c4}', '\u{293}'),
+    ('\u{295}', '\u{2af}'), ('\u{370}', '\u{373}'), ('\u{376}', '\u{377}'), ('\u{37b}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'), ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'), ('\u{3a3}', '\u{3f5}'), ('\u{3f7}', '\u{481}'), ('\u{48a}', '\u{52f}'),
+    ('\u{531}', '\u{556}'), ('\u{560}', '\u{588}'), ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'), ('\u{10fd}', '\u{10ff}'), ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'), ('\u{1c80}', '\u{1c88}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1d00}', '\u{1d2b}'), ('\u{1d6b}', '\u{1d77}'), ('\u{1d79}', '\u{1d9a}'), ('\u{1e00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'), ('\u{1f48}', '\u{1f4d}'), ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'), ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fbc}'), ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'), ('\u{1fd0}', '\u{1fd3}'), ('\u{1fd6}', '\u{1fdb}'), ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffc}'), ('\u{2102}', '\u{2102}'), ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'), ('\u{2115}', '\u{2115}'), ('\u{2119}', '\u{211d}'), ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'), ('\u{212f}', '\u{2134}'),
+    ('\u{2139}', '\u{2139}'), ('\u{213c}', '\u{213f}'), ('\u{2145}', '\u{2149}'), ('\u{214e}', '\u{214e}'),
+    ('\u{2183}', '\u{2184}'), ('\u{2c00}', '\u{2c7b}'), ('\u{2c7e}', '\u{2ce4}'), ('\u{2ceb}', '\u{2cee}'),
+    ('\u{2cf2}', '\u{2cf3}'), ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{a640}', '\u{a66d}'), ('\u{a680}', '\u{a69b}'), ('\u{a722}', '\u{a76f}'), ('\u{a771}', '\u{a787}'),
+    ('\u{a78b}', '\u{a78e}'), ('\u{a790}', '\u{a7ca}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'), ('\u{a7f5}', '\u{a7f6}'), ('\u{a7fa}', '\u{a7fa}'), ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab60}', '\u{ab68}'), ('\u{ab70}', '\u{abbf}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'),
+    ('\u{ff21}', '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{10400}', '\u{1044f}'), ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'), ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'), ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'), ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{118a0}', '\u{118df}'),
+    ('\u{16e40}', '\u{16e7f}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'), ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'), ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'), ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'), ('\u{1df00}', '\u{1df09}'),
+    ('\u{1df0b}', '\u{1df1e}'), ('\u{1e900}', '\u{1e943}'),
+];
+
+pub const GC_LL: &[(char, char)] = &[
+    ('\u{61}', '\u{7a}'), ('\u{b5}', '\u{b5}'), ('\u{df}', '\u{f6}'), ('\u{f8}', '\u{ff}'),
+    ('\u{101}', '\u{101}'), ('\u{103}', '\u{103}'), ('\u{105}', '\u{105}'), ('\u{107}', '\u{107}'),
+    ('\u{109}', '\u{109}'), ('\u{10b}', '\u{10b}'), ('\u{10d}', '\u{10d}'), ('\u{10f}', '\u{10f}'),
+    ('\u{111}', '\u{111}'), ('\u{113}', '\u{113}'), ('\u{115}', '\u{115}'), ('\u{117}', '\u{117}'),
+    ('\u{119}', '\u{119}'), ('\u{11b}', '\u{11b}'), ('\u{11d}', '\u{11d}'), ('\u{11f}', '\u{11f}'),
+    ('\u{121}', '\u{121}'), ('\u{123}', '\u{123}'), ('\u{125}', '\u{125}'), ('\u{127}', '\u{127}'),
+    ('\u{129}', '\u{129}'), ('\u{12b}', '\u{12b}'), ('\u{12d}', '\u{12d}'), ('\u{12f}', '\u{12f}'),
+    ('\u{131}', '\u{131}'), ('\u{133}', '\u{133}'), ('\u{135}', '\u{135}'), ('\u{137}', '\u{138}'),
+    ('\u{13a}', '\u{13a}'), ('\u{13c}', '\u{13c}'), ('\u{13e}', '\u{13e}'), ('\u{140}', '\u{140}'),
+    ('\u{142}', '\u{142}'), ('\u{144}', '\u{144}'), ('\u{146}', '\u{146}'), ('\u{148}', '\u{149}'),
+    ('\u{14b}', '\u{14b}'), ('\u{14d}', '\u{14d}'), ('\u{14f}', '\u{14f}'), ('\u{151}', '\u{151}'),
+    ('\u{153}', '\u{153}'), ('\u{155}', '\u{155}'), ('\u{157}', '\u{157}'), ('\u{159}', '\u{159}'),
+    ('\u{15b}', '\u{15b}'), ('\u{15d}', '\u{15d}'), ('\u{15f}', '\u{15f}'), ('\u{161}', '\u{161}'),
+    ('\u{163}', '\u{163}'), ('\u{165}', '\u{165}'), ('\u{167}', '\u{167}'), ('\u{169}', '\u{169}'),
+    ('\u{16b}', '\u{16b}'), ('\u{16d}', '\u{16d}'), ('\u{16f}', '\u{16f}'), ('\u{171}', '\u{171}'),
+    ('\u{173}', '\u{173}'), ('\u{175}', '\u{175}'), ('\u{177}', '\u{177}'), ('\u{17a}', '\u{17a}'),
+    ('\u{17c}', '\u{17c}'), ('\u{17e}', '\u{180}'), ('\u{183}', '\u{183}'), ('\u{185}', '\u{185}'),
+    ('\u{188}', '\u{188}'), ('\u{18c}', '\u{18d}'), ('\u{192}', '\u{192}'), ('\u{195}', '\u{195}'),
+    ('\u{199}', '\u{19b}'), ('\u{19e}', '\u{19e}'), ('\u{1a1}', '\u{1a1}'), ('\u{1a3}', '\u{1a3}'),
+    ('\u{1a5}', '\u{1a5}'), ('\u{1a8}', '\u{1a8}'), ('\u{1aa}', '\u{1ab}'), ('\u{1ad}', '\u{1ad}'),
+    ('\u{1b0}', '\u{1b0}'), ('\u{1b4}', '\u{1b4}'), ('\u{1b6}', '\u{1b6}'), ('\u{1b9}', '\u{1ba}'),
+    ('\u{1bd}', '\u{1bf}'), ('\u{1c6}', '\u{1c6}'), ('\u{1c9}', '\u{1c9}'), ('\u{1cc}', '\u{1cc}'),
+    ('\u{1ce}', '\u{1ce}'), ('\u{1d0}', '\u{1d0}'), ('\u{1d2}', '\u{1d2}'), ('\u{1d4}', '\u{1d4}'),
+    ('\u{1d6}', '\u{1d6}'), ('\u{1d8}', '\u{1d8}'), ('\u{1da}', '\u{1da}'), ('\u{1dc}', '\u{1dd}'),
+    ('\u{1df}', '\u{1df}'), ('\u{1e1}', '\u{1e1}'), ('\u{1e3}', '\u{1e3}'), ('\u{1e5}', '\u{1e5}'),
+    ('\u{1e7}', '\u{1e7}'), ('\u{1e9}', '\u{1e9}'), ('\u{1eb}', '\u{1eb}'), ('\u{1ed}', '\u{1ed}'),
+    ('\u{1ef}', '\u{1f0}'), ('\u{1f3}', '\u{1f3}'), ('\u{1f5}', '\u{1f5}'), ('\u{1f9}', '\u{1f9}'),
+    ('\u{1fb}', '\u{1fb}'), ('\u{1fd}', '\u{1fd}'), ('\u{1ff}', '\u{1ff}'), ('\u{201}', '\u{201}'),
+    ('\u{203}', '\u{203}'), ('\u{205}', '\u{205}'), ('\u{207}', '\u{207}'), ('\u{209}', '\u{209}'),
+    ('\u{20b}', '\u{20b}'), ('\u{20d}', '\u{20d}'), ('\u{20f}', '\u{20f}'), ('\u{211}', '\u{211}'),
+    ('\u{213}', '\u{213}'), ('\u{215}', '\u{215}'), ('\u{217}', '\u{217}'), ('\u{219}', '\u{219}'),
+    ('\u{21b}', '\u{21b}'), ('\u{21d}', '\u{21d}'), ('\u{21f}', '\u{21f}'), ('\u{221}', '\u{221}'),
+    ('\u{223}', '\u{223}'), ('\u{225}', '\u{225}'), ('\u{227}', '\u{227}'), ('\u{229}', '\u{229}'),
+    ('\u{22b}', '\u{22b}'), ('\u{22d}', '\u{22d}'), ('\u{22f}', '\u{22f}'), ('\u{231}', '\u{231}'),
+    ('\u{233}', '\u{239}'), ('\u{23c}', '\u{23c}'), ('\u{23f}', '\u{240}'), ('\u{242}', '\u{242}'),
+    ('\u{247}', '\u{247}'), ('\u{249}', '\u{249}'), ('\u{24b}', '\u{24b}'), ('\u{24d}', '\u{24d}'),
+    ('\u{24f}', '\u{293}'), ('\u{295}', '\u{2af}'), ('\u{371}', '\u{371}'), ('\u{373}', '\u{373}'),
+    ('\u{377}', '\u{377}'), ('\u{37b}', '\u{37d}'), ('\u{390}', '\u{390}'), ('\u{3ac}', '\u{3ce}'),
+    ('\u{3d0}', '\u{3d1}'), ('\u{3d5}', '\u{3d7}'), ('\u{3d9}', '\u{3d9}'), ('\u{3db}', '\u{3db}'),
+    ('\u{3dd}', '\u{3dd}'), ('\u{3df}', '\u{3df}'), ('\u{3e1}', '\u{3e1}'), ('\u{3e3}', '\u{3e3}'),
+    ('\u{3e5}', '\u{3e5}'), ('\u{3e7}', '\u{3e7}'), ('\u{3e9}', '\u{3e9}'), ('\u{3eb}', '\u{3eb}'),
+    ('\u{3ed}', '\u{3ed}'), ('\u{3ef}', '\u{3f3}'), ('\u{3f5}', '\u{3f5}'), ('\u{3f8}', '\u{3f8}'),
+    ('\u{3fb}', '\u{3fc}'), ('\u{430}', '\u{45f}'), ('\u{461}', '\u{461}'), ('\u{463}', '\u{463}'),
+    ('\u{465}', '\u{465}'), ('\u{467}', '\u{467}'), ('\u{469}', '\u{469}'), ('\u{46b}', '\u{46b}'),
+    ('\u{46d}', '\u{46d}'), ('\u{46f}', '\u{46f}'), ('\u{471}', '\u{471}'), ('\u{473}', '\u{473}'),
+    ('\u{475}', '\u{475}'), ('\u{477}', '\u{477}'), ('\u{479}', '\u{479}'), ('\u{47b}', '\u{47b}'),
+    ('\u{47d}', '\u{47d}'), ('\u{47f}', '\u{47f}'), ('\u{481}', '\u{481}'), ('\u{48b}', '\u{48b}'),
+    ('\u{48d}', '\u{48d}'), ('\u{48f}', '\u{48f}'), ('\u{491}', '\u{491}'), ('\u{493}', '\u{493}'),
+    ('\u{495}', '\u{495}'), ('\u{497}', '\u{497}'), ('\u{499}', '\u{499}'), ('\u{49b}', '\u{49b}'),
+    ('\u{49d}', '\u{49d}'), ('\u{49f}', '\u{49f}'), ('\u{4a1}', '\u{4a1}'), ('\u{4a3}', '\u{4a3}'),
+    ('\u{4a5}', '\u{4a5}'), ('\u{4a7}', '\u{4a7}'), ('\u{4a9}', '\u{4a9}'), ('\u{4ab}', '\u{4ab}'),
+    ('\u{4ad}', '\u{4ad}'), ('\u{4af}', '\u{4af}'), ('\u{4b1}', '\u{4b1}'), ('\u{4b3}', '\u{4b3}'),
+    ('\u{4b5}', '\u{4b5}'), ('\u{4b7}', '\u{4b7}'), ('\u{4b9}', '\u{4b9}'), ('\u{4bb}', '\u{4bb}'),
+    ('\u{4bd}', '\u{4bd}'), ('\u{4bf}', '\u{4bf}'), ('\u{4c2}', '\u{4c2}'), ('\u{4c4}', '\u{4c4}'),
+    ('\u{4c6}', '\u{4c6}'), ('\u{4c8}', '\u{4c8}'), ('\u{4ca}', '\u{4ca}'), ('\u{4cc}', '\u{4cc}'),
+    ('\u{4ce}', '\u{4cf}'), ('\u{4d1}', '\u{4d1}'), ('\u{4d3}', '\u{4d3}'), ('\u{4d5}', '\u{4d5}'),
+    ('\u{4d7}', '\u{4d7}'), ('\u{4d9}', '\u{4d9}'), ('\u{4db}', '\u{4db}'), ('\u{4dd}', '\u{4dd}'),
+    ('\u{4df}', '\u{4df}'), ('\u{4e1}', '\u{4e1}'), ('\u{4e3}', '\u{4e3}'), ('\u{4e5}', '\u{4e5}'),
+    ('\u{4e7}', '\u{4e7}'), ('\u{4e9}', '\u{4e9}'), ('\u{4eb}', '\u{4eb}'), ('\u{4ed}', '\u{4ed}'),
+    ('\u{4ef}', '\u{4ef}'), ('\u{4f1}', '\u{4f1}'), ('\u{4f3}', '\u{4f3}'), ('\u{4f5}', '\u{4f5}'),
+    ('\u{4f7}', '\u{4f7}'), ('\u{4f9}', '\u{4f9}'), ('\u{4fb}', '\u{4fb}'), ('\u{4fd}', '\u{4fd}'),
+    ('\u{4ff}', '\u{4ff}'), ('\u{501}', '\u{501}'), ('\u{503}', '\u{503}'), ('\u{505}', '\u{505}'),
+    ('\u{507}', '\u{507}'), ('\u{509}', '\u{509}'), ('\u{50b}', '\u{50b}'), ('\u{50d}', '\u{50d}'),
+    ('\u{50f}', '\u{50f}'), ('\u{511}', '\u{511}'), ('\u{513}', '\u{513}'), ('\u{515}', '\u{515}'),
+    ('\u{517}', '\u{517}'), ('\u{519}', '\u{519}'), ('\u{51b}', '\u{51b}'), ('\u{51d}', '\u{51d}'),
+    ('\u{51f}', '\u{51f}'), ('\u{521}', '\u{521}'), ('\u{523}', '\u{523}'), ('\u{525}', '\u{525}'),
+    ('\u{527}', '\u{527}'), ('\u{529}', '\u{529}'), ('\u{52b}', '\u{52b}'), ('\u{52d}', '\u{52d}'),
+    ('\u{52f}', '\u{52f}'), ('\u{560}', '\u{588}'), ('\u{10d0}', '\u{10fa}'), ('\u{10fd}', '\u{10ff}'),
+    ('\u{13f8}', '\u{13fd}'), ('\u{1c80}', '\u{1c88}'), ('\u{1d00}', '\u{1d2b}'), ('\u{1d6b}', '\u{1d77}'),
+    ('\u{1d79}', '\u{1d9a}'), ('\u{1e01}', '\u{1e01}'), ('\u{1e03}', '\u{1e03}'), ('\u{1e05}', '\u{1e05}'),
+    ('\u{1e07}', '\u{1e07}'), ('\u{1e09}', '\u{1e09}'), ('\u{1e0b}', '\u{1e0b}'), ('\u{1e0d}', '\u{1e0d}'),
+    ('\u{1e0f}', '\u{1e0f}'), ('\u{1e11}', '\u{1e11}'), ('\u{1e13}', '\u{1e13}'), ('\u{1e15}', '\u{1e15}'),
+    ('\u{1e17}', '\u{1e17}'), ('\u{1e19}', '\u{1e19}'), ('\u{1e1b}', '\u{1e1b}'), ('\u{1e1d}', '\u{1e1d}'),
+    ('\u{1e1f}', '\u{1e1f}'), ('\u{1e21}', '\u{1e21}'), ('\u{1e23}', '\u{1e23}'), ('\u{1e25}', '\u{1e25}'),
+    ('\u{1e27}', '\u{1e27}'), ('\u{1e29}', '\u{1e29}'), ('\u{1e2b}', '\u{1e2b}'), ('\u{1e2d}', '\u{1e2d}'),
+    ('\u{1e2f}', '\u{1e2f}'), ('\u{1e31}', '\u{1e31}'), ('\u{1e33}', '\u{1e33}'), ('\u{1e35}', '\u{1e35}'),
+    ('\u{1e37}', '\u{1e37}'), ('\u{1e39}', '\u{1e39}'), ('\u{1e3b}', '\u{1e3b}'), ('\u{1e3d}', '\u{1e3d}'),
+    ('\u{1e3f}', '\u{1e3f}'), ('\u{1e41}', '\u{1e41}'), ('\u{1e43}', '\u{1e43}'), ('\u{1e45}', '\u{1e45}'),
+    ('\u{1e47}', '\u{1e47}'), ('\u{1e49}', '\u{1e49}'), ('\u{1e4b}', '\u{1e4b}'), ('\u{1e4d}', '\u{1e4d}'),
+    ('\u{1e4f}', '\u{1e4f}'), ('\u{1e51}', '\u{1e51}'), ('\u{1e53}', '\u{1e53}'), ('\u{1e55}', '\u{1e55}'),
+    ('\u{1e57}', '\u{1e57}'), ('\u{1e59}', '\u{1e59}'), ('\u{1e5b}', '\u{1e5b}'), ('\u{1e5d}', '\u{1e5d}'),
+    ('\u{1e5f}', '\u{1e5f}'), ('\u{1e61}', '\u{1e61}'), ('\u{1e63}', '\u{1e63}'), ('\u{1e65}', '\u{1e65}'),
+    ('\u{1e67}', '\u{1e67}'), ('\u{1e69}', '\u{1e69}'), ('\u{1e6b}', '\u{1e6b}'), ('\u{1e6d}', '\u{1e6d}'),
+    ('\u{1e6f}', '\u{1e6f}'), ('\u{1e71}', '\u{1e71}'), ('\u{1e73}', '\u{1e73}'), ('\u{1e75}', '\u{1e75}'),
+    ('\u{1e77}', '\u{1e77}'), ('\u{1e79}', '\u{1e79}'), ('\u{1e7b}', '\u{1e7b}'), ('\u{1e7d}', '\u{1e7d}'),
+    ('\u{1e7f}', '\u{1e7f}'), ('\u{1e81}', '\u{1e81}'), ('\u{1e83}', '\u{1e83}'), ('\u{1e85}', '\u{1e85}'),
+    ('\u{1e87}', '\u{1e87}'), ('\u{1e89}', '\u{1e89}'), ('\u{1e8b}', '\u{1e8b}'), ('\u{1e8d}', '\u{1e8d}'),
+    ('\u{1e8f}', '\u{1e8f}'), ('\u{1e91}', '\u{1e91}'), ('\u{1e93}', '\u{1e93}'), ('\u{1e95}', '\u{1e9d}'),
+    ('\u{1e9f}', '\u{1e9f}'), ('\u{1ea1}', '\u{1ea1}'), ('\u{1ea3}', '\u{1ea3}'), ('\u{1ea5}', '\u{1ea5}'),
+    ('\u{1ea7}', '\u{1ea7}'), ('\u{1ea9}', '\u{1ea9}'), ('\u{1eab}', '\u{1eab}'), ('\u{1ead}', '\u{1ead}'),
+    ('\u{1eaf}', '\u{1eaf}'), ('\u{1eb1}', '\u{1eb1}'), ('\u{1eb3}', '\u{1eb3}'), ('\u{1eb5}', '\u{1eb5}'),
+    ('\u{1eb7}', '\u{1eb7}'), ('\u{1eb9}', '\u{1eb9}'), ('\u{1ebb}', '\u{1ebb}'), ('\u{1ebd}', '\u{1ebd}'),
+    ('\u{1ebf}', '\u{1ebf}'), ('\u{1ec1}', '\u{1ec1}'), ('\u{1ec3}', '\u{1ec3}'), ('\u{1ec5}', '\u{1ec5}'),
+    ('\u{1ec7}', '\u{1ec7}'), ('\u{1ec9}', '\u{1ec9}'), ('\u{1ecb}', '\u{1ecb}'), ('\u{1ecd}', '\u{1ecd}'),
+    ('\u{1ecf}', '\u{1ecf}'), ('\u{1ed1}', '\u{1ed1}'), ('\u{1ed3}', '\u{1ed3}'), ('\u{1ed5}', '\u{1ed5}'),
+    ('\u{1ed7}', '\u{1ed7}'), ('\u{1ed9}', '\u{1ed9}'), ('\u{1edb}', '\u{1edb}'), ('\u{1edd}', '\u{1edd}'),
+    ('\u{1edf}', '\u{1edf}'), ('\u{1ee1}', '\u{1ee1}'), ('\u{1ee3}', '\u{1ee3}'), ('\u{1ee5}', '\u{1ee5}'),
+    ('\u{1ee7}', '\u{1ee7}'), ('\u{1ee9}', '\u{1ee9}'), ('\u{1eeb}', '\u{1eeb}'), ('\u{1eed}', '\u{1eed}'),
+    ('\u{1eef}', '\u{1eef}'), ('\u{1ef1}', '\u{1ef1}'), ('\u{1ef3}', '\u{1ef3}'), ('\u{1ef5}', '\u{1ef5}'),
+    ('\u{1ef7}', '\u{1ef7}'), ('\u{1ef9}', '\u{1ef9}'), ('\u{1efb}', '\u{1efb}'), ('\u{1efd}', '\u{1efd}'),
+    ('\u{1eff}', '\u{1f07}'), ('\u{1f10}', '\u{1f15}'), ('\u{1f20}', '\u{1f27}'), ('\u{1f30}', '\u{1f37}'),
+    ('\u{1f40}', '\u{1f45}'), ('\u{1f50}', '\u{1f57}'), ('\u{1f60}', '\u{1f67}'), ('\u{1f70}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1f87}'), ('\u{1f90}', '\u{1f97}'), ('\u{1fa0}', '\u{1fa7}'), ('\u{1fb0}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fb7}'), ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fc7}'),
+    ('\u{1fd0}', '\u{1fd3}'), ('\u{1fd6}', '\u{1fd7}'), ('\u{1fe0}', '\u{1fe7}'), ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ff7}'), ('\u{210a}', '\u{210a}'), ('\u{210e}', '\u{210f}'), ('\u{2113}', '\u{2113}'),
+    ('\u{212f}', '\u{212f}'), ('\u{2134}', '\u{2134}'), ('\u{2139}', '\u{2139}'), ('\u{213c}', '\u{213d}'),
+    ('\u{2146}', '\u{2149}'), ('\u{214e}', '\u{214e}'), ('\u{2184}', '\u{2184}'), ('\u{2c30}', '\u{2c5f}'),
+    ('\u{2c61}', '\u{2c61}'), ('\u{2c65}', '\u{2c66}'), ('\u{2c68}', '\u{2c68}'), ('\u{2c6a}', '\u{2c6a}'),
+    ('\u{2c6c}', '\u{2c6c}'), ('\u{2c71}', '\u{2c71}'), ('\u{2c73}', '\u{2c74}'), ('\u{2c76}', '\u{2c7b}'),
+    ('\u{2c81}', '\u{2c81}'), ('\u{2c83}', '\u{2c83}'), ('\u{2c85}', '\u{2c85}'), ('\u{2c87}', '\u{2c87}'),
+    ('\u{2c89}', '\u{2c89}'), ('\u{2c8b}', '\u{2c8b}'), ('\u{2c8d}', '\u{2c8d}'), ('\u{2c8f}', '\u{2c8f}'),
+    ('\u{2c91}', '\u{2c91}'), ('\u{2c93}', '\u{2c93}'), ('\u{2c95}', '\u{2c95}'), ('\u{2c97}', '\u{2c97}'),
+    ('\u{2c99}', '\u{2c99}'), ('\u{2c9b}', '\u{2c9b}'), ('\u{2c9d}', '\u{2c9d}'), ('\u{2c9f}', '\u{2c9f}'),
+    ('\u{2ca1}', '\u{2ca1}'), ('\u{2ca3}', '\u{2ca3}'), ('\u{2ca5}', '\u{2ca5}'), ('\u{2ca7}', '\u{2ca7}'),
+    ('\u{2ca9}', '\u{2ca9}'), ('\u{2cab}', '\u{2cab}'), ('\u{2cad}', '\u{2cad}'), ('\u{2caf}', '\u{2caf}'),
+    ('\u{2cb1}', '\u{2cb1}'), ('\u{2cb3}', '\u{2cb3}'), ('\u{2cb5}', '\u{2cb5}'), ('\u{2cb7}', '\u{2cb7}'),
+    ('\u{2cb9}', '\u{2cb9}'), ('\u{2cbb}', '\u{2cbb}'), ('\u{2cbd}', '\u{2cbd}'), ('\u{2cbf}', '\u{2cbf}'),
+    ('\u{2cc1}', '\u{2cc1}'), ('\u{2cc3}', '\u{2cc3}'), ('\u{2cc5}', '\u{2cc5}'), ('\u{2cc7}', '\u{2cc7}'),
+    ('\u{2cc9}', '\u{2cc9}'), ('\u{2ccb}', '\u{2ccb}'), ('\u{2ccd}', '\u{2ccd}'), ('\u{2ccf}', '\u{2ccf}'),
+    ('\u{2cd1}', '\u{2cd1}'), ('\u{2cd3}', '\u{2cd3}'), ('\u{2cd5}', '\u{2cd5}'), ('\u{2cd7}', '\u{2cd7}'),
+    ('\u{2cd9}', '\u{2cd9}'), ('\u{2cdb}', '\u{2cdb}'), ('\u{2cdd}', '\u{2cdd}'), ('\u{2cdf}', '\u{2cdf}'),
+    ('\u{2ce1}', '\u{2ce1}'), ('\u{2ce3}', '\u{2ce4}'), ('\u{2cec}', '\u{2cec}'), ('\u{2cee}', '\u{2cee}'),
+    ('\u{2cf3}', '\u{2cf3}'), ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{a641}', '\u{a641}'), ('\u{a643}', '\u{a643}'), ('\u{a645}', '\u{a645}'), ('\u{a647}', '\u{a647}'),
+    ('\u{a649}', '\u{a649}'), ('\u{a64b}', '\u{a64b}'), ('\u{a64d}', '\u{a64d}'), ('\u{a64f}', '\u{a64f}'),
+    ('\u{a651}', '\u{a651}'), ('\u{a653}', '\u{a653}'), ('\u{a655}', '\u{a655}'), ('\u{a657}', '\u{a657}'),
+    ('\u{a659}', '\u{a659}'), ('\u{a65b}', '\u{a65b}'), ('\u{a65d}', '\u{a65d}'), ('\u{a65f}', '\u{a65f}'),
+    ('\u{a661}', '\u{a661}'), ('\u{a663}', '\u{a663}'), ('\u{a665}', '\u{a665}'), ('\u{a667}', '\u{a667}'),
+    ('\u{a669}', '\u{a669}'), ('\u{a66b}', '\u{a66b}'), ('\u{a66d}', '\u{a66d}'), ('\u{a681}', '\u{a681}'),
+    ('\u{a683}', '\u{a683}'), ('\u{a685}', '\u{a685}'), ('\u{a687}', '\u{a687}'), ('\u{a689}', '\u{a689}'),
+    ('\u{a68b}', '\u{a68b}'), ('\u{a68d}', '\u{a68d}'), ('\u{a68f}', '\u{a68f}'), ('\u{a691}', '\u{a691}'),
+    ('\u{a693}', '\u{a693}'), ('\u{a695}', '\u{a695}'), ('\u{a697}', '\u{a697}'), ('\u{a699}', '\u{a699}'),
+    ('\u{a69b}', '\u{a69b}'), ('\u{a723}', '\u{a723}'), ('\u{a725}', '\u{a725}'), ('\u{a727}', '\u{a727}'),
+    ('\u{a729}', '\u{a729}'), ('\u{a72b}', '\u{a72b}'), ('\u{a72d}', '\u{a72d}'), ('\u{a72f}', '\u{a731}'),
+    ('\u{a733}', '\u{a733}'), ('\u{a735}', '\u{a735}'), ('\u{a737}', '\u{a737}'), ('\u{a739}', '\u{a739}'),
+    ('\u{a73b}', '\u{a73b}'), ('\u{a73d}', '\u{a73d}'), ('\u{a73f}', '\u{a73f}'), ('\u{a741}', '\u{a741}'),
+    ('\u{a743}', '\u{a743}'), ('\u{a745}', '\u{a745}'), ('\u{a747}', '\u{a747}'), ('\u{a749}', '\u{a749}'),
+    ('\u{a74b}', '\u{a74b}'), ('\u{a74d}', '\u{a74d}'), ('\u{a74f}', '\u{a74f}'), ('\u{a751}', '\u{a751}'),
+    ('\u{a753}', '\u{a753}'), ('\u{a755}', '\u{a755}'), ('\u{a757}', '\u{a757}'), ('\u{a759}', '\u{a759}'),
+    ('\u{a75b}', '\u{a75b}'), ('\u{a75d}', '\u{a75d}'), ('\u{a75f}', '\u{a75f}'), ('\u{a761}', '\u{a761}'),
+    ('\u{a763}', '\u{a763}'), ('\u{a765}', '\u{a765}'), ('\u{a767}', '\u{a767}'), ('\u{a769}', '\u{a769}'),
+    ('\u{a76b}', '\u{a76b}'), ('\u{a76d}', '\u{a76d}'), ('\u{a76f}', '\u{a76f}'), ('\u{a771}', '\u{a778}'),
+    ('\u{a77a}', '\u{a77a}'), ('\u{a77c}', '\u{a77c}'), ('\u{a77f}', '\u{a77f}'), ('\u{a781}', '\u{a781}'),
+    ('\u{a783}', '\u{a783}'), ('\u{a785}', '\u{a785}'), ('\u{a787}', '\u{a787}'), ('\u{a78c}', '\u{a78c}'),
+    ('\u{a78e}', '\u{a78e}'), ('\u{a791}', '\u{a791}'), ('\u{a793}', '\u{a795}'), ('\u{a797}', '\u{a797}'),
+    ('\u{a799}', '\u{a799}'), ('\u{a79b}', '\u{a79b}'), ('\u{a79d}', '\u{a79d}'), ('\u{a79f}', '\u{a79f}'),
+    ('\u{a7a1}', '\u{a7a1}'), ('\u{a7a3}', '\u{a7a3}'), ('\u{a7a5}', '\u{a7a5}'), ('\u{a7a7}', '\u{a7a7}'),
+    ('\u{a7a9}', '\u{a7a9}'), ('\u{a7af}', '\u{a7af}'), ('\u{a7b5}', '\u{a7b5}'), ('\u{a7b7}', '\u{a7b7}'),
+    ('\u{a7b9}', '\u{a7b9}'), ('\u{a7bb}', '\u{a7bb}'), ('\u{a7bd}', '\u{a7bd}'), ('\u{a7bf}', '\u{a7bf}'),
+    ('\u{a7c1}', '\u{a7c1}'), ('\u{a7c3}', '\u{a7c3}'), ('\u{a7c8}', '\u{a7c8}'), ('\u{a7ca}', '\u{a7ca}'),
+    ('\u{a7d1}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'), ('\u{a7d5}', '\u{a7d5}'), ('\u{a7d7}', '\u{a7d7}'),
+    ('\u{a7d9}', '\u{a7d9}'), ('\u{a7f6}', '\u{a7f6}'), ('\u{a7fa}', '\u{a7fa}'), ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab60}', '\u{ab68}'), ('\u{ab70}', '\u{abbf}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'),
+    ('\u{ff41}', '\u{ff5a}'), ('\u{10428}', '\u{1044f}'), ('\u{104d8}', '\u{104fb}'), ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'), ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{118c0}', '\u{118df}'), ('\u{16e60}', '\u{16e7f}'), ('\u{1d41a}', '\u{1d433}'), ('\u{1d44e}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d467}'), ('\u{1d482}', '\u{1d49b}'), ('\u{1d4b6}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d4cf}'), ('\u{1d4ea}', '\u{1d503}'), ('\u{1d51e}', '\u{1d537}'),
+    ('\u{1d552}', '\u{1d56b}'), ('\u{1d586}', '\u{1d59f}'), ('\u{1d5ba}', '\u{1d5d3}'), ('\u{1d5ee}', '\u{1d607}'),
+    ('\u{1d622}', '\u{1d63b}'), ('\u{1d656}', '\u{1d66f}'), ('\u{1d68a}', '\u{1d6a5}'), ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6e1}'), ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d71b}'), ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d755}'), ('\u{1d770}', '\u{1d788}'), ('\u{1d78a}', '\u{1d78f}'), ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7c9}'), ('\u{1d7cb}', '\u{1d7cb}'), ('\u{1df00}', '\u{1df09}'), ('\u{1df0b}', '\u{1df1e}'),
+    ('\u{1e922}', '\u{1e943}'),
+];
+
+pub const GC_LM: &[(char, char)] = &[
+    ('\u{2b0}', '\u{2c1}'), ('\u{2c6}', '\u{2d1}'), ('\u{2e0}', '\u{2e4}'), ('\u{2ec}', '\u{2ec}'),
+    ('\u{2ee}', '\u{2ee}'), ('\u{374}', '\u{374}'), ('\u{37a}', '\u{37a}'), ('\u{559}', '\u{559}'),
+    ('\u{640}', '\u{640}'), ('\u{6e5}', '\u{6e6}'), ('\u{7f4}', '\u{7f5}'), ('\u{7fa}', '\u{7fa}'),
+    ('\u{81a}', '\u{81a}'), ('\u{824}', '\u{824}'), ('\u{828}', '\u{828}'), ('\u{8c9}', '\u{8c9}'),
+    ('\u{971}', '\u{971}'), ('\u{e46}', '\u{e46}'), ('\u{ec6}', '\u{ec6}'), ('\u{10fc}', '\u{10fc}'),
+    ('\u{17d7}', '\u{17d7}'), ('\u{1843}', '\u{1843}'), ('\u{1aa7}', '\u{1aa7}'), ('\u{1c78}', '\u{1c7d}'),
+    ('\u{1d2c}', '\u{1d6a}'), ('\u{1d78}', '\u{1d78}'), ('\u{1d9b}', '\u{1dbf}'), ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{2c7c}', '\u{2c7d}'), ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2e2f}', '\u{2e2f}'), ('\u{3005}', '\u{3005}'), ('\u{3031}', '\u{3035}'), ('\u{303b}', '\u{303b}'),
+    ('\u{309d}', '\u{309e}'), ('\u{30fc}', '\u{30fe}'), ('\u{a015}', '\u{a015}'), ('\u{a4f8}', '\u{a4fd}'),
+    ('\u{a60c}', '\u{a60c}'), ('\u{a67f}', '\u{a67f}'), ('\u{a69c}', '\u{a69d}'), ('\u{a717}', '\u{a71f}'),
+    ('\u{a770}', '\u{a770}'), ('\u{a788}', '\u{a788}'), ('\u{a7f2}', '\u{a7f4}'), ('\u{a7f8}', '\u{a7f9}'),
+    ('\u{a9cf}', '\u{a9cf}'), ('\u{a9e6}', '\u{a9e6}'), ('\u{aa70}', '\u{aa70}'), ('\u{aadd}', '\u{aadd}'),
+    ('\u{aaf3}', '\u{aaf4}'), ('\u{ab5c}', '\u{ab5f}'), ('\u{ab69}', '\u{ab69}'), ('\u{ff70}', '\u{ff70}'),
+    ('\u{ff9e}', '\u{ff9f}'), ('\u{10780}', '\u{10785}'), ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'),
+    ('\u{16b40}', '\u{16b43}'), ('\u{16f93}', '\u{16f9f}'), ('\u{16fe0}', '\u{16fe1}'), ('\u{16fe3}', '\u{16fe3}'),
+    ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}', '\u{1affb}'), ('\u{1affd}', '\u{1affe}'), ('\u{1e137}', '\u{1e13d}'),
+    ('\u{1e94b}', '\u{1e94b}'),
+];
+
+pub const GC_LO: &[(char, char)] = &[
+    ('\u{aa}', '\u{aa}'), ('\u{ba}', '\u{ba}'), ('\u{1bb}', '\u{1bb}'), ('\u{1c0}', '\u{1c3}'),
+    ('\u{294}', '\u{294}'), ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f2}'), ('\u{620}', '\u{63f}'),
+    ('\u{641}', '\u{64a}'), ('\u{66e}', '\u{66f}'), ('\u{671}', '\u{6d3}'), ('\u{6d5}', '\u{6d5}'),
+    ('\u{6ee}', '\u{6ef}'), ('\u{6fa}', '\u{6fc}'), ('\u{6ff}', '\u{6ff}'), ('\u{710}', '\u{710}'),
+    ('\u{712}', '\u{72f}'), ('\u{74d}', '\u{7a5}'), ('\u{7b1}', '\u{7b1}'), ('\u{7ca}', '\u{7ea}'),
+    ('\u{800}', '\u{815}'), ('\u{840}', '\u{858}'), ('\u{860}', '\u{86a}'), ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88e}'), ('\u{8a0}', '\u{8c8}'), ('\u{904}', '\u{939}'), ('\u{93d}', '\u{93d}'),
+    ('\u{950}', '\u{950}'), ('\u{958}', '\u{961}'), ('\u{972}', '\u{980}'), ('\u{985}', '\u{98c}'),
+    ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'), ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'),
+    ('\u{9b6}', '\u{9b9}'), ('\u{9bd}', '\u{9bd}'), ('\u{9ce}', '\u{9ce}'), ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e1}'), ('\u{9f0}', '\u{9f1}'), ('\u{9fc}', '\u{9fc}'), ('\u{a05}', '\u{a0a}'),
+    ('\u{a0f}', '\u{a10}'), ('\u{a13}', '\u{a28}'), ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'),
+    ('\u{a35}', '\u{a36}'), ('\u{a38}', '\u{a39}'), ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'),
+    ('\u{a72}', '\u{a74}'), ('\u{a85}', '\u{a8d}'), ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'), ('\u{ab5}', '\u{ab9}'), ('\u{abd}', '\u{abd}'),
+    ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae1}'), ('\u{af9}', '\u{af9}'), ('\u{b05}', '\u{b0c}'),
+    ('\u{b0f}', '\u{b10}'), ('\u{b13}', '\u{b28}'), ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'),
+    ('\u{b35}', '\u{b39}'), ('\u{b3d}', '\u{b3d}'), ('\u{b5c}', '\u{b5d}'), ('\u{b5f}', '\u{b61}'),
+    ('\u{b71}', '\u{b71}'), ('\u{b83}', '\u{b83}'), ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'), ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'), ('\u{bae}', '\u{bb9}'), ('\u{bd0}', '\u{bd0}'),
+    ('\u{c05}', '\u{c0c}'), ('\u{c0e}', '\u{c10}'), ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c39}'),
+    ('\u{c3d}', '\u{c3d}'), ('\u{c58}', '\u{c5a}'), ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c61}'),
+    ('\u{c80}', '\u{c80}'), ('\u{c85}', '\u{c8c}'), ('\u{c8e}', '\u{c90}'), ('\u{c92}', '\u{ca8}'),
+    ('\u{caa}', '\u{cb3}'), ('\u{cb5}', '\u{cb9}'), ('\u{cbd}', '\u{cbd}'), ('\u{cdd}', '\u{cde}'),
+    ('\u{ce0}', '\u{ce1}'), ('\u{cf1}', '\u{cf2}'), ('\u{d04}', '\u{d0c}'), ('\u{d0e}', '\u{d10}'),
+    ('\u{d12}', '\u{d3a}'), ('\u{d3d}', '\u{d3d}'), ('\u{d4e}', '\u{d4e}'), ('\u{d54}', '\u{d56}'),
+    ('\u{d5f}', '\u{d61}'), ('\u{d7a}', '\u{d7f}'), ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'),
+    ('\u{db3}', '\u{dbb}'), ('\u{dbd}', '\u{dbd}'), ('\u{dc0}', '\u{dc6}'), ('\u{e01}', '\u{e30}'),
+    ('\u{e32}', '\u{e33}'), ('\u{e40}', '\u{e45}'), ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'),
+    ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'), ('\u{ea5}', '\u{ea5}'), ('\u{ea7}', '\u{eb0}'),
+    ('\u{eb2}', '\u{eb3}'), ('\u{ebd}', '\u{ebd}'), ('\u{ec0}', '\u{ec4}'), ('\u{edc}', '\u{edf}'),
+    ('\u{f00}', '\u{f00}'), ('\u{f40}', '\u{f47}'), ('\u{f49}', '\u{f6c}'), ('\u{f88}', '\u{f8c}'),
+    ('\u{1000}', '\u{102a}'), ('\u{103f}', '\u{103f}'), ('\u{1050}', '\u{1055}'), ('\u{105a}', '\u{105d}'),
+    ('\u{1061}', '\u{1061}'), ('\u{1065}', '\u{1066}'), ('\u{106e}', '\u{1070}'), ('\u{1075}', '\u{1081}'),
+    ('\u{108e}', '\u{108e}'), ('\u{1100}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'), ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'), ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'), ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'), ('\u{1380}', '\u{138f}'), ('\u{1401}', '\u{166c}'), ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'), ('\u{16a0}', '\u{16ea}'), ('\u{16f1}', '\u{16f8}'), ('\u{1700}', '\u{1711}'),
+    ('\u{171f}', '\u{1731}'), ('\u{1740}', '\u{1751}'), ('\u{1760}', '\u{176c}'), ('\u{176e}', '\u{1770}'),
+    ('\u{1780}', '\u{17b3}'), ('\u{17dc}', '\u{17dc}'), ('\u{1820}', '\u{1842}'), ('\u{1844}', '\u{1878}'),
+    ('\u{1880}', '\u{1884}'), ('\u{1887}', '\u{18a8}'), ('\u{18aa}', '\u{18aa}'), ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'), ('\u{1950}', '\u{196d}'), ('\u{1970}', '\u{1974}'), ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'), ('\u{1a00}', '\u{1a16}'), ('\u{1a20}', '\u{1a54}'), ('\u{1b05}', '\u{1b33}'),
+    ('\u{1b45}', '\u{1b4c}'), ('\u{1b83}', '\u{1ba0}'), ('\u{1bae}', '\u{1baf}'), ('\u{1bba}', '\u{1be5}'),
+    ('\u{1c00}', '\u{1c23}'), ('\u{1c4d}', '\u{1c4f}'), ('\u{1c5a}', '\u{1c77}'), ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'), ('\u{1cf5}', '\u{1cf6}'), ('\u{1cfa}', '\u{1cfa}'), ('\u{2135}', '\u{2138}'),
+    ('\u{2d30}', '\u{2d67}'), ('\u{2d80}', '\u{2d96}'), ('\u{2da0}', '\u{2da6}'), ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'), ('\u{2dc0}', '\u{2dc6}'), ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{3006}', '\u{3006}'), ('\u{303c}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'), ('\u{309f}', '\u{309f}'), ('\u{30a1}', '\u{30fa}'), ('\u{30ff}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'), ('\u{3131}', '\u{318e}'), ('\u{31a0}', '\u{31bf}'), ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'), ('\u{4e00}', '\u{a014}'), ('\u{a016}', '\u{a48c}'), ('\u{a4d0}', '\u{a4f7}'),
+    ('\u{a500}', '\u{a60b}'), ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'), ('\u{a66e}', '\u{a66e}'),
+    ('\u{a6a0}', '\u{a6e5}'), ('\u{a78f}', '\u{a78f}'), ('\u{a7f7}', '\u{a7f7}'), ('\u{a7fb}', '\u{a801}'),
+    ('\u{a803}', '\u{a805}'), ('\u{a807}', '\u{a80a}'), ('\u{a80c}', '\u{a822}'), ('\u{a840}', '\u{a873}'),
+    ('\u{a882}', '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'), ('\u{a8fd}', '\u{a8fe}'),
+    ('\u{a90a}', '\u{a925}'), ('\u{a930}', '\u{a946}'), ('\u{a960}', '\u{a97c}'), ('\u{a984}', '\u{a9b2}'),
+    ('\u{a9e0}', '\u{a9e4}'), ('\u{a9e7}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}', '\u{aa28}'),
+    ('\u{aa40}', '\u{aa42}'), ('\u{aa44}', '\u{aa4b}'), ('\u{aa60}', '\u{aa6f}'), ('\u{aa71}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'), ('\u{aab1}', '\u{aab1}'), ('\u{aab5}', '\u{aab6}'),
+    ('\u{aab9}', '\u{aabd}'), ('\u{aac0}', '\u{aac0}'), ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadc}'),
+    ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf2}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{abc0}', '\u{abe2}'),
+    ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'), ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'), ('\u{ff66}', '\u{ff6f}'),
+    ('\u{ff71}', '\u{ff9d}'), ('\u{ffa0}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'), ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'), ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'), ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{10340}'), ('\u{10342}', '\u{10349}'), ('\u{10350}', '\u{10375}'), ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'), ('\u{103c8}', '\u{103cf}'), ('\u{10450}', '\u{1049d}'), ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'),
+    ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'), ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'), ('\u{108f4}', '\u{108f5}'), ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109b7}'), ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a35}'), ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'), ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'), ('\u{10b80}', '\u{10b91}'), ('\u{10c00}', '\u{10c48}'), ('\u{10d00}', '\u{10d23}'),
+    ('\u{10e80}', '\u{10ea9}'), ('\u{10eb0}', '\u{10eb1}'), ('\u{10f00}', '\u{10f1c}'), ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f45}'), ('\u{10f70}', '\u{10f81}'), ('\u{10fb0}', '\u{10fc4}'), ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11003}', '\u{11037}'), ('\u{11071}', '\u{11072}'), ('\u{11075}', '\u{11075}'), ('\u{11083}', '\u{110af}'),
+    ('\u{110d0}', '\u{110e8}'), ('\u{11103}', '\u{11126}'), ('\u{11144}', '\u{11144}'), ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'), ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'), ('\u{111c1}', '\u{111c4}'),
+    ('\u{111da}', '\u{111da}'), ('\u{111dc}', '\u{111dc}'), ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{1122b}'),
+    ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'), ('\u{1128a}', '\u{1128d}'), ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'),
+    ('\u{1133d}', '\u{1133d}'), ('\u{11350}', '\u{11350}'), ('\u{1135d}', '\u{11361}'), ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144a}'), ('\u{1145f}', '\u{11461}'), ('\u{11480}', '\u{114af}'), ('\u{114c4}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'), ('\u{11580}', '\u{115ae}'), ('\u{115d8}', '\u{115db}'), ('\u{11600}', '\u{1162f}'),
+    ('\u{11644}', '\u{11644}'), ('\u{11680}', '\u{116aa}'), ('\u{116b8}', '\u{116b8}'), ('\u{11700}', '\u{1171a}'),
+    ('\u{11740}', '\u{11746}'), ('\u{11800}', '\u{1182b}'), ('\u{118ff}', '\u{11906}'), ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'), ('\u{11915}', '\u{11916}'), ('\u{11918}', '\u{1192f}'), ('\u{1193f}', '\u{1193f}'),
+    ('\u{11941}', '\u{11941}'), ('\u{119a0}', '\u{119a7}'), ('\u{119aa}', '\u{119d0}'), ('\u{119e1}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e3}'), ('\u{11a00}', '\u{11a00}'), ('\u{11a0b}', '\u{11a32}'), ('\u{11a3a}', '\u{11a3a}'),
+    ('\u{11a50}', '\u{11a50}'), ('\u{11a5c}', '\u{11a89}'), ('\u{11a9d}', '\u{11a9d}'), ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11c00}', '\u{11c08}'), ('\u{11c0a}', '\u{11c2e}'), ('\u{11c40}', '\u{11c40}'), ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11d00}', '\u{11d06}'), ('\u{11d08}', '\u{11d09}'), ('\u{11d0b}', '\u{11d30}'), ('\u{11d46}', '\u{11d46}'),
+    ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'), ('\u{11d6a}', '\u{11d89}'), ('\u{11d98}', '\u{11d98}'),
+    ('\u{11ee0}', '\u{11ef2}'), ('\u{11fb0}', '\u{11fb0}'), ('\u{12000}', '\u{12399}'), ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'), ('\u{13000}', '\u{1342e}'), ('\u{14400}', '\u{14646}'), ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'), ('\u{16a70}', '\u{16abe}'), ('\u{16ad0}', '\u{16aed}'), ('\u{16b00}', '\u{16b2f}'),
+    ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'), ('\u{16f00}', '\u{16f4a}'), ('\u{16f50}', '\u{16f50}'),
+    ('\u{17000}', '\u{187f7}'), ('\u{18800}', '\u{18cd5}'), ('\u{18d00}', '\u{18d08}'), ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b150}', '\u{1b152}'), ('\u{1b164}', '\u{1b167}'), ('\u{1b170}', '\u{1b2fb}'), ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'), ('\u{1df0a}', '\u{1df0a}'),
+    ('\u{1e100}', '\u{1e12c}'), ('\u{1e14e}', '\u{1e14e}'), ('\u{1e290}', '\u{1e2ad}'), ('\u{1e2c0}', '\u{1e2eb}'),
+    ('\u{1e7e0}', '\u{1e7e6}'), ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'), ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{2a6df}'), ('\u{2a700}', '\u{2b738}'),
+    ('\u{2b740}', '\u{2b81d}'), ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'), ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+];
+
+pub const GC_LT: &[(char, char)] = &[
+    ('\u{1c5}', '\u{1c5}'), ('\u{1c8}', '\u{1c8}'), ('\u{1cb}', '\u{1cb}'), ('\u{1f2}', '\u{1f2}'),
+    ('\u{1f88}', '\u{1f8f}'), ('\u{1f98}', '\u{1f9f}'), ('\u{1fa8}', '\u{1faf}'), ('\u{1fbc}', '\u{1fbc}'),
+    ('\u{1fcc}', '\u{1fcc}'), ('\u{1ffc}', '\u{1ffc}'),
+];
+
+pub const GC_LU: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{de}'), ('\u{100}', '\u{100}'),
+    ('\u{102}', '\u{102}'), ('\u{104}', '\u{104}'), ('\u{106}', '\u{106}'), ('\u{108}', '\u{108}'),
+    ('\u{10a}', '\u{10a}'), ('\u{10c}', '\u{10c}'), ('\u{10e}', '\u{10e}'), ('\u{110}', '\u{110}'),
+    ('\u{112}', '\u{112}'), ('\u{114}', '\u{114}'), ('\u{116}', '\u{116}'), ('\u{118}', '\u{118}'),
+    ('\u{11a}', '\u{11a}'), ('\u{11c}', '\u{11c}'), ('\u{11e}', '\u{11e}'), ('\u{120}', '\u{120}'),
+    ('\u{122}', '\u{122}'), ('\u{124}', '\u{124}'), ('\u{126}', '\u{126}'), ('\u{128}', '\u{128}'),
+    ('\u{12a}', '\u{12a}'), ('\u{12c}', '\u{12c}'), ('\u{12e}', '\u{12e}'), ('\u{130}', '\u{130}'),
+    ('\u{132}', '\u{132}'), ('\u{134}', '\u{134}'), ('\u{136}', '\u{136}'), ('\u{139}', '\u{139}'),
+    ('\u{13b}', '\u{13b}'), ('\u{13d}', '\u{13d}'), ('\u{13f}', '\u{13f}'), ('\u{141}', '\u{141}'),
+    ('\u{143}', '\u{143}'), ('\u{145}', '\u{145}'), ('\u{147}', '\u{147}'), ('\u{14a}', '\u{14a}'),
+    ('\u{14c}', '\u{14c}'), ('\u{14e}', '\u{14e}'), ('\u{150}', '\u{150}'), ('\u{152}', '\u{152}'),
+    ('\u{154}', '\u{154}'), ('\u{156}', '\u{156}'), ('\u{158}', '\u{158}'), ('\u{15a}', '\u{15a}'),
+    ('\u{15c}', '\u{15c}'), ('\u{15e}', '\u{15e}'), ('\u{160}', '\u{160}'), ('\u{162}', '\u{162}'),
+    ('\u{164}', '\u{164}'), ('\u{166}', '\u{166}'), ('\u{168}', '\u{168}'), ('\u{16a}', '\u{16a}'),
+    ('\u{16c}', '\u{16c}'), ('\u{16e}', '\u{16e}'), ('\u{170}', '\u{170}'), ('\u{172}', '\u{172}'),
+    ('\u{174}', '\u{174}'), ('\u{176}', '\u{176}'), ('\u{178}', '\u{179}'), ('\u{17b}', '\u{17b}'),
+    ('\u{17d}', '\u{17d}'), ('\u{181}', '\u{182}'), ('\u{184}', '\u{184}'), ('\u{186}', '\u{187}'),
+    ('\u{189}', '\u{18b}'), ('\u{18e}', '\u{191}'), ('\u{193}', '\u{194}'), ('\u{196}', '\u{198}'),
+    ('\u{19c}', '\u{19d}'), ('\u{19f}', '\u{1a0}'), ('\u{1a2}', '\u{1a2}'), ('\u{1a4}', '\u{1a4}'),
+    ('\u{1a6}', '\u{1a7}'), ('\u{1a9}', '\u{1a9}'), ('\u{1ac}', '\u{1ac}'), ('\u{1ae}', '\u{1af}'),
+    ('\u{1b1}', '\u{1b3}'), ('\u{1b5}', '\u{1b5}'), ('\u{1b7}', '\u{1b8}'), ('\u{1bc}', '\u{1bc}'),
+    ('\u{1c4}', '\u{1c4}'), ('\u{1c7}', '\u{1c7}'), ('\u{1ca}', '\u{1ca}'), ('\u{1cd}', '\u{1cd}'),
+    ('\u{1cf}', '\u{1cf}'), ('\u{1d1}', '\u{1d1}'), ('\u{1d3}', '\u{1d3}'), ('\u{1d5}', '\u{1d5}'),
+    ('\u{1d7}', '\u{1d7}'), ('\u{1d9}', '\u{1d9}'), ('\u{1db}', '\u{1db}'), ('\u{1de}', '\u{1de}'),
+    ('\u{1e0}', '\u{1e0}'), ('\u{1e2}', '\u{1e2}'), ('\u{1e4}', '\u{1e4}'), ('\u{1e6}', '\u{1e6}'),
+    ('\u{1e8}', '\u{1e8}'), ('\u{1ea}', '\u{1ea}'), ('\u{1ec}', '\u{1ec}'), ('\u{1ee}', '\u{1ee}'),
+    ('\u{1f1}', '\u{1f1}'), ('\u{1f4}', '\u{1f4}'), ('\u{1f6}', '\u{1f8}'), ('\u{1fa}', '\u{1fa}'),
+    ('\u{1fc}', '\u{1fc}'), ('\u{1fe}', '\u{1fe}'), ('\u{200}', '\u{200}'), ('\u{202}', '\u{202}'),
+    ('\u{204}', '\u{204}'), ('\u{206}', '\u{206}'), ('\u{208}', '\u{208}'), ('\u{20a}', '\u{20a}'),
+    ('\u{20c}', '\u{20c}'), ('\u{20e}', '\u{20e}'), ('\u{210}', '\u{210}'), ('\u{212}', '\u{212}'),
+    ('\u{214}', '\u{214}'), ('\u{216}', '\u{216}'), ('\u{218}', '\u{218}'), ('\u{21a}', '\u{21a}'),
+    ('\u{21c}', '\u{21c}'), ('\u{21e}', '\u{21e}'), ('\u{220}', '\u{220}'), ('\u{222}', '\u{222}'),
+    ('\u{224}', '\u{224}'), ('\u{226}', '\u{226}'), ('\u{228}', '\u{228}'), ('\u{22a}', '\u{22a}'),
+    ('\u{22c}', '\u{22c}'), ('\u{22e}', '\u{22e}'), ('\u{230}', '\u{230}'), ('\u{232}', '\u{232}'),
+    ('\u{23a}', '\u{23b}'), ('\u{23d}', '\u{23e}'), ('\u{241}', '\u{241}'), ('\u{243}', '\u{246}'),
+    ('\u{248}', '\u{248}'), ('\u{24a}', '\u{24a}'), ('\u{24c}', '\u{24c}'), ('\u{24e}', '\u{24e}'),
+    ('\u{370}', '\u{370}'), ('\u{372}', '\u{372}'), ('\u{376}', '\u{376}'), ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'), ('\u{38e}', '\u{38f}'),
+    ('\u{391}', '\u{3a1}'), ('\u{3a3}', '\u{3ab}'), ('\u{3cf}', '\u{3cf}'), ('\u{3d2}', '\u{3d4}'),
+    ('\u{3d8}', '\u{3d8}'), ('\u{3da}', '\u{3da}'), ('\u{3dc}', '\u{3dc}'), ('\u{3de}', '\u{3de}'),
+    ('\u{3e0}', '\u{3e0}'), ('\u{3e2}', '\u{3e2}'), ('\u{3e4}', '\u{3e4}'), ('\u{3e6}', '\u{3e6}'),
+    ('\u{3e8}', '\u{3e8}'), ('\u{3ea}', '\u{3ea}'), ('\u{3ec}', '\u{3ec}'), ('\u{3ee}', '\u{3ee}'),
+    ('\u{3f4}', '\u{3f4}'), ('\u{3f7}', '\u{3f7}'), ('\u{3f9}', '\u{3fa}'), ('\u{3fd}', '\u{42f}'),
+    ('\u{460}', '\u{460}'), ('\u{462}', '\u{462}'), ('\u{464}', '\u{464}'), ('\u{466}', '\u{466}'),
+    ('\u{468}', '\u{468}'), ('\u{46a}', '\u{46a}'), ('\u{46c}', '\u{46c}'), ('\u{46e}', '\u{46e}'),
+    ('\u{470}', '\u{470}'), ('\u{472}', '\u{472}'), ('\u{474}', '\u{474}'), ('\u{476}', '\u{476}'),
+    ('\u{478}', '\u{478}'), ('\u{47a}', '\u{47a}'), ('\u{47c}', '\u{47c}'), ('\u{47e}', '\u{47e}'),
+    ('\u{480}', '\u{480}'), ('\u{48a}', '\u{48a}'), ('\u{48c}', '\u{48c}'), ('\u{48e}', '\u{48e}'),
+    ('\u{490}', '\u{490}'), ('\u{492}', '\u{492}'), ('\u{494}', '\u{494}'), ('\u{496}', '\u{496}'),
+    ('\u{498}', '\u{498}'), ('\u{49a}', '\u{49a}'), ('\u{49c}', '\u{49c}'), ('\u{49e}', '\u{49e}'),
+    ('\u{4a0}', '\u{4a0}'), ('\u{4a2}', '\u{4a2}'), ('\u{4a4}', '\u{4a4}'), ('\u{4a6}', '\u{4a6}'),
+    ('\u{4a8}', '\u{4a8}'), ('\u{4aa}', '\u{4aa}'), ('\u{4ac}', '\u{4ac}'), ('\u{4ae}', '\u{4ae}'),
+    ('\u{4b0}', '\u{4b0}'), ('\u{4b2}', '\u{4b2}'), ('\u{4b4}', '\u{4b4}'), ('\u{4b6}', '\u{4b6}'),
+    ('\u{4b8}', '\u{4b8}'), ('\u{4ba}', '\u{4ba}'), ('\u{4bc}', '\u{4bc}'), ('\u{4be}', '\u{4be}'),
+    ('\u{4c0}', '\u{4c1}'), ('\u{4c3}', '\u{4c3}'), ('\u{4c5}', '\u{4c5}'), ('\u{4c7}', '\u{4c7}'),
+    ('\u{4c9}', '\u{4c9}'), ('\u{4cb}', '\u{4cb}'), ('\u{4cd}', '\u{4cd}'), ('\u{4d0}', '\u{4d0}'),
+    ('\u{4d2}', '\u{4d2}'), ('\u{4d4}', '\u{4d4}'), ('\u{4d6}', '\u{4d6}'), ('\u{4d8}', '\u{4d8}'),
+    ('\u{4da}', '\u{4da}'), ('\u{4dc}', '\u{4dc}'), ('\u{4de}', '\u{4de}'), ('\u{4e0}', '\u{4e0}'),
+    ('\u{4e2}', '\u{4e2}'), ('\u{4e4}', '\u{4e4}'), ('\u{4e6}', '\u{4e6}'), ('\u{4e8}', '\u{4e8}'),
+    ('\u{4ea}', '\u{4ea}'), ('\u{4ec}', '\u{4ec}'), ('\u{4ee}', '\u{4ee}'), ('\u{4f0}', '\u{4f0}'),
+    ('\u{4f2}', '\u{4f2}'), ('\u{4f4}', '\u{4f4}'), ('\u{4f6}', '\u{4f6}'), ('\u{4f8}', '\u{4f8}'),
+    ('\u{4fa}', '\u{4fa}'), ('\u{4fc}', '\u{4fc}'), ('\u{4fe}', '\u{4fe}'), ('\u{500}', '\u{500}'),
+    ('\u{502}', '\u{502}'), ('\u{504}', '\u{504}'), ('\u{506}', '\u{506}'), ('\u{508}', '\u{508}'),
+    ('\u{50a}', '\u{50a}'), ('\u{50c}', '\u{50c}'), ('\u{50e}', '\u{50e}'), ('\u{510}', '\u{510}'),
+    ('\u{512}', '\u{512}'), ('\u{514}', '\u{514}'), ('\u{516}', '\u{516}'), ('\u{518}', '\u{518}'),
+    ('\u{51a}', '\u{51a}'), ('\u{51c}', '\u{51c}'), ('\u{51e}', '\u{51e}'), ('\u{520}', '\u{520}'),
+    ('\u{522}', '\u{522}'), ('\u{524}', '\u{524}'), ('\u{526}', '\u{526}'), ('\u{528}', '\u{528}'),
+    ('\u{52a}', '\u{52a}'), ('\u{52c}', '\u{52c}'), ('\u{52e}', '\u{52e}'), ('\u{531}', '\u{556}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{13a0}', '\u{13f5}'),
+    ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{1e00}', '\u{1e00}'), ('\u{1e02}', '\u{1e02}'),
+    ('\u{1e04}', '\u{1e04}'), ('\u{1e06}', '\u{1e06}'), ('\u{1e08}', '\u{1e08}'), ('\u{1e0a}', '\u{1e0a}'),
+    ('\u{1e0c}', '\u{1e0c}'), ('\u{1e0e}', '\u{1e0e}'), ('\u{1e10}', '\u{1e10}'), ('\u{1e12}', '\u{1e12}'),
+    ('\u{1e14}', '\u{1e14}'), ('\u{1e16}', '\u{1e16}'), ('\u{1e18}', '\u{1e18}'), ('\u{1e1a}', '\u{1e1a}'),
+    ('\u{1e1c}', '\u{1e1c}'), ('\u{1e1e}', '\u{1e1e}'), ('\u{1e20}', '\u{1e20}'), ('\u{1e22}', '\u{1e22}'),
+    ('\u{1e24}', '\u{1e24}'), ('\u{1e26}', '\u{1e26}'), ('\u{1e28}', '\u{1e28}'), ('\u{1e2a}', '\u{1e2a}'),
+    ('\u{1e2c}', '\u{1e2c}'), ('\u{1e2e}', '\u{1e2e}'), ('\u{1e30}', '\u{1e30}'), ('\u{1e32}', '\u{1e32}'),
+    ('\u{1e34}', '\u{1e34}'), ('\u{1e36}', '\u{1e36}'), ('\u{1e38}', '\u{1e38}'), ('\u{1e3a}', '\u{1e3a}'),
+    ('\u{1e3c}', '\u{1e3c}'), ('\u{1e3e}', '\u{1e3e}'), ('\u{1e40}', '\u{1e40}'), ('\u{1e42}', '\u{1e42}'),
+    ('\u{1e44}', '\u{1e44}'), ('\u{1e46}', '\u{1e46}'), ('\u{1e48}', '\u{1e48}'), ('\u{1e4a}', '\u{1e4a}'),
+    ('\u{1e4c}', '\u{1e4c}'), ('\u{1e4e}', '\u{1e4e}'), ('\u{1e50}', '\u{1e50}'), ('\u{1e52}', '\u{1e52}'),
+    ('\u{1e54}', '\u{1e54}'), ('\u{1e56}', '\u{1e56}'), ('\u{1e58}', '\u{1e58}'), ('\u{1e5a}', '\u{1e5a}'),
+    ('\u{1e5c}', '\u{1e5c}'), ('\u{1e5e}', '\u{1e5e}'), ('\u{1e60}', '\u{1e60}'), ('\u{1e62}', '\u{1e62}'),
+    ('\u{1e64}', '\u{1e64}'), ('\u{1e66}', '\u{1e66}'), ('\u{1e68}', '\u{1e68}'), ('\u{1e6a}', '\u{1e6a}'),
+    ('\u{1e6c}', '\u{1e6c}'), ('\u{1e6e}', '\u{1e6e}'), ('\u{1e70}', '\u{1e70}'), ('\u{1e72}', '\u{1e72}'),
+    ('\u{1e74}', '\u{1e74}'), ('\u{1e76}', '\u{1e76}'), ('\u{1e78}', '\u{1e78}'), ('\u{1e7a}', '\u{1e7a}'),
+    ('\u{1e7c}', '\u{1e7c}'), ('\u{1e7e}', '\u{1e7e}'), ('\u{1e80}', '\u{1e80}'), ('\u{1e82}', '\u{1e82}'),
+    ('\u{1e84}', '\u{1e84}'), ('\u{1e86}', '\u{1e86}'), ('\u{1e88}', '\u{1e88}'), ('\u{1e8a}', '\u{1e8a}'),
+    ('\u{1e8c}', '\u{1e8c}'), ('\u{1e8e}', '\u{1e8e}'), ('\u{1e90}', '\u{1e90}'), ('\u{1e92}', '\u{1e92}'),
+    ('\u{1e94}', '\u{1e94}'), ('\u{1e9e}', '\u{1e9e}'), ('\u{1ea0}', '\u{1ea0}'), ('\u{1ea2}', '\u{1ea2}'),
+    ('\u{1ea4}', '\u{1ea4}'), ('\u{1ea6}', '\u{1ea6}'), ('\u{1ea8}', '\u{1ea8}'), ('\u{1eaa}', '\u{1eaa}'),
+    ('\u{1eac}', '\u{1eac}'), ('\u{1eae}', '\u{1eae}'), ('\u{1eb0}', '\u{1eb0}'), ('\u{1eb2}', '\u{1eb2}'),
+    ('\u{1eb4}', '\u{1eb4}'), ('\u{1eb6}', '\u{1eb6}'), ('\u{1eb8}', '\u{1eb8}'), ('\u{1eba}', '\u{1eba}'),
+    ('\u{1ebc}', '\u{1ebc}'), ('\u{1ebe}', '\u{1ebe}'), ('\u{1ec0}', '\u{1ec0}'), ('\u{1ec2}', '\u{1ec2}'),
+    ('\u{1ec4}', '\u{1ec4}'), ('\u{1ec6}', '\u{1ec6}'), ('\u{1ec8}', '\u{1ec8}'), ('\u{1eca}', '\u{1eca}'),
+    ('\u{1ecc}', '\u{1ecc}'), ('\u{1ece}', '\u{1ece}'), ('\u{1ed0}', '\u{1ed0}'), ('\u{1ed2}', '\u{1ed2}'),
+    ('\u{1ed4}', '\u{1ed4}'), ('\u{1ed6}', '\u{1ed6}'), ('\u{1ed8}', '\u{1ed8}'), ('\u{1eda}', '\u{1eda}'),
+    ('\u{1edc}', '\u{1edc}'), ('\u{1ede}', '\u{1ede}'), ('\u{1ee0}', '\u{1ee0}'), ('\u{1ee2}', '\u{1ee2}'),
+    ('\u{1ee4}', '\u{1ee4}'), ('\u{1ee6}', '\u{1ee6}'), ('\u{1ee8}', '\u{1ee8}'), ('\u{1eea}', '\u{1eea}'),
+    ('\u{1eec}', '\u{1eec}'), ('\u{1eee}', '\u{1eee}'), ('\u{1ef0}', '\u{1ef0}'), ('\u{1ef2}', '\u{1ef2}'),
+    ('\u{1ef4}', '\u{1ef4}'), ('\u{1ef6}', '\u{1ef6}'), ('\u{1ef8}', '\u{1ef8}'), ('\u{1efa}', '\u{1efa}'),
+    ('\u{1efc}', '\u{1efc}'), ('\u{1efe}', '\u{1efe}'), ('\u{1f08}', '\u{1f0f}'), ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f28}', '\u{1f2f}'), ('\u{1f38}', '\u{1f3f}'), ('\u{1f48}', '\u{1f4d}'), ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'), ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f5f}'), ('\u{1f68}', '\u{1f6f}'),
+    ('\u{1fb8}', '\u{1fbb}'), ('\u{1fc8}', '\u{1fcb}'), ('\u{1fd8}', '\u{1fdb}'), ('\u{1fe8}', '\u{1fec}'),
+    ('\u{1ff8}', '\u{1ffb}'), ('\u{2102}', '\u{2102}'), ('\u{2107}', '\u{2107}'), ('\u{210b}', '\u{210d}'),
+    ('\u{2110}', '\u{2112}'), ('\u{2115}', '\u{2115}'), ('\u{2119}', '\u{211d}'), ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{212d}'), ('\u{2130}', '\u{2133}'),
+    ('\u{213e}', '\u{213f}'), ('\u{2145}', '\u{2145}'), ('\u{2183}', '\u{2183}'), ('\u{2c00}', '\u{2c2f}'),
+    ('\u{2c60}', '\u{2c60}'), ('\u{2c62}', '\u{2c64}'), ('\u{2c67}', '\u{2c67}'), ('\u{2c69}', '\u{2c69}'),
+    ('\u{2c6b}', '\u{2c6b}'), ('\u{2c6d}', '\u{2c70}'), ('\u{2c72}', '\u{2c72}'), ('\u{2c75}', '\u{2c75}'),
+    ('\u{2c7e}', '\u{2c80}'), ('\u{2c82}', '\u{2c82}'), ('\u{2c84}', '\u{2c84}'), ('\u{2c86}', '\u{2c86}'),
+    ('\u{2c88}', '\u{2c88}'), ('\u{2c8a}', '\u{2c8a}'), ('\u{2c8c}', '\u{2c8c}'), ('\u{2c8e}', '\u{2c8e}'),
+    ('\u{2c90}', '\u{2c90}'), ('\u{2c92}', '\u{2c92}'), ('\u{2c94}', '\u{2c94}'), ('\u{2c96}', '\u{2c96}'),
+    ('\u{2c98}', '\u{2c98}'), ('\u{2c9a}', '\u{2c9a}'), ('\u{2c9c}', '\u{2c9c}'), ('\u{2c9e}', '\u{2c9e}'),
+    ('\u{2ca0}', '\u{2ca0}'), ('\u{2ca2}', '\u{2ca2}'), ('\u{2ca4}', '\u{2ca4}'), ('\u{2ca6}', '\u{2ca6}'),
+    ('\u{2ca8}', '\u{2ca8}'), ('\u{2caa}', '\u{2caa}'), ('\u{2cac}', '\u{2cac}'), ('\u{2cae}', '\u{2cae}'),
+    ('\u{2cb0}', '\u{2cb0}'), ('\u{2cb2}', '\u{2cb2}'), ('\u{2cb4}', '\u{2cb4}'), ('\u{2cb6}', '\u{2cb6}'),
+    ('\u{2cb8}', '\u{2cb8}'), ('\u{2cba}', '\u{2cba}'), ('\u{2cbc}', '\u{2cbc}'), ('\u{2cbe}', '\u{2cbe}'),
+    ('\u{2cc0}', '\u{2cc0}'), ('\u{2cc2}', '\u{2cc2}'), ('\u{2cc4}', '\u{2cc4}'), ('\u{2cc6}', '\u{2cc6}'),
+    ('\u{2cc8}', '\u{2cc8}'), ('\u{2cca}', '\u{2cca}'), ('\u{2ccc}', '\u{2ccc}'), ('\u{2cce}', '\u{2cce}'),
+    ('\u{2cd0}', '\u{2cd0}'), ('\u{2cd2}', '\u{2cd2}'), ('\u{2cd4}', '\u{2cd4}'), ('\u{2cd6}', '\u{2cd6}'),
+    ('\u{2cd8}', '\u{2cd8}'), ('\u{2cda}', '\u{2cda}'), ('\u{2cdc}', '\u{2cdc}'), ('\u{2cde}', '\u{2cde}'),
+    ('\u{2ce0}', '\u{2ce0}'), ('\u{2ce2}', '\u{2ce2}'), ('\u{2ceb}', '\u{2ceb}'), ('\u{2ced}', '\u{2ced}'),
+    ('\u{2cf2}', '\u{2cf2}'), ('\u{a640}', '\u{a640}'), ('\u{a642}', '\u{a642}'), ('\u{a644}', '\u{a644}'),
+    ('\u{a646}', '\u{a646}'), ('\u{a648}', '\u{a648}'), ('\u{a64a}', '\u{a64a}'), ('\u{a64c}', '\u{a64c}'),
+    ('\u{a64e}', '\u{a64e}'), ('\u{a650}', '\u{a650}'), ('\u{a652}', '\u{a652}'), ('\u{a654}', '\u{a654}'),
+    ('\u{a656}', '\u{a656}'), ('\u{a658}', '\u{a658}'), ('\u{a65a}', '\u{a65a}'), ('\u{a65c}', '\u{a65c}'),
+    ('\u{a65e}', '\u{a65e}'), ('\u{a660}', '\u{a660}'), ('\u{a662}', '\u{a662}'), ('\u{a664}', '\u{a664}'),
+    ('\u{a666}', '\u{a666}'), ('\u{a668}', '\u{a668}'), ('\u{a66a}', '\u{a66a}'), ('\u{a66c}', '\u{a66c}'),
+    ('\u{a680}', '\u{a680}'), ('\u{a682}', '\u{a682}'), ('\u{a684}', '\u{a684}'), ('\u{a686}', '\u{a686}'),
+    ('\u{a688}', '\u{a688}'), ('\u{a68a}', '\u{a68a}'), ('\u{a68c}', '\u{a68c}'), ('\u{a68e}', '\u{a68e}'),
+    ('\u{a690}', '\u{a690}'), ('\u{a692}', '\u{a692}'), ('\u{a694}', '\u{a694}'), ('\u{a696}', '\u{a696}'),
+    ('\u{a698}', '\u{a698}'), ('\u{a69a}', '\u{a69a}'), ('\u{a722}', '\u{a722}'), ('\u{a724}', '\u{a724}'),
+    ('\u{a726}', '\u{a726}'), ('\u{a728}', '\u{a728}'), ('\u{a72a}', '\u{a72a}'), ('\u{a72c}', '\u{a72c}'),
+    ('\u{a72e}', '\u{a72e}'), ('\u{a732}', '\u{a732}'), ('\u{a734}', '\u{a734}'), ('\u{a736}', '\u{a736}'),
+    ('\u{a738}', '\u{a738}'), ('\u{a73a}', '\u{a73a}'), ('\u{a73c}', '\u{a73c}'), ('\u{a73e}', '\u{a73e}'),
+    ('\u{a740}', '\u{a740}'), ('\u{a742}', '\u{a742}'), ('\u{a744}', '\u{a744}'), ('\u{a746}', '\u{a746}'),
+    ('\u{a748}', '\u{a748}'), ('\u{a74a}', '\u{a74a}'), ('\u{a74c}', '\u{a74c}'), ('\u{a74e}', '\u{a74e}'),
+    ('\u{a750}', '\u{a750}'), ('\u{a752}', '\u{a752}'), ('\u{a754}', '\u{a754}'), ('\u{a756}', '\u{a756}'),
+    ('\u{a758}', '\u{a758}'), ('\u{a75a}', '\u{a75a}'), ('\u{a75c}', '\u{a75c}'), ('\u{a75e}', '\u{a75e}'),
+    ('\u{a760}', '\u{a760}'), ('\u{a762}', '\u{a762}'), ('\u{a764}', '\u{a764}'), ('\u{a766}', '\u{a766}'),
+    ('\u{a768}', '\u{a768}'), ('\u{a76a}', '\u{a76a}'), ('\u{a76c}', '\u{a76c}'), ('\u{a76e}', '\u{a76e}'),
+    ('\u{a779}', '\u{a779}'), ('\u{a77b}', '\u{a77b}'), ('\u{a77d}', '\u{a77e}'), ('\u{a780}', '\u{a780}'),
+    ('\u{a782}', '\u{a782}'), ('\u{a784}', '\u{a784}'), ('\u{a786}', '\u{a786}'), ('\u{a78b}', '\u{a78b}'),
+    ('\u{a78d}', '\u{a78d}'), ('\u{a790}', '\u{a790}'), ('\u{a792}', '\u{a792}'), ('\u{a796}', '\u{a796}'),
+    ('\u{a798}', '\u{a798}'), ('\u{a79a}', '\u{a79a}'), ('\u{a79c}', '\u{a79c}'), ('\u{a79e}', '\u{a79e}'),
+    ('\u{a7a0}', '\u{a7a0}'), ('\u{a7a2}', '\u{a7a2}'), ('\u{a7a4}', '\u{a7a4}'), ('\u{a7a6}', '\u{a7a6}'),
+    ('\u{a7a8}', '\u{a7a8}'), ('\u{a7aa}', '\u{a7ae}'), ('\u{a7b0}', '\u{a7b4}'), ('\u{a7b6}', '\u{a7b6}'),
+    ('\u{a7b8}', '\u{a7b8}'), ('\u{a7ba}', '\u{a7ba}'), ('\u{a7bc}', '\u{a7bc}'), ('\u{a7be}', '\u{a7be}'),
+    ('\u{a7c0}', '\u{a7c0}'), ('\u{a7c2}', '\u{a7c2}'), ('\u{a7c4}', '\u{a7c7}'), ('\u{a7c9}', '\u{a7c9}'),
+    ('\u{a7d0}', '\u{a7d0}'), ('\u{a7d6}', '\u{a7d6}'), ('\u{a7d8}', '\u{a7d8}'), ('\u{a7f5}', '\u{a7f5}'),
+    ('\u{ff21}', '\u{ff3a}'), ('\u{10400}', '\u{10427}'), ('\u{104b0}', '\u{104d3}'), ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'), ('\u{10594}', '\u{10595}'), ('\u{10c80}', '\u{10cb2}'),
+    ('\u{118a0}', '\u{118bf}'), ('\u{16e40}', '\u{16e5f}'), ('\u{1d400}', '\u{1d419}'), ('\u{1d434}', '\u{1d44d}'),
+    ('\u{1d468}', '\u{1d481}'), ('\u{1d49c}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b5}'), ('\u{1d4d0}', '\u{1d4e9}'),
+    ('\u{1d504}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d538}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'), ('\u{1d56c}', '\u{1d585}'), ('\u{1d5a0}', '\u{1d5b9}'), ('\u{1d5d4}', '\u{1d5ed}'),
+    ('\u{1d608}', '\u{1d621}'), ('\u{1d63c}', '\u{1d655}'), ('\u{1d670}', '\u{1d689}'), ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6e2}', '\u{1d6fa}'), ('\u{1d71c}', '\u{1d734}'), ('\u{1d756}', '\u{1d76e}'), ('\u{1d790}', '\u{1d7a8}'),
+    ('\u{1d7ca}', '\u{1d7ca}'), ('\u{1e900}', '\u{1e921}'),
+];
+
+pub const GC_M: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'), ('\u{483}', '\u{489}'), ('\u{591}', '\u{5bd}'), ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'), ('\u{5c4}', '\u{5c5}'), ('\u{5c7}', '\u{5c7}'), ('\u{610}', '\u{61a}'),
+    ('\u{64b}', '\u{65f}'), ('\u{670}', '\u{670}'), ('\u{6d6}', '\u{6dc}'), ('\u{6df}', '\u{6e4}'),
+    ('\u{6e7}', '\u{6e8}'), ('\u{6ea}', '\u{6ed}'), ('\u{711}', '\u{711}'), ('\u{730}', '\u{74a}'),
+    ('\u{7a6}', '\u{7b0}'), ('\u{7eb}', '\u{7f3}'), ('\u{7fd}', '\u{7fd}'), ('\u{816}', '\u{819}'),
+    ('\u{81b}', '\u{823}'), ('\u{825}', '\u{827}'), ('\u{829}', '\u{82d}'), ('\u{859}', '\u{85b}'),
+    ('\u{898}', '\u{89f}'), ('\u{8ca}', '\u{8e1}'), ('\u{8e3}', '\u{903}'), ('\u{93a}', '\u{93c}'),
+    ('\u{93e}', '\u{94f}'), ('\u{951}', '\u{957}'), ('\u{962}', '\u{963}'), ('\u{981}', '\u{983}'),
+    ('\u{9bc}', '\u{9bc}'), ('\u{9be}', '\u{9c4}'), ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9cd}'),
+    ('\u{9d7}', '\u{9d7}'), ('\u{9e2}', '\u{9e3}'), ('\u{9fe}', '\u{9fe}'), ('\u{a01}', '\u{a03}'),
+    ('\u{a3c}', '\u{a3c}'), ('\u{a3e}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a70}', '\u{a71}'), ('\u{a75}', '\u{a75}'), ('\u{a81}', '\u{a83}'),
+    ('\u{abc}', '\u{abc}'), ('\u{abe}', '\u{ac5}'), ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acd}'),
+    ('\u{ae2}', '\u{ae3}'), ('\u{afa}', '\u{aff}'), ('\u{b01}', '\u{b03}'), ('\u{b3c}', '\u{b3c}'),
+    ('\u{b3e}', '\u{b44}'), ('\u{b47}', '\u{b48}'), ('\u{b4b}', '\u{b4d}'), ('\u{b55}', '\u{b57}'),
+    ('\u{b62}', '\u{b63}'), ('\u{b82}', '\u{b82}'), ('\u{bbe}', '\u{bc2}'), ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'), ('\u{bd7}', '\u{bd7}'), ('\u{c00}', '\u{c04}'), ('\u{c3c}', '\u{c3c}'),
+    ('\u{c3e}', '\u{c44}'), ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4d}'), ('\u{c55}', '\u{c56}'),
+    ('\u{c62}', '\u{c63}'), ('\u{c81}', '\u{c83}'), ('\u{cbc}', '\u{cbc}'), ('\u{cbe}', '\u{cc4}'),
+    ('\u{cc6}', '\u{cc8}'), ('\u{cca}', '\u{ccd}'), ('\u{cd5}', '\u{cd6}'), ('\u{ce2}', '\u{ce3}'),
+    ('\u{d00}', '\u{d03}'), ('\u{d3b}', '\u{d3c}'), ('\u{d3e}', '\u{d44}'), ('\u{d46}', '\u{d48}'),
+    ('\u{d4a}', '\u{d4d}'), ('\u{d57}', '\u{d57}'), ('\u{d62}', '\u{d63}'), ('\u{d81}', '\u{d83}'),
+    ('\u{dca}', '\u{dca}'), ('\u{dcf}', '\u{dd4}'), ('\u{dd6}', '\u{dd6}'), ('\u{dd8}', '\u{ddf}'),
+    ('\u{df2}', '\u{df3}'), ('\u{e31}', '\u{e31}'), ('\u{e34}', '\u{e3a}'), ('\u{e47}', '\u{e4e}'),
+    ('\u{eb1}', '\u{eb1}'), ('\u{eb4}', '\u{ebc}'), ('\u{ec8}', '\u{ecd}'), ('\u{f18}', '\u{f19}'),
+    ('\u{f35}', '\u{f35}'), ('\u{f37}', '\u{f37}'), ('\u{f39}', '\u{f39}'), ('\u{f3e}', '\u{f3f}'),
+    ('\u{f71}', '\u{f84}'), ('\u{f86}', '\u{f87}'), ('\u{f8d}', '\u{f97}'), ('\u{f99}', '\u{fbc}'),
+    ('\u{fc6}', '\u{fc6}'), ('\u{102b}', '\u{103e}'), ('\u{1056}', '\u{1059}'), ('\u{105e}', '\u{1060}'),
+    ('\u{1062}', '\u{1064}'), ('\u{1067}', '\u{106d}'), ('\u{1071}', '\u{1074}'), ('\u{1082}', '\u{108d}'),
+    ('\u{108f}', '\u{108f}'), ('\u{109a}', '\u{109d}'), ('\u{135d}', '\u{135f}'), ('\u{1712}', '\u{1715}'),
+    ('\u{1732}', '\u{1734}'), ('\u{1752}', '\u{1753}'), ('\u{1772}', '\u{1773}'), ('\u{17b4}', '\u{17d3}'),
+    ('\u{17dd}', '\u{17dd}'), ('\u{180b}', '\u{180d}'), ('\u{180f}', '\u{180f}'), ('\u{1885}', '\u{1886}'),
+    ('\u{18a9}', '\u{18a9}'), ('\u{1920}', '\u{192b}'), ('\u{1930}', '\u{193b}'), ('\u{1a17}', '\u{1a1b}'),
+    ('\u{1a55}', '\u{1a5e}'), ('\u{1a60}', '\u{1a7c}'), ('\u{1a7f}', '\u{1a7f}'), ('\u{1ab0}', '\u{1ace}'),
+    ('\u{1b00}', '\u{1b04}'), ('\u{1b34}', '\u{1b44}'), ('\u{1b6b}', '\u{1b73}'), ('\u{1b80}', '\u{1b82}'),
+    ('\u{1ba1}', '\u{1bad}'), ('\u{1be6}', '\u{1bf3}'), ('\u{1c24}', '\u{1c37}'), ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce8}'), ('\u{1ced}', '\u{1ced}'), ('\u{1cf4}', '\u{1cf4}'), ('\u{1cf7}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'), ('\u{20d0}', '\u{20f0}'), ('\u{2cef}', '\u{2cf1}'), ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'), ('\u{302a}', '\u{302f}'), ('\u{3099}', '\u{309a}'), ('\u{a66f}', '\u{a672}'),
+    ('\u{a674}', '\u{a67d}'), ('\u{a69e}', '\u{a69f}'), ('\u{a6f0}', '\u{a6f1}'), ('\u{a802}', '\u{a802}'),
+    ('\u{a806}', '\u{a806}'), ('\u{a80b}', '\u{a80b}'), ('\u{a823}', '\u{a827}'), ('\u{a82c}', '\u{a82c}'),
+    ('\u{a880}', '\u{a881}'), ('\u{a8b4}', '\u{a8c5}'), ('\u{a8e0}', '\u{a8f1}'), ('\u{a8ff}', '\u{a8ff}'),
+    ('\u{a926}', '\u{a92d}'), ('\u{a947}', '\u{a953}'), ('\u{a980}', '\u{a983}'), ('\u{a9b3}', '\u{a9c0}'),
+    ('\u{a9e5}', '\u{a9e5}'), ('\u{aa29}', '\u{aa36}'), ('\u{aa43}', '\u{aa43}'), ('\u{aa4c}', '\u{aa4d}'),
+    ('\u{aa7b}', '\u{aa7d}'), ('\u{aab0}', '\u{aab0}'), ('\u{aab2}', '\u{aab4}'), ('\u{aab7}', '\u{aab8}'),
+    ('\u{aabe}', '\u{aabf}'), ('\u{aac1}', '\u{aac1}'), ('\u{aaeb}', '\u{aaef}'), ('\u{aaf5}', '\u{aaf6}'),
+    ('\u{abe3}', '\u{abea}'), ('\u{abec}', '\u{abed}'), ('\u{fb1e}', '\u{fb1e}'), ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'), ('\u{101fd}', '\u{101fd}'), ('\u{102e0}', '\u{102e0}'), ('\u{10376}', '\u{1037a}'),
+    ('\u{10a01}', '\u{10a03}'), ('\u{10a05}', '\u{10a06}'), ('\u{10a0c}', '\u{10a0f}'), ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'), ('\u{10ae5}', '\u{10ae6}'), ('\u{10d24}', '\u{10d27}'), ('\u{10eab}', '\u{10eac}'),
+    ('\u{10f46}', '\u{10f50}'), ('\u{10f82}', '\u{10f85}'), ('\u{11000}', '\u{11002}'), ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'), ('\u{11073}', '\u{11074}'), ('\u{1107f}', '\u{11082}'), ('\u{110b0}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'), ('\u{11100}', '\u{11102}'), ('\u{11127}', '\u{11134}'), ('\u{11145}', '\u{11146}'),
+    ('\u{11173}', '\u{11173}'), ('\u{11180}', '\u{11182}'), ('\u{111b3}', '\u{111c0}'), ('\u{111c9}', '\u{111cc}'),
+    ('\u{111ce}', '\u{111cf}'), ('\u{1122c}', '\u{11237}'), ('\u{1123e}', '\u{1123e}'), ('\u{112df}', '\u{112ea}'),
+    ('\u{11300}', '\u{11303}'), ('\u{1133b}', '\u{1133c}'), ('\u{1133e}', '\u{11344}'), ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'), ('\u{11357}', '\u{11357}'), ('\u{11362}', '\u{11363}'), ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'), ('\u{11435}', '\u{11446}'), ('\u{1145e}', '\u{1145e}'), ('\u{114b0}', '\u{114c3}'),
+    ('\u{115af}', '\u{115b5}'), ('\u{115b8}', '\u{115c0}'), ('\u{115dc}', '\u{115dd}'), ('\u{11630}', '\u{11640}'),
+    ('\u{116ab}', '\u{116b7}'), ('\u{1171d}', '\u{1172b}'), ('\u{1182c}', '\u{1183a}'), ('\u{11930}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'), ('\u{1193b}', '\u{1193e}'), ('\u{11940}', '\u{11940}'), ('\u{11942}', '\u{11943}'),
+    ('\u{119d1}', '\u{119d7}'), ('\u{119da}', '\u{119e0}'), ('\u{119e4}', '\u{119e4}'), ('\u{11a01}', '\u{11a0a}'),
+    ('\u{11a33}', '\u{11a39}'), ('\u{11a3b}', '\u{11a3e}'), ('\u{11a47}', '\u{11a47}'), ('\u{11a51}', '\u{11a5b}'),
+    ('\u{11a8a}', '\u{11a99}'), ('\u{11c2f}', '\u{11c36}'), ('\u{11c38}', '\u{11c3f}'), ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11ca9}', '\u{11cb6}'), ('\u{11d31}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'), ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d45}'), ('\u{11d47}', '\u{11d47}'), ('\u{11d8a}', '\u{11d8e}'), ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d93}', '\u{11d97}'), ('\u{11ef3}', '\u{11ef6}'), ('\u{16af0}', '\u{16af4}'), ('\u{16b30}', '\u{16b36}'),
+    ('\u{16f4f}', '\u{16f4f}'), ('\u{16f51}', '\u{16f87}'), ('\u{16f8f}', '\u{16f92}'), ('\u{16fe4}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'), ('\u{1bc9d}', '\u{1bc9e}'), ('\u{1cf00}', '\u{1cf2d}'), ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'), ('\u{1d16d}', '\u{1d172}'), ('\u{1d17b}', '\u{1d182}'), ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'), ('\u{1d242}', '\u{1d244}'), ('\u{1da00}', '\u{1da36}'), ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'), ('\u{1da84}', '\u{1da84}'), ('\u{1da9b}', '\u{1da9f}'), ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'), ('\u{1e01b}', '\u{1e021}'), ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'), ('\u{1e130}', '\u{1e136}'), ('\u{1e2ae}', '\u{1e2ae}'), ('\u{1e2ec}', '\u{1e2ef}'),
+    ('\u{1e8d0}', '\u{1e8d6}'), ('\u{1e944}', '\u{1e94a}'), ('\u{e0100}', '\u{e01ef}'),
+];
+
+pub const GC_MC: &[(char, char)] = &[
+    ('\u{903}', '\u{903}'), ('\u{93b}', '\u{93b}'), ('\u{93e}', '\u{940}'), ('\u{949}', '\u{94c}'),
+    ('\u{94e}', '\u{94f}'), ('\u{982}', '\u{983}'), ('\u{9be}', '\u{9c0}'), ('\u{9c7}', '\u{9c8}'),
+    ('\u{9cb}', '\u{9cc}'), ('\u{9d7}', '\u{9d7}'), ('\u{a03}', '\u{a03}'), ('\u{a3e}', '\u{a40}'),
+    ('\u{a83}', '\u{a83}'), ('\u{abe}', '\u{ac0}'), ('\u{ac9}', '\u{ac9}'), ('\u{acb}', '\u{acc}'),
+    ('\u{b02}', '\u{b03}'), ('\u{b3e}', '\u{b3e}'), ('\u{b40}', '\u{b40}'), ('\u{b47}', '\u{b48}'),
+    ('\u{b4b}', '\u{b4c}'), ('\u{b57}', '\u{b57}'), ('\u{bbe}', '\u{bbf}'), ('\u{bc1}', '\u{bc2}'),
+    ('\u{bc6}', '\u{bc8}'), ('\u{bca}', '\u{bcc}'), ('\u{bd7}', '\u{bd7}'), ('\u{c01}', '\u{c03}'),
+    ('\u{c41}', '\u{c44}'), ('\u{c82}', '\u{c83}'), ('\u{cbe}', '\u{cbe}'), ('\u{cc0}', '\u{cc4}'),
+    ('\u{cc7}', '\u{cc8}'), ('\u{cca}', '\u{ccb}'), ('\u{cd5}', '\u{cd6}'), ('\u{d02}', '\u{d03}'),
+    ('\u{d3e}', '\u{d40}'), ('\u{d46}', '\u{d48}'), ('\u{d4a}', '\u{d4c}'), ('\u{d57}', '\u{d57}'),
+    ('\u{d82}', '\u{d83}'), ('\u{dcf}', '\u{dd1}'), ('\u{dd8}', '\u{ddf}'), ('\u{df2}', '\u{df3}'),
+    ('\u{f3e}', '\u{f3f}'), ('\u{f7f}', '\u{f7f}'), ('\u{102b}', '\u{102c}'), ('\u{1031}', '\u{1031}'),
+    ('\u{1038}', '\u{1038}'), ('\u{103b}', '\u{103c}'), ('\u{1056}', '\u{1057}'), ('\u{1062}', '\u{1064}'),
+    ('\u{1067}', '\u{106d}'), ('\u{1083}', '\u{1084}'), ('\u{1087}', '\u{108c}'), ('\u{108f}', '\u{108f}'),
+    ('\u{109a}', '\u{109c}'), ('\u{1715}', '\u{1715}'), ('\u{1734}', '\u{1734}'), ('\u{17b6}', '\u{17b6}'),
+    ('\u{17be}', '\u{17c5}'), ('\u{17c7}', '\u{17c8}'), ('\u{1923}', '\u{1926}'), ('\u{1929}', '\u{192b}'),
+    ('\u{1930}', '\u{1931}'), ('\u{1933}', '\u{1938}'), ('\u{1a19}', '\u{1a1a}'), ('\u{1a55}', '\u{1a55}'),
+    ('\u{1a57}', '\u{1a57}'), ('\u{1a61}', '\u{1a61}'), ('\u{1a63}', '\u{1a64}'), ('\u{1a6d}', '\u{1a72}'),
+    ('\u{1b04}', '\u{1b04}'), ('\u{1b35}', '\u{1b35}'), ('\u{1b3b}', '\u{1b3b}'), ('\u{1b3d}', '\u{1b41}'),
+    ('\u{1b43}', '\u{1b44}'), ('\u{1b82}', '\u{1b82}'), ('\u{1ba1}', '\u{1ba1}'), ('\u{1ba6}', '\u{1ba7}'),
+    ('\u{1baa}', '\u{1baa}'), ('\u{1be7}', '\u{1be7}'), ('\u{1bea}', '\u{1bec}'), ('\u{1bee}', '\u{1bee}'),
+    ('\u{1bf2}', '\u{1bf3}'), ('\u{1c24}', '\u{1c2b}'), ('\u{1c34}', '\u{1c35}'), ('\u{1ce1}', '\u{1ce1}'),
+    ('\u{1cf7}', '\u{1cf7}'), ('\u{302e}', '\u{302f}'), ('\u{a823}', '\u{a824}'), ('\u{a827}', '\u{a827}'),
+    ('\u{a880}', '\u{a881}'), ('\u{a8b4}', '\u{a8c3}'), ('\u{a952}', '\u{a953}'), ('\u{a983}', '\u{a983}'),
+    ('\u{a9b4}', '\u{a9b5}'), ('\u{a9ba}', '\u{a9bb}'), ('\u{a9be}', '\u{a9c0}'), ('\u{aa2f}', '\u{aa30}'),
+    ('\u{aa33}', '\u{aa34}'), ('\u{aa4d}', '\u{aa4d}'), ('\u{aa7b}', '\u{aa7b}'), ('\u{aa7d}', '\u{aa7d}'),
+    ('\u{aaeb}', '\u{aaeb}'), ('\u{aaee}', '\u{aaef}'), ('\u{aaf5}', '\u{aaf5}'), ('\u{abe3}', '\u{abe4}'),
+    ('\u{abe6}', '\u{abe7}'), ('\u{abe9}', '\u{abea}'), ('\u{abec}', '\u{abec}'), ('\u{11000}', '\u{11000}'),
+    ('\u{11002}', '\u{11002}'), ('\u{11082}', '\u{11082}'), ('\u{110b0}', '\u{110b2}'), ('\u{110b7}', '\u{110b8}'),
+    ('\u{1112c}', '\u{1112c}'), ('\u{11145}', '\u{11146}'), ('\u{11182}', '\u{11182}'), ('\u{111b3}', '\u{111b5}'),
+    ('\u{111bf}', '\u{111c0}'), ('\u{111ce}', '\u{111ce}'), ('\u{1122c}', '\u{1122e}'), ('\u{11232}', '\u{11233}'),
+    ('\u{11235}', '\u{11235}'), ('\u{112e0}', '\u{112e2}'), ('\u{11302}', '\u{11303}'), ('\u{1133e}', '\u{1133f}'),
+    ('\u{11341}', '\u{11344}'), ('\u{11347}', '\u{11348}'), ('\u{1134b}', '\u{1134d}'), ('\u{11357}', '\u{11357}'),
+    ('\u{11362}', '\u{11363}'), ('\u{11435}', '\u{11437}'), ('\u{11440}', '\u{11441}'), ('\u{11445}', '\u{11445}'),
+    ('\u{114b0}', '\u{114b2}'), ('\u{114b9}', '\u{114b9}'), ('\u{114bb}', '\u{114be}'), ('\u{114c1}', '\u{114c1}'),
+    ('\u{115af}', '\u{115b1}'), ('\u{115b8}', '\u{115bb}'), ('\u{115be}', '\u{115be}'), ('\u{11630}', '\u{11632}'),
+    ('\u{1163b}', '\u{1163c}'), ('\u{1163e}', '\u{1163e}'), ('\u{116ac}', '\u{116ac}'), ('\u{116ae}', '\u{116af}'),
+    ('\u{116b6}', '\u{116b6}'), ('\u{11720}', '\u{11721}'), ('\u{11726}', '\u{11726}'), ('\u{1182c}', '\u{1182e}'),
+    ('\u{11838}', '\u{11838}'), ('\u{11930}', '\u{11935}'), ('\u{11937}', '\u{11938}'), ('\u{1193d}', '\u{1193d}'),
+    ('\u{11940}', '\u{11940}'), ('\u{11942}', '\u{11942}'), ('\u{119d1}', '\u{119d3}'), ('\u{119dc}', '\u{119df}'),
+    ('\u{119e4}', '\u{119e4}'), ('\u{11a39}', '\u{11a39}'), ('\u{11a57}', '\u{11a58}'), ('\u{11a97}', '\u{11a97}'),
+    ('\u{11c2f}', '\u{11c2f}'), ('\u{11c3e}', '\u{11c3e}'), ('\u{11ca9}', '\u{11ca9}'), ('\u{11cb1}', '\u{11cb1}'),
+    ('\u{11cb4}', '\u{11cb4}'), ('\u{11d8a}', '\u{11d8e}'), ('\u{11d93}', '\u{11d94}'), ('\u{11d96}', '\u{11d96}'),
+    ('\u{11ef5}', '\u{11ef6}'), ('\u{16f51}', '\u{16f87}'), ('\u{16ff0}', '\u{16ff1}'), ('\u{1d165}', '\u{1d166}'),
+    ('\u{1d16d}', '\u{1d172}'),
+];
+
+pub const GC_ME: &[(char, char)] = &[
+    ('\u{488}', '\u{489}'), ('\u{1abe}', '\u{1abe}'), ('\u{20dd}', '\u{20e0}'), ('\u{20e2}', '\u{20e4}'),
+    ('\u{a670}', '\u{a672}'),
+];
+
+pub const GC_MN: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'), ('\u{483}', '\u{487}'), ('\u{591}', '\u{5bd}'), ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'), ('\u{5c4}', '\u{5c5}'), ('\u{5c7}', '\u{5c7}'), ('\u{610}', '\u{61a}'),
+    ('\u{64b}', '\u{65f}'), ('\u{670}', '\u{670}'), ('\u{6d6}', '\u{6dc}'), ('\u{6df}', '\u{6e4}'),
+    ('\u{6e7}', '\u{6e8}'), ('\u{6ea}', '\u{6ed}'), ('\u{711}', '\u{711}'), ('\u{730}', '\u{74a}'),
+    ('\u{7a6}', '\u{7b0}'), ('\u{7eb}', '\u{7f3}'), ('\u{7fd}', '\u{7fd}'), ('\u{816}', '\u{819}'),
+    ('\u{81b}', '\u{823}'), ('\u{825}', '\u{827}'), ('\u{829}', '\u{82d}'), ('\u{859}', '\u{85b}'),
+    ('\u{898}', '\u{89f}'), ('\u{8ca}', '\u{8e1}'), ('\u{8e3}', '\u{902}'), ('\u{93a}', '\u{93a}'),
+    ('\u{93c}', '\u{93c}'), ('\u{941}', '\u{948}'), ('\u{94d}', '\u{94d}'), ('\u{951}', '\u{957}'),
+    ('\u{962}', '\u{963}'), ('\u{981}', '\u{981}'), ('\u{9bc}', '\u{9bc}'), ('\u{9c1}', '\u{9c4}'),
+    ('\u{9cd}', '\u{9cd}'), ('\u{9e2}', '\u{9e3}'), ('\u{9fe}', '\u{9fe}'), ('\u{a01}', '\u{a02}'),
+    ('\u{a3c}', '\u{a3c}'), ('\u{a41}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a70}', '\u{a71}'), ('\u{a75}', '\u{a75}'), ('\u{a81}', '\u{a82}'),
+    ('\u{abc}', '\u{abc}'), ('\u{ac1}', '\u{ac5}'), ('\u{ac7}', '\u{ac8}'), ('\u{acd}', '\u{acd}'),
+    ('\u{ae2}', '\u{ae3}'), ('\u{afa}', '\u{aff}'), ('\u{b01}', '\u{b01}'), ('\u{b3c}', '\u{b3c}'),
+    ('\u{b3f}', '\u{b3f}'), ('\u{b41}', '\u{b44}'), ('\u{b4d}', '\u{b4d}'), ('\u{b55}', '\u{b56}'),
+    ('\u{b62}', '\u{b63}'), ('\u{b82}', '\u{b82}'), ('\u{bc0}', '\u{bc0}'), ('\u{bcd}', '\u{bcd}'),
+    ('\u{c00}', '\u{c00}'), ('\u{c04}', '\u{c04}'), ('\u{c3c}', '\u{c3c}'), ('\u{c3e}', '\u{c40}'),
+    ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4d}'), ('\u{c55}', '\u{c56}'), ('\u{c62}', '\u{c63}'),
+    ('\u{c81}', '\u{c81}'), ('\u{cbc}', '\u{cbc}'), ('\u{cbf}', '\u{cbf}'), ('\u{cc6}', '\u{cc6}'),
+    ('\u{ccc}', '\u{ccd}'), ('\u{ce2}', '\u{ce3}'), ('\u{d00}', '\u{d01}'), ('\u{d3b}', '\u{d3c}'),
+    ('\u{d41}', '\u{d44}'), ('\u{d4d}', '\u{d4d}'), ('\u{d62}', '\u{d63}'), ('\u{d81}', '\u{d81}'),
+    ('\u{dca}', '\u{dca}'), ('\u{dd2}', '\u{dd4}'), ('\u{dd6}', '\u{dd6}'), ('\u{e31}', '\u{e31}'),
+    ('\u{e34}', '\u{e3a}'), ('\u{e47}', '\u{e4e}'), ('\u{eb1}', '\u{eb1}'), ('\u{eb4}', '\u{ebc}'),
+    ('\u{ec8}', '\u{ecd}'), ('\u{f18}', '\u{f19}'), ('\u{f35}', '\u{f35}'), ('\u{f37}', '\u{f37}'),
+    ('\u{f39}', '\u{f39}'), ('\u{f71}', '\u{f7e}'), ('\u{f80}', '\u{f84}'), ('\u{f86}', '\u{f87}'),
+    ('\u{f8d}', '\u{f97}'), ('\u{f99}', '\u{fbc}'), ('\u{fc6}', '\u{fc6}'), ('\u{102d}', '\u{1030}'),
+    ('\u{1032}', '\u{1037}'), ('\u{1039}', '\u{103a}'), ('\u{103d}', '\u{103e}'), ('\u{1058}', '\u{1059}'),
+    ('\u{105e}', '\u{1060}'), ('\u{1071}', '\u{1074}'), ('\u{1082}', '\u{1082}'), ('\u{1085}', '\u{1086}'),
+    ('\u{108d}', '\u{108d}'), ('\u{109d}', '\u{109d}'), ('\u{135d}', '\u{135f}'), ('\u{1712}', '\u{1714}'),
+    ('\u{1732}', '\u{1733}'), ('\u{1752}', '\u{1753}'), ('\u{1772}', '\u{1773}'), ('\u{17b4}', '\u{17b5}'),
+    ('\u{17b7}', '\u{17bd}'), ('\u{17c6}', '\u{17c6}'), ('\u{17c9}', '\u{17d3}'), ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'), ('\u{180f}', '\u{180f}'), ('\u{1885}', '\u{1886}'), ('\u{18a9}', '\u{18a9}'),
+    ('\u{1920}', '\u{1922}'), ('\u{1927}', '\u{1928}'), ('\u{1932}', '\u{1932}'), ('\u{1939}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a18}'), ('\u{1a1b}', '\u{1a1b}'), ('\u{1a56}', '\u{1a56}'), ('\u{1a58}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a60}'), ('\u{1a62}', '\u{1a62}'), ('\u{1a65}', '\u{1a6c}'), ('\u{1a73}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'), ('\u{1ab0}', '\u{1abd}'), ('\u{1abf}', '\u{1ace}'), ('\u{1b00}', '\u{1b03}'),
+    ('\u{1b34}', '\u{1b34}'), ('\u{1b36}', '\u{1b3a}'), ('\u{1b3c}', '\u{1b3c}'), ('\u{1b42}', '\u{1b42}'),
+    ('\u{1b6b}', '\u{1b73}'), ('\u{1b80}', '\u{1b81}'), ('\u{1ba2}', '\u{1ba5}'), ('\u{1ba8}', '\u{1ba9}'),
+    ('\u{1bab}', '\u{1bad}'), ('\u{1be6}', '\u{1be6}'), ('\u{1be8}', '\u{1be9}'), ('\u{1bed}', '\u{1bed}'),
+    ('\u{1bef}', '\u{1bf1}'), ('\u{1c2c}', '\u{1c33}'), ('\u{1c36}', '\u{1c37}'), ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce0}'), ('\u{1ce2}', '\u{1ce8}'), ('\u{1ced}', '\u{1ced}'), ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf8}', '\u{1cf9}'), ('\u{1dc0}', '\u{1dff}'), ('\u{20d0}', '\u{20dc}'), ('\u{20e1}', '\u{20e1}'),
+    ('\u{20e5}', '\u{20f0}'), ('\u{2cef}', '\u{2cf1}'), ('\u{2d7f}', '\u{2d7f}'), ('\u{2de0}', '\u{2dff}'),
+    ('\u{302a}', '\u{302d}'), ('\u{3099}', '\u{309a}'), ('\u{a66f}', '\u{a66f}'), ('\u{a674}', '\u{a67d}'),
+    ('\u{a69e}', '\u{a69f}'), ('\u{a6f0}', '\u{a6f1}'), ('\u{a802}', '\u{a802}'), ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'), ('\u{a825}', '\u{a826}'), ('\u{a82c}', '\u{a82c}'), ('\u{a8c4}', '\u{a8c5}'),
+    ('\u{a8e0}', '\u{a8f1}'), ('\u{a8ff}', '\u{a8ff}'), ('\u{a926}', '\u{a92d}'), ('\u{a947}', '\u{a951}'),
+    ('\u{a980}', '\u{a982}'), ('\u{a9b3}', '\u{a9b3}'), ('\u{a9b6}', '\u{a9b9}'), ('\u{a9bc}', '\u{a9bd}'),
+    ('\u{a9e5}', '\u{a9e5}'), ('\u{aa29}', '\u{aa2e}'), ('\u{aa31}', '\u{aa32}'), ('\u{aa35}', '\u{aa36}'),
+    ('\u{aa43}', '\u{aa43}'), ('\u{aa4c}', '\u{aa4c}'), ('\u{aa7c}', '\u{aa7c}'), ('\u{aab0}', '\u{aab0}'),
+    ('\u{aab2}', '\u{aab4}'), ('\u{aab7}', '\u{aab8}'), ('\u{aabe}', '\u{aabf}'), ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaec}', '\u{aaed}'), ('\u{aaf6}', '\u{aaf6}'), ('\u{abe5}', '\u{abe5}'), ('\u{abe8}', '\u{abe8}'),
+    ('\u{abed}', '\u{abed}'), ('\u{fb1e}', '\u{fb1e}'), ('\u{fe00}', '\u{fe0f}'), ('\u{fe20}', '\u{fe2f}'),
+    ('\u{101fd}', '\u{101fd}'), ('\u{102e0}', '\u{102e0}'), ('\u{10376}', '\u{1037a}'), ('\u{10a01}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'), ('\u{10a0c}', '\u{10a0f}'), ('\u{10a38}', '\u{10a3a}'), ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10ae5}', '\u{10ae6}'), ('\u{10d24}', '\u{10d27}'), ('\u{10eab}', '\u{10eac}'), ('\u{10f46}', '\u{10f50}'),
+    ('\u{10f82}', '\u{10f85}'), ('\u{11001}', '\u{11001}'), ('\u{11038}', '\u{11046}'), ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'), ('\u{1107f}', '\u{11081}'), ('\u{110b3}', '\u{110b6}'), ('\u{110b9}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'), ('\u{11100}', '\u{11102}'), ('\u{11127}', '\u{1112b}'), ('\u{1112d}', '\u{11134}'),
+    ('\u{11173}', '\u{11173}'), ('\u{11180}', '\u{11181}'), ('\u{111b6}', '\u{111be}'), ('\u{111c9}', '\u{111cc}'),
+    ('\u{111cf}', '\u{111cf}'), ('\u{1122f}', '\u{11231}'), ('\u{11234}', '\u{11234}'), ('\u{11236}', '\u{11237}'),
+    ('\u{1123e}', '\u{1123e}'), ('\u{112df}', '\u{112df}'), ('\u{112e3}', '\u{112ea}'), ('\u{11300}', '\u{11301}'),
+    ('\u{1133b}', '\u{1133c}'), ('\u{11340}', '\u{11340}'), ('\u{11366}', '\u{1136c}'), ('\u{11370}', '\u{11374}'),
+    ('\u{11438}', '\u{1143f}'), ('\u{11442}', '\u{11444}'), ('\u{11446}', '\u{11446}'), ('\u{1145e}', '\u{1145e}'),
+    ('\u{114b3}', '\u{114b8}'), ('\u{114ba}', '\u{114ba}'), ('\u{114bf}', '\u{114c0}'), ('\u{114c2}', '\u{114c3}'),
+    ('\u{115b2}', '\u{115b5}'), ('\u{115bc}', '\u{115bd}'), ('\u{115bf}', '\u{115c0}'), ('\u{115dc}', '\u{115dd}'),
+    ('\u{11633}', '\u{1163a}'), ('\u{1163d}', '\u{1163d}'), ('\u{1163f}', '\u{11640}'), ('\u{116ab}', '\u{116ab}'),
+    ('\u{116ad}', '\u{116ad}'), ('\u{116b0}', '\u{116b5}'), ('\u{116b7}', '\u{116b7}'), ('\u{1171d}', '\u{1171f}'),
+    ('\u{11722}', '\u{11725}'), ('\u{11727}', '\u{1172b}'), ('\u{1182f}', '\u{11837}'), ('\u{11839}', '\u{1183a}'),
+    ('\u{1193b}', '\u{1193c}'), ('\u{1193e}', '\u{1193e}'), ('\u{11943}', '\u{11943}'), ('\u{119d4}', '\u{119d7}'),
+    ('\u{119da}', '\u{119db}'), ('\u{119e0}', '\u{119e0}'), ('\u{11a01}', '\u{11a0a}'), ('\u{11a33}', '\u{11a38}'),
+    ('\u{11a3b}', '\u{11a3e}'), ('\u{11a47}', '\u{11a47}'), ('\u{11a51}', '\u{11a56}'), ('\u{11a59}', '\u{11a5b}'),
+    ('\u{11a8a}', '\u{11a96}'), ('\u{11a98}', '\u{11a99}'), ('\u{11c30}', '\u{11c36}'), ('\u{11c38}', '\u{11c3d}'),
+    ('\u{11c3f}', '\u{11c3f}'), ('\u{11c92}', '\u{11ca7}'), ('\u{11caa}', '\u{11cb0}'), ('\u{11cb2}', '\u{11cb3}'),
+    ('\u{11cb5}', '\u{11cb6}'), ('\u{11d31}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'), ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d45}'), ('\u{11d47}', '\u{11d47}'), ('\u{11d90}', '\u{11d91}'), ('\u{11d95}', '\u{11d95}'),
+    ('\u{11d97}', '\u{11d97}'), ('\u{11ef3}', '\u{11ef4}'), ('\u{16af0}', '\u{16af4}'), ('\u{16b30}', '\u{16b36}'),
+    ('\u{16f4f}', '\u{16f4f}'), ('\u{16f8f}', '\u{16f92}'), ('\u{16fe4}', '\u{16fe4}'), ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1cf00}', '\u{1cf2d}'), ('\u{1cf30}', '\u{1cf46}'), ('\u{1d167}', '\u{1d169}'), ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'), ('\u{1d1aa}', '\u{1d1ad}'), ('\u{1d242}', '\u{1d244}'), ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'), ('\u{1da75}', '\u{1da75}'), ('\u{1da84}', '\u{1da84}'), ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'), ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'), ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'), ('\u{1e026}', '\u{1e02a}'), ('\u{1e130}', '\u{1e136}'), ('\u{1e2ae}', '\u{1e2ae}'),
+    ('\u{1e2ec}', '\u{1e2ef}'), ('\u{1e8d0}', '\u{1e8d6}'), ('\u{1e944}', '\u{1e94a}'), ('\u{e0100}', '\u{e01ef}'),
+];
+
+pub const GC_N: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'), ('\u{b2}', '\u{b3}'), ('\u{b9}', '\u{b9}'), ('\u{bc}', '\u{be}'),
+    ('\u{660}', '\u{669}'), ('\u{6f0}', '\u{6f9}'), ('\u{7c0}', '\u{7c9}'), ('\u{966}', '\u{96f}'),
+    ('\u{9e6}', '\u{9ef}'), ('\u{9f4}', '\u{9f9}'), ('\u{a66}', '\u{a6f}'), ('\u{ae6}', '\u{aef}'),
+    ('\u{b66}', '\u{b6f}'), ('\u{b72}', '\u{b77}'), ('\u{be6}', '\u{bf2}'), ('\u{c66}', '\u{c6f}'),
+    ('\u{c78}', '\u{c7e}'), ('\u{ce6}', '\u{cef}'), ('\u{d58}', '\u{d5e}'), ('\u{d66}', '\u{d78}'),
+    ('\u{de6}', '\u{def}'), ('\u{e50}', '\u{e59}'), ('\u{ed0}', '\u{ed9}'), ('\u{f20}', '\u{f33}'),
+    ('\u{1040}', '\u{1049}'), ('\u{1090}', '\u{1099}'), ('\u{1369}', '\u{137c}'), ('\u{16ee}', '\u{16f0}'),
+    ('\u{17e0}', '\u{17e9}'), ('\u{17f0}', '\u{17f9}'), ('\u{1810}', '\u{1819}'), ('\u{1946}', '\u{194f}'),
+    ('\u{19d0}', '\u{19da}'), ('\u{1a80}', '\u{1a89}'), ('\u{1a90}', '\u{1a99}'), ('\u{1b50}', '\u{1b59}'),
+    ('\u{1bb0}', '\u{1bb9}'), ('\u{1c40}', '\u{1c49}'), ('\u{1c50}', '\u{1c59}'), ('\u{2070}', '\u{2070}'),
+    ('\u{2074}', '\u{2079}'), ('\u{2080}', '\u{2089}'), ('\u{2150}', '\u{2182}'), ('\u{2185}', '\u{2189}'),
+    ('\u{2460}', '\u{249b}'), ('\u{24ea}', '\u{24ff}'), ('\u{2776}', '\u{2793}'), ('\u{2cfd}', '\u{2cfd}'),
+    ('\u{3007}', '\u{3007}'), ('\u{3021}', '\u{3029}'), ('\u{3038}', '\u{303a}'), ('\u{3192}', '\u{3195}'),
+    ('\u{3220}', '\u{3229}'), ('\u{3248}', '\u{324f}'), ('\u{3251}', '\u{325f}'), ('\u{3280}', '\u{3289}'),
+    ('\u{32b1}', '\u{32bf}'), ('\u{a620}', '\u{a629}'), ('\u{a6e6}', '\u{a6ef}'), ('\u{a830}', '\u{a835}'),
+    ('\u{a8d0}', '\u{a8d9}'), ('\u{a900}', '\u{a909}'), ('\u{a9d0}', '\u{a9d9}'), ('\u{a9f0}', '\u{a9f9}'),
+    ('\u{aa50}', '\u{aa59}'), ('\u{abf0}', '\u{abf9}'), ('\u{ff10}', '\u{ff19}'), ('\u{10107}', '\u{10133}'),
+    ('\u{10140}', '\u{10178}'), ('\u{1018a}', '\u{1018b}'), ('\u{102e1}', '\u{102fb}'), ('\u{10320}', '\u{10323}'),
+    ('\u{10341}', '\u{10341}'), ('\u{1034a}', '\u{1034a}'), ('\u{103d1}', '\u{103d5}'), ('\u{104a0}', '\u{104a9}'),
+    ('\u{10858}', '\u{1085f}'), ('\u{10879}', '\u{1087f}'), ('\u{108a7}', '\u{108af}'), ('\u{108fb}', '\u{108ff}'),
+    ('\u{10916}', '\u{1091b}'), ('\u{109bc}', '\u{109bd}'), ('\u{109c0}', '\u{109cf}'), ('\u{109d2}', '\u{109ff}'),
+    ('\u{10a40}', '\u{10a48}'), ('\u{10a7d}', '\u{10a7e}'), ('\u{10a9d}', '\u{10a9f}'), ('\u{10aeb}', '\u{10aef}'),
+    ('\u{10b58}', '\u{10b5f}'), ('\u{10b78}', '\u{10b7f}'), ('\u{10ba9}', '\u{10baf}'), ('\u{10cfa}', '\u{10cff}'),
+    ('\u{10d30}', '\u{10d39}'), ('\u{10e60}', '\u{10e7e}'), ('\u{10f1d}', '\u{10f26}'), ('\u{10f51}', '\u{10f54}'),
+    ('\u{10fc5}', '\u{10fcb}'), ('\u{11052}', '\u{1106f}'), ('\u{110f0}', '\u{110f9}'), ('\u{11136}', '\u{1113f}'),
+    ('\u{111d0}', '\u{111d9}'), ('\u{111e1}', '\u{111f4}'), ('\u{112f0}', '\u{112f9}'), ('\u{11450}', '\u{11459}'),
+    ('\u{114d0}', '\u{114d9}'), ('\u{11650}', '\u{11659}'), ('\u{116c0}', '\u{116c9}'), ('\u{11730}', '\u{1173b}'),
+    ('\u{118e0}', '\u{118f2}'), ('\u{11950}', '\u{11959}'), ('\u{11c50}', '\u{11c6c}'), ('\u{11d50}', '\u{11d59}'),
+    ('\u{11da0}', '\u{11da9}'), ('\u{11fc0}', '\u{11fd4}'), ('\u{12400}', '\u{1246e}'), ('\u{16a60}', '\u{16a69}'),
+    ('\u{16ac0}', '\u{16ac9}'), ('\u{16b50}', '\u{16b59}'), ('\u{16b5b}', '\u{16b61}'), ('\u{16e80}', '\u{16e96}'),
+    ('\u{1d2e0}', '\u{1d2f3}'), ('\u{1d360}', '\u{1d378}'), ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1e140}', '\u{1e149}'),
+    ('\u{1e2f0}', '\u{1e2f9}'), ('\u{1e8c7}', '\u{1e8cf}'), ('\u{1e950}', '\u{1e959}'), ('\u{1ec71}', '\u{1ecab}'),
+    ('\u{1ecad}', '\u{1ecaf}'), ('\u{1ecb1}', '\u{1ecb4}'), ('\u{1ed01}', '\u{1ed2d}'), ('\u{1ed2f}', '\u{1ed3d}'),
+    ('\u{1f100}', '\u{1f10c}'), ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+pub const GC_ND: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'), ('\u{660}', '\u{669}'), ('\u{6f0}', '\u{6f9}'), ('\u{7c0}', '\u{7c9}'),
+    ('\u{966}', '\u{96f}'), ('\u{9e6}', '\u{9ef}'), ('\u{a66}', '\u{a6f}'), ('\u{ae6}', '\u{aef}'),
+    ('\u{b66}', '\u{b6f}'), ('\u{be6}', '\u{bef}'), ('\u{c66}', '\u{c6f}'), ('\u{ce6}', '\u{cef}'),
+    ('\u{d66}', '\u{d6f}'), ('\u{de6}', '\u{def}'), ('\u{e50}', '\u{e59}'), ('\u{ed0}', '\u{ed9}'),
+    ('\u{f20}', '\u{f29}'), ('\u{1040}', '\u{1049}'), ('\u{1090}', '\u{1099}'), ('\u{17e0}', '\u{17e9}'),
+    ('\u{1810}', '\u{1819}'), ('\u{1946}', '\u{194f}'), ('\u{19d0}', '\u{19d9}'), ('\u{1a80}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'), ('\u{1b50}', '\u{1b59}'), ('\u{1bb0}', '\u{1bb9}'), ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c50}', '\u{1c59}'), ('\u{a620}', '\u{a629}'), ('\u{a8d0}', '\u{a8d9}'), ('\u{a900}', '\u{a909}'),
+    ('\u{a9d0}', '\u{a9d9}'), ('\u{a9f0}', '\u{a9f9}'), ('\u{aa50}', '\u{aa59}'), ('\u{abf0}', '\u{abf9}'),
+    ('\u{ff10}', '\u{ff19}'), ('\u{104a0}', '\u{104a9}'), ('\u{10d30}', '\u{10d39}'), ('\u{11066}', '\u{1106f}'),
+    ('\u{110f0}', '\u{110f9}'), ('\u{11136}', '\u{1113f}'), ('\u{111d0}', '\u{111d9}'), ('\u{112f0}', '\u{112f9}'),
+    ('\u{11450}', '\u{11459}'), ('\u{114d0}', '\u{114d9}'), ('\u{11650}', '\u{11659}'), ('\u{116c0}', '\u{116c9}'),
+    ('\u{11730}', '\u{11739}'), ('\u{118e0}', '\u{118e9}'), ('\u{11950}', '\u{11959}'), ('\u{11c50}', '\u{11c59}'),
+    ('\u{11d50}', '\u{11d59}'), ('\u{11da0}', '\u{11da9}'), ('\u{16a60}', '\u{16a69}'), ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16b50}', '\u{16b59}'), ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1e140}', '\u{1e149}'), ('\u{1e2f0}', '\u{1e2f9}'),
+    ('\u{1e950}', '\u{1e959}'), ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+pub const GC_NL: &[(char, char)] = &[
+    ('\u{16ee}', '\u{16f0}'), ('\u{2160}', '\u{2182}'), ('\u{2185}', '\u{2188}'), ('\u{3007}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'), ('\u{3038}', '\u{303a}'), ('\u{a6e6}', '\u{a6ef}'), ('\u{10140}', '\u{10174}'),
+    ('\u{10341}', '\u{10341}'), ('\u{1034a}', '\u{1034a}'), ('\u{103d1}', '\u{103d5}'), ('\u{12400}', '\u{1246e}'),
+];
+
+pub const GC_NO: &[(char, char)] = &[
+    ('\u{b2}', '\u{b3}'), ('\u{b9}', '\u{b9}'), ('\u{bc}', '\u{be}'), ('\u{9f4}', '\u{9f9}'),
+    ('\u{b72}', '\u{b77}'), ('\u{bf0}', '\u{bf2}'), ('\u{c78}', '\u{c7e}'), ('\u{d58}', '\u{d5e}'),
+    ('\u{d70}', '\u{d78}'), ('\u{f2a}', '\u{f33}'), ('\u{1369}', '\u{137c}'), ('\u{17f0}', '\u{17f9}'),
+    ('\u{19da}', '\u{19da}'), ('\u{2070}', '\u{2070}'), ('\u{2074}', '\u{2079}'), ('\u{2080}', '\u{2089}'),
+    ('\u{2150}', '\u{215f}'), ('\u{2189}', '\u{2189}'), ('\u{2460}', '\u{249b}'), ('\u{24ea}', '\u{24ff}'),
+    ('\u{2776}', '\u{2793}'), ('\u{2cfd}', '\u{2cfd}'), ('\u{3192}', '\u{3195}'), ('\u{3220}', '\u{3229}'),
+    ('\u{3248}', '\u{324f}'), ('\u{3251}', '\u{325f}'), ('\u{3280}', '\u{3289}'), ('\u{32b1}', '\u{32bf}'),
+    ('\u{a830}', '\u{a835}'), ('\u{10107}', '\u{10133}'), ('\u{10175}', '\u{10178}'), ('\u{1018a}', '\u{1018b}'),
+    ('\u{102e1}', '\u{102fb}'), ('\u{10320}', '\u{10323}'), ('\u{10858}', '\u{1085f}'), ('\u{10879}', '\u{1087f}'),
+    ('\u{108a7}', '\u{108af}'), ('\u{108fb}', '\u{108ff}'), ('\u{10916}', '\u{1091b}'), ('\u{109bc}', '\u{109bd}'),
+    ('\u{109c0}', '\u{109cf}'), ('\u{109d2}', '\u{109ff}'), ('\u{10a40}', '\u{10a48}'), ('\u{10a7d}', '\u{10a7e}'),
+    ('\u{10a9d}', '\u{10a9f}'), ('\u{10aeb}', '\u{10aef}'), ('\u{10b58}', '\u{10b5f}'), ('\u{10b78}', '\u{10b7f}'),
+    ('\u{10ba9}', '\u{10baf}'), ('\u{10cfa}', '\u{10cff}'), ('\u{10e60}', '\u{10e7e}'), ('\u{10f1d}', '\u{10f26}'),
+    ('\u{10f51}', '\u{10f54}'), ('\u{10fc5}', '\u{10fcb}'), ('\u{11052}', '\u{11065}'), ('\u{111e1}', '\u{111f4}'),
+    ('\u{1173a}', '\u{1173b}'), ('\u{118ea}', '\u{118f2}'), ('\u{11c5a}', '\u{11c6c}'), ('\u{11fc0}', '\u{11fd4}'),
+    ('\u{16b5b}', '\u{16b61}'), ('\u{16e80}', '\u{16e96}'), ('\u{1d2e0}', '\u{1d2f3}'), ('\u{1d360}', '\u{1d378}'),
+    ('\u{1e8c7}', '\u{1e8cf}'), ('\u{1ec71}', '\u{1ecab}'), ('\u{1ecad}', '\u{1ecaf}'), ('\u{1ecb1}', '\u{1ecb4}'),
+    ('\u{1ed01}', '\u{1ed2d}'), ('\u{1ed2f}', '\u{1ed3d}'), ('\u{1f100}', '\u{1f10c}'),
+];
+
+pub const GC_P: &[(char, char)] = &[
+    ('\u{21}', '\u{23}'), ('\u{25}', '\u{2a}'), ('\u{2c}', '\u{2f}'), ('\u{3a}', '\u{3b}'),
+    ('\u{3f}', '\u{40}'), ('\u{5b}', '\u{5d}'), ('\u{5f}', '\u{5f}'), ('\u{7b}', '\u{7b}'),
+    ('\u{7d}', '\u{7d}'), ('\u{a1}', '\u{a1}'), ('\u{a7}', '\u{a7}'), ('\u{ab}', '\u{ab}'),
+    ('\u{b6}', '\u{b7}'), ('\u{bb}', '\u{bb}'), ('\u{bf}', '\u{bf}'), ('\u{37e}', '\u{37e}'),
+    ('\u{387}', '\u{387}'), ('\u{55a}', '\u{55f}'), ('\u{589}', '\u{58a}'), ('\u{5be}', '\u{5be}'),
+    ('\u{5c0}', '\u{5c0}'), ('\u{5c3}', '\u{5c3}'), ('\u{5c6}', '\u{5c6}'), ('\u{5f3}', '\u{5f4}'),
+    ('\u{609}', '\u{60a}'), ('\u{60c}', '\u{60d}'), ('\u{61b}', '\u{61b}'), ('\u{61d}', '\u{61f}'),
+    ('\u{66a}', '\u{66d}'), ('\u{6d4}', '\u{6d4}'), ('\u{700}', '\u{70d}'), ('\u{7f7}', '\u{7f9}'),
+    ('\u{830}', '\u{83e}'), ('\u{85e}', '\u{85e}'), ('\u{964}', '\u{965}'), ('\u{970}', '\u{970}'),
+    ('\u{9fd}', '\u{9fd}'), ('\u{a76}', '\u{a76}'), ('\u{af0}', '\u{af0}'), ('\u{c77}', '\u{c77}'),
+    ('\u{c84}', '\u{c84}'), ('\u{df4}', '\u{df4}'), ('\u{e4f}', '\u{e4f}'), ('\u{e5a}', '\u{e5b}'),
+    ('\u{f04}', '\u{f12}'), ('\u{f14}', '\u{f14}'), ('\u{f3a}', '\u{f3d}'), ('\u{f85}', '\u{f85}'),
+    ('\u{fd0}', '\u{fd4}'), ('\u{fd9}', '\u{fda}'), ('\u{104a}', '\u{104f}'), ('\u{10fb}', '\u{10fb}'),
+    ('\u{1360}', '\u{1368}'), ('\u{1400}', '\u{1400}'), ('\u{166e}', '\u{166e}'), ('\u{169b}', '\u{169c}'),
+    ('\u{16eb}', '\u{16ed}'), ('\u{1735}', '\u{1736}'), ('\u{17d4}', '\u{17d6}'), ('\u{17d8}', '\u{17da}'),
+    ('\u{1800}', '\u{180a}'), ('\u{1944}', '\u{1945}'), ('\u{1a1e}', '\u{1a1f}'), ('\u{1aa0}', '\u{1aa6}'),
+    ('\u{1aa8}', '\u{1aad}'), ('\u{1b5a}', '\u{1b60}'), ('\u{1b7d}', '\u{1b7e}'), ('\u{1bfc}', '\u{1bff}'),
+    ('\u{1c3b}', '\u{1c3f}'), ('\u{1c7e}', '\u{1c7f}'), ('\u{1cc0}', '\u{1cc7}'), ('\u{1cd3}', '\u{1cd3}'),
+    ('\u{2010}', '\u{2027}'), ('\u{2030}', '\u{2043}'), ('\u{2045}', '\u{2051}'), ('\u{2053}', '\u{205e}'),
+    ('\u{207d}', '\u{207e}'), ('\u{208d}', '\u{208e}'), ('\u{2308}', '\u{230b}'), ('\u{2329}', '\u{232a}'),
+    ('\u{2768}', '\u{2775}'), ('\u{27c5}', '\u{27c6}'), ('\u{27e6}', '\u{27ef}'), ('\u{2983}', '\u{2998}'),
+    ('\u{29d8}', '\u{29db}'), ('\u{29fc}', '\u{29fd}'), ('\u{2cf9}', '\u{2cfc}'), ('\u{2cfe}', '\u{2cff}'),
+    ('\u{2d70}', '\u{2d70}'), ('\u{2e00}', '\u{2e2e}'), ('\u{2e30}', '\u{2e4f}'), ('\u{2e52}', '\u{2e5d}'),
+    ('\u{3001}', '\u{3003}'), ('\u{3008}', '\u{3011}'), ('\u{3014}', '\u{301f}'), ('\u{3030}', '\u{3030}'),
+    ('\u{303d}', '\u{303d}'), ('\u{30a0}', '\u{30a0}'), ('\u{30fb}', '\u{30fb}'), ('\u{a4fe}', '\u{a4ff}'),
+    ('\u{a60d}', '\u{a60f}'), ('\u{a673}', '\u{a673}'), ('\u{a67e}', '\u{a67e}'), ('\u{a6f2}', '\u{a6f7}'),
+    ('\u{a874}', '\u{a877}'), ('\u{a8ce}', '\u{a8cf}'), ('\u{a8f8}', '\u{a8fa}'), ('\u{a8fc}', '\u{a8fc}'),
+    ('\u{a92e}', '\u{a92f}'), ('\u{a95f}', '\u{a95f}'), ('\u{a9c1}', '\u{a9cd}'), ('\u{a9de}', '\u{a9df}'),
+    ('\u{aa5c}', '\u{aa5f}'), ('\u{aade}', '\u{aadf}'), ('\u{aaf0}', '\u{aaf1}'), ('\u{abeb}', '\u{abeb}'),
+    ('\u{fd3e}', '\u{fd3f}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'), ('\u{fe54}', '\u{fe61}'),
+    ('\u{fe63}', '\u{fe63}'), ('\u{fe68}', '\u{fe68}'), ('\u{fe6a}', '\u{fe6b}'), ('\u{ff01}', '\u{ff03}'),
+    ('\u{ff05}', '\u{ff0a}'), ('\u{ff0c}', '\u{ff0f}'), ('\u{ff1a}', '\u{ff1b}'), ('\u{ff1f}', '\u{ff20}'),
+    ('\u{ff3b}', '\u{ff3d}'), ('\u{ff3f}', '\u{ff3f}'), ('\u{ff5b}', '\u{ff5b}'), ('\u{ff5d}', '\u{ff5d}'),
+    ('\u{ff5f}', '\u{ff65}'), ('\u{10100}', '\u{10102}'), ('\u{1039f}', '\u{1039f}'), ('\u{103d0}', '\u{103d0}'),
+    ('\u{1056f}', '\u{1056f}'), ('\u{10857}', '\u{10857}'), ('\u{1091f}', '\u{1091f}'), ('\u{1093f}', '\u{1093f}'),
+    ('\u{10a50}', '\u{10a58}'), ('\u{10a7f}', '\u{10a7f}'), ('\u{10af0}', '\u{10af6}'), ('\u{10b39}', '\u{10b3f}'),
+    ('\u{10b99}', '\u{10b9c}'), ('\u{10ead}', '\u{10ead}'), ('\u{10f55}', '\u{10f59}'), ('\u{10f86}', '\u{10f89}'),
+    ('\u{11047}', '\u{1104d}'), ('\u{110bb}', '\u{110bc}'), ('\u{110be}', '\u{110c1}'), ('\u{11140}', '\u{11143}'),
+    ('\u{11174}', '\u{11175}'), ('\u{111c5}', '\u{111c8}'), ('\u{111cd}', '\u{111cd}'), ('\u{111db}', '\u{111db}'),
+    ('\u{111dd}', '\u{111df}'), ('\u{11238}', '\u{1123d}'), ('\u{112a9}', '\u{112a9}'), ('\u{1144b}', '\u{1144f}'),
+    ('\u{1145a}', '\u{1145b}'), ('\u{1145d}', '\u{1145d}'), ('\u{114c6}', '\u{114c6}'), ('\u{115c1}', '\u{115d7}'),
+    ('\u{11641}', '\u{11643}'), ('\u{11660}', '\u{1166c}'), ('\u{116b9}', '\u{116b9}'), ('\u{1173c}', '\u{1173e}'),
+    ('\u{1183b}', '\u{1183b}'), ('\u{11944}', '\u{11946}'), ('\u{119e2}', '\u{119e2}'), ('\u{11a3f}', '\u{11a46}'),
+    ('\u{11a9a}', '\u{11a9c}'), ('\u{11a9e}', '\u{11aa2}'), ('\u{11c41}', '\u{11c45}'), ('\u{11c70}', '\u{11c71}'),
+    ('\u{11ef7}', '\u{11ef8}'), ('\u{11fff}', '\u{11fff}'), ('\u{12470}', '\u{12474}'), ('\u{12ff1}', '\u{12ff2}'),
+    ('\u{16a6e}', '\u{16a6f}'), ('\u{16af5}', '\u{16af5}'), ('\u{16b37}', '\u{16b3b}'), ('\u{16b44}', '\u{16b44}'),
+    ('\u{16e97}', '\u{16e9a}'), ('\u{16fe2}', '\u{16fe2}'), ('\u{1bc9f}', '\u{1bc9f}'), ('\u{1da87}', '\u{1da8b}'),
+    ('\u{1e95e}', '\u{1e95f}'),
+];
+
+pub const GC_PC: &[(char, char)] = &[
+    ('\u{5f}', '\u{5f}'), ('\u{203f}', '\u{2040}'), ('\u{2054}', '\u{2054}'), ('\u{fe33}', '\u{fe34}'),
+    ('\u{fe4d}', '\u{fe4f}'), ('\u{ff3f}', '\u{ff3f}'),
+];
+
+pub const GC_PD: &[(char, char)] = &[
+    ('\u{2d}', '\u{2d}'), ('\u{58a}', '\u{58a}'), ('\u{5be}', '\u{5be}'), ('\u{1400}', '\u{1400}'),
+    ('\u{1806}', '\u{1806}'), ('\u{2010}', '\u{2015}'), ('\u{2e17}', '\u{2e17}'), ('\u{2e1a}', '\u{2e1a}'),
+    ('\u{2e3a}', '\u{2e3b}'), ('\u{2e40}', '\u{2e40}'), ('\u{2e5d}', '\u{2e5d}'), ('\u{301c}', '\u{301c}'),
+    ('\u{3030}', '\u{3030}'), ('\u{30a0}', '\u{30a0}'), ('\u{fe31}', '\u{fe32}'), ('\u{fe58}', '\u{fe58}'),
+    ('\u{fe63}', '\u{fe63}'), ('\u{ff0d}', '\u{ff0d}'), ('\u{10ead}', '\u{10ead}'),
+];
+
+pub const GC_PE: &[(char, char)] = &[
+    ('\u{29}', '\u{29}'), ('\u{5d}', '\u{5d}'), ('\u{7d}', '\u{7d}'), ('\u{f3b}', '\u{f3b}'),
+    ('\u{f3d}', '\u{f3d}'), ('\u{169c}', '\u{169c}'), ('\u{2046}', '\u{2046}'), ('\u{207e}', '\u{207e}'),
+    ('\u{208e}', '\u{208e}'), ('\u{2309}', '\u{2309}'), ('\u{230b}', '\u{230b}'), ('\u{232a}', '\u{232a}'),
+    ('\u{2769}', '\u{2769}'), ('\u{276b}', '\u{276b}'), ('\u{276d}', '\u{276d}'), ('\u{276f}', '\u{276f}'),
+    ('\u{2771}', '\u{2771}'), ('\u{2773}', '\u{2773}'), ('\u{2775}', '\u{2775}'), ('\u{27c6}', '\u{27c6}'),
+    ('\u{27e7}', '\u{27e7}'), ('\u{27e9}', '\u{27e9}'), ('\u{27eb}', '\u{27eb}'), ('\u{27ed}', '\u{27ed}'),
+    ('\u{27ef}', '\u{27ef}'), ('\u{2984}', '\u{2984}'), ('\u{2986}', '\u{2986}'), ('\u{2988}', '\u{2988}'),
+    ('\u{298a}', '\u{298a}'), ('\u{298c}', '\u{298c}'), ('\u{298e}', '\u{298e}'), ('\u{2990}', '\u{2990}'),
+    ('\u{2992}', '\u{2992}'), ('\u{2994}', '\u{2994}'), ('\u{2996}', '\u{2996}'), ('\u{2998}', '\u{2998}'),
+    ('\u{29d9}', '\u{29d9}'), ('\u{29db}', '\u{29db}'), ('\u{29fd}', '\u{29fd}'), ('\u{2e23}', '\u{2e23}'),
+    ('\u{2e25}', '\u{2e25}'), ('\u{2e27}', '\u{2e27}'), ('\u{2e29}', '\u{2e29}'), ('\u{2e56}', '\u{2e56}'),
+    ('\u{2e58}', '\u{2e58}'), ('\u{2e5a}', '\u{2e5a}'), ('\u{2e5c}', '\u{2e5c}'), ('\u{3009}', '\u{3009}'),
+    ('\u{300b}', '\u{300b}'), ('\u{300d}', '\u{300d}'), ('\u{300f}', '\u{300f}'), ('\u{3011}', '\u{3011}'),
+    ('\u{3015}', '\u{3015}'), ('\u{3017}', '\u{3017}'), ('\u{3019}', '\u{3019}'), ('\u{301b}', '\u{301b}'),
+    ('\u{301e}', '\u{301f}'), ('\u{fd3e}', '\u{fd3e}'), ('\u{fe18}', '\u{fe18}'), ('\u{fe36}', '\u{fe36}'),
+    ('\u{fe38}', '\u{fe38}'), ('\u{fe3a}', '\u{fe3a}'), ('\u{fe3c}', '\u{fe3c}'), ('\u{fe3e}', '\u{fe3e}'),
+    ('\u{fe40}', '\u{fe40}'), ('\u{fe42}', '\u{fe42}'), ('\u{fe44}', '\u{fe44}'), ('\u{fe48}', '\u{fe48}'),
+    ('\u{fe5a}', '\u{fe5a}'), ('\u{fe5c}', '\u{fe5c}'), ('\u{fe5e}', '\u{fe5e}'), ('\u{ff09}', '\u{ff09}'),
+    ('\u{ff3d}', '\u{ff3d}'), ('\u{ff5d}', '\u{ff5d}'), ('\u{ff60}', '\u{ff60}'), ('\u{ff63}', '\u{ff63}'),
+];
+
+pub const GC_PF: &[(char, char)] = &[
+    ('\u{bb}', '\u{bb}'), ('\u{2019}', '\u{2019}'), ('\u{201d}', '\u{201d}'), ('\u{203a}', '\u{203a}'),
+    ('\u{2e03}', '\u{2e03}'), ('\u{2e05}', '\u{2e05}'), ('\u{2e0a}', '\u{2e0a}'), ('\u{2e0d}', '\u{2e0d}'),
+    ('\u{2e1d}', '\u{2e1d}'), ('\u{2e21}', '\u{2e21}'),
+];
+
+pub const GC_PI: &[(char, char)] = &[
+    ('\u{ab}', '\u{ab}'), ('\u{2018}', '\u{2018}'), ('\u{201b}', '\u{201c}'), ('\u{201f}', '\u{201f}'),
+    ('\u{2039}', '\u{2039}'), ('\u{2e02}', '\u{2e02}'), ('\u{2e04}', '\u{2e04}'), ('\u{2e09}', '\u{2e09}'),
+    ('\u{2e0c}', '\u{2e0c}'), ('\u{2e1c}', '\u{2e1c}'), ('\u{2e20}', '\u{2e20}'),
+];
+
+pub const GC_PO: &[(char, char)] = &[
+    ('\u{21}', '\u{23}'), ('\u{25}', '\u{27}'), ('\u{2a}', '\u{2a}'), ('\u{2c}', '\u{2c}'),
+    ('\u{2e}', '\u{2f}'), ('\u{3a}', '\u{3b}'), ('\u{3f}', '\u{40}'), ('\u{5c}', '\u{5c}'),
+    ('\u{a1}', '\u{a1}'), ('\u{a7}', '\u{a7}'), ('\u{b6}', '\u{b7}'), ('\u{bf}', '\u{bf}'),
+    ('\u{37e}', '\u{37e}'), ('\u{387}', '\u{387}'), ('\u{55a}', '\u{55f}'), ('\u{589}', '\u{589}'),
+    ('\u{5c0}', '\u{5c0}'), ('\u{5c3}', '\u{5c3}'), ('\u{5c6}', '\u{5c6}'), ('\u{5f3}', '\u{5f4}'),
+    ('\u{609}', '\u{60a}'), ('\u{60c}', '\u{60d}'), ('\u{61b}', '\u{61b}'), ('\u{61d}', '\u{61f}'),
+    ('\u{66a}', '\u{66d}'), ('\u{6d4}', '\u{6d4}'), ('\u{700}', '\u{70d}'), ('\u{7f7}', '\u{7f9}'),
+    ('\u{830}', '\u{83e}'), ('\u{85e}', '\u{85e}'), ('\u{964}', '\u{965}'), ('\u{970}', '\u{970}'),
+    ('\u{9fd}', '\u{9fd}'), ('\u{a76}', '\u{a76}'), ('\u{af0}', '\u{af0}'), ('\u{c77}', '\u{c77}'),
+    ('\u{c84}', '\u{c84}'), ('\u{df4}', '\u{df4}'), ('\u{e4f}', '\u{e4f}'), ('\u{e5a}', '\u{e5b}'),
+    ('\u{f04}', '\u{f12}'), ('\u{f14}', '\u{f14}'), ('\u{f85}', '\u{f85}'), ('\u{fd0}', '\u{fd4}'),
+    ('\u{fd9}', '\u{fda}'), ('\u{104a}', '\u{104f}'), ('\u{10fb}', '\u{10fb}'), ('\u{1360}', '\u{1368}'),
+    ('\u{166e}', '\u{166e}'), ('\u{16eb}', '\u{16ed}'), ('\u{1735}', '\u{1736}'), ('\u{17d4}', '\u{17d6}'),
+    ('\u{17d8}', '\u{17da}'), ('\u{1800}', '\u{1805}'), ('\u{1807}', '\u{180a}'), ('\u{1944}', '\u{1945}'),
+    ('\u{1a1e}', '\u{1a1f}'), ('\u{1aa0}', '\u{1aa6}'), ('\u{1aa8}', '\u{1aad}'), ('\u{1b5a}', '\u{1b60}'),
+    ('\u{1b7d}', '\u{1b7e}'), ('\u{1bfc}', '\u{1bff}'), ('\u{1c3b}', '\u{1c3f}'), ('\u{1c7e}', '\u{1c7f}'),
+    ('\u{1cc0}', '\u{1cc7}'), ('\u{1cd3}', '\u{1cd3}'), ('\u{2016}', '\u{2017}'), ('\u{2020}', '\u{2027}'),
+    ('\u{2030}', '\u{2038}'), ('\u{203b}', '\u{203e}'), ('\u{2041}', '\u{2043}'), ('\u{2047}', '\u{2051}'),
+    ('\u{2053}', '\u{2053}'), ('\u{2055}', '\u{205e}'), ('\u{2cf9}', '\u{2cfc}'), ('\u{2cfe}', '\u{2cff}'),
+    ('\u{2d70}', '\u{2d70}'), ('\u{2e00}', '\u{2e01}'), ('\u{2e06}', '\u{2e08}'), ('\u{2e0b}', '\u{2e0b}'),
+    ('\u{2e0e}', '\u{2e16}'), ('\u{2e18}', '\u{2e19}'), ('\u{2e1b}', '\u{2e1b}'), ('\u{2e1e}', '\u{2e1f}'),
+    ('\u{2e2a}', '\u{2e2e}'), ('\u{2e30}', '\u{2e39}'), ('\u{2e3c}', '\u{2e3f}'), ('\u{2e41}', '\u{2e41}'),
+    ('\u{2e43}', '\u{2e4f}'), ('\u{2e52}', '\u{2e54}'), ('\u{3001}', '\u{3003}'), ('\u{303d}', '\u{303d}'),
+    ('\u{30fb}', '\u{30fb}'), ('\u{a4fe}', '\u{a4ff}'), ('\u{a60d}', '\u{a60f}'), ('\u{a673}', '\u{a673}'),
+    ('\u{a67e}', '\u{a67e}'), ('\u{a6f2}', '\u{a6f7}'), ('\u{a874}', '\u{a877}'), ('\u{a8ce}', '\u{a8cf}'),
+    ('\u{a8f8}', '\u{a8fa}'), ('\u{a8fc}', '\u{a8fc}'), ('\u{a92e}', '\u{a92f}'), ('\u{a95f}', '\u{a95f}'),
+    ('\u{a9c1}', '\u{a9cd}'), ('\u{a9de}', '\u{a9df}'), ('\u{aa5c}', '\u{aa5f}'), ('\u{aade}', '\u{aadf}'),
+    ('\u{aaf0}', '\u{aaf1}'), ('\u{abeb}', '\u{abeb}'), ('\u{fe10}', '\u{fe16}'), ('\u{fe19}', '\u{fe19}'),
+    ('\u{fe30}', '\u{fe30}'), ('\u{fe45}', '\u{fe46}'), ('\u{fe49}', '\u{fe4c}'), ('\u{fe50}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe57}'), ('\u{fe5f}', '\u{fe61}'), ('\u{fe68}', '\u{fe68}'), ('\u{fe6a}', '\u{fe6b}'),
+    ('\u{ff01}', '\u{ff03}'), ('\u{ff05}', '\u{ff07}'), ('\u{ff0a}', '\u{ff0a}'), ('\u{ff0c}', '\u{ff0c}'),
+    ('\u{ff0e}', '\u{ff0f}'), ('\u{ff1a}', '\u{ff1b}'), ('\u{ff1f}', '\u{ff20}'), ('\u{ff3c}', '\u{ff3c}'),
+    ('\u{ff61}', '\u{ff61}'), ('\u{ff64}', '\u{ff65}'), ('\u{10100}', '\u{10102}'), ('\u{1039f}', '\u{1039f}'),
+    ('\u{103d0}', '\u{103d0}'), ('\u{1056f}', '\u{1056f}'), ('\u{10857}', '\u{10857}'), ('\u{1091f}', '\u{1091f}'),
+    ('\u{1093f}', '\u{1093f}'), ('\u{10a50}', '\u{10a58}'), ('\u{10a7f}', '\u{10a7f}'), ('\u{10af0}', '\u{10af6}'),
+    ('\u{10b39}', '\u{10b3f}'), ('\u{10b99}', '\u{10b9c}'), ('\u{10f55}', '\u{10f59}'), ('\u{10f86}', '\u{10f89}'),
+    ('\u{11047}', '\u{1104d}'), ('\u{110bb}', '\u{110bc}'), ('\u{110be}', '\u{110c1}'), ('\u{11140}', '\u{11143}'),
+    ('\u{11174}', '\u{11175}'), ('\u{111c5}', '\u{111c8}'), ('\u{111cd}', '\u{111cd}'), ('\u{111db}', '\u{111db}'),
+    ('\u{111dd}', '\u{111df}'), ('\u{11238}', '\u{1123d}'), ('\u{112a9}', '\u{112a9}'), ('\u{1144b}', '\u{1144f}'),
+    ('\u{1145a}', '\u{1145b}'), ('\u{1145d}', '\u{1145d}'), ('\u{114c6}', '\u{114c6}'), ('\u{115c1}', '\u{115d7}'),
+    ('\u{11641}', '\u{11643}'), ('\u{11660}', '\u{1166c}'), ('\u{116b9}', '\u{116b9}'), ('\u{1173c}', '\u{1173e}'),
+    ('\u{1183b}', '\u{1183b}'), ('\u{11944}', '\u{11946}'), ('\u{119e2}', '\u{119e2}'), ('\u{11a3f}', '\u{11a46}'),
+    ('\u{11a9a}', '\u{11a9c}'), ('\u{11a9e}', '\u{11aa2}'), ('\u{11c41}', '\u{11c45}'), ('\u{11c70}', '\u{11c71}'),
+    ('\u{11ef7}', '\u{11ef8}'), ('\u{11fff}', '\u{11fff}'), ('\u{12470}', '\u{12474}'), ('\u{12ff1}', '\u{12ff2}'),
+    ('\u{16a6e}', '\u{16a6f}'), ('\u{16af5}', '\u{16af5}'), ('\u{16b37}', '\u{16b3b}'), ('\u{16b44}', '\u{16b44}'),
+    ('\u{16e97}', '\u{16e9a}'), ('\u{16fe2}', '\u{16fe2}'), ('\u{1bc9f}', '\u{1bc9f}'), ('\u{1da87}', '\u{1da8b}'),
+    ('\u{1e95e}', '\u{1e95f}'),
+];
+
+pub const GC_PS: &[(char, char)] = &[
+    ('\u{28}', '\u{28}'), ('\u{5b}', '\u{5b}'), ('\u{7b}', '\u{7b}'), ('\u{f3a}', '\u{f3a}'),
+    ('\u{f3c}', '\u{f3c}'), ('\u{169b}', '\u{169b}'), ('\u{201a}', '\u{201a}'), ('\u{201e}', '\u{201e}'),
+    ('\u{2045}', '\u{2045}'), ('\u{207d}', '\u{207d}'), ('\u{208d}', '\u{208d}'), ('\u{2308}', '\u{2308}'),
+    ('\u{230a}', '\u{230a}'), ('\u{2329}', '\u{2329}'), ('\u{2768}', '\u{2768}'), ('\u{276a}', '\u{276a}'),
+    ('\u{276c}', '\u{276c}'), ('\u{276e}', '\u{276e}'), ('\u{2770}', '\u{2770}'), ('\u{2772}', '\u{2772}'),
+    ('\u{2774}', '\u{2774}'), ('\u{27c5}', '\u{27c5}'), ('\u{27e6}', '\u{27e6}'), ('\u{27e8}', '\u{27e8}'),
+    ('\u{27ea}', '\u{27ea}'), ('\u{27ec}', '\u{27ec}'), ('\u{27ee}', '\u{27ee}'), ('\u{2983}', '\u{2983}'),
+    ('\u{2985}', '\u{2985}'), ('\u{2987}', '\u{2987}'), ('\u{2989}', '\u{2989}'), ('\u{298b}', '\u{298b}'),
+    ('\u{298d}', '\u{298d}'), ('\u{298f}', '\u{298f}'), ('\u{2991}', '\u{2991}'), ('\u{2993}', '\u{2993}'),
+    ('\u{2995}', '\u{2995}'), ('\u{2997}', '\u{2997}'), ('\u{29d8}', '\u{29d8}'), ('\u{29da}', '\u{29da}'),
+    ('\u{29fc}', '\u{29fc}'), ('\u{2e22}', '\u{2e22}'), ('\u{2e24}', '\u{2e24}'), ('\u{2e26}', '\u{2e26}'),
+    ('\u{2e28}', '\u{2e28}'), ('\u{2e42}', '\u{2e42}'), ('\u{2e55}', '\u{2e55}'), ('\u{2e57}', '\u{2e57}'),
+    ('\u{2e59}', '\u{2e59}'), ('\u{2e5b}', '\u{2e5b}'), ('\u{3008}', '\u{3008}'), ('\u{300a}', '\u{300a}'),
+    ('\u{300c}', '\u{300c}'), ('\u{300e}', '\u{300e}'), ('\u{3010}', '\u{3010}'), ('\u{3014}', '\u{3014}'),
+    ('\u{3016}', '\u{3016}'), ('\u{3018}', '\u{3018}'), ('\u{301a}', '\u{301a}'), ('\u{301d}', '\u{301d}'),
+    ('\u{fd3f}', '\u{fd3f}'), ('\u{fe17}', '\u{fe17}'), ('\u{fe35}', '\u{fe35}'), ('\u{fe37}', '\u{fe37}'),
+    ('\u{fe39}', '\u{fe39}'), ('\u{fe3b}', '\u{fe3b}'), ('\u{fe3d}', '\u{fe3d}'), ('\u{fe3f}', '\u{fe3f}'),
+    ('\u{fe41}', '\u{fe41}'), ('\u{fe43}', '\u{fe43}'), ('\u{fe47}', '\u{fe47}'), ('\u{fe59}', '\u{fe59}'),
+    ('\u{fe5b}', '\u{fe5b}'), ('\u{fe5d}', '\u{fe5d}'), ('\u{ff08}', '\u{ff08}'), ('\u{ff3b}', '\u{ff3b}'),
+    ('\u{ff5b}', '\u{ff5b}'), ('\u{ff5f}', '\u{ff5f}'), ('\u{ff62}', '\u{ff62}'),
+];
+
+pub const GC_S: &[(char, char)] = &[
+    ('\u{24}', '\u{24}'), ('\u{2b}', '\u{2b}'), ('\u{3c}', '\u{3e}'), ('\u{5e}', '\u{5e}'),
+    ('\u{60}', '\u{60}'), ('\u{7c}', '\u{7c}'), ('\u{7e}', '\u{7e}'), ('\u{a2}', '\u{a6}'),
+    ('\u{a8}', '\u{a9}'), ('\u{ac}', '\u{ac}'), ('\u{ae}', '\u{b1}'), ('\u{b4}', '\u{b4}'),
+    ('\u{b8}', '\u{b8}'), ('\u{d7}', '\u{d7}'), ('\u{f7}', '\u{f7}'), ('\u{2c2}', '\u{2c5}'),
+    ('\u{2d2}', '\u{2df}'), ('\u{2e5}', '\u{2eb}'), ('\u{2ed}', '\u{2ed}'), ('\u{2ef}', '\u{2ff}'),
+    ('\u{375}', '\u{375}'), ('\u{384}', '\u{385}'), ('\u{3f6}', '\u{3f6}'), ('\u{482}', '\u{482}'),
+    ('\u{58d}', '\u{58f}'), ('\u{606}', '\u{608}'), ('\u{60b}', '\u{60b}'), ('\u{60e}', '\u{60f}'),
+    ('\u{6de}', '\u{6de}'), ('\u{6e9}', '\u{6e9}'), ('\u{6fd}', '\u{6fe}'), ('\u{7f6}', '\u{7f6}'),
+    ('\u{7fe}', '\u{7ff}'), ('\u{888}', '\u{888}'), ('\u{9f2}', '\u{9f3}'), ('\u{9fa}', '\u{9fb}'),
+    ('\u{af1}', '\u{af1}'), ('\u{b70}', '\u{b70}'), ('\u{bf3}', '\u{bfa}'), ('\u{c7f}', '\u{c7f}'),
+    ('\u{d4f}', '\u{d4f}'), ('\u{d79}', '\u{d79}'), ('\u{e3f}', '\u{e3f}'), ('\u{f01}', '\u{f03}'),
+    ('\u{f13}', '\u{f13}'), ('\u{f15}', '\u{f17}'), ('\u{f1a}', '\u{f1f}'), ('\u{f34}', '\u{f34}'),
+    ('\u{f36}', '\u{f36}'), ('\u{f38}', '\u{f38}'), ('\u{fbe}', '\u{fc5}'), ('\u{fc7}', '\u{fcc}'),
+    ('\u{fce}', '\u{fcf}'), ('\u{fd5}', '\u{fd8}'), ('\u{109e}', '\u{109f}'), ('\u{1390}', '\u{1399}'),
+    ('\u{166d}', '\u{166d}'), ('\u{17db}', '\u{17db}'), ('\u{1940}', '\u{1940}'), ('\u{19de}', '\u{19ff}'),
+    ('\u{1b61}', '\u{1b6a}'), ('\u{1b74}', '\u{1b7c}'), ('\u{1fbd}', '\u{1fbd}'), ('\u{1fbf}', '\u{1fc1}'),
+    ('\u{1fcd}', '\u{1fcf}'), ('\u{1fdd}', '\u{1fdf}'), ('\u{1fed}', '\u{1fef}'), ('\u{1ffd}', '\u{1ffe}'),
+    ('\u{2044}', '\u{2044}'), ('\u{2052}', '\u{2052}'), ('\u{207a}', '\u{207c}'), ('\u{208a}', '\u{208c}'),
+    ('\u{20a0}', '\u{20c0}'), ('\u{2100}', '\u{2101}'), ('\u{2103}', '\u{2106}'), ('\u{2108}', '\u{2109}'),
+    ('\u{2114}', '\u{2114}'), ('\u{2116}', '\u{2118}'), ('\u{211e}', '\u{2123}'), ('\u{2125}', '\u{2125}'),
+    ('\u{2127}', '\u{2127}'), ('\u{2129}', '\u{2129}'), ('\u{212e}', '\u{212e}'), ('\u{213a}', '\u{213b}'),
+    ('\u{2140}', '\u{2144}'), ('\u{214a}', '\u{214d}'), ('\u{214f}', '\u{214f}'), ('\u{218a}', '\u{218b}'),
+    ('\u{2190}', '\u{2307}'), ('\u{230c}', '\u{2328}'), ('\u{232b}', '\u{2426}'), ('\u{2440}', '\u{244a}'),
+    ('\u{249c}', '\u{24e9}'), ('\u{2500}', '\u{2767}'), ('\u{2794}', '\u{27c4}'), ('\u{27c7}', '\u{27e5}'),
+    ('\u{27f0}', '\u{2982}'), ('\u{2999}', '\u{29d7}'), ('\u{29dc}', '\u{29fb}'), ('\u{29fe}', '\u{2b73}'),
+    ('\u{2b76}', '\u{2b95}'), ('\u{2b97}', '\u{2bff}'), ('\u{2ce5}', '\u{2cea}'), ('\u{2e50}', '\u{2e51}'),
+    ('\u{2e80}', '\u{2e99}'), ('\u{2e9b}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{2ff0}', '\u{2ffb}'),
+    ('\u{3004}', '\u{3004}'), ('\u{3012}', '\u{3013}'), ('\u{3020}', '\u{3020}'), ('\u{3036}', '\u{3037}'),
+    ('\u{303e}', '\u{303f}'), ('\u{309b}', '\u{309c}'), ('\u{3190}', '\u{3191}'), ('\u{3196}', '\u{319f}'),
+    ('\u{31c0}', '\u{31e3}'), ('\u{3200}', '\u{321e}'), ('\u{322a}', '\u{3247}'), ('\u{3250}', '\u{3250}'),
+    ('\u{3260}', '\u{327f}'), ('\u{328a}', '\u{32b0}'), ('\u{32c0}', '\u{33ff}'), ('\u{4dc0}', '\u{4dff}'),
+    ('\u{a490}', '\u{a4c6}'), ('\u{a700}', '\u{a716}'), ('\u{a720}', '\u{a721}'), ('\u{a789}', '\u{a78a}'),
+    ('\u{a828}', '\u{a82b}'), ('\u{a836}', '\u{a839}'), ('\u{aa77}', '\u{aa79}'), ('\u{ab5b}', '\u{ab5b}'),
+    ('\u{ab6a}', '\u{ab6b}'), ('\u{fb29}', '\u{fb29}'), ('\u{fbb2}', '\u{fbc2}'), ('\u{fd40}', '\u{fd4f}'),
+    ('\u{fdcf}', '\u{fdcf}'), ('\u{fdfc}', '\u{fdff}'), ('\u{fe62}', '\u{fe62}'), ('\u{fe64}', '\u{fe66}'),
+    ('\u{fe69}', '\u{fe69}'), ('\u{ff04}', '\u{ff04}'), ('\u{ff0b}', '\u{ff0b}'), ('\u{ff1c}', '\u{ff1e}'),
+    ('\u{ff3e}', '\u{ff3e}'), ('\u{ff40}', '\u{ff40}'), ('\u{ff5c}', '\u{ff5c}'), ('\u{ff5e}', '\u{ff5e}'),
+    ('\u{ffe0}', '\u{ffe6}'), ('\u{ffe8}', '\u{ffee}'), ('\u{fffc}', '\u{fffd}'), ('\u{10137}', '\u{1013f}'),
+    ('\u{10179}', '\u{10189}'), ('\u{1018c}', '\u{1018e}'), ('\u{10190}', '\u{1019c}'), ('\u{101a0}', '\u{101a0}'),
+    ('\u{101d0}', '\u{101fc}'), ('\u{10877}', '\u{10878}'), ('\u{10ac8}', '\u{10ac8}'), ('\u{1173f}', '\u{1173f}'),
+    ('\u{11fd5}', '\u{11ff1}'), ('\u{16b3c}', '\u{16b3f}'), ('\u{16b45}', '\u{16b45}'), ('\u{1bc9c}', '\u{1bc9c}'),
+    ('\u{1cf50}', '\u{1cfc3}'), ('\u{1d000}', '\u{1d0f5}'), ('\u{1d100}', '\u{1d126}'), ('\u{1d129}', '\u{1d164}'),
+    ('\u{1d16a}', '\u{1d16c}'), ('\u{1d183}', '\u{1d184}'), ('\u{1d18c}', '\u{1d1a9}'), ('\u{1d1ae}', '\u{1d1ea}'),
+    ('\u{1d200}', '\u{1d241}'), ('\u{1d245}', '\u{1d245}'), ('\u{1d300}', '\u{1d356}'), ('\u{1d6c1}', '\u{1d6c1}'),
+    ('\u{1d6db}', '\u{1d6db}'), ('\u{1d6fb}', '\u{1d6fb}'), ('\u{1d715}', '\u{1d715}'), ('\u{1d735}', '\u{1d735}'),
+    ('\u{1d74f}', '\u{1d74f}'), ('\u{1d76f}', '\u{1d76f}'), ('\u{1d789}', '\u{1d789}'), ('\u{1d7a9}', '\u{1d7a9}'),
+    ('\u{1d7c3}', '\u{1d7c3}'), ('\u{1d800}', '\u{1d9ff}'), ('\u{1da37}', '\u{1da3a}'), ('\u{1da6d}', '\u{1da74}'),
+    ('\u{1da76}', '\u{1da83}'), ('\u{1da85}', '\u{1da86}'), ('\u{1e14f}', '\u{1e14f}'), ('\u{1e2ff}', '\u{1e2ff}'),
+    ('\u{1ecac}', '\u{1ecac}'), ('\u{1ecb0}', '\u{1ecb0}'), ('\u{1ed2e}', '\u{1ed2e}'), ('\u{1eef0}', '\u{1eef1}'),
+    ('\u{1f000}', '\u{1f02b}'), ('\u{1f030}', '\u{1f093}'), ('\u{1f0a0}', '\u{1f0ae}'), ('\u{1f0b1}', '\u{1f0bf}'),
+    ('\u{1f0c1}', '\u{1f0cf}'), ('\u{1f0d1}', '\u{1f0f5}'), ('\u{1f10d}', '\u{1f1ad}'), ('\u{1f1e6}', '\u{1f202}'),
+    ('\u{1f210}', '\u{1f23b}'), ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'), ('\u{1f260}', '\u{1f265}'),
+    ('\u{1f300}', '\u{1f6d7}'), ('\u{1f6dd}', '\u{1f6ec}'), ('\u{1f6f0}', '\u{1f6fc}'), ('\u{1f700}', '\u{1f773}'),
+    ('\u{1f780}', '\u{1f7d8}'), ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}', '\u{1f7f0}'), ('\u{1f800}', '\u{1f80b}'),
+    ('\u{1f810}', '\u{1f847}'), ('\u{1f850}', '\u{1f859}'), ('\u{1f860}', '\u{1f887}'), ('\u{1f890}', '\u{1f8ad}'),
+    ('\u{1f8b0}', '\u{1f8b1}'), ('\u{1f900}', '\u{1fa53}'), ('\u{1fa60}', '\u{1fa6d}'), ('\u{1fa70}', '\u{1fa74}'),
+    ('\u{1fa78}', '\u{1fa7c}'), ('\u{1fa80}', '\u{1fa86}'), ('\u{1fa90}', '\u{1faac}'), ('\u{1fab0}', '\u{1faba}'),
+    ('\u{1fac0}', '\u{1fac5}'), ('\u{1fad0}', '\u{1fad9}'), ('\u{1fae0}', '\u{1fae7}'), ('\u{1faf0}', '\u{1faf6}'),
+    ('\u{1fb00}', '\u{1fb92}'), ('\u{1fb94}', '\u{1fbca}'),
+];
+
+pub const GC_SC: &[(char, char)] = &[
+    ('\u{24}', '\u{24}'), ('\u{a2}', '\u{a5}'), ('\u{58f}', '\u{58f}'), ('\u{60b}', '\u{60b}'),
+    ('\u{7fe}', '\u{7ff}'), ('\u{9f2}', '\u{9f3}'), ('\u{9fb}', '\u{9fb}'), ('\u{af1}', '\u{af1}'),
+    ('\u{bf9}', '\u{bf9}'), ('\u{e3f}', '\u{e3f}'), ('\u{17db}', '\u{17db}'), ('\u{20a0}', '\u{20c0}'),
+    ('\u{a838}', '\u{a838}'), ('\u{fdfc}', '\u{fdfc}'), ('\u{fe69}', '\u{fe69}'), ('\u{ff04}', '\u{ff04}'),
+    ('\u{ffe0}', '\u{ffe1}'), ('\u{ffe5}', '\u{ffe6}'), ('\u{11fdd}', '\u{11fe0}'), ('\u{1e2ff}', '\u{1e2ff}'),
+    ('\u{1ecb0}', '\u{1ecb0}'),
+];
+
+pub const GC_SK: &[(char, char)] = &[
+    ('\u{5e}', '\u{5e}'), ('\u{60}', '\u{60}'), ('\u{a8}', '\u{a8}'), ('\u{af}', '\u{af}'),
+    ('\u{b4}', '\u{b4}'), ('\u{b8}', '\u{b8}'), ('\u{2c2}', '\u{2c5}'), ('\u{2d2}', '\u{2df}'),
+    ('\u{2e5}', '\u{2eb}'), ('\u{2ed}', '\u{2ed}'), ('\u{2ef}', '\u{2ff}'), ('\u{375}', '\u{375}'),
+    ('\u{384}', '\u{385}'), ('\u{888}', '\u{888}'), ('\u{1fbd}', '\u{1fbd}'), ('\u{1fbf}', '\u{1fc1}'),
+    ('\u{1fcd}', '\u{1fcf}'), ('\u{1fdd}', '\u{1fdf}'), ('\u{1fed}', '\u{1fef}'), ('\u{1ffd}', '\u{1ffe}'),
+    ('\u{309b}', '\u{309c}'), ('\u{a700}', '\u{a716}'), ('\u{a720}', '\u{a721}'), ('\u{a789}', '\u{a78a}'),
+    ('\u{ab5b}', '\u{ab5b}'), ('\u{ab6a}', '\u{ab6b}'), ('\u{fbb2}', '\u{fbc2}'), ('\u{ff3e}', '\u{ff3e}'),
+    ('\u{ff40}', '\u{ff40}'), ('\u{ffe3}', '\u{ffe3}'), ('\u{1f3fb}', '\u{1f3ff}'),
+];
+
+pub const GC_SM: &[(char, char)] = &[
+    ('\u{2b}', '\u{2b}'), ('\u{3c}', '\u{3e}'), ('\u{7c}', '\u{7c}'), ('\u{7e}', '\u{7e}'),
+    ('\u{ac}', '\u{ac}'), ('\u{b1}', '\u{b1}'), ('\u{d7}', '\u{d7}'), ('\u{f7}', '\u{f7}'),
+    ('\u{3f6}', '\u{3f6}'), ('\u{606}', '\u{608}'), ('\u{2044}', '\u{2044}'), ('\u{2052}', '\u{2052}'),
+    ('\u{207a}', '\u{207c}'), ('\u{208a}', '\u{208c}'), ('\u{2118}', '\u{2118}'), ('\u{2140}', '\u{2144}'),
+    ('\u{214b}', '\u{214b}'), ('\u{2190}', '\u{2194}'), ('\u{219a}', '\u{219b}'), ('\u{21a0}', '\u{21a0}'),
+    ('\u{21a3}', '\u{21a3}'), ('\u{21a6}', '\u{21a6}'), ('\u{21ae}', '\u{21ae}'), ('\u{21ce}', '\u{21cf}'),
+    ('\u{21d2}', '\u{21d2}'), ('\u{21d4}', '\u{21d4}'), ('\u{21f4}', '\u{22ff}'), ('\u{2320}', '\u{2321}'),
+    ('\u{237c}', '\u{237c}'), ('\u{239b}', '\u{23b3}'), ('\u{23dc}', '\u{23e1}'), ('\u{25b7}', '\u{25b7}'),
+    ('\u{25c1}', '\u{25c1}'), ('\u{25f8}', '\u{25ff}'), ('\u{266f}', '\u{266f}'), ('\u{27c0}', '\u{27c4}'),
+    ('\u{27c7}', '\u{27e5}'), ('\u{27f0}', '\u{27ff}'), ('\u{2900}', '\u{2982}'), ('\u{2999}', '\u{29d7}'),
+    ('\u{29dc}', '\u{29fb}'), ('\u{29fe}', '\u{2aff}'), ('\u{2b30}', '\u{2b44}'), ('\u{2b47}', '\u{2b4c}'),
+    ('\u{fb29}', '\u{fb29}'), ('\u{fe62}', '\u{fe62}'), ('\u{fe64}', '\u{fe66}'), ('\u{ff0b}', '\u{ff0b}'),
+    ('\u{ff1c}', '\u{ff1e}'), ('\u{ff5c}', '\u{ff5c}'), ('\u{ff5e}', '\u{ff5e}'), ('\u{ffe2}', '\u{ffe2}'),
+    ('\u{ffe9}', '\u{ffec}'), ('\u{1d6c1}', '\u{1d6c1}'), ('\u{1d6db}', '\u{1d6db}'), ('\u{1d6fb}', '\u{1d6fb}'),
+    ('\u{1d715}', '\u{1d715}'), ('\u{1d735}', '\u{1d735}'), ('\u{1d74f}', '\u{1d74f}'), ('\u{1d76f}', '\u{1d76f}'),
+    ('\u{1d789}', '\u{1d789}'), ('\u{1d7a9}', '\u{1d7a9}'), ('\u{1d7c3}', '\u{1d7c3}'), ('\u{1eef0}', '\u{1eef1}'),
+];
+
+pub const GC_SO: &[(char, char)] = &[
+    ('\u{a6}', '\u{a6}'), ('\u{a9}', '\u{a9}'), ('\u{ae}', '\u{ae}'), ('\u{b0}', '\u{b0}'),
+    ('\u{482}', '\u{482}'), ('\u{58d}', '\u{58e}'), ('\u{60e}', '\u{60f}'), ('\u{6de}', '\u{6de}'),
+    ('\u{6e9}', '\u{6e9}'), ('\u{6fd}', '\u{6fe}'), ('\u{7f6}', '\u{7f6}'), ('\u{9fa}', '\u{9fa}'),
+    ('\u{b70}', '\u{b70}'), ('\u{bf3}', '\u{bf8}'), ('\u{bfa}', '\u{bfa}'), ('\u{c7f}', '\u{c7f}'),
+    ('\u{d4f}', '\u{d4f}'), ('\u{d79}', '\u{d79}'), ('\u{f01}', '\u{f03}'), ('\u{f13}', '\u{f13}'),
+    ('\u{f15}', '\u{f17}'), ('\u{f1a}', '\u{f1f}'), ('\u{f34}', '\u{f34}'), ('\u{f36}', '\u{f36}'),
+    ('\u{f38}', '\u{f38}'), ('\u{fbe}', '\u{fc5}'), ('\u{fc7}', '\u{fcc}'), ('\u{fce}', '\u{fcf}'),
+    ('\u{fd5}', '\u{fd8}'), ('\u{109e}', '\u{109f}'), ('\u{1390}', '\u{1399}'), ('\u{166d}', '\u{166d}'),
+    ('\u{1940}', '\u{1940}'), ('\u{19de}', '\u{19ff}'), ('\u{1b61}', '\u{1b6a}'), ('\u{1b74}', '\u{1b7c}'),
+    ('\u{2100}', '\u{2101}'), ('\u{2103}', '\u{2106}'), ('\u{2108}', '\u{2109}'), ('\u{2114}', '\u{2114}'),
+    ('\u{2116}', '\u{2117}'), ('\u{211e}', '\u{2123}'), ('\u{2125}', '\u{2125}'), ('\u{2127}', '\u{2127}'),
+    ('\u{2129}', '\u{2129}'), ('\u{212e}', '\u{212e}'), ('\u{213a}', '\u{213b}'), ('\u{214a}', '\u{214a}'),
+    ('\u{214c}', '\u{214d}'), ('\u{214f}', '\u{214f}'), ('\u{218a}', '\u{218b}'), ('\u{2195}', '\u{2199}'),
+    ('\u{219c}', '\u{219f}'), ('\u{21a1}', '\u{21a2}'), ('\u{21a4}', '\u{21a5}'), ('\u{21a7}', '\u{21ad}'),
+    ('\u{21af}', '\u{21cd}'), ('\u{21d0}', '\u{21d1}'), ('\u{21d3}', '\u{21d3}'), ('\u{21d5}', '\u{21f3}'),
+    ('\u{2300}', '\u{2307}'), ('\u{230c}', '\u{231f}'), ('\u{2322}', '\u{2328}'), ('\u{232b}', '\u{237b}'),
+    ('\u{237d}', '\u{239a}'), ('\u{23b4}', '\u{23db}'), ('\u{23e2}', '\u{2426}'), ('\u{2440}', '\u{244a}'),
+    ('\u{249c}', '\u{24e9}'), ('\u{2500}', '\u{25b6}'), ('\u{25b8}', '\u{25c0}'), ('\u{25c2}', '\u{25f7}'),
+    ('\u{2600}', '\u{266e}'), ('\u{2670}', '\u{2767}'), ('\u{2794}', '\u{27bf}'), ('\u{2800}', '\u{28ff}'),
+    ('\u{2b00}', '\u{2b2f}'), ('\u{2b45}', '\u{2b46}'), ('\u{2b4d}', '\u{2b73}'), ('\u{2b76}', '\u{2b95}'),
+    ('\u{2b97}', '\u{2bff}'), ('\u{2ce5}', '\u{2cea}'), ('\u{2e50}', '\u{2e51}'), ('\u{2e80}', '\u{2e99}'),
+    ('\u{2e9b}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{2ff0}', '\u{2ffb}'), ('\u{3004}', '\u{3004}'),
+    ('\u{3012}', '\u{3013}'), ('\u{3020}', '\u{3020}'), ('\u{3036}', '\u{3037}'), ('\u{303e}', '\u{303f}'),
+    ('\u{3190}', '\u{3191}'), ('\u{3196}', '\u{319f}'), ('\u{31c0}', '\u{31e3}'), ('\u{3200}', '\u{321e}'),
+    ('\u{322a}', '\u{3247}'), ('\u{3250}', '\u{3250}'), ('\u{3260}', '\u{327f}'), ('\u{328a}', '\u{32b0}'),
+    ('\u{32c0}', '\u{33ff}'), ('\u{4dc0}', '\u{4dff}'), ('\u{a490}', '\u{a4c6}'), ('\u{a828}', '\u{a82b}'),
+    ('\u{a836}', '\u{a837}'), ('\u{a839}', '\u{a839}'), ('\u{aa77}', '\u{aa79}'), ('\u{fd40}', '\u{fd4f}'),
+    ('\u{fdcf}', '\u{fdcf}'), ('\u{fdfd}', '\u{fdff}'), ('\u{ffe4}', '\u{ffe4}'), ('\u{ffe8}', '\u{ffe8}'),
+    ('\u{ffed}', '\u{ffee}'), ('\u{fffc}', '\u{fffd}'), ('\u{10137}', '\u{1013f}'), ('\u{10179}', '\u{10189}'),
+    ('\u{1018c}', '\u{1018e}'), ('\u{10190}', '\u{1019c}'), ('\u{101a0}', '\u{101a0}'), ('\u{101d0}', '\u{101fc}'),
+    ('\u{10877}', '\u{10878}'), ('\u{10ac8}', '\u{10ac8}'), ('\u{1173f}', '\u{1173f}'), ('\u{11fd5}', '\u{11fdc}'),
+    ('\u{11fe1}', '\u{11ff1}'), ('\u{16b3c}', '\u{16b3f}'), ('\u{16b45}', '\u{16b45}'), ('\u{1bc9c}', '\u{1bc9c}'),
+    ('\u{1cf50}', '\u{1cfc3}'), ('\u{1d000}', '\u{1d0f5}'), ('\u{1d100}', '\u{1d126}'), ('\u{1d129}', '\u{1d164}'),
+    ('\u{1d16a}', '\u{1d16c}'), ('\u{1d183}', '\u{1d184}'), ('\u{1d18c}', '\u{1d1a9}'), ('\u{1d1ae}', '\u{1d1ea}'),
+    ('\u{1d200}', '\u{1d241}'), ('\u{1d245}', '\u{1d245}'), ('\u{1d300}', '\u{1d356}'), ('\u{1d800}', '\u{1d9ff}'),
+    ('\u{1da37}', '\u{1da3a}'), ('\u{1da6d}', '\u{1da74}'), ('\u{1da76}', '\u{1da83}'), ('\u{1da85}', '\u{1da86}'),
+    ('\u{1e14f}', '\u{1e14f}'), ('\u{1ecac}', '\u{1ecac}'), ('\u{1ed2e}', '\u{1ed2e}'), ('\u{1f000}', '\u{1f02b}'),
+    ('\u{1f030}', '\u{1f093}'), ('\u{1f0a0}', '\u{1f0ae}'), ('\u{1f0b1}', '\u{1f0bf}'), ('\u{1f0c1}', '\u{1f0cf}'),
+    ('\u{1f0d1}', '\u{1f0f5}'), ('\u{1f10d}', '\u{1f1ad}'), ('\u{1f1e6}', '\u{1f202}'), ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'), ('\u{1f260}', '\u{1f265}'), ('\u{1f300}', '\u{1f3fa}'),
+    ('\u{1f400}', '\u{1f6d7}'), ('\u{1f6dd}', '\u{1f6ec}'), ('\u{1f6f0}', '\u{1f6fc}'), ('\u{1f700}', '\u{1f773}'),
+    ('\u{1f780}', '\u{1f7d8}'), ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}', '\u{1f7f0}'), ('\u{1f800}', '\u{1f80b}'),
+    ('\u{1f810}', '\u{1f847}'), ('\u{1f850}', '\u{1f859}'), ('\u{1f860}', '\u{1f887}'), ('\u{1f890}', '\u{1f8ad}'),
+    ('\u{1f8b0}', '\u{1f8b1}'), ('\u{1f900}', '\u{1fa53}'), ('\u{1fa60}', '\u{1fa6d}'), ('\u{1fa70}', '\u{1fa74}'),
+    ('\u{1fa78}', '\u{1fa7c}'), ('\u{1fa80}', '\u{1fa86}'), ('\u{1fa90}', '\u{1faac}'), ('\u{1fab0}', '\u{1faba}'),
+    ('\u{1fac0}', '\u{1fac5}'), ('\u{1fad0}', '\u{1fad9}'), ('\u{1fae0}', '\u{1fae7}'), ('\u{1faf0}', '\u{1faf6}'),
+    ('\u{1fb00}', '\u{1fb92}'), ('\u{1fb94}', '\u{1fbca}'),
+];
+
+pub const GC_Z: &[(char, char)] = &[
+    ('\u{20}', '\u{20}'), ('\u{a0}', '\u{a0}'), ('\u{1680}', '\u{1680}'), ('\u{2000}', '\u{200a}'),
+    ('\u{2028}', '\u{2029}'), ('\u{202f}', '\u{202f}'), ('\u{205f}', '\u{205f}'), ('\u{3000}', '\u{3000}'),
+];
+
+pub const GC_ZL: &[(char, char)] = &[
+    ('\u{2028}', '\u{2028}'),
+];
+
+pub const GC_ZP: &[(char, char)] = &[
+    ('\u{2029}', '\u{2029}'),
+];
+
+pub const GC_ZS: &[(char, char)] = &[
+    ('\u{20}', '\u{20}'), ('\u{a0}', '\u{a0}'), ('\u{1680}', '\u{1680}'), ('\u{2000}', '\u{200a}'),
+    ('\u{202f}', '\u{202f}'), ('\u{205f}', '\u{205f}'), ('\u{3000}', '\u{3000}'),
+];
+
+pub const GENERAL_CATEGORIES: &[PropertyEntry] = &[
+    ("C", &["C", "Other"], GC_C),
+    ("Cc", &["Cc", "Control", "Cntrl"], GC_CC),
+    ("Cf", &["Cf", "Format"], GC_CF),
+    ("Cn", &["Cn", "Unassigned"], GC_CN),
+    ("Co", &["Co", "Private_Use"], GC_CO),
+    ("Cs", &["Cs", "Surrogate"], GC_CS),
+    ("L", &["L", "Letter"], GC_L),
+    ("LC", &["LC", "Cased_Letter"], GC_LC),
+    ("Ll", &["Ll", "Lowercase_Letter"], GC_LL),
+    ("Lm", &["Lm", "Modifier_Letter"], GC_LM),
+    ("Lo", &["Lo", "Other_Letter"], GC_LO),
+    ("Lt", &["Lt", "Titlecase_Letter"], GC_LT),
+    ("Lu", &["Lu", "Uppercase_Letter"], GC_LU),
+    ("M", &["M", "Mark", "Combining_Mark"], GC_M),
+    ("Mc", &["Mc", "Spacing_Mark"], GC_MC),
+    ("Me", &["Me", "Enclosing_Mark"], GC_ME),
+    ("Mn", &["Mn", "Nonspacing_Mark"], GC_MN),
+    ("N", &["N", "Number"], GC_N),
+    ("Nd", &["Nd", "Decimal_Number", "Digit"], GC_ND),
+    ("Nl", &["Nl", "Letter_Number"], GC_NL),
+    ("No", &["No", "Other_Number"], GC_NO),
+    ("P", &["P", "Punctuation", "Punct"], GC_P),
+    ("Pc", &["Pc", "Connector_Punctuation"], GC_PC),
+    ("Pd", &["Pd", "Dash_Punctuation"], GC_PD),
+    ("Pe", &["Pe", "Close_Punctuation"], GC_PE),
+    ("Pf", &["Pf", "Final_Punctuation"], GC_PF),
+    ("Pi", &["Pi", "Initial_Punctuation"], GC_PI),
+    ("Po", &["Po", "Other_Punctuation"], GC_PO),
+    ("Ps", &["Ps", "Open_Punctuation"], GC_PS),
+    ("S", &["S", "Symbol"], GC_S),
+    ("Sc", &["Sc", "Currency_Symbol"], GC_SC),
+    ("Sk", &["Sk", "Modifier_Symbol"], GC_SK),
+    ("Sm", &["Sm", "Math_Symbol"], GC_SM),
+    ("So", &["So", "Other_Symbol"], GC_SO),
+    ("Z", &["Z", "Separator"], GC_Z),
+    ("Zl", &["Zl", "Line_Separator"], GC_ZL),
+    ("Zp", &["Zp", "Paragraph_Separator"], GC_ZP),
+    ("Zs", &["Zs", "Space_Separator"], GC_ZS),
+];
+
+pub const SC_ADLAM: &[(char, char)] = &[
+    ('\u{1e900}', '\u{1e94b}'), ('\u{1e950}', '\u{1e959}'), ('\u{1e95e}', '\u{1e95f}'),
+];
+
+pub const SC_CAUCASIAN_ALBANIAN: &[(char, char)] = &[
+    ('\u{10530}', '\u{10563}'), ('\u{1056f}', '\u{1056f}'),
+];
+
+pub const SC_AHOM: &[(char, char)] = &[
+    ('\u{11700}', '\u{1171a}'), ('\u{1171d}', '\u{1172b}'), ('\u{11730}', '\u{11746}'),
+];
+
+pub const SC_ARABIC: &[(char, char)] = &[
+    ('\u{600}', '\u{604}'), ('\u{606}', '\u{60b}'), ('\u{60d}', '\u{61a}'), ('\u{61c}', '\u{61e}'),
+    ('\u{620}', '\u{63f}'), ('\u{641}', '\u{64a}'), ('\u{656}', '\u{66f}'), ('\u{671}', '\u{6dc}'),
+    ('\u{6de}', '\u{6ff}'), ('\u{750}', '\u{77f}'), ('\u{870}', '\u{88e}'), ('\u{890}', '\u{891}'),
+    ('\u{898}', '\u{8e1}'), ('\u{8e3}', '\u{8ff}'), ('\u{fb50}', '\u{fbc2}'), ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd40}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'), ('\u{fdcf}', '\u{fdcf}'), ('\u{fdf0}', '\u{fdff}'),
+    ('\u{fe70}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'), ('\u{10e60}', '\u{10e7e}'), ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1eef0}', '\u{1eef1}'),
+];
+
+pub const SC_IMPERIAL_ARAMAIC: &[(char, char)] = &[
+    ('\u{10840}', '\u{10855}'), ('\u{10857}', '\u{1085f}'),
+];
+
+pub const SC_ARMENIAN: &[(char, char)] = &[
+    ('\u{531}', '\u{556}'), ('\u{559}', '\u{58a}'), ('\u{58d}', '\u{58f}'), ('\u{fb13}', '\u{fb17}'),
+];
+
+pub const SC_AVESTAN: &[(char, char)] = &[
+    ('\u{10b00}', '\u{10b35}'), ('\u{10b39}', '\u{10b3f}'),
+];
+
+pub const SC_BALINESE: &[(char, char)] = &[
+    ('\u{1b00}', '\u{1b4c}'), ('\u{1b50}', '\u{1b7e}'),
+];
+
+pub const SC_BAMUM: &[(char, char)] = &[
+    ('\u{a6a0}', '\u{a6f7}'), ('\u{16800}', '\u{16a38}'),
+];
+
+pub const SC_BASSA_VAH: &[(char, char)] = &[
+    ('\u{16ad0}', '\u{16aed}'), ('\u{16af0}', '\u{16af5}'),
+];
+
+pub const SC_BATAK: &[(char, char)] = &[
+    ('\u{1bc0}', '\u{1bf3}'), ('\u{1bfc}', '\u{1bff}'),
+];
+
+pub const SC_BENGALI: &[(char, char)] = &[
+    ('\u{980}', '\u{983}'), ('\u{985}', '\u{98c}'), ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'), ('\u{9b6}', '\u{9b9}'), ('\u{9bc}', '\u{9c4}'),
+    ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9ce}'), ('\u{9d7}', '\u{9d7}'), ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e3}'), ('\u{9e6}', '\u{9fe}'),
+];
+
+pub const SC_BHAIKSUKI: &[(char, char)] = &[
+    ('\u{11c00}', '\u{11c08}'), ('\u{11c0a}', '\u{11c36}'), ('\u{11c38}', '\u{11c45}'), ('\u{11c50}', '\u{11c6c}'),
+];
+
+pub const SC_BOPOMOFO: &[(char, char)] = &[
+    ('\u{2ea}', '\u{2eb}'), ('\u{3105}', '\u{312f}'), ('\u{31a0}', '\u{31bf}'),
+];
+
+pub const SC_BRAHMI: &[(char, char)] = &[
+    ('\u{11000}', '\u{1104d}'), ('\u{11052}', '\u{11075}'), ('\u{1107f}', '\u{1107f}'),
+];
+
+pub const SC_BRAILLE: &[(char, char)] = &[
+    ('\u{2800}', '\u{28ff}'),
+];
+
+pub const SC_BUGINESE: &[(char, char)] = &[
+    ('\u{1a00}', '\u{1a1b}'), ('\u{1a1e}', '\u{1a1f}'),
+];
+
+pub const SC_BUHID: &[(char, char)] = &[
+    ('\u{1740}', '\u{1753}'),
+];
+
+pub const SC_CHAKMA: &[(char, char)] = &[
+    ('\u{11100}', '\u{11134}'), ('\u{11136}', '\u{11147}'),
+];
+
+pub const SC_CANADIAN_ABORIGINAL: &[(char, char)] = &[
+    ('\u{1400}', '\u{167f}'), ('\u{18b0}', '\u{18f5}'), ('\u{11ab0}', '\u{11abf}'),
+];
+
+pub const SC_CARIAN: &[(char, char)] = &[
+    ('\u{102a0}', '\u{102d0}'),
+];
+
+pub const SC_CHAM: &[(char, char)] = &[
+    ('\u{aa00}', '\u{aa36}'), ('\u{aa40}', '\u{aa4d}'), ('\u{aa50}', '\u{aa59}'), ('\u{aa5c}', '\u{aa5f}'),
+];
+
+pub const SC_CHEROKEE: &[(char, char)] = &[
+    ('\u{13a0}', '\u{13f5}'), ('\u{13f8}', '\u{13fd}'), ('\u{ab70}', '\u{abbf}'),
+];
+
+pub const SC_CHORASMIAN: &[(char, char)] = &[
+    ('\u{10fb0}', '\u{10fcb}'),
+];
+
+pub const SC_COPTIC: &[(char, char)] = &[
+    ('\u{3e2}', '\u{3ef}'), ('\u{2c80}', '\u{2cf3}'), ('\u{2cf9}', '\u{2cff}'),
+];
+
+pub const SC_CYPRO_MINOAN: &[(char, char)] = &[
+    ('\u{12f90}', '\u{12ff2}'),
+];
+
+pub const SC_CYPRIOT: &[(char, char)] = &[
+    ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'), ('\u{1083f}', '\u{1083f}'),
+];
+
+pub const SC_CYRILLIC: &[(char, char)] = &[
+    ('\u{400}', '\u{484}'), ('\u{487}', '\u{52f}'), ('\u{1c80}', '\u{1c88}'), ('\u{1d2b}', '\u{1d2b}'),
+    ('\u{1d78}', '\u{1d78}'), ('\u{2de0}', '\u{2dff}'), ('\u{a640}', '\u{a69f}'), ('\u{fe2e}', '\u{fe2f}'),
+];
+
+pub const SC_DEVANAGARI: &[(char, char)] = &[
+    ('\u{900}', '\u{950}'), ('\u{955}', '\u{963}'), ('\u{966}', '\u{97f}'), ('\u{a8e0}', '\u{a8ff}'),
+];
+
+pub const SC_DIVES_AKURU: &[(char, char)] = &[
+    ('\u{11900}', '\u{11906}'), ('\u{11909}', '\u{11909}'), ('\u{1190c}', '\u{11913}'), ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'), ('\u{11937}', '\u{11938}'), ('\u{1193b}', '\u{11946}'), ('\u{11950}', '\u{11959}'),
+];
+
+pub const SC_DOGRA: &[(char, char)] = &[
+    ('\u{11800}', '\u{1183b}'),
+];
+
+pub const SC_DESERET: &[(char, char)] = &[
+    ('\u{10400}', '\u{1044f}'),
+];
+
+pub const SC_DUPLOYAN: &[(char, char)] = &[
+    ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1bc9c}', '\u{1bc9f}'),
+];
+
+pub const SC_EGYPTIAN_HIEROGLYPHS: &[(char, char)] = &[
+    ('\u{13000}', '\u{1342e}'), ('\u{13430}', '\u{13438}'),
+];
+
+pub const SC_ELBASAN: &[(char, char)] = &[
+    ('\u{10500}', '\u{10527}'),
+];
+
+pub const SC_ELYMAIC: &[(char, char)] = &[
+    ('\u{10fe0}', '\u{10ff6}'),
+];
+
+pub const SC_ETHIOPIC: &[(char, char)] = &[
+    ('\u{1200}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'), ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{135a}'),
+    ('\u{135d}', '\u{137c}'), ('\u{1380}', '\u{1399}'), ('\u{2d80}', '\u{2d96}'), ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'), ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'), ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'), ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'),
+    ('\u{1e7e0}', '\u{1e7e6}'), ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'),
+];
+
+pub const SC_GEORGIAN: &[(char, char)] = &[
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{10ff}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'),
+];
+
+pub const SC_GLAGOLITIC: &[(char, char)] = &[
+    ('\u{2c00}', '\u{2c5f}'), ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'), ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'), ('\u{1e026}', '\u{1e02a}'),
+];
+
+pub const SC_GUNJALA_GONDI: &[(char, char)] = &[
+    ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'), ('\u{11d6a}', '\u{11d8e}'), ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d93}', '\u{11d98}'), ('\u{11da0}', '\u{11da9}'),
+];
+
+pub const SC_MASARAM_GONDI: &[(char, char)] = &[
+    ('\u{11d00}', '\u{11d06}'), ('\u{11d08}', '\u{11d09}'), ('\u{11d0b}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'), ('\u{11d3f}', '\u{11d47}'), ('\u{11d50}', '\u{11d59}'),
+];
+
+pub const SC_GOTHIC: &[(char, char)] = &[
+    ('\u{10330}', '\u{1034a}'),
+];
+
+pub const SC_GRANTHA: &[(char, char)] = &[
+    ('\u{11300}', '\u{11303}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133c}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'), ('\u{1134b}', '\u{1134d}'), ('\u{11350}', '\u{11350}'), ('\u{11357}', '\u{11357}'),
+    ('\u{1135d}', '\u{11363}'), ('\u{11366}', '\u{1136c}'), ('\u{11370}', '\u{11374}'),
+];
+
+pub const SC_GREEK: &[(char, char)] = &[
+    ('\u{370}', '\u{373}'), ('\u{375}', '\u{377}'), ('\u{37a}', '\u{37d}'), ('\u{37f}', '\u{37f}'),
+    ('\u{384}', '\u{384}'), ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'), ('\u{3a3}', '\u{3e1}'), ('\u{3f0}', '\u{3ff}'), ('\u{1d26}', '\u{1d2a}'),
+    ('\u{1d5d}', '\u{1d61}'), ('\u{1d66}', '\u{1d6a}'), ('\u{1dbf}', '\u{1dbf}'), ('\u{1f00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'), ('\u{1f48}', '\u{1f4d}'), ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'), ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fd3}'), ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fdd}', '\u{1fef}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffe}'), ('\u{2126}', '\u{2126}'),
+    ('\u{ab65}', '\u{ab65}'), ('\u{10140}', '\u{1018e}'), ('\u{101a0}', '\u{101a0}'), ('\u{1d200}', '\u{1d245}'),
+];
+
+pub const SC_GUJARATI: &[(char, char)] = &[
+    ('\u{a81}', '\u{a83}'), ('\u{a85}', '\u{a8d}'), ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'), ('\u{ab5}', '\u{ab9}'), ('\u{abc}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acd}'), ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae3}'),
+    ('\u{ae6}', '\u{af1}'), ('\u{af9}', '\u{aff}'),
+];
+
+pub const SC_GURMUKHI: &[(char, char)] = &[
+    ('\u{a01}', '\u{a03}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'), ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'), ('\u{a35}', '\u{a36}'), ('\u{a38}', '\u{a39}'),
+    ('\u{a3c}', '\u{a3c}'), ('\u{a3e}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'), ('\u{a66}', '\u{a76}'),
+];
+
+pub const SC_HANGUL: &[(char, char)] = &[
+    ('\u{1100}', '\u{11ff}'), ('\u{302e}', '\u{302f}'), ('\u{3131}', '\u{318e}'), ('\u{3200}', '\u{321e}'),
+    ('\u{3260}', '\u{327e}'), ('\u{a960}', '\u{a97c}'), ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'), ('\u{ffa0}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'),
+];
+
+pub const SC_HAN: &[(char, char)] = &[
+    ('\u{2e80}', '\u{2e99}'), ('\u{2e9b}', '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{3005}', '\u{3005}'),
+    ('\u{3007}', '\u{3007}'), ('\u{3021}', '\u{3029}'), ('\u{3038}', '\u{303b}'), ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{9fff}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'), ('\u{16fe2}', '\u{16fe3}'),
+    ('\u{16ff0}', '\u{16ff1}'), ('\u{20000}', '\u{2a6df}'), ('\u{2a700}', '\u{2b738}'), ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'), ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'),
+];
+
+pub const SC_HANUNOO: &[(char, char)] = &[
+    ('\u{1720}', '\u{1734}'),
+];
+
+pub const SC_HATRAN: &[(char, char)] = &[
+    ('\u{108e0}', '\u{108f2}'), ('\u{108f4}', '\u{108f5}'), ('\u{108fb}', '\u{108ff}'),
+];
+
+pub const SC_HEBREW: &[(char, char)] = &[
+    ('\u{591}', '\u{5c7}'), ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f4}'), ('\u{fb1d}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fb4f}'),
+];
+
+pub const SC_HIRAGANA: &[(char, char)] = &[
+    ('\u{3041}', '\u{3096}'), ('\u{309d}', '\u{309f}'), ('\u{1b001}', '\u{1b11f}'), ('\u{1b150}', '\u{1b152}'),
+    ('\u{1f200}', '\u{1f200}'),
+];
+
+pub const SC_ANATOLIAN_HIEROGLYPHS: &[(char, char)] = &[
+    ('\u{14400}', '\u{14646}'),
+];
+
+pub const SC_PAHAWH_HMONG: &[(char, char)] = &[
+    ('\u{16b00}', '\u{16b45}'), ('\u{16b50}', '\u{16b59}'), ('\u{16b5b}', '\u{16b61}'), ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+];
+
+pub const SC_NYIAKENG_PUACHUE_HMONG: &[(char, char)] = &[
+    ('\u{1e100}', '\u{1e12c}'), ('\u{1e130}', '\u{1e13d}'), ('\u{1e140}', '\u{1e149}'), ('\u{1e14e}', '\u{1e14f}'),
+];
+
+pub const SC_OLD_HUNGARIAN: &[(char, char)] = &[
+    ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{10cfa}', '\u{10cff}'),
+];
+
+pub const SC_OLD_ITALIC: &[(char, char)] = &[
+    ('\u{10300}', '\u{10323}'), ('\u{1032d}', '\u{1032f}'),
+];
+
+pub const SC_JAVANESE: &[(char, char)] = &[
+    ('\u{a980}', '\u{a9cd}'), ('\u{a9d0}', '\u{a9d9}'), ('\u{a9de}', '\u{a9df}'),
+];
+
+pub const SC_KAYAH_LI: &[(char, char)] = &[
+    ('\u{a900}', '\u{a92d}'), ('\u{a92f}', '\u{a92f}'),
+];
+
+pub const SC_KATAKANA: &[(char, char)] = &[
+    ('\u{30a1}', '\u{30fa}'), ('\u{30fd}', '\u{30ff}'), ('\u{31f0}', '\u{31ff}'), ('\u{32d0}', '\u{32fe}'),
+    ('\u{3300}', '\u{3357}'), ('\u{ff66}', '\u{ff6f}'), ('\u{ff71}', '\u{ff9d}'), ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'), ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b000}'), ('\u{1b120}', '\u{1b122}'),
+    ('\u{1b164}', '\u{1b167}'),
+];
+
+pub const SC_KHAROSHTHI: &[(char, char)] = &[
+    ('\u{10a00}', '\u{10a03}'), ('\u{10a05}', '\u{10a06}'), ('\u{10a0c}', '\u{10a13}'), ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'), ('\u{10a38}', '\u{10a3a}'), ('\u{10a3f}', '\u{10a48}'), ('\u{10a50}', '\u{10a58}'),
+];
+
+pub const SC_KHMER: &[(char, char)] = &[
+    ('\u{1780}', '\u{17dd}'), ('\u{17e0}', '\u{17e9}'), ('\u{17f0}', '\u{17f9}'), ('\u{19e0}', '\u{19ff}'),
+];
+
+pub const SC_KHOJKI: &[(char, char)] = &[
+    ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{1123e}'),
+];
+
+pub const SC_KHITAN_SMALL_SCRIPT: &[(char, char)] = &[
+    ('\u{16fe4}', '\u{16fe4}'), ('\u{18b00}', '\u{18cd5}'),
+];
+
+pub const SC_KANNADA: &[(char, char)] = &[
+    ('\u{c80}', '\u{c8c}'), ('\u{c8e}', '\u{c90}'), ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb3}'),
+    ('\u{cb5}', '\u{cb9}'), ('\u{cbc}', '\u{cc4}'), ('\u{cc6}', '\u{cc8}'), ('\u{cca}', '\u{ccd}'),
+    ('\u{cd5}', '\u{cd6}'), ('\u{cdd}', '\u{cde}'), ('\u{ce0}', '\u{ce3}'), ('\u{ce6}', '\u{cef}'),
+    ('\u{cf1}', '\u{cf2}'),
+];
+
+pub const SC_KAITHI: &[(char, char)] = &[
+    ('\u{11080}', '\u{110c2}'), ('\u{110cd}', '\u{110cd}'),
+];
+
+pub const SC_TAI_THAM: &[(char, char)] = &[
+    ('\u{1a20}', '\u{1a5e}'), ('\u{1a60}', '\u{1a7c}'), ('\u{1a7f}', '\u{1a89}'), ('\u{1a90}', '\u{1a99}'),
+    ('\u{1aa0}', '\u{1aad}'),
+];
+
+pub const SC_LAO: &[(char, char)] = &[
+    ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'), ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'), ('\u{ea7}', '\u{ebd}'), ('\u{ec0}', '\u{ec4}'), ('\u{ec6}', '\u{ec6}'),
+    ('\u{ec8}', '\u{ecd}'), ('\u{ed0}', '\u{ed9}'), ('\u{edc}', '\u{edf}'),
+];
+
+pub const SC_LATIN: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'), ('\u{61}', '\u{7a}'), ('\u{aa}', '\u{aa}'), ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{2b8}'), ('\u{2e0}', '\u{2e4}'),
+    ('\u{1d00}', '\u{1d25}'), ('\u{1d2c}', '\u{1d5c}'), ('\u{1d62}', '\u{1d65}'), ('\u{1d6b}', '\u{1d77}'),
+    ('\u{1d79}', '\u{1dbe}'), ('\u{1e00}', '\u{1eff}'), ('\u{2071}', '\u{2071}'), ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'), ('\u{212a}', '\u{212b}'), ('\u{2132}', '\u{2132}'), ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'), ('\u{2c60}', '\u{2c7f}'), ('\u{a722}', '\u{a787}'), ('\u{a78b}', '\u{a7ca}'),
+    ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'), ('\u{a7d5}', '\u{a7d9}'), ('\u{a7f2}', '\u{a7ff}'),
+    ('\u{ab30}', '\u{ab5a}'), ('\u{ab5c}', '\u{ab64}'), ('\u{ab66}', '\u{ab69}'), ('\u{fb00}', '\u{fb06}'),
+    ('\u{ff21}', '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{10780}', '\u{10785}'), ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'), ('\u{1df00}', '\u{1df1e}'),
+];
+
+pub const SC_LEPCHA: &[(char, char)] = &[
+    ('\u{1c00}', '\u{1c37}'), ('\u{1c3b}', '\u{1c49}'), ('\u{1c4d}', '\u{1c4f}'),
+];
+
+pub const SC_LIMBU: &[(char, char)] = &[
+    ('\u{1900}', '\u{191e}'), ('\u{1920}', '\u{192b}'), ('\u{1930}', '\u{193b}'), ('\u{1940}', '\u{1940}'),
+    ('\u{1944}', '\u{194f}'),
+];
+
+pub const SC_LINEAR_A: &[(char, char)] = &[
+    ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'),
+];
+
+pub const SC_LINEAR_B: &[(char, char)] = &[
+    ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'),
+];
+
+pub const SC_LISU: &[(char, char)] = &[
+    ('\u{a4d0}', '\u{a4ff}'), ('\u{11fb0}', '\u{11fb0}'),
+];
+
+pub const SC_LYCIAN: &[(char, char)] = &[
+    ('\u{10280}', '\u{1029c}'),
+];
+
+pub const SC_LYDIAN: &[(char, char)] = &[
+    ('\u{10920}', '\u{10939}'), ('\u{1093f}', '\u{1093f}'),
+];
+
+pub const SC_MAHAJANI: &[(char, char)] = &[
+    ('\u{11150}', '\u{11176}'),
+];
+
+pub const SC_MAKASAR: &[(char, char)] = &[
+    ('\u{11ee0}', '\u{11ef8}'),
+];
+
+pub const SC_MANDAIC: &[(char, char)] = &[
+    ('\u{840}', '\u{85b}'), ('\u{85e}', '\u{85e}'),
+];
+
+pub const SC_MANICHAEAN: &[(char, char)] = &[
+    ('\u{10ac0}', '\u{10ae6}'), ('\u{10aeb}', '\u{10af6}'),
+];
+
+pub const SC_MARCHEN: &[(char, char)] = &[
+    ('\u{11c70}', '\u{11c8f}'), ('\u{11c92}', '\u{11ca7}'), ('\u{11ca9}', '\u{11cb6}'),
+];
+
+pub const SC_MEDEFAIDRIN: &[(char, char)] = &[
+    ('\u{16e40}', '\u{16e9a}'),
+];
+
+pub const SC_MENDE_KIKAKUI: &[(char, char)] = &[
+    ('\u{1e800}', '\u{1e8c4}'), ('\u{1e8c7}', '\u{1e8d6}'),
+];
+
+pub const SC_MEROITIC_CURSIVE: &[(char, char)] = &[
+    ('\u{109a0}', '\u{109b7}'), ('\u{109bc}', '\u{109cf}'), ('\u{109d2}', '\u{109ff}'),
+];
+
+pub const SC_MEROITIC_HIEROGLYPHS: &[(char, char)] = &[
+    ('\u{10980}', '\u{1099f}'),
+];
+
+pub const SC_MALAYALAM: &[(char, char)] = &[
+    ('\u{d00}', '\u{d0c}'), ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d44}'), ('\u{d46}', '\u{d48}'),
+    ('\u{d4a}', '\u{d4f}'), ('\u{d54}', '\u{d63}'), ('\u{d66}', '\u{d7f}'),
+];
+
+pub const SC_MODI: &[(char, char)] = &[
+    ('\u{11600}', '\u{11644}'), ('\u{11650}', '\u{11659}'),
+];
+
+pub const SC_MONGOLIAN: &[(char, char)] = &[
+    ('\u{1800}', '\u{1801}'), ('\u{1804}', '\u{1804}'), ('\u{1806}', '\u{1819}'), ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18aa}'), ('\u{11660}', '\u{1166c}'),
+];
+
+pub const SC_MRO: &[(char, char)] = &[
+    ('\u{16a40}', '\u{16a5e}'), ('\u{16a60}', '\u{16a69}'), ('\u{16a6e}', '\u{16a6f}'),
+];
+
+pub const SC_MEETEI_MAYEK: &[(char, char)] = &[
+    ('\u{aae0}', '\u{aaf6}'), ('\u{abc0}', '\u{abed}'), ('\u{abf0}', '\u{abf9}'),
+];
+
+pub const SC_MULTANI: &[(char, char)] = &[
+    ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'), ('\u{1128a}', '\u{1128d}'), ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a9}'),
+];
+
+pub const SC_MYANMAR: &[(char, char)] = &[
+    ('\u{1000}', '\u{109f}'), ('\u{a9e0}', '\u{a9fe}'), ('\u{aa60}', '\u{aa7f}'),
+];
+
+pub const SC_NANDINAGARI: &[(char, char)] = &[
+    ('\u{119a0}', '\u{119a7}'), ('\u{119aa}', '\u{119d7}'), ('\u{119da}', '\u{119e4}'),
+];
+
+pub const SC_OLD_NORTH_ARABIAN: &[(char, char)] = &[
+    ('\u{10a80}', '\u{10a9f}'),
+];
+
+pub const SC_NABATAEAN: &[(char, char)] = &[
+    ('\u{10880}', '\u{1089e}'), ('\u{108a7}', '\u{108af}'),
+];
+
+pub const SC_NEWA: &[(char, char)] = &[
+    ('\u{11400}', '\u{1145b}'), ('\u{1145d}', '\u{11461}'),
+];
+
+pub const SC_NKO: &[(char, char)] = &[
+    ('\u{7c0}', '\u{7fa}'), ('\u{7fd}', '\u{7ff}'),
+];
+
+pub const SC_NUSHU: &[(char, char)] = &[
+    ('\u{16fe1}', '\u{16fe1}'), ('\u{1b170}', '\u{1b2fb}'),
+];
+
+pub const SC_OGHAM: &[(char, char)] = &[
+    ('\u{1680}', '\u{169c}'),
+];
+
+pub const SC_OL_CHIKI: &[(char, char)] = &[
+    ('\u{1c50}', '\u{1c7f}'),
+];
+
+pub const SC_OLD_TURKIC: &[(char, char)] = &[
+    ('\u{10c00}', '\u{10c48}'),
+];
+
+pub const SC_ORIYA: &[(char, char)] = &[
+    ('\u{b01}', '\u{b03}'), ('\u{b05}', '\u{b0c}'), ('\u{b0f}', '\u{b10}'), ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'), ('\u{b35}', '\u{b39}'), ('\u{b3c}', '\u{b44}'),
+    ('\u{b47}', '\u{b48}'), ('\u{b4b}', '\u{b4d}'), ('\u{b55}', '\u{b57}'), ('\u{b5c}', '\u{b5d}'),
+    ('\u{b5f}', '\u{b63}'), ('\u{b66}', '\u{b77}'),
+];
+
+pub const SC_OSAGE: &[(char, char)] = &[
+    ('\u{104b0}', '\u{104d3}'), ('\u{104d8}', '\u{104fb}'),
+];
+
+pub const SC_OSMANYA: &[(char, char)] = &[
+    ('\u{10480}', '\u{1049d}'), ('\u{104a0}', '\u{104a9}'),
+];
+
+pub const SC_OLD_UYGHUR: &[(char, char)] = &[
+    ('\u{10f70}', '\u{10f89}'),
+];
+
+pub const SC_PALMYRENE: &[(char, char)] = &[
+    ('\u{10860}', '\u{1087f}'),
+];
+
+pub const SC_PAU_CIN_HAU: &[(char, char)] = &[
+    ('\u{11ac0}', '\u{11af8}'),
+];
+
+pub const SC_OLD_PERMIC: &[(char, char)] = &[
+    ('\u{10350}', '\u{1037a}'),
+];
+
+pub const SC_PHAGS_PA: &[(char, char)] = &[
+    ('\u{a840}', '\u{a877}'),
+];
+
+pub const SC_INSCRIPTIONAL_PAHLAVI: &[(char, char)] = &[
+    ('\u{10b60}', '\u{10b72}'), ('\u{10b78}', '\u{10b7f}'),
+];
+
+pub const SC_PSALTER_PAHLAVI: &[(char, char)] = &[
+    ('\u{10b80}', '\u{10b91}'), ('\u{10b99}', '\u{10b9c}'), ('\u{10ba9}', '\u{10baf}'),
+];
+
+pub const SC_PHOENICIAN: &[(char, char)] = &[
+    ('\u{10900}', '\u{1091b}'), ('\u{1091f}', '\u{1091f}'),
+];
+
+pub const SC_MIAO: &[(char, char)] = &[
+    ('\u{16f00}', '\u{16f4a}'), ('\u{16f4f}', '\u{16f87}'), ('\u{16f8f}', '\u{16f9f}'),
+];
+
+pub const SC_INSCRIPTIONAL_PARTHIAN: &[(char, char)] = &[
+    ('\u{10b40}', '\u{10b55}'), ('\u{10b58}', '\u{10b5f}'),
+];
+
+pub const SC_REJANG: &[(char, char)] = &[
+    ('\u{a930}', '\u{a953}'), ('\u{a95f}', '\u{a95f}'),
+];
+
+pub const SC_HANIFI_ROHINGYA: &[(char, char)] = &[
+    ('\u{10d00}', '\u{10d27}'), ('\u{10d30}', '\u{10d39}'),
+];
+
+pub const SC_RUNIC: &[(char, char)] = &[
+    ('\u{16a0}', '\u{16ea}'), ('\u{16ee}', '\u{16f8}'),
+];
+
+pub const SC_SAMARITAN: &[(char, char)] = &[
+    ('\u{800}', '\u{82d}'), ('\u{830}', '\u{83e}'),
+];
+
+pub const SC_OLD_SOUTH_ARABIAN: &[(char, char)] = &[
+    ('\u{10a60}', '\u{10a7f}'),
+];
+
+pub const SC_SAURASHTRA: &[(char, char)] = &[
+    ('\u{a880}', '\u{a8c5}'), ('\u{a8ce}', '\u{a8d9}'),
+];
+
+pub const SC_SIGNWRITING: &[(char, char)] = &[
+    ('\u{1d800}', '\u{1da8b}'), ('\u{1da9b}', '\u{1da9f}'), ('\u{1daa1}', '\u{1daaf}'),
+];
+
+pub const SC_SHAVIAN: &[(char, char)] = &[
+    ('\u{10450}', '\u{1047f}'),
+];
+
+pub const SC_SHARADA: &[(char, char)] = &[
+    ('\u{11180}', '\u{111df}'),
+];
+
+pub const SC_SIDDHAM: &[(char, char)] = &[
+    ('\u{11580}', '\u{115b5}'), ('\u{115b8}', '\u{115dd}'),
+];
+
+pub const SC_KHUDAWADI: &[(char, char)] = &[
+    ('\u{112b0}', '\u{112ea}'), ('\u{112f0}', '\u{112f9}'),
+];
+
+pub const SC_SINHALA: &[(char, char)] = &[
+    ('\u{d81}', '\u{d83}'), ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'), ('\u{db3}', '\u{dbb}'),
+    ('\u{dbd}', '\u{dbd}'), ('\u{dc0}', '\u{dc6}'), ('\u{dca}', '\u{dca}'), ('\u{dcf}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'), ('\u{dd8}', '\u{ddf}'), ('\u{de6}', '\u{def}'), ('\u{df2}', '\u{df4}'),
+    ('\u{111e1}', '\u{111f4}'),
+];
+
+pub const SC_SOGDIAN: &[(char, char)] = &[
+    ('\u{10f30}', '\u{10f59}'),
+];
+
+pub const SC_OLD_SOGDIAN: &[(char, char)] = &[
+    ('\u{10f00}', '\u{10f27}'),
+];
+
+pub const SC_SORA_SOMPENG: &[(char, char)] = &[
+    ('\u{110d0}', '\u{110e8}'), ('\u{110f0}', '\u{110f9}'),
+];
+
+pub const SC_SOYOMBO: &[(char, char)] = &[
+    ('\u{11a50}', '\u{11aa2}'),
+];
+
+pub const SC_SUNDANESE: &[(char, char)] = &[
+    ('\u{1b80}', '\u{1bbf}'), ('\u{1cc0}', '\u{1cc7}'),
+];
+
+pub const SC_SYLOTI_NAGRI: &[(char, char)] = &[
+    ('\u{a800}', '\u{a82c}'),
+];
+
+pub const SC_SYRIAC: &[(char, char)] = &[
+    ('\u{700}', '\u{70d}'), ('\u{70f}', '\u{74a}'), ('\u{74d}', '\u{74f}'), ('\u{860}', '\u{86a}'),
+];
+
+pub const SC_TAGBANWA: &[(char, char)] = &[
+    ('\u{1760}', '\u{176c}'), ('\u{176e}', '\u{1770}'), ('\u{1772}', '\u{1773}'),
+];
+
+pub const SC_TAKRI: &[(char, char)] = &[
+    ('\u{11680}', '\u{116b9}'), ('\u{116c0}', '\u{116c9}'),
+];
+
+pub const SC_TAI_LE: &[(char, char)] = &[
+    ('\u{1950}', '\u{196d}'), ('\u{1970}', '\u{1974}'),
+];
+
+pub const SC_NEW_TAI_LUE: &[(char, char)] = &[
+    ('\u{1980}', '\u{19ab}'), ('\u{19b0}', '\u{19c9}'), ('\u{19d0}', '\u{19da}'), ('\u{19de}', '\u{19df}'),
+];
+
+pub const SC_TAMIL: &[(char, char)] = &[
+    ('\u{b82}', '\u{b83}'), ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'), ('\u{b92}', '\u{b95}'),
+    ('\u{b99}', '\u{b9a}'), ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'), ('\u{ba3}', '\u{ba4}'),
+    ('\u{ba8}', '\u{baa}'), ('\u{bae}', '\u{bb9}'), ('\u{bbe}', '\u{bc2}'), ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'), ('\u{bd0}', '\u{bd0}'), ('\u{bd7}', '\u{bd7}'), ('\u{be6}', '\u{bfa}'),
+    ('\u{11fc0}', '\u{11ff1}'), ('\u{11fff}', '\u{11fff}'),
+];
+
+pub const SC_TANGUT: &[(char, char)] = &[
+    ('\u{16fe0}', '\u{16fe0}'), ('\u{17000}', '\u{187f7}'), ('\u{18800}', '\u{18aff}'), ('\u{18d00}', '\u{18d08}'),
+];
+
+pub const SC_TAI_VIET: &[(char, char)] = &[
+    ('\u{aa80}', '\u{aac2}'), ('\u{aadb}', '\u{aadf}'),
+];
+
+pub const SC_TELUGU: &[(char, char)] = &[
+    ('\u{c00}', '\u{c0c}'), ('\u{c0e}', '\u{c10}'), ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c39}'),
+    ('\u{c3c}', '\u{c44}'), ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4d}'), ('\u{c55}', '\u{c56}'),
+    ('\u{c58}', '\u{c5a}'), ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c63}'), ('\u{c66}', '\u{c6f}'),
+    ('\u{c77}', '\u{c7f}'),
+];
+
+pub const SC_TIFINAGH: &[(char, char)] = &[
+    ('\u{2d30}', '\u{2d67}'), ('\u{2d6f}', '\u{2d70}'), ('\u{2d7f}', '\u{2d7f}'),
+];
+
+pub const SC_TAGALOG: &[(char, char)] = &[
+    ('\u{1700}', '\u{1715}'), ('\u{171f}', '\u{171f}'),
+];
+
+pub const SC_THAANA: &[(char, char)] = &[
+    ('\u{780}', '\u{7b1}'),
+];
+
+pub const SC_THAI: &[(char, char)] = &[
+    ('\u{e01}', '\u{e3a}'), ('\u{e40}', '\u{e5b}'),
+];
+
+pub const SC_TIBETAN: &[(char, char)] = &[
+    ('\u{f00}', '\u{f47}'), ('\u{f49}', '\u{f6c}'), ('\u{f71}', '\u{f97}'), ('\u{f99}', '\u{fbc}'),
+    ('\u{fbe}', '\u{fcc}'), ('\u{fce}', '\u{fd4}'), ('\u{fd9}', '\u{fda}'),
+];
+
+pub const SC_TIRHUTA: &[(char, char)] = &[
+    ('\u{11480}', '\u{114c7}'), ('\u{114d0}', '\u{114d9}'),
+];
+
+pub const SC_TANGSA: &[(char, char)] = &[
+    ('\u{16a70}', '\u{16abe}'), ('\u{16ac0}', '\u{16ac9}'),
+];
+
+pub const SC_TOTO: &[(char, char)] = &[
+    ('\u{1e290}', '\u{1e2ae}'),
+];
+
+pub const SC_UGARITIC: &[(char, char)] = &[
+    ('\u{10380}', '\u{1039d}'), ('\u{1039f}', '\u{1039f}'),
+];
+
+pub const SC_VAI: &[(char, char)] = &[
+    ('\u{a500}', '\u{a62b}'),
+];
+
+pub const SC_VITHKUQI: &[(char, char)] = &[
+    ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'), ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'),
+];
+
+pub const SC_WARANG_CITI: &[(char, char)] = &[
+    ('\u{118a0}', '\u{118f2}'), ('\u{118ff}', '\u{118ff}'),
+];
+
+pub const SC_WANCHO: &[(char, char)] = &[
+    ('\u{1e2c0}', '\u{1e2f9}'), ('\u{1e2ff}', '\u{1e2ff}'),
+];
+
+pub const SC_OLD_PERSIAN: &[(char, char)] = &[
+    ('\u{103a0}', '\u{103c3}'), ('\u{103c8}', '\u{103d5}'),
+];
+
+pub const SC_CUNEIFORM: &[(char, char)] = &[
+    ('\u{12000}', '\u{12399}'), ('\u{12400}', '\u{1246e}'), ('\u{12470}', '\u{12474}'), ('\u{12480}', '\u{12543}'),
+];
+
+pub const SC_YEZIDI: &[(char, char)] = &[
+    ('\u{10e80}', '\u{10ea9}'), ('\u{10eab}', '\u{10ead}'), ('\u{10eb0}', '\u{10eb1}'),
+];
+
+pub const SC_YI: &[(char, char)] = &[
+    ('\u{a000}', '\u{a48c}'), ('\u{a490}', '\u{a4c6}'),
+];
+
+pub const SC_ZANABAZAR_SQUARE: &[(char, char)] = &[
+    ('\u{11a00}', '\u{11a47}'),
+];
+
+pub const SC_INHERITED: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'), ('\u{485}', '\u{486}'), ('\u{64b}', '\u{655}'), ('\u{670}', '\u{670}'),
+    ('\u{951}', '\u{954}'), ('\u{1ab0}', '\u{1ace}'), ('\u{1cd0}', '\u{1cd2}'), ('\u{1cd4}', '\u{1ce0}'),
+    ('\u{1ce2}', '\u{1ce8}'), ('\u{1ced}', '\u{1ced}'), ('\u{1cf4}', '\u{1cf4}'), ('\u{1cf8}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'), ('\u{200c}', '\u{200d}'), ('\u{20d0}', '\u{20f0}'), ('\u{302a}', '\u{302d}'),
+    ('\u{3099}', '\u{309a}'), ('\u{fe00}', '\u{fe0f}'), ('\u{fe20}', '\u{fe2d}'), ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'), ('\u{1133b}', '\u{1133b}'), ('\u{1cf00}', '\u{1cf2d}'), ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d167}', '\u{1d169}'), ('\u{1d17b}', '\u{1d182}'), ('\u{1d185}', '\u{1d18b}'), ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+pub const SC_COMMON: &[(char, char)] = &[
+    ('\u{0}', '\u{40}'), ('\u{5b}', '\u{60}'), ('\u{7b}', '\u{a9}'), ('\u{ab}', '\u{b9}'),
+    ('\u{bb}', '\u{bf}'), ('\u{d7}', '\u{d7}'), ('\u{f7}', '\u{f7}'), ('\u{2b9}', '\u{2df}'),
+    ('\u{2e5}', '\u{2e9}'), ('\u{2ec}', '\u{2ff}'), ('\u{374}', '\u{374}'), ('\u{37e}', '\u{37e}'),
+    ('\u{385}', '\u{385}'), ('\u{387}', '\u{387}'), ('\u{605}', '\u{605}'), ('\u{60c}', '\u{60c}'),
+    ('\u{61b}', '\u{61b}'), ('\u{61f}', '\u{61f}'), ('\u{640}', '\u{640}'), ('\u{6dd}', '\u{6dd}'),
+    ('\u{8e2}', '\u{8e2}'), ('\u{964}', '\u{965}'), ('\u{e3f}', '\u{e3f}'), ('\u{fd5}', '\u{fd8}'),
+    ('\u{10fb}', '\u{10fb}'), ('\u{16eb}', '\u{16ed}'), ('\u{1735}', '\u{1736}'), ('\u{1802}', '\u{1803}'),
+    ('\u{1805}', '\u{1805}'), ('\u{1cd3}', '\u{1cd3}'), ('\u{1ce1}', '\u{1ce1}'), ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'), ('\u{1cf5}', '\u{1cf7}'), ('\u{1cfa}', '\u{1cfa}'), ('\u{2000}', '\u{200b}'),
+    ('\u{200e}', '\u{2064}'), ('\u{2066}', '\u{2070}'), ('\u{2074}', '\u{207e}'), ('\u{2080}', '\u{208e}'),
+    ('\u{20a0}', '\u{20c0}'), ('\u{2100}', '\u{2125}'), ('\u{2127}', '\u{2129}'), ('\u{212c}', '\u{2131}'),
+    ('\u{2133}', '\u{214d}'), ('\u{214f}', '\u{215f}'), ('\u{2189}', '\u{218b}'), ('\u{2190}', '\u{2426}'),
+    ('\u{2440}', '\u{244a}'), ('\u{2460}', '\u{27ff}'), ('\u{2900}', '\u{2b73}'), ('\u{2b76}', '\u{2b95}'),
+    ('\u{2b97}', '\u{2bff}'), ('\u{2e00}', '\u{2e5d}'), ('\u{2ff0}', '\u{2ffb}'), ('\u{3000}', '\u{3004}'),
+    ('\u{3006}', '\u{3006}'), ('\u{3008}', '\u{3020}'), ('\u{3030}', '\u{3037}'), ('\u{303c}', '\u{303f}'),
+    ('\u{309b}', '\u{309c}'), ('\u{30a0}', '\u{30a0}'), ('\u{30fb}', '\u{30fc}'), ('\u{3190}', '\u{319f}'),
+    ('\u{31c0}', '\u{31e3}'), ('\u{3220}', '\u{325f}'), ('\u{327f}', '\u{32cf}'), ('\u{32ff}', '\u{32ff}'),
+    ('\u{3358}', '\u{33ff}'), ('\u{4dc0}', '\u{4dff}'), ('\u{a700}', '\u{a721}'), ('\u{a788}', '\u{a78a}'),
+    ('\u{a830}', '\u{a839}'), ('\u{a92e}', '\u{a92e}'), ('\u{a9cf}', '\u{a9cf}'), ('\u{ab5b}', '\u{ab5b}'),
+    ('\u{ab6a}', '\u{ab6b}'), ('\u{fd3e}', '\u{fd3f}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe66}'), ('\u{fe68}', '\u{fe6b}'), ('\u{feff}', '\u{feff}'), ('\u{ff01}', '\u{ff20}'),
+    ('\u{ff3b}', '\u{ff40}'), ('\u{ff5b}', '\u{ff65}'), ('\u{ff70}', '\u{ff70}'), ('\u{ff9e}', '\u{ff9f}'),
+    ('\u{ffe0}', '\u{ffe6}'), ('\u{ffe8}', '\u{ffee}'), ('\u{fff9}', '\u{fffd}'), ('\u{10100}', '\u{10102}'),
+    ('\u{10107}', '\u{10133}'), ('\u{10137}', '\u{1013f}'), ('\u{10190}', '\u{1019c}'), ('\u{101d0}', '\u{101fc}'),
+    ('\u{102e1}', '\u{102fb}'), ('\u{1bca0}', '\u{1bca3}'), ('\u{1cf50}', '\u{1cfc3}'), ('\u{1d000}', '\u{1d0f5}'),
+    ('\u{1d100}', '\u{1d126}'), ('\u{1d129}', '\u{1d166}'), ('\u{1d16a}', '\u{1d17a}'), ('\u{1d183}', '\u{1d184}'),
+    ('\u{1d18c}', '\u{1d1a9}'), ('\u{1d1ae}', '\u{1d1ea}'), ('\u{1d2e0}', '\u{1d2f3}'), ('\u{1d300}', '\u{1d356}'),
+    ('\u{1d360}', '\u{1d378}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'), ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d7cb}'), ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1ec71}', '\u{1ecb4}'), ('\u{1ed01}', '\u{1ed3d}'),
+    ('\u{1f000}', '\u{1f02b}'), ('\u{1f030}', '\u{1f093}'), ('\u{1f0a0}', '\u{1f0ae}'), ('\u{1f0b1}', '\u{1f0bf}'),
+    ('\u{1f0c1}', '\u{1f0cf}'), ('\u{1f0d1}', '\u{1f0f5}'), ('\u{1f100}', '\u{1f1ad}'), ('\u{1f1e6}', '\u{1f1ff}'),
+    ('\u{1f201}', '\u{1f202}'), ('\u{1f210}', '\u{1f23b}'), ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'),
+    ('\u{1f260}', '\u{1f265}'), ('\u{1f300}', '\u{1f6d7}'), ('\u{1f6dd}', '\u{1f6ec}'), ('\u{1f6f0}', '\u{1f6fc}'),
+    ('\u{1f700}', '\u{1f773}'), ('\u{1f780}', '\u{1f7d8}'), ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}', '\u{1f7f0}'),
+    ('\u{1f800}', '\u{1f80b}'), ('\u{1f810}', '\u{1f847}'), ('\u{1f850}', '\u{1f859}'), ('\u{1f860}', '\u{1f887}'),
+    ('\u{1f890}', '\u{1f8ad}'), ('\u{1f8b0}', '\u{1f8b1}'), ('\u{1f900}', '\u{1fa53}'), ('\u{1fa60}', '\u{1fa6d}'),
+    ('\u{1fa70}', '\u{1fa74}'), ('\u{1fa78}', '\u{1fa7c}'), ('\u{1fa80}', '\u{1fa86}'), ('\u{1fa90}', '\u{1faac}'),
+    ('\u{1fab0}', '\u{1faba}'), ('\u{1fac0}', '\u{1fac5}'), ('\u{1fad0}', '\u{1fad9}'), ('\u{1fae0}', '\u{1fae7}'),
+    ('\u{1faf0}', '\u{1faf6}'), ('\u{1fb00}', '\u{1fb92}'), ('\u{1fb94}', '\u{1fbca}'), ('\u{1fbf0}', '\u{1fbf9}'),
+    ('\u{e0001}', '\u{e0001}'), ('\u{e0020}', '\u{e007f}'),
+];
+
+pub const SC_UNKNOWN: &[(char, char)] = &[
+    ('\u{378}', '\u{379}'), ('\u{380}', '\u{383}'), ('\u{38b}', '\u{38b}'), ('\u{38d}', '\u{38d}'),
+    ('\u{3a2}', '\u{3a2}'), ('\u{530}', '\u{530}'), ('\u{557}', '\u{558}'), ('\u{58b}', '\u{58c}'),
+    ('\u{590}', '\u{590}'), ('\u{5c8}', '\u{5cf}'), ('\u{5eb}', '\u{5ee}'), ('\u{5f5}', '\u{5ff}'),
+    ('\u{70e}', '\u{70e}'), ('\u{74b}', '\u{74c}'), ('\u{7b2}', '\u{7bf}'), ('\u{7fb}', '\u{7fc}'),
+    ('\u{82e}', '\u{82f}'), ('\u{83f}', '\u{83f}'), ('\u{85c}', '\u{85d}'), ('\u{85f}', '\u{85f}'),
+    ('\u{86b}', '\u{86f}'), ('\u{88f}', '\u{88f}'), ('\u{892}', '\u{897}'), ('\u{984}', '\u{984}'),
+    ('\u{98d}', '\u{98e}'), ('\u{991}', '\u{992}'), ('\u{9a9}', '\u{9a9}'), ('\u{9b1}', '\u{9b1}'),
+    ('\u{9b3}', '\u{9b5}'), ('\u{9ba}', '\u{9bb}'), ('\u{9c5}', '\u{9c6}'), ('\u{9c9}', '\u{9ca}'),
+    ('\u{9cf}', '\u{9d6}'), ('\u{9d8}', '\u{9db}'), ('\u{9de}', '\u{9de}'), ('\u{9e4}', '\u{9e5}'),
+    ('\u{9ff}', '\u{a00}'), ('\u{a04}', '\u{a04}'), ('\u{a0b}', '\u{a0e}'), ('\u{a11}', '\u{a12}'),
+    ('\u{a29}', '\u{a29}'), ('\u{a31}', '\u{a31}'), ('\u{a34}', '\u{a34}'), ('\u{a37}', '\u{a37}'),
+    ('\u{a3a}', '\u{a3b}'), ('\u{a3d}', '\u{a3d}'), ('\u{a43}', '\u{a46}'), ('\u{a49}', '\u{a4a}'),
+    ('\u{a4e}', '\u{a50}'), ('\u{a52}', '\u{a58}'), ('\u{a5d}', '\u{a5d}'), ('\u{a5f}', '\u{a65}'),
+    ('\u{a77}', '\u{a80}'), ('\u{a84}', '\u{a84}'), ('\u{a8e}', '\u{a8e}'), ('\u{a92}', '\u{a92}'),
+    ('\u{aa9}', '\u{aa9}'), ('\u{ab1}', '\u{ab1}'), ('\u{ab4}', '\u{ab4}'), ('\u{aba}', '\u{abb}'),
+    ('\u{ac6}', '\u{ac6}'), ('\u{aca}', '\u{aca}'), ('\u{ace}', '\u{acf}'), ('\u{ad1}', '\u{adf}'),
+    ('\u{ae4}', '\u{ae5}'), ('\u{af2}', '\u{af8}'), ('\u{b00}', '\u{b00}'), ('\u{b04}', '\u{b04}'),
+    ('\u{b0d}', '\u{b0e}'), ('\u{b11}', '\u{b12}'), ('\u{b29}', '\u{b29}'), ('\u{b31}', '\u{b31}'),
+    ('\u{b34}', '\u{b34}'), ('\u{b3a}', '\u{b3b}'), ('\u{b45}', '\u{b46}'), ('\u{b49}', '\u{b4a}'),
+    ('\u{b4e}', '\u{b54}'), ('\u{b58}', '\u{b5b}'), ('\u{b5e}', '\u{b5e}'), ('\u{b64}', '\u{b65}'),
+    ('\u{b78}', '\u{b81}'), ('\u{b84}', '\u{b84}'), ('\u{b8b}', '\u{b8d}'), ('\u{b91}', '\u{b91}'),
+    ('\u{b96}', '\u{b98}'), ('\u{b9b}', '\u{b9b}'), ('\u{b9d}', '\u{b9d}'), ('\u{ba0}', '\u{ba2}'),
+    ('\u{ba5}', '\u{ba7}'), ('\u{bab}', '\u{bad}'), ('\u{bba}', '\u{bbd}'), ('\u{bc3}', '\u{bc5}'),
+    ('\u{bc9}', '\u{bc9}'), ('\u{bce}', '\u{bcf}'), ('\u{bd1}', '\u{bd6}'), ('\u{bd8}', '\u{be5}'),
+    ('\u{bfb}', '\u{bff}'), ('\u{c0d}', '\u{c0d}'), ('\u{c11}', '\u{c11}'), ('\u{c29}', '\u{c29}'),
+    ('\u{c3a}', '\u{c3b}'), ('\u{c45}', '\u{c45}'), ('\u{c49}', '\u{c49}'), ('\u{c4e}', '\u{c54}'),
+    ('\u{c57}', '\u{c57}'), ('\u{c5b}', '\u{c5c}'), ('\u{c5e}', '\u{c5f}'), ('\u{c64}', '\u{c65}'),
+    ('\u{c70}', '\u{c76}'), ('\u{c8d}', '\u{c8d}'), ('\u{c91}', '\u{c91}'), ('\u{ca9}', '\u{ca9}'),
+    ('\u{cb4}', '\u{cb4}'), ('\u{cba}', '\u{cbb}'), ('\u{cc5}', '\u{cc5}'), ('\u{cc9}', '\u{cc9}'),
+    ('\u{cce}', '\u{cd4}'), ('\u{cd7}', '\u{cdc}'), ('\u{cdf}', '\u{cdf}'), ('\u{ce4}', '\u{ce5}'),
+    ('\u{cf0}', '\u{cf0}'), ('\u{cf3}', '\u{cff}'), ('\u{d0d}', '\u{d0d}'), ('\u{d11}', '\u{d11}'),
+    ('\u{d45}', '\u{d45}'), ('\u{d49}', '\u{d49}'), ('\u{d50}', '\u{d53}'), ('\u{d64}', '\u{d65}'),
+    ('\u{d80}', '\u{d80}'), ('\u{d84}', '\u{d84}'), ('\u{d97}', '\u{d99}'), ('\u{db2}', '\u{db2}'),
+    ('\u{dbc}', '\u{dbc}'), ('\u{dbe}', '\u{dbf}'), ('\u{dc7}', '\u{dc9}'), ('\u{dcb}', '\u{dce}'),
+    ('\u{dd5}', '\u{dd5}'), ('\u{dd7}', '\u{dd7}'), ('\u{de0}', '\u{de5}'), ('\u{df0}', '\u{df1}'),
+    ('\u{df5}', '\u{e00}'), ('\u{e3b}', '\u{e3e}'), ('\u{e5c}', '\u{e80}'), ('\u{e83}', '\u{e83}'),
+    ('\u{e85}', '\u{e85}'), ('\u{e8b}', '\u{e8b}'), ('\u{ea4}', '\u{ea4}'), ('\u{ea6}', '\u{ea6}'),
+    ('\u{ebe}', '\u{ebf}'), ('\u{ec5}', '\u{ec5}'), ('\u{ec7}', '\u{ec7}'), ('\u{ece}', '\u{ecf}'),
+    ('\u{eda}', '\u{edb}'), ('\u{ee0}', '\u{eff}'), ('\u{f48}', '\u{f48}'), ('\u{f6d}', '\u{f70}'),
+    ('\u{f98}', '\u{f98}'), ('\u{fbd}', '\u{fbd}'), ('\u{fcd}', '\u{fcd}'), ('\u{fdb}', '\u{fff}'),
+    ('\u{10c6}', '\u{10c6}'), ('\u{10c8}', '\u{10cc}'), ('\u{10ce}', '\u{10cf}'), ('\u{1249}', '\u{1249}'),
+    ('\u{124e}', '\u{124f}'), ('\u{1257}', '\u{1257}'), ('\u{1259}', '\u{1259}'), ('\u{125e}', '\u{125f}'),
+    ('\u{1289}', '\u{1289}'), ('\u{128e}', '\u{128f}'), ('\u{12b1}', '\u{12b1}'), ('\u{12b6}', '\u{12b7}'),
+    ('\u{12bf}', '\u{12bf}'), ('\u{12c1}', '\u{12c1}'), ('\u{12c6}', '\u{12c7}'), ('\u{12d7}', '\u{12d7}'),
+    ('\u{1311}', '\u{1311}'), ('\u{1316}', '\u{1317}'), ('\u{135b}', '\u{135c}'), ('\u{137d}', '\u{137f}'),
+    ('\u{139a}', '\u{139f}'), ('\u{13f6}', '\u{13f7}'), ('\u{13fe}', '\u{13ff}'), ('\u{169d}', '\u{169f}'),
+    ('\u{16f9}', '\u{16ff}'), ('\u{1716}', '\u{171e}'), ('\u{1737}', '\u{173f}'), ('\u{1754}', '\u{175f}'),
+    ('\u{176d}', '\u{176d}'), ('\u{1771}', '\u{1771}'), ('\u{1774}', '\u{177f}'), ('\u{17de}', '\u{17df}'),
+    ('\u{17ea}', '\u{17ef}'), ('\u{17fa}', '\u{17ff}'), ('\u{181a}', '\u{181f}'), ('\u{1879}', '\u{187f}'),
+    ('\u{18ab}', '\u{18af}'), ('\u{18f6}', '\u{18ff}'), ('\u{191f}', '\u{191f}'), ('\u{192c}', '\u{192f}'),
+    ('\u{193c}', '\u{193f}'), ('\u{1941}', '\u{1943}'), ('\u{196e}', '\u{196f}'), ('\u{1975}', '\u{197f}'),
+    ('\u{19ac}', '\u{19af}'), ('\u{19ca}', '\u{19cf}'), ('\u{19db}', '\u{19dd}'), ('\u{1a1c}', '\u{1a1d}'),
+    ('\u{1a5f}', '\u{1a5f}'), ('\u{1a7d}', '\u{1a7e}'), ('\u{1a8a}', '\u{1a8f}'), ('\u{1a9a}', '\u{1a9f}'),
+    ('\u{1aae}', '\u{1aaf}'), ('\u{1acf}', '\u{1aff}'), ('\u{1b4d}', '\u{1b4f}'), ('\u{1b7f}', '\u{1b7f}'),
+    ('\u{1bf4}', '\u{1bfb}'), ('\u{1c38}', '\u{1c3a}'), ('\u{1c4a}', '\u{1c4c}'), ('\u{1c89}', '\u{1c8f}'),
+    ('\u{1cbb}', '\u{1cbc}'), ('\u{1cc8}', '\u{1ccf}'), ('\u{1cfb}', '\u{1cff}'), ('\u{1f16}', '\u{1f17}'),
+    ('\u{1f1e}', '\u{1f1f}'), ('\u{1f46}', '\u{1f47}'), ('\u{1f4e}', '\u{1f4f}'), ('\u{1f58}', '\u{1f58}'),
+    ('\u{1f5a}', '\u{1f5a}'), ('\u{1f5c}', '\u{1f5c}'), ('\u{1f5e}', '\u{1f5e}'), ('\u{1f7e}', '\u{1f7f}'),
+    ('\u{1fb5}', '\u{1fb5}'), ('\u{1fc5}', '\u{1fc5}'), ('\u{1fd4}', '\u{1fd5}'), ('\u{1fdc}', '\u{1fdc}'),
+    ('\u{1ff0}', '\u{1ff1}'), ('\u{1ff5}', '\u{1ff5}'), ('\u{1fff}', '\u{1fff}'), ('\u{2065}', '\u{2065}'),
+    ('\u{2072}', '\u{2073}'), ('\u{208f}', '\u{208f}'), ('\u{209d}', '\u{209f}'), ('\u{20c1}', '\u{20cf}'),
+    ('\u{20f1}', '\u{20ff}'), ('\u{218c}', '\u{218f}'), ('\u{2427}', '\u{243f}'), ('\u{244b}', '\u{245f}'),
+    ('\u{2b74}', '\u{2b75}'), ('\u{2b96}', '\u{2b96}'), ('\u{2cf4}', '\u{2cf8}'), ('\u{2d26}', '\u{2d26}'),
+    ('\u{2d28}', '\u{2d2c}'), ('\u{2d2e}', '\u{2d2f}'), ('\u{2d68}', '\u{2d6e}'), ('\u{2d71}', '\u{2d7e}'),
+    ('\u{2d97}', '\u{2d9f}'), ('\u{2da7}', '\u{2da7}'), ('\u{2daf}', '\u{2daf}'), ('\u{2db7}', '\u{2db7}'),
+    ('\u{2dbf}', '\u{2dbf}'), ('\u{2dc7}', '\u{2dc7}'), ('\u{2dcf}', '\u{2dcf}'), ('\u{2dd7}', '\u{2dd7}'),
+    ('\u{2ddf}', '\u{2ddf}'), ('\u{2e5e}', '\u{2e7f}'), ('\u{2e9a}', '\u{2e9a}'), ('\u{2ef4}', '\u{2eff}'),
+    ('\u{2fd6}', '\u{2fef}'), ('\u{2ffc}', '\u{2fff}'), ('\u{3040}', '\u{3040}'), ('\u{3097}', '\u{3098}'),
+    ('\u{3100}', '\u{3104}'), ('\u{3130}', '\u{3130}'), ('\u{318f}', '\u{318f}'), ('\u{31e4}', '\u{31ef}'),
+    ('\u{321f}', '\u{321f}'), ('\u{a48d}', '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}', '\u{a63f}'),
+    ('\u{a6f8}', '\u{a6ff}'), ('\u{a7cb}', '\u{a7cf}'), ('\u{a7d2}', '\u{a7d2}'), ('\u{a7d4}', '\u{a7d4}'),
+    ('\u{a7da}', '\u{a7f1}'), ('\u{a82d}', '\u{a82f}'), ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'),
+    ('\u{a8c6}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'), ('\u{a954}', '\u{a95e}'), ('\u{a97d}', '\u{a97f}'),
+    ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'), ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'),
+    ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}', '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}', '\u{ab00}'),
+    ('\u{ab07}', '\u{ab08}'), ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'),
+    ('\u{ab2f}', '\u{ab2f}'), ('\u{ab6c}', '\u{ab6f}'), ('\u{abee}', '\u{abef}'), ('\u{abfa}', '\u{abff}'),
+    ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'), ('\u{e000}', '\u{f8ff}'),
+    ('\u{fa6e}', '\u{fa6f}'), ('\u{fada}', '\u{faff}'), ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'),
+    ('\u{fb37}', '\u{fb37}'), ('\u{fb3d}', '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'),
+    ('\u{fb45}', '\u{fb45}'), ('\u{fbc3}', '\u{fbd2}'), ('\u{fd90}', '\u{fd91}'), ('\u{fdc8}', '\u{fdce}'),
+    ('\u{fdd0}', '\u{fdef}'), ('\u{fe1a}', '\u{fe1f}'), ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'),
+    ('\u{fe6c}', '\u{fe6f}'), ('\u{fe75}', '\u{fe75}'), ('\u{fefd}', '\u{fefe}'), ('\u{ff00}', '\u{ff00}'),
+    ('\u{ffbf}', '\u{ffc1}'), ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'),
+    ('\u{ffdd}', '\u{ffdf}'), ('\u{ffe7}', '\u{ffe7}'), ('\u{ffef}', '\u{fff8}'), ('\u{fffe}', '\u{ffff}'),
+    ('\u{1000c}', '\u{1000c}'), ('\u{10027}', '\u{10027}'), ('\u{1003b}', '\u{1003b}'), ('\u{1003e}', '\u{1003e}'),
+    ('\u{1004e}', '\u{1004f}'), ('\u{1005e}', '\u{1007f}'), ('\u{100fb}', '\u{100ff}'), ('\u{10103}', '\u{10106}'),
+    ('\u{10134}', '\u{10136}'), ('\u{1018f}', '\u{1018f}'), ('\u{1019d}', '\u{1019f}'), ('\u{101a1}', '\u{101cf}'),
+    ('\u{101fe}', '\u{1027f}'), ('\u{1029d}', '\u{1029f}'), ('\u{102d1}', '\u{102df}'), ('\u{102fc}', '\u{102ff}'),
+    ('\u{10324}', '\u{1032c}'), ('\u{1034b}', '\u{1034f}'), ('\u{1037b}', '\u{1037f}'), ('\u{1039e}', '\u{1039e}'),
+    ('\u{103c4}', '\u{103c7}'), ('\u{103d6}', '\u{103ff}'), ('\u{1049e}', '\u{1049f}'), ('\u{104aa}', '\u{104af}'),
+    ('\u{104d4}', '\u{104d7}'), ('\u{104fc}', '\u{104ff}'), ('\u{10528}', '\u{1052f}'), ('\u{10564}', '\u{1056e}'),
+    ('\u{1057b}', '\u{1057b}'), ('\u{1058b}', '\u{1058b}'), ('\u{10593}', '\u{10593}'), ('\u{10596}', '\u{10596}'),
+    ('\u{105a2}', '\u{105a2}'), ('\u{105b2}', '\u{105b2}'), ('\u{105ba}', '\u{105ba}'), ('\u{105bd}', '\u{105ff}'),
+    ('\u{10737}', '\u{1073f}'), ('\u{10756}', '\u{1075f}'), ('\u{10768}', '\u{1077f}'), ('\u{10786}', '\u{10786}'),
+    ('\u{107b1}', '\u{107b1}'), ('\u{107bb}', '\u{107ff}'), ('\u{10806}', '\u{10807}'), ('\u{10809}', '\u{10809}'),
+    ('\u{10836}', '\u{10836}'), ('\u{10839}', '\u{1083b}'), ('\u{1083d}', '\u{1083e}'), ('\u{10856}', '\u{10856}'),
+    ('\u{1089f}', '\u{108a6}'), ('\u{108b0}', '\u{108df}'), ('\u{108f3}', '\u{108f3}'), ('\u{108f6}', '\u{108fa}'),
+    ('\u{1091c}', '\u{1091e}'), ('\u{1093a}', '\u{1093e}'), ('\u{10940}', '\u{1097f}'), ('\u{109b8}', '\u{109bb}'),
+    ('\u{109d0}', '\u{109d1}'), ('\u{10a04}', '\u{10a04}'), ('\u{10a07}', '\u{10a0b}'), ('\u{10a14}', '\u{10a14}'),
+    ('\u{10a18}', '\u{10a18}'), ('\u{10a36}', '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'), ('\u{10a49}', '\u{10a4f}'),
+    ('\u{10a59}', '\u{10a5f}'), ('\u{10aa0}', '\u{10abf}'), ('\u{10ae7}', '\u{10aea}'), ('\u{10af7}', '\u{10aff}'),
+    ('\u{10b36}', '\u{10b38}'), ('\u{10b56}', '\u{10b57}'), ('\u{10b73}', '\u{10b77}'), ('\u{10b92}', '\u{10b98}'),
+    ('\u{10b9d}', '\u{10ba8}'), ('\u{10bb0}', '\u{10bff}'), ('\u{10c49}', '\u{10c7f}'), ('\u{10cb3}', '\u{10cbf}'),
+    ('\u{10cf3}', '\u{10cf9}'), ('\u{10d28}', '\u{10d2f}'), ('\u{10d3a}', '\u{10e5f}'), ('\u{10e7f}', '\u{10e7f}'),
+    ('\u{10eaa}', '\u{10eaa}'), ('\u{10eae}', '\u{10eaf}'), ('\u{10eb2}', '\u{10eff}'), ('\u{10f28}', '\u{10f2f}'),
+    ('\u{10f5a}', '\u{10f6f}'), ('\u{10f8a}', '\u{10faf}'), ('\u{10fcc}', '\u{10fdf}'), ('\u{10ff7}', '\u{10fff}'),
+    ('\u{1104e}', '\u{11051}'), ('\u{11076}', '\u{1107e}'), ('\u{110c3}', '\u{110cc}'), ('\u{110ce}', '\u{110cf}'),
+    ('\u{110e9}', '\u{110ef}'), ('\u{110fa}', '\u{110ff}'), ('\u{11135}', '\u{11135}'), ('\u{11148}', '\u{1114f}'),
+    ('\u{11177}', '\u{1117f}'), ('\u{111e0}', '\u{111e0}'), ('\u{111f5}', '\u{111ff}'), ('\u{11212}', '\u{11212}'),
+    ('\u{1123f}', '\u{1127f}'), ('\u{11287}', '\u{11287}'), ('\u{11289}', '\u{11289}'), ('\u{1128e}', '\u{1128e}'),
+    ('\u{1129e}', '\u{1129e}'), ('\u{112aa}', '\u{112af}'), ('\u{112eb}', '\u{112ef}'), ('\u{112fa}', '\u{112ff}'),
+    ('\u{11304}', '\u{11304}'), ('\u{1130d}', '\u{1130e}'), ('\u{11311}', '\u{11312}'), ('\u{11329}', '\u{11329}'),
+    ('\u{11331}', '\u{11331}'), ('\u{11334}', '\u{11334}'), ('\u{1133a}', '\u{1133a}'), ('\u{11345}', '\u{11346}'),
+    ('\u{11349}', '\u{1134a}'), ('\u{1134e}', '\u{1134f}'), ('\u{11351}', '\u{11356}'), ('\u{11358}', '\u{1135c}'),
+    ('\u{11364}', '\u{11365}'), ('\u{1136d}', '\u{1136f}'), ('\u{11375}', '\u{113ff}'), ('\u{1145c}', '\u{1145c}'),
+    ('\u{11462}', '\u{1147f}'), ('\u{114c8}', '\u{114cf}'), ('\u{114da}', '\u{1157f}'), ('\u{115b6}', '\u{115b7}'),
+    ('\u{115de}', '\u{115ff}'), ('\u{11645}', '\u{1164f}'), ('\u{1165a}', '\u{1165f}'), ('\u{1166d}', '\u{1167f}'),
+    ('\u{116ba}', '\u{116bf}'), ('\u{116ca}', '\u{116ff}'), ('\u{1171b}', '\u{1171c}'), ('\u{1172c}', '\u{1172f}'),
+    ('\u{11747}', '\u{117ff}'), ('\u{1183c}', '\u{1189f}'), ('\u{118f3}', '\u{118fe}'), ('\u{11907}', '\u{11908}'),
+    ('\u{1190a}', '\u{1190b}'), ('\u{11914}', '\u{11914}'), ('\u{11917}', '\u{11917}'), ('\u{11936}', '\u{11936}'),
+    ('\u{11939}', '\u{1193a}'), ('\u{11947}', '\u{1194f}'), ('\u{1195a}', '\u{1199f}'), ('\u{119a8}', '\u{119a9}'),
+    ('\u{119d8}', '\u{119d9}'), ('\u{119e5}', '\u{119ff}'), ('\u{11a48}', '\u{11a4f}'), ('\u{11aa3}', '\u{11aaf}'),
+    ('\u{11af9}', '\u{11bff}'), ('\u{11c09}', '\u{11c09}'), ('\u{11c37}', '\u{11c37}'), ('\u{11c46}', '\u{11c4f}'),
+    ('\u{11c6d}', '\u{11c6f}'), ('\u{11c90}', '\u{11c91}'), ('\u{11ca8}', '\u{11ca8}'), ('\u{11cb7}', '\u{11cff}'),
+    ('\u{11d07}', '\u{11d07}'), ('\u{11d0a}', '\u{11d0a}'), ('\u{11d37}', '\u{11d39}'), ('\u{11d3b}', '\u{11d3b}'),
+    ('\u{11d3e}', '\u{11d3e}'), ('\u{11d48}', '\u{11d4f}'), ('\u{11d5a}', '\u{11d5f}'), ('\u{11d66}', '\u{11d66}'),
+    ('\u{11d69}', '\u{11d69}'), ('\u{11d8f}', '\u{11d8f}'), ('\u{11d92}', '\u{11d92}'), ('\u{11d99}', '\u{11d9f}'),
+    ('\u{11daa}', '\u{11edf}'), ('\u{11ef9}', '\u{11faf}'), ('\u{11fb1}', '\u{11fbf}'), ('\u{11ff2}', '\u{11ffe}'),
+    ('\u{1239a}', '\u{123ff}'), ('\u{1246f}', '\u{1246f}'), ('\u{12475}', '\u{1247f}'), ('\u{12544}', '\u{12f8f}'),
+    ('\u{12ff3}', '\u{12fff}'), ('\u{1342f}', '\u{1342f}'), ('\u{13439}', '\u{143ff}'), ('\u{14647}', '\u{167ff}'),
+    ('\u{16a39}', '\u{16a3f}'), ('\u{16a5f}', '\u{16a5f}'), ('\u{16a6a}', '\u{16a6d}'), ('\u{16abf}', '\u{16abf}'),
+    ('\u{16aca}', '\u{16acf}'), ('\u{16aee}', '\u{16aef}'), ('\u{16af6}', '\u{16aff}'), ('\u{16b46}', '\u{16b4f}'),
+    ('\u{16b5a}', '\u{16b5a}'), ('\u{16b62}', '\u{16b62}'), ('\u{16b78}', '\u{16b7c}'), ('\u{16b90}', '\u{16e3f}'),
+    ('\u{16e9b}', '\u{16eff}'), ('\u{16f4b}', '\u{16f4e}'), ('\u{16f88}', '\u{16f8e}'), ('\u{16fa0}', '\u{16fdf}'),
+    ('\u{16fe5}', '\u{16fef}'), ('\u{16ff2}', '\u{16fff}'), ('\u{187f8}', '\u{187ff}'), ('\u{18cd6}', '\u{18cff}'),
+    ('\u{18d09}', '\u{1afef}'), ('\u{1aff4}', '\u{1aff4}'), ('\u{1affc}', '\u{1affc}'), ('\u{1afff}', '\u{1afff}'),
+    ('\u{1b123}', '\u{1b14f}'), ('\u{1b153}', '\u{1b163}'), ('\u{1b168}', '\u{1b16f}'), ('\u{1b2fc}', '\u{1bbff}'),
+    ('\u{1bc6b}', '\u{1bc6f}'), ('\u{1bc7d}', '\u{1bc7f}'), ('\u{1bc89}', '\u{1bc8f}'), ('\u{1bc9a}', '\u{1bc9b}'),
+    ('\u{1bca4}', '\u{1ceff}'), ('\u{1cf2e}', '\u{1cf2f}'), ('\u{1cf47}', '\u{1cf4f}'), ('\u{1cfc4}', '\u{1cfff}'),
+    ('\u{1d0f6}', '\u{1d0ff}'), ('\u{1d127}', '\u{1d128}'), ('\u{1d1eb}', '\u{1d1ff}'), ('\u{1d246}', '\u{1d2df}'),
+    ('\u{1d2f4}', '\u{1d2ff}'), ('\u{1d357}', '\u{1d35f}'), ('\u{1d379}', '\u{1d3ff}'), ('\u{1d455}', '\u{1d455}'),
+    ('\u{1d49d}', '\u{1d49d}'), ('\u{1d4a0}', '\u{1d4a1}'), ('\u{1d4a3}', '\u{1d4a4}'), ('\u{1d4a7}', '\u{1d4a8}'),
+    ('\u{1d4ad}', '\u{1d4ad}'), ('\u{1d4ba}', '\u{1d4ba}'), ('\u{1d4bc}', '\u{1d4bc}'), ('\u{1d4c4}', '\u{1d4c4}'),
+    ('\u{1d506}', '\u{1d506}'), ('\u{1d50b}', '\u{1d50c}'), ('\u{1d515}', '\u{1d515}'), ('\u{1d51d}', '\u{1d51d}'),
+    ('\u{1d53a}', '\u{1d53a}'), ('\u{1d53f}', '\u{1d53f}'), ('\u{1d545}', '\u{1d545}'), ('\u{1d547}', '\u{1d549}'),
+    ('\u{1d551}', '\u{1d551}'), ('\u{1d6a6}', '\u{1d6a7}'), ('\u{1d7cc}', '\u{1d7cd}'), ('\u{1da8c}', '\u{1da9a}'),
+    ('\u{1daa0}', '\u{1daa0}'), ('\u{1dab0}', '\u{1deff}'), ('\u{1df1f}', '\u{1dfff}'), ('\u{1e007}', '\u{1e007}'),
+    ('\u{1e019}', '\u{1e01a}'), ('\u{1e022}', '\u{1e022}'), ('\u{1e025}', '\u{1e025}'), ('\u{1e02b}', '\u{1e0ff}'),
+    ('\u{1e12d}', '\u{1e12f}'), ('\u{1e13e}', '\u{1e13f}'), ('\u{1e14a}', '\u{1e14d}'), ('\u{1e150}', '\u{1e28f}'),
+    ('\u{1e2af}', '\u{1e2bf}'), ('\u{1e2fa}', '\u{1e2fe}'), ('\u{1e300}', '\u{1e7df}'), ('\u{1e7e7}', '\u{1e7e7}'),
+    ('\u{1e7ec}', '\u{1e7ec}'), ('\u{1e7ef}', '\u{1e7ef}'), ('\u{1e7ff}', '\u{1e7ff}'), ('\u{1e8c5}', '\u{1e8c6}'),
+    ('\u{1e8d7}', '\u{1e8ff}'), ('\u{1e94c}', '\u{1e94f}'), ('\u{1e95a}', '\u{1e95d}'), ('\u{1e960}', '\u{1ec70}'),
+    ('\u{1ecb5}', '\u{1ed00}'), ('\u{1ed3e}', '\u{1edff}'), ('\u{1ee04}', '\u{1ee04}'), ('\u{1ee20}', '\u{1ee20}'),
+    ('\u{1ee23}', '\u{1ee23}'), ('\u{1ee25}', '\u{1ee26}'), ('\u{1ee28}', '\u{1ee28}'), ('\u{1ee33}', '\u{1ee33}'),
+    ('\u{1ee38}', '\u{1ee38}'), ('\u{1ee3a}', '\u{1ee3a}'), ('\u{1ee3c}', '\u{1ee41}'), ('\u{1ee43}', '\u{1ee46}'),
+    ('\u{1ee48}', '\u{1ee48}'), ('\u{1ee4a}', '\u{1ee4a}'), ('\u{1ee4c}', '\u{1ee4c}'), ('\u{1ee50}', '\u{1ee50}'),
+    ('\u{1ee53}', '\u{1ee53}'), ('\u{1ee55}', '\u{1ee56}'), ('\u{1ee58}', '\u{1ee58}'), ('\u{1ee5a}', '\u{1ee5a}'),
+    ('\u{1ee5c}', '\u{1ee5c}'), ('\u{1ee5e}', '\u{1ee5e}'), ('\u{1ee60}', '\u{1ee60}'), ('\u{1ee63}', '\u{1ee63}'),
+    ('\u{1ee65}', '\u{1ee66}'), ('\u{1ee6b}', '\u{1ee6b}'), ('\u{1ee73}', '\u{1ee73}'), ('\u{1ee78}', '\u{1ee78}'),
+    ('\u{1ee7d}', '\u{1ee7d}'), ('\u{1ee7f}', '\u{1ee7f}'), ('\u{1ee8a}', '\u{1ee8a}'), ('\u{1ee9c}', '\u{1eea0}'),
+    ('\u{1eea4}', '\u{1eea4}'), ('\u{1eeaa}', '\u{1eeaa}'), ('\u{1eebc}', '\u{1eeef}'), ('\u{1eef2}', '\u{1efff}'),
+    ('\u{1f02c}', '\u{1f02f}'), ('\u{1f094}', '\u{1f09f}'), ('\u{1f0af}', '\u{1f0b0}'), ('\u{1f0c0}', '\u{1f0c0}'),
+    ('\u{1f0d0}', '\u{1f0d0}'), ('\u{1f0f6}', '\u{1f0ff}'), ('\u{1f1ae}', '\u{1f1e5}'), ('\u{1f203}', '\u{1f20f}'),
+    ('\u{1f23c}', '\u{1f23f}'), ('\u{1f249}', '\u{1f24f}'), ('\u{1f252}', '\u{1f25f}'), ('\u{1f266}', '\u{1f2ff}'),
+    ('\u{1f6d8}', '\u{1f6dc}'), ('\u{1f6ed}', '\u{1f6ef}'), ('\u{1f6fd}', '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'),
+    ('\u{1f7d9}', '\u{1f7df}'), ('\u{1f7ec}', '\u{1f7ef}'), ('\u{1f7f1}', '\u{1f7ff}'), ('\u{1f80c}', '\u{1f80f}'),
+    ('\u{1f848}', '\u{1f84f}'), ('\u{1f85a}', '\u{1f85f}'), ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1f8af}'),
+    ('\u{1f8b2}', '\u{1f8ff}'), ('\u{1fa54}', '\u{1fa5f}'), ('\u{1fa6e}', '\u{1fa6f}'), ('\u{1fa75}', '\u{1fa77}'),
+    ('\u{1fa7d}', '\u{1fa7f}'), ('\u{1fa87}', '\u{1fa8f}'), ('\u{1faad}', '\u{1faaf}'), ('\u{1fabb}', '\u{1fabf}'),
+    ('\u{1fac6}', '\u{1facf}'), ('\u{1fada}', '\u{1fadf}'), ('\u{1fae8}', '\u{1faef}'), ('\u{1faf7}', '\u{1faff}'),
+    ('\u{1fb93}', '\u{1fb93}'), ('\u{1fbcb}', '\u{1fbef}'), ('\u{1fbfa}', '\u{1ffff}'), ('\u{2a6e0}', '\u{2a6ff}'),
+    ('\u{2b739}', '\u{2b73f}'), ('\u{2b81e}', '\u{2b81f}'), ('\u{2cea2}', '\u{2ceaf}'), ('\u{2ebe1}', '\u{2f7ff}'),
+    ('\u{2fa1e}', '\u{2ffff}'), ('\u{3134b}', '\u{e0000}'), ('\u{e0002}', '\u{e001f}'), ('\u{e0080}', '\u{e00ff}'),
+    ('\u{e01f0}', '\u{10ffff}'),
+];
+
+pub const SCRIPTS: &[PropertyEntry] = &[
+    ("Adlam", &["Adlm", "Adlam"], SC_ADLAM),
+    ("Caucasian_Albanian", &["Aghb", "Caucasian_Albanian"], SC_CAUCASIAN_ALBANIAN),
+    ("Ahom", &["Ahom"], SC_AHOM),
+    ("Arabic", &["Arab", "Arabic"], SC_ARABIC),
+    ("Imperial_Aramaic", &["Armi", "Imperial_Aramaic"], SC_IMPERIAL_ARAMAIC),
+    ("Armenian", &["Armn", "Armenian"], SC_ARMENIAN),
+    ("Avestan", &["Avst", "Avestan"], SC_AVESTAN),
+    ("Balinese", &["Bali", "Balinese"], SC_BALINESE),
+    ("Bamum", &["Bamu", "Bamum"], SC_BAMUM),
+    ("Bassa_Vah", &["Bass", "Bassa_Vah"], SC_BASSA_VAH),
+    ("Batak", &["Batk", "Batak"], SC_BATAK),
+    ("Bengali", &["Beng", "Bengali"], SC_BENGALI),
+    ("Bhaiksuki", &["Bhks", "Bhaiksuki"], SC_BHAIKSUKI),
+    ("Bopomofo", &["Bopo", "Bopomofo"], SC_BOPOMOFO),
+    ("Brahmi", &["Brah", "Brahmi"], SC_BRAHMI),
+    ("Braille", &["Brai", "Braille"], SC_BRAILLE),
+    ("Buginese", &["Bugi", "Buginese"], SC_BUGINESE),
+    ("Buhid", &["Buhd", "Buhid"], SC_BUHID),
+    ("Chakma", &["Cakm", "Chakma"], SC_CHAKMA),
+    ("Canadian_Aboriginal", &["Cans", "Canadian_Aboriginal"], SC_CANADIAN_ABORIGINAL),
+    ("Carian", &["Cari", "Carian"], SC_CARIAN),
+    ("Cham", &["Cham"], SC_CHAM),
+    ("Cherokee", &["Cher", "Cherokee"], SC_CHEROKEE),
+    ("Chorasmian", &["Chrs", "Chorasmian"], SC_CHORASMIAN),
+    ("Coptic", &["Copt", "Coptic", "Qaac"], SC_COPTIC),
+    ("Cypro_Minoan", &["Cpmn", "Cypro_Minoan"], SC_CYPRO_MINOAN),
+    ("Cypriot", &["Cprt", "Cypriot"], SC_CYPRIOT),
+    ("Cyrillic", &["Cyrl", "Cyrillic"], SC_CYRILLIC),
+    ("Devanagari", &["Deva", "Devanagari"], SC_DEVANAGARI),
+    ("Dives_Akuru", &["Diak", "Dives_Akuru"], SC_DIVES_AKURU),
+    ("Dogra", &["Dogr", "Dogra"], SC_DOGRA),
+    ("Deseret", &["Dsrt", "Deseret"], SC_DESERET),
+    ("Duployan", &["Dupl", "Duployan"], SC_DUPLOYAN),
+    ("Egyptian_Hieroglyphs", &["Egyp", "Egyptian_Hieroglyphs"], SC_EGYPTIAN_HIEROGLYPHS),
+    ("Elbasan", &["Elba", "Elbasan"], SC_ELBASAN),
+    ("Elymaic", &["Elym", "Elymaic"], SC_ELYMAIC),
+    ("Ethiopic", &["Ethi", "Ethiopic"], SC_ETHIOPIC),
+    ("Georgian", &["Geor", "Georgian"], SC_GEORGIAN),
+    ("Glagolitic", &["Glag", "Glagolitic"], SC_GLAGOLITIC),
+    ("Gunjala_Gondi", &["Gong", "Gunjala_Gondi"], SC_GUNJALA_GONDI),
+    ("Masaram_Gondi", &["Gonm", "Masaram_Gondi"], SC_MASARAM_GONDI),
+    ("Gothic", &["Goth", "Gothic"], SC_GOTHIC),
+    ("Grantha", &["Gran", "Grantha"], SC_GRANTHA),
+    ("Greek", &["Grek", "Greek"], SC_GREEK),
+    ("Gujarati", &["Gujr", "Gujarati"], SC_GUJARATI),
+    ("Gurmukhi", &["Guru", "Gurmukhi"], SC_GURMUKHI),
+    ("Hangul", &["Hang", "Hangul"], SC_HANGUL),
+    ("Han", &["Hani", "Han"], SC_HAN),
+    ("Hanunoo", &["Hano", "Hanunoo"], SC_HANUNOO),
+    ("Hatran", &["Hatr", "Hatran"], SC_HATRAN),
+    ("Hebrew", &["Hebr", "Hebrew"], SC_HEBREW),
+    ("Hiragana", &["Hira", "Hiragana"], SC_HIRAGANA),
+    ("Anatolian_Hieroglyphs", &["Hluw", "Anatolian_Hieroglyphs"], SC_ANATOLIAN_HIEROGLYPHS),
+    ("Pahawh_Hmong", &["Hmng", "Pahawh_Hmong"], SC_PAHAWH_HMONG),
+    ("Nyiakeng_Puachue_Hmong", &["Hmnp", "Nyiakeng_Puachue_Hmong"], SC_NYIAKENG_PUACHUE_HMONG),
+    ("Old_Hungarian", &["Hung", "Old_Hungarian"], SC_OLD_HUNGARIAN),
+    ("Old_Italic", &["Ital", "Old_Italic"], SC_OLD_ITALIC),
+    ("Javanese", &["Java", "Javanese"], SC_JAVANESE),
+    ("Kayah_Li", &["Kali", "Kayah_Li"], SC_KAYAH_LI),
+    ("Katakana", &["Kana", "Katakana"], SC_KATAKANA),
+    ("Kharoshthi", &["Khar", "Kharoshthi"], SC_KHAROSHTHI),
+    ("Khmer", &["Khmr", "Khmer"], SC_KHMER),
+    ("Khojki", &["Khoj", "Khojki"], SC_KHOJKI),
+    ("Khitan_Small_Script", &["Kits", "Khitan_Small_Script"], SC_KHITAN_SMALL_SCRIPT),
+    ("Kannada", &["Knda", "Kannada"], SC_KANNADA),
+    ("Kaithi", &["Kthi", "Kaithi"], SC_KAITHI),
+    ("Tai_Tham", &["Lana", "Tai_Tham"], SC_TAI_THAM),
+    ("Lao", &["Laoo", "Lao"], SC_LAO),
+    ("Latin", &["Latn", "Latin"], SC_LATIN),
+    ("Lepcha", &["Lepc", "Lepcha"], SC_LEPCHA),
+    ("Limbu", &["Limb", "Limbu"], SC_LIMBU),
+    ("Linear_A", &["Lina", "Linear_A"], SC_LINEAR_A),
+    ("Linear_B", &["Linb", "Linear_B"], SC_LINEAR_B),
+    ("Lisu", &["Lisu"], SC_LISU),
+    ("Lycian", &["Lyci", "Lycian"], SC_LYCIAN),
+    ("Lydian", &["Lydi", "Lydian"], SC_LYDIAN),
+    ("Mahajani", &["Mahj", "Mahajani"], SC_MAHAJANI),
+    ("Makasar", &["Maka", "Makasar"], SC_MAKASAR),
+    ("Mandaic", &["Mand", "Mandaic"], SC_MANDAIC),
+    ("Manichaean", &["Mani", "Manichaean"], SC_MANICHAEAN),
+    ("Marchen", &["Marc", "Marchen"], SC_MARCHEN),
+    ("Medefaidrin", &["Medf", "Medefaidrin"], SC_MEDEFAIDRIN),
+    ("Mende_Kikakui", &["Mend", "Mende_Kikakui"], SC_MENDE_KIKAKUI),
+    ("Meroitic_Cursive", &["Merc", "Meroitic_Cursive"], SC_MEROITIC_CURSIVE),
+    ("Meroitic_Hieroglyphs", &["Mero", "Meroitic_Hieroglyphs"], SC_MEROITIC_HIEROGLYPHS),
+    ("Malayalam", &["Mlym", "Malayalam"], SC_MALAYALAM),
+    ("Modi", &["Modi"], SC_MODI),
+    ("Mongolian", &["Mong", "Mongolian"], SC_MONGOLIAN),
+    ("Mro", &["Mroo", "Mro"], SC_MRO),
+    ("Meetei_Mayek", &["Mtei", "Meetei_Mayek"], SC_MEETEI_MAYEK),
+    ("Multani", &["Mult", "Multani"], SC_MULTANI),
+    ("Myanmar", &["Mymr", "Myanmar"], SC_MYANMAR),
+    ("Nandinagari", &["Nand", "Nandinagari"], SC_NANDINAGARI),
+    ("Old_North_Arabian", &["Narb", "Old_North_Arabian"], SC_OLD_NORTH_ARABIAN),
+    ("Nabataean", &["Nbat", "Nabataean"], SC_NABATAEAN),
+    ("Newa", &["Newa"], SC_NEWA),
+    ("Nko", &["Nkoo", "Nko"], SC_NKO),
+    ("Nushu", &["Nshu", "Nushu"], SC_NUSHU),
+    ("Ogham", &["Ogam", "Ogham"], SC_OGHAM),
+    ("Ol_Chiki", &["Olck", "Ol_Chiki"], SC_OL_CHIKI),
+    ("Old_Turkic", &["Orkh", "Old_Turkic"], SC_OLD_TURKIC),
+    ("Oriya", &["Orya", "Oriya"], SC_ORIYA),
+    ("Osage", &["Osge", "Osage"], SC_OSAGE),
+    ("Osmanya", &["Osma", "Osmanya"], SC_OSMANYA),
+    ("Old_Uyghur", &["Ougr", "Old_Uyghur"], SC_OLD_UYGHUR),
+    ("Palmyrene", &["Palm", "Palmyrene"], SC_PALMYRENE),
+    ("Pau_Cin_Hau", &["Pauc", "Pau_Cin_Hau"], SC_PAU_CIN_HAU),
+    ("Old_Permic", &["Perm", "Old_Permic"], SC_OLD_PERMIC),
+    ("Phags_Pa", &["Phag", "Phags_Pa"], SC_PHAGS_PA),
+    ("Inscriptional_Pahlavi", &["Phli", "Inscriptional_Pahlavi"], SC_INSCRIPTIONAL_PAHLAVI),
+    ("Psalter_Pahlavi", &["Phlp", "Psalter_Pahlavi"], SC_PSALTER_PAHLAVI),
+    ("Phoenician", &["Phnx", "Phoenician"], SC_PHOENICIAN),
+    ("Miao", &["Plrd", "Miao"], SC_MIAO),
+    ("Inscriptional_Parthian", &["Prti", "Inscriptional_Parthian"], SC_INSCRIPTIONAL_PARTHIAN),
+    ("Rejang", &["Rjng", "Rejang"], SC_REJANG),
+    ("Hanifi_Rohingya", &["Rohg", "Hanifi_Rohingya"], SC_HANIFI_ROHINGYA),
+    ("Runic", &["Runr", "Runic"], SC_RUNIC),
+    ("Samaritan", &["Samr", "Samaritan"], SC_SAMARITAN),
+    ("Old_South_Arabian", &["Sarb", "Old_South_Arabian"], SC_OLD_SOUTH_ARABIAN),
+    ("Saurashtra", &["Saur", "Saurashtra"], SC_SAURASHTRA),
+    ("SignWriting", &["Sgnw", "SignWriting"], SC_SIGNWRITING),
+    ("Shavian", &["Shaw", "Shavian"], SC_SHAVIAN),
+    ("Sharada", &["Shrd", "Sharada"], SC_SHARADA),
+    ("Siddham", &["Sidd", "Siddham"], SC_SIDDHAM),
+    ("Khudawadi", &["Sind", "Khudawadi"], SC_KHUDAWADI),
+    ("Sinhala", &["Sinh", "Sinhala"], SC_SINHALA),
+    ("Sogdian", &["Sogd", "Sogdian"], SC_SOGDIAN),
+    ("Old_Sogdian", &["Sogo", "Old_Sogdian"], SC_OLD_SOGDIAN),
+    ("Sora_Sompeng", &["Sora", "Sora_Sompeng"], SC_SORA_SOMPENG),
+    ("Soyombo", &["Soyo", "Soyombo"], SC_SOYOMBO),
+    ("Sundanese", &["Sund", "Sundanese"], SC_SUNDANESE),
+    ("Syloti_Nagri", &["Sylo", "Syloti_Nagri"], SC_SYLOTI_NAGRI),
+    ("Syriac", &["Syrc", "Syriac"], SC_SYRIAC),
+    ("Tagbanwa", &["Tagb", "Tagbanwa"], SC_TAGBANWA),
+    ("Takri", &["Takr", "Takri"], SC_TAKRI),
+    ("Tai_Le", &["Tale", "Tai_Le"], SC_TAI_LE),
+    ("New_Tai_Lue", &["Talu", "New_Tai_Lue"], SC_NEW_TAI_LUE),
+    ("Tamil", &["Taml", "Tamil"], SC_TAMIL),
+    ("Tangut", &["Tang", "Tangut"], SC_TANGUT),
+    ("Tai_Viet", &["Tavt", "Tai_Viet"], SC_TAI_VIET),
+    ("Telugu", &["Telu", "Telugu"], SC_TELUGU),
+    ("Tifinagh", &["Tfng", "Tifinagh"], SC_TIFINAGH),
+    ("Tagalog", &["Tglg", "Tagalog"], SC_TAGALOG),
+    ("Thaana", &["Thaa", "Thaana"], SC_THAANA),
+    ("Thai", &["Thai"], SC_THAI),
+    ("Tibetan", &["Tibt", "Tibetan"], SC_TIBETAN),
+    ("Tirhuta", &["Tirh", "Tirhuta"], SC_TIRHUTA),
+    ("Tangsa", &["Tnsa", "Tangsa"], SC_TANGSA),
+    ("Toto", &["Toto"], SC_TOTO),
+    ("Ugaritic", &["Ugar", "Ugaritic"], SC_UGARITIC),
+    ("Vai", &["Vaii", "Vai"], SC_VAI),
+    ("Vithkuqi", &["Vith", "Vithkuqi"], SC_VITHKUQI),
+    ("Warang_Citi", &["Wara", "Warang_Citi"], SC_WARANG_CITI),
+    ("Wancho", &["Wcho", "Wancho"], SC_WANCHO),
+    ("Old_Persian", &["Xpeo", "Old_Persian"], SC_OLD_PERSIAN),
+    ("Cuneiform", &["Xsux", "Cuneiform"], SC_CUNEIFORM),
+    ("Yezidi", &["Yezi", "Yezidi"], SC_YEZIDI),
+    ("Yi", &["Yiii", "Yi"], SC_YI),
+    ("Zanabazar_Square", &["Zanb", "Zanabazar_Square"], SC_ZANABAZAR_SQUARE),
+    ("Inherited", &["Zinh", "Inherited", "Qaai"], SC_INHERITED),
+    ("Common", &["Zyyy", "Common"], SC_COMMON),
+    ("Unknown", &["Zzzz", "Unknown"], SC_UNKNOWN),
+];
@@ -0,0 +1,238 @@
+#!/usr/bin/env perl
+# Generate src/unicode_tables.rs from the Unicode Character Database bundled with Perl.
+#
+#   perl tools/gen_unicode_tables.pl > src/unicode_tables.rs
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invlist prop_invmap prop_values prop_value_aliases charinfo);
+use Unicode::Normalize ();
+use Encode ();
+
+# ranges of code points which have the property
+sub prop {
+    my ($prop) = @_;
+    my @invlist = prop_invlist($prop);
+    die "unknown property: $prop" unless @invlist;
+    my @ranges;
+    for (my $i = 0; $i < @invlist; $i += 2) {
+        my $end = $i + 1 < @invlist ? $invlist[$i + 1] - 1 : 0x10FFFF;
+        push @ranges, [$invlist[$i], $end];
+    }
+    return \@ranges;
+}
+
+# ranges of the listed code points
+sub points {
+    return [map { [$_, $_] } @_];
+}
+
+# union of sets of ranges
+sub union {
+    my @all = sort { $a->[0] <=> $b->[0] } map { @$_ } @_;
+    my @ranges;
+    for my $r (@all) {
+        if (@ranges && $r->[0] <= $ranges[-1][1] + 1) {
+            $ranges[-1][1] = $r->[1] if $r->[1] > $ranges[-1][1];
+        } else {
+            push @ranges, [@$r];
+        }
+    }
+    return \@ranges;
+}
+
+# intersection of two sets of ranges
+sub intersect {
+    my ($x, $y) = @_;
+    my @ranges;
+    for my $a (@$x) {
+        for my $b (@$y) {
+            my $start = $a->[0] > $b->[0] ? $a->[0] : $b->[0];
+            my $end = $a->[1] < $b->[1] ? $a->[1] : $b->[1];
+            push @ranges, [$start, $end] if $start <= $end;
+        }
+    }
+    return union(\@ranges);
+}
+
+# kana marks shared by hiragana and katakana:
+# combining and spacing (semi-)voiced sound marks, prolonged sound mark
+my $KANA_MARKS = points(0x3099 .. 0x309C, 0x30FC);
+
+# kanji of JIS X 0208 from the row `first` to the cell `last_cell` of the row `last`
+sub jis_kanji {
+    my ($first, $last, $last_cell) = @_;
+    my @points;
+    for my $row ($first .. $last) {
+        for my $cell (1 .. ($row == $last ? $last_cell : 94)) {
+            my $euc = pack('CC', 0xA0 + $row, 0xA0 + $cell);
+            my $s = Encode::decode('euc-jp', $euc, Encode::FB_CROAK);
+            push @points, ord($s);
+        }
+    }
+    return union(points(@points));
+}
+
+# (constant name, set of ranges)
+my @TABLES = (
+    ['ALPHABETIC', prop('Alphabetic')],
+    ['DECIMAL_NUMBER', prop('General_Category=Nd')],
+    ['WHITE_SPACE', prop('White_Space')],
+    ['UPPERCASE', prop('Uppercase')],
+    ['LOWERCASE', prop('Lowercase')],
+    ['PUNCTUATION', prop('General_Category=P')],
+    ['HIRAGANA', union(prop('Script=Hiragana'), $KANA_MARKS)],
+    # halfwidth prolonged sound mark and (semi-)voiced sound marks are Common
+    ['KATAKANA', union(prop('Script=Katakana'), $KANA_MARKS, points(0xFF70, 0xFF9E, 0xFF9F))],
+    ['KANJI', prop('Script=Han')],
+    ['FULLWIDTH', prop('East_Asian_Width=F')],
+    ['HALFWIDTH_KATAKANA', union(
+        intersect(prop('Script=Katakana'), prop('East_Asian_Width=H')),
+        points(0xFF65, 0xFF70, 0xFF9E, 0xFF9F),
+    )],
+    # JIS X 0208 level 1 (rows 16-47) and level 2 (rows 48-84) kanji
+    ['JIS_LEVEL1', jis_kanji(16, 47, 51)],
+    ['JIS_LEVEL2', jis_kanji(48, 84, 6)],
+    # characters which may change in NFC / NFKC (quick check is "No" or "Maybe")
+    ['NFC_QUICK_CHECK', union(prop('NFC_Quick_Check=No'), prop('NFC_Quick_Check=Maybe'))],
+    ['NFKC_QUICK_CHECK', union(prop('NFKC_Quick_Check=No'), prop('NFKC_Quick_Check=Maybe'))],
+);
+
+# surrogates are not chars
+sub without_surrogates {
+    my ($set) = @_;
+    my @ranges;
+    for my $r (@$set) {
+        my ($start, $end) = @$r;
+        push @ranges, [$start, $end < 0xD7FF ? $end : 0xD7FF] if $start < 0xD800;
+        push @ranges, [$start > 0xE000 ? $start : 0xE000, $end] if $end > 0xDFFF;
+    }
+    return @ranges;
+}
+
+sub emit_table {
+    my ($name, $set) = @_;
+    print "\n";
+    print "pub const $name: &[(char, char)] = &[\n";
+    my @items = map { sprintf("('\\u{%x}', '\\u{%x}')", @$_) } without_surrogates($set);
+    while (my @line = splice(@items, 0, 4)) {
+        print "    ", join(", ", @line), ",\n";
+    }
+    print "];\n";
+}
+
+sub rust_char {
+    return sprintf("'\\u{%x}'", $_[0]);
+}
+
+sub rust_str {
+    return '"' . join('', map { sprintf("\\u{%x}", ord($_)) } split(//, $_[0])) . '"';
+}
+
+sub emit_list {
+    my ($name, $type, $per_line, @items) = @_;
+    print "\n";
+    print "pub const $name: &[$type] = &[\n";
+    while (my @line = splice(@items, 0, $per_line)) {
+        print "    ", join(", ", @line), ",\n";
+    }
+    print "];\n";
+}
+
+# code points except surrogates and Hangul syllables (which are decomposed algorithmically)
+my @CODE_POINTS = grep { !($_ >= 0xAC00 && $_ <= 0xD7A3) } (0 .. 0xD7FF, 0xE000 .. 0x10FFFF);
+
+# canonical combining class (0 is omitted)
+sub emit_combining_class {
+    my ($list, $map) = prop_invmap('Canonical_Combining_Class');
+    my @items;
+    for my $i (0 .. $#$list - 1) {
+        next if $map->[$i] eq '0';
+        my $class = $map->[$i] =~ /^\d+$/ ? $map->[$i] : die "unexpected class: $map->[$i]";
+        push @items, sprintf("(%s, %s, %d)", rust_char($list->[$i]), rust_char($list->[$i + 1] - 1), $class);
+    }
+    emit_list('COMBINING_CLASS', '(char, char, u8)', 3, @items);
+}
+
+# full canonical and compatibility decompositions
+sub emit_decomposition {
+    my (@canonical, @compatibility);
+    for my $cp (@CODE_POINTS) {
+        my $canon = Unicode::Normalize::getCanon($cp);
+        my $compat = Unicode::Normalize::getCompat($cp);
+        push @canonical, sprintf("(%s, %s)", rust_char($cp), rust_str($canon)) if defined $canon;
+        if (defined $compat && (!defined $canon || $compat ne $canon)) {
+            push @compatibility, sprintf("(%s, %s)", rust_char($cp), rust_str($compat));
+        }
+    }
+    emit_list('CANONICAL_DECOMPOSITION', '(char, &str)', 3, @canonical);
+    emit_list('COMPATIBILITY_DECOMPOSITION', '(char, &str)', 3, @compatibility);
+}
+
+# primary composites sorted by the pair of characters
+sub emit_composition {
+    my %excluded = map { $_ => 1 } map { $_->[0] .. $_->[1] } @{prop('Full_Composition_Exclusion')};
+    my @pairs;
+    for my $cp (@CODE_POINTS) {
+        next if $excluded{$cp} || !defined Unicode::Normalize::getCanon($cp);
+        my $decomposition = charinfo($cp)->{decomposition};
+        next if $decomposition =~ /^</;
+        my @pair = map { hex } split(/ /, $decomposition);
+        push @pairs, [@pair, $cp] if @pair == 2;
+    }
+    @pairs = sort { $a->[0] <=> $b->[0] || $a->[1] <=> $b->[1] } @pairs;
+    emit_list('COMPOSITION', '(char, char, char)', 3, map { sprintf("(%s)", join(", ", map { rust_char($_) } @$_)) } @pairs);
+}
+
+# grapheme cluster break property (Other is omitted)
+sub emit_grapheme_break {
+    my %names = (
+        CR => 'Cr', LF => 'Lf', Control => 'Control', Extend => 'Extend', ZWJ => 'Zwj',
+        Regional_Indicator => 'RegionalIndicator', Prepend => 'Prepend', SpacingMark => 'SpacingMark',
+        L => 'L', V => 'V', T => 'T', LV => 'Lv', LVT => 'Lvt',
+        # Extended_Pictographic is merged into Other by Perl
+        ExtPict_XX => 'ExtendedPictographic',
+    );
+    my ($list, $map) = prop_invmap('Grapheme_Cluster_Break');
+    my @items;
+    for my $i (0 .. $#$list - 1) {
+        next if $map->[$i] eq 'Other';
+        my $name = $names{$map->[$i]} // die "unexpected value: $map->[$i]";
+        for my $r (without_surrogates([[$list->[$i], $list->[$i + 1] - 1]])) {
+            push @items, sprintf("(%s, %s, GraphemeBreak::%s)", rust_char($r->[0]), rust_char($r->[1]), $name);
+        }
+    }
+    emit_list('GRAPHEME_BREAK', '(char, char, GraphemeBreak)', 2, @items);
+}
+
+# general categories and scripts usable as \p{..}: (display name, aliases, table)
+sub emit_properties {
+    my ($list_name, $prop_name, $prefix, $display) = @_;
+    my @entries;
+    for my $value (sort(prop_values($prop_name))) {
+        my @aliases = prop_value_aliases($prop_name, $value);
+        next unless @aliases;
+        my $name = $aliases[$display];
+        my $table = $prefix . uc($name);
+        emit_table($table, prop("$prop_name=$aliases[0]"));
+        my %seen;
+        my @unique = grep { !$seen{$_}++ } @aliases;
+        push @entries, sprintf('("%s", &[%s], %s)', $name, join(", ", map { "\"$_\"" } @unique), $table);
+    }
+    emit_list($list_name, 'PropertyEntry', 1, @entries);
+}
+
+print "// This file is generated by tools/gen_unicode_tables.pl. Do not edit.\n";
+printf "// Unicode version: %s\n", Unicode::UCD::UnicodeVersion();
+print "\nuse crate::grapheme::GraphemeBreak;\n";
+for my $table (@TABLES) {
+    emit_table(@$table);
+}
+emit_combining_class();
+emit_decomposition();
+emit_composition();
+emit_grapheme_break();
+# short names for general categories (Lu) and long names for scripts (Han)
+print "\n/// (display name, aliases, table)\n";
+print "pub type PropertyEntry = (&'static str, &'static [&'static str], &'static [(char, char)]);\n";
+emit_properties('GENERAL_CATEGORIES', 'General_Category', 'GC_', 0);
+emit_properties('SCRIPTS', 'Script', 'SC_', 1);